@@ -0,0 +1,67 @@
+//! Benchmark for the per-cycle overhead of recording local memory access events in
+//! [`ExecutorMode::Trace`], comparing the batched log-and-fold path against a memory-bound
+//! guest program that repeatedly reads and writes the same working set.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use zkm2_core_executor::{Executor, Instruction, Opcode, Program, Register};
+use zkm2_stark::ZKMCoreOpts;
+
+/// Builds a guest program that loops `iters` times over a small window of low memory words,
+/// issuing one load and one store per iteration so the benchmark is dominated by memory-access
+/// bookkeeping rather than ALU work.
+fn memory_bound_program(iters: u32) -> Program {
+    let mut instructions = vec![
+        // a0 = 0 (loop counter)
+        Instruction::new(Opcode::ADD, Register::A0 as u8, 0, 0, false, true),
+        // a1 = iters (loop bound)
+        Instruction::new(Opcode::ADD, Register::A1 as u8, 0, iters, false, true),
+    ];
+    let loop_start = instructions.len() as u32;
+    instructions.extend([
+        // a2 = a0 & 0xff, so the working set stays a handful of words (cache/hashmap-friendly).
+        Instruction::new(Opcode::AND, Register::A2 as u8, Register::A0 as u8, 0xff, false, true),
+        // a3 = load word at [a2]
+        Instruction::new(Opcode::LW, Register::A3 as u8, Register::A2 as u8, 0, false, true),
+        // a3 += 1
+        Instruction::new(Opcode::ADD, Register::A3 as u8, Register::A3 as u8, 1, false, true),
+        // store a3 back at [a2]
+        Instruction::new(Opcode::SW, Register::A3 as u8, Register::A2 as u8, 0, false, true),
+        // a0 += 1
+        Instruction::new(Opcode::ADD, Register::A0 as u8, Register::A0 as u8, 1, false, true),
+        // loop while a0 != a1
+        Instruction::new(Opcode::BNE, Register::A0 as u8, Register::A1 as u8, 0, true, false),
+    ]);
+    let loop_len = instructions.len() as u32 - loop_start;
+    let back_branch = instructions.len() - 1;
+    // Branch back to `loop_start`: the offset is relative to the delay slot's pc (the branch's
+    // pc + 4), so it's negative (wrapping) for a backward jump.
+    instructions[back_branch] = Instruction::new(
+        Opcode::BNE,
+        Register::A0 as u8,
+        Register::A1 as u8,
+        0u32.wrapping_sub(loop_len * 4),
+        true,
+        false,
+    );
+    // Delay slot of the back branch (a no-op write to `zero`).
+    instructions.push(Instruction::new(Opcode::ADD, 0, 0, 0, false, true));
+
+    Program::new(instructions, 0, 0)
+}
+
+fn bench_local_memory_access(c: &mut Criterion) {
+    let mut group = c.benchmark_group("local_memory_access");
+    for iters in [1_000u32, 10_000, 100_000] {
+        group.bench_with_input(BenchmarkId::from_parameter(iters), &iters, |b, &iters| {
+            b.iter(|| {
+                let program = memory_bound_program(iters);
+                let mut runtime = Executor::new(program, ZKMCoreOpts::default());
+                runtime.run().unwrap();
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_local_memory_access);
+criterion_main!(benches);
@@ -0,0 +1,62 @@
+//! Generates the symbolic disassembler and the `MiscInstrsChip` selector lookup from
+//! `instructions.in`.
+//!
+//! The way holey-bytes derives its decoder/disassembler from one instruction table, this turns
+//! a single declarative list of `<Opcode> <operand format> [misc selector]` lines into two
+//! `match`es over `Opcode`: one the runtime disassembler (see `src/disasm.rs`) uses to pick an
+//! operand layout, the other `zkm2_core_machine::misc::trace`'s `event_to_row` uses to populate
+//! its `is_*` selector columns. Keeping both in one place means adding a new opcode only means
+//! adding a line here instead of hand-updating a formatter and a chain of boolean selectors that
+//! can both independently drift out of sync with `Instruction::decode_from`.
+
+use std::{
+    env,
+    fmt::Write as _,
+    fs,
+    path::Path,
+};
+
+fn main() {
+    println!("cargo:rerun-if-changed=instructions.in");
+
+    let table = fs::read_to_string("instructions.in").expect("failed to read instructions.in");
+
+    let mut operand_format = String::new();
+    operand_format.push_str("// @generated by build.rs from instructions.in. Do not edit by hand.\n");
+    operand_format.push_str("match opcode {\n");
+
+    let mut misc_selector = String::new();
+    misc_selector.push_str("// @generated by build.rs from instructions.in. Do not edit by hand.\n");
+    misc_selector.push_str("match opcode {\n");
+
+    for line in table.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut parts = line.split_whitespace();
+        let opcode = parts.next().expect("missing opcode column in instructions.in");
+        let format = parts.next().expect("missing format column in instructions.in");
+        writeln!(
+            operand_format,
+            "    crate::Opcode::{opcode} => crate::disasm::OperandFormat::{format},"
+        )
+        .unwrap();
+
+        if let Some(selector) = parts.next() {
+            writeln!(
+                misc_selector,
+                "    crate::Opcode::{opcode} => Some(crate::disasm::MiscSelector::{selector}),"
+            )
+            .unwrap();
+        }
+    }
+    operand_format.push_str("}\n");
+    misc_selector.push_str("    _ => None,\n}\n");
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
+    fs::write(Path::new(&out_dir).join("operand_format.rs"), operand_format)
+        .expect("failed to write generated operand_format.rs");
+    fs::write(Path::new(&out_dir).join("misc_selector.rs"), misc_selector)
+        .expect("failed to write generated misc_selector.rs");
+}
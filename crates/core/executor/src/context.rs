@@ -0,0 +1,78 @@
+//! Host-provided configuration for an [`crate::Executor`], threaded through
+//! [`crate::Executor::with_context`].
+
+use std::sync::Arc;
+
+use crate::{
+    cycle_cost::CycleCostModel, diagnostics::SymbolResolver, executor::TickAction,
+    hook::HookRegistry, state::ExecutionState, subproof::SubproofVerifier, trace::TraceSink,
+    trap::TrapHandler,
+};
+
+/// Context for configuring the execution of a program.
+#[derive(Default)]
+pub struct ZKMContext<'a> {
+    /// The maximum number of cycles to run the program for.
+    pub max_cycles: Option<u64>,
+    /// A cycle budget the guest can query via `SYSMETER` and meter itself against, checked
+    /// alongside [`Self::max_cycles`] in the syscall dispatch path. Unlike `max_cycles`, crossing
+    /// it aborts with [`crate::ExecutionError::CycleBudgetExceeded`], a distinguished exit
+    /// condition a prover can use to tell "untrusted program ran past its declared budget" apart
+    /// from "hit the outer hard ceiling". `None` (the default) disables metering.
+    pub cycle_limit: Option<u64>,
+    /// Whether to skip deferred proof verification.
+    pub skip_deferred_proof_verification: bool,
+    /// A custom hook registry, invoked by writing to certain file descriptors.
+    pub hook_registry: Option<HookRegistry<'a>>,
+    /// A custom verifier for deferred proofs.
+    pub subproof_verifier: Option<Arc<dyn SubproofVerifier + 'a>>,
+    /// Whether to flag loads from memory that were never written and have no initial value from
+    /// the program image, instead of silently treating them as zero. A "valgrind-lite" pass over
+    /// a guest program, useful to run before the expensive proving phase.
+    pub detect_uninitialized: bool,
+    /// Whether an uninitialized read should abort execution with
+    /// [`crate::ExecutionError::UninitializedRead`], rather than merely being recorded in the
+    /// [`crate::ExecutionReport`]. Has no effect unless `detect_uninitialized` is also set.
+    pub uninitialized_reads_are_fatal: bool,
+    /// A custom cycle-cost model for estimating realistic MIPS timing. Defaults to
+    /// [`crate::cycle_cost::DefaultCycleCostModel`], which charges one cycle per access.
+    pub cycle_cost_model: Option<Arc<dyn CycleCostModel>>,
+    /// Whether naturally-aligned loads/stores (`LW`/`LL`/`SW`/`SC`/`LH`/`LHU`/`SH`) must be
+    /// aligned to their access size, raising [`crate::ExecutionError::MemoryAlignment`] instead of
+    /// silently masking the low address bits the way real MIPS hardware would fault. Defaults to
+    /// `false` so existing programs that rely on the legacy silent-masking behavior keep working.
+    pub strict_memory_alignment: bool,
+    /// An optional cap, in [`crate::cycle_cost::CycleCostModel`]-weighted cost units, on how much
+    /// trace-column pressure a shard may accumulate before it's closed early, checked alongside
+    /// the existing uniform `shard_size` (in cycles). `None` (the default) disables this and
+    /// keeps the legacy shard-size-only behavior, since the weighted cost only reflects something
+    /// meaningful once a non-default [`crate::cycle_cost::CycleCostModel`] is configured.
+    pub weighted_shard_size: Option<u64>,
+    /// The cadence, in `global_clk` cycles, at which `tick_callback` is invoked. `0` (the
+    /// default) disables it.
+    pub tick_quotient: usize,
+    /// An optional host callback invoked every `tick_quotient` cycles with a read-only view of
+    /// the current [`ExecutionState`], modeled on the "timer quotient" pattern used to trap a
+    /// guest VM to its host at a fixed cycle cadence. Gives an embedder progress reporting,
+    /// watchdog/time-budget enforcement, and the ability to inject shard cuts without the guest
+    /// cooperating. See [`TickAction`].
+    pub tick_callback: Option<Box<dyn FnMut(&ExecutionState) -> TickAction + 'a>>,
+    /// An opt-in sink for a per-instruction disassembly + register-delta trace (see
+    /// [`crate::trace`]), e.g. for replaying a failing test with the exact instruction that
+    /// computed a wrong register value visible. `None` (the default) disables it, so this has no
+    /// overhead unless installed.
+    pub instruction_trace: Option<Box<dyn TraceSink + 'a>>,
+    /// Whether [`Self::instruction_trace`] lines are ANSI-colorized for terminal debugging.
+    /// Ignored if `instruction_trace` is `None`. Defaults to `false`, so a file or in-memory
+    /// capture stays plain text unless explicitly asked to style it.
+    pub instruction_trace_color: bool,
+    /// Maps a faulting PC back to a guest source location for
+    /// [`crate::diagnostics::Fault::render`], e.g. via DWARF info parsed out of the loaded ELF.
+    /// `None` (the default) means faults render without a source snippet.
+    pub symbol_resolver: Option<Arc<dyn SymbolResolver + 'a>>,
+    /// A policy for syscalls [`crate::syscalls::default_syscall_map`] doesn't recognize, and for
+    /// bad file descriptors passed to ones that are (see [`TrapHandler`]). `None` (the default)
+    /// preserves the legacy behavior: an unrecognized `SyscallCode` aborts with
+    /// [`crate::ExecutionError::UnsupportedSyscall`], and a bad fd returns `-1`/`MIPS_EBADF`.
+    pub trap_handler: Option<Arc<dyn TrapHandler + 'a>>,
+}
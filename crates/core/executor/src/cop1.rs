@@ -0,0 +1,115 @@
+//! A minimal COP1 (floating-point) subsystem.
+//!
+//! MIPS32 FPUs carry 32 FP registers, each viewable as a 32-bit single or a 64-bit double (in the
+//! `FR=1` layout modeled here: a double occupies one full register rather than an even/odd pair),
+//! plus an `FCSR` control/status register holding the rounding mode and condition-code flags.
+//!
+//! Rounding must be reproducible for proving, so [`Fcsr`] pins the rounding mode to
+//! round-to-nearest-even (ties to even) and never deviates from it: every arithmetic op here uses
+//! plain `f32`/`f64` operators, which are already defined by IEEE-754 to round to nearest-even, so
+//! there is no separate rounding step to pin — the invariant is simply that one is never added.
+//!
+//! `FpRegisterFile` is a CPU-side scratchpad, not part of the memory-mapped register file that
+//! backs [`crate::events::CpuEvent`]/[`crate::events::MemoryRecord`] tracing the way general
+//! registers are (see the "register file lives in memory" note on
+//! [`crate::state::ExecutionState`]). That means FP-bearing guests execute correctly, but FP
+//! register reads/writes don't yet produce trace events of their own beyond
+//! [`crate::events::FpAluEvent`] — wiring an FPR memory image into the proving pipeline is a
+//! larger follow-up.
+
+use serde::{Deserialize, Serialize};
+
+/// The 32 FPU registers, each able to hold either one `f64` or one `f32` (in the low 32 bits).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct FpRegisterFile {
+    regs: [u64; 32],
+}
+
+impl Default for FpRegisterFile {
+    fn default() -> Self {
+        Self { regs: [0; 32] }
+    }
+}
+
+impl FpRegisterFile {
+    /// Read register `idx` as a single-precision float (its low 32 bits).
+    #[must_use]
+    pub fn read_single(&self, idx: usize) -> f32 {
+        f32::from_bits(self.regs[idx] as u32)
+    }
+
+    /// Write a single-precision float to register `idx`, leaving its high 32 bits at zero.
+    pub fn write_single(&mut self, idx: usize, value: f32) {
+        self.regs[idx] = u64::from(value.to_bits());
+    }
+
+    /// Read register `idx` as a double-precision float.
+    #[must_use]
+    pub fn read_double(&self, idx: usize) -> f64 {
+        f64::from_bits(self.regs[idx])
+    }
+
+    /// Write a double-precision float to register `idx`.
+    pub fn write_double(&mut self, idx: usize, value: f64) {
+        self.regs[idx] = value.to_bits();
+    }
+
+    /// Read register `idx`'s raw bit pattern, e.g. for `LWC1`/`SWC1`'s low word.
+    #[must_use]
+    pub fn read_raw_low(&self, idx: usize) -> u32 {
+        self.regs[idx] as u32
+    }
+
+    /// Read register `idx`'s raw high word, for `LDC1`/`SDC1`.
+    #[must_use]
+    pub fn read_raw_high(&self, idx: usize) -> u32 {
+        (self.regs[idx] >> 32) as u32
+    }
+
+    /// Overwrite register `idx`'s low 32 bits, for `LWC1`.
+    pub fn write_raw_low(&mut self, idx: usize, value: u32) {
+        self.regs[idx] = (self.regs[idx] & 0xFFFF_FFFF_0000_0000) | u64::from(value);
+    }
+
+    /// Overwrite register `idx`'s high 32 bits, for `LDC1`.
+    pub fn write_raw_high(&mut self, idx: usize, value: u32) {
+        self.regs[idx] = (self.regs[idx] & 0x0000_0000_FFFF_FFFF) | (u64::from(value) << 32);
+    }
+}
+
+/// `FCSR`'s eight condition-code flags, set by `c.cond.{s,d}` and read by `bc1{t,f}`.
+///
+/// Only `cc` 0 is used by the single condition-code MIPS I ISA encoding this executor targets;
+/// the full eight-bit field is modeled so a guest compiled against the MIPS32 multi-cc encoding
+/// still has somewhere to put its comparisons.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct Fcsr {
+    condition_flags: u8,
+}
+
+impl Fcsr {
+    /// Set condition-code flag `cc` (0..8) to `value`.
+    pub fn set_condition(&mut self, cc: u32, value: bool) {
+        let bit = 1 << cc;
+        if value {
+            self.condition_flags |= bit;
+        } else {
+            self.condition_flags &= !bit;
+        }
+    }
+
+    /// Read condition-code flag `cc` (0..8).
+    #[must_use]
+    pub fn condition(&self, cc: u32) -> bool {
+        self.condition_flags & (1 << cc) != 0
+    }
+}
+
+/// The full COP1 register state: the FP register file plus `FCSR`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct Cop1State {
+    /// The 32 FP registers.
+    pub fpr: FpRegisterFile,
+    /// The FP control/status register (rounding mode pinned to nearest-even; see module docs).
+    pub fcsr: Fcsr,
+}
@@ -0,0 +1,71 @@
+//! A minimal CP0 (Coprocessor 0) exception subsystem.
+//!
+//! Real MIPS cores route traps (bad addresses, `syscall`, `teq`/`break`, ...) through CP0's
+//! `Status`/`Cause`/`EPC`/`BadVAddr` registers and a fixed exception vector rather than aborting
+//! the CPU outright. [`Executor`](crate::Executor) models just enough of that to let `teq` raise a
+//! catchable exception instead of panicking: the faulting `pc` is saved to `EPC` (with `Cause`'s
+//! `BD` bit set when it sits in a branch-delay slot), the cause is recorded, and control transfers to
+//! [`GENERAL_EXCEPTION_VECTOR`]. `eret` is the inverse, restoring `pc` from `EPC`.
+
+use serde::{Deserialize, Serialize};
+
+/// The fixed address MIPS dispatches general exceptions to (the address Linux/boot code installs
+/// its handler at).
+pub const GENERAL_EXCEPTION_VECTOR: u32 = 0x8000_0180;
+
+/// The `ExcCode` field of the `Cause` register, identifying why an exception was raised.
+///
+/// Only these codes are modeled; the MIPS spec defines several more (TLB faults,
+/// coprocessor-unusable, ...) that have no meaning without an MMU or COP1/COP2. Of these, only
+/// `Tr` is raised by this executor; misaligned accesses are reported as
+/// `ExecutionError::MemoryAlignment`, not as `AdEL`/`AdES`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExcCode {
+    /// Address error on load or instruction fetch.
+    AdEL = 4,
+    /// Address error on store.
+    AdES = 5,
+    /// Trap (`teq`/`tne`/...).
+    Tr = 13,
+}
+
+/// The `Cause` register's `ExcCode` field occupies bits 2..=6.
+const CAUSE_EXC_CODE_SHIFT: u32 = 2;
+
+/// The `Cause` register's branch-delay (`BD`) bit.
+const CAUSE_BD_BIT: u32 = 1 << 31;
+
+/// CP0's exception-related register file: `Status`, `Cause`, `EPC`, and `BadVAddr`.
+///
+/// Only the subset of each register needed to raise and return from an exception is modeled; the
+/// rest of `Status` (interrupt masks, kernel/user mode, ...) and `Cause` (pending interrupts) have
+/// no effect on this executor and are left as zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cp0State {
+    /// The `Status` register.
+    pub status: u32,
+    /// The `Cause` register: `ExcCode` in bits 2..=6, the `BD` bit in bit 31.
+    pub cause: u32,
+    /// The `EPC` register: the `pc` to resume at on `eret`.
+    pub epc: u32,
+    /// The `BadVAddr` register: the faulting address, for address-error exceptions.
+    pub bad_vaddr: u32,
+}
+
+impl Cp0State {
+    /// Record an exception and return the address execution should continue at
+    /// ([`GENERAL_EXCEPTION_VECTOR`]).
+    ///
+    /// `epc` is the instruction that faulted; `in_branch_delay_slot` is whether that instruction
+    /// sits in the delay slot of a preceding branch/jump, which MIPS records in `Cause`'s `BD` bit
+    /// so the handler knows to resume one instruction earlier than `EPC` plus four.
+    pub fn raise(&mut self, exc_code: ExcCode, epc: u32, bad_vaddr: u32, in_branch_delay_slot: bool) -> u32 {
+        self.epc = epc;
+        self.bad_vaddr = bad_vaddr;
+        self.cause = (exc_code as u32) << CAUSE_EXC_CODE_SHIFT;
+        if in_branch_delay_slot {
+            self.cause |= CAUSE_BD_BIT;
+        }
+        GENERAL_EXCEPTION_VECTOR
+    }
+}
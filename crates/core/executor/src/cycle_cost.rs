@@ -0,0 +1,55 @@
+//! A pluggable cycle-cost model, so users can estimate realistic MIPS timing (distinct costs for
+//! sequential vs. non-sequential memory access, multi-cycle mul/div, etc.) without touching the
+//! proving-relevant `clk` progression, which must stay uniform for the AIR constraints.
+
+use crate::{events::MemoryAccessPosition, Opcode};
+
+/// Maps an instruction's `(opcode, memory access position, sequential?)` to a cycle count.
+pub trait CycleCostModel: Send + Sync {
+    /// The number of cycles this access costs.
+    fn cost(&self, opcode: Opcode, position: MemoryAccessPosition, sequential: bool) -> u64;
+}
+
+/// The cost model used when none is configured: every access costs exactly one cycle, matching
+/// the uniform `clk` progression the prover already assumes.
+pub struct DefaultCycleCostModel;
+
+impl CycleCostModel for DefaultCycleCostModel {
+    fn cost(&self, _opcode: Opcode, _position: MemoryAccessPosition, _sequential: bool) -> u64 {
+        1
+    }
+}
+
+/// A cost model with distinct costs for sequential vs. non-sequential word fetches and
+/// multi-cycle multiply/divide, approximating a real in-order MIPS pipeline.
+pub struct MipsPipelineCostModel {
+    /// Cycle cost of a word access that continues the previous one (`addr == prev_addr + 4`).
+    pub sequential_access_cost: u64,
+    /// Cycle cost of a word access that does not continue the previous one.
+    pub non_sequential_access_cost: u64,
+    /// Cycle cost of a multiply or divide instruction, which a real pipeline would stall on.
+    pub mul_div_cost: u64,
+}
+
+impl Default for MipsPipelineCostModel {
+    fn default() -> Self {
+        Self { sequential_access_cost: 1, non_sequential_access_cost: 2, mul_div_cost: 4 }
+    }
+}
+
+impl CycleCostModel for MipsPipelineCostModel {
+    fn cost(&self, opcode: Opcode, position: MemoryAccessPosition, sequential: bool) -> u64 {
+        if matches!(opcode, Opcode::MULT | Opcode::MULTU | Opcode::MUL | Opcode::DIV | Opcode::DIVU) {
+            return self.mul_div_cost;
+        }
+        if position == MemoryAccessPosition::Memory {
+            if sequential {
+                self.sequential_access_cost
+            } else {
+                self.non_sequential_access_cost
+            }
+        } else {
+            1
+        }
+    }
+}
@@ -0,0 +1,345 @@
+//! Decode a flat MIPS code segment into [`Instruction`]s.
+//!
+//! `Instruction::decode_from` performs the actual opcode/funct bit-extraction on a single 32-bit
+//! word; this module only turns a raw `&[u8]` byte segment into those words (MIPS is big-endian)
+//! and maps `decode_from` over the result. It exists for callers that have a flat code segment
+//! rather than a pre-built `Vec<Instruction>` or a full ELF file, e.g. [`Program::from_bytes`].
+//!
+//! [`Instruction::decode_from`] itself returns a structured [`DecodeError`] when nothing in its
+//! table matches; [`decode`] is a thin alias kept for callers -- a fuzzer, an assembler
+//! round-trip test -- that want a free function rather than an associated one. For
+//! [`crate::Executor`], failing upfront isn't the right behavior: an unrecognized word should
+//! still load as [`Opcode::UNIMPL`] and trap only if execution actually reaches it, so
+//! [`Instruction::decode_from_lenient`] maps a [`DecodeError`] back to the old `UNIMPL`-with-raw-
+//! word sentinel for that call site ([`crate::Program::from`]).
+//!
+//! This ISA is plain 32-bit MIPS with no compressed (16-bit) instruction extension, unlike
+//! RISC-V's C extension -- there's no low-bits tag to branch on, so [`decode`] only ever consumes
+//! a full word.
+
+use anyhow::{bail, Result};
+use thiserror::Error;
+
+use crate::{Instruction, WORD_SIZE};
+
+/// Split a big-endian byte segment into 32-bit words.
+///
+/// # Errors
+///
+/// Returns an error if `code`'s length isn't a multiple of [`WORD_SIZE`].
+pub fn words_from_be_bytes(code: &[u8]) -> Result<Vec<u32>> {
+    if code.len() % WORD_SIZE != 0 {
+        bail!(
+            "code segment length {} is not a multiple of the word size ({WORD_SIZE})",
+            code.len()
+        );
+    }
+    Ok(code
+        .chunks_exact(WORD_SIZE)
+        .map(|word| u32::from_be_bytes(word.try_into().unwrap()))
+        .collect())
+}
+
+/// Decode each word into an [`Instruction`], in order.
+///
+/// # Errors
+///
+/// Returns an error if any word fails to decode; see [`Instruction::decode_from`].
+pub fn decode_words(words: &[u32]) -> Result<Vec<Instruction>> {
+    words.iter().map(|word| Instruction::decode_from(*word).map_err(anyhow::Error::from)).collect()
+}
+
+/// Why [`decode`] rejected a word.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Fewer than [`WORD_SIZE`] bytes were available to decode.
+    #[error("instruction word is truncated: only {0} of {WORD_SIZE} bytes available")]
+    Truncated(usize),
+
+    /// The opcode/funct pairing is one MIPS32 explicitly reserves rather than assigns, as
+    /// opposed to one this decoder simply doesn't implement yet.
+    #[error("reserved encoding: opcode {opcode:#08b} funct {funct:#08b}")]
+    Reserved {
+        /// The word's 6-bit primary opcode field (bits 31:26).
+        opcode: u8,
+        /// The word's 6-bit funct field (bits 5:0), meaningful only for `SPECIAL` (opcode 0).
+        funct: u8,
+    },
+
+    /// No instruction in [`Instruction::decode_from`]'s table matches this opcode/funct pairing
+    /// -- either a MIPS32r2+ instruction this decoder doesn't implement yet (e.g. an unassigned
+    /// `BSHFL` `sa` selector, see [`Instruction::decode_from`]'s `0b011111`/`0b100000` arm), or
+    /// genuinely malformed.
+    #[error("unknown encoding: opcode {opcode:#08b} funct {funct:#08b}")]
+    Unknown {
+        /// The word's 6-bit primary opcode field (bits 31:26).
+        opcode: u8,
+        /// The word's 6-bit funct field (bits 5:0), meaningful only for `SPECIAL` (opcode 0).
+        funct: u8,
+    },
+
+    /// A coprocessor instruction (`MFC0`/`MTC0` and friends) named a register-bank selector this
+    /// decoder doesn't model. Currently unused -- this decoder's table doesn't decode any CP0
+    /// instructions yet, so there's no register-bank selector to validate -- but kept distinct
+    /// from [`DecodeError::Unknown`] for when that support is added, the same way
+    /// [`DecodeError::Reserved`] is kept distinct today.
+    #[error("invalid coprocessor register-bank access: sel {0:#x}")]
+    InvalidRegBankAccess(u32),
+}
+
+/// The 6-bit primary opcode field, bits 31:26.
+#[must_use]
+pub fn opcode_field(word: u32) -> u8 {
+    ((word >> 26) & 0x3f) as u8
+}
+
+/// The 6-bit funct field, bits 5:0 -- only meaningful when [`opcode_field`] is `SPECIAL` (0).
+#[must_use]
+pub fn funct_field(word: u32) -> u8 {
+    (word & 0x3f) as u8
+}
+
+/// A mask of the low `width` bits set, e.g. `ones(5) == 0x1f`. Saturates to `u32::MAX` for
+/// `width >= 32` rather than overflowing the shift.
+#[must_use]
+pub fn ones(width: u32) -> u32 {
+    if width >= 32 {
+        u32::MAX
+    } else {
+        (1u32 << width) - 1
+    }
+}
+
+/// Sign-extends the low `bits` bits of `value` to a full 32 bits, as MIPS immediate fields
+/// (16-bit `offset`/`imm`, 26-bit jump `target`) require.
+#[must_use]
+pub fn sign_extend(value: u32, bits: u32) -> u32 {
+    let shift = 32 - bits;
+    (((value << shift) as i32) >> shift) as u32
+}
+
+/// Masks a register-held shift amount (`SLLV`/`SRLV`/`SRAV`'s `rs`) down to the low 5 bits MIPS
+/// actually shifts by, e.g. a register holding `0xffffffe0` shifts by `0`.
+#[must_use]
+pub fn shift_amount(value: u32) -> u32 {
+    value & ones(5)
+}
+
+/// Rotates `value` right by `amount` bits, the 32-bit specialization of the `ROR`/`Ones` bit
+/// helpers the yaxpeax ARM a64 module uses for immediate decoding. Backs
+/// [`Instruction::decode_from`]'s `ROTR`/`ROTRV` arms (`amount` is masked to 5 bits the same way
+/// [`shift_amount`] masks a register-held shift amount).
+///
+/// [`Instruction::decode_from`]: crate::Instruction::decode_from
+#[must_use]
+pub fn ror32(value: u32, amount: u32) -> u32 {
+    let amount = amount & 31;
+    if amount == 0 {
+        value
+    } else {
+        (value >> amount) | (value << (32 - amount))
+    }
+}
+
+/// Expands an 8-bit immediate (sign : 3-bit exponent : 4-bit fraction) into a full 32-bit
+/// single-precision float bit pattern, the same way yaxpeax's ARM a64 module's `VFPExpandImm`
+/// expands AArch64's `FMOV (scalar, immediate)` encoding: the exponent's middle bit is inverted
+/// and replicated to fill out the IEEE-754 8-bit exponent field, and the 4-bit fraction is
+/// left-justified into the 23-bit mantissa.
+///
+/// This MIPS decoder's table has no encoding that carries an immediate FP constant this way
+/// today (COP1 has no analog of AArch64's `FMOV` immediate form) -- `VFPExpandImm` is in the
+/// request asking for this module's FP support to follow yaxpeax's naming/structure, so it's
+/// provided here ready for a future encoding to call, the same way [`DecodeError::Reserved`] and
+/// [`DecodeError::InvalidRegBankAccess`] are kept distinct from `Unknown` before anything decodes
+/// into them.
+#[must_use]
+pub fn vfp_expand_imm(imm8: u8) -> u32 {
+    let sign = u32::from(imm8 >> 7) & 1;
+    let b6 = u32::from(imm8 >> 6) & 1;
+    let not_b6 = b6 ^ 1;
+    let b6_replicated = if b6 == 1 { 0x1f } else { 0 };
+    let exponent = (not_b6 << 7) | (b6_replicated << 2) | (u32::from(imm8 >> 4) & 0x3);
+    let fraction = (u32::from(imm8) & 0xf) << 19;
+    (sign << 31) | (exponent << 23) | fraction
+}
+
+/// Decodes a single 32-bit MIPS instruction word.
+///
+/// A thin free-function alias for [`Instruction::decode_from`], for callers that prefer it over
+/// the associated function.
+///
+/// # Errors
+///
+/// Returns [`DecodeError::Unknown`] if `word`'s opcode/funct pairing isn't one
+/// [`Instruction::decode_from`]'s table assigns a meaning to. [`DecodeError::Reserved`] and
+/// [`DecodeError::InvalidRegBankAccess`] are currently unused -- this MIPS variant's table treats
+/// every unassigned encoding as unknown rather than architecturally reserved, and decodes no CP0
+/// instructions yet -- but are kept distinct from `Unknown` for callers that need to tell "not
+/// implemented" apart from "never will be" once such encodings are identified.
+pub fn decode(word: u32) -> Result<Instruction, DecodeError> {
+    Instruction::decode_from(word)
+}
+
+/// Byte order used to reassemble a 4-byte instruction word out of a flat byte stream, for
+/// [`decode_stream`]. MIPS code is classically big-endian -- see [`words_from_be_bytes`], which
+/// [`decode_stream`] generalizes -- but a disassembler built on top of this decoder may still
+/// need to walk a little-endian dump (e.g. one produced by a little-endian MIPS target or a tool
+/// that byte-swapped it along the way).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endian {
+    /// Most significant byte first; the MIPS default.
+    Big,
+    /// Least significant byte first.
+    Little,
+}
+
+impl Endian {
+    fn word_from_bytes(self, bytes: [u8; WORD_SIZE]) -> u32 {
+        match self {
+            Endian::Big => u32::from_be_bytes(bytes),
+            Endian::Little => u32::from_le_bytes(bytes),
+        }
+    }
+}
+
+impl Default for Endian {
+    fn default() -> Self {
+        Self::Big
+    }
+}
+
+/// Walks `code` [`WORD_SIZE`] bytes at a time, decoding each word as an [`Instruction`] and
+/// pairing it with the address (byte offset from the start of `code`) it was read from.
+///
+/// Mirrors the yaxpeax `Decoder`/`Reader` streaming front-end: rather than every caller
+/// extracting and byte-swapping one `u32` at a time the way [`decode`] requires, this walks a
+/// whole program image (or an ELF `.text` section) in one call -- a prerequisite for
+/// delay-slot-aware analysis, which needs to see an instruction's successor without the caller
+/// re-deriving it by hand at every call site.
+///
+/// A trailing partial word (`code.len()` not a multiple of [`WORD_SIZE`]) yields one final
+/// [`DecodeError::Truncated`] item for that tail rather than silently dropping it.
+pub fn decode_stream(
+    code: &[u8],
+    endian: Endian,
+) -> impl Iterator<Item = (u32, Result<Instruction, DecodeError>)> + '_ {
+    code.chunks(WORD_SIZE).enumerate().map(move |(i, chunk)| {
+        let addr = (i * WORD_SIZE) as u32;
+        match <[u8; WORD_SIZE]>::try_from(chunk) {
+            Ok(bytes) => (addr, Instruction::decode_from(endian.word_from_bytes(bytes))),
+            Err(_) => (addr, Err(DecodeError::Truncated(chunk.len()))),
+        }
+    })
+}
+
+/// Decodes a single big-endian instruction word out of a byte slice.
+///
+/// # Errors
+///
+/// Returns [`DecodeError::Truncated`] if `bytes` is shorter than [`WORD_SIZE`]; see [`decode`]
+/// for decode failures.
+pub fn decode_bytes(bytes: &[u8]) -> Result<Instruction, DecodeError> {
+    if bytes.len() < WORD_SIZE {
+        return Err(DecodeError::Truncated(bytes.len()));
+    }
+    decode(u32::from_be_bytes(bytes[..WORD_SIZE].try_into().unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, decode_bytes, decode_stream, ones, ror32, shift_amount, sign_extend, DecodeError, Endian};
+
+    #[test]
+    fn ones_builds_contiguous_low_masks() {
+        assert_eq!(ones(0), 0);
+        assert_eq!(ones(5), 0x1f);
+        assert_eq!(ones(16), 0xffff);
+        assert_eq!(ones(32), u32::MAX);
+    }
+
+    #[test]
+    fn sign_extend_16_bit_negative_offset() {
+        assert_eq!(sign_extend(0xffff, 16), 0xffff_ffff);
+        assert_eq!(sign_extend(0x7fff, 16), 0x0000_7fff);
+    }
+
+    #[test]
+    fn sign_extend_26_bit_jump_target() {
+        assert_eq!(sign_extend(0x03ff_ffff, 26), 0xffff_ffff);
+    }
+
+    #[test]
+    fn shift_amount_masks_to_low_five_bits() {
+        assert_eq!(shift_amount(0xffff_ffe0), 0);
+        assert_eq!(shift_amount(0xffff_ffff), 0x1f);
+        assert_eq!(shift_amount(3), 3);
+    }
+
+    #[test]
+    fn ror32_rotates_right_by_masked_amount() {
+        assert_eq!(ror32(0x1, 1), 0x8000_0000);
+        assert_eq!(ror32(0x8000_0001, 0), 0x8000_0001);
+        // A rotate by 32 is masked down to a rotate by 0, i.e. a no-op.
+        assert_eq!(ror32(0x1234_5678, 32), 0x1234_5678);
+    }
+
+    #[test]
+    fn decode_rejects_unknown_encoding() {
+        // Opcode 0x3f, funct 0x3f isn't assigned by `Instruction::decode_from`'s table.
+        let word = (0x3f << 26) | 0x3f;
+        assert_eq!(
+            decode(word),
+            Err(DecodeError::Unknown { opcode: 0x3f, funct: 0x3f })
+        );
+    }
+
+    #[test]
+    fn decode_accepts_known_encoding() {
+        // ADD $rd=1, $rs=2, $rt=3: opcode SPECIAL (0), funct 0b100000.
+        let word = (2 << 21) | (3 << 16) | (1 << 11) | 0b100000;
+        decode(word).expect("ADD is a known encoding");
+    }
+
+    #[test]
+    fn decode_bytes_rejects_truncated_input() {
+        assert_eq!(decode_bytes(&[0, 0, 0]), Err(DecodeError::Truncated(3)));
+    }
+
+    #[test]
+    fn decode_stream_pairs_each_word_with_its_address() {
+        // ADD $rd=1, $rs=2, $rt=3 twice in a row, big-endian.
+        let word = (2u32 << 21) | (3 << 16) | (1 << 11) | 0b100000;
+        let mut code = Vec::new();
+        code.extend_from_slice(&word.to_be_bytes());
+        code.extend_from_slice(&word.to_be_bytes());
+
+        let decoded: Vec<_> = decode_stream(&code, Endian::Big).collect();
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].0, 0);
+        assert_eq!(decoded[1].0, 4);
+        assert!(decoded[0].1.is_ok());
+        assert!(decoded[1].1.is_ok());
+    }
+
+    #[test]
+    fn decode_stream_flags_a_trailing_partial_word() {
+        let word = (2u32 << 21) | (3 << 16) | (1 << 11) | 0b100000;
+        let mut code = word.to_be_bytes().to_vec();
+        code.push(0); // one extra byte, not a full word
+
+        let decoded: Vec<_> = decode_stream(&code, Endian::Big).collect();
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[1], (4, Err(DecodeError::Truncated(1))));
+    }
+
+    #[test]
+    fn decode_stream_honors_little_endian() {
+        let word = (2u32 << 21) | (3 << 16) | (1 << 11) | 0b100000;
+        let code = word.to_le_bytes();
+
+        let (addr, decoded) = decode_stream(&code, Endian::Little).next().unwrap();
+        assert_eq!(addr, 0);
+        decoded.expect("ADD is a known encoding");
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    events::{AluEvent, BranchEvent, JumpEvent, MemInstrEvent, MiscEvent, MemoryRecord},
+    events::{AluEvent, BranchEvent, JumpEvent, MemInstrEvent, MiscEvent, MemoryRecord, TrapCause, TrapEvent},
     utils::{get_msb, get_quotient_and_remainder, is_signed_operation},
     Executor, Opcode, UNUSED_PC, DEFAULT_PC_INC,
 };
@@ -119,6 +119,47 @@ pub fn emit_cloclz_dependencies(executor: &mut Executor, event: AluEvent) {
     }
 }
 
+/// Emits the dependencies for the count-trailing-zeros operation.
+///
+/// `a = ctz(b)` is pinned by two shift events: an `SRL` asserting `(b >> a) & 1 == 1` (the
+/// lowest set bit sits at position `a`), and an `SLL` asserting `b << (32 - a) == 0` (nothing
+/// below position `a` is set). `b == 0` is the degenerate case (result defined as 32), so no
+/// shift events are emitted, mirroring how [`emit_cloclz_dependencies`] skips emission there.
+pub fn emit_ctz_dependencies(executor: &mut Executor, event: AluEvent) {
+    if event.b == 0 {
+        return;
+    }
+
+    let srl_event = AluEvent {
+        pc: UNUSED_PC,
+        next_pc: UNUSED_PC + DEFAULT_PC_INC,
+        opcode: Opcode::SRL,
+        hi: 0,
+        a: (event.b >> event.a) & 1,
+        b: event.b,
+        c: event.a,
+        op_a_0: false,
+    };
+    executor.record.shift_right_events.push(srl_event);
+
+    // When `a == 0` there's nothing below position 0 to rule out, so `32 - a` would be a
+    // (chip-unrepresentable) shift of 32; skip the event in that case, same as the `b == 0`
+    // early-out above.
+    if event.a != 0 {
+        let sll_event = AluEvent {
+            pc: UNUSED_PC,
+            next_pc: UNUSED_PC + DEFAULT_PC_INC,
+            opcode: Opcode::SLL,
+            hi: 0,
+            a: event.b << (32 - event.a),
+            b: event.b,
+            c: 32 - event.a,
+            op_a_0: false,
+        };
+        executor.record.shift_left_events.push(sll_event);
+    }
+}
+
 /// Emit the dependencies for memory instructions.
 pub fn emit_memory_dependencies(
     executor: &mut Executor,
@@ -141,6 +182,10 @@ pub fn emit_memory_dependencies(
     let addr_offset = (memory_addr % 4_u32) as u8;
     let mem_value = memory_record.value;
 
+    if matches!(event.opcode, Opcode::LWL | Opcode::LWR | Opcode::SWL | Opcode::SWR) {
+        emit_unaligned_word_dependencies(executor, event, mem_value, addr_offset);
+    }
+
     if matches!(event.opcode, Opcode::LB | Opcode::LH) {
         let (unsigned_mem_val, most_sig_mem_value_byte, sign_value) = match event.opcode {
             Opcode::LB => {
@@ -181,6 +226,116 @@ pub fn emit_memory_dependencies(
     }
 }
 
+/// Emit the dependencies for the unaligned word instructions `LWL`/`LWR`/`SWL`/`SWR`.
+///
+/// Each of these merges only part of a 4-byte-aligned word with a value that already exists
+/// (the destination register for a load, the aligned memory word for a store), so unlike the
+/// other memory instructions above we can't just prove the raw value -- we have to prove the
+/// merge itself. That takes three sub-events, mirroring what the instruction actually does:
+///   1. a `SLL`/`SRL` event that repositions the bytes being merged in (`mem_value` for a load,
+///      `event.a` for a store) to line up with the bytes they're replacing,
+///   2. an `AND` event that masks the untouched side (`event.prev_a` for a load, `mem_value` for
+///      a store) down to the bytes that must be preserved, and
+///   3. an `OR` event that combines the two into the final merged word.
+///
+/// The shift amount is the one `execute_load`/`execute_store` use: `(3 - addr_offset) * 8` for
+/// `LWL`/`SWL` and `addr_offset * 8` for `LWR`/`SWR`. At the degenerate offsets
+/// (`addr_offset == 3` for `LWL`/`SWL`, `addr_offset == 0` for `LWR`/`SWR`) the shift amount is
+/// zero, the preserved side is masked to
+/// nothing, and the merge is a full-word overwrite -- the three events are still emitted so the
+/// lookups balance.
+pub fn emit_unaligned_word_dependencies(
+    executor: &mut Executor,
+    event: MemInstrEvent,
+    mem_value: u32,
+    addr_offset: u8,
+) {
+    // `shift_left` says which direction repositions the merged-in bytes; `merge_src`/
+    // `preserved_src` say which values play the "incoming" and "kept" roles. `LWL` pulls the low
+    // `(addr_offset + 1)` bytes of `mem_value` up to the top of the word (shifting left) and
+    // keeps the register's low bytes; `SWL` is the exact inverse (shifting the register's high
+    // bytes back down, keeping the memory word's high bytes). `LWR`/`SWR` mirror this toward the
+    // low end of the word.
+    let (shift_left, merge_src, preserved_src) = match event.opcode {
+        Opcode::LWL => (true, mem_value, event.prev_a),
+        Opcode::LWR => (false, mem_value, event.prev_a),
+        Opcode::SWL => (false, event.a, mem_value),
+        Opcode::SWR => (true, event.a, mem_value),
+        _ => unreachable!("emit_unaligned_word_dependencies called with opcode {:?}", event.opcode),
+    };
+
+    let is_left_instr = matches!(event.opcode, Opcode::LWL | Opcode::SWL);
+    let shift = if is_left_instr { (3 - addr_offset) * 8 } else { addr_offset * 8 };
+
+    let shift_event = if shift_left {
+        AluEvent {
+            pc: UNUSED_PC,
+            next_pc: UNUSED_PC + DEFAULT_PC_INC,
+            opcode: Opcode::SLL,
+            hi: 0,
+            a: merge_src << shift,
+            b: merge_src,
+            c: u32::from(shift),
+            op_a_0: false,
+        }
+    } else {
+        AluEvent {
+            pc: UNUSED_PC,
+            next_pc: UNUSED_PC + DEFAULT_PC_INC,
+            opcode: Opcode::SRL,
+            hi: 0,
+            a: merge_src >> shift,
+            b: merge_src,
+            c: u32::from(shift),
+            op_a_0: false,
+        }
+    };
+    let shifted = shift_event.a;
+    if shift_left {
+        executor.record.shift_left_events.push(shift_event);
+    } else {
+        executor.record.shift_right_events.push(shift_event);
+    }
+
+    // The mask keeps exactly the bytes `shifted` didn't just fill in. At the degenerate offsets
+    // (`shift == 0`) `shifted` already occupies the whole word, so the mask collapses to zero --
+    // no bytes are preserved, matching a full-word overwrite.
+    let preserve_mask = if shift_left {
+        (1_u32 << shift) - 1
+    } else if shift == 0 {
+        0
+    } else {
+        !((1_u32 << (32 - shift)) - 1)
+    };
+
+    let and_event = AluEvent {
+        pc: UNUSED_PC,
+        next_pc: UNUSED_PC + DEFAULT_PC_INC,
+        opcode: Opcode::AND,
+        hi: 0,
+        a: preserved_src & preserve_mask,
+        b: preserved_src,
+        c: preserve_mask,
+        op_a_0: false,
+    };
+    let preserved = and_event.a;
+    executor.record.bitwise_events.push(and_event);
+
+    // `shifted` and `preserved` never overlap by construction, so the `OR` merge is exact.
+    let merged = shifted | preserved;
+    let or_event = AluEvent {
+        pc: UNUSED_PC,
+        next_pc: UNUSED_PC + DEFAULT_PC_INC,
+        opcode: Opcode::OR,
+        hi: 0,
+        a: merged,
+        b: shifted,
+        c: preserved,
+        op_a_0: false,
+    };
+    executor.record.bitwise_events.push(or_event);
+}
+
 /// Emit the dependencies for branch instructions.
 pub fn emit_branch_dependencies(executor: &mut Executor, event: BranchEvent) {
     let a_eq_b = event.a == event.b;
@@ -210,12 +365,12 @@ pub fn emit_branch_dependencies(executor: &mut Executor, event: BranchEvent) {
     executor.record.lt_events.push(lt_comp_event);
     executor.record.lt_events.push(gt_comp_event);
     let branching = match event.opcode {
-        Opcode::BEQ => a_eq_b,
-        Opcode::BNE => !a_eq_b,
-        Opcode::BLTZ => a_lt_b,
-        Opcode::BLEZ => a_lt_b || a_eq_b,
-        Opcode::BGTZ => a_gt_b,
-        Opcode::BGEZ => a_eq_b || a_gt_b,
+        Opcode::BEQ | Opcode::BEQL => a_eq_b,
+        Opcode::BNE | Opcode::BNEL => !a_eq_b,
+        Opcode::BLTZ | Opcode::BLTZL => a_lt_b,
+        Opcode::BLEZ | Opcode::BLEZL => a_lt_b || a_eq_b,
+        Opcode::BGTZ | Opcode::BGTZL => a_gt_b,
+        Opcode::BGEZ | Opcode::BGEZL => a_eq_b || a_gt_b,
         _ => unreachable!(),
     };
     if branching {
@@ -257,6 +412,217 @@ pub fn emit_jump_dependencies(executor: &mut Executor, event: JumpEvent) {
 }
 
 /// Emit the dependencies for misc instructions.
-pub fn emit_misc_dependencies(_executor: &mut Executor, _event: MiscEvent) {
-    // TODO
+///
+/// Covers the MIPS32r2 bitfield/sign-extend encodings (`EXT`, `INS`, `SEXT` -- which stands in
+/// for both `seb`/`seh`, distinguished by `event.size` -- and `WSBH`), each decomposed into the
+/// same shift/bitwise ALU event streams the rest of this file already feeds. `event.pos`/
+/// `event.size` (the extracted/deposited bitfield's start and width) and `event.prev_a` (the
+/// destination register's value before this instruction, needed by `INS` to prove which bits
+/// are preserved) are carried as extra fields on [`MiscEvent`].
+pub fn emit_misc_dependencies(executor: &mut Executor, event: MiscEvent) {
+    match event.opcode {
+        Opcode::EXT => {
+            let srl_event = AluEvent {
+                pc: UNUSED_PC,
+                next_pc: UNUSED_PC + DEFAULT_PC_INC,
+                opcode: Opcode::SRL,
+                hi: 0,
+                a: event.b >> event.pos,
+                b: event.b,
+                c: u32::from(event.pos),
+                op_a_0: false,
+            };
+            let shifted = srl_event.a;
+            executor.record.shift_right_events.push(srl_event);
+
+            let field_mask = (1_u32 << event.size) - 1;
+            let and_event = AluEvent {
+                pc: UNUSED_PC,
+                next_pc: UNUSED_PC + DEFAULT_PC_INC,
+                opcode: Opcode::AND,
+                hi: 0,
+                a: shifted & field_mask,
+                b: shifted,
+                c: field_mask,
+                op_a_0: false,
+            };
+            executor.record.bitwise_events.push(and_event);
+        }
+        Opcode::INS => {
+            let field_mask = ((1_u32 << event.size) - 1) << event.pos;
+            let and_event = AluEvent {
+                pc: UNUSED_PC,
+                next_pc: UNUSED_PC + DEFAULT_PC_INC,
+                opcode: Opcode::AND,
+                hi: 0,
+                a: event.prev_a & !field_mask,
+                b: event.prev_a,
+                c: !field_mask,
+                op_a_0: false,
+            };
+            let cleared = and_event.a;
+            executor.record.bitwise_events.push(and_event);
+
+            // The source field is assumed to already be masked down to `size` bits (as the
+            // decode stage guarantees for `INS`'s source operand), so positioning it is a plain
+            // shift; `cleared` and the shifted field never overlap, making the final merge exact.
+            let sll_event = AluEvent {
+                pc: UNUSED_PC,
+                next_pc: UNUSED_PC + DEFAULT_PC_INC,
+                opcode: Opcode::SLL,
+                hi: 0,
+                a: event.b << event.pos,
+                b: event.b,
+                c: u32::from(event.pos),
+                op_a_0: false,
+            };
+            let positioned = sll_event.a;
+            executor.record.shift_left_events.push(sll_event);
+
+            let or_event = AluEvent {
+                pc: UNUSED_PC,
+                next_pc: UNUSED_PC + DEFAULT_PC_INC,
+                opcode: Opcode::OR,
+                hi: 0,
+                a: cleared | positioned,
+                b: cleared,
+                c: positioned,
+                op_a_0: false,
+            };
+            executor.record.bitwise_events.push(or_event);
+        }
+        Opcode::SEXT => {
+            // `event.size` is 8 for `seb`, 16 for `seh` -- see `Operation::Signext` in
+            // `operation.rs`, which the decoder maps both onto this opcode.
+            let sign_value = 1_u32 << event.size;
+            let unsigned_val = event.b & (sign_value - 1);
+            if unsigned_val >> (event.size - 1) & 1 == 1 {
+                let sub_event = AluEvent {
+                    pc: UNUSED_PC,
+                    next_pc: UNUSED_PC + DEFAULT_PC_INC,
+                    opcode: Opcode::SUB,
+                    hi: 0,
+                    a: event.a,
+                    b: unsigned_val,
+                    c: sign_value,
+                    op_a_0: false,
+                };
+                executor.record.add_events.push(sub_event);
+            }
+        }
+        Opcode::WSBH => {
+            // Swap bytes within each halfword: two independent lanes (low halfword, high
+            // halfword), each built from a shift-left, a shift-right, and the ANDs that isolate
+            // the two bytes being swapped, then ORed together.
+            let low_hi_byte = AluEvent {
+                pc: UNUSED_PC,
+                next_pc: UNUSED_PC + DEFAULT_PC_INC,
+                opcode: Opcode::SLL,
+                hi: 0,
+                a: (event.b & 0x0000_00FF) << 8,
+                b: event.b & 0x0000_00FF,
+                c: 8,
+                op_a_0: false,
+            };
+            let low_lo_byte = AluEvent {
+                pc: UNUSED_PC,
+                next_pc: UNUSED_PC + DEFAULT_PC_INC,
+                opcode: Opcode::SRL,
+                hi: 0,
+                a: (event.b & 0x0000_FF00) >> 8,
+                b: event.b & 0x0000_FF00,
+                c: 8,
+                op_a_0: false,
+            };
+            let high_hi_byte = AluEvent {
+                pc: UNUSED_PC,
+                next_pc: UNUSED_PC + DEFAULT_PC_INC,
+                opcode: Opcode::SLL,
+                hi: 0,
+                a: (event.b & 0x00FF_0000) << 8,
+                b: event.b & 0x00FF_0000,
+                c: 8,
+                op_a_0: false,
+            };
+            let high_lo_byte = AluEvent {
+                pc: UNUSED_PC,
+                next_pc: UNUSED_PC + DEFAULT_PC_INC,
+                opcode: Opcode::SRL,
+                hi: 0,
+                a: (event.b & 0xFF00_0000) >> 8,
+                b: event.b & 0xFF00_0000,
+                c: 8,
+                op_a_0: false,
+            };
+            let low_lane = low_hi_byte.a | low_lo_byte.a;
+            let high_lane = high_hi_byte.a | high_lo_byte.a;
+            executor.record.shift_left_events.push(low_hi_byte);
+            executor.record.shift_right_events.push(low_lo_byte);
+            executor.record.shift_left_events.push(high_hi_byte);
+            executor.record.shift_right_events.push(high_lo_byte);
+
+            let or_event = AluEvent {
+                pc: UNUSED_PC,
+                next_pc: UNUSED_PC + DEFAULT_PC_INC,
+                opcode: Opcode::OR,
+                hi: 0,
+                a: low_lane | high_lane,
+                b: low_lane,
+                c: high_lane,
+                op_a_0: false,
+            };
+            executor.record.bitwise_events.push(or_event);
+        }
+        _ => {}
+    }
+}
+
+/// Emits the dependencies for trapping `TADD`/`TSUB`, and raises a [`TrapEvent`] on overflow.
+///
+/// Signed overflow is detected purely from the sign bits of the operands and the (already
+/// wrapped) result: for `TADD`, `sign(b) == sign(c) && sign(a) != sign(b)`; for `TSUB`,
+/// `sign(b) != sign(c) && sign(a) != sign(b)`. Each sign bit is extracted via an `SLT`-style
+/// comparison against zero (`sign(x) == 1` iff `(x as i32) < 0`), so the AIR can assert the
+/// predicate from two lookups instead of inspecting raw bits directly.
+pub fn emit_trap_arith_dependencies(executor: &mut Executor, event: AluEvent) {
+    let sign_a = (event.a as i32) < 0;
+    let sign_b = (event.b as i32) < 0;
+    let sign_c = (event.c as i32) < 0;
+
+    let sign_a_event = AluEvent {
+        pc: UNUSED_PC,
+        next_pc: UNUSED_PC + DEFAULT_PC_INC,
+        opcode: Opcode::SLT,
+        hi: 0,
+        a: sign_a as u32,
+        b: event.a,
+        c: 0,
+        op_a_0: false,
+    };
+    let sign_b_event = AluEvent {
+        pc: UNUSED_PC,
+        next_pc: UNUSED_PC + DEFAULT_PC_INC,
+        opcode: Opcode::SLT,
+        hi: 0,
+        a: sign_b as u32,
+        b: event.b,
+        c: 0,
+        op_a_0: false,
+    };
+    executor.record.lt_events.push(sign_a_event);
+    executor.record.lt_events.push(sign_b_event);
+
+    let operands_agree = if event.opcode == Opcode::TADD { sign_b == sign_c } else { sign_b != sign_c };
+    let overflow = operands_agree && sign_a != sign_b;
+
+    if overflow {
+        let cause = TrapCause::IntegerOverflow;
+        executor.record.trap_events.push(TrapEvent {
+            shard: executor.shard(),
+            clk: executor.state.clk,
+            pc: executor.state.pc,
+            bad_vaddr: 0,
+            cause,
+        });
+    }
 }
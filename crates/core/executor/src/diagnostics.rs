@@ -0,0 +1,194 @@
+//! Source-annotated diagnostics for runtime faults: misaligned/out-of-range memory accesses and
+//! undecodable instructions.
+//!
+//! [`crate::executor::ExecutionError`] already distinguishes these from each other and carries
+//! the opcode/address/word involved, which is enough to propagate and match on. This module adds
+//! a richer, human-facing report on top of that for the cases an embedder surfaces directly to a
+//! user: [`Fault`] captures the faulting PC, the decoded instruction, the registers it read, and
+//! (when a [`SymbolResolver`] is installed) the guest source location, then [`Fault::render`]
+//! lays all of that out as an annotated multi-line snippet.
+
+use crate::{disasm, executor::ExecutionError, Instruction, Opcode};
+
+/// One labeled region of a [`Fault`]'s rendered disassembly line, underlined in the snippet.
+#[derive(Debug, Clone)]
+pub struct Span {
+    /// Text shown under the underline, e.g. `"offending address: 0x00001003"`.
+    pub label: String,
+    /// Byte offset of the span's start within the rendered disassembly line.
+    pub start: usize,
+    /// Byte offset one past the span's end.
+    pub end: usize,
+}
+
+impl Span {
+    /// Builds a span covering `[start, end)` of the disassembly line.
+    #[must_use]
+    pub fn new(label: impl Into<String>, start: usize, end: usize) -> Self {
+        Self { label: label.into(), start, end }
+    }
+
+    /// Builds a span covering the last comma-separated operand of a disassembly `line`, e.g. the
+    /// immediate offset in `"sw $a0, $sp, 4"`. Imprecise -- the disassembler doesn't track operand
+    /// columns -- but close enough to anchor the label next to the instruction's address operand
+    /// without threading column info through the whole decode/disasm pipeline for this alone.
+    #[must_use]
+    pub fn trailing_operand(line: &str, label: impl Into<String>) -> Self {
+        let after_comma = line.rfind(',').map_or(0, |i| i + 1);
+        let start = line.len() - line[after_comma..].trim_start().len();
+        Self::new(label, start, line.len())
+    }
+}
+
+/// A guest source location for a faulting PC, with a window of surrounding lines for context.
+#[derive(Debug, Clone)]
+pub struct SourceLocation {
+    /// The guest source file, as recorded in the debug info (e.g. `"main.rs"`).
+    pub file: String,
+    /// The 1-indexed line the fault maps to.
+    pub line: u32,
+    /// `(line number, text)` pairs for the lines surrounding [`Self::line`], already windowed by
+    /// the resolver -- this module doesn't know how wide a window is useful.
+    pub context: Vec<(u32, String)>,
+}
+
+/// Maps a faulting PC back to a [`SourceLocation`].
+///
+/// This crate has no DWARF parser vendored, so there's no built-in implementation: an embedder
+/// that has already loaded the guest ELF's debug info (e.g. with `gimli`/`addr2line`) plugs one in
+/// here and installs it via [`crate::context::ZKMContext`], the same pluggable-trait shape as
+/// [`crate::subproof::SubproofVerifier`].
+pub trait SymbolResolver: Send + Sync {
+    /// Resolves `pc` to a guest source location, or `None` if no debug info covers it.
+    fn resolve(&self, pc: u32) -> Option<SourceLocation>;
+}
+
+/// The kind of runtime fault a [`Fault`] reports on: a diagnostics-oriented mirror of the subset
+/// of [`ExecutionError`] variants that represent an addressing or decoding mistake, as opposed to
+/// ordinary control flow like [`ExecutionError::Breakpoint`] or [`ExecutionError::Paused`].
+#[derive(Debug, Clone, Copy)]
+pub enum FaultKind {
+    /// A memory access landed outside the region the executor considers valid.
+    InvalidMemoryAccess {
+        /// The opcode performing the access.
+        opcode: Opcode,
+        /// The faulting virtual address.
+        addr: u32,
+    },
+    /// A naturally-aligned load/store's address wasn't aligned to its access size. See
+    /// [`crate::context::ZKMContext::strict_memory_alignment`].
+    MemoryAlignment {
+        /// The opcode performing the access.
+        opcode: Opcode,
+        /// The misaligned virtual address.
+        addr: u32,
+    },
+    /// [`Instruction::decode_from`] couldn't match `word`'s opcode/funct encoding.
+    UnsupportedInstruction {
+        /// The undecodable instruction word.
+        word: u32,
+    },
+}
+
+impl FaultKind {
+    /// Builds a [`FaultKind`] from an [`ExecutionError`], or `None` if `error` isn't one of this
+    /// module's fault variants (e.g. a breakpoint or a paused tick callback, which aren't
+    /// addressing/decoding mistakes and have no useful snippet to render).
+    #[must_use]
+    pub fn from_error(error: &ExecutionError) -> Option<Self> {
+        match *error {
+            ExecutionError::InvalidMemoryAccess(opcode, addr) => {
+                Some(Self::InvalidMemoryAccess { opcode, addr })
+            }
+            ExecutionError::MemoryAlignment(opcode, addr) => {
+                Some(Self::MemoryAlignment { opcode, addr })
+            }
+            ExecutionError::UnsupportedInstruction(word) => {
+                Some(Self::UnsupportedInstruction { word })
+            }
+            _ => None,
+        }
+    }
+
+    fn summary(self) -> String {
+        match self {
+            Self::InvalidMemoryAccess { opcode, addr } => {
+                format!("invalid memory access for opcode {opcode} at address {addr:#010x}")
+            }
+            Self::MemoryAlignment { opcode, addr } => {
+                let width = match opcode {
+                    Opcode::LW | Opcode::LL | Opcode::SW | Opcode::SC => 4,
+                    _ => 2,
+                };
+                format!(
+                    "address {addr:#010x} is misaligned for {opcode} (needs {width}-byte alignment)"
+                )
+            }
+            Self::UnsupportedInstruction { word } => {
+                format!("instruction word {word:#010x} doesn't decode to a known opcode")
+            }
+        }
+    }
+}
+
+/// A runtime fault, captured at the moment it happened: the faulting PC, its [`FaultKind`], the
+/// decoded instruction, the registers relevant to it, and -- when a [`SymbolResolver`] is
+/// installed -- the guest source location. Built by [`crate::Executor`]'s memory-access and decode
+/// paths instead of unwrapping, and turned into an annotated snippet by [`Self::render`].
+#[derive(Debug, Clone)]
+pub struct Fault {
+    /// The program counter of the faulting instruction.
+    pub pc: u32,
+    /// What went wrong.
+    pub kind: FaultKind,
+    /// The faulting instruction, decoded.
+    pub instruction: Instruction,
+    /// `(ABI register name, value)` pairs relevant to the fault, e.g. the base register a
+    /// misaligned load computed its address from.
+    pub registers: Vec<(&'static str, u32)>,
+    /// Regions of the rendered disassembly line to underline.
+    pub spans: Vec<Span>,
+    /// The guest source location, if a [`SymbolResolver`] resolved one.
+    pub source: Option<SourceLocation>,
+}
+
+impl Fault {
+    /// Renders the fault as a multi-line, rustc-diagnostic-style snippet: the faulting PC and
+    /// disassembly with the offending span underlined, the relevant registers, and -- if
+    /// available -- the guest source line with its own surrounding context.
+    #[must_use]
+    pub fn render(&self, color: bool) -> String {
+        let disasm_line = disasm::disassemble_instruction(&self.instruction, self.pc);
+        let mut out = format!("fault: {}\n", self.kind.summary());
+        out.push_str(&format!("  --> pc {:#010x}\n", self.pc));
+        out.push_str(&format!("   | {disasm_line}\n"));
+        for span in &self.spans {
+            let indent = " ".repeat(3 + span.start);
+            let underline = "^".repeat(span.end.saturating_sub(span.start).max(1));
+            let (underline, label) = if color {
+                (format!("\x1b[31m{underline}\x1b[0m"), format!("\x1b[31m{}\x1b[0m", span.label))
+            } else {
+                (underline, span.label.clone())
+            };
+            out.push_str(&format!("   |{indent}{underline} {label}\n"));
+        }
+        if !self.registers.is_empty() {
+            let regs = self
+                .registers
+                .iter()
+                .map(|(name, value)| format!("{name}={value:#010x}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            out.push_str(&format!("   | registers: {regs}\n"));
+        }
+        if let Some(source) = &self.source {
+            out.push_str(&format!("note: guest source {}:{}\n", source.file, source.line));
+            let width = source.context.iter().map(|(n, _)| n.to_string().len()).max().unwrap_or(1);
+            for (number, text) in &source.context {
+                let marker = if *number == source.line { ">" } else { " " };
+                out.push_str(&format!(" {marker} {number:width$} | {text}\n"));
+            }
+        }
+        out
+    }
+}
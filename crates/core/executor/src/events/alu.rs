@@ -27,12 +27,19 @@ pub struct AluEvent {
     pub c: u32,
     /// The result of the operation in the format of [``LookupId``; 5]
     pub sub_lookups: [LookupId; 5],
+    /// This event's position within its destination event vector (e.g. `add_events`,
+    /// `lt_events`) at the shard it was recorded in. Threaded into the `send_alu`/`receive_alu`
+    /// bus interactions alongside `shard` so a row can't satisfy another row's lookup just by
+    /// sharing its operands -- the same row-disambiguation idea `MulCols::nonce` applies on the
+    /// one ALU-family chip that has its own trace in this tree, applied here at the event level
+    /// since none of the others do yet.
+    pub nonce: u32,
 }
 
 impl AluEvent {
     /// Create a new [`AluEvent`].
     #[must_use]
-    pub fn new(shard: u32, clk: u32, opcode: Opcode, a: u32, b: u32, c: u32) -> Self {
+    pub fn new(shard: u32, clk: u32, opcode: Opcode, a: u32, b: u32, c: u32, nonce: u32) -> Self {
         Self {
             lookup_id: LookupId::default(),
             shard,
@@ -43,6 +50,7 @@ impl AluEvent {
             c,
             hi: 0,
             sub_lookups: create_random_lookup_ids(),
+            nonce,
         }
     }
 
@@ -50,7 +58,16 @@ impl AluEvent {
     /// Used for opcode with LO and HI registers
     /// DIV DIVU MULT MULLTU
     #[must_use]
-    pub fn new_with_hi(shard: u32, clk: u32, opcode: Opcode, a: u32, b: u32, c: u32, hi: u32) -> Self {
+    pub fn new_with_hi(
+        shard: u32,
+        clk: u32,
+        opcode: Opcode,
+        a: u32,
+        b: u32,
+        c: u32,
+        hi: u32,
+        nonce: u32,
+    ) -> Self {
         Self {
             lookup_id: LookupId::default(),
             shard,
@@ -61,6 +78,71 @@ impl AluEvent {
             c,
             hi,
             sub_lookups: create_random_lookup_ids(),
+            nonce,
         }
     }
 }
+
+/// A COP1 floating-point ALU event.
+///
+/// Operands and the result are recorded as raw bit patterns (the low 32 bits of the FP register
+/// for single precision, the full 64 bits for double precision) rather than as `f32`/`f64`, so the
+/// event keeps the exact bits, including NaN payloads and the sign of zero.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct FpAluEvent {
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The opcode.
+    pub opcode: Opcode,
+    /// The result, as a raw bit pattern.
+    pub a: u64,
+    /// The first input operand, as a raw bit pattern.
+    pub b: u64,
+    /// The second input operand, as a raw bit pattern (unused by unary ops like conversions).
+    pub c: u64,
+    /// This event's position within `fp_alu_events` at the shard it was recorded in -- the same
+    /// row-disambiguation nonce [`AluEvent::nonce`] adds to the integer ALU events.
+    pub nonce: u32,
+}
+
+impl FpAluEvent {
+    /// Create a new [`FpAluEvent`].
+    #[must_use]
+    pub fn new(shard: u32, clk: u32, opcode: Opcode, a: u64, b: u64, c: u64, nonce: u32) -> Self {
+        Self { shard, clk, opcode, a, b, c, nonce }
+    }
+}
+
+/// Why a [`TrapEvent`] was raised.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrapCause {
+    /// Signed overflow from a trapping `TADD`/`TSUB`/`TADDI`.
+    IntegerOverflow,
+    /// A load from an address that isn't aligned to the access width (`LW`/`LL`/`SW`/`SC` need
+    /// 4-byte alignment, `LH`/`LHU`/`SH` need 2-byte alignment).
+    LoadAddressError,
+    /// A store to an address that isn't aligned to the access width.
+    StoreAddressError,
+}
+
+/// A trap raised by a faulting instruction.
+///
+/// Recorded so the proving layer can assert that an exception fired -- rather than the
+/// wrapping `ADD`/`SUB` family silently discarding the overflow -- for guests that rely on
+/// MIPS's trapping arithmetic instructions.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TrapEvent {
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The program counter of the faulting instruction.
+    pub pc: u32,
+    /// The faulting virtual address, for [`TrapCause::LoadAddressError`] and
+    /// [`TrapCause::StoreAddressError`]. `0` for causes that aren't address errors.
+    pub bad_vaddr: u32,
+    /// Why the trap was raised.
+    pub cause: TrapCause,
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// The width of a Poseidon2 digest, in `u32` words.
+pub const DEFERRED_PROOF_DIGEST_SIZE: usize = 8;
+
+/// `SYSVERIFY` event.
+///
+/// Emitted once per `syscall_verify` call, recording one deferred proof claim -- the guest is
+/// asserting that some other zkMIPS proof, whose verifying key hashes to `vkey_digest`, committed
+/// to `committed_value_digest` as its public values. The recursion stage later checks
+/// `vkey_digest` against the vkey-allowlist Merkle tree and folds the pair into the running
+/// deferred-digest accumulator (see [`crate::ZKMDeferredVerifier`](../../../recursion) in the
+/// recursion circuit crate), the same way [`super::keccak_sponge::KeccakSpongeEvent`] lets a
+/// dedicated chip constrain the keccak permutation.
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DeferredProofClaimEvent {
+    pub shard: u32,
+    pub clk: u32,
+    pub vkey_digest: [u32; DEFERRED_PROOF_DIGEST_SIZE],
+    pub committed_value_digest: [u32; DEFERRED_PROOF_DIGEST_SIZE],
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// `FP_OP` event.
+///
+/// Emitted once per `syscall_fp_op` call so a dedicated chip can constrain the softfloat
+/// arithmetic (add/sub/mul/div/sqrt, or an int<->float conversion) the same way
+/// [`super::keccak_sponge::KeccakSpongeEvent`] constrains the keccak permutation.
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct FpOpEvent {
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The `FP_OP_*` selector, OR'd with `FP_OP_DOUBLE` for double precision.
+    pub op: u32,
+    /// The `FP_ROUND_*` rounding mode used.
+    pub round_mode: u32,
+    /// The first operand's raw bit pattern (zero-extended from `u32` for single precision).
+    pub a: u64,
+    /// The second operand's raw bit pattern (unused by `FP_OP_SQRT` and the conversions).
+    pub b: u64,
+    /// The result's raw bit pattern.
+    pub result: u64,
+    /// The sticky `FP_FLAG_*` word returned alongside the result.
+    pub flags: u32,
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// Number of 64-bit lanes in a keccak-f[1600] state, duplicated from
+/// [`crate::syscalls::keccak::KECCAK_PERMUTE_STATE_WORDS`] -- events live below syscalls in this
+/// crate's dependency order, the same split [`super::mtree::MTREE_DIGEST_WORDS`] keeps with
+/// `crate::syscalls::mtree`.
+pub const KECCAK_PERMUTE_STATE_WORDS: usize = 25;
+
+/// `KECCAK_PERMUTE` event.
+///
+/// Emitted once per `syscall_keccak_permute` call so a dedicated chip can re-derive the same
+/// keccak-f[1600] round function, the same way [`super::keccak_sponge::KeccakSpongeEvent`]
+/// constrains the permutation folded into each absorb/squeeze block -- this is the bare,
+/// single-call version with no rate/input-length bookkeeping around it.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct KeccakPermuteEvent {
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The address the 25-lane (50-word) state was read from and written back to.
+    pub state_addr: u32,
+    /// The state before the permutation.
+    pub pre_state: [u64; KECCAK_PERMUTE_STATE_WORDS],
+    /// The state after the permutation.
+    pub post_state: [u64; KECCAK_PERMUTE_STATE_WORDS],
+}
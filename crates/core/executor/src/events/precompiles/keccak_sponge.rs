@@ -23,11 +23,20 @@ pub struct KeccakSpongeEvent {
     pub output: [u32; KECCAK_GENERAL_OUTPUT_U32S],
     /// The length of the input (in u32s).
     pub input_len_u32s: u32,
+    /// The requested output length (in u32s), for SHAKE-style squeeze calls. `0` (the default)
+    /// means "use [`KECCAK_GENERAL_OUTPUT_U32S`] and `output` above", matching the original
+    /// fixed-digest behavior; a nonzero value instead squeezes that many words into
+    /// `squeeze_output`, re-running `keccakf_u32s` once per [`KECCAK_GENERAL_RATE_U32S`]-word
+    /// block beyond the first as a SHAKE128/256 or cSHAKE caller would expect.
+    pub output_len_u32s: u32,
+    /// The full squeeze output, `output_len_u32s` words long, when `output_len_u32s != 0`.
+    pub squeeze_output: Vec<u32>,
     /// The memory records for the input
     pub input_read_records: Vec<MemoryReadRecord>,
     /// The memory records for the input length
     pub input_length_record: MemoryReadRecord,
-    /// The memory records for the output
+    /// The memory records for the output: one entry per word of `output` (fixed-digest calls) or
+    /// `squeeze_output` (squeeze calls).
     pub output_write_records: Vec<MemoryWriteRecord>,
     /// The state of the sponge.
     pub xored_state_list: Vec<[u64; 25]>,
@@ -37,6 +46,12 @@ pub struct KeccakSpongeEvent {
     pub output_addr: u32,
     /// The local memory access records.
     pub local_mem_access: Vec<MemoryLocalEvent>,
+    /// This call's position within its batch, for the multi-preimage `KECCAK_SPONGE` batching
+    /// path (e.g. hashing every leaf of a Merkle tree with one syscall): `0` for a lone call or
+    /// the first instance of a batch. Purely bookkeeping -- each instance still carries its own
+    /// full absorb/squeeze state above, so the STARK side only needs this to tag which instance a
+    /// row belongs to (stamped onto every row's `instance_id` column by `event_to_rows`).
+    pub instance_id: u32,
 }
 
 impl KeccakSpongeEvent {
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{MemoryReadRecord, MemoryWriteRecord};
+
+/// `MEMCPY_32`/`MEMCPY_64` event.
+///
+/// Emitted once per `syscall_memcpy_32`/`syscall_memcpy_64` call so a dedicated chip can re-derive
+/// the same word-for-word copy, the same role [`super::keccak_permute::KeccakPermuteEvent`] plays
+/// for its own precompile. Unlike that event, which only keeps the before/after state values,
+/// this one captures every source read and destination write as a full [`MemoryReadRecord`]/
+/// [`MemoryWriteRecord`] -- the level of detail `crate::events::MiscEvent`'s `a_record`/
+/// `hi_record` fields carry so `MiscInstrsChip::populate_movcond`/`populate_maddsub` can call
+/// `op_a_access.populate(...)` directly from them, without re-deriving the access from a bare
+/// value. One event covers either a `MEMCPY_32` or `MEMCPY_64` call; `src_records.len()`
+/// (equivalently `dst_records.len()`) tells the two apart, the same way
+/// `crate::events::Bn254ScalarOpEvent`'s `is_mac`/`is_add`/`is_sub`/`is_mul` flags let one event
+/// shape cover several call kinds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemCopyEvent {
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle of the first word moved; the `i`th source read and destination write both
+    /// happen at `clk + i`.
+    pub clk: u32,
+    /// The source region's base address.
+    pub src_ptr: u32,
+    /// The destination region's base address.
+    pub dst_ptr: u32,
+    /// One read record per source word, in order.
+    pub src_records: Vec<MemoryReadRecord>,
+    /// One write record per destination word, in the same order as `src_records`.
+    pub dst_records: Vec<MemoryWriteRecord>,
+}
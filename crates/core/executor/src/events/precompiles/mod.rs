@@ -0,0 +1,13 @@
+mod deferred_proof;
+mod fp_op;
+mod keccak_permute;
+mod keccak_sponge;
+mod mem_copy;
+mod mtree;
+
+pub use deferred_proof::*;
+pub use fp_op::*;
+pub use keccak_permute::*;
+pub use keccak_sponge::*;
+pub use mem_copy::*;
+pub use mtree::*;
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+/// The number of `u32` words in one Merkle digest. Matches half of the width-8 Poseidon2
+/// permutation's state (`left || right -> permute -> low half`), the same compression shape
+/// `crate::syscalls::poseidon2::poseidon2_permute::<8>` is sized for.
+pub const MTREE_DIGEST_WORDS: usize = 4;
+
+/// The maximum tree depth (number of sibling levels) one `MTREE_VERIFY_PATH` call supports. A
+/// real deployment would want something like 32 -- kept small here since this chip embeds every
+/// level's full Poseidon2 permutation into a single row (see
+/// `zkm_core_machine::syscall::precompiles::mtree::columns`), and row width scales linearly with
+/// it. Raising it is mechanical; proving deeper trees via a cross-chip lookup into the existing
+/// Poseidon2 chip instead of embedding the rounds per level is the better long-term fix, the same
+/// way ALU identities are referenced via `send_alu` rather than inlined.
+pub const MTREE_MAX_DEPTH: usize = 4;
+
+/// One level of an `MTREE_VERIFY_PATH` call: the sibling digest supplied as non-deterministic
+/// advice (from the hint stream, not plain memory) and which side of the compression it sits on.
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MtreePathLevel {
+    /// The sibling digest at this level, read from the hint stream.
+    pub sibling: [u32; MTREE_DIGEST_WORDS],
+    /// `true` if the running digest is the *right* input to this level's compression (i.e. the
+    /// path's index bit at this level is `1`), `false` if it's the left input.
+    pub sibling_on_left: bool,
+    /// The running digest going into this level's compression (before it).
+    pub input: [u32; MTREE_DIGEST_WORDS],
+    /// The digest produced by this level's compression.
+    pub output: [u32; MTREE_DIGEST_WORDS],
+}
+
+/// `MTREE_VERIFY_PATH` event.
+///
+/// Emitted once per `syscall_mtree_verify_path` call so a dedicated chip can re-derive the same
+/// level-by-level compression chain and compare the result against `claimed_root`, rather than
+/// trusting the host's `verified` bit outright.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MtreeVerifyPathEvent {
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The leaf digest, read from `leaf_ptr`.
+    pub leaf: [u32; MTREE_DIGEST_WORDS],
+    /// The leaf's index in the tree (bit `i` selects left/right at level `i`).
+    pub index: u32,
+    /// The number of levels actually walked, `<= `[`MTREE_MAX_DEPTH`]`. Any index bit at or above
+    /// this depth must be zero -- an index that doesn't fit in `depth` bits, or a `depth` over
+    /// [`MTREE_MAX_DEPTH`], is treated as simply unverifiable rather than faulting the guest.
+    pub depth: u32,
+    /// This call's levels, leaf-to-root order.
+    pub levels: Vec<MtreePathLevel>,
+    /// The root claimed by the caller, read from `root_ptr`.
+    pub claimed_root: [u32; MTREE_DIGEST_WORDS],
+    /// The root this event's own recomputed chain actually produced.
+    pub computed_root: [u32; MTREE_DIGEST_WORDS],
+    /// Whether `computed_root == claimed_root`; this is what `execute` returns to the guest.
+    pub verified: bool,
+}
+
+/// `MTREE_MERGE` event.
+///
+/// Emitted once per `syscall_mtree_merge` call so the chip can re-derive the same single
+/// compression step.
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MtreeMergeEvent {
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The left subtree root.
+    pub left: [u32; MTREE_DIGEST_WORDS],
+    /// The right subtree root.
+    pub right: [u32; MTREE_DIGEST_WORDS],
+    /// `compress(left, right)`, the parent root.
+    pub parent: [u32; MTREE_DIGEST_WORDS],
+}
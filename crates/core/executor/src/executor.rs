@@ -4,7 +4,7 @@ use std::{
     sync::Arc,
 };
 
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use num::{traits::ops::overflowing::OverflowingAdd, PrimInt};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -12,18 +12,23 @@ use zkm2_stark::ZKMCoreOpts;
 
 use crate::{
     context::ZKMContext,
-    dependencies::{emit_cpu_dependencies, emit_divrem_dependencies, emit_cloclz_dependencies},
+    cop1::Cop1State,
+    cp0::{Cp0State, ExcCode},
+    dependencies::{emit_cpu_dependencies, emit_divrem_dependencies, emit_cloclz_dependencies, emit_trap_arith_dependencies},
     events::{
-        AluEvent, CpuEvent, LookupId, MemoryAccessPosition, MemoryInitializeFinalizeEvent,
-        MemoryLocalEvent, MemoryReadRecord, MemoryRecord, MemoryWriteRecord, SyscallEvent,
+        AluEvent, CpuEvent, FpAluEvent, LookupId, MemoryAccessPosition,
+        MemoryInitializeFinalizeEvent, MemoryLocalEvent, MemoryReadRecord, MemoryRecord,
+        MemoryWriteRecord, SyscallEvent,
     },
     hook::{HookEnv, HookRegistry},
     memory::{Entry, PagedMemory},
+    profiler::Profiler,
     record::{ExecutionRecord, MemoryAccessRecord},
     sign_extend,
     state::{ExecutionState, ForkState},
     subproof::{DefaultSubproofVerifier, SubproofVerifier},
     syscalls::{default_syscall_map, Syscall, SyscallCode, SyscallContext},
+    trap::{SyscallTrap, TrapHandler},
     ExecutionReport, Instruction, Opcode, Program, Register,
 };
 
@@ -91,6 +96,13 @@ pub struct Executor<'a> {
     /// The maximum number of cpu cycles to use for execution.
     pub max_cycles: Option<u64>,
 
+    /// A cycle budget a guest can observe and meter itself against via `SYSMETER` (see
+    /// [`crate::syscalls::MeterSyscall`]), distinct from [`Self::max_cycles`]: crossing it aborts
+    /// with [`ExecutionError::CycleBudgetExceeded`] rather than [`ExecutionError::ExceededCycleLimit`],
+    /// so a host can tell an untrusted program that ran past its declared budget apart from one
+    /// that simply hit the hard outer ceiling. `None` (the default) leaves the budget unmetered.
+    pub cycle_limit: Option<u64>,
+
     /// Skip deferred proof verification.
     pub deferred_proof_verification: DeferredProofVerification,
 
@@ -103,8 +115,17 @@ pub struct Executor<'a> {
     /// The collected records, split by cpu cycles.
     pub records: Vec<ExecutionRecord>,
 
-    /// Local memory access events.
-    pub local_memory_access: HashMap<u32, MemoryLocalEvent>,
+    /// Raw `(addr, initial_record, latest_record)` tuples appended for every traced memory
+    /// access in the current shard, in access order. A plain append is cheaper than the
+    /// `HashMap` entry lookup `mr`/`mw` used to do on every single access; the per-address
+    /// dedup (first-seen initial, last-seen final) is instead folded in one pass at the shard
+    /// boundary, in [`Executor::bump_record`].
+    local_memory_access_log: Vec<(u32, MemoryRecord, MemoryRecord)>,
+
+    /// The capacity `local_memory_access_log` is (re-)allocated with at the start of each
+    /// shard, so a memory-bound shard doesn't pay for repeated `Vec` growth as it fills up
+    /// access-by-access.
+    local_memory_access_log_capacity: usize,
 
     /// A counter for the number of cycles that have been executed in certain functions.
     pub cycle_tracker: HashMap<String, (u64, u32)>,
@@ -112,9 +133,46 @@ pub struct Executor<'a> {
     /// A buffer for stdout and stderr IO.
     pub io_buf: HashMap<u32, String>,
 
+    /// The bounded, length-delimited buffer backing `SYSSETRETURNDATA`/`SYSGETRETURNDATA` (see
+    /// [`crate::syscalls::SetReturnDataSyscall`]), a structured result channel a guest can hand
+    /// off to whatever consumes its proof next, kept separate from the stdout-style
+    /// [`Self::io_buf`] write stream so the two don't interleave.
+    pub return_data: Vec<u8>,
+
     /// A buffer for writing trace events to a file.
     pub trace_buf: Option<BufWriter<File>>,
 
+    /// A function-level cycle profiler, sampling `state.pc` every cycle against the symbol
+    /// table it was built with. `None` (the default) disables sampling entirely, so this has no
+    /// overhead unless installed via [`Executor::with_profiler`].
+    pub profiler: Option<Profiler>,
+
+    /// Whether to log a disassembled `clk pc: <disasm>` line for every executed instruction.
+    /// Opt in via the `TRACE_VERBOSE` environment variable, since this dominates the log
+    /// otherwise.
+    pub verbose_trace: bool,
+
+    /// An opt-in sink for a per-instruction disassembly + register-delta trace, installed via
+    /// [`crate::context::ZKMContext::instruction_trace`]. `None` disables it.
+    pub instruction_trace: Option<Box<dyn crate::trace::TraceSink + 'a>>,
+
+    /// Whether [`Self::instruction_trace`] lines are ANSI-colorized.
+    pub instruction_trace_color: bool,
+
+    /// Maps a faulting PC back to a guest source location, installed via
+    /// [`crate::context::ZKMContext::symbol_resolver`]. `None` disables source-annotated faults.
+    pub symbol_resolver: Option<Arc<dyn crate::diagnostics::SymbolResolver + 'a>>,
+
+    /// A policy for syscalls the dispatcher doesn't recognize and for bad file descriptors,
+    /// installed via [`crate::context::ZKMContext::trap_handler`]. `None` preserves the legacy
+    /// behavior (see [`TrapHandler`]'s default methods).
+    pub trap_handler: Option<Arc<dyn TrapHandler + 'a>>,
+
+    /// The most recent [`crate::diagnostics::Fault`] built from an [`ExecutionError`] that one of
+    /// the `run_*` entry points returned, for a caller to render with
+    /// [`crate::diagnostics::Fault::render`]. Replaced on every fault, never cleared otherwise.
+    pub last_fault: Option<crate::diagnostics::Fault>,
+
     /// The state of the runtime when in unconstrained mode.
     pub unconstrained_state: ForkState,
 
@@ -125,8 +183,120 @@ pub struct Executor<'a> {
 
     /// Registry of hooks, to be invoked by writing to certain file descriptors.
     pub hook_registry: HookRegistry<'a>,
+
+    /// Set by [`Executor::request_hook`] when a hook-invoking syscall has no buffered response
+    /// yet for its file descriptor, parking execution there. Consumed by
+    /// [`Executor::run_until_yield`], which surfaces it as `ExecutionPause::AwaitingHook`.
+    pending_hook: Option<(u32, Vec<u8>)>,
+
+    /// The response to the currently parked hook, queued by [`Executor::resume`] and consumed
+    /// by the next call to [`Executor::request_hook`] for the same file descriptor.
+    queued_hook_response: Option<Vec<u8>>,
+
+    /// Program-counter addresses that should stop [`Executor::continue_until_break`] before the
+    /// instruction there executes. Checked by `execute_cycle` on every cycle, so it's also
+    /// enforced under [`Executor::run`]/[`Executor::run_fast`]/etc., not only the debugger-driven
+    /// entry points; empty by default, so this has no effect unless populated.
+    pub breakpoints: HashSet<u32>,
+    /// Set by [`Executor::step`] so the very next `execute_cycle` executes the instruction at the
+    /// current `pc` even if it's in [`Self::breakpoints`] — a deliberate single step always steps,
+    /// it's `continue_until_break` that stops before re-entering a breakpoint it's already sitting
+    /// on. Consumed (cleared) by the first breakpoint check it affects.
+    skip_breakpoint_once: bool,
+
+    /// Registers and memory words watched by [`Executor::run_until_break`] for a change in
+    /// value. Checked after every instruction executes; empty by default, so this has no effect
+    /// unless populated via [`Executor::watch_register`]/[`Executor::watch_memory`].
+    pub watchpoints: Vec<Watchpoint>,
+
     /// The maximal shapes for the program.
     pub maximal_shapes: Option<Vec<HashMap<String, usize>>>,
+
+    /// Whether to flag loads from never-written, never-initialized memory. See
+    /// [`crate::context::ZKMContext::detect_uninitialized`].
+    pub detect_uninitialized: bool,
+    /// Whether an uninitialized read aborts execution instead of only being recorded. See
+    /// [`crate::context::ZKMContext::uninitialized_reads_are_fatal`].
+    pub uninitialized_reads_are_fatal: bool,
+    /// Whether naturally-aligned loads/stores must be address-aligned to their access size. See
+    /// [`crate::context::ZKMContext::strict_memory_alignment`].
+    pub strict_memory_alignment: bool,
+    /// Set by `mr`/`mw` when `detect_uninitialized` is on and the current instruction touched an
+    /// address that was never written and never initialized. Consumed (and cleared) by
+    /// `execute_cycle` once the instruction finishes, so the low-level memory accessors don't
+    /// need to thread a `Result` through every caller.
+    pending_uninitialized_read: Option<u32>,
+
+    /// The stack of open transactional snapshots, innermost last. See
+    /// [`crate::snapshot::SnapshotId`].
+    pub(crate) snapshot_stack: Vec<crate::snapshot::SnapshotFrame>,
+
+    /// The word-aligned address reserved by the last `LL`, if that reservation is still live.
+    /// `SC` only performs its store and reports success if this still matches its own address;
+    /// it's cleared unconditionally by `SC`, by any store that touches the reserved word, at
+    /// every syscall boundary, and by [`Executor::rollback`] (it isn't part of `self.state.memory`,
+    /// so the snapshot/rollback memory diff doesn't cover it on its own), mirroring the
+    /// load/store-exclusive pattern used by other ISAs.
+    pub(crate) reservation: Option<u32>,
+
+    /// CP0's exception-related registers (`Status`/`Cause`/`EPC`/`BadVAddr`). See
+    /// [`crate::cp0::Cp0State`].
+    pub cp0: Cp0State,
+
+    /// COP1's floating-point register file and `FCSR`. See [`crate::cop1::Cop1State`].
+    pub cop1: Cop1State,
+
+    /// Whether the instruction about to execute sits in the branch-delay slot of a preceding
+    /// branch/jump. Read by [`Executor::raise_exception`] to set `Cause`'s `BD` bit, and updated
+    /// at the end of every cycle based on the opcode that just ran.
+    branch_delay_slot: bool,
+
+    /// Whether the instruction about to execute is nullified: it sits in the delay slot of a
+    /// preceding "likely" branch (`BEQL`/`BNEL`/`BLEZL`/`BGTZL`/`BLTZL`/`BGEZL`, see
+    /// [`Opcode::is_branch_likely`]) that was *not* taken. Consumed and cleared at the top of
+    /// [`Executor::execute_operation`], which skips the instruction's effects entirely (as if it
+    /// were a NOP) when this is set.
+    nullify_delay_slot: bool,
+
+    /// The pluggable model used to estimate realistic MIPS cycle timing, independent of the
+    /// proving-relevant `clk` progression. See [`crate::cycle_cost::CycleCostModel`].
+    pub cycle_cost_model: Arc<dyn crate::cycle_cost::CycleCostModel>,
+    /// The opcode of the instruction currently being executed, so memory accessors can charge
+    /// opcode-specific cycle costs without threading it through every call site.
+    pub(crate) current_opcode: Opcode,
+    /// The address most recently accessed at each [`MemoryAccessPosition`], used to classify an
+    /// access as sequential or non-sequential for [`Self::cycle_cost_model`].
+    pub(crate) last_access_addr: [Option<u32>; 6],
+
+    /// An optional cap on [`Self::cycle_cost_model`]-weighted cost accumulated since the current
+    /// shard started, checked alongside the uniform `clk`-based shard boundary. See
+    /// [`crate::context::ZKMContext::weighted_shard_size`].
+    pub weighted_shard_size: Option<u64>,
+    /// Running total of [`Self::cycle_cost_model`] cost charged since the current shard started;
+    /// reset to zero every time a shard closes. See [`Self::weighted_shard_size`].
+    weighted_clk_since_shard_start: u64,
+
+    /// The cadence, in `global_clk` cycles, at which [`Self::tick_callback`] is invoked; `0`
+    /// disables it. See [`crate::context::ZKMContext::tick_quotient`].
+    pub tick_quotient: usize,
+    /// An optional host callback invoked every [`Self::tick_quotient`] cycles, given a read-only
+    /// view of the current [`ExecutionState`]. Lets an embedder report progress, enforce a time
+    /// budget, or cut a shard early without the guest program cooperating, the way a "timer
+    /// quotient" periodically traps a guest VM to its host. See [`crate::context::ZKMContext::tick_callback`].
+    pub tick_callback: Option<Box<dyn FnMut(&ExecutionState) -> TickAction + 'a>>,
+
+    /// Indexed dispatch table for every opcode's operand fetch/writeback, built once at
+    /// construction and indexed by `opcode as u8` on every cycle. See [`build_dispatch_table`].
+    dispatch_table: [OpHandler; 256],
+
+    /// A one-time decode pass over `self.program.instructions`, pairing each already-decoded
+    /// instruction's slot with the [`OpHandler`] it resolves to, so [`Self::execute_operation`]'s
+    /// `run_fast`/`run_very_fast` hot loop can do a single index into this `Vec` instead of
+    /// re-deriving `opcode as u8 as usize` into [`Self::dispatch_table`] every cycle. Populated
+    /// lazily by [`Self::ensure_compiled_handlers`]; `None` until then, and left unused by
+    /// [`ExecutorMode::Trace`]/[`ExecutorMode::Checkpoint`], which index `dispatch_table`
+    /// directly since they don't run in the tightest loop.
+    compiled_handlers: Option<Vec<OpHandler>>,
 }
 
 /// The different modes the executor can run in.
@@ -138,6 +308,9 @@ pub enum ExecutorMode {
     Checkpoint,
     /// Run the execution with full tracing of events.
     Trace,
+    /// Run the execution under control of a [``crate::gdb::GdbStub``], one instruction or
+    /// breakpoint range at a time.
+    Debug,
 }
 
 /// Errors that the [``Executor``] can throw.
@@ -167,6 +340,12 @@ pub enum ExecutionError {
     #[error("exceeded cycle limit of {0}")]
     ExceededCycleLimit(u64),
 
+    /// The execution ran past its configured [`crate::context::ZKMContext::cycle_limit`] cycle
+    /// budget, caught in the syscall dispatch path before the over-budget cycles were spent. See
+    /// [`crate::syscalls::MeterSyscall`].
+    #[error("exceeded cycle budget of {0}")]
+    CycleBudgetExceeded(u64),
+
     /// The execution failed because the syscall was called in unconstrained mode.
     #[error("syscall called in unconstrained mode")]
     InvalidSyscallUsage(u64),
@@ -178,6 +357,104 @@ pub enum ExecutionError {
     /// The program ended in unconstrained mode.
     #[error("program ended in unconstrained mode")]
     EndInUnconstrained(),
+
+    /// The execution read from memory that was never written and never initialized.
+    #[error("uninitialized memory read by opcode {0} at address {1:#010x}")]
+    UninitializedRead(Opcode, u32),
+
+    /// The execution issued a naturally-aligned load/store (`LW`/`LH`/`SW`/`SH`/...) at an address
+    /// that isn't aligned to its access size, with [`crate::context::ZKMContext::strict_memory_alignment`]
+    /// enabled.
+    #[error("misaligned memory access by opcode {0} at address {1:#010x}")]
+    MemoryAlignment(Opcode, u32),
+
+    /// [`Executor::tick_callback`] returned [`TickAction::Pause`], asking the host to take back
+    /// control. Unlike `ExecutionPause`, this surfaces through every `run_*` entry point (not
+    /// just [`Executor::run_until_yield`]), since the tick callback can fire in any of them.
+    #[error("execution paused by tick callback at clk {0}")]
+    Paused(u64),
+}
+
+/// Why [`Executor::continue_until_break`] stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionOutcome {
+    /// The program halted.
+    Halted,
+    /// Execution stopped at `pc`, a breakpoint in [`Executor::breakpoints`], before the
+    /// instruction there executed. Call [`Executor::step`] to execute through it, or
+    /// [`Executor::continue_until_break`] again after removing it from `breakpoints`.
+    Breakpoint(u32),
+}
+
+/// What a [`Watchpoint`] watches for a change in value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchTarget {
+    /// A register, read the same way [`Executor::register`] does.
+    Register(Register),
+    /// A memory word at this address, read the same way [`Executor::word`] does.
+    Memory(u32),
+}
+
+/// A register or memory word watched by [`Executor::run_until_break`], plus the value it last
+/// had when checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Watchpoint {
+    /// What's being watched.
+    pub target: WatchTarget,
+    /// The value observed the last time this watchpoint was checked.
+    pub last_value: u32,
+}
+
+/// Why [`Executor::run_until_break`] stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugEvent {
+    /// The program halted.
+    Halt,
+    /// Execution stopped at `pc`, a breakpoint in [`Executor::breakpoints`], before the
+    /// instruction there executed.
+    Breakpoint(u32),
+    /// A watched register or memory word changed value after the instruction at `pc` executed.
+    Watchpoint {
+        /// The program counter of the instruction that caused the change.
+        pc: u32,
+        /// What changed.
+        target: WatchTarget,
+        /// The value before the instruction executed.
+        old: u32,
+        /// The value after the instruction executed.
+        new: u32,
+    },
+}
+
+/// What [`Executor::tick_callback`] asks the executor to do after a periodic tick, per
+/// [`Executor::tick_quotient`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickAction {
+    /// Keep running normally.
+    Continue,
+    /// Close the current shard early, as if the shard-size/weighted-cost limit had been hit.
+    /// Ignored in [`ExecutorMode`]s that don't shard (e.g. while `self.unconstrained`).
+    ForceShardBoundary,
+    /// Stop execution immediately with [`ExecutionError::Paused`].
+    Pause,
+}
+
+/// Why [`Executor::run_until_yield`] stopped before reaching the end of the program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecutionPause {
+    /// The program halted.
+    Halted,
+    /// The current shard batch is full; call [`Executor::run_until_yield`] again to continue
+    /// into the next batch.
+    ShardFull,
+    /// Execution is parked on a hook invoked on file descriptor `fd` that doesn't have a
+    /// buffered response yet. Call [`Executor::resume`] with the hook's response to continue.
+    AwaitingHook {
+        /// The file descriptor the hook was invoked on.
+        fd: u32,
+        /// The request bytes passed to the hook.
+        request: Vec<u8>,
+    },
 }
 
 macro_rules! assert_valid_memory_access {
@@ -187,6 +464,360 @@ macro_rules! assert_valid_memory_access {
     };
 }
 
+/// The outcome of dispatching one instruction's opcode-specific logic through
+/// [`Executor::dispatch_table`]: everything [`Executor::execute_operation`] needs to finish the
+/// cycle once the handler returns. Fields default to the caller's fallthrough values and are only
+/// overridden by handlers that need to (branches/jumps/traps override `next_next_pc`; the ALU
+/// overrides `s1` for opcodes with a HI result).
+struct OpDispatchResult {
+    s1: Option<u32>,
+    s2: Option<u32>,
+    a: u32,
+    b: u32,
+    c: u32,
+    next_next_pc: u32,
+}
+
+/// A dispatch-table entry: the opcode-specific half of [`Executor::execute_operation`], taking the
+/// cycle's `pc`/`next_pc`/`next_next_pc` and the lookup ID allocated for this cycle (used only by
+/// the plain-ALU handler) and returning what changed.
+///
+/// `SYSCALL` and `UNIMPL` aren't dispatched through this table: both need control flow
+/// (`execute_operation`'s early returns, or mutating `clk`/`pc` directly from a precompile) that
+/// doesn't fit this table's uniform signature, so they keep their dedicated branches.
+type OpHandler = fn(
+    &mut Executor<'_>,
+    &Instruction,
+    u32,
+    u32,
+    u32,
+    LookupId,
+) -> Result<OpDispatchResult, ExecutionError>;
+
+fn op_condmov(
+    exec: &mut Executor<'_>,
+    instruction: &Instruction,
+    _pc: u32,
+    _next_pc: u32,
+    next_next_pc: u32,
+    _lookup_id: LookupId,
+) -> Result<OpDispatchResult, ExecutionError> {
+    let (a, b, c) = exec.execute_condmov(instruction);
+    Ok(OpDispatchResult { s1: None, s2: None, a, b, c, next_next_pc })
+}
+
+fn op_alu(
+    exec: &mut Executor<'_>,
+    instruction: &Instruction,
+    _pc: u32,
+    _next_pc: u32,
+    next_next_pc: u32,
+    lookup_id: LookupId,
+) -> Result<OpDispatchResult, ExecutionError> {
+    if !exec.unconstrained {
+        match instruction.opcode {
+            Opcode::DIVU | Opcode::DIV => {
+                exec.report.event_counts[Opcode::MUL] += 2;
+                exec.report.event_counts[Opcode::ADD] += 2;
+                exec.report.event_counts[Opcode::SLTU] += 1;
+            }
+            Opcode::CLZ | Opcode::CLO => {
+                exec.report.event_counts[Opcode::SRL] += 1;
+            }
+            _ => {}
+        }
+    }
+    let (s1, a, b, c) = exec.execute_alu(instruction, lookup_id);
+    Ok(OpDispatchResult { s1, s2: None, a, b, c, next_next_pc })
+}
+
+fn op_load(
+    exec: &mut Executor<'_>,
+    instruction: &Instruction,
+    _pc: u32,
+    _next_pc: u32,
+    next_next_pc: u32,
+    _lookup_id: LookupId,
+) -> Result<OpDispatchResult, ExecutionError> {
+    if !exec.unconstrained
+        && matches!(
+            instruction.opcode,
+            Opcode::LB
+                | Opcode::LH
+                | Opcode::LW
+                | Opcode::LBU
+                | Opcode::LHU
+                | Opcode::LWL
+                | Opcode::LWR
+        )
+    {
+        exec.report.event_counts[Opcode::ADD] += 2;
+    }
+    let (a, b, c) = exec.execute_load(instruction)?;
+    Ok(OpDispatchResult { s1: None, s2: None, a, b, c, next_next_pc })
+}
+
+fn op_store(
+    exec: &mut Executor<'_>,
+    instruction: &Instruction,
+    _pc: u32,
+    _next_pc: u32,
+    next_next_pc: u32,
+    _lookup_id: LookupId,
+) -> Result<OpDispatchResult, ExecutionError> {
+    let (a, b, c) = exec.execute_store(instruction)?;
+    Ok(OpDispatchResult { s1: None, s2: None, a, b, c, next_next_pc })
+}
+
+fn op_fp_load(
+    exec: &mut Executor<'_>,
+    instruction: &Instruction,
+    _pc: u32,
+    _next_pc: u32,
+    next_next_pc: u32,
+    _lookup_id: LookupId,
+) -> Result<OpDispatchResult, ExecutionError> {
+    exec.execute_fp_load(instruction)?;
+    Ok(OpDispatchResult { s1: None, s2: None, a: 0, b: 0, c: 0, next_next_pc })
+}
+
+fn op_fp_store(
+    exec: &mut Executor<'_>,
+    instruction: &Instruction,
+    _pc: u32,
+    _next_pc: u32,
+    next_next_pc: u32,
+    _lookup_id: LookupId,
+) -> Result<OpDispatchResult, ExecutionError> {
+    exec.execute_fp_store(instruction)?;
+    Ok(OpDispatchResult { s1: None, s2: None, a: 0, b: 0, c: 0, next_next_pc })
+}
+
+fn op_fp_alu(
+    exec: &mut Executor<'_>,
+    instruction: &Instruction,
+    _pc: u32,
+    _next_pc: u32,
+    next_next_pc: u32,
+    _lookup_id: LookupId,
+) -> Result<OpDispatchResult, ExecutionError> {
+    exec.execute_fp_alu(instruction);
+    Ok(OpDispatchResult { s1: None, s2: None, a: 0, b: 0, c: 0, next_next_pc })
+}
+
+fn op_branch(
+    exec: &mut Executor<'_>,
+    instruction: &Instruction,
+    _pc: u32,
+    next_pc: u32,
+    next_next_pc: u32,
+    _lookup_id: LookupId,
+) -> Result<OpDispatchResult, ExecutionError> {
+    if !exec.unconstrained {
+        match instruction.opcode {
+            Opcode::BEQ | Opcode::BNE | Opcode::BEQL | Opcode::BNEL => {
+                exec.report.event_counts[Opcode::ADD] += 1;
+            }
+            Opcode::BLTZ
+            | Opcode::BGEZ
+            | Opcode::BLEZ
+            | Opcode::BGTZ
+            | Opcode::BLTZL
+            | Opcode::BGEZL
+            | Opcode::BLEZL
+            | Opcode::BGTZL => {
+                exec.report.event_counts[Opcode::ADD] += 1;
+                exec.report.event_counts[Opcode::SLT] += 2;
+            }
+            _ => {}
+        }
+    }
+    let (a, b, c, next_next_pc) = exec.execute_branch(instruction, next_pc, next_next_pc);
+    Ok(OpDispatchResult { s1: None, s2: None, a, b, c, next_next_pc })
+}
+
+fn op_jump(
+    exec: &mut Executor<'_>,
+    instruction: &Instruction,
+    _pc: u32,
+    _next_pc: u32,
+    _next_next_pc: u32,
+    _lookup_id: LookupId,
+) -> Result<OpDispatchResult, ExecutionError> {
+    let (a, b, c, next_next_pc) = exec.execute_jump(instruction);
+    Ok(OpDispatchResult { s1: None, s2: None, a, b, c, next_next_pc })
+}
+
+fn op_jumpi(
+    exec: &mut Executor<'_>,
+    instruction: &Instruction,
+    _pc: u32,
+    _next_pc: u32,
+    _next_next_pc: u32,
+    _lookup_id: LookupId,
+) -> Result<OpDispatchResult, ExecutionError> {
+    let (a, b, c, next_next_pc) = exec.execute_jumpi(instruction);
+    Ok(OpDispatchResult { s1: None, s2: None, a, b, c, next_next_pc })
+}
+
+fn op_jump_direct(
+    exec: &mut Executor<'_>,
+    instruction: &Instruction,
+    _pc: u32,
+    _next_pc: u32,
+    _next_next_pc: u32,
+    _lookup_id: LookupId,
+) -> Result<OpDispatchResult, ExecutionError> {
+    if !exec.unconstrained {
+        exec.report.event_counts[Opcode::ADD] += 1;
+    }
+    let (a, b, c, next_next_pc) = exec.execute_jump_direct(instruction);
+    Ok(OpDispatchResult { s1: None, s2: None, a, b, c, next_next_pc })
+}
+
+fn op_nop(
+    exec: &mut Executor<'_>,
+    _instruction: &Instruction,
+    _pc: u32,
+    _next_pc: u32,
+    next_next_pc: u32,
+    _lookup_id: LookupId,
+) -> Result<OpDispatchResult, ExecutionError> {
+    exec.rw(Register::ZERO, 0, MemoryAccessPosition::A);
+    Ok(OpDispatchResult { s1: None, s2: None, a: 0, b: 0, c: 0, next_next_pc })
+}
+
+fn op_teq(
+    exec: &mut Executor<'_>,
+    instruction: &Instruction,
+    pc: u32,
+    _next_pc: u32,
+    next_next_pc: u32,
+    _lookup_id: LookupId,
+) -> Result<OpDispatchResult, ExecutionError> {
+    let (a, b, c, next_next_pc) = exec.execute_teq(instruction, pc, next_next_pc);
+    Ok(OpDispatchResult { s1: None, s2: None, a, b, c, next_next_pc })
+}
+
+fn op_tcond(
+    exec: &mut Executor<'_>,
+    instruction: &Instruction,
+    pc: u32,
+    _next_pc: u32,
+    next_next_pc: u32,
+    _lookup_id: LookupId,
+) -> Result<OpDispatchResult, ExecutionError> {
+    let (a, b, c, next_next_pc) = exec.execute_tcond(instruction, pc, next_next_pc);
+    Ok(OpDispatchResult { s1: None, s2: None, a, b, c, next_next_pc })
+}
+
+fn op_eret(
+    exec: &mut Executor<'_>,
+    _instruction: &Instruction,
+    _pc: u32,
+    _next_pc: u32,
+    _next_next_pc: u32,
+    _lookup_id: LookupId,
+) -> Result<OpDispatchResult, ExecutionError> {
+    let next_next_pc = exec.cp0.epc;
+    Ok(OpDispatchResult { s1: None, s2: None, a: 0, b: 0, c: 0, next_next_pc })
+}
+
+/// Placeholder for table slots that are never actually dispatched through (`SYSCALL`, `UNIMPL`,
+/// and any reserved discriminants), since `execute_operation` special-cases those before
+/// consulting [`Executor::dispatch_table`].
+fn op_unreachable(
+    _exec: &mut Executor<'_>,
+    _instruction: &Instruction,
+    _pc: u32,
+    _next_pc: u32,
+    next_next_pc: u32,
+    _lookup_id: LookupId,
+) -> Result<OpDispatchResult, ExecutionError> {
+    Ok(OpDispatchResult { s1: None, s2: None, a: 0, b: 0, c: 0, next_next_pc })
+}
+
+/// Build the indexed opcode dispatch table, once, at executor construction. Every array slot is
+/// filled (unimplemented discriminants get [`op_unreachable`]) so indexing by `opcode as u8`
+/// is always safe, with no per-cycle bounds check or fallback branch needed.
+fn build_dispatch_table() -> [OpHandler; 256] {
+    let mut table: [OpHandler; 256] = [op_unreachable; 256];
+    table[Opcode::MEQ as usize] = op_condmov;
+    table[Opcode::MNE as usize] = op_condmov;
+    table[Opcode::ADD as usize] = op_alu;
+    table[Opcode::SUB as usize] = op_alu;
+    table[Opcode::MULT as usize] = op_alu;
+    table[Opcode::MULTU as usize] = op_alu;
+    table[Opcode::MUL as usize] = op_alu;
+    table[Opcode::DIV as usize] = op_alu;
+    table[Opcode::DIVU as usize] = op_alu;
+    table[Opcode::SLL as usize] = op_alu;
+    table[Opcode::SRL as usize] = op_alu;
+    table[Opcode::SRA as usize] = op_alu;
+    table[Opcode::SLT as usize] = op_alu;
+    table[Opcode::SLTU as usize] = op_alu;
+    table[Opcode::AND as usize] = op_alu;
+    table[Opcode::OR as usize] = op_alu;
+    table[Opcode::XOR as usize] = op_alu;
+    table[Opcode::NOR as usize] = op_alu;
+    table[Opcode::CLZ as usize] = op_alu;
+    table[Opcode::CLO as usize] = op_alu;
+    table[Opcode::LB as usize] = op_load;
+    table[Opcode::LH as usize] = op_load;
+    table[Opcode::LW as usize] = op_load;
+    table[Opcode::LWL as usize] = op_load;
+    table[Opcode::LBU as usize] = op_load;
+    table[Opcode::LHU as usize] = op_load;
+    table[Opcode::LWR as usize] = op_load;
+    table[Opcode::LL as usize] = op_load;
+    table[Opcode::SB as usize] = op_store;
+    table[Opcode::SH as usize] = op_store;
+    table[Opcode::SW as usize] = op_store;
+    table[Opcode::SWL as usize] = op_store;
+    table[Opcode::SWR as usize] = op_store;
+    table[Opcode::SC as usize] = op_store;
+    table[Opcode::LWC1 as usize] = op_fp_load;
+    table[Opcode::LDC1 as usize] = op_fp_load;
+    table[Opcode::SWC1 as usize] = op_fp_store;
+    table[Opcode::SDC1 as usize] = op_fp_store;
+    table[Opcode::FADD_S as usize] = op_fp_alu;
+    table[Opcode::FSUB_S as usize] = op_fp_alu;
+    table[Opcode::FMUL_S as usize] = op_fp_alu;
+    table[Opcode::FDIV_S as usize] = op_fp_alu;
+    table[Opcode::FADD_D as usize] = op_fp_alu;
+    table[Opcode::FSUB_D as usize] = op_fp_alu;
+    table[Opcode::FMUL_D as usize] = op_fp_alu;
+    table[Opcode::FDIV_D as usize] = op_fp_alu;
+    table[Opcode::FC_EQ_S as usize] = op_fp_alu;
+    table[Opcode::FC_LT_S as usize] = op_fp_alu;
+    table[Opcode::FCVT_S_W as usize] = op_fp_alu;
+    table[Opcode::FCVT_W_S as usize] = op_fp_alu;
+    table[Opcode::BEQ as usize] = op_branch;
+    table[Opcode::BNE as usize] = op_branch;
+    table[Opcode::BGEZ as usize] = op_branch;
+    table[Opcode::BLEZ as usize] = op_branch;
+    table[Opcode::BGTZ as usize] = op_branch;
+    table[Opcode::BLTZ as usize] = op_branch;
+    table[Opcode::BEQL as usize] = op_branch;
+    table[Opcode::BNEL as usize] = op_branch;
+    table[Opcode::BGEZL as usize] = op_branch;
+    table[Opcode::BLEZL as usize] = op_branch;
+    table[Opcode::BGTZL as usize] = op_branch;
+    table[Opcode::BLTZL as usize] = op_branch;
+    table[Opcode::Jump as usize] = op_jump;
+    table[Opcode::Jumpi as usize] = op_jumpi;
+    table[Opcode::JumpDirect as usize] = op_jump_direct;
+    table[Opcode::NOP as usize] = op_nop;
+    table[Opcode::TEQ as usize] = op_teq;
+    table[Opcode::TNE as usize] = op_tcond;
+    table[Opcode::TGE as usize] = op_tcond;
+    table[Opcode::TGEU as usize] = op_tcond;
+    table[Opcode::TLT as usize] = op_tcond;
+    table[Opcode::TLTU as usize] = op_tcond;
+    table[Opcode::ERET as usize] = op_eret;
+    table
+}
+
 impl<'a> Executor<'a> {
     /// Create a new [``Executor``] from a program and options.
     #[must_use]
@@ -208,6 +839,11 @@ impl<'a> Executor<'a> {
         // Create a default record with the program.
         let record = ExecutionRecord::new(program.clone());
 
+        // Every instruction touches at most a handful of addresses (registers plus up to one
+        // memory operand), so sizing off the shard's instruction budget comfortably covers a
+        // shard without the log ever needing to grow mid-shard.
+        let local_memory_access_log_capacity = (opts.shard_size as usize * 4).min(1 << 20);
+
         // Determine the maximum number of cycles for any syscall.
         let syscall_map = default_syscall_map();
         let max_syscall_cycles = syscall_map
@@ -224,6 +860,9 @@ impl<'a> Executor<'a> {
             None
         };
 
+        // If `TRACE_VERBOSE` is set, log a disassembled line for every executed instruction.
+        let verbose_trace = std::env::var("TRACE_VERBOSE").is_ok();
+
         let subproof_verifier = context
             .subproof_verifier
             .unwrap_or_else(|| Arc::new(DefaultSubproofVerifier::new()));
@@ -239,7 +878,15 @@ impl<'a> Executor<'a> {
             shard_batch_size: opts.shard_batch_size as u32,
             cycle_tracker: HashMap::new(),
             io_buf: HashMap::new(),
+            return_data: Vec::new(),
             trace_buf,
+            profiler: None,
+            verbose_trace,
+            instruction_trace: context.instruction_trace,
+            instruction_trace_color: context.instruction_trace_color,
+            symbol_resolver: context.symbol_resolver,
+            trap_handler: context.trap_handler,
+            last_fault: None,
             unconstrained: false,
             unconstrained_state: ForkState::default(),
             syscall_map,
@@ -250,8 +897,14 @@ impl<'a> Executor<'a> {
             print_report: false,
             subproof_verifier,
             hook_registry,
+            pending_hook: None,
+            queued_hook_response: None,
+            breakpoints: HashSet::new(),
+            skip_breakpoint_once: false,
+            watchpoints: Vec::new(),
             opts,
             max_cycles: context.max_cycles,
+            cycle_limit: context.cycle_limit,
             deferred_proof_verification: if context.skip_deferred_proof_verification {
                 DeferredProofVerification::Disabled
             } else {
@@ -259,11 +912,52 @@ impl<'a> Executor<'a> {
             },
             memory_checkpoint: PagedMemory::new_preallocated(),
             uninitialized_memory_checkpoint: PagedMemory::new_preallocated(),
-            local_memory_access: HashMap::new(),
+            local_memory_access_log: Vec::with_capacity(local_memory_access_log_capacity),
+            local_memory_access_log_capacity,
             maximal_shapes: None,
+            detect_uninitialized: context.detect_uninitialized,
+            uninitialized_reads_are_fatal: context.uninitialized_reads_are_fatal,
+            strict_memory_alignment: context.strict_memory_alignment,
+            pending_uninitialized_read: None,
+            snapshot_stack: Vec::new(),
+            reservation: None,
+            cp0: Cp0State::default(),
+            cop1: Cop1State::default(),
+            branch_delay_slot: false,
+            nullify_delay_slot: false,
+            cycle_cost_model: context
+                .cycle_cost_model
+                .unwrap_or_else(|| Arc::new(crate::cycle_cost::DefaultCycleCostModel)),
+            current_opcode: Opcode::ADD,
+            last_access_addr: [None; 6],
+            weighted_shard_size: context.weighted_shard_size,
+            weighted_clk_since_shard_start: 0,
+            tick_quotient: context.tick_quotient,
+            tick_callback: context.tick_callback,
+            dispatch_table: build_dispatch_table(),
+            compiled_handlers: None,
         }
     }
 
+    /// Lazily lower `self.program.instructions` into [`Self::compiled_handlers`]: a `Vec` of the
+    /// same length, pairing each instruction's slot with the [`OpHandler`] its opcode resolves to
+    /// in [`Self::dispatch_table`]. Called once by [`Self::run_fast`]/[`Self::run_very_fast`]
+    /// before their execution loop, so `execute_operation`'s hot path can index this `Vec`
+    /// directly by instruction slot instead of re-deriving `opcode as u8 as usize` into
+    /// `dispatch_table` every cycle. A no-op if already populated.
+    fn ensure_compiled_handlers(&mut self) {
+        if self.compiled_handlers.is_some() {
+            return;
+        }
+        let handlers = self
+            .program
+            .instructions
+            .iter()
+            .map(|instruction| self.dispatch_table[instruction.opcode as u8 as usize])
+            .collect();
+        self.compiled_handlers = Some(handlers);
+    }
+
     /// Invokes a hook with the given file descriptor `fd` with the data `buf`.
     ///
     /// # Errors
@@ -284,6 +978,54 @@ impl<'a> Executor<'a> {
         HookEnv { runtime: self }
     }
 
+    /// Fetches a hook's response without blocking the execution thread, for use by
+    /// hook-invoking syscalls that want to support [`Executor::run_until_yield`].
+    ///
+    /// If a response has already been queued by a previous call to [`Executor::resume`], it's
+    /// taken and returned immediately. Otherwise this parks `(fd, request)` as the pending
+    /// pause and returns `None`; the calling syscall must not have made any other state
+    /// mutation yet for the current cycle, since once `resume` supplies the response, the same
+    /// instruction is retried from the top -- it's never partially replayed and never
+    /// double-emits its `CpuEvent`.
+    pub fn request_hook(&mut self, fd: u32, request: Vec<u8>) -> Option<Vec<u8>> {
+        if let Some(response) = self.queued_hook_response.take() {
+            return Some(response);
+        }
+        self.pending_hook = Some((fd, request));
+        None
+    }
+
+    /// Installs `handler` for `code`, replacing the default handler if one was already
+    /// registered by [`default_syscall_map`]. Lets an embedder override a built-in syscall (e.g.
+    /// to back `SYSBUNDLEOPEN` with a real filesystem) or add a handler for a code of its own in
+    /// the reserved range documented on [`SyscallCode`].
+    pub fn register_syscall(&mut self, code: SyscallCode, handler: Arc<dyn Syscall>) {
+        self.syscall_map.insert(code, handler);
+    }
+
+    /// The number of cycles executed so far, for [`crate::syscalls::MeterSyscall`] to report
+    /// against [`Self::cycle_limit`].
+    #[must_use]
+    pub fn cycles_used(&self) -> u64 {
+        self.state.global_clk
+    }
+
+    /// Installs a [`Profiler`] over `symbols`, so every cycle from here on samples `state.pc`
+    /// into it. Chain off [`Executor::new`]/[`Executor::with_context`], e.g.
+    /// `Executor::new(program, opts).with_profiler(symbols)`.
+    #[must_use]
+    pub fn with_profiler(mut self, symbols: Vec<crate::profiler::Symbol>) -> Self {
+        self.profiler = Some(Profiler::new(symbols));
+        self
+    }
+
+    /// Takes the [`Profiler`] installed by [`Executor::with_profiler`], if any, leaving `None`
+    /// behind. Call after [`Executor::run`] to read out the accumulated cycle counts without
+    /// holding a borrow on the executor.
+    pub fn take_profile(&mut self) -> Option<Profiler> {
+        self.profiler.take()
+    }
+
     /// Recover runtime state from a program and existing execution state.
     #[must_use]
     pub fn recover(program: Program, state: ExecutionState, opts: ZKMCoreOpts) -> Self {
@@ -435,12 +1177,30 @@ impl<'a> Executor<'a> {
                 .or_insert(record.copied());
         }
 
+        // If we're inside a `snapshot()`ed region, lazily record the pre-mutation value in the
+        // innermost frame, the first time this address is touched since that snapshot.
+        if let Some(frame) = self.snapshot_stack.last_mut() {
+            let record = match entry {
+                Entry::Occupied(ref entry) => Some(entry.get()),
+                Entry::Vacant(_) => None,
+            };
+            frame.memory_diff.entry(addr).or_insert(record.copied());
+        }
+
         // If it's the first time accessing this address, initialize previous values.
         let record: &mut MemoryRecord = match entry {
             Entry::Occupied(entry) => entry.into_mut(),
             Entry::Vacant(entry) => {
                 // If addr has a specific value to be initialized with, use that, otherwise 0.
-                let value = self.state.uninitialized_memory.get(addr).unwrap_or(&0);
+                let value = self.state.uninitialized_memory.get(addr);
+                if self.detect_uninitialized
+                    && value.is_none()
+                    && addr != Register::ZERO as u32
+                    && self.pending_uninitialized_read.is_none()
+                {
+                    self.pending_uninitialized_read = Some(addr);
+                }
+                let value = value.unwrap_or(&0);
                 self.uninitialized_memory_checkpoint
                     .entry(addr)
                     .or_insert_with(|| *value != 0);
@@ -457,22 +1217,20 @@ impl<'a> Executor<'a> {
         record.timestamp = timestamp;
 
         if !self.unconstrained && self.executor_mode == ExecutorMode::Trace {
-            let local_memory_access = if let Some(local_memory_access) = local_memory_access {
+            if let Some(local_memory_access) = local_memory_access {
                 local_memory_access
+                    .entry(addr)
+                    .and_modify(|e| {
+                        e.final_mem_access = *record;
+                    })
+                    .or_insert(MemoryLocalEvent {
+                        addr,
+                        initial_mem_access: prev_record,
+                        final_mem_access: *record,
+                    });
             } else {
-                &mut self.local_memory_access
-            };
-
-            local_memory_access
-                .entry(addr)
-                .and_modify(|e| {
-                    e.final_mem_access = *record;
-                })
-                .or_insert(MemoryLocalEvent {
-                    addr,
-                    initial_mem_access: prev_record,
-                    final_mem_access: *record,
-                });
+                self.local_memory_access_log.push((addr, prev_record, *record));
+            }
         }
 
         // Construct the memory read record.
@@ -523,6 +1281,16 @@ impl<'a> Executor<'a> {
                 .or_insert(record.copied());
         }
 
+        // If we're inside a `snapshot()`ed region, lazily record the pre-mutation value in the
+        // innermost frame, the first time this address is touched since that snapshot.
+        if let Some(frame) = self.snapshot_stack.last_mut() {
+            let record = match entry {
+                Entry::Occupied(ref entry) => Some(entry.get()),
+                Entry::Vacant(_) => None,
+            };
+            frame.memory_diff.entry(addr).or_insert(record.copied());
+        }
+
         // If it's the first time accessing this address, initialize previous values.
         let record: &mut MemoryRecord = match entry {
             Entry::Occupied(entry) => entry.into_mut(),
@@ -547,22 +1315,20 @@ impl<'a> Executor<'a> {
         record.timestamp = timestamp;
 
         if !self.unconstrained && self.executor_mode == ExecutorMode::Trace {
-            let local_memory_access = if let Some(local_memory_access) = local_memory_access {
+            if let Some(local_memory_access) = local_memory_access {
                 local_memory_access
+                    .entry(addr)
+                    .and_modify(|e| {
+                        e.final_mem_access = *record;
+                    })
+                    .or_insert(MemoryLocalEvent {
+                        addr,
+                        initial_mem_access: prev_record,
+                        final_mem_access: *record,
+                    });
             } else {
-                &mut self.local_memory_access
-            };
-
-            local_memory_access
-                .entry(addr)
-                .and_modify(|e| {
-                    e.final_mem_access = *record;
-                })
-                .or_insert(MemoryLocalEvent {
-                    addr,
-                    initial_mem_access: prev_record,
-                    final_mem_access: *record,
-                });
+                self.local_memory_access_log.push((addr, prev_record, *record));
+            }
         }
 
         // Construct the memory write record.
@@ -576,11 +1342,24 @@ impl<'a> Executor<'a> {
         )
     }
 
+    /// Classify `addr` against the last access at `position` as sequential or not, charge the
+    /// configured [`Self::cycle_cost_model`] for it, and remember `addr` for next time.
+    fn account_cycle_cost(&mut self, addr: u32, position: MemoryAccessPosition) {
+        let slot = &mut self.last_access_addr[position as usize];
+        let sequential = *slot == Some(addr.wrapping_sub(4));
+        *slot = Some(addr);
+        let cost = self.cycle_cost_model.cost(self.current_opcode, position, sequential);
+        self.report.weighted_cycles += cost;
+        self.weighted_clk_since_shard_start += cost;
+    }
+
     /// Read from memory, assuming that all addresses are aligned.
     pub fn mr_cpu(&mut self, addr: u32, position: MemoryAccessPosition) -> u32 {
         // Assert that the address is aligned.
         assert_valid_memory_access!(addr, position);
 
+        self.account_cycle_cost(addr, position);
+
         // Read the address from memory and create a memory read record.
         let record = self.mr(addr, self.shard(), self.timestamp(&position), None);
 
@@ -608,6 +1387,8 @@ impl<'a> Executor<'a> {
         // Assert that the address is aligned.
         assert_valid_memory_access!(addr, position);
 
+        self.account_cycle_cost(addr, position);
+
         // Read the address from memory and create a memory read record.
         let record = self.mw(addr, value, self.shard(), self.timestamp(&position), None);
 
@@ -732,6 +1513,24 @@ impl<'a> Executor<'a> {
         c: u32,
         lookup_id: LookupId,
     ) {
+        // The nonce is this event's position in whichever event vector it's about to be pushed
+        // onto, so it has to be read off that vector's current length before the push below --
+        // the same row-index-as-nonce idea `MulCols::nonce` populates via `.enumerate()` during
+        // trace generation, just computed at event-creation time since no standalone chip's
+        // `generate_trace` exists yet for most of these opcodes.
+        let nonce = match opcode {
+            Opcode::ADD | Opcode::TADD => self.record.add_events.len(),
+            Opcode::SUB | Opcode::TSUB => self.record.sub_events.len(),
+            Opcode::XOR | Opcode::OR | Opcode::AND | Opcode::NOR => self.record.bitwise_events.len(),
+            Opcode::SLL => self.record.shift_left_events.len(),
+            Opcode::SRL | Opcode::SRA => self.record.shift_right_events.len(),
+            Opcode::SLT | Opcode::SLTU => self.record.lt_events.len(),
+            Opcode::MUL | Opcode::MULT | Opcode::MULTU => self.record.mul_events.len(),
+            Opcode::DIV | Opcode::DIVU => self.record.divrem_events.len(),
+            Opcode::CLZ | Opcode::CLO => self.record.cloclz_events.len(),
+            _ => 0,
+        } as u32;
+
         let event = AluEvent {
             lookup_id,
             shard: self.shard(),
@@ -742,6 +1541,7 @@ impl<'a> Executor<'a> {
             b,
             c,
             sub_lookups: self.record.create_lookup_ids(),
+            nonce,
         };
         match opcode {
             Opcode::ADD => {
@@ -750,6 +1550,14 @@ impl<'a> Executor<'a> {
             Opcode::SUB => {
                 self.record.sub_events.push(event);
             }
+            Opcode::TADD => {
+                self.record.add_events.push(event);
+                emit_trap_arith_dependencies(self, event);
+            }
+            Opcode::TSUB => {
+                self.record.sub_events.push(event);
+                emit_trap_arith_dependencies(self, event);
+            }
             Opcode::XOR | Opcode::OR | Opcode::AND | Opcode::NOR => {
                 self.record.bitwise_events.push(event);
             }
@@ -886,7 +1694,9 @@ impl<'a> Executor<'a> {
 
     /// Execute the given instruction over the current state of the runtime.
     #[allow(clippy::too_many_lines)]
-    fn execute_operation(&mut self, instruction: &Instruction) -> Result<(), ExecutionError> {
+    pub(crate) fn execute_operation(&mut self, instruction: &Instruction) -> Result<(), ExecutionError> {
+        self.current_opcode = instruction.opcode;
+
         let mut pc = self.state.pc;
         let mut clk = self.state.clk;
         let mut exit_code = 0u32; // use in halt code
@@ -894,6 +1704,17 @@ impl<'a> Executor<'a> {
         let mut next_pc = self.state.next_pc;
         let mut next_next_pc = self.state.next_pc.wrapping_add(4);
 
+        // This instruction sits in the delay slot of a "likely" branch that wasn't taken: run it
+        // as a pure NOP (no register/memory effects, no report counting) and just advance pc,
+        // exactly as real MIPS hardware nullifies it.
+        if std::mem::take(&mut self.nullify_delay_slot) {
+            self.state.pc = next_pc;
+            self.state.next_pc = next_next_pc;
+            self.branch_delay_slot = false;
+            self.state.clk += 7;
+            return Ok(());
+        }
+
         //todo: uncomment this when all the operations have been implemented
         // let (a, b, c): (u32, u32, u32);
         let mut a = 0u32;
@@ -920,214 +1741,166 @@ impl<'a> Executor<'a> {
         if !self.unconstrained {
             self.report.opcode_counts[instruction.opcode] += 1;
             self.report.event_counts[instruction.opcode] += 1;
-            match instruction.opcode {
-                // todo: check all
-                Opcode::LB
-                | Opcode::LH
-                | Opcode::LW
-                | Opcode::LBU
-                | Opcode::LHU
-                | Opcode::LWL
-                | Opcode::LWR => {
-                    self.report.event_counts[Opcode::ADD] += 2;
-                }
-                Opcode::JumpDirect => {
-                    self.report.event_counts[Opcode::ADD] += 1;
-                }
-                Opcode::BEQ | Opcode::BNE => {
-                    self.report.event_counts[Opcode::ADD] += 1;
-                }
-                Opcode::BLTZ | Opcode::BGEZ | Opcode::BLEZ | Opcode::BGTZ => {
-                    self.report.event_counts[Opcode::ADD] += 1;
-                    self.report.event_counts[Opcode::SLT] += 2;
-                }
-                Opcode::DIVU | Opcode::DIV => {
-                    self.report.event_counts[Opcode::MUL] += 2;
-                    self.report.event_counts[Opcode::ADD] += 2;
-                    self.report.event_counts[Opcode::SLTU] += 1;
-                }
-                Opcode::CLZ | Opcode::CLO => {
-                    self.report.event_counts[Opcode::SRL] += 1;
-                }
-                _ => {}
-            };
         }
 
-        match instruction.opcode {
-            // syscall
-            Opcode::SYSCALL => {
-                let syscall_id = self.register(Register::V0);
-                c = self.rr(Register::A1, MemoryAccessPosition::C);
-                b = self.rr(Register::A0, MemoryAccessPosition::B);
-                let syscall = SyscallCode::from_u32(syscall_id);
-
-                if self.print_report && !self.unconstrained {
-                    self.report.syscall_counts[syscall] += 1;
-                }
+        if instruction.opcode == Opcode::SYSCALL {
+            // Every syscall boundary invalidates any in-flight LL/SC reservation.
+            self.reservation = None;
 
-                // `hint_slice` is allowed in unconstrained mode since it is used to write the hint.
-                // Other syscalls are not allowed because they can lead to non-deterministic
-                // behavior, especially since many syscalls modify memory in place,
-                // which is not permitted in unconstrained mode. This will result in
-                // non-zero memory interactions when generating a proof.
+            let syscall_id = self.register(Register::V0);
+            c = self.rr(Register::A1, MemoryAccessPosition::C);
+            b = self.rr(Register::A0, MemoryAccessPosition::B);
+            let syscall = SyscallCode::from_u32(syscall_id);
 
-                if self.unconstrained
-                    && (syscall != SyscallCode::EXIT_UNCONSTRAINED && syscall != SyscallCode::WRITE)
-                {
-                    return Err(ExecutionError::InvalidSyscallUsage(syscall_id as u64));
-                }
+            if self.print_report && !self.unconstrained {
+                self.report.syscall_counts[syscall] += 1;
+            }
 
-                // Update the syscall counts.
-                let syscall_for_count = syscall.count_map();
-                let syscall_count = self
-                    .state
-                    .syscall_counts
-                    .entry(syscall_for_count)
-                    .or_insert(0);
-                let (threshold, multiplier) = match syscall_for_count {
-                    SyscallCode::KECCAK_PERMUTE => (self.opts.split_opts.keccak, 24),
-                    SyscallCode::SHA_EXTEND => (self.opts.split_opts.sha_extend, 48),
-                    SyscallCode::SHA_COMPRESS => (self.opts.split_opts.sha_compress, 80),
-                    _ => (self.opts.split_opts.deferred, 1),
-                };
-                let nonce = (((*syscall_count as usize) % threshold) * multiplier) as u32;
-                self.record.nonce_lookup[syscall_lookup_id.0 as usize] = nonce;
-                *syscall_count += 1;
+            // `hint_slice` is allowed in unconstrained mode since it is used to write the hint.
+            // Other syscalls are not allowed because they can lead to non-deterministic
+            // behavior, especially since many syscalls modify memory in place,
+            // which is not permitted in unconstrained mode. This will result in
+            // non-zero memory interactions when generating a proof.
 
-                let syscall_impl = self.get_syscall(syscall).cloned();
-                if syscall.should_send() != 0 && self.executor_mode == ExecutorMode::Trace {
-                    self.emit_syscall(clk, syscall.syscall_id(), b, c, syscall_lookup_id);
-                }
-                let mut precompile_rt = SyscallContext::new(self);
-                precompile_rt.syscall_lookup_id = syscall_lookup_id;
-                let (precompile_next_pc, precompile_cycles, returned_exit_code) =
-                    if let Some(syscall_impl) = syscall_impl {
-                        // Executing a syscall optionally returns a value to write to the t0
-                        // register. If it returns None, we just keep the
-                        // syscall_id in t0.
-                        let res = syscall_impl.execute(&mut precompile_rt, syscall, b, c);
-                        if let Some(r0) = res {
-                            a = r0;
-                        } else {
-                            a = syscall_id;
-                        }
+            if self.unconstrained
+                && (syscall != SyscallCode::EXIT_UNCONSTRAINED && syscall != SyscallCode::WRITE)
+            {
+                return Err(ExecutionError::InvalidSyscallUsage(syscall_id as u64));
+            }
 
-                        // If the syscall is `HALT` and the exit code is non-zero, return an error.
-                        if syscall == SyscallCode::HALT && precompile_rt.exit_code != 0 {
-                            return Err(ExecutionError::HaltWithNonZeroExitCode(
-                                precompile_rt.exit_code,
-                            ));
-                        }
+            // Update the syscall counts.
+            let syscall_for_count = syscall.count_map();
+            let syscall_count = self
+                .state
+                .syscall_counts
+                .entry(syscall_for_count)
+                .or_insert(0);
+            let (threshold, multiplier) = match syscall_for_count {
+                SyscallCode::KECCAK_PERMUTE => (self.opts.split_opts.keccak, 24),
+                SyscallCode::SHA_EXTEND => (self.opts.split_opts.sha_extend, 48),
+                SyscallCode::SHA_COMPRESS => (self.opts.split_opts.sha_compress, 80),
+                _ => (self.opts.split_opts.deferred, 1),
+            };
+            let nonce = (((*syscall_count as usize) % threshold) * multiplier) as u32;
+            self.record.nonce_lookup[syscall_lookup_id.0 as usize] = nonce;
+            *syscall_count += 1;
 
-                        (
-                            precompile_rt.next_pc,
-                            syscall_impl.num_extra_cycles(),
-                            precompile_rt.exit_code,
-                        )
+            let syscall_impl = self.get_syscall(syscall).cloned();
+            if syscall.should_send() != 0 && self.executor_mode == ExecutorMode::Trace {
+                self.emit_syscall(clk, syscall.syscall_id(), b, c, syscall_lookup_id);
+            }
+            let mut precompile_rt = SyscallContext::new(self);
+            precompile_rt.syscall_lookup_id = syscall_lookup_id;
+            let (precompile_next_pc, precompile_cycles, returned_exit_code) =
+                if let Some(syscall_impl) = syscall_impl {
+                    // Executing a syscall optionally returns a value to write to the t0
+                    // register. If it returns None, we just keep the
+                    // syscall_id in t0.
+                    let res = syscall_impl.execute(&mut precompile_rt, syscall, b, c);
+                    if let Some(r0) = res {
+                        a = r0;
                     } else {
-                        return Err(ExecutionError::UnsupportedSyscall(syscall_id));
-                    };
+                        a = syscall_id;
+                    }
 
-                if syscall == SyscallCode::HALT && returned_exit_code == 0 {
-                    self.state.exited = true;
-                }
+                    // If the syscall is `HALT` and the exit code is non-zero, return an error.
+                    if syscall == SyscallCode::HALT && precompile_rt.exit_code != 0 {
+                        return Err(ExecutionError::HaltWithNonZeroExitCode(
+                            precompile_rt.exit_code,
+                        ));
+                    }
 
-                // Allow the syscall impl to modify state.clk/pc (exit unconstrained does this)
-                clk = self.state.clk;
-                pc = self.state.pc;
-
-                self.rw(Register::V0, a, MemoryAccessPosition::A);
-                next_pc = precompile_next_pc;
-                self.state.clk += precompile_cycles;
-                exit_code = returned_exit_code;
-            }
-            Opcode::MEQ | Opcode::MNE => {
-                (a, b, c) = self.execute_condmov(instruction);
-            }
-
-            // Arithmetic instructions
-            Opcode::ADD
-            | Opcode::SUB
-            | Opcode::MULT
-            | Opcode::MULTU
-            | Opcode::MUL
-            | Opcode::DIV
-            | Opcode::DIVU
-            | Opcode::SLL
-            | Opcode::SRL
-            | Opcode::SRA
-            | Opcode::SLT
-            | Opcode::SLTU
-            | Opcode::AND
-            | Opcode::OR
-            | Opcode::XOR
-            | Opcode::NOR
-            | Opcode::CLZ
-            | Opcode::CLO => {
-                (s1, a, b, c) = self.execute_alu(instruction, lookup_id);
-            }
-
-            // Load instructions.
-            Opcode::LB
-            | Opcode::LH
-            | Opcode::LW
-            | Opcode::LWL
-            | Opcode::LBU
-            | Opcode::LHU
-            | Opcode::LWR
-            | Opcode::LL => {
-                (a, b, c) = self.execute_load(instruction)?;
-            }
-
-            // Store instructions.
-            Opcode::SB
-            | Opcode::SH
-            | Opcode::SW
-            | Opcode::SWL
-            | Opcode::SWR
-            | Opcode::SDC1
-            | Opcode::SC => {
-                (a, b, c) = self.execute_store(instruction)?;
-            }
-
-            // Branch instructions.
-            Opcode::BEQ
-            | Opcode::BNE
-            | Opcode::BGEZ
-            | Opcode::BLEZ
-            | Opcode::BGTZ
-            | Opcode::BLTZ => {
-                (a, b, c, next_next_pc) = self.execute_branch(instruction, next_pc, next_next_pc);
-            }
+                    (
+                        precompile_rt.next_pc,
+                        syscall_impl.num_extra_cycles(),
+                        precompile_rt.exit_code,
+                    )
+                } else if let Some(trap_handler) = self.trap_handler.clone() {
+                    match trap_handler.handle_unsupported_syscall(syscall_id, b, c) {
+                        SyscallTrap::Handled(r0, _r1) => {
+                            a = r0;
+                            (self.state.next_pc, 0, 0)
+                        }
+                        SyscallTrap::Abort => {
+                            return Err(ExecutionError::UnsupportedSyscall(syscall_id))
+                        }
+                    }
+                } else {
+                    return Err(ExecutionError::UnsupportedSyscall(syscall_id));
+                };
 
-            // Jump instructions.
-            Opcode::Jump => {
-                (a, b, c, next_next_pc) = self.execute_jump(instruction);
-            }
-            Opcode::Jumpi => {
-                (a, b, c, next_next_pc) = self.execute_jumpi(instruction);
-            }
-            Opcode::JumpDirect => {
-                (a, b, c, next_next_pc) = self.execute_jump_direct(instruction);
+            // A metered cycle budget (see `SYSMETER` / `Executor::cycle_limit`) is checked here,
+            // before the syscall's extra cycles are spent, rather than only at the coarser
+            // per-instruction `max_cycles` check below -- a syscall like the BLS12-381 pairing
+            // precompile can single-handedly blow through a tight budget in one dispatch.
+            if let Some(limit) = self.cycle_limit {
+                if self.state.global_clk + u64::from(precompile_cycles) > limit {
+                    return Err(ExecutionError::CycleBudgetExceeded(limit));
+                }
             }
 
-            // Opcode::GetContext | Opcode::SetContext => {}
-            Opcode::NOP => {
-                self.rw(Register::ZERO, 0, MemoryAccessPosition::A);
+            if syscall == SyscallCode::HALT && returned_exit_code == 0 {
+                self.state.exited = true;
             }
 
-            Opcode::TEQ => {
-                (a, b, c) = self.execute_teq(instruction);
-            }
-            Opcode::UNIMPL => {
-                return Err(ExecutionError::UnsupportedInstruction(instruction.op_c));
-            }
+            // Allow the syscall impl to modify state.clk/pc (exit unconstrained does this)
+            clk = self.state.clk;
+            pc = self.state.pc;
+
+            self.rw(Register::V0, a, MemoryAccessPosition::A);
+            next_pc = precompile_next_pc;
+            self.state.clk += precompile_cycles;
+            exit_code = returned_exit_code;
+        } else if instruction.opcode == Opcode::UNIMPL {
+            return Err(ExecutionError::UnsupportedInstruction(instruction.raw.unwrap_or(instruction.op_c)));
+        } else {
+            // Every other opcode's logic lives in a precomputed, indexed dispatch table (see
+            // [`Self::dispatch_table`]) instead of a giant re-evaluated match, so the hot loop
+            // pays for one array index and one call rather than a branch per possible opcode.
+            // In `Simple` mode, [`Self::compiled_handlers`] has already paired this pc's slot
+            // with its handler (see [`Self::ensure_compiled_handlers`]), skipping the
+            // `opcode as u8 as usize` indirection into `dispatch_table` that `Trace`/`Checkpoint`
+            // still take.
+            let handler = match &self.compiled_handlers {
+                Some(handlers) if self.executor_mode == ExecutorMode::Simple => {
+                    handlers[((pc.wrapping_sub(self.program.pc_base)) / 4) as usize]
+                }
+                _ => self.dispatch_table[instruction.opcode as u8 as usize],
+            };
+            let outcome = handler(self, instruction, pc, next_pc, next_next_pc, lookup_id)?;
+            s1 = outcome.s1;
+            s2 = outcome.s2;
+            a = outcome.a;
+            b = outcome.b;
+            c = outcome.c;
+            next_next_pc = outcome.next_next_pc;
         }
 
         // Update the program counter.
         self.state.pc = next_pc;
         self.state.next_pc = next_next_pc;
 
+        // Track whether the *next* instruction will be sitting in this one's branch-delay slot,
+        // for `Cause`'s `BD` bit should it trap.
+        self.branch_delay_slot = matches!(
+            instruction.opcode,
+            Opcode::BEQ
+                | Opcode::BNE
+                | Opcode::BGEZ
+                | Opcode::BGTZ
+                | Opcode::BLEZ
+                | Opcode::BLTZ
+                | Opcode::BEQL
+                | Opcode::BNEL
+                | Opcode::BGEZL
+                | Opcode::BGTZL
+                | Opcode::BLEZL
+                | Opcode::BLTZL
+                | Opcode::Jump
+                | Opcode::Jumpi
+                | Opcode::JumpDirect
+        );
+
         // Update the clk to the next cycle.
         // todo: 5 -> 7 because of adding memory access position
         self.state.clk += 7;
@@ -1153,7 +1926,12 @@ impl<'a> Executor<'a> {
         Ok(())
     }
 
-    fn execute_teq(&mut self, instruction: &Instruction) -> (u32, u32, u32) {
+    fn execute_teq(
+        &mut self,
+        instruction: &Instruction,
+        pc: u32,
+        next_next_pc: u32,
+    ) -> (u32, u32, u32, u32) {
         let (rs, rt) = (
             (instruction.op_a as u8).into(),
             (instruction.op_b as u8).into(),
@@ -1163,9 +1941,43 @@ impl<'a> Executor<'a> {
         let src2 = self.rr(rt, MemoryAccessPosition::B);
 
         if src1 == src2 {
-            panic!("Trap Error");
+            let vector = self.raise_exception(ExcCode::Tr, pc, 0);
+            return (src1, src2, 0, vector);
+        }
+        (src1, src2, 0, next_next_pc)
+    }
+
+    /// `TNE`/`TGE`/`TGEU`/`TLT`/`TLTU` (register and immediate forms alike, see `alu_rr`): raise
+    /// the same `Tr` CP0 exception [`Self::execute_teq`] does, for whichever two-operand
+    /// condition `instruction.opcode` names.
+    fn execute_tcond(
+        &mut self,
+        instruction: &Instruction,
+        pc: u32,
+        next_next_pc: u32,
+    ) -> (u32, u32, u32, u32) {
+        let (_, b, c) = self.alu_rr(instruction);
+
+        let condition = match instruction.opcode {
+            Opcode::TNE => b != c,
+            Opcode::TGE => (b as i32) >= (c as i32),
+            Opcode::TGEU => b >= c,
+            Opcode::TLT => (b as i32) < (c as i32),
+            Opcode::TLTU => b < c,
+            _ => unreachable!("execute_tcond dispatched for non-trap-on-condition opcode"),
+        };
+
+        if condition {
+            let vector = self.raise_exception(ExcCode::Tr, pc, 0);
+            return (b, c, 0, vector);
         }
-        (src1, src2, 0)
+        (b, c, 0, next_next_pc)
+    }
+
+    /// Raise a CP0 exception: save `epc` and the `BD` bit to `Cause`, and return
+    /// [`crate::cp0::GENERAL_EXCEPTION_VECTOR`] for the caller to redirect `next_next_pc` to.
+    fn raise_exception(&mut self, exc_code: ExcCode, epc: u32, bad_vaddr: u32) -> u32 {
+        self.cp0.raise(exc_code, epc, bad_vaddr, self.branch_delay_slot)
     }
 
     fn execute_condmov(&mut self, instruction: &Instruction) -> (u32, u32, u32) {
@@ -1197,8 +2009,8 @@ impl<'a> Executor<'a> {
     ) -> (Option<u32>, u32, u32, u32) {
         let (rd, b, c) = self.alu_rr(instruction);
         let (a, hi) = match instruction.opcode {
-            Opcode::ADD => (b.overflowing_add(c).0, 0),
-            Opcode::SUB => (b.overflowing_sub(c).0, 0),
+            Opcode::ADD | Opcode::TADD => (b.overflowing_add(c).0, 0),
+            Opcode::SUB | Opcode::TSUB => (b.overflowing_sub(c).0, 0),
 
             Opcode::SLL => (b << (c & 0x1f), 0),
             Opcode::SRL => (b >> (c & 0x1F), 0),
@@ -1232,11 +2044,8 @@ impl<'a> Executor<'a> {
                 let out = b as u64 * c as u64;
                 (out as u32, (out >> 32) as u32) //lo,hi
             }
-            Opcode::DIV => (
-                ((b as i32) / (c as i32)) as u32, // lo
-                ((b as i32) % (c as i32)) as u32, // hi
-            ),
-            Opcode::DIVU => (b / c, b % c), //lo,hi
+            Opcode::DIV => Self::checked_divrem_signed(b as i32, c as i32),
+            Opcode::DIVU => Self::checked_divrem_unsigned(b, c),
             Opcode::AND => (b & c, 0),
             Opcode::OR => (b | c, 0),
             Opcode::XOR => (b ^ c, 0),
@@ -1251,6 +2060,231 @@ impl<'a> Executor<'a> {
         self.alu_rw(&instruction, rd, hi, a, b, c, lookup_id)
     }
 
+    /// Signed `(quotient, remainder)` for `DIV`, defined for every `(b, c)` pair so the guest can
+    /// never crash the prover by controlling the divisor.
+    ///
+    /// MIPS leaves `DIV`/`DIVU` by zero and `INT_MIN / -1` architecturally undefined; this fixes a
+    /// deterministic convention for both so every run (and the constraint system checking it)
+    /// agrees on LO/HI:
+    /// - Divide by zero: LO = `0xFFFF_FFFF` (the all-ones sentinel), HI = `b`.
+    /// - `INT_MIN / -1`: LO = `INT_MIN` (the wrapped quotient), HI = `0`.
+    fn checked_divrem_signed(b: i32, c: i32) -> (u32, u32) {
+        if c == 0 {
+            return (0xFFFF_FFFF, b as u32);
+        }
+        if b == i32::MIN && c == -1 {
+            return (i32::MIN as u32, 0);
+        }
+        ((b / c) as u32, (b % c) as u32)
+    }
+
+    /// Unsigned `(quotient, remainder)` for `DIVU`, using the same divide-by-zero convention as
+    /// [`Self::checked_divrem_signed`] (unsigned division has no `INT_MIN / -1` case).
+    fn checked_divrem_unsigned(b: u32, c: u32) -> (u32, u32) {
+        if c == 0 {
+            return (0xFFFF_FFFF, b);
+        }
+        (b / c, b % c)
+    }
+
+    /// `LWC1 ft, offset(rs)` / `LDC1 ft, offset(rs)`: load a single/double word from memory into
+    /// FP register `ft`. Unlike general loads, this writes [`Self::cop1`] directly rather than
+    /// returning a value for the generic `rw` path, since `ft` indexes the FP register file, not
+    /// the GPR one.
+    fn execute_fp_load(&mut self, instruction: &Instruction) -> Result<(), ExecutionError> {
+        let (ft, rs_reg, offset_ext) = (
+            instruction.op_a as usize,
+            (instruction.op_b as u8).into(),
+            instruction.op_c,
+        );
+        let rs_raw = self.rr(rs_reg, MemoryAccessPosition::B);
+        let virt = rs_raw.wrapping_add(offset_ext) & 0xFFFF_FFFC;
+
+        let low = self.mr_cpu(virt, MemoryAccessPosition::Memory);
+        self.cop1.fpr.write_raw_low(ft, low);
+        if instruction.opcode == Opcode::LDC1 {
+            let high = self.mr_cpu(virt.wrapping_add(4), MemoryAccessPosition::S1);
+            self.cop1.fpr.write_raw_high(ft, high);
+        }
+        Ok(())
+    }
+
+    /// `SWC1 ft, offset(rs)` / `SDC1 ft, offset(rs)`: store FP register `ft`'s single/double value
+    /// to memory. See [`Self::execute_fp_load`] for why this bypasses the generic store path.
+    fn execute_fp_store(&mut self, instruction: &Instruction) -> Result<(), ExecutionError> {
+        let (ft, rs_reg, offset_ext) = (
+            instruction.op_a as usize,
+            (instruction.op_b as u8).into(),
+            instruction.op_c,
+        );
+        let rs_raw = self.rr(rs_reg, MemoryAccessPosition::B);
+        let virt = rs_raw.wrapping_add(offset_ext) & 0xFFFF_FFFC;
+
+        let low = self.cop1.fpr.read_raw_low(ft);
+        self.mw_cpu(virt, low, MemoryAccessPosition::Memory);
+        if instruction.opcode == Opcode::SDC1 {
+            let high = self.cop1.fpr.read_raw_high(ft);
+            self.mw_cpu(virt.wrapping_add(4), high, MemoryAccessPosition::S1);
+        }
+        Ok(())
+    }
+
+    /// COP1 arithmetic/compare/convert: `op_a`/`op_b`/`op_c` index the FP register file (`fd`,
+    /// `fs`, `ft`) rather than the GPRs. Compares write [`crate::cop1::Fcsr`]'s condition-code 0
+    /// instead of a destination register.
+    fn execute_fp_alu(&mut self, instruction: &Instruction) {
+        let (fd, fs, ft) = (
+            instruction.op_a as usize,
+            instruction.op_b as usize,
+            instruction.op_c as usize,
+        );
+
+        let (a, b, c): (u64, u64, u64) = match instruction.opcode {
+            Opcode::FADD_S | Opcode::FSUB_S | Opcode::FMUL_S | Opcode::FDIV_S => {
+                let (x, y) = (self.cop1.fpr.read_single(fs), self.cop1.fpr.read_single(ft));
+                let result = match instruction.opcode {
+                    Opcode::FADD_S => x + y,
+                    Opcode::FSUB_S => x - y,
+                    Opcode::FMUL_S => x * y,
+                    Opcode::FDIV_S => x / y,
+                    _ => unreachable!(),
+                };
+                self.cop1.fpr.write_single(fd, result);
+                (u64::from(result.to_bits()), u64::from(x.to_bits()), u64::from(y.to_bits()))
+            }
+            Opcode::FADD_D | Opcode::FSUB_D | Opcode::FMUL_D | Opcode::FDIV_D => {
+                let (x, y) = (self.cop1.fpr.read_double(fs), self.cop1.fpr.read_double(ft));
+                let result = match instruction.opcode {
+                    Opcode::FADD_D => x + y,
+                    Opcode::FSUB_D => x - y,
+                    Opcode::FMUL_D => x * y,
+                    Opcode::FDIV_D => x / y,
+                    _ => unreachable!(),
+                };
+                self.cop1.fpr.write_double(fd, result);
+                (result.to_bits(), x.to_bits(), y.to_bits())
+            }
+            Opcode::FC_EQ_S | Opcode::FC_LT_S => {
+                let (x, y) = (self.cop1.fpr.read_single(fs), self.cop1.fpr.read_single(ft));
+                let holds = match instruction.opcode {
+                    Opcode::FC_EQ_S => x == y,
+                    Opcode::FC_LT_S => x < y,
+                    _ => unreachable!(),
+                };
+                self.cop1.fcsr.set_condition(0, holds);
+                (u64::from(holds), u64::from(x.to_bits()), u64::from(y.to_bits()))
+            }
+            Opcode::FCVT_S_W => {
+                let x = self.cop1.fpr.read_raw_low(fs) as i32;
+                let result = x as f32;
+                self.cop1.fpr.write_single(fd, result);
+                (u64::from(result.to_bits()), u64::from(x as u32), 0)
+            }
+            Opcode::FCVT_W_S => {
+                let x = self.cop1.fpr.read_single(fs);
+                let result = x as i32 as u32;
+                self.cop1.fpr.write_raw_low(fd, result);
+                (u64::from(result), u64::from(x.to_bits()), 0)
+            }
+            _ => unreachable!(),
+        };
+
+        if self.executor_mode == ExecutorMode::Trace {
+            let nonce = self.record.fp_alu_events.len() as u32;
+            self.record.fp_alu_events.push(FpAluEvent::new(
+                self.shard(),
+                self.state.clk,
+                instruction.opcode,
+                a,
+                b,
+                c,
+                nonce,
+            ));
+        }
+    }
+
+    /// Builds a [`crate::diagnostics::Fault`] from `error`, if it's one of
+    /// [`crate::diagnostics::FaultKind`]'s variants, capturing the instruction's relevant
+    /// registers and -- if [`Self::symbol_resolver`] is installed -- the guest source location.
+    /// Returns `None` for errors that aren't addressing/decoding faults in that sense
+    /// (breakpoints, pauses, unsupported syscalls, ...), which have no useful snippet to render.
+    fn explain_fault(
+        &self,
+        pc: u32,
+        instruction: &Instruction,
+        error: &ExecutionError,
+    ) -> Option<crate::diagnostics::Fault> {
+        let kind = crate::diagnostics::FaultKind::from_error(error)?;
+        let disasm_line = crate::disasm::disassemble_instruction(instruction, pc);
+
+        let rs_register = |op_b: u32| {
+            let name = crate::disasm::ABI_REGISTER_NAMES
+                .get(op_b as usize)
+                .copied()
+                .unwrap_or("?");
+            let value = self.state.memory.get(op_b).map_or(0, |record| record.value);
+            (name, value)
+        };
+
+        let (registers, spans) = match kind {
+            crate::diagnostics::FaultKind::InvalidMemoryAccess { addr, .. } => (
+                vec![rs_register(instruction.op_b)],
+                vec![crate::diagnostics::Span::trailing_operand(
+                    &disasm_line,
+                    format!("invalid address {addr:#010x}"),
+                )],
+            ),
+            crate::diagnostics::FaultKind::MemoryAlignment { addr, .. } => (
+                vec![rs_register(instruction.op_b)],
+                vec![crate::diagnostics::Span::trailing_operand(
+                    &disasm_line,
+                    format!("misaligned address {addr:#010x}"),
+                )],
+            ),
+            crate::diagnostics::FaultKind::UnsupportedInstruction { .. } => (vec![], vec![]),
+        };
+
+        Some(crate::diagnostics::Fault {
+            pc,
+            kind,
+            instruction: *instruction,
+            registers,
+            spans,
+            source: self.symbol_resolver.as_ref().and_then(|resolver| resolver.resolve(pc)),
+        })
+    }
+
+    /// If [`Self::strict_memory_alignment`] is set, check that `virt_raw` is aligned to the
+    /// access size `opcode` naturally requires (4 bytes for word ops, 2 for halfword), returning
+    /// [`ExecutionError::MemoryAlignment`] otherwise. Byte ops and the unaligned-by-design
+    /// `LWL`/`LWR`/`SWL`/`SWR` have no natural alignment requirement and always pass.
+    /// Checks that a load/store's virtual address is aligned to its access width.
+    ///
+    /// This raises a hard [`ExecutionError::MemoryAlignment`] rather than a continuable,
+    /// provable CP0 trap (`TrapCause::LoadAddressError`/`StoreAddressError`, raised the same way
+    /// [`Self::execute_teq`] raises `ExcCode::Tr`) -- [`crate::diagnostics::FaultKind::MemoryAlignment`]
+    /// depends on this `Err` path to report the fault, so redirecting it through
+    /// [`Self::raise_exception`] to make unaligned loads/stores provable (instead of aborting) is
+    /// left for a follow-up that updates that diagnostics consumer too.
+    fn check_memory_alignment(
+        &self,
+        opcode: Opcode,
+        virt_raw: u32,
+    ) -> Result<(), ExecutionError> {
+        if !self.strict_memory_alignment {
+            return Ok(());
+        }
+        let required_mask = match opcode {
+            Opcode::LW | Opcode::LL | Opcode::SW | Opcode::SC => 0x3,
+            Opcode::LH | Opcode::LHU | Opcode::SH => 0x1,
+            _ => return Ok(()),
+        };
+        if virt_raw & required_mask != 0 {
+            return Err(ExecutionError::MemoryAlignment(opcode, virt_raw));
+        }
+        Ok(())
+    }
+
     fn execute_load(
         &mut self,
         instruction: &Instruction,
@@ -1266,6 +2300,7 @@ impl<'a> Executor<'a> {
         let rt = self.register(rt_reg);
 
         let virt_raw = rs_raw.wrapping_add(offset_ext);
+        self.check_memory_alignment(instruction.opcode, virt_raw)?;
         let virt = virt_raw & 0xFFFF_FFFC;
 
         let mem = self.mr_cpu(virt, MemoryAccessPosition::Memory);
@@ -1301,7 +2336,10 @@ impl<'a> Executor<'a> {
                 };
                 out(rs & 3)
             }
-            Opcode::LL => mem,
+            Opcode::LL => {
+                self.reservation = Some(virt);
+                mem
+            }
             Opcode::LB => {
                 let out = |i: u32| -> u32 { sign_extend::<8>((mem >> (i * 8)) & 0xff) };
                 out(rs & 3)
@@ -1331,10 +2369,23 @@ impl<'a> Executor<'a> {
         };
 
         let virt_raw = rs.wrapping_add(offset_ext);
+        self.check_memory_alignment(instruction.opcode, virt_raw)?;
         let virt = virt_raw & 0xFFFF_FFFC;
 
         let mem = self.word(virt);
 
+        // `SC` succeeds only if the reservation `LL` set is still live for this exact word; it
+        // clears the reservation either way. Any other store that happens to touch the reserved
+        // word invalidates it too, since the memory underneath the reservation has changed.
+        let sc_succeeds = self.reservation == Some(virt);
+        if instruction.opcode == Opcode::SC || self.reservation == Some(virt) {
+            self.reservation = None;
+        }
+        if instruction.opcode == Opcode::SC && !sc_succeeds {
+            self.rw(rt_reg, 0, MemoryAccessPosition::A);
+            return Ok((0, rs, offset_ext));
+        }
+
         let val = match instruction.opcode {
             Opcode::SB => {
                 let out = |i: u32| -> u32 {
@@ -1370,7 +2421,6 @@ impl<'a> Executor<'a> {
                 out(virt_raw & 3)
             }
             Opcode::SC => rt,
-            Opcode::SDC1 => 0,
             _ => todo!(),
         };
         self.mw_cpu(
@@ -1395,12 +2445,12 @@ impl<'a> Executor<'a> {
     ) -> (u32, u32, u32, u32) {
         let (src1, src2, target_pc) = self.branch_rr(instruction);
         let should_jump = match instruction.opcode {
-            Opcode::BEQ => src1 == src2,
-            Opcode::BNE => src1 != src2,
-            Opcode::BGEZ => (src1 as i32) >= 0,
-            Opcode::BLEZ => (src1 as i32) <= 0,
-            Opcode::BGTZ => (src1 as i32) > 0,
-            Opcode::BLTZ => (src1 as i32) < 0,
+            Opcode::BEQ | Opcode::BEQL => src1 == src2,
+            Opcode::BNE | Opcode::BNEL => src1 != src2,
+            Opcode::BGEZ | Opcode::BGEZL => (src1 as i32) >= 0,
+            Opcode::BLEZ | Opcode::BLEZL => (src1 as i32) <= 0,
+            Opcode::BGTZ | Opcode::BGTZL => (src1 as i32) > 0,
+            Opcode::BLTZ | Opcode::BLTZL => (src1 as i32) < 0,
             _ => {
                 unreachable!()
             }
@@ -1408,6 +2458,10 @@ impl<'a> Executor<'a> {
 
         if should_jump {
             next_next_pc = target_pc.wrapping_add(next_pc);
+        } else if instruction.opcode.is_branch_likely() {
+            // The delay slot at `next_pc` is nullified rather than executed: flag it so the
+            // next `execute_operation` call skips its effects entirely.
+            self.nullify_delay_slot = true;
         }
         (src1, src2, target_pc, next_next_pc)
     }
@@ -1451,23 +2505,79 @@ impl<'a> Executor<'a> {
     /// Executes one cycle of the program, returning whether the program has finished.
     #[inline]
     #[allow(clippy::too_many_lines)]
-    fn execute_cycle(&mut self) -> Result<bool, ExecutionError> {
+    pub(crate) fn execute_cycle(&mut self) -> Result<bool, ExecutionError> {
+        // Stop before fetching if `pc` is a breakpoint, unless `step` asked to execute through it
+        // this one time. See [`Self::breakpoints`].
+        if self.breakpoints.contains(&self.state.pc) && !std::mem::take(&mut self.skip_breakpoint_once) {
+            return Err(ExecutionError::Breakpoint());
+        }
+
         // Fetch the instruction at the current program counter.
         let instruction = self.fetch();
+        let pc = self.state.pc;
 
         // Log the current state of the runtime.
         #[cfg(debug_assertions)]
         self.log(&instruction);
 
+        #[cfg(debug_assertions)]
+        let trace_before = self.capture_trace_before(&instruction);
+
         // Execute the instruction.
-        self.execute_operation(&instruction)?;
+        if let Err(error) = self.execute_operation(&instruction) {
+            self.last_fault = self.explain_fault(pc, &instruction, &error);
+            return Err(error);
+        }
+
+        #[cfg(debug_assertions)]
+        self.emit_instruction_trace(pc, &instruction, trace_before);
+
+        // A hook-invoking syscall parked itself on `request_hook` instead of completing: bail
+        // out before any of the bookkeeping below runs, so `pc`/`clk` are exactly where they
+        // were before this cycle started and the instruction can be retried untouched once
+        // `resume` supplies the response.
+        if self.pending_hook.is_some() {
+            return Ok(false);
+        }
+
+        // If this instruction read from memory that was never written and never initialized,
+        // record it (and abort, if configured to treat it as fatal).
+        if let Some(addr) = self.pending_uninitialized_read.take() {
+            self.report.uninitialized_reads.push((instruction.opcode, addr));
+            if self.uninitialized_reads_are_fatal {
+                return Err(ExecutionError::UninitializedRead(instruction.opcode, addr));
+            }
+        }
 
         // Increment the clock.
         self.state.global_clk += 1;
 
+        // Every `tick_quotient` cycles, give the host's tick callback a chance to report
+        // progress, enforce a time budget, or cut a shard without the guest cooperating. See
+        // [`Self::tick_quotient`].
+        let mut tick_force_shard_boundary = false;
+        if self.tick_quotient != 0 && self.state.global_clk as usize % self.tick_quotient == 0 {
+            if let Some(callback) = self.tick_callback.as_mut() {
+                match callback(&self.state) {
+                    TickAction::Continue => {}
+                    TickAction::ForceShardBoundary => tick_force_shard_boundary = true,
+                    TickAction::Pause => return Err(ExecutionError::Paused(self.state.global_clk)),
+                }
+            }
+        }
+
         if !self.unconstrained {
             // If there's not enough cycles left for another instruction, move to the next shard.
-            let cpu_exit = self.max_syscall_cycles + self.state.clk >= self.shard_size;
+            // The uniform `clk` count is what the AIR constraints actually key off of, so it's
+            // always checked; `weighted_shard_size`, if configured, additionally closes the shard
+            // early once `self.cycle_cost_model`'s per-opcode costs say it's carrying as much
+            // trace-column pressure as a shard should (div/mul chips are expensive), without
+            // perturbing `clk` itself. See [`crate::context::ZKMContext::weighted_shard_size`].
+            let cpu_exit = self.max_syscall_cycles + self.state.clk >= self.shard_size
+                || tick_force_shard_boundary
+                || self
+                    .weighted_shard_size
+                    .is_some_and(|limit| self.weighted_clk_since_shard_start >= limit);
             // println!("cpu exit {cpu_exit}, {} {}, {}", self.max_syscall_cycles, self.state.clk, self.shard_size);
 
             // Every N cycles, check if there exists at least one shape that fits.
@@ -1602,6 +2712,7 @@ impl<'a> Executor<'a> {
             if cpu_exit || !shape_match_found {
                 self.state.current_shard += 1;
                 self.state.clk = 0;
+                self.weighted_clk_since_shard_start = 0;
                 self.report.event_counts = Box::default();
                 self.bump_record();
             }
@@ -1614,6 +2725,14 @@ impl<'a> Executor<'a> {
             }
         }
 
+        // Likewise for the metered `cycle_limit` budget (see `SYSMETER`), so a plain instruction
+        // crossing the ceiling aborts the same way a syscall doing so does above.
+        if let Some(limit) = self.cycle_limit {
+            if self.state.global_clk >= limit {
+                return Err(ExecutionError::CycleBudgetExceeded(limit));
+            }
+        }
+
         // todo: check done
         let done = self.state.pc == 0
             || self.state.exited
@@ -1632,12 +2751,28 @@ impl<'a> Executor<'a> {
 
     /// Bump the record.
     pub fn bump_record(&mut self) {
-        // Copy all of the existing local memory accesses to the record's local_memory_access vec.
+        // Fold the shard's raw access log into deduplicated `MemoryLocalEvent`s -- keeping the
+        // first-seen initial access and the last-seen final access per address -- in one pass,
+        // then copy them to the record's local_memory_access vec. Then reset the log with its
+        // shard-sized capacity rather than an empty one, so the next shard doesn't immediately
+        // start re-growing it one access at a time.
         if self.executor_mode == ExecutorMode::Trace {
-            for (_, event) in self.local_memory_access.drain() {
+            let mut folded: HashMap<u32, MemoryLocalEvent> =
+                HashMap::with_capacity(self.local_memory_access_log.len());
+            for (addr, initial_mem_access, final_mem_access) in
+                self.local_memory_access_log.drain(..)
+            {
+                folded
+                    .entry(addr)
+                    .and_modify(|e| e.final_mem_access = final_mem_access)
+                    .or_insert(MemoryLocalEvent { addr, initial_mem_access, final_mem_access });
+            }
+            self.record.cpu_local_memory_access.reserve(folded.len());
+            for (_, event) in folded {
                 self.record.cpu_local_memory_access.push(event);
             }
         }
+        self.local_memory_access_log = Vec::with_capacity(self.local_memory_access_log_capacity);
 
         let removed_record =
             std::mem::replace(&mut self.record, ExecutionRecord::new(self.program.clone()));
@@ -1750,6 +2885,7 @@ impl<'a> Executor<'a> {
     pub fn run_very_fast(&mut self) -> Result<(), ExecutionError> {
         self.executor_mode = ExecutorMode::Simple;
         self.print_report = false;
+        self.ensure_compiled_handlers();
         while !self.execute()? {}
         Ok(())
     }
@@ -1762,6 +2898,7 @@ impl<'a> Executor<'a> {
     pub fn run_fast(&mut self) -> Result<(), ExecutionError> {
         self.executor_mode = ExecutorMode::Simple;
         self.print_report = true;
+        self.ensure_compiled_handlers();
         while !self.execute()? {}
         Ok(())
     }
@@ -1778,6 +2915,198 @@ impl<'a> Executor<'a> {
         Ok(())
     }
 
+    /// Executes cycles in [`ExecutorMode::Trace`] until the program halts, the current shard
+    /// batch fills, or execution parks on a hook that's awaiting a response from the host, so a
+    /// host that fetches that response asynchronously (an oracle lookup, a proof input fetched
+    /// over the network) doesn't have to block the execution thread to answer it.
+    ///
+    /// Unlike [`Executor::execute`], this never blocks inside `hook_registry`: a hook-invoking
+    /// syscall with no buffered response instead parks itself via [`Executor::request_hook`]
+    /// without mutating any other state, so the instruction it's on can be safely retried, not
+    /// replayed, once [`Executor::resume`] is called. That means the pause is bit-identical to
+    /// uninterrupted execution and never double-emits the `CpuEvent` for the parked instruction.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the program execution fails.
+    pub fn run_until_yield(&mut self) -> Result<ExecutionPause, ExecutionError> {
+        self.executor_mode = ExecutorMode::Trace;
+        self.print_report = true;
+
+        // Initialize the nonce lookup table if it's uninitialized.
+        if self.record.nonce_lookup.len() <= 2 {
+            self.record.nonce_lookup = vec![0; self.opts.shard_size * 32];
+        }
+
+        // If it's the first cycle, initialize the program.
+        if self.state.global_clk == 0 {
+            self.initialize();
+        }
+
+        let mut current_shard = self.state.current_shard;
+        let mut num_shards_executed = 0;
+        loop {
+            let done = self.execute_cycle()?;
+
+            if let Some((fd, request)) = self.pending_hook.take() {
+                return Ok(ExecutionPause::AwaitingHook { fd, request });
+            }
+
+            if done {
+                self.postprocess();
+                self.bump_record();
+                return Ok(ExecutionPause::Halted);
+            }
+
+            if self.shard_batch_size > 0 && current_shard != self.state.current_shard {
+                num_shards_executed += 1;
+                current_shard = self.state.current_shard;
+                if num_shards_executed == self.shard_batch_size {
+                    return Ok(ExecutionPause::ShardFull);
+                }
+            }
+        }
+    }
+
+    /// Resumes execution parked by an `ExecutionPause::AwaitingHook`, feeding `response` back to
+    /// the syscall that requested it.
+    ///
+    /// Call [`Executor::run_until_yield`] again afterwards to continue; the parked instruction
+    /// retries from the top and picks up `response` via [`Executor::request_hook`].
+    pub fn resume(&mut self, response: Vec<u8>) {
+        self.queued_hook_response = Some(response);
+    }
+
+    /// Executes exactly one cycle, so a debugger can inspect `state.memory`, registers, and the
+    /// in-progress [`crate::ExecutionRecord`] in between instructions. Always executes the
+    /// instruction at the current `pc`, even if it's in [`Self::breakpoints`] — the breakpoint
+    /// that's already been stopped on shouldn't block the explicit step past it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the program execution fails.
+    pub fn step(&mut self) -> Result<bool, ExecutionError> {
+        if self.record.nonce_lookup.len() <= 2 {
+            self.record.nonce_lookup = vec![0; self.opts.shard_size * 32];
+        }
+        if self.state.global_clk == 0 {
+            self.initialize();
+        }
+        self.skip_breakpoint_once = true;
+        self.execute_cycle()
+    }
+
+    /// Executes cycles until the program halts or `pc` reaches an address in
+    /// [`Self::breakpoints`], useful for debugging guest programs that only fail many shards in,
+    /// without tracing every cycle up to that point.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the program execution fails for a reason other than
+    /// hitting a breakpoint.
+    pub fn continue_until_break(&mut self) -> Result<ExecutionOutcome, ExecutionError> {
+        if self.record.nonce_lookup.len() <= 2 {
+            self.record.nonce_lookup = vec![0; self.opts.shard_size * 32];
+        }
+        if self.state.global_clk == 0 {
+            self.initialize();
+        }
+        loop {
+            match self.execute_cycle() {
+                Ok(true) => return Ok(ExecutionOutcome::Halted),
+                Ok(false) => {}
+                Err(ExecutionError::Breakpoint()) => {
+                    return Ok(ExecutionOutcome::Breakpoint(self.state.pc))
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Adds a watchpoint on `register`, snapshotting its current value so the first check in
+    /// [`Executor::run_until_break`] compares against what it holds right now, not `0`.
+    pub fn watch_register(&mut self, register: Register) {
+        let last_value = self.register(register);
+        self.watchpoints.push(Watchpoint { target: WatchTarget::Register(register), last_value });
+    }
+
+    /// Adds a watchpoint on the memory word at `addr`, snapshotting its current value the same
+    /// way [`Executor::watch_register`] does for a register.
+    pub fn watch_memory(&mut self, addr: u32) {
+        let last_value = self.word(addr);
+        self.watchpoints.push(Watchpoint { target: WatchTarget::Memory(addr), last_value });
+    }
+
+    /// Re-reads every [`Watchpoint`] target, updating [`Self::watchpoints`] in place and
+    /// returning the first one whose value changed since it was last checked. `pc` is the
+    /// program counter of the instruction that just executed, reported on the returned event.
+    fn check_watchpoints(&mut self, pc: u32) -> Option<DebugEvent> {
+        for i in 0..self.watchpoints.len() {
+            let target = self.watchpoints[i].target;
+            let old = self.watchpoints[i].last_value;
+            let new = match target {
+                WatchTarget::Register(register) => self.register(register),
+                WatchTarget::Memory(addr) => self.word(addr),
+            };
+            if new != old {
+                self.watchpoints[i].last_value = new;
+                return Some(DebugEvent::Watchpoint { pc, target, old, new });
+            }
+        }
+        None
+    }
+
+    /// Executes cycles until the program halts, `pc` reaches a [`Self::breakpoints`] address, or
+    /// a [`Self::watchpoints`] entry changes value, returning which of those stopped it. Unlike
+    /// [`Executor::continue_until_break`], a watchpoint hit stops execution *after* the
+    /// instruction that caused it, since the whole point is to see the new value.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the program execution fails for a reason other than
+    /// hitting a breakpoint.
+    pub fn run_until_break(&mut self) -> Result<DebugEvent, ExecutionError> {
+        if self.record.nonce_lookup.len() <= 2 {
+            self.record.nonce_lookup = vec![0; self.opts.shard_size * 32];
+        }
+        if self.state.global_clk == 0 {
+            self.initialize();
+        } else if self.state.pc == 0
+            || self.state.exited
+            || self.state.pc.wrapping_sub(self.program.pc_base)
+                >= (self.program.instructions.len() * 4) as u32
+        {
+            return Ok(DebugEvent::Halt);
+        }
+        loop {
+            let pc = self.state.pc;
+            match self.execute_cycle() {
+                Ok(done) => {
+                    if let Some(event) = self.check_watchpoints(pc) {
+                        return Ok(event);
+                    }
+                    if done {
+                        return Ok(DebugEvent::Halt);
+                    }
+                }
+                Err(ExecutionError::Breakpoint()) => return Ok(DebugEvent::Breakpoint(self.state.pc)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Reads the full general-purpose register file, in [`Register`] order.
+    #[must_use]
+    pub fn register_file(&mut self) -> [u32; 32] {
+        std::array::from_fn(|i| self.register(Register::from_u8(i as u8)))
+    }
+
+    /// Reads `len` bytes of memory starting at `addr`, one [`Executor::byte`] at a time.
+    #[must_use]
+    pub fn read_memory_range(&mut self, addr: u32, len: u32) -> Vec<u8> {
+        (0..len).map(|i| self.byte(addr + i)).collect()
+    }
+
     /// Executes up to `self.shard_batch_size` cycles of the program, returning whether the program
     /// has finished.
     pub fn execute(&mut self) -> Result<bool, ExecutionError> {
@@ -1954,7 +3283,7 @@ impl<'a> Executor<'a> {
 
     #[inline]
     #[cfg(debug_assertions)]
-    fn log(&mut self, _: &Instruction) {
+    fn log(&mut self, instruction: &Instruction) {
         // Write the current program counter to the trace buffer for the cycle tracer.
         if let Some(ref mut buf) = self.trace_buf {
             if !self.unconstrained {
@@ -1962,6 +3291,21 @@ impl<'a> Executor<'a> {
             }
         }
 
+        if let Some(ref mut profiler) = self.profiler {
+            if !self.unconstrained {
+                profiler.sample(self.state.global_clk, self.state.pc, instruction);
+            }
+        }
+
+        if self.verbose_trace && !self.unconstrained {
+            log::info!(
+                "{} {:#x}: {}",
+                self.state.global_clk,
+                self.state.pc,
+                crate::disasm::disassemble_instruction(instruction, self.state.pc)
+            );
+        }
+
         if !self.unconstrained && self.state.global_clk % 10_000_000 == 0 {
             log::info!(
                 "clk = {} pc = 0x{:x?}",
@@ -1972,14 +3316,54 @@ impl<'a> Executor<'a> {
     }
 
     fn show_regs(&self) {
-        let regs = (0..34)
-            .map(|i| self.state.memory.get(i).unwrap().value)
+        let mut regs = (0..32)
+            .map(|i| {
+                format!(
+                    "{}={:#x}",
+                    crate::disasm::ABI_REGISTER_NAMES[i as usize],
+                    self.state.memory.get(i).unwrap().value
+                )
+            })
             .collect::<Vec<_>>();
+        regs.push(format!("lo={:#x}", self.state.memory.get(32).unwrap().value));
+        regs.push(format!("hi={:#x}", self.state.memory.get(33).unwrap().value));
         println!(
-            "global_clk: {}, pc: {}, regs {:?}",
-            self.state.global_clk, self.state.pc, regs
+            "global_clk: {}, pc: {:#x}: {}",
+            self.state.global_clk,
+            self.state.pc,
+            regs.join(", ")
         );
     }
+
+    /// Snapshots `instruction`'s result register (if any) before it executes, for
+    /// [`Self::emit_instruction_trace`] to diff against afterward.
+    #[cfg(debug_assertions)]
+    fn capture_trace_before(&self, instruction: &Instruction) -> Option<(u8, u32)> {
+        if self.unconstrained || self.instruction_trace.is_none() {
+            return None;
+        }
+        crate::trace::result_register(instruction).map(|reg| {
+            let before = self.state.memory.get(u32::from(reg)).map_or(0, |record| record.value);
+            (reg, before)
+        })
+    }
+
+    /// Emits one line to [`Self::instruction_trace`], if installed, for the instruction that just
+    /// executed at `pc`. `before` is the result register's pre-execution value, captured by
+    /// [`Self::capture_trace_before`] prior to [`Self::execute_operation`].
+    #[cfg(debug_assertions)]
+    fn emit_instruction_trace(&mut self, pc: u32, instruction: &Instruction, before: Option<(u8, u32)>) {
+        if self.unconstrained || self.instruction_trace.is_none() {
+            return;
+        }
+        let delta = before.map(|(reg, before)| crate::trace::RegisterDelta {
+            register: u32::from(reg),
+            before,
+            after: self.state.memory.get(u32::from(reg)).map_or(0, |record| record.value),
+        });
+        let line = crate::trace::format_trace_line(pc, instruction, delta, self.instruction_trace_color);
+        self.instruction_trace.as_mut().unwrap().write_line(&line);
+    }
 }
 
 impl Default for ExecutorMode {
@@ -2070,6 +3454,61 @@ mod tests {
         runtime.run().unwrap();
     }
 
+    #[test]
+    fn test_continue_until_break() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 1, false, true),
+            Instruction::new(Opcode::ADD, 29, 29, 1, false, true),
+            Instruction::new(Opcode::ADD, 29, 29, 1, false, true),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, ZKMCoreOpts::default());
+        runtime.breakpoints.insert(8); // the third instruction's pc
+
+        let outcome = runtime.continue_until_break().unwrap();
+        assert_eq!(outcome, super::ExecutionOutcome::Breakpoint(8));
+        assert_eq!(runtime.register(Register::X29), 2);
+
+        // `step` executes through the breakpoint it's currently sitting on.
+        runtime.step().unwrap();
+        assert_eq!(runtime.register(Register::X29), 3);
+    }
+
+    #[test]
+    fn test_run_until_break_watchpoint() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 1, false, true),
+            Instruction::new(Opcode::ADD, 29, 29, 1, false, true),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, ZKMCoreOpts::default());
+        runtime.watch_register(Register::X29);
+
+        let event = runtime.run_until_break().unwrap();
+        assert_eq!(
+            event,
+            super::DebugEvent::Watchpoint {
+                pc: 0,
+                target: super::WatchTarget::Register(Register::X29),
+                old: 0,
+                new: 1,
+            }
+        );
+
+        let event = runtime.run_until_break().unwrap();
+        assert_eq!(
+            event,
+            super::DebugEvent::Watchpoint {
+                pc: 4,
+                target: super::WatchTarget::Register(Register::X29),
+                old: 1,
+                new: 2,
+            }
+        );
+
+        assert_eq!(runtime.run_until_break().unwrap(), super::DebugEvent::Halt);
+    }
+
     #[test]
     fn test_beq_jump() {
         let instructions = vec![
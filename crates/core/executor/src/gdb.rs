@@ -0,0 +1,215 @@
+//! A minimal GDB remote serial protocol (RSP) stub for stepping a running [`Executor`].
+//!
+//! This lets a standard GDB client (`target remote host:port`) single-step MIPS execution, set
+//! breakpoints, and inspect registers/memory before the expensive proving phase, instead of the
+//! zkVM being an opaque box that only reports "the guest panicked somewhere".
+//!
+//! Debugger-initiated reads go through the executor's non-recording accessors ([`Executor::word`],
+//! [`Executor::register`]) so that attaching a debugger never perturbs `local_memory_access` or
+//! the [`crate::ExecutionRecord`] being built for the current cycle.
+
+use std::{
+    io::{Read, Write},
+    net::{TcpListener, TcpStream},
+};
+
+use crate::{Executor, ExecutorMode};
+
+/// Number of general-purpose MIPS registers GDB expects in a `g` packet reply.
+const NUM_REGISTERS: usize = 32;
+
+/// Upper bound on an `m addr,length` packet's `length`, the same guest/attacker-controlled-length-
+/// clamp convention `syscalls::keccak_sponge::MAX_KECCAK_SPONGE_WORDS` and friends use -- far more
+/// than any real debugger session needs to inspect at once, but small enough to reject a
+/// multi-gigabyte allocation attempt outright.
+const MAX_GDB_READ_LEN: u32 = 1 << 16;
+
+/// Drives an [`Executor`] from a GDB client speaking the remote serial protocol over TCP.
+pub struct GdbStub<'a, 'b> {
+    executor: &'a mut Executor<'b>,
+}
+
+impl<'a, 'b> GdbStub<'a, 'b> {
+    /// Wrap an executor for debugging. Puts the executor into [`ExecutorMode::Debug`].
+    pub fn new(executor: &'a mut Executor<'b>) -> Self {
+        executor.executor_mode = ExecutorMode::Debug;
+        Self { executor }
+    }
+
+    /// Listen on `addr` for a single GDB client and serve it until it disconnects or the program
+    /// halts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the socket can't be bound, or on I/O failure talking to the client.
+    pub fn serve(&mut self, addr: &str) -> std::io::Result<()> {
+        let listener = TcpListener::bind(addr)?;
+        let (stream, _) = listener.accept()?;
+        self.handle_client(stream)
+    }
+
+    fn handle_client(&mut self, mut stream: TcpStream) -> std::io::Result<()> {
+        let mut buf = [0u8; 4096];
+        loop {
+            let Some(packet) = read_packet(&mut stream, &mut buf)? else {
+                return Ok(());
+            };
+            // Every well-formed packet is acknowledged before the reply.
+            stream.write_all(b"+")?;
+            let reply = self.dispatch(&packet);
+            write_packet(&mut stream, &reply)?;
+        }
+    }
+
+    /// Dispatch one decoded RSP command, returning the (unframed) reply payload.
+    fn dispatch(&mut self, packet: &str) -> String {
+        let (cmd, rest) = packet.split_at(1);
+        match cmd {
+            "?" => "S05".to_string(),
+            "g" => self.read_registers(),
+            "G" => {
+                self.write_registers(rest);
+                "OK".to_string()
+            }
+            "m" => self.read_memory(rest).unwrap_or_else(|| "E01".to_string()),
+            "M" => self.write_memory(rest).unwrap_or_else(|| "E01".to_string()),
+            "c" => self.resume(None),
+            "s" => self.resume(Some(1)),
+            "Z" => self.insert_breakpoint(rest),
+            "z" => self.remove_breakpoint(rest),
+            _ => String::new(),
+        }
+    }
+
+    fn read_registers(&mut self) -> String {
+        let mut out = String::with_capacity(NUM_REGISTERS * 8);
+        for i in 0..NUM_REGISTERS as u8 {
+            let value = self.executor.register(crate::Register::from_u8(i));
+            out.push_str(&hex_be(value));
+        }
+        out
+    }
+
+    fn write_registers(&mut self, hex: &str) {
+        for (i, chunk) in hex.as_bytes().chunks(8).enumerate().take(NUM_REGISTERS) {
+            if let Ok(value) = u32::from_str_radix(std::str::from_utf8(chunk).unwrap_or(""), 16) {
+                self.executor.rw(
+                    crate::Register::from_u8(i as u8),
+                    value,
+                    crate::events::MemoryAccessPosition::A,
+                );
+            }
+        }
+    }
+
+    /// `m addr,length` — read `length` bytes from `addr`, one byte at a time, via the
+    /// non-recording [`Executor::byte`] accessor (which reads the containing word through
+    /// [`Executor::word`]).
+    fn read_memory(&mut self, args: &str) -> Option<String> {
+        let (addr, len) = parse_addr_len(args)?;
+        // `len` comes straight off the wire from whatever's connected to the stub's TCP port, the
+        // same guest/attacker-controlled-length pattern this tree's syscalls clamp before
+        // allocating (see e.g. `syscalls::keccak_sponge::MAX_KECCAK_SPONGE_WORDS`) -- without this,
+        // `m 0,ffffffff` forces an ~8.5GB `String::with_capacity` attempt and aborts the process.
+        let len = len.min(MAX_GDB_READ_LEN);
+        let mut out = String::with_capacity(len as usize * 2);
+        for offset in 0..len {
+            let byte = self.executor.byte(addr + offset);
+            out.push_str(&format!("{byte:02x}"));
+        }
+        Some(out)
+    }
+
+    /// `M addr,length:XX...` — write `length` bytes at `addr`, one word at a time via `mw_cpu`.
+    fn write_memory(&mut self, args: &str) -> Option<String> {
+        let (header, data) = args.split_once(':')?;
+        let (addr, len) = parse_addr_len(header)?;
+        let bytes: Vec<u8> = (0..len as usize)
+            .map(|i| u8::from_str_radix(data.get(i * 2..i * 2 + 2)?, 16).ok())
+            .collect::<Option<_>>()?;
+        for (i, byte) in bytes.iter().enumerate() {
+            let word_addr = (addr + i as u32) & !3;
+            let shift = ((addr + i as u32) % 4) * 8;
+            let word = self.executor.word(word_addr);
+            let word = (word & !(0xff << shift)) | (u32::from(*byte) << shift);
+            self.executor.mw_cpu(word_addr, word, crate::events::MemoryAccessPosition::Memory);
+        }
+        Some("OK".to_string())
+    }
+
+    fn insert_breakpoint(&mut self, args: &str) -> String {
+        if let Some((addr, _)) = args.trim_start_matches("0,").split_once(',') {
+            if let Ok(addr) = u32::from_str_radix(addr, 16) {
+                self.executor.breakpoints.insert(addr);
+                return "OK".to_string();
+            }
+        }
+        "E01".to_string()
+    }
+
+    fn remove_breakpoint(&mut self, args: &str) -> String {
+        if let Some((addr, _)) = args.trim_start_matches("0,").split_once(',') {
+            if let Ok(addr) = u32::from_str_radix(addr, 16) {
+                self.executor.breakpoints.remove(&addr);
+                return "OK".to_string();
+            }
+        }
+        "E01".to_string()
+    }
+
+    /// Drive the executor forward either one instruction (`steps = Some(1)`) or until it hits a
+    /// breakpoint or halts (`steps = None`), via [`Executor::step`]/[`Executor::continue_until_break`].
+    fn resume(&mut self, steps: Option<u32>) -> String {
+        if steps.is_some() {
+            return match self.executor.step() {
+                Ok(_) => "S05".to_string(),
+                Err(_) => "S06".to_string(),
+            };
+        }
+        match self.executor.continue_until_break() {
+            Ok(crate::ExecutionOutcome::Halted) => "W00".to_string(),
+            Ok(crate::ExecutionOutcome::Breakpoint(_)) => "S05".to_string(),
+            Err(_) => "S06".to_string(),
+        }
+    }
+}
+
+fn parse_addr_len(args: &str) -> Option<(u32, u32)> {
+    let (addr, len) = args.split_once(',')?;
+    Some((u32::from_str_radix(addr, 16).ok()?, u32::from_str_radix(len, 16).ok()?))
+}
+
+fn hex_be(value: u32) -> String {
+    format!("{:08x}", value.swap_bytes())
+}
+
+/// Read one `$<data>#<checksum>` packet, skipping any stray acks (`+`/`-`).
+fn read_packet(stream: &mut TcpStream, scratch: &mut [u8]) -> std::io::Result<Option<String>> {
+    let mut data = Vec::new();
+    let mut in_packet = false;
+    loop {
+        let n = stream.read(scratch)?;
+        if n == 0 {
+            return Ok(None);
+        }
+        for &byte in &scratch[..n] {
+            match byte {
+                b'$' => {
+                    in_packet = true;
+                    data.clear();
+                }
+                b'#' if in_packet => {
+                    // The two trailing checksum bytes follow; we don't validate them here.
+                    return Ok(Some(String::from_utf8_lossy(&data).into_owned()));
+                }
+                _ if in_packet => data.push(byte),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn write_packet(stream: &mut TcpStream, payload: &str) -> std::io::Result<()> {
+    let checksum: u8 = payload.bytes().fold(0u8, |acc, b| acc.wrapping_add(b));
+    write!(stream, "${payload}#{checksum:02x}")
+}
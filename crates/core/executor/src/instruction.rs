@@ -124,7 +124,18 @@ impl Instruction {
     pub const fn is_branch_instruction(&self) -> bool {
         matches!(
             self.opcode,
-            Opcode::BEQ | Opcode::BNE | Opcode::BLTZ | Opcode::BGEZ | Opcode::BLEZ | Opcode::BGTZ
+            Opcode::BEQ
+                | Opcode::BNE
+                | Opcode::BLTZ
+                | Opcode::BGEZ
+                | Opcode::BLEZ
+                | Opcode::BGTZ
+                | Opcode::BEQL
+                | Opcode::BNEL
+                | Opcode::BLTZL
+                | Opcode::BGEZL
+                | Opcode::BLEZL
+                | Opcode::BGTZL
         )
     }
 
@@ -137,7 +148,15 @@ impl Instruction {
         )
     }
 
-    pub fn decode_from(insn: u32) -> anyhow::Result<Self> {
+    /// Decodes `insn`'s opcode/funct fields against this table.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::decode::DecodeError::Unknown`] if no entry in this table matches. Callers
+    /// that want the old permissive behavior -- decode to [`Opcode::UNIMPL`] instead of failing,
+    /// right for [`crate::Executor`] loading a program it'll only trap on if execution actually
+    /// reaches the bad word -- should use [`Self::decode_from_lenient`] instead.
+    pub fn decode_from(insn: u32) -> Result<Self, crate::decode::DecodeError> {
         let opcode = ((insn >> 26) & 0x3F).to_le_bytes()[0];
         let func = (insn & 0x3F).to_le_bytes()[0];
         let rt = ((insn >> 16) & 0x1F).to_le_bytes()[0] as u32;
@@ -205,7 +224,10 @@ impl Instruction {
             // } // SRL: rd = rt >> sa
             (0b000000, 0b000010) => {
                 if rs == 1 {
-                    Ok(Self::new_with_raw(Opcode::UNIMPL, 0, 0, 0, true, true, insn))
+                    // ROTR: rd = rt rotated right by sa. Shares SRL's funct code; `rs == 1` (a
+                    // field that's always 0 for plain SRL) is MIPS32r2's marker for the rotate
+                    // form. See `decode::ror32` for the rotate itself.
+                    Ok(Self::new(Opcode::ROR, rd, rt, sa, false, true))
                 } else {
                     Ok(Self::new(Opcode::SRL, rd, rt, sa, false, true)) // SRL: rd = rt >> sa
                 }
@@ -227,7 +249,16 @@ impl Instruction {
             //     rt,
             //     rd,
             // )), // SRLV: rd = rt >> rs[4:0]
-            (0b000000, 0b000110) => Ok(Self::new(Opcode::SRL, rd, rt, rs, false, false)), // SRLV: rd = rt >> rs[4:0]
+            (0b000000, 0b000110) => {
+                if sa & 1 == 1 {
+                    // ROTRV: rd = rt rotated right by rs[4:0]. Shares SRLV's funct code; bit 6 of
+                    // the instruction (the low bit of the `sa` field, which SRLV always leaves 0)
+                    // is MIPS32r2's marker for the rotate form.
+                    Ok(Self::new(Opcode::ROR, rd, rt, rs, false, false))
+                } else {
+                    Ok(Self::new(Opcode::SRL, rd, rt, rs, false, false)) // SRLV: rd = rt >> rs[4:0]
+                }
+            }
             // (0b000000, 0b000111) => Ok(Operation::BinaryArithmetic(
             //     BinaryOperator::SRAV,
             //     rs,
@@ -314,10 +345,27 @@ impl Instruction {
                 } else if rt == 0x11 && rs == 0 {
                     // Ok(Operation::JumpDirect(31, offset)) // BAL
                     Ok(Self::new(Opcode::JumpDirect, 31, offset_ext16.overflowing_shl(2).0, 0, true, true))
+                } else if rt == 8 {
+                    // TGEI rs, imm: trap if rs >= sext(imm) (signed). Reuses the register-form
+                    // TGE opcode with `imm_c` set, the same way `ADDI` reuses `ADD`.
+                    Ok(Self::new(Opcode::TGE, 0, rs, offset_ext16, false, true))
+                } else if rt == 9 {
+                    // TGEIU rs, imm: unsigned counterpart of TGEI.
+                    Ok(Self::new(Opcode::TGEU, 0, rs, offset_ext16, false, true))
+                } else if rt == 10 {
+                    // TLTI rs, imm: trap if rs < sext(imm) (signed).
+                    Ok(Self::new(Opcode::TLT, 0, rs, offset_ext16, false, true))
+                } else if rt == 11 {
+                    // TLTIU rs, imm: unsigned counterpart of TLTI.
+                    Ok(Self::new(Opcode::TLTU, 0, rs, offset_ext16, false, true))
+                } else if rt == 12 {
+                    // TEQI rs, imm: trap if rs == sext(imm).
+                    Ok(Self::new(Opcode::TEQ, 0, rs, offset_ext16, false, true))
+                } else if rt == 14 {
+                    // TNEI rs, imm: trap if rs != sext(imm).
+                    Ok(Self::new(Opcode::TNE, 0, rs, offset_ext16, false, true))
                 } else {
-                    // todo: change to ProgramError later
-                    // panic!("InvalidOpcode")
-                    Ok(Self::new_with_raw(Opcode::UNIMPL, 0, 0, 0, true, true, insn))
+                    Err(crate::decode::DecodeError::Unknown { opcode, funct: func })
                 }
             }
             // (0x02, _) => Ok(Operation::Jumpi(0u8, target)), // J
@@ -375,11 +423,43 @@ impl Instruction {
             (0b101110, _) => Ok(Self::new(Opcode::SWR, rt as u8, rs, offset_ext16, false, true)),
             // (0b111000, _) => Ok(Operation::MstoreGeneral(MemOp::SC, rs, rt, offset)),
             (0b111000, _) => Ok(Self::new(Opcode::SC, rt as u8, rs, offset_ext16, false, true)),
-            // (0b111101, _) => Ok(Operation::MstoreGeneral(MemOp::SDC1, rs, rt, offset)),
+            // LWC1 ft, offset(rs): op_a is `ft` (the `rt`-position field, per `execute_fp_load`'s
+            // expected (ft, base, offset) layout), op_b the base register -- the same order
+            // ordinary LW uses, just indexing the FP register file instead of the GPRs.
+            (0b110001, _) => Ok(Self::new(
+                Opcode::LWC1,
+                rt as u8,
+                rs,
+                offset_ext16,
+                false,
+                true,
+            )),
+            // SWC1 ft, offset(rs): see `execute_fp_store`'s expected (ft, base, offset) layout.
+            (0b111001, _) => Ok(Self::new(
+                Opcode::SWC1,
+                rt as u8,
+                rs,
+                offset_ext16,
+                false,
+                true,
+            )),
+            // LDC1 ft, offset(rs): see `execute_fp_load`'s expected (ft, base, offset) layout.
+            (0b110101, _) => Ok(Self::new(
+                Opcode::LDC1,
+                rt as u8,
+                rs,
+                offset_ext16,
+                false,
+                true,
+            )),
+            // SDC1 ft, offset(rs): see `execute_fp_store`'s expected (ft, base, offset) layout --
+            // `op_a`/`op_b` previously carried `rs`/`rt` instead of `rt`/`rs` here, which actually
+            // stored the wrong 32-bit half at the wrong address once `execute_fp_store` read them
+            // back as (ft, base); fixed to match LWC1/SWC1/LDC1 above.
             (0b111101, _) => Ok(Self::new(
                 Opcode::SDC1,
-                rs as u8,
-                rt,
+                rt as u8,
+                rs,
                 offset_ext16,
                 false,
                 true,
@@ -506,31 +586,296 @@ impl Instruction {
             (0b110011, _) => Ok(Self::new(Opcode::NOP, 0, 0, 0, true, true)), // Pref
             // (0b000000, 0b110100) => Ok(Operation::Teq(rs, rt)), // teq
             (0b000000, 0b110100) => Ok(Self::new(Opcode::TEQ, rd, rs, rt, false, false)), // teq
+            // Rest of the trap-on-condition family (see `TEQ` above): like `TEQ`, these have no
+            // destination register, so `op_a` is unused (set to 0) and `op_b`/`op_c` carry the
+            // two compared registers.
+            (0b000000, 0b110000) => Ok(Self::new(Opcode::TGE, 0, rs, rt, false, false)), // TGE
+            (0b000000, 0b110001) => Ok(Self::new(Opcode::TGEU, 0, rs, rt, false, false)), // TGEU
+            (0b000000, 0b110010) => Ok(Self::new(Opcode::TLT, 0, rs, rt, false, false)), // TLT
+            (0b000000, 0b110011) => Ok(Self::new(Opcode::TLTU, 0, rs, rt, false, false)), // TLTU
+            (0b000000, 0b110110) => Ok(Self::new(Opcode::TNE, 0, rs, rt, false, false)), // TNE
+
+            // SPECIAL3 (0b011111): the MIPS32r2 bitfield-manipulation family. `rd`/`sa` don't
+            // name a destination register/shift amount here the way they do elsewhere in this
+            // table -- EXT and INS instead pack them as the extracted/inserted bitfield's
+            // `msbd`/`lsb` (`size`/`pos`) into `op_c`, the same `(upper << 5) | lsb` layout
+            // `zkm2_core_machine::misc::trace`'s `populate_ext`/`populate_ins` already unpack
+            // `event.c` with.
+            //
+            // EXT rt, rs, pos, size: rt = extract `size = rd+1` bits from `rs`, starting at bit
+            // `sa` (MIPS calls this field `lsb`, and the one carrying `size - 1` `msbd`).
+            (0b011111, 0b000000) => {
+                Ok(Self::new(Opcode::EXT, rt as u8, rs, (u32::from(rd) << 5) | sa, false, true))
+            }
+            // INS rt, rs, pos, size: inserts the low `size = rd - sa + 1` bits of `rs` into `rt`
+            // at bit `sa`, preserving `rt`'s other bits (MIPS calls the `rd`-position field `msb`
+            // here, not `msbd` as in EXT).
+            (0b011111, 0b000100) => {
+                Ok(Self::new(Opcode::INS, rt as u8, rs, (u32::from(rd) << 5) | sa, false, true))
+            }
+            // BSHFL (funct 0b100000): `sa` selects which byte/halfword-shuffle op this is.
+            (0b011111, 0b100000) => match sa {
+                // WSBH rd, rt: swap the bytes within each halfword of rt.
+                0x02 => Ok(Self::new(Opcode::WSBH, rd, rt, 0, false, false)),
+                // SEB rd, rt: sign-extend rt's low byte. `Instruction::decode_from` maps both SEB
+                // and SEH onto `Opcode::SEXT`, distinguished by `op_c` (0 for SEB, 1 for SEH) --
+                // see `crate::dependencies::emit_misc_dependencies`'s `Opcode::SEXT` arm.
+                0x10 => Ok(Self::new(Opcode::SEXT, rd, rt, 0, false, true)),
+                // SEH rd, rt: sign-extend rt's low halfword.
+                0x18 => Ok(Self::new(Opcode::SEXT, rd, rt, 1, false, true)),
+                _ => Err(crate::decode::DecodeError::Unknown { opcode, funct: func }),
+            },
+
+            // COP1 (0b010001): the MIPS floating-point coprocessor. Unlike every other opcode in
+            // this table, `rs` doesn't name a source register here -- it selects which COP1
+            // sub-format this word is: MF/MT (move a raw word between a GPR and an FPR), BC
+            // (branch on the FPU condition-code flag a prior `C.cond.fmt` set), or one of the
+            // `fmt` selectors (S/D/W) gating the arithmetic/convert/compare ops dispatched on
+            // `func` below. The COP1 field names are kept in the comments even though the match
+            // reuses the same `rs`/`rt`/`rd`/`sa` bindings the rest of `decode_from` uses: `rd`
+            // is `fs` (bits 15:11), `sa` is `fd` (bits 10:6), `rt` is `ft` (bits 20:16).
+            (0b010001, _) => match rs {
+                // MFC1 rt, fs: rt = the raw bits of FPR `fs`, reinterpreted as an integer.
+                0x00 => Ok(Self::new(Opcode::MFC1, rt as u8, u32::from(rd), 0, false, false)),
+                // MTC1 rt, fs: FPR `fs` = the raw bits of GPR `rt`, reinterpreted as a float.
+                0x04 => Ok(Self::new(Opcode::MTC1, rt as u8, u32::from(rd), 0, false, false)),
+                // BC1T/BC1F offset: branch if the FPU condition-code flag (set by the most
+                // recent `C.cond.fmt`) is true/false -- pc-relative on the delay slot exactly
+                // like `BEQ`/`BNE`, see `disasm::resolve_target`. `rt`'s low bit (MIPS calls
+                // this field `tf`) picks which of the two.
+                0x08 => {
+                    let branch_opcode = if rt & 1 == 1 { Opcode::BC1T } else { Opcode::BC1F };
+                    Ok(Self::new(
+                        branch_opcode,
+                        0,
+                        0,
+                        offset_ext16.overflowing_shl(2).0,
+                        true,
+                        true,
+                    ))
+                }
+                // S fmt: single-precision arithmetic/convert/compare, selected by `func`.
+                0x10 => match func {
+                    0b000000 => {
+                        Ok(Self::new(Opcode::FADD_S, sa as u8, u32::from(rd), rt, false, false))
+                    } // ADD.S fd, fs, ft
+                    0b000001 => {
+                        Ok(Self::new(Opcode::FSUB_S, sa as u8, u32::from(rd), rt, false, false))
+                    } // SUB.S fd, fs, ft
+                    0b000010 => {
+                        Ok(Self::new(Opcode::FMUL_S, sa as u8, u32::from(rd), rt, false, false))
+                    } // MUL.S fd, fs, ft
+                    0b000011 => {
+                        Ok(Self::new(Opcode::FDIV_S, sa as u8, u32::from(rd), rt, false, false))
+                    } // DIV.S fd, fs, ft
+                    // CVT.W.S fd, fs: converts single-precision fs to a 32-bit signed int fd.
+                    0b100100 => {
+                        Ok(Self::new(Opcode::FCVT_W_S, sa as u8, u32::from(rd), 0, false, false))
+                    }
+                    // C.EQ.S/C.LT.S fs, ft: the low 4 bits of `func` give the condition; this
+                    // decoder only assigns meaning to EQ/LT (see `Opcode::FC_EQ_S`/
+                    // `Opcode::FC_LT_S`'s doc comments), and always targets FCSR condition
+                    // code 0, so there's no `cc` operand to decode out of `rd`'s upper bits.
+                    0b110010 => Ok(Self::new(Opcode::FC_EQ_S, 0, u32::from(rd), rt, false, false)),
+                    0b111100 => Ok(Self::new(Opcode::FC_LT_S, 0, u32::from(rd), rt, false, false)),
+                    _ => Err(crate::decode::DecodeError::Unknown { opcode, funct: func }),
+                },
+                // D fmt: double-precision arithmetic. This decoder defines no D-format
+                // compare/convert `Opcode` (no FC_EQ_D/FCVT_*_D), so any `func` outside
+                // ADD/SUB/MUL/DIV falls through to Unknown like any other unassigned encoding.
+                0x11 => match func {
+                    0b000000 => {
+                        Ok(Self::new(Opcode::FADD_D, sa as u8, u32::from(rd), rt, false, false))
+                    }
+                    0b000001 => {
+                        Ok(Self::new(Opcode::FSUB_D, sa as u8, u32::from(rd), rt, false, false))
+                    }
+                    0b000010 => {
+                        Ok(Self::new(Opcode::FMUL_D, sa as u8, u32::from(rd), rt, false, false))
+                    }
+                    0b000011 => {
+                        Ok(Self::new(Opcode::FDIV_D, sa as u8, u32::from(rd), rt, false, false))
+                    }
+                    _ => Err(crate::decode::DecodeError::Unknown { opcode, funct: func }),
+                },
+                // W fmt: CVT.S.W fd, fs -- the only conversion this decoder assigns out of W fmt.
+                0x14 => match func {
+                    0b100000 => {
+                        Ok(Self::new(Opcode::FCVT_S_W, sa as u8, u32::from(rd), 0, false, false))
+                    }
+                    _ => Err(crate::decode::DecodeError::Unknown { opcode, funct: func }),
+                },
+                _ => Err(crate::decode::DecodeError::Unknown { opcode, funct: func }),
+            },
+
             _ => {
                 log::warn!("decode: invalid opcode {:#08b} {:#08b}", opcode, func);
-                // todo: change to ProgramError later
-                // panic!("InvalidOpcode")
-                Ok(Self::new_with_raw(Opcode::UNIMPL, 0, 0, 0, true, true, insn))
+                Err(crate::decode::DecodeError::Unknown { opcode, funct: func })
             }
         }
     }
+
+    /// Like [`Self::decode_from`], but maps any [`crate::decode::DecodeError`] back to the old
+    /// permissive [`Opcode::UNIMPL`] sentinel (with `insn` stashed in [`Self::raw`]) instead of
+    /// failing. For [`crate::Executor`] loading a program: an unrecognized word should still load
+    /// successfully and only fault via [`crate::ExecutionError::UnsupportedInstruction`] if
+    /// execution actually reaches it, not at load time.
+    #[must_use]
+    pub fn decode_from_lenient(insn: u32) -> Self {
+        Self::decode_from(insn)
+            .unwrap_or_else(|_| Self::new_with_raw(Opcode::UNIMPL, 0, 0, 0, true, true, insn))
+    }
+
+    /// Streams decoded instructions out of a flat byte segment; see
+    /// [`crate::decode::decode_stream`].
+    pub fn decode_stream(
+        bytes: &[u8],
+        endian: crate::decode::Endian,
+    ) -> impl Iterator<Item = (u32, Result<Self, crate::decode::DecodeError>)> + '_ {
+        crate::decode::decode_stream(bytes, endian)
+    }
+
+    /// Writes this instruction as a line of MIPS assembly addressed at `pc`, resolving any
+    /// branch/jump target (see [`crate::disasm::resolve_target`]) to an absolute address and, if
+    /// `symbols` resolves that address to a name, rendering it as a label instead of a bare hex
+    /// address. Mirrors yaxpeax's `ShowContextual::contextualize`.
+    pub fn contextualize(
+        &self,
+        pc: u32,
+        symbols: Option<&dyn Fn(u32) -> Option<String>>,
+        f: &mut impl std::fmt::Write,
+    ) -> std::fmt::Result {
+        f.write_str(&crate::disasm::contextualize_instruction(self, pc, symbols))
+    }
 }
 
-impl Debug for Instruction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mnemonic = self.opcode.mnemonic();
-        let op_a_formatted = format!("%x{}", self.op_a);
-        let op_b_formatted = if self.imm_b {
-            format!("{}", self.op_b as i32)
-        } else {
-            format!("%x{}", self.op_b)
+/// What [`Instruction::fmt_with`] does with an operand whose rendered width exceeds its column
+/// budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// Cut the operand off at the column width. (The fixed-`width = 10` [`Debug`] impl pads
+    /// short fields but never cuts long ones.)
+    Truncate,
+    /// Let the operand spill past its column width rather than losing information.
+    Overflow,
+}
+
+/// Configuration for [`Instruction::fmt_with`], replacing the [`Debug`] impl's hardcoded
+/// `width = 10` with knobs callers can tune for their output: narrow terminals want a small
+/// `max_width` and `Truncate`; wide log files can afford `Overflow` and generous column widths.
+/// Mirrors rustfmt's width-heuristic approach -- when the inline aligned row would exceed
+/// `max_width`, [`Instruction::fmt_with`] falls back to a compact one-field-per-line layout
+/// instead of cramming or truncating it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisasmConfig {
+    /// Column width for the mnemonic field.
+    pub mnemonic_width: usize,
+    /// Column width shared by the op_a/op_b/op_c fields.
+    pub operand_width: usize,
+    /// Maximum width of the inline aligned row before falling back to one field per line.
+    pub max_width: usize,
+    /// What to do with an operand that doesn't fit its column width.
+    pub overflow: Overflow,
+}
+
+impl Default for DisasmConfig {
+    fn default() -> Self {
+        Self { mnemonic_width: 10, operand_width: 10, max_width: 40, overflow: Overflow::Truncate }
+    }
+}
+
+/// Renders the raw `%xN`-style operand columns (mnemonic, op_a, op_b, op_c) the [`Debug`] impl
+/// and [`Instruction::format_program`] both build rows out of, so the two stay in sync.
+fn debug_columns(instruction: &Instruction) -> (&str, String, String, String) {
+    let mnemonic = instruction.opcode.mnemonic();
+    let op_a_formatted = format!("%x{}", instruction.op_a);
+    let op_b_formatted = if instruction.imm_b {
+        format!("{}", instruction.op_b as i32)
+    } else {
+        format!("%x{}", instruction.op_b)
+    };
+    let op_c_formatted = if instruction.imm_c {
+        format!("{}", instruction.op_c as i32)
+    } else {
+        format!("%x{}", instruction.op_c)
+    };
+    (mnemonic, op_a_formatted, op_b_formatted, op_c_formatted)
+}
+
+impl Instruction {
+    /// Renders a whole program as a grid-aligned `%xN`-style listing, one instruction per line.
+    ///
+    /// Unlike the single-instruction [`Debug`] impl, which pads every column to a fixed width of
+    /// 10 (letting long mnemonics/operands push later columns out of line and wasting space on
+    /// short ones), this makes a
+    /// first pass over `instructions` to find each column's actual maximum rendered width, then
+    /// pads every row to that per-column maximum -- the same two-pass fit-to-contents approach
+    /// `term_grid` uses to size its cells.
+    #[must_use]
+    pub fn format_program(instructions: &[Self]) -> String {
+        let columns: Vec<(&str, String, String, String)> =
+            instructions.iter().map(debug_columns).collect();
+
+        let mnemonic_width = columns.iter().map(|(m, ..)| m.len()).max().unwrap_or(0);
+        let op_a_width = columns.iter().map(|(_, a, ..)| a.len()).max().unwrap_or(0);
+        let op_b_width = columns.iter().map(|(_, _, b, _)| b.len()).max().unwrap_or(0);
+        let op_c_width = columns.iter().map(|(.., c)| c.len()).max().unwrap_or(0);
+
+        columns
+            .iter()
+            .map(|(mnemonic, op_a, op_b, op_c)| {
+                format!(
+                    "{mnemonic:<mnemonic_width$} {op_a:<op_a_width$} {op_b:<op_b_width$} {op_c:<op_c_width$}"
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Writes this instruction's raw `%xN`-style operand columns to `f`, honoring `config`'s
+    /// column widths and overflow policy instead of the [`Debug`] impl's fixed `width = 10`.
+    ///
+    /// When the inline aligned row (mnemonic plus the three operand columns, each padded or
+    /// truncated to `config`'s widths) would exceed `config.max_width`, falls back to a compact
+    /// one-field-per-line layout instead, the same fallback rustfmt uses when a single-line
+    /// rendering would blow its own width heuristic.
+    pub fn fmt_with(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        config: &DisasmConfig,
+    ) -> std::fmt::Result {
+        let (mnemonic, op_a, op_b, op_c) = debug_columns(self);
+        let fit = |field: &str, width: usize| -> String {
+            if config.overflow == Overflow::Truncate && field.len() > width {
+                field[..width].to_string()
+            } else {
+                field.to_string()
+            }
         };
-        let op_c_formatted = if self.imm_c {
-            format!("{}", self.op_c as i32)
+        let mnemonic = fit(mnemonic, config.mnemonic_width);
+        let op_a = fit(&op_a, config.operand_width);
+        let op_b = fit(&op_b, config.operand_width);
+        let op_c = fit(&op_c, config.operand_width);
+
+        let mnemonic_width = config.mnemonic_width;
+        let operand_width = config.operand_width;
+        let inline = format!(
+            "{mnemonic:<mnemonic_width$} {op_a:<operand_width$} {op_b:<operand_width$} {op_c:<operand_width$}"
+        );
+        if inline.len() <= config.max_width {
+            f.write_str(inline.trim_end())
         } else {
-            format!("%x{}", self.op_c)
-        };
+            writeln!(f, "{mnemonic}")?;
+            writeln!(f, "  {op_a}")?;
+            writeln!(f, "  {op_b}")?;
+            write!(f, "  {op_c}")
+        }
+    }
+}
 
+impl Debug for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (mnemonic, op_a_formatted, op_b_formatted, op_c_formatted) = debug_columns(self);
         let width = 10;
         write!(
             f,
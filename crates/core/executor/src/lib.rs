@@ -1,14 +1,40 @@
+mod context;
+pub mod cop1;
+pub mod cp0;
+pub mod cycle_cost;
+pub mod decode;
+pub mod diagnostics;
+pub mod disasm;
 pub mod events;
+mod executor;
+pub mod gdb;
 mod instruction;
+pub mod memory;
 mod opcode;
 mod program;
+pub mod profiler;
 mod record;
+mod rvfi_dii;
 mod shape;
+mod snapshot;
 mod state;
+pub mod syscalls;
+pub mod trace;
+pub mod trace_diff;
+pub mod trap;
+#[cfg(feature = "ufmt")]
+pub mod udisplay;
 
+pub use context::*;
+pub use cop1::*;
+pub use cp0::*;
+pub use cycle_cost::*;
+pub use executor::*;
 pub use instruction::*;
 pub use opcode::*;
 pub use program::*;
 pub use record::*;
+pub use rvfi_dii::*;
 pub use shape::*;
+pub use snapshot::*;
 pub use state::*;
@@ -0,0 +1,279 @@
+//! A paged sparse memory map keyed by word-aligned addresses.
+//!
+//! Guest memory is mostly empty: a typical program only ever touches a small fraction of the
+//! `MAX_MEMORY` address space. Rather than paying for a `BTreeMap` node per touched word, we
+//! split the address space into fixed-size pages and only allocate a page's backing array the
+//! first time one of its words is touched, which is the same trade-off a real MMU makes.
+
+use std::fmt;
+
+use serde::{
+    de::{SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::program::MAX_MEMORY;
+
+const PAGE_SIZE_LOG2: u32 = 12;
+const PAGE_SIZE: usize = 1 << PAGE_SIZE_LOG2;
+const WORDS_PER_PAGE: usize = PAGE_SIZE / 4;
+const NUM_PAGES: usize = MAX_MEMORY / PAGE_SIZE;
+
+type Page<T> = [Option<T>; WORDS_PER_PAGE];
+
+fn page_index(addr: u32) -> (usize, usize) {
+    let addr = addr as usize;
+    (addr >> PAGE_SIZE_LOG2, (addr & (PAGE_SIZE - 1)) >> 2)
+}
+
+/// A sparse map from word-aligned `u32` addresses to `T`, paged for cheap allocation.
+pub struct PagedMemory<T> {
+    pages: Vec<Option<Box<Page<T>>>>,
+    len: usize,
+}
+
+impl<T> PagedMemory<T> {
+    /// Create an empty map with the page table preallocated (but no pages themselves allocated).
+    #[must_use]
+    pub fn new_preallocated() -> Self {
+        let mut pages = Vec::new();
+        pages.resize_with(NUM_PAGES, || None);
+        Self { pages, len: 0 }
+    }
+
+    /// The number of addresses with a stored value.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the map has no stored values.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the value stored at `addr`, if any.
+    #[must_use]
+    pub fn get(&self, addr: u32) -> Option<&T> {
+        let (page, offset) = page_index(addr);
+        self.pages.get(page)?.as_ref()?[offset].as_ref()
+    }
+
+    /// Insert a value at `addr`, returning the previous value if one was present.
+    pub fn insert(&mut self, addr: u32, value: T) -> Option<T> {
+        let (page, offset) = page_index(addr);
+        let slot = self.pages[page].get_or_insert_with(|| Box::new(std::array::from_fn(|_| None)));
+        let prev = slot[offset].replace(value);
+        if prev.is_none() {
+            self.len += 1;
+        }
+        prev
+    }
+
+    /// Remove the value stored at `addr`, if any.
+    pub fn remove(&mut self, addr: u32) -> Option<T> {
+        let (page, offset) = page_index(addr);
+        let prev = self.pages.get_mut(page)?.as_mut()?[offset].take();
+        if prev.is_some() {
+            self.len -= 1;
+        }
+        prev
+    }
+
+    /// Get the entry for `addr`, for in-place inspection/mutation without a double lookup.
+    pub fn entry(&mut self, addr: u32) -> Entry<'_, T> {
+        let (page, offset) = page_index(addr);
+        let slot = self.pages[page].get_or_insert_with(|| Box::new(std::array::from_fn(|_| None)));
+        if slot[offset].is_some() {
+            Entry::Occupied(OccupiedEntry { slot: &mut slot[offset] })
+        } else {
+            Entry::Vacant(VacantEntry { slot: &mut slot[offset], len: &mut self.len })
+        }
+    }
+
+    /// Remove every stored value, keeping the page table allocated.
+    pub fn clear(&mut self) {
+        for page in &mut self.pages {
+            *page = None;
+        }
+        self.len = 0;
+    }
+
+    /// Iterate over the addresses with a stored value, in ascending order.
+    pub fn keys(&self) -> impl Iterator<Item = u32> + '_ {
+        self.iter().map(|(addr, _)| addr)
+    }
+
+    /// Iterate over `(addr, value)` pairs, in ascending address order.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &T)> + '_ {
+        self.pages.iter().enumerate().filter_map(|(p, page)| page.as_deref().map(|page| (p, page))).flat_map(
+            |(p, page)| {
+                page.iter().enumerate().filter_map(move |(o, value)| {
+                    value.as_ref().map(|value| (((p << PAGE_SIZE_LOG2) | (o << 2)) as u32, value))
+                })
+            },
+        )
+    }
+}
+
+impl<T> Default for PagedMemory<T> {
+    fn default() -> Self {
+        Self::new_preallocated()
+    }
+}
+
+impl<T: Clone> Clone for PagedMemory<T> {
+    fn clone(&self) -> Self {
+        Self { pages: self.pages.clone(), len: self.len }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.pages.clone_from(&source.pages);
+        self.len = source.len;
+    }
+}
+
+impl<T: PartialEq> PartialEq for PagedMemory<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+impl<T: Eq> Eq for PagedMemory<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for PagedMemory<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<(u32, T)> for PagedMemory<T> {
+    fn from_iter<I: IntoIterator<Item = (u32, T)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        for (addr, value) in iter {
+            map.insert(addr, value);
+        }
+        map
+    }
+}
+
+/// Owning iterator over `(addr, value)` pairs, in ascending address order.
+pub struct IntoIter<T> {
+    inner: std::vec::IntoIter<(u32, T)>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = (u32, T);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl<T> IntoIterator for PagedMemory<T> {
+    type Item = (u32, T);
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut entries = Vec::with_capacity(self.len);
+        for (p, page) in self.pages.into_iter().enumerate() {
+            let Some(page) = page else { continue };
+            for (o, value) in (*page).into_iter().enumerate() {
+                if let Some(value) = value {
+                    entries.push((((p << PAGE_SIZE_LOG2) | (o << 2)) as u32, value));
+                }
+            }
+        }
+        IntoIter { inner: entries.into_iter() }
+    }
+}
+
+/// An entry in a [`PagedMemory`], for in-place inspection or insertion.
+pub enum Entry<'a, T> {
+    /// The address already has a stored value.
+    Occupied(OccupiedEntry<'a, T>),
+    /// The address has no stored value yet.
+    Vacant(VacantEntry<'a, T>),
+}
+
+impl<'a, T> Entry<'a, T> {
+    /// Insert `default` if vacant, returning a mutable reference to the value either way.
+    pub fn or_insert(self, default: T) -> &'a mut T {
+        self.or_insert_with(|| default)
+    }
+
+    /// Insert the result of `f` if vacant, returning a mutable reference to the value either way.
+    pub fn or_insert_with(self, f: impl FnOnce() -> T) -> &'a mut T {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(f()),
+        }
+    }
+}
+
+/// A view into an occupied entry in a [`PagedMemory`].
+pub struct OccupiedEntry<'a, T> {
+    slot: &'a mut Option<T>,
+}
+
+impl<'a, T> OccupiedEntry<'a, T> {
+    /// Borrow the current value.
+    #[must_use]
+    pub fn get(&self) -> &T {
+        self.slot.as_ref().expect("occupied entry always has a value")
+    }
+
+    /// Consume the entry, returning a mutable reference to the value with the entry's lifetime.
+    #[must_use]
+    pub fn into_mut(self) -> &'a mut T {
+        self.slot.as_mut().expect("occupied entry always has a value")
+    }
+}
+
+/// A view into a vacant entry in a [`PagedMemory`].
+pub struct VacantEntry<'a, T> {
+    slot: &'a mut Option<T>,
+    len: &'a mut usize,
+}
+
+impl<'a, T> VacantEntry<'a, T> {
+    /// Insert a value, returning a mutable reference to it.
+    pub fn insert(self, value: T) -> &'a mut T {
+        *self.len += 1;
+        self.slot.insert(value)
+    }
+}
+
+impl<T: Serialize> Serialize for PagedMemory<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len))?;
+        for (addr, value) in self.iter() {
+            seq.serialize_element(&(addr, value))?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for PagedMemory<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PagedMemoryVisitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for PagedMemoryVisitor<T> {
+            type Value = PagedMemory<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sequence of (addr, value) pairs")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut map = PagedMemory::default();
+                while let Some((addr, value)) = seq.next_element::<(u32, T)>()? {
+                    map.insert(addr, value);
+                }
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_seq(PagedMemoryVisitor(std::marker::PhantomData))
+    }
+}
@@ -33,6 +33,8 @@ pub enum Opcode {
     CLZ = 16,    // cloclz
     // count leading ones
     CLO = 17,    // cloclz
+    // count trailing zeros
+    CTZ = 70,    // cloclz
     BEQ = 18,    // BRANCH
     BGEZ = 19,   // BRANCH
     BGTZ = 20,   // BRANCH
@@ -69,6 +71,70 @@ pub enum Opcode {
     MADDU = 50,   // MISC  
     MSUBU = 51,   // MISC
     INS = 52,     // MISC
+    // CP0 exception return: restores `pc` from `EPC`.
+    ERET = 53,    // MISC
+    // COP1 load/store.
+    LWC1 = 54,    // LOAD
+    SWC1 = 55,    // STORE
+    LDC1 = 56,    // LOAD
+    SDC1 = 57,    // STORE
+    // COP1 single-precision arithmetic.
+    FADD_S = 58,  // FPALU
+    FSUB_S = 59,  // FPALU
+    FMUL_S = 60,  // FPALU
+    FDIV_S = 61,  // FPALU
+    // COP1 double-precision arithmetic.
+    FADD_D = 62,  // FPALU
+    FSUB_D = 63,  // FPALU
+    FMUL_D = 64,  // FPALU
+    FDIV_D = 65,  // FPALU
+    // COP1 compare (single precision), result in FCSR condition-code 0.
+    FC_EQ_S = 66, // FPALU
+    FC_LT_S = 67, // FPALU
+    // COP1 convert between single-precision float and 32-bit signed int.
+    FCVT_S_W = 68, // FPALU
+    FCVT_W_S = 69, // FPALU
+    // Trapping variants of ADD/SUB (register form also covers ADDI, same as how `ADD` above
+    // serves both the register and immediate-form ADDU/ADDIU via `Instruction::imm_c`): unlike
+    // the wrapping `ADD`/`SUB`, which this crate uses throughout for the non-trapping
+    // ADDU/SUBU/ADDIU family, these raise a `TrapEvent` on signed overflow instead of wrapping
+    // silently.
+    TADD = 71,   // addsub
+    TSUB = 72,   // addsub
+    // "Likely" branches: like their non-likely counterparts above, but the delay-slot instruction
+    // is nullified (its effects suppressed) rather than executed when the branch is *not* taken.
+    // Common compiler-emitted forms (e.g. loop back-edges) that a prover needs to support without
+    // rewriting the binary.
+    BEQL = 73,   // BRANCH
+    BNEL = 74,   // BRANCH
+    BLEZL = 75,  // BRANCH
+    BGTZL = 76,  // BRANCH
+    BLTZL = 77,  // BRANCH
+    BGEZL = 78,  // BRANCH
+    // Signed counterparts of MADDU/MSUBU above: multiply-accumulate/-subtract into the
+    // HI:LO pair, sign-extending the operands the same way MULT does (MADDU/MSUBU treat them
+    // like MULTU instead).
+    MADD = 79,   // MISC
+    MSUB = 80,   // MISC
+    // COP1 register-bank moves: unlike the arithmetic/compare/convert FPALU ops above, these
+    // cross between the FPR and GPR banks, so exactly one of their two register operands is an
+    // FPR -- see `Opcode::uses_fp_registers`'s doc comment.
+    MFC1 = 81,   // FPALU
+    MTC1 = 82,   // FPALU
+    // COP1 branches: like `BEQ`/`BNE` and friends, these are pc-relative on the delay slot, but
+    // test the FPU condition-code flag `C.cond.fmt` last set rather than a GPR comparison.
+    BC1T = 83,   // BRANCH
+    BC1F = 84,   // BRANCH
+    // Trap-on-condition family: like `TEQ` above, these compare two operands and raise a `Tr`
+    // CP0 exception (see `Executor::raise_exception`) instead of writing a result when the
+    // condition holds. Each register form also covers its immediate counterpart (e.g. `TGE`
+    // serves both `TGE` and `TGEI`), the same way `ADD` above covers both `ADD` and `ADDI` via
+    // `Instruction::imm_c`.
+    TNE = 85,    // MISC
+    TGE = 86,    // MISC
+    TGEU = 87,   // MISC
+    TLT = 88,    // MISC
+    TLTU = 89,   // MISC
     UNIMPL = 0xff,
 }
 
@@ -99,6 +165,12 @@ impl Opcode {
             Opcode::BLEZ => "blez",
             Opcode::BGTZ => "bgtz",
             Opcode::BLTZ => "bltz",
+            Opcode::BEQL => "beql",
+            Opcode::BNEL => "bnel",
+            Opcode::BGEZL => "bgezl",
+            Opcode::BLEZL => "blezl",
+            Opcode::BGTZL => "bgtzl",
+            Opcode::BLTZL => "bltzl",
             Opcode::MEQ => "meq",
             Opcode::MNE => "mne",
             Opcode::LH => "lh",
@@ -117,6 +189,7 @@ impl Opcode {
             Opcode::LB => "lb",
             Opcode::CLZ => "clz",
             Opcode::CLO => "clo",
+            Opcode::CTZ => "ctz",
             Opcode::Jump => "jump",
             Opcode::Jumpi => "jumpi",
             Opcode::JumpDirect => "jump_direct",
@@ -129,6 +202,36 @@ impl Opcode {
             Opcode::ROR => "ror",
             Opcode::MADDU => "maddu",
             Opcode::MSUBU => "msubu",
+            Opcode::MADD => "madd",
+            Opcode::MSUB => "msub",
+            Opcode::ERET => "eret",
+            Opcode::LWC1 => "lwc1",
+            Opcode::SWC1 => "swc1",
+            Opcode::LDC1 => "ldc1",
+            Opcode::SDC1 => "sdc1",
+            Opcode::FADD_S => "add.s",
+            Opcode::FSUB_S => "sub.s",
+            Opcode::FMUL_S => "mul.s",
+            Opcode::FDIV_S => "div.s",
+            Opcode::FADD_D => "add.d",
+            Opcode::FSUB_D => "sub.d",
+            Opcode::FMUL_D => "mul.d",
+            Opcode::FDIV_D => "div.d",
+            Opcode::FC_EQ_S => "c.eq.s",
+            Opcode::FC_LT_S => "c.lt.s",
+            Opcode::FCVT_S_W => "cvt.s.w",
+            Opcode::FCVT_W_S => "cvt.w.s",
+            Opcode::MFC1 => "mfc1",
+            Opcode::MTC1 => "mtc1",
+            Opcode::BC1T => "bc1t",
+            Opcode::BC1F => "bc1f",
+            Opcode::TADD => "add",
+            Opcode::TSUB => "sub",
+            Opcode::TNE => "tne",
+            Opcode::TGE => "tge",
+            Opcode::TGEU => "tgeu",
+            Opcode::TLT => "tlt",
+            Opcode::TLTU => "tltu",
             Opcode::UNIMPL => "unimpl",
         }
     }
@@ -141,24 +244,88 @@ impl Opcode {
     
     pub fn is_use_lo_hi_alu(&self) -> bool {
         match self {
-            Opcode::DIV | Opcode::DIVU | Opcode::MULT | Opcode::MULTU | Opcode::MADDU | Opcode::MSUBU => true,
+            Opcode::DIV
+            | Opcode::DIVU
+            | Opcode::MULT
+            | Opcode::MULTU
+            | Opcode::MADD
+            | Opcode::MADDU
+            | Opcode::MSUB
+            | Opcode::MSUBU => true,
             _ => false,
         }
     }
 
     pub fn only_one_operand(&self) -> bool {
         match self {
-            Opcode::BGEZ | Opcode::BLEZ | Opcode::BGTZ | Opcode::BLTZ => true,
+            Opcode::BGEZ
+            | Opcode::BLEZ
+            | Opcode::BGTZ
+            | Opcode::BLTZ
+            | Opcode::BGEZL
+            | Opcode::BLEZL
+            | Opcode::BGTZL
+            | Opcode::BLTZL => true,
             _ => false,
         }
     }
 
     pub fn signed_compare(&self) -> bool {
         match self {
-            Opcode::BGEZ | Opcode::BLEZ | Opcode::BGTZ | Opcode::BLTZ => true,
+            Opcode::BGEZ
+            | Opcode::BLEZ
+            | Opcode::BGTZ
+            | Opcode::BLTZ
+            | Opcode::BGEZL
+            | Opcode::BLEZL
+            | Opcode::BGTZL
+            | Opcode::BLTZL => true,
             _ => false,
         }
     }
+
+    /// Whether this is a MIPS "likely" branch, which nullifies (suppresses the effects of) the
+    /// delay-slot instruction when the branch is not taken, instead of always executing it.
+    pub fn is_branch_likely(&self) -> bool {
+        matches!(
+            self,
+            Opcode::BEQL
+                | Opcode::BNEL
+                | Opcode::BLEZL
+                | Opcode::BGTZL
+                | Opcode::BLTZL
+                | Opcode::BGEZL
+        )
+    }
+
+    /// Whether this opcode reads or writes the FPU's register bank (`$f0`..`$f31`) rather than,
+    /// or in addition to, the integer GPR bank, so downstream trace/ALU handling can route its
+    /// operands through the FPR file instead of the GPR one. `MFC1`/`MTC1` straddle both banks
+    /// (one GPR operand, one FPR operand) and are included; `BC1T`/`BC1F` only read the FCSR
+    /// condition-code flag, not a register, so they're excluded.
+    pub fn uses_fp_registers(&self) -> bool {
+        matches!(
+            self,
+            Opcode::LWC1
+                | Opcode::SWC1
+                | Opcode::LDC1
+                | Opcode::SDC1
+                | Opcode::FADD_S
+                | Opcode::FSUB_S
+                | Opcode::FMUL_S
+                | Opcode::FDIV_S
+                | Opcode::FADD_D
+                | Opcode::FSUB_D
+                | Opcode::FMUL_D
+                | Opcode::FDIV_D
+                | Opcode::FC_EQ_S
+                | Opcode::FC_LT_S
+                | Opcode::FCVT_S_W
+                | Opcode::FCVT_W_S
+                | Opcode::MFC1
+                | Opcode::MTC1
+        )
+    }
 }
 
 impl Display for Opcode {
@@ -1,11 +1,129 @@
 //! Instructions for the ZKM.
 
-use anyhow::Result;
 use core::fmt::Debug;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use crate::{BinaryOperator, BranchCond, MemOp, MovCond};
 
+/// Floating-point operand width for a COP1 instruction's `fmt`/source-format field (bits 25..21
+/// when it selects a format rather than a move sub-op). `W` only ever appears as a [`FpConvert`]
+/// endpoint -- MIPS has no `ADD.W`/`C.EQ.W` etc.
+///
+/// [`FpConvert`]: Operation::FpConvert
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum FpFmt {
+    /// Single-precision (32-bit) float.
+    S,
+    /// Double-precision (64-bit) float.
+    D,
+    /// 32-bit signed integer, valid only as a [`FpConvert`](Operation::FpConvert) endpoint.
+    W,
+}
+
+/// A COP1 binary arithmetic operator, shared between the `S` and `D` formats.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum FpOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// A COP1 unary arithmetic operator.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum FpUnaryOp {
+    Abs,
+    Neg,
+    Mov,
+}
+
+/// A COP1 compare condition. Both write their result to FCSR condition-code 0, the only
+/// condition code this decoder's sibling execution semantics (see `cop1.rs`) currently models.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum FpCond {
+    Eq,
+    Lt,
+}
+
+/// A MIPS trap condition, shared between the `SPECIAL` register-register traps (`TGE`, ...) and
+/// the `REGIMM` register-immediate traps (`TGEI`, ...).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TrapCond {
+    Ge,
+    Geu,
+    Lt,
+    Ltu,
+    Eq,
+    Ne,
+}
+
+/// A COP1 GPR<->FPR/control-register move.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum FpMoveKind {
+    /// `mfc1 rt, fs`: `rt` = the low 32 bits of FPR `fs`.
+    Mfc1,
+    /// `mtc1 rt, fs`: the low 32 bits of FPR `fs` = `rt`.
+    Mtc1,
+    /// `cfc1 rt, fs`: `rt` = COP1 control register `fs` (only FCSR, register 31, is wired up).
+    Cfc1,
+    /// `ctc1 rt, fs`: COP1 control register `fs` = `rt`.
+    Ctc1,
+}
+
+/// Why [`Operation::decode_from`] rejected an instruction word.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// No opcode/func pairing in the table matches this word's `opcode` and `func`.
+    #[error("unknown opcode {opcode:#08b} (func {func:#08b})")]
+    UnknownOpcode {
+        /// The word's 6-bit primary opcode field (bits 31:26).
+        opcode: u8,
+        /// The word's 6-bit func field (bits 5:0).
+        func: u8,
+    },
+
+    /// `opcode`/`func` are recognized, but none of the sub-cases keyed on another field (`rt`,
+    /// `sa`, ...) matches the rest of the word.
+    #[error("unknown sub-case of opcode {opcode:#08b} func {func:#08b}")]
+    UnknownFunction {
+        /// The word's 6-bit primary opcode field (bits 31:26).
+        opcode: u8,
+        /// The word's 6-bit func field (bits 5:0).
+        func: u8,
+    },
+
+    /// The opcode/func pairing is one MIPS32 explicitly reserves rather than assigns.
+    #[error("reserved encoding: opcode {opcode:#08b} func {func:#08b}")]
+    ReservedField {
+        /// The word's 6-bit primary opcode field (bits 31:26).
+        opcode: u8,
+        /// The word's 6-bit func field (bits 5:0).
+        func: u8,
+    },
+}
+
+/// Why [`Operation::encode_to`] could not represent an [`Operation`] as a single instruction
+/// word.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodeError {
+    /// A register-operand field held a value that doesn't fit the 5-bit register encoding and
+    /// isn't one of the synthetic HI/LO sentinels (32/33) the opcode expects in that slot.
+    #[error("register operand {value} out of range for {slot}")]
+    RegisterOutOfRange {
+        /// Which operand slot rejected the value, e.g. `"rd"`.
+        slot: &'static str,
+        /// The out-of-range value.
+        value: u8,
+    },
+
+    /// No `(opcode, func)` pairing in [`Operation::decode_from`]'s table produces this
+    /// combination of variant, operator, and operands, so there's no instruction word to
+    /// reconstruct it from.
+    #[error("no instruction word encodes this operation")]
+    Unrepresentable,
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Operation {
     Syscall,
@@ -31,11 +149,75 @@ pub enum Operation {
     Rdhwr(u8, u8),
     Signext(u8, u8, u8),
     SwapHalf(u8, u8),
-    Teq(u8, u8),
+    /// `t{cond} rs, rt`: raise a trap exception if `rs`/`rt` satisfy `cond`.
+    Trap(TrapCond, u8, u8),
+    /// `t{cond}i rs, imm`: raise a trap exception if `rs`/`imm` satisfy `cond`.
+    TrapImm(TrapCond, u8, u16),
+    /// `break code`: unconditionally raise a breakpoint exception, `code` being the 20-bit
+    /// software-defined payload (bits 25..6).
+    Break(u32),
+    /// `op.fmt fd, fs, ft`: binary COP1 arithmetic, `fmt` being `S` or `D`.
+    FpBinary(FpOp, FpFmt, u8, u8, u8),
+    /// `op.fmt fd, fs`: unary COP1 arithmetic, `fmt` being `S` or `D`.
+    FpUnary(FpUnaryOp, FpFmt, u8, u8),
+    /// `cvt.to.from fd, fs`.
+    FpConvert(FpFmt, FpFmt, u8, u8),
+    /// `c.cond.fmt fs, ft`.
+    FpCompare(FpCond, FpFmt, u8, u8),
+    /// `mfc1`/`mtc1`/`cfc1`/`ctc1 rt, fs`.
+    FpMove(FpMoveKind, u8, u8),
+    /// `lwc1`/`ldc1 ft, offset(rs)`, `fmt` being `S` for `lwc1` or `D` for `ldc1`.
+    FpLoad(FpFmt, u8, u8, u32),
 }
 
 impl Operation {
-    pub fn decode_from(insn: u32) -> Result<Self> {
+    pub fn decode_from(insn: u32) -> Result<Self, DecodeError> {
+        /// Decodes a COP1 arithmetic/convert/compare `func` once `fmt`/`rs` has already picked
+        /// `fmt` out as `S`, `D`, or (for `cvt.*.w` only) `W`. `ft`/`fs`/`fd` are the COP1 FPR
+        /// operand fields, which sit where `rt`/`rd`/`sa` do for an ordinary R-type word.
+        fn decode_fp_arith(
+            fmt: FpFmt,
+            func: u8,
+            ft: u8,
+            fs: u8,
+            fd: u8,
+            opcode: u8,
+        ) -> Result<Operation, DecodeError> {
+            if fmt == FpFmt::W && !matches!(func, 0b100000 | 0b100001) {
+                return Err(DecodeError::UnknownFunction { opcode, func });
+            }
+            match func {
+                0b000000 => Ok(Operation::FpBinary(FpOp::Add, fmt, ft, fs, fd)),
+                0b000001 => Ok(Operation::FpBinary(FpOp::Sub, fmt, ft, fs, fd)),
+                0b000010 => Ok(Operation::FpBinary(FpOp::Mul, fmt, ft, fs, fd)),
+                0b000011 => Ok(Operation::FpBinary(FpOp::Div, fmt, ft, fs, fd)),
+                0b000101 => Ok(Operation::FpUnary(FpUnaryOp::Abs, fmt, fs, fd)),
+                0b000110 => Ok(Operation::FpUnary(FpUnaryOp::Mov, fmt, fs, fd)),
+                0b000111 => Ok(Operation::FpUnary(FpUnaryOp::Neg, fmt, fs, fd)),
+                0b100000 => Ok(Operation::FpConvert(fmt, FpFmt::S, fs, fd)),
+                0b100001 => Ok(Operation::FpConvert(fmt, FpFmt::D, fs, fd)),
+                0b100100 => Ok(Operation::FpConvert(fmt, FpFmt::W, fs, fd)),
+                0b110010 => Ok(Operation::FpCompare(FpCond::Eq, fmt, fs, ft)),
+                0b110100 => Ok(Operation::FpCompare(FpCond::Lt, fmt, fs, ft)),
+                _ => Err(DecodeError::UnknownFunction { opcode, func }),
+            }
+        }
+
+        /// Maps a `REGIMM` (opcode `0x01`) `rt` field to its trap condition, for the
+        /// register-immediate trap family (`TGEI`, `TGEIU`, `TLTI`, `TLTIU`, `TEQI`, `TNEI`)
+        /// that shares opcode `0x01` with `BGEZ`/`BLTZ`/`BAL`.
+        fn trap_imm_cond(rt: u8) -> Option<TrapCond> {
+            match rt {
+                0b01000 => Some(TrapCond::Ge),
+                0b01001 => Some(TrapCond::Geu),
+                0b01010 => Some(TrapCond::Lt),
+                0b01011 => Some(TrapCond::Ltu),
+                0b01100 => Some(TrapCond::Eq),
+                0b01110 => Some(TrapCond::Ne),
+                _ => None,
+            }
+        }
+
         let opcode = ((insn >> 26) & 0x3F).to_le_bytes()[0];
         let func = (insn & 0x3F).to_le_bytes()[0];
         let rt = ((insn >> 16) & 0x1F).to_le_bytes()[0];
@@ -159,9 +341,10 @@ impl Operation {
                     Ok(Operation::Branch(BranchCond::LT, rs, 0u8, offset)) // BLTZ
                 } else if rt == 0x11 && rs == 0 {
                     Ok(Operation::JumpDirect(31, offset)) // BAL
+                } else if let Some(cond) = trap_imm_cond(rt) {
+                    Ok(Operation::TrapImm(cond, rs, offset as u16))
                 } else {
-                    // todo: change to ProgramError later
-                    panic!("InvalidOpcode")
+                    Err(DecodeError::UnknownFunction { opcode, func })
                 }
             }
             (0x02, _) => Ok(Operation::Jumpi(0u8, target)), // J
@@ -186,6 +369,21 @@ impl Operation {
             (0b101110, _) => Ok(Operation::MstoreGeneral(MemOp::SWR, rs, rt, offset)),
             (0b111000, _) => Ok(Operation::MstoreGeneral(MemOp::SC, rs, rt, offset)),
             (0b111101, _) => Ok(Operation::MstoreGeneral(MemOp::SDC1, rs, rt, offset)),
+            (0b110001, _) => Ok(Operation::FpLoad(FpFmt::S, rs, rt, offset)), // LWC1
+            (0b110101, _) => Ok(Operation::FpLoad(FpFmt::D, rs, rt, offset)), // LDC1
+            (0b111001, _) => Ok(Operation::MstoreGeneral(MemOp::SWC1, rs, rt, offset)), // SWC1
+            // COP1: `rs` selects either a move sub-op or, for arithmetic/convert/compare, the
+            // source `fmt`. `ft`/`fs`/`fd` are `rt`/`rd`/`sa` under COP1's FPR operand layout.
+            (0b010001, _) => match rs {
+                0b00000 => Ok(Operation::FpMove(FpMoveKind::Mfc1, rt, rd)), // MFC1
+                0b00010 => Ok(Operation::FpMove(FpMoveKind::Cfc1, rt, rd)), // CFC1
+                0b00100 => Ok(Operation::FpMove(FpMoveKind::Mtc1, rt, rd)), // MTC1
+                0b00110 => Ok(Operation::FpMove(FpMoveKind::Ctc1, rt, rd)), // CTC1
+                0b10000 => decode_fp_arith(FpFmt::S, func, rt, rd, sa, opcode),
+                0b10001 => decode_fp_arith(FpFmt::D, func, rt, rd, sa, opcode),
+                0b10100 => decode_fp_arith(FpFmt::W, func, rt, rd, sa, opcode),
+                _ => Err(DecodeError::UnknownFunction { opcode, func }),
+            },
             (0b001000, _) => Ok(Operation::BinaryArithmeticImm(
                 BinaryOperator::ADDI,
                 rs,
@@ -280,20 +478,377 @@ impl Operation {
                         func,
                         sa
                     );
-                    // todo: change to ProgramError later
-                    panic!("InvalidOpcode")
+                    Err(DecodeError::UnknownFunction { opcode, func })
                 }
             }
-            (0b000000, 0b110100) => Ok(Operation::Teq(rs, rt)), // teq
+            (0b000000, 0b001101) => Ok(Operation::Break((insn >> 6) & 0xf_ffff)), // break
+            (0b000000, 0b110000) => Ok(Operation::Trap(TrapCond::Ge, rs, rt)), // tge
+            (0b000000, 0b110001) => Ok(Operation::Trap(TrapCond::Geu, rs, rt)), // tgeu
+            (0b000000, 0b110010) => Ok(Operation::Trap(TrapCond::Lt, rs, rt)), // tlt
+            (0b000000, 0b110011) => Ok(Operation::Trap(TrapCond::Ltu, rs, rt)), // tltu
+            (0b000000, 0b110100) => Ok(Operation::Trap(TrapCond::Eq, rs, rt)), // teq
+            (0b000000, 0b110110) => Ok(Operation::Trap(TrapCond::Ne, rs, rt)), // tne
             _ => {
                 log::warn!("decode: invalid opcode {:#08b} {:#08b}", opcode, func);
-                // todo: change to ProgramError later
-                panic!("InvalidOpcode")
+                Err(DecodeError::UnknownOpcode { opcode, func })
+            }
+        }
+    }
+
+    /// Reconstructs the instruction word [`Self::decode_from`] would decode back into `self`.
+    ///
+    /// Re-emits the `(opcode, func, sa)` special-casing the decoder folds together -- `SRL` vs
+    /// `ROR` sharing func `0b000010` (distinguished by `rs == 1`), `SEH`/`SEB`/`WSBH` sharing
+    /// `(0b011111, 0b100000)` (distinguished by `sa`), and `BAL` sharing opcode `0x01` with
+    /// `BGEZ`/`BLTZ` (distinguished by `rt`/`rs`) -- so `decode_from(op.encode_to()?) == Ok(op)`
+    /// for every `op` that `decode_from` can actually produce. Variants `decode_from` never
+    /// emits (e.g. [`Operation::KeccakGeneral`], reserved through a syscall rather than a raw
+    /// opcode) have no instruction word and return [`EncodeError::Unrepresentable`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodeError::RegisterOutOfRange`] if a register-operand field doesn't fit 5
+    /// bits in a slot that doesn't accept the synthetic HI/LO sentinels, or
+    /// [`EncodeError::Unrepresentable`] if no `(opcode, func)` pairing produces this operation.
+    pub fn encode_to(&self) -> Result<u32, EncodeError> {
+        fn reg(slot: &'static str, value: u8) -> Result<u32, EncodeError> {
+            if value < 32 {
+                Ok(u32::from(value))
+            } else {
+                Err(EncodeError::RegisterOutOfRange { slot, value })
+            }
+        }
+        fn r_word(opcode: u32, rs: u32, rt: u32, rd: u32, sa: u32, func: u32) -> u32 {
+            (opcode << 26) | (rs << 21) | (rt << 16) | (rd << 11) | (sa << 6) | func
+        }
+        fn i_word(opcode: u32, rs: u32, rt: u32, imm: u32) -> u32 {
+            (opcode << 26) | (rs << 21) | (rt << 16) | (imm & 0xffff)
+        }
+        fn j_word(opcode: u32, target: u32) -> u32 {
+            (opcode << 26) | (target & 0x3ff_ffff)
+        }
+
+        match *self {
+            Operation::Syscall => Ok(r_word(0b000000, 0, 0, 0, 0, 0b001100)),
+            Operation::BinaryArithmetic(op, a, b, rd) => match op {
+                BinaryOperator::ADD => {
+                    Ok(r_word(0, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b100000))
+                }
+                BinaryOperator::ADDU => {
+                    Ok(r_word(0, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b100001))
+                }
+                BinaryOperator::SUB => {
+                    Ok(r_word(0, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b100010))
+                }
+                BinaryOperator::SUBU => {
+                    Ok(r_word(0, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b100011))
+                }
+                BinaryOperator::SLL => {
+                    Ok(r_word(0, 0, reg("rt", b)?, reg("rd", rd)?, u32::from(a), 0b000000))
+                }
+                // rs == 0 here distinguishes plain SRL from ROR, which the decoder recognizes
+                // by rs == 1 at this same (opcode, func).
+                BinaryOperator::SRL => {
+                    Ok(r_word(0, 0, reg("rt", b)?, reg("rd", rd)?, u32::from(a), 0b000010))
+                }
+                BinaryOperator::SRA => {
+                    Ok(r_word(0, 0, reg("rt", b)?, reg("rd", rd)?, u32::from(a), 0b000011))
+                }
+                BinaryOperator::SLLV => {
+                    Ok(r_word(0, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b000100))
+                }
+                BinaryOperator::SRLV => {
+                    Ok(r_word(0, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b000110))
+                }
+                BinaryOperator::SRAV => {
+                    Ok(r_word(0, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b000111))
+                }
+                BinaryOperator::MUL => {
+                    Ok(r_word(0b011100, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b000010))
+                }
+                BinaryOperator::MULT => {
+                    Ok(r_word(0, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b011000))
+                }
+                BinaryOperator::MULTU => {
+                    Ok(r_word(0, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b011001))
+                }
+                BinaryOperator::DIV => {
+                    Ok(r_word(0, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b011010))
+                }
+                BinaryOperator::DIVU => {
+                    Ok(r_word(0, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b011011))
+                }
+                BinaryOperator::MFHI if a == 33 => {
+                    Ok(r_word(0, 0, 0, reg("rd", rd)?, 0, 0b010000))
+                }
+                BinaryOperator::MTHI if rd == 33 => Ok(r_word(0, reg("rs", a)?, 0, 0, 0, 0b010001)),
+                BinaryOperator::MFLO if a == 32 => {
+                    Ok(r_word(0, 0, 0, reg("rd", rd)?, 0, 0b010010))
+                }
+                BinaryOperator::MTLO if rd == 32 => Ok(r_word(0, reg("rs", a)?, 0, 0, 0, 0b010011)),
+                BinaryOperator::SLT => {
+                    Ok(r_word(0, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b101010))
+                }
+                BinaryOperator::SLTU => {
+                    Ok(r_word(0, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b101011))
+                }
+                BinaryOperator::AND => {
+                    Ok(r_word(0, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b100100))
+                }
+                BinaryOperator::OR => {
+                    Ok(r_word(0, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b100101))
+                }
+                BinaryOperator::XOR => {
+                    Ok(r_word(0, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b100110))
+                }
+                BinaryOperator::NOR => {
+                    Ok(r_word(0, reg("rs", a)?, reg("rt", b)?, reg("rd", rd)?, 0, 0b100111))
+                }
+                _ => Err(EncodeError::Unrepresentable),
+            },
+            Operation::BinaryArithmeticImm(op, rs, rt, imm) => match op {
+                BinaryOperator::ADDI => {
+                    Ok(i_word(0b001000, reg("rs", rs)?, reg("rt", rt)?, imm))
+                }
+                BinaryOperator::ADDIU => {
+                    Ok(i_word(0b001001, reg("rs", rs)?, reg("rt", rt)?, imm))
+                }
+                BinaryOperator::SLTI => {
+                    Ok(i_word(0b001010, reg("rs", rs)?, reg("rt", rt)?, imm))
+                }
+                BinaryOperator::SLTIU => {
+                    Ok(i_word(0b001011, reg("rs", rs)?, reg("rt", rt)?, imm))
+                }
+                BinaryOperator::LUI => Ok(i_word(0b001111, reg("rs", rs)?, reg("rt", rt)?, imm)),
+                BinaryOperator::AND => Ok(i_word(0b001100, reg("rs", rs)?, reg("rt", rt)?, imm)),
+                BinaryOperator::OR => Ok(i_word(0b001101, reg("rs", rs)?, reg("rt", rt)?, imm)),
+                BinaryOperator::XOR => Ok(i_word(0b001110, reg("rs", rs)?, reg("rt", rt)?, imm)),
+                _ => Err(EncodeError::Unrepresentable),
+            },
+            Operation::Count(leading_ones, rs, rd) => {
+                let func = if leading_ones { 0b100001 } else { 0b100000 };
+                Ok(r_word(0b011100, reg("rs", rs)?, 0, reg("rd", rd)?, 0, func))
+            }
+            Operation::CondMov(cond, rs, rt, rd) => {
+                let func = match cond {
+                    MovCond::EQ => 0b001010,
+                    MovCond::NE => 0b001011,
+                };
+                Ok(r_word(0, reg("rs", rs)?, reg("rt", rt)?, reg("rd", rd)?, 0, func))
+            }
+            Operation::KeccakGeneral
+            | Operation::Pc
+            | Operation::GetContext
+            | Operation::SetContext => Err(EncodeError::Unrepresentable),
+            Operation::Jump(0, rs) => Ok(r_word(0, reg("rs", rs)?, 0, 0, 0, 0x08)), // JR
+            Operation::Jump(rd, rs) => {
+                Ok(r_word(0, reg("rs", rs)?, 0, reg("rd", rd)?, 0, 0x09)) // JALR
+            }
+            Operation::Jumpi(0, target) => Ok(j_word(0x02, target)), // J
+            Operation::Jumpi(31, target) => Ok(j_word(0x03, target)), // JAL
+            Operation::Jumpi(_, _) => Err(EncodeError::Unrepresentable),
+            Operation::Branch(BranchCond::EQ, rs, rt, offset) => {
+                Ok(i_word(0x04, reg("rs", rs)?, reg("rt", rt)?, offset))
+            }
+            Operation::Branch(BranchCond::NE, rs, rt, offset) => {
+                Ok(i_word(0x05, reg("rs", rs)?, reg("rt", rt)?, offset))
+            }
+            Operation::Branch(BranchCond::LE, rs, 0, offset) => {
+                Ok(i_word(0x06, reg("rs", rs)?, 0, offset))
             }
+            Operation::Branch(BranchCond::GT, rs, 0, offset) => {
+                Ok(i_word(0x07, reg("rs", rs)?, 0, offset))
+            }
+            Operation::Branch(BranchCond::GE, rs, 0, offset) => {
+                Ok(i_word(0x01, reg("rs", rs)?, 1, offset)) // BGEZ
+            }
+            Operation::Branch(BranchCond::LT, rs, 0, offset) => {
+                Ok(i_word(0x01, reg("rs", rs)?, 0, offset)) // BLTZ
+            }
+            Operation::Branch(_, _, _, _) => Err(EncodeError::Unrepresentable),
+            Operation::JumpDirect(31, offset) => Ok(i_word(0x01, 0, 0x11, offset)), // BAL
+            Operation::JumpDirect(_, _) => Err(EncodeError::Unrepresentable),
+            Operation::MloadGeneral(op, rs, rt, offset) => {
+                let opcode = match op {
+                    MemOp::LB => 0b100000,
+                    MemOp::LH => 0b100001,
+                    MemOp::LWL => 0b100010,
+                    MemOp::LW => 0b100011,
+                    MemOp::LBU => 0b100100,
+                    MemOp::LHU => 0b100101,
+                    MemOp::LWR => 0b100110,
+                    MemOp::LL => 0b110000,
+                    _ => return Err(EncodeError::Unrepresentable),
+                };
+                Ok(i_word(opcode, reg("rs", rs)?, reg("rt", rt)?, offset))
+            }
+            Operation::MstoreGeneral(op, rs, rt, offset) => {
+                let opcode = match op {
+                    MemOp::SB => 0b101000,
+                    MemOp::SH => 0b101001,
+                    MemOp::SWL => 0b101010,
+                    MemOp::SW => 0b101011,
+                    MemOp::SWR => 0b101110,
+                    MemOp::SC => 0b111000,
+                    MemOp::SDC1 => 0b111101,
+                    MemOp::SWC1 => 0b111001,
+                    _ => return Err(EncodeError::Unrepresentable),
+                };
+                Ok(i_word(opcode, reg("rs", rs)?, reg("rt", rt)?, offset))
+            }
+            Operation::Nop => Ok(r_word(0b110011, 0, 0, 0, 0, 0)), // Pref
+            Operation::Ext(rt, rs, rd, sa) => Ok(r_word(
+                0b011111,
+                reg("rs", rs)?,
+                reg("rt", rt)?,
+                reg("rd", rd)?,
+                u32::from(sa),
+                0b000000,
+            )),
+            Operation::Ins(rt, rs, rd, sa) => Ok(r_word(
+                0b011111,
+                reg("rs", rs)?,
+                reg("rt", rt)?,
+                reg("rd", rd)?,
+                u32::from(sa),
+                0b000100,
+            )),
+            Operation::Maddu(rt, rs) => {
+                Ok(r_word(0b011100, reg("rs", rs)?, reg("rt", rt)?, 0, 0, 0b000001))
+            }
+            // rs == 1 here is what distinguishes ROR from SRL at this same (opcode, func).
+            Operation::Ror(rd, rt, sa) => {
+                Ok(r_word(0, 1, reg("rt", rt)?, reg("rd", rd)?, u32::from(sa), 0b000010))
+            }
+            Operation::Rdhwr(rt, rd) => {
+                Ok(r_word(0b011111, 0, reg("rt", rt)?, reg("rd", rd)?, 0, 0b111011))
+            }
+            Operation::Signext(rd, rt, 16) => {
+                Ok(r_word(0b011111, 0, reg("rt", rt)?, reg("rd", rd)?, 0b011000, 0b100000)) // seh
+            }
+            Operation::Signext(rd, rt, 8) => {
+                Ok(r_word(0b011111, 0, reg("rt", rt)?, reg("rd", rd)?, 0b010000, 0b100000)) // seb
+            }
+            Operation::Signext(_, _, _) => Err(EncodeError::Unrepresentable),
+            Operation::SwapHalf(rd, rt) => {
+                Ok(r_word(0b011111, 0, reg("rt", rt)?, reg("rd", rd)?, 0b000010, 0b100000))
+                // wsbh
+            }
+            Operation::Trap(cond, rs, rt) => {
+                let func = match cond {
+                    TrapCond::Ge => 0b110000,
+                    TrapCond::Geu => 0b110001,
+                    TrapCond::Lt => 0b110010,
+                    TrapCond::Ltu => 0b110011,
+                    TrapCond::Eq => 0b110100,
+                    TrapCond::Ne => 0b110110,
+                };
+                Ok(r_word(0, reg("rs", rs)?, reg("rt", rt)?, 0, 0, func))
+            }
+            Operation::TrapImm(cond, rs, imm) => {
+                let rt = match cond {
+                    TrapCond::Ge => 0b01000,
+                    TrapCond::Geu => 0b01001,
+                    TrapCond::Lt => 0b01010,
+                    TrapCond::Ltu => 0b01011,
+                    TrapCond::Eq => 0b01100,
+                    TrapCond::Ne => 0b01110,
+                };
+                Ok(i_word(0x01, reg("rs", rs)?, rt, u32::from(imm)))
+            }
+            Operation::Break(code) => {
+                if code > 0xf_ffff {
+                    return Err(EncodeError::Unrepresentable);
+                }
+                Ok((code << 6) | 0b001101)
+            }
+            Operation::FpBinary(op, fmt, ft, fs, fd) => {
+                let fmt_bits = match fmt {
+                    FpFmt::S => 0b10000,
+                    FpFmt::D => 0b10001,
+                    FpFmt::W => return Err(EncodeError::Unrepresentable),
+                };
+                let func = match op {
+                    FpOp::Add => 0b000000,
+                    FpOp::Sub => 0b000001,
+                    FpOp::Mul => 0b000010,
+                    FpOp::Div => 0b000011,
+                };
+                Ok(r_word(0b010001, fmt_bits, reg("ft", ft)?, reg("fs", fs)?, reg("fd", fd)?, func))
+            }
+            Operation::FpUnary(op, fmt, fs, fd) => {
+                let fmt_bits = match fmt {
+                    FpFmt::S => 0b10000,
+                    FpFmt::D => 0b10001,
+                    FpFmt::W => return Err(EncodeError::Unrepresentable),
+                };
+                let func = match op {
+                    FpUnaryOp::Abs => 0b000101,
+                    FpUnaryOp::Mov => 0b000110,
+                    FpUnaryOp::Neg => 0b000111,
+                };
+                Ok(r_word(0b010001, fmt_bits, 0, reg("fs", fs)?, reg("fd", fd)?, func))
+            }
+            Operation::FpConvert(from, to, fs, fd) => {
+                let fmt_bits = match from {
+                    FpFmt::S => 0b10000,
+                    FpFmt::D => 0b10001,
+                    FpFmt::W => 0b10100,
+                };
+                let func = match to {
+                    FpFmt::S => 0b100000,
+                    FpFmt::D => 0b100001,
+                    FpFmt::W => 0b100100,
+                };
+                Ok(r_word(0b010001, fmt_bits, 0, reg("fs", fs)?, reg("fd", fd)?, func))
+            }
+            Operation::FpCompare(cond, fmt, fs, ft) => {
+                let fmt_bits = match fmt {
+                    FpFmt::S => 0b10000,
+                    FpFmt::D => 0b10001,
+                    FpFmt::W => return Err(EncodeError::Unrepresentable),
+                };
+                let func = match cond {
+                    FpCond::Eq => 0b110010,
+                    FpCond::Lt => 0b110100,
+                };
+                Ok(r_word(0b010001, fmt_bits, reg("ft", ft)?, reg("fs", fs)?, 0, func))
+            }
+            Operation::FpMove(kind, rt, fs) => {
+                let sub = match kind {
+                    FpMoveKind::Mfc1 => 0b00000,
+                    FpMoveKind::Cfc1 => 0b00010,
+                    FpMoveKind::Mtc1 => 0b00100,
+                    FpMoveKind::Ctc1 => 0b00110,
+                };
+                Ok(r_word(0b010001, sub, reg("rt", rt)?, reg("fs", fs)?, 0, 0))
+            }
+            Operation::FpLoad(fmt, rs, ft, offset) => {
+                let opcode = match fmt {
+                    FpFmt::S => 0b110001,
+                    FpFmt::D => 0b110101,
+                    FpFmt::W => return Err(EncodeError::Unrepresentable),
+                };
+                Ok(i_word(opcode, reg("rs", rs)?, reg("ft", ft)?, offset))
+            }
+        }
+    }
+
+    /// The [`TrapCond`] this operation evaluates, for [`Operation::Trap`] and
+    /// [`Operation::TrapImm`] -- `None` for every other variant, including [`Operation::Break`],
+    /// which traps unconditionally and so has no condition to evaluate.
+    #[must_use]
+    pub fn trap_condition(&self) -> Option<TrapCond> {
+        match *self {
+            Operation::Trap(cond, _, _) | Operation::TrapImm(cond, _, _) => Some(cond),
+            _ => None,
         }
     }
 
-    //todo: remove
+    /// Whether this operation is a `MULT`/`MULTU`/`DIV`/`DIVU` that writes both synthetic HI/LO
+    /// registers (the wide half of a multiply or the remainder of a divide). The HI/LO moves
+    /// (`MFHI`/`MTHI`/`MFLO`/`MTLO`) return `false`.
+    #[must_use]
     pub fn is_use_lo_hi_alu(&self) -> bool {
         match self {
             Operation::BinaryArithmetic(BinaryOperator::DIV, _, _, _)
@@ -307,83 +862,469 @@ impl Operation {
             _ => false,
         }
     }
-}
-/*
-impl Instruction {
-    /// Create a new [`RiscvInstruction`].
-    #[must_use]
-    pub const fn new(
-        opcode: Opcode,
-        op_a: u8,
-        op_b: u32,
-        op_c: u32,
-        imm_b: bool,
-        imm_c: bool,
-    ) -> Self {
-        Self { opcode, op_a, op_b, op_c, imm_b, imm_c }
-    }
-
-    /// Returns if the instruction is an ALU instruction.
+
+    /// Whether this is an ALU operation: a register-register or register-immediate arithmetic,
+    /// logic, or shift, keyed on the enclosing [`BinaryOperator`] rather than any individual one,
+    /// since every [`BinaryOperator`] variant is an ALU op in one of these two forms.
     #[must_use]
     pub const fn is_alu_instruction(&self) -> bool {
-        matches!(
-            self.opcode,
-            Opcode::ADD
-                | Opcode::SUB
-                | Opcode::XOR
-                | Opcode::OR
-                | Opcode::AND
-                | Opcode::SLL
-                | Opcode::SRL
-                | Opcode::SRA
-                | Opcode::SLT
-                | Opcode::SLTU
-                | Opcode::MUL
-                | Opcode::MULH
-                | Opcode::MULHU
-                | Opcode::MULHSU
-                | Opcode::DIV
-                | Opcode::DIVU
-                | Opcode::REM
-                | Opcode::REMU
-        )
+        matches!(self, Operation::BinaryArithmetic(..) | Operation::BinaryArithmeticImm(..))
     }
 
-    /// Returns if the instruction is a syscall instruction.
+    /// Whether this operation traps into the runtime via `syscall`.
     #[must_use]
-    pub fn is_syscall_instruction(&self) -> bool {
-        self.opcode == Opcode::SYSCALL
+    pub const fn is_syscall_instruction(&self) -> bool {
+        matches!(self, Operation::Syscall)
     }
 
-    /// Returns if the instruction is a memory instruction.
+    /// Whether this operation addresses memory: a GPR load/store, or a COP1 `lwc1`/`ldc1`
+    /// (`swc1`/`sdc1` are GPR-shaped [`Operation::MstoreGeneral`]s and so already covered there).
     #[must_use]
     pub const fn is_memory_instruction(&self) -> bool {
         matches!(
-            self.opcode,
-            Opcode::LB
-                | Opcode::LH
-                | Opcode::LW
-                | Opcode::LBU
-                | Opcode::LHU
-                | Opcode::SB
-                | Opcode::SH
-                | Opcode::SW
+            self,
+            Operation::MloadGeneral(..) | Operation::MstoreGeneral(..) | Operation::FpLoad(..)
         )
     }
 
-    /// Returns if the instruction is a branch instruction.
+    /// Whether this operation is a conditional branch.
     #[must_use]
     pub const fn is_branch_instruction(&self) -> bool {
-        matches!(
-            self.opcode,
-            Opcode::BEQ | Opcode::BNE | Opcode::BLT | Opcode::BGE | Opcode::BLTU | Opcode::BGEU
-        )
+        matches!(self, Operation::Branch(..))
     }
 
-    /// Returns if the instruction is a jump instruction.
+    /// Whether this operation is an unconditional jump, direct or indirect.
     #[must_use]
     pub const fn is_jump_instruction(&self) -> bool {
-        matches!(self.opcode, Opcode::JAL | Opcode::JALR)
+        matches!(self, Operation::Jump(..) | Operation::Jumpi(..) | Operation::JumpDirect(..))
+    }
+}
+
+#[cfg(test)]
+mod encode_decode_tests {
+    use super::{
+        BinaryOperator, BranchCond, EncodeError, FpCond, FpFmt, FpMoveKind, FpOp, FpUnaryOp,
+        MemOp, MovCond, Operation, TrapCond,
+    };
+
+    fn assert_round_trips(op: Operation) {
+        let word = op.encode_to().expect("op should be representable");
+        assert_eq!(Operation::decode_from(word), Ok(op));
+    }
+
+    #[test]
+    fn round_trips_register_arithmetic() {
+        assert_round_trips(Operation::BinaryArithmetic(BinaryOperator::ADD, 1, 2, 3));
+        assert_round_trips(Operation::BinaryArithmetic(BinaryOperator::NOR, 4, 5, 6));
+    }
+
+    #[test]
+    fn round_trips_srl_and_ror_sharing_func_0b000010() {
+        // SRL: rs == 0, a plain shift amount in place of a register.
+        assert_round_trips(Operation::BinaryArithmetic(BinaryOperator::SRL, 7, 8, 9));
+        // ROR: the same (opcode, func) with rs == 1 baked into `encode_to`.
+        assert_round_trips(Operation::Ror(9, 8, 7));
+    }
+
+    #[test]
+    fn round_trips_hi_lo_transfers_without_emitting_32_or_33() {
+        let mfhi = Operation::BinaryArithmetic(BinaryOperator::MFHI, 33, 0, 10);
+        let word = mfhi.encode_to().expect("MFHI should be representable");
+        assert_eq!((word >> 21) & 0x1f, 0, "the HI sentinel must not reach the rs field");
+        assert_round_trips(mfhi);
+
+        assert_round_trips(Operation::BinaryArithmetic(BinaryOperator::MTHI, 11, 0, 33));
+        assert_round_trips(Operation::BinaryArithmetic(BinaryOperator::MFLO, 32, 0, 12));
+        assert_round_trips(Operation::BinaryArithmetic(BinaryOperator::MTLO, 13, 0, 32));
+    }
+
+    #[test]
+    fn round_trips_seh_seb_wsbh_sharing_opcode_and_func() {
+        assert_round_trips(Operation::Signext(1, 2, 16)); // seh
+        assert_round_trips(Operation::Signext(1, 2, 8)); // seb
+        assert_round_trips(Operation::SwapHalf(1, 2)); // wsbh
+    }
+
+    #[test]
+    fn round_trips_bal_sharing_opcode_with_bgez_bltz() {
+        assert_round_trips(Operation::Branch(BranchCond::GE, 4, 0, 0x100));
+        assert_round_trips(Operation::Branch(BranchCond::LT, 4, 0, 0x100));
+        assert_round_trips(Operation::JumpDirect(31, 0x100));
+    }
+
+    #[test]
+    fn round_trips_loads_stores_and_cond_mov() {
+        assert_round_trips(Operation::MloadGeneral(MemOp::LW, 5, 6, 0x10));
+        assert_round_trips(Operation::MstoreGeneral(MemOp::SW, 5, 6, 0x10));
+        assert_round_trips(Operation::CondMov(MovCond::EQ, 1, 2, 3));
+    }
+
+    #[test]
+    fn unrepresentable_operations_are_rejected() {
+        assert_eq!(Operation::Pc.encode_to(), Err(EncodeError::Unrepresentable));
+        assert_eq!(Operation::KeccakGeneral.encode_to(), Err(EncodeError::Unrepresentable));
+    }
+
+    #[test]
+    fn round_trips_fp_arithmetic_and_compare() {
+        assert_round_trips(Operation::FpBinary(FpOp::Add, FpFmt::S, 1, 2, 3));
+        assert_round_trips(Operation::FpBinary(FpOp::Div, FpFmt::D, 4, 5, 6));
+        assert_round_trips(Operation::FpUnary(FpUnaryOp::Neg, FpFmt::S, 7, 8));
+        assert_round_trips(Operation::FpCompare(FpCond::Lt, FpFmt::D, 9, 10));
+    }
+
+    #[test]
+    fn round_trips_fp_convert_between_int_and_float() {
+        assert_round_trips(Operation::FpConvert(FpFmt::W, FpFmt::S, 1, 2));
+        assert_round_trips(Operation::FpConvert(FpFmt::S, FpFmt::W, 3, 4));
+        assert_round_trips(Operation::FpConvert(FpFmt::S, FpFmt::D, 5, 6));
+    }
+
+    #[test]
+    fn round_trips_fp_moves_and_loads() {
+        assert_round_trips(Operation::FpMove(FpMoveKind::Mtc1, 1, 2));
+        assert_round_trips(Operation::FpMove(FpMoveKind::Cfc1, 3, 31));
+        assert_round_trips(Operation::FpLoad(FpFmt::S, 4, 5, 0x20));
+        assert_round_trips(Operation::FpLoad(FpFmt::D, 4, 6, 0x30));
+        assert_round_trips(Operation::MstoreGeneral(MemOp::SWC1, 4, 7, 0x40));
+    }
+
+    #[test]
+    fn round_trips_register_and_immediate_traps() {
+        assert_round_trips(Operation::Trap(TrapCond::Ge, 1, 2));
+        assert_round_trips(Operation::Trap(TrapCond::Ne, 3, 4));
+        assert_round_trips(Operation::TrapImm(TrapCond::Eq, 5, 0x100));
+        assert_round_trips(Operation::TrapImm(TrapCond::Ltu, 6, 0xffff));
+    }
+
+    #[test]
+    fn round_trips_break_with_its_20_bit_code() {
+        assert_round_trips(Operation::Break(0x1_2345));
+        assert_eq!(
+            Operation::Break(0x10_0000).encode_to(),
+            Err(EncodeError::Unrepresentable)
+        );
+    }
+
+    #[test]
+    fn trap_condition_is_none_for_unconditional_break() {
+        assert_eq!(Operation::Trap(TrapCond::Eq, 1, 2).trap_condition(), Some(TrapCond::Eq));
+        assert_eq!(Operation::Break(0).trap_condition(), None);
+    }
+
+    #[test]
+    fn fp_ops_with_word_format_are_unrepresentable() {
+        assert_eq!(
+            Operation::FpBinary(FpOp::Add, FpFmt::W, 1, 2, 3).encode_to(),
+            Err(EncodeError::Unrepresentable)
+        );
+        assert_eq!(
+            Operation::FpLoad(FpFmt::W, 1, 2, 0).encode_to(),
+            Err(EncodeError::Unrepresentable)
+        );
+    }
+
+    #[test]
+    fn classifies_alu_and_syscall_instructions() {
+        let add = Operation::BinaryArithmetic(BinaryOperator::ADD, 1, 2, 3);
+        assert!(add.is_alu_instruction());
+        assert!(!add.is_syscall_instruction());
+
+        let addi = Operation::BinaryArithmeticImm(BinaryOperator::ADDI, 1, 2, 4);
+        assert!(addi.is_alu_instruction());
+
+        assert!(Operation::Syscall.is_syscall_instruction());
+        assert!(!Operation::Syscall.is_alu_instruction());
+    }
+
+    #[test]
+    fn classifies_memory_instructions_including_fp_loads() {
+        assert!(Operation::MloadGeneral(MemOp::LW, 1, 2, 0).is_memory_instruction());
+        assert!(Operation::MstoreGeneral(MemOp::SW, 1, 2, 0).is_memory_instruction());
+        assert!(Operation::MstoreGeneral(MemOp::SWC1, 1, 2, 0).is_memory_instruction());
+        assert!(Operation::FpLoad(FpFmt::S, 1, 2, 0).is_memory_instruction());
+        assert!(!Operation::Nop.is_memory_instruction());
+    }
+
+    #[test]
+    fn classifies_branch_and_jump_instructions() {
+        assert!(Operation::Branch(BranchCond::EQ, 1, 2, 0x10).is_branch_instruction());
+        assert!(!Operation::Branch(BranchCond::EQ, 1, 2, 0x10).is_jump_instruction());
+
+        assert!(Operation::Jump(1, 2).is_jump_instruction());
+        assert!(Operation::Jumpi(1, 0x100).is_jump_instruction());
+        assert!(Operation::JumpDirect(1, 0x100).is_jump_instruction());
+        assert!(!Operation::Jump(1, 2).is_branch_instruction());
+    }
+}
+
+/// Register name for operand `idx`, aliasing the synthetic HI/LO indices (33/32) that
+/// [`BinaryOperator::MFHI`]/`MTHI`/`MFLO`/`MTLO` smuggle through the normal register-operand
+/// slots, so a disassembly line reads `mfhi $rd` / `$rd, hi` rather than an out-of-range `$33`.
+fn reg_name(idx: u8) -> &'static str {
+    match idx {
+        32 => "lo",
+        33 => "hi",
+        idx => crate::disasm::ABI_REGISTER_NAMES.get(idx as usize).copied().unwrap_or("?"),
+    }
+}
+
+/// Mnemonic for a register-register [`Operation::BinaryArithmetic`].
+fn binop_mnemonic(op: BinaryOperator) -> &'static str {
+    match op {
+        BinaryOperator::ADD => "add",
+        BinaryOperator::ADDU => "addu",
+        BinaryOperator::SUB => "sub",
+        BinaryOperator::SUBU => "subu",
+        BinaryOperator::SLL => "sll",
+        BinaryOperator::SRL => "srl",
+        BinaryOperator::SRA => "sra",
+        BinaryOperator::SLLV => "sllv",
+        BinaryOperator::SRLV => "srlv",
+        BinaryOperator::SRAV => "srav",
+        BinaryOperator::MUL => "mul",
+        BinaryOperator::MULT => "mult",
+        BinaryOperator::MULTU => "multu",
+        BinaryOperator::DIV => "div",
+        BinaryOperator::DIVU => "divu",
+        BinaryOperator::MFHI => "mfhi",
+        BinaryOperator::MTHI => "mthi",
+        BinaryOperator::MFLO => "mflo",
+        BinaryOperator::MTLO => "mtlo",
+        BinaryOperator::SLT => "slt",
+        BinaryOperator::SLTU => "sltu",
+        BinaryOperator::AND => "and",
+        BinaryOperator::OR => "or",
+        BinaryOperator::XOR => "xor",
+        BinaryOperator::NOR => "nor",
+        BinaryOperator::LUI => "lui",
+        BinaryOperator::ADDI | BinaryOperator::ADDIU | BinaryOperator::SLTI
+        | BinaryOperator::SLTIU => "?",
+    }
+}
+
+/// Mnemonic for an immediate [`Operation::BinaryArithmeticImm`]. `AND`/`OR`/`XOR`/`LUI` are the
+/// same [`BinaryOperator`] variants [`binop_mnemonic`] renders for the register-register form;
+/// only the `i` suffix (or LUI's lack of one) distinguishes them here.
+fn binop_imm_mnemonic(op: BinaryOperator) -> &'static str {
+    match op {
+        BinaryOperator::ADDI => "addi",
+        BinaryOperator::ADDIU => "addiu",
+        BinaryOperator::SLTI => "slti",
+        BinaryOperator::SLTIU => "sltiu",
+        BinaryOperator::LUI => "lui",
+        BinaryOperator::AND => "andi",
+        BinaryOperator::OR => "ori",
+        BinaryOperator::XOR => "xori",
+        _ => "?",
+    }
+}
+
+/// Mnemonic for a [`BranchCond`], MIPS style: `EQ`/`NE` compare two registers, the rest compare
+/// a single register against zero.
+fn branch_mnemonic(cond: BranchCond) -> &'static str {
+    match cond {
+        BranchCond::EQ => "beq",
+        BranchCond::NE => "bne",
+        BranchCond::LE => "blez",
+        BranchCond::GT => "bgtz",
+        BranchCond::GE => "bgez",
+        BranchCond::LT => "bltz",
+    }
+}
+
+/// Mnemonic suffix for a [`TrapCond`]: `t{cond}`/`t{cond}i`.
+fn trap_mnemonic(cond: TrapCond) -> &'static str {
+    match cond {
+        TrapCond::Ge => "ge",
+        TrapCond::Geu => "geu",
+        TrapCond::Lt => "lt",
+        TrapCond::Ltu => "ltu",
+        TrapCond::Eq => "eq",
+        TrapCond::Ne => "ne",
+    }
+}
+
+/// Name for a floating-point register operand, MIPS assembly convention minus the `$`
+/// (`reg_name` omits it too): `f0`..`f31`.
+fn fpr_name(idx: u8) -> String {
+    format!("f{idx}")
+}
+
+/// The `.s`/`.d`/`.w` suffix most COP1 mnemonics carry to name their operand format.
+fn fp_fmt_suffix(fmt: FpFmt) -> &'static str {
+    match fmt {
+        FpFmt::S => "s",
+        FpFmt::D => "d",
+        FpFmt::W => "w",
+    }
+}
+
+impl core::fmt::Display for Operation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Operation::Syscall => write!(f, "syscall"),
+            Operation::BinaryArithmetic(op, a, b, rd) => {
+                write!(
+                    f,
+                    "{} {}, {}, {}",
+                    binop_mnemonic(op),
+                    reg_name(rd),
+                    reg_name(a),
+                    reg_name(b)
+                )
+            }
+            Operation::BinaryArithmeticImm(op, rs, rt, imm) => {
+                write!(
+                    f,
+                    "{} {}, {}, {}",
+                    binop_imm_mnemonic(op),
+                    reg_name(rt),
+                    reg_name(rs),
+                    imm as i32
+                )
+            }
+            Operation::Count(leading_ones, rs, rd) => {
+                let mnemonic = if leading_ones { "clo" } else { "clz" };
+                write!(f, "{mnemonic} {}, {}", reg_name(rd), reg_name(rs))
+            }
+            Operation::CondMov(cond, rs, rt, rd) => {
+                let mnemonic = match cond {
+                    MovCond::EQ => "movz",
+                    MovCond::NE => "movn",
+                };
+                write!(f, "{mnemonic} {}, {}, {}", reg_name(rd), reg_name(rs), reg_name(rt))
+            }
+            Operation::KeccakGeneral => write!(f, "keccak_general"),
+            Operation::Jump(0, rs) => write!(f, "jr {}", reg_name(rs)),
+            Operation::Jump(rd, rs) => write!(f, "jalr {}, {}", reg_name(rd), reg_name(rs)),
+            Operation::Jumpi(0, target) => write!(f, "j 0x{target:08x}"),
+            Operation::Jumpi(_, target) => write!(f, "jal 0x{target:08x}"),
+            Operation::Branch(cond, rs, 0, offset)
+                if !matches!(cond, BranchCond::EQ | BranchCond::NE) =>
+            {
+                write!(f, "{} {}, {}", branch_mnemonic(cond), reg_name(rs), offset as i32)
+            }
+            Operation::Branch(cond, rs, rt, offset) => {
+                write!(
+                    f,
+                    "{} {}, {}, {}",
+                    branch_mnemonic(cond),
+                    reg_name(rs),
+                    reg_name(rt),
+                    offset as i32
+                )
+            }
+            Operation::JumpDirect(rd, offset) => {
+                write!(f, "bal {}, {}", reg_name(rd), offset as i32)
+            }
+            Operation::Pc => write!(f, "pc"),
+            Operation::GetContext => write!(f, "getctx"),
+            Operation::SetContext => write!(f, "setctx"),
+            Operation::MloadGeneral(op, rs, rt, offset) => {
+                let mnemonic = match op {
+                    MemOp::LB => "lb",
+                    MemOp::LH => "lh",
+                    MemOp::LWL => "lwl",
+                    MemOp::LW => "lw",
+                    MemOp::LBU => "lbu",
+                    MemOp::LHU => "lhu",
+                    MemOp::LWR => "lwr",
+                    MemOp::LL => "ll",
+                    _ => "?",
+                };
+                write!(f, "{mnemonic} {}, {}({})", reg_name(rt), offset as i32, reg_name(rs))
+            }
+            Operation::MstoreGeneral(op, rs, rt, offset) => {
+                let mnemonic = match op {
+                    MemOp::SB => "sb",
+                    MemOp::SH => "sh",
+                    MemOp::SWL => "swl",
+                    MemOp::SW => "sw",
+                    MemOp::SWR => "swr",
+                    MemOp::SC => "sc",
+                    MemOp::SDC1 => "sdc1",
+                    MemOp::SWC1 => "swc1",
+                    _ => "?",
+                };
+                write!(f, "{mnemonic} {}, {}({})", reg_name(rt), offset as i32, reg_name(rs))
+            }
+            Operation::Nop => write!(f, "nop"),
+            Operation::Ext(rt, rs, rd, sa) => {
+                write!(f, "ext {}, {}, {}, {}", reg_name(rt), reg_name(rs), rd, sa)
+            }
+            Operation::Ins(rt, rs, rd, sa) => {
+                write!(f, "ins {}, {}, {}, {}", reg_name(rt), reg_name(rs), rd, sa)
+            }
+            Operation::Maddu(rt, rs) => write!(f, "maddu {}, {}", reg_name(rt), reg_name(rs)),
+            Operation::Ror(rd, rt, sa) => {
+                write!(f, "ror {}, {}, {}", reg_name(rd), reg_name(rt), sa)
+            }
+            Operation::Rdhwr(rt, rd) => write!(f, "rdhwr {}, {}", reg_name(rt), reg_name(rd)),
+            Operation::Signext(rd, rt, 16) => write!(f, "seh {}, {}", reg_name(rd), reg_name(rt)),
+            Operation::Signext(rd, rt, _) => write!(f, "seb {}, {}", reg_name(rd), reg_name(rt)),
+            Operation::SwapHalf(rd, rt) => write!(f, "wsbh {}, {}", reg_name(rd), reg_name(rt)),
+            Operation::Trap(cond, rs, rt) => {
+                write!(f, "t{} {}, {}", trap_mnemonic(cond), reg_name(rs), reg_name(rt))
+            }
+            Operation::TrapImm(cond, rs, imm) => {
+                write!(f, "t{}i {}, {}", trap_mnemonic(cond), reg_name(rs), imm as i16)
+            }
+            Operation::Break(code) => write!(f, "break {code}"),
+            Operation::FpBinary(op, fmt, ft, fs, fd) => {
+                let mnemonic = match op {
+                    FpOp::Add => "add",
+                    FpOp::Sub => "sub",
+                    FpOp::Mul => "mul",
+                    FpOp::Div => "div",
+                };
+                write!(
+                    f,
+                    "{mnemonic}.{} {}, {}, {}",
+                    fp_fmt_suffix(fmt),
+                    fpr_name(fd),
+                    fpr_name(fs),
+                    fpr_name(ft)
+                )
+            }
+            Operation::FpUnary(op, fmt, fs, fd) => {
+                let mnemonic = match op {
+                    FpUnaryOp::Abs => "abs",
+                    FpUnaryOp::Neg => "neg",
+                    FpUnaryOp::Mov => "mov",
+                };
+                write!(f, "{mnemonic}.{} {}, {}", fp_fmt_suffix(fmt), fpr_name(fd), fpr_name(fs))
+            }
+            Operation::FpConvert(from, to, fs, fd) => {
+                write!(
+                    f,
+                    "cvt.{}.{} {}, {}",
+                    fp_fmt_suffix(to),
+                    fp_fmt_suffix(from),
+                    fpr_name(fd),
+                    fpr_name(fs)
+                )
+            }
+            Operation::FpCompare(cond, fmt, fs, ft) => {
+                let mnemonic = match cond {
+                    FpCond::Eq => "c.eq",
+                    FpCond::Lt => "c.lt",
+                };
+                write!(f, "{mnemonic}.{} {}, {}", fp_fmt_suffix(fmt), fpr_name(fs), fpr_name(ft))
+            }
+            Operation::FpMove(kind, rt, fs) => {
+                let mnemonic = match kind {
+                    FpMoveKind::Mfc1 => "mfc1",
+                    FpMoveKind::Mtc1 => "mtc1",
+                    FpMoveKind::Cfc1 => "cfc1",
+                    FpMoveKind::Ctc1 => "ctc1",
+                };
+                write!(f, "{mnemonic} {}, {}", reg_name(rt), fpr_name(fs))
+            }
+            Operation::FpLoad(fmt, rs, ft, offset) => {
+                let mnemonic = if fmt == FpFmt::D { "ldc1" } else { "lwc1" };
+                write!(f, "{mnemonic} {}, {}({})", fpr_name(ft), offset as i32, reg_name(rs))
+            }
+        }
     }
 }
-*/
@@ -0,0 +1,171 @@
+//! A function-level cycle profiler built on top of the per-cycle `pc` samples that
+//! [`crate::Executor::trace_buf`] already records.
+//!
+//! This crate has no debug-info parser, so the symbol table is supplied by the embedder: a list
+//! of `(name, address range)` pairs, one per guest function. Each sampled `pc` is attributed to
+//! its enclosing symbol, and a shadow call stack -- pushed on a linking jump (`jal`/`jalr`),
+//! popped on a jump back through `$ra` -- splits the result into inclusive and exclusive cycle
+//! counts and a nested trace of call frames.
+
+use hashbrown::HashMap;
+
+use crate::{Instruction, Opcode, Register};
+
+/// A named address range resolved against sampled `pc` values, e.g. one per guest function.
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    /// The function's display name.
+    pub name: String,
+    /// Inclusive start address.
+    pub start: u32,
+    /// Exclusive end address.
+    pub end: u32,
+}
+
+/// A completed call-stack frame, ready to be rendered as one row of a Chrome trace.
+#[derive(Debug, Clone)]
+pub struct ProfileFrame {
+    /// The symbol this frame ran in.
+    pub name: String,
+    /// The `global_clk` its call was sampled on.
+    pub start_cycle: u64,
+    /// The `global_clk` it returned on.
+    pub end_cycle: u64,
+}
+
+/// Aggregates `pc` samples against a [`Symbol`] table into per-function cycle counts, installed
+/// via [`crate::Executor::with_profiler`] and read back with [`crate::Executor::take_profile`].
+#[derive(Debug, Clone, Default)]
+pub struct Profiler {
+    symbols: Vec<Symbol>,
+    /// Cycles charged to each function while it, or something it called, was running --
+    /// everything under it on the shadow call stack.
+    pub inclusive_cycles: HashMap<String, u64>,
+    /// Cycles charged to each function only while it, specifically, was on top of the shadow
+    /// call stack.
+    pub exclusive_cycles: HashMap<String, u64>,
+    /// Completed call-stack frames, in the order they returned, for [`Profiler::to_chrome_trace`].
+    frames: Vec<ProfileFrame>,
+    /// `(symbol name, global_clk the call was sampled on)` for every call still open, outermost
+    /// first.
+    call_stack: Vec<(String, u64)>,
+}
+
+impl Profiler {
+    /// Builds a profiler over `symbols`. A sampled `pc` that falls outside every range is
+    /// attributed to `"<unknown>"`.
+    #[must_use]
+    pub fn new(mut symbols: Vec<Symbol>) -> Self {
+        symbols.sort_by_key(|symbol| symbol.start);
+        Self { symbols, ..Self::default() }
+    }
+
+    fn resolve(&self, pc: u32) -> &str {
+        let idx = self.symbols.binary_search_by(|symbol| {
+            if pc < symbol.start {
+                std::cmp::Ordering::Greater
+            } else if pc >= symbol.end {
+                std::cmp::Ordering::Less
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        });
+        match idx {
+            Ok(idx) => &self.symbols[idx].name,
+            Err(_) => "<unknown>",
+        }
+    }
+
+    /// Records one cycle at `pc`, maintaining the shadow call stack by watching `instruction` for
+    /// linking jumps (calls) and jumps back through `$ra` (returns).
+    pub fn sample(&mut self, clk: u64, pc: u32, instruction: &Instruction) {
+        let name = self.resolve(pc).to_string();
+
+        *self.exclusive_cycles.entry(name.clone()).or_insert(0) += 1;
+        *self.inclusive_cycles.entry(name.clone()).or_insert(0) += 1;
+        for (caller, _) in &self.call_stack {
+            if *caller != name {
+                *self.inclusive_cycles.entry(caller.clone()).or_insert(0) += 1;
+            }
+        }
+
+        let is_call = matches!(
+            instruction.opcode,
+            Opcode::Jump | Opcode::Jumpi | Opcode::JumpDirect
+        ) && instruction.op_a != 0;
+        let is_return =
+            instruction.opcode == Opcode::Jump && instruction.op_b == Register::RA as u32;
+
+        if is_return {
+            // The frame is named for the function returning (the callee), not the caller that
+            // was pushed when the call was sampled.
+            if let Some((_, start_cycle)) = self.call_stack.pop() {
+                self.frames.push(ProfileFrame { name, start_cycle, end_cycle: clk });
+            }
+        } else if is_call {
+            self.call_stack.push((name, clk));
+        }
+    }
+
+    /// Serializes the completed call frames as a Chrome `about:tracing`-compatible JSON array of
+    /// complete (`"X"`) events, one per frame, with `global_clk` standing in for microseconds.
+    #[must_use]
+    pub fn to_chrome_trace(&self) -> String {
+        let events: Vec<String> = self
+            .frames
+            .iter()
+            .map(|frame| {
+                format!(
+                    r#"{{"name":"{}","cat":"guest","ph":"X","ts":{},"dur":{},"pid":0,"tid":0}}"#,
+                    frame.name,
+                    frame.start_cycle,
+                    frame.end_cycle.saturating_sub(frame.start_cycle),
+                )
+            })
+            .collect();
+        format!("[{}]", events.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_and_unknown() {
+        let profiler = Profiler::new(vec![
+            Symbol { name: "main".to_string(), start: 0, end: 16 },
+            Symbol { name: "helper".to_string(), start: 16, end: 32 },
+        ]);
+        assert_eq!(profiler.resolve(4), "main");
+        assert_eq!(profiler.resolve(20), "helper");
+        assert_eq!(profiler.resolve(1000), "<unknown>");
+    }
+
+    #[test]
+    fn test_call_and_return_splits_inclusive_exclusive() {
+        let mut profiler = Profiler::new(vec![
+            Symbol { name: "main".to_string(), start: 0, end: 8 },
+            Symbol { name: "helper".to_string(), start: 8, end: 16 },
+        ]);
+
+        // main: jal helper (jumpi with a non-zero link register).
+        let call = Instruction::new(Opcode::Jumpi, Register::RA as u8, 8, 0, true, true);
+        profiler.sample(0, 0, &call);
+
+        // helper body.
+        let nop = Instruction::new(Opcode::ADD, 0, 0, 0, false, true);
+        profiler.sample(1, 8, &nop);
+
+        // helper: jr $ra (jump with link register zero, target register $ra).
+        let ret = Instruction::new(Opcode::Jump, 0, Register::RA as u32, 0, false, true);
+        profiler.sample(2, 12, &ret);
+
+        assert_eq!(profiler.exclusive_cycles["main"], 1);
+        assert_eq!(profiler.exclusive_cycles["helper"], 2);
+        assert_eq!(profiler.inclusive_cycles["main"], 3);
+        assert_eq!(profiler.inclusive_cycles["helper"], 2);
+        assert_eq!(profiler.frames.len(), 1);
+        assert_eq!(profiler.frames[0].name, "helper");
+    }
+}
@@ -20,6 +20,39 @@ pub const MAX_MEMORY: usize = 0x10000000;
 pub const INIT_SP: u32 = MAX_MEMORY as u32 - 0x4000;
 pub const WORD_SIZE: usize = core::mem::size_of::<u32>();
 
+/// A single file's directory entry within a [`FileBundle`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileBundleEntry {
+    /// The file's name, as looked up by the guest's `open` syscall.
+    pub name: String,
+    /// The byte offset of the file's contents, relative to the bundle's base address.
+    pub offset: u32,
+    /// The length of the file's contents, in bytes.
+    pub len: u32,
+}
+
+/// A read-only, page-aligned "initramfs"-style data bundle mapped into [`Program::image`] below
+/// [`INIT_SP`], so a guest program can consume multiple named input files without the host
+/// pre-parsing everything through `io::read_vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileBundle {
+    /// The address of the first byte of the bundle (the directory header).
+    pub base_addr: u32,
+    /// The directory of files contained in the bundle.
+    pub entries: Vec<FileBundleEntry>,
+}
+
+impl FileBundle {
+    /// Look up a file by name, returning its absolute `(offset, len)` within guest memory.
+    #[must_use]
+    pub fn find(&self, name: &str) -> Option<(u32, u32)> {
+        self.entries
+            .iter()
+            .find(|entry| entry.name == name)
+            .map(|entry| (self.base_addr + entry.offset, entry.len))
+    }
+}
+
 /// A program that can be executed by the ZKM.
 #[derive(PartialEq, Eq, Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Program {
@@ -33,6 +66,8 @@ pub struct Program {
     /// The shape for the preprocessed tables.
     // todo: check if necessary
     pub preprocessed_shape: Option<CoreShape>,
+    /// An optional read-only file bundle mapped into `image` below [`INIT_SP`].
+    pub file_bundle: Option<FileBundle>,
 }
 
 impl Program {
@@ -146,7 +181,7 @@ impl Program {
         // decode each instruction
         let instructions: Vec<_> = instructions
             .par_iter()
-            .map(|inst| Instruction::decode_from(*inst).unwrap())
+            .map(|inst| Instruction::decode_from_lenient(*inst))
             .collect();
 
         Ok(Program {
@@ -156,9 +191,123 @@ impl Program {
             next_pc: entry + 4,
             image,
             preprocessed_shape: None,
+            file_bundle: None,
         })
     }
 
+    /// Build a `Program` directly from a flat, big-endian MIPS code segment, without an ELF
+    /// container.
+    ///
+    /// The segment is mapped starting at address `0`: `pc_base` and `pc_start` are both `0`, and
+    /// every word is also inserted into `image` so it participates in the memory-initialization
+    /// argument exactly like an ELF `PT_LOAD` segment would. This is mainly useful for loading
+    /// hand-assembled or otherwise non-ELF-wrapped machine code.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `code`'s length isn't a multiple of [`WORD_SIZE`], or if any word
+    /// fails to decode; see [`crate::decode::words_from_be_bytes`] and
+    /// [`crate::decode::decode_words`].
+    pub fn from_bytes(code: &[u8]) -> Result<Program> {
+        let words = crate::decode::words_from_be_bytes(code)?;
+        let instructions = crate::decode::decode_words(&words)?;
+
+        let image = words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| ((i * WORD_SIZE) as u32, *word))
+            .collect();
+
+        Ok(Program {
+            instructions,
+            pc_start: 0,
+            pc_base: 0,
+            next_pc: 4,
+            image,
+            preprocessed_shape: None,
+            file_bundle: None,
+        })
+    }
+
+    /// Attach a read-only file bundle to this program, materializing it into `image` at a
+    /// reserved, page-aligned region immediately below [`INIT_SP`].
+    ///
+    /// The layout is deterministic: files are packed in the order given, each starting on a
+    /// word boundary, with the directory header (file count, then every file's `(offset, len)`
+    /// pair, then every file's name hash, each in file order) placed first so the guest's
+    /// `open`/`stat` syscalls can resolve a name without the host having parsed it in advance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bundle would overlap a `PT_LOAD` segment already present in
+    /// `image`, or if it would exceed [`MAX_MEMORY`].
+    pub fn with_file_bundle(mut self, files: Vec<(String, Vec<u8>)>) -> Result<Self> {
+        const HEADER_ENTRY_WORDS: u32 = 3; // name-hash, offset, len.
+
+        // One count word, then `HEADER_ENTRY_WORDS` words per file.
+        let header_len = (1 + files.len() as u32 * HEADER_ENTRY_WORDS) * WORD_SIZE as u32;
+
+        let mut entries = Vec::with_capacity(files.len());
+        let mut payload: Vec<u8> = Vec::new();
+        for (name, data) in &files {
+            let offset = header_len + payload.len() as u32;
+            payload.extend_from_slice(data);
+            while payload.len() % WORD_SIZE != 0 {
+                payload.push(0);
+            }
+            entries.push(FileBundleEntry { name: name.clone(), offset, len: data.len() as u32 });
+        }
+        let total_len = header_len
+            .checked_add(payload.len() as u32)
+            .context("file bundle overflowed u32 addressing")?;
+
+        // Reserve a page-aligned region directly below INIT_SP.
+        let region_len = total_len.next_multiple_of(PAGE_SIZE);
+        let base_addr = INIT_SP
+            .checked_sub(region_len)
+            .context("file bundle does not fit below INIT_SP")?
+            & !(PAGE_SIZE - 1);
+
+        if base_addr as usize + region_len as usize > MAX_MEMORY {
+            bail!("file bundle region exceeds MAX_MEMORY");
+        }
+        for addr in (base_addr..base_addr + region_len).step_by(WORD_SIZE) {
+            if self.image.contains_key(&addr) {
+                bail!("file bundle region overlaps an existing PT_LOAD segment at 0x{addr:08x}");
+            }
+        }
+
+        // Materialize the directory header, then each file's bytes.
+        let mut words: Vec<u32> = Vec::with_capacity(total_len as usize / WORD_SIZE);
+        words.push(files.len() as u32);
+        for entry in &entries {
+            words.push(entry.offset);
+            words.push(entry.len);
+        }
+        for (name, _) in &files {
+            // A simple FNV-1a hash stands in for the name so lookups from the guest side don't
+            // require variable-length string comparisons against raw memory.
+            let mut hash: u32 = 0x811c_9dc5;
+            for byte in name.bytes() {
+                hash ^= u32::from(byte);
+                hash = hash.wrapping_mul(0x0100_0193);
+            }
+            words.push(hash);
+        }
+        for chunk in payload.chunks(WORD_SIZE) {
+            let mut word_bytes = [0u8; WORD_SIZE];
+            word_bytes[..chunk.len()].copy_from_slice(chunk);
+            words.push(u32::from_be_bytes(word_bytes));
+        }
+
+        for (i, word) in words.into_iter().enumerate() {
+            self.image.insert(base_addr + (i as u32) * WORD_SIZE as u32, word);
+        }
+
+        self.file_bundle = Some(FileBundle { base_addr, entries });
+        Ok(self)
+    }
+
     /// Custom logic for padding the trace to a power of two according to the proof shape.
     pub fn fixed_log2_rows<F: Field, A: MachineAir<F>>(&self, air: &A) -> Option<usize> {
         self.preprocessed_shape
@@ -178,6 +327,22 @@ impl Program {
         let idx = ((pc - self.pc_base) / 4) as usize;
         self.instructions[idx]
     }
+
+    /// Disassemble every instruction in the program, pairing each with its address.
+    ///
+    /// Addresses and branch/jump targets are resolved relative to `pc_base`, so the result reads
+    /// like a real listing rather than raw encoded operands.
+    #[must_use]
+    pub fn disassemble(&self) -> Vec<(u32, String)> {
+        self.instructions
+            .iter()
+            .enumerate()
+            .map(|(i, instruction)| {
+                let pc = self.pc_base + (i as u32) * WORD_SIZE as u32;
+                (pc, crate::disasm::disassemble_instruction(instruction, pc))
+            })
+            .collect()
+    }
 }
 
 impl<F: Field> MachineProgram<F> for Program {
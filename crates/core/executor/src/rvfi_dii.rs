@@ -0,0 +1,296 @@
+//! MIPS Formal Interface with Direct Instruction Injection (RVFI-DII style).
+//!
+//! This mirrors RISC-V's RVFI-DII: rather than fetching instructions from the
+//! program image, the executor pulls one instruction word at a time from an
+//! external injection channel (a pipe or socket) and executes it against live
+//! register/memory state. For every retired instruction a fixed-layout
+//! [`RetireRecord`] is emitted so the trace can be diffed against a golden
+//! MIPS simulator (e.g. a Sail/QEMU model) to localize semantic bugs.
+
+use std::io::{self, Read, Write};
+
+use crate::{Executor, Instruction, Opcode, Register};
+
+/// A single instruction packet pulled from the injection channel.
+///
+/// A packet with `halt` set to `true` carries no instruction and simply ends
+/// the run, flushing any buffered retire records.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DiiPacket {
+    /// The raw MIPS instruction word to inject.
+    pub insn_word: u32,
+    /// Whether this packet is the terminating "halt" packet.
+    pub halt: bool,
+}
+
+/// A fixed-layout retire trace record, emitted once per injected instruction.
+///
+/// `rs1_rdata`/`rs2_rdata` and the `mem_*` fields are captured directly by
+/// [`Executor::run_rvfi_dii`] via the same non-recording accessors a debugger
+/// uses ([`Executor::register`]/[`Executor::word`]), rather than by threading
+/// a bookkeeping record out of [`Executor::execute_operation`] -- simpler, and
+/// it doesn't need this mode to special-case the hot dispatch path.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RetireRecord {
+    /// Monotonic retire order, unique and increasing across shards.
+    pub order: u64,
+    /// The program counter of the retired instruction.
+    pub pc: u32,
+    /// The next program counter, honoring branch-delay-slot semantics.
+    pub next_pc: u32,
+    /// The raw instruction word that was executed.
+    pub insn_word: u32,
+    /// The destination register index, if any.
+    pub rd_index: u8,
+    /// The value written back to `rd_index`.
+    pub rd_wdata: u32,
+    /// The first source register index.
+    pub rs1_index: u8,
+    /// The value read from `rs1_index`.
+    pub rs1_rdata: u32,
+    /// The second source register index.
+    pub rs2_index: u8,
+    /// The value read from `rs2_index`.
+    pub rs2_rdata: u32,
+    /// The memory address touched by the instruction, if any.
+    pub mem_addr: u32,
+    /// A byte mask of the bytes read from `mem_addr`.
+    pub mem_rmask: u8,
+    /// The data read from `mem_addr`.
+    pub mem_rdata: u32,
+    /// A byte mask of the bytes written to `mem_addr`.
+    pub mem_wmask: u8,
+    /// The data written to `mem_addr`.
+    pub mem_wdata: u32,
+    /// Whether the instruction trapped.
+    pub trap: bool,
+    /// Whether this record is the final one for the run.
+    pub halt: bool,
+}
+
+/// The address and byte-mask/data touched by a load/store, captured by
+/// [`Executor::run_rvfi_dii`] for [`RetireRecord::from_step`]. All-zero means the instruction
+/// didn't access memory.
+#[derive(Debug, Clone, Copy, Default)]
+struct MemAccess {
+    addr: u32,
+    rmask: u8,
+    rdata: u32,
+    wmask: u8,
+    wdata: u32,
+}
+
+impl RetireRecord {
+    /// Build a retire record for a normal (non-halting) step.
+    #[allow(clippy::too_many_arguments)]
+    fn from_step(
+        order: u64,
+        pc: u32,
+        next_pc: u32,
+        insn_word: u32,
+        rd_index: u8,
+        rd_wdata: u32,
+        rs1_index: u8,
+        rs1_rdata: u32,
+        rs2_index: u8,
+        rs2_rdata: u32,
+        mem: MemAccess,
+        trap: bool,
+    ) -> Self {
+        Self {
+            order,
+            pc,
+            next_pc,
+            insn_word,
+            rd_index,
+            rd_wdata,
+            rs1_index,
+            rs1_rdata,
+            rs2_index,
+            rs2_rdata,
+            mem_addr: mem.addr,
+            mem_rmask: mem.rmask,
+            mem_rdata: mem.rdata,
+            mem_wmask: mem.wmask,
+            mem_wdata: mem.wdata,
+            trap,
+            halt: false,
+        }
+    }
+
+    /// The terminating record flushed when a halt packet is received.
+    #[must_use]
+    pub fn halt_record(order: u64) -> Self {
+        Self {
+            order,
+            halt: true,
+            ..Default::default()
+        }
+    }
+}
+
+/// A source of [`DiiPacket`]s, backed by a stdin pipe or TCP socket.
+pub struct DiiChannel<R> {
+    reader: R,
+}
+
+impl<R: Read> DiiChannel<R> {
+    /// Wrap a byte source as a DII channel.
+    pub const fn new(reader: R) -> Self {
+        Self { reader }
+    }
+
+    /// Read the next packet: a 4-byte little-endian instruction word, or
+    /// end-of-stream treated as the halt packet.
+    pub fn next_packet(&mut self) -> io::Result<DiiPacket> {
+        let mut buf = [0u8; 4];
+        match self.reader.read_exact(&mut buf) {
+            Ok(()) => Ok(DiiPacket {
+                insn_word: u32::from_le_bytes(buf),
+                halt: false,
+            }),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                Ok(DiiPacket { insn_word: 0, halt: true })
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Executor<'_> {
+    /// Drive this executor's register/memory state by pulling one instruction
+    /// word at a time from `channel`, emitting a [`RetireRecord`] to `sink`
+    /// for each retired instruction.
+    ///
+    /// The `order` counter is monotonic across shards: it is never reset by
+    /// [`Self::bump_record`], unlike the per-shard clock.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the channel cannot be read or a retire record
+    /// cannot be written to `sink`.
+    pub fn run_rvfi_dii<R: Read, W: Write>(
+        &mut self,
+        channel: &mut DiiChannel<R>,
+        sink: &mut W,
+    ) -> anyhow::Result<()> {
+        let mut order: u64 = 0;
+
+        loop {
+            let packet = channel.next_packet()?;
+            if packet.halt {
+                let record = RetireRecord::halt_record(order);
+                Self::write_retire_record(sink, &record)?;
+                break;
+            }
+
+            // An injected word a fuzzer sends is often one `decode_from` doesn't recognize; that
+            // must still retire as a trapped record (below, via `UnsupportedInstruction`) instead
+            // of aborting the whole run the way propagating a decode `Err` here would.
+            let instruction = Instruction::decode_from_lenient(packet.insn_word);
+            let pc_before = self.state.pc;
+
+            // Capture rs1/rs2 as they stood *before* execution, via the same non-recording
+            // accessor a debugger uses ([`Self::register`]) -- reading them afterward could
+            // observe the instruction's own write-back (e.g. an ALU op with `rd == rs1`). `op_b`/
+            // `op_c` only name a register when the matching `imm_*` flag is clear; an immediate
+            // operand has no register behind it, so its "rdata" is just the immediate itself.
+            let rs1_index = instruction.op_b as u8;
+            let rs1_rdata = if instruction.imm_b {
+                instruction.op_b
+            } else {
+                self.register(Register::from_u8(rs1_index))
+            };
+            let rs2_index = instruction.op_c as u8;
+            let rs2_rdata = if instruction.imm_c {
+                instruction.op_c
+            } else {
+                self.register(Register::from_u8(rs2_index))
+            };
+
+            // Loads/stores address `rs1_rdata + op_c` (the sign-extended immediate, already
+            // folded into `op_c` at decode time) and touch the aligned word there, the same
+            // computation `Executor::execute_load`/`Executor::execute_store` do; snapshot that
+            // word before and after so the retire record carries the real access.
+            let is_mem = instruction.is_memory_instruction() && instruction.opcode != Opcode::SDC1;
+            let mem_addr = rs1_rdata.wrapping_add(instruction.op_c) & 0xFFFF_FFFC;
+            let mem_before = if is_mem { self.word(mem_addr) } else { 0 };
+
+            let trap = self.execute_operation(&instruction).is_err();
+            let next_pc = self.state.next_pc;
+            let rd_wdata = self.register(Register::from_u8(instruction.op_a));
+
+            let mem = if is_mem {
+                let mask = memory_access_mask(instruction.opcode);
+                if is_store_opcode(instruction.opcode) {
+                    MemAccess { addr: mem_addr, wmask: mask, wdata: self.word(mem_addr), ..Default::default() }
+                } else {
+                    MemAccess { addr: mem_addr, rmask: mask, rdata: mem_before, ..Default::default() }
+                }
+            } else {
+                MemAccess::default()
+            };
+
+            let record = RetireRecord::from_step(
+                order,
+                pc_before,
+                next_pc,
+                packet.insn_word,
+                instruction.op_a,
+                rd_wdata,
+                rs1_index,
+                rs1_rdata,
+                rs2_index,
+                rs2_rdata,
+                mem,
+                trap,
+            );
+            Self::write_retire_record(sink, &record)?;
+            order += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Write a retire record to `sink` in a fixed binary layout so a golden
+    /// simulator's companion tooling can diff traces byte-for-byte.
+    fn write_retire_record<W: Write>(sink: &mut W, record: &RetireRecord) -> io::Result<()> {
+        sink.write_all(&record.order.to_le_bytes())?;
+        sink.write_all(&record.pc.to_le_bytes())?;
+        sink.write_all(&record.next_pc.to_le_bytes())?;
+        sink.write_all(&record.insn_word.to_le_bytes())?;
+        sink.write_all(&[record.rd_index])?;
+        sink.write_all(&record.rd_wdata.to_le_bytes())?;
+        sink.write_all(&[record.rs1_index])?;
+        sink.write_all(&record.rs1_rdata.to_le_bytes())?;
+        sink.write_all(&[record.rs2_index])?;
+        sink.write_all(&record.rs2_rdata.to_le_bytes())?;
+        sink.write_all(&record.mem_addr.to_le_bytes())?;
+        sink.write_all(&[record.mem_rmask])?;
+        sink.write_all(&record.mem_rdata.to_le_bytes())?;
+        sink.write_all(&[record.mem_wmask])?;
+        sink.write_all(&record.mem_wdata.to_le_bytes())?;
+        sink.write_all(&[record.trap as u8, record.halt as u8])?;
+        sink.flush()
+    }
+}
+
+/// Whether `opcode` writes memory rather than reading it, for the subset of
+/// [`Instruction::is_memory_instruction`] this module knows how to address (excludes `SDC1`; see
+/// `Executor::run_rvfi_dii`).
+const fn is_store_opcode(opcode: Opcode) -> bool {
+    matches!(opcode, Opcode::SB | Opcode::SH | Opcode::SWL | Opcode::SW | Opcode::SWR | Opcode::SC)
+}
+
+/// The byte mask, within the aligned word [`Executor::run_rvfi_dii`] snapshots, that `opcode`
+/// actually reads or writes: one bit per byte, least-significant byte first. `LWL`/`LWR`/`SWL`/
+/// `SWR` can touch a variable 1-4 bytes depending on alignment; this reports the full word since
+/// working out which bytes needs the same alignment arithmetic `Executor::execute_load`/
+/// `Executor::execute_store` already do internally, which this module doesn't duplicate.
+const fn memory_access_mask(opcode: Opcode) -> u8 {
+    match opcode {
+        Opcode::LB | Opcode::LBU | Opcode::SB => 0x1,
+        Opcode::LH | Opcode::LHU | Opcode::SH => 0x3,
+        _ => 0xf,
+    }
+}
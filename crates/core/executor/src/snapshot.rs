@@ -0,0 +1,119 @@
+//! Nested transactional snapshot/rollback for [`crate::Executor`].
+//!
+//! This generalizes the single flat `unconstrained_state: ForkState` fork into a stack: host code
+//! can speculatively execute a region (a syscall precompile, a guest `try`/rollback pattern) and
+//! cheaply discard its memory effects by rolling back to a snapshot, or fold them into the
+//! enclosing transaction by committing. Each frame records the prior [`crate::events::MemoryRecord`]
+//! the first time one of its addresses is mutated, exactly like the existing `memory_diff`
+//! copy-on-write logic in `mr`/`mw`.
+
+use hashbrown::HashMap;
+
+use crate::{events::MemoryRecord, Executor};
+
+/// Identifies an open snapshot frame, returned by [`Executor::snapshot`].
+///
+/// Rolling back or committing anything other than the innermost open snapshot also discards (by
+/// rolling back) or folds in (by committing) every snapshot nested inside it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotId(usize);
+
+impl SnapshotId {
+    /// Recovers a [`SnapshotId`] from the raw index a guest got back from `SYSSNAPSHOT` and later
+    /// passes to `SYSROLLBACK`. See [`crate::syscalls::SnapshotSyscall`]/[`crate::syscalls::RollbackSyscall`].
+    #[must_use]
+    pub(crate) fn from_raw(raw: usize) -> Self {
+        Self(raw)
+    }
+
+    /// The raw index to hand back to the guest as the `SYSSNAPSHOT` return value.
+    #[must_use]
+    pub(crate) fn raw(self) -> usize {
+        self.0
+    }
+
+    /// Whether `self` identifies a currently-open snapshot on `stack` -- the same check
+    /// [`Executor::rollback`]/[`Executor::commit`] assert on, exposed so a guest-facing syscall
+    /// can validate an untrusted `id` and report an error instead of hitting the assert.
+    #[must_use]
+    pub(crate) fn is_open(self, stack: &[SnapshotFrame]) -> bool {
+        self.0 < stack.len()
+    }
+}
+
+/// One frame of the snapshot stack.
+#[derive(Debug, Default)]
+pub struct SnapshotFrame {
+    /// The clock cycle when this frame was opened.
+    pub clk: u32,
+    /// The program counter when this frame was opened.
+    pub pc: u32,
+    /// The prior value of each memory address touched since this frame was opened, `None` if it
+    /// was vacant.
+    pub memory_diff: HashMap<u32, Option<MemoryRecord>>,
+}
+
+impl<'a> Executor<'a> {
+    /// Push a new snapshot frame, returning an id that can later be passed to [`Self::rollback`]
+    /// or [`Self::commit`].
+    pub fn snapshot(&mut self) -> SnapshotId {
+        let id = SnapshotId(self.snapshot_stack.len());
+        self.snapshot_stack.push(SnapshotFrame {
+            clk: self.state.clk,
+            pc: self.state.pc,
+            memory_diff: HashMap::new(),
+        });
+        id
+    }
+
+    /// Discard every memory write (and the clock/pc advance) made since `id` was opened, along
+    /// with any snapshots nested inside it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` does not identify a currently-open snapshot.
+    pub fn rollback(&mut self, id: SnapshotId) {
+        assert!(id.0 < self.snapshot_stack.len(), "rollback of a snapshot that isn't open");
+        // An `LL` reservation isn't part of `self.state.memory`, so it isn't covered by the
+        // memory_diff restore below; a rollback that discards the `LL` (or the writes it was
+        // guarding) must still invalidate it, the same way crossing a syscall boundary does, or a
+        // later `SC` could spuriously succeed against state the rollback was supposed to undo.
+        self.reservation = None;
+        // Undo frames innermost-first, so an address touched in two nested frames is restored to
+        // its value from *before* the outer frame, not left at the inner frame's start value.
+        while self.snapshot_stack.len() > id.0 {
+            let frame = self.snapshot_stack.pop().unwrap();
+            for (addr, prior) in frame.memory_diff {
+                match prior {
+                    Some(record) => {
+                        self.state.memory.insert(addr, record);
+                    }
+                    None => {
+                        self.state.memory.remove(addr);
+                    }
+                }
+            }
+            self.state.clk = frame.clk;
+            self.state.pc = frame.pc;
+        }
+    }
+
+    /// Keep every memory write made since `id` was opened, folding its diff (and that of any
+    /// snapshots nested inside it) into the enclosing frame so an *outer* rollback can still undo
+    /// it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` does not identify a currently-open snapshot.
+    pub fn commit(&mut self, id: SnapshotId) {
+        assert!(id.0 < self.snapshot_stack.len(), "commit of a snapshot that isn't open");
+        while self.snapshot_stack.len() > id.0 {
+            let frame = self.snapshot_stack.pop().unwrap();
+            if let Some(parent) = self.snapshot_stack.last_mut() {
+                for (addr, prior) in frame.memory_diff {
+                    parent.memory_diff.entry(addr).or_insert(prior);
+                }
+            }
+        }
+    }
+}
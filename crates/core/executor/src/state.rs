@@ -0,0 +1,123 @@
+//! The state of an in-progress execution, and serializable checkpoints of it.
+//!
+//! A checkpoint captures everything needed to resume execution byte-for-byte identically: the
+//! register file (which lives in `memory` at the reserved register addresses), the full memory
+//! image, `pc`/`next_pc`, and the clock/shard counters. This lets a long guest run be split
+//! across machines: worker `N` loads checkpoint `N`, executes its cycle window, and emits that
+//! shard's records, which is how parallel trace generation works for programs too large to
+//! execute in one process.
+
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{events::MemoryRecord, memory::PagedMemory};
+
+/// A serializable snapshot of an [`crate::Executor`]'s state.
+///
+/// Cloning this (and the rest of the executor's bookkeeping, which is cheap to recompute) is
+/// enough to resume execution: `resume_from` continues from exactly this point and produces
+/// bit-identical memory-access records and `SyscallEvent`s to an uninterrupted run.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ExecutionState {
+    /// The program counter.
+    pub pc: u32,
+    /// The next program counter (honors branch-delay-slot semantics).
+    pub next_pc: u32,
+    /// The clock cycle within the current shard.
+    pub clk: u32,
+    /// The clock cycle across the entire execution.
+    pub global_clk: u64,
+    /// The current shard number.
+    pub current_shard: u32,
+    /// The full memory image, keyed by word-aligned address.
+    pub memory: PagedMemory<MemoryRecord>,
+    /// Addresses that were initialized with a non-zero value ahead of execution (e.g. from the
+    /// program's ELF image), so a first read of them doesn't look like an uninitialized access.
+    pub uninitialized_memory: PagedMemory<u32>,
+    /// Whether the program has exited.
+    pub exited: bool,
+    /// The input stream, consumed by `SYSHINTREAD`-style syscalls.
+    pub input_stream: Vec<Vec<u8>>,
+    /// The index of the next unread entry in `input_stream`.
+    pub input_stream_ptr: usize,
+    /// Deferred proofs to be verified, consumed by `SYSVERIFY`.
+    pub proof_stream: Vec<Vec<u8>>,
+    /// The index of the next unread entry in `proof_stream`.
+    pub proof_stream_ptr: usize,
+}
+
+impl Default for ExecutionState {
+    fn default() -> Self {
+        Self {
+            pc: 0,
+            next_pc: 4,
+            clk: 0,
+            global_clk: 0,
+            current_shard: 0,
+            memory: PagedMemory::new_preallocated(),
+            uninitialized_memory: PagedMemory::new_preallocated(),
+            exited: false,
+            input_stream: Vec::new(),
+            input_stream_ptr: 0,
+            proof_stream: Vec::new(),
+            proof_stream_ptr: 0,
+        }
+    }
+}
+
+impl ExecutionState {
+    /// Create a fresh execution state for a program starting at `pc_start`.
+    #[must_use]
+    pub fn new(pc_start: u32, next_pc: u32) -> Self {
+        Self {
+            pc: pc_start,
+            next_pc,
+            ..Default::default()
+        }
+    }
+
+    /// Snapshot the current state as a checkpoint suitable for persisting to disk and resuming
+    /// later, e.g. at a configurable cycle/shard boundary.
+    #[must_use]
+    pub fn checkpoint(&self) -> Self {
+        self.clone()
+    }
+
+    /// Resume execution from a previously saved checkpoint.
+    ///
+    /// This is the inverse of `checkpoint`: the returned state, when driven by the executor,
+    /// produces identical memory-access records and `SyscallEvent`s to an uninterrupted run that
+    /// reached the same point.
+    #[must_use]
+    pub fn resume_from(checkpoint: Self) -> Self {
+        checkpoint
+    }
+
+    /// Serialize this checkpoint to a writer, e.g. a file, so it can be shipped to another
+    /// worker machine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn write_checkpoint<W: std::io::Write>(&self, writer: W) -> anyhow::Result<()> {
+        bincode::serialize_into(writer, self).map_err(Into::into)
+    }
+
+    /// Deserialize a checkpoint previously written by [`Self::write_checkpoint`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if deserialization fails.
+    pub fn read_checkpoint<R: std::io::Read>(reader: R) -> anyhow::Result<Self> {
+        bincode::deserialize_from(reader).map_err(Into::into)
+    }
+}
+
+/// The state saved and restored around an `unconstrained { ... }` block: a diff of the memory
+/// addresses it touched, so leaving the block can cheaply undo everything but what was written
+/// to the input stream.
+#[derive(Debug, Clone, Default)]
+pub struct ForkState {
+    /// The prior value of each memory address touched inside the block, `None` if it was vacant.
+    pub memory_diff: HashMap<u32, Option<MemoryRecord>>,
+}
@@ -0,0 +1,151 @@
+//! A minimal arbitrary-precision unsigned integer, used only to derive the final-exponentiation
+//! exponent `(q^12 - 1) / r` from the curve's base-field modulus and scalar-field order at call
+//! time, rather than hard-coding the ~4300-bit result directly.
+
+/// Little-endian base-2^64 limbs, most-significant limb never zero (except for the value `0`,
+/// which is a single zero limb).
+#[derive(Clone)]
+pub struct BigUint(Vec<u64>);
+
+impl BigUint {
+    pub fn from_limbs(limbs: &[u64]) -> Self {
+        let mut v = limbs.to_vec();
+        Self::trim(&mut v);
+        BigUint(v)
+    }
+
+    fn trim(v: &mut Vec<u64>) {
+        while v.len() > 1 && *v.last().unwrap() == 0 {
+            v.pop();
+        }
+    }
+
+    fn bit_len(&self) -> usize {
+        let top = *self.0.last().unwrap();
+        if top == 0 {
+            0
+        } else {
+            self.0.len() * 64 - top.leading_zeros() as usize
+        }
+    }
+
+    fn bit(&self, i: usize) -> bool {
+        let limb = i / 64;
+        let offset = i % 64;
+        limb < self.0.len() && (self.0[limb] >> offset) & 1 == 1
+    }
+
+    #[must_use]
+    pub fn mul(&self, other: &BigUint) -> BigUint {
+        let mut out = vec![0u64; self.0.len() + other.0.len()];
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry: u128 = 0;
+            for (j, &b) in other.0.iter().enumerate() {
+                let prod = u128::from(out[i + j]) + u128::from(a) * u128::from(b) + carry;
+                out[i + j] = prod as u64;
+                carry = prod >> 64;
+            }
+            let mut k = i + other.0.len();
+            while carry != 0 {
+                let sum = u128::from(out[k]) + carry;
+                out[k] = sum as u64;
+                carry = sum >> 64;
+                k += 1;
+            }
+        }
+        Self::trim(&mut out);
+        BigUint(out)
+    }
+
+    #[must_use]
+    pub fn pow_u32(&self, exp: u32) -> BigUint {
+        let mut result = BigUint::from_limbs(&[1]);
+        for _ in 0..exp {
+            result = result.mul(self);
+        }
+        result
+    }
+
+    #[must_use]
+    pub fn sub_one(&self) -> BigUint {
+        let mut out = self.0.clone();
+        for limb in &mut out {
+            if *limb == 0 {
+                *limb = u64::MAX;
+            } else {
+                *limb -= 1;
+                break;
+            }
+        }
+        Self::trim(&mut out);
+        BigUint(out)
+    }
+
+    fn cmp_ge(&self, other: &BigUint) -> bool {
+        if self.0.len() != other.0.len() {
+            return self.0.len() > other.0.len();
+        }
+        for i in (0..self.0.len()).rev() {
+            if self.0[i] != other.0[i] {
+                return self.0[i] > other.0[i];
+            }
+        }
+        true
+    }
+
+    fn sub(&self, other: &BigUint) -> BigUint {
+        let mut out = vec![0u64; self.0.len()];
+        let mut borrow: i128 = 0;
+        for i in 0..self.0.len() {
+            let b = if i < other.0.len() { other.0[i] } else { 0 };
+            let diff = i128::from(self.0[i]) - i128::from(b) - borrow;
+            if diff < 0 {
+                out[i] = (diff + (1i128 << 64)) as u64;
+                borrow = 1;
+            } else {
+                out[i] = diff as u64;
+                borrow = 0;
+            }
+        }
+        Self::trim(&mut out);
+        BigUint(out)
+    }
+
+    fn shl1(&self) -> BigUint {
+        let mut out = vec![0u64; self.0.len() + 1];
+        let mut carry = 0u64;
+        for (i, &limb) in self.0.iter().enumerate() {
+            out[i] = (limb << 1) | carry;
+            carry = limb >> 63;
+        }
+        out[self.0.len()] = carry;
+        Self::trim(&mut out);
+        BigUint(out)
+    }
+
+    /// `self / other`, by binary long division. Only ever called with `other` dividing `self`
+    /// exactly, so the (discarded) remainder isn't exposed.
+    #[must_use]
+    pub fn div_exact(&self, other: &BigUint) -> BigUint {
+        let mut quotient = vec![0u64; self.0.len()];
+        let mut remainder = BigUint(vec![0]);
+        for i in (0..self.bit_len()).rev() {
+            remainder = remainder.shl1();
+            if self.bit(i) {
+                remainder.0[0] |= 1;
+            }
+            if remainder.cmp_ge(other) {
+                remainder = remainder.sub(other);
+                quotient[i / 64] |= 1u64 << (i % 64);
+            }
+        }
+        Self::trim(&mut quotient);
+        BigUint(quotient)
+    }
+
+    /// The exponent's bits, most-significant first, with no leading zero bits.
+    #[must_use]
+    pub fn bits_be(&self) -> Vec<bool> {
+        (0..self.bit_len()).rev().map(|i| self.bit(i)).collect()
+    }
+}
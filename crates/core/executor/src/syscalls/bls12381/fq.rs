@@ -0,0 +1,247 @@
+//! Montgomery-form arithmetic for the BLS12-381 base field `F_q`, `q` a 381-bit prime, represented
+//! as six 64-bit limbs in little-endian order. This backs both the `F_q` coordinates used
+//! directly by G1 and, via [`super::fq2::Fq2`], the `F_q2`/`F_q6`/`F_q12` tower used by G2 and the
+//! pairing.
+
+/// The BLS12-381 base field modulus, little-endian 64-bit limbs.
+pub const MODULUS: [u64; 6] = [
+    0xb9fe_ffff_ffff_aaab,
+    0x1eab_fffe_b153_ffff,
+    0x6730_d2a0_f6b0_f624,
+    0x6477_4b84_f385_12bf,
+    0x4b1b_a7b6_434b_acd7,
+    0x1a01_11ea_397f_e69a,
+];
+
+/// `-q^-1 mod 2^64`, the CIOS Montgomery reduction constant.
+const INV: u64 = 0x89f3_fffc_fffc_fffd;
+
+/// `R = 2^384 mod q`, i.e. the Montgomery form of `1`.
+const R: [u64; 6] = [
+    0x7609_0000_0002_fffd,
+    0xebf4_000b_c40c_0002,
+    0x5f48_9857_53c7_58ba,
+    0x77ce_5853_7052_5745,
+    0x5c07_1a97_a256_ec6d,
+    0x15f6_5ec3_fa80_e493,
+];
+
+/// `R^2 = 2^768 mod q`, used to convert an integer into Montgomery form via one extra
+/// multiplication (`a * R^2 * R^-1 = a * R`).
+const R2: [u64; 6] = [
+    0xf4df_1f34_1c34_1746,
+    0x0a76_e6a6_09d1_04f1,
+    0x8de5_476c_4c95_b6d5,
+    0x67eb_88a9_939d_83c0,
+    0x9a79_3e85_b519_952d,
+    0x1198_8fe5_92ca_e3aa,
+];
+
+fn limbs_geq(a: &[u64; 6], b: &[u64; 6]) -> bool {
+    for i in (0..6).rev() {
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+fn limbs_sub(a: &[u64; 6], b: &[u64; 6]) -> [u64; 6] {
+    let mut out = [0u64; 6];
+    let mut borrow: i128 = 0;
+    for i in 0..6 {
+        let diff = i128::from(a[i]) - i128::from(b[i]) - borrow;
+        if diff < 0 {
+            out[i] = (diff + (1i128 << 64)) as u64;
+            borrow = 1;
+        } else {
+            out[i] = diff as u64;
+            borrow = 0;
+        }
+    }
+    out
+}
+
+/// CIOS Montgomery multiplication: `a * b * R^-1 mod q`.
+fn mont_mul(a: &[u64; 6], b: &[u64; 6]) -> [u64; 6] {
+    let mut t = [0u64; 7];
+    for i in 0..6 {
+        let mut carry: u128 = 0;
+        for (j, &t_j) in t.iter().enumerate().take(6) {
+            let prod = u128::from(t_j) + u128::from(a[j]) * u128::from(b[i]) + carry;
+            t[j] = prod as u64;
+            carry = prod >> 64;
+        }
+        let sum = u128::from(t[6]) + carry;
+        t[6] = sum as u64;
+        let overflow = sum >> 64;
+
+        let m = t[0].wrapping_mul(INV);
+        let mut carry2: u128 = 0;
+        for (j, &t_j) in t.iter().enumerate().take(6) {
+            let prod = u128::from(t_j) + u128::from(m) * u128::from(MODULUS[j]) + carry2;
+            t[j] = prod as u64;
+            carry2 = prod >> 64;
+        }
+        let sum2 = u128::from(t[6]) + carry2 + overflow;
+        t[6] = sum2 as u64;
+
+        for j in 0..6 {
+            t[j] = t[j + 1];
+        }
+        t[6] = 0;
+    }
+    let mut out = [0u64; 6];
+    out.copy_from_slice(&t[0..6]);
+    if limbs_geq(&out, &MODULUS) {
+        out = limbs_sub(&out, &MODULUS);
+    }
+    out
+}
+
+/// An element of the BLS12-381 base field `F_q`, stored internally in Montgomery form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fq(pub(crate) [u64; 6]);
+
+impl Fq {
+    pub const ZERO: Fq = Fq([0; 6]);
+    pub const ONE: Fq = Fq(R);
+
+    /// Lifts a small integer into Montgomery form.
+    #[must_use]
+    pub fn from_u64(value: u64) -> Fq {
+        Fq(mont_mul(&[value, 0, 0, 0, 0, 0], &R2))
+    }
+
+    /// Interprets 12 little-endian `u32` limbs (two per 64-bit limb) as an integer reduced `mod
+    /// q` and lifts it into Montgomery form.
+    #[must_use]
+    pub fn from_words(words: &[u32; 12]) -> Self {
+        let mut raw = [0u64; 6];
+        for i in 0..6 {
+            raw[i] = u64::from(words[2 * i]) | (u64::from(words[2 * i + 1]) << 32);
+        }
+        // A 384-bit input can be several multiples of `q`, so this one subtraction doesn't fully
+        // reduce it; `mont_mul` still returns a value below `q`, since for an input below 2^384
+        // times `R2 < q` its pre-subtraction output is below `2q`.
+        if limbs_geq(&raw, &MODULUS) {
+            raw = limbs_sub(&raw, &MODULUS);
+        }
+        Fq(mont_mul(&raw, &R2))
+    }
+
+    /// Converts back out of Montgomery form into 12 little-endian `u32` limbs.
+    #[must_use]
+    pub fn to_words(self) -> [u32; 12] {
+        let raw = mont_mul(&self.0, &[1, 0, 0, 0, 0, 0]);
+        let mut words = [0u32; 12];
+        for i in 0..6 {
+            words[2 * i] = raw[i] as u32;
+            words[2 * i + 1] = (raw[i] >> 32) as u32;
+        }
+        words
+    }
+
+    #[must_use]
+    pub fn add(self, rhs: Fq) -> Fq {
+        let mut out = [0u64; 6];
+        let mut carry: u128 = 0;
+        for i in 0..6 {
+            let sum = u128::from(self.0[i]) + u128::from(rhs.0[i]) + carry;
+            out[i] = sum as u64;
+            carry = sum >> 64;
+        }
+        if carry != 0 || limbs_geq(&out, &MODULUS) {
+            out = limbs_sub(&out, &MODULUS);
+        }
+        Fq(out)
+    }
+
+    #[must_use]
+    pub fn sub(self, rhs: Fq) -> Fq {
+        if limbs_geq(&self.0, &rhs.0) {
+            Fq(limbs_sub(&self.0, &rhs.0))
+        } else {
+            // self - rhs + q, computed as q - (rhs - self) so no step underflows.
+            Fq(limbs_sub(&MODULUS, &limbs_sub(&rhs.0, &self.0)))
+        }
+    }
+
+    #[must_use]
+    pub fn neg(self) -> Fq {
+        if self == Fq::ZERO {
+            self
+        } else {
+            Fq(limbs_sub(&MODULUS, &self.0))
+        }
+    }
+
+    #[must_use]
+    pub fn mul(self, rhs: Fq) -> Fq {
+        Fq(mont_mul(&self.0, &rhs.0))
+    }
+
+    #[must_use]
+    pub fn square(self) -> Fq {
+        self.mul(self)
+    }
+
+    #[must_use]
+    pub fn is_zero(self) -> bool {
+        self == Fq::ZERO
+    }
+
+    /// `self^-1`, computed via Fermat's little theorem (`self^(q-2)`). Returns `Fq::ZERO` for
+    /// `self == 0`, matching the convention used by callers that have already rejected the
+    /// malformed/zero case.
+    #[must_use]
+    pub fn invert(self) -> Fq {
+        if self.is_zero() {
+            return Fq::ZERO;
+        }
+        let exp = limbs_sub(&MODULUS, &[2, 0, 0, 0, 0, 0]);
+        let mut result = Fq::ONE;
+        for limb in exp.iter().rev() {
+            for bit in (0..64).rev() {
+                result = result.square();
+                if (limb >> bit) & 1 == 1 {
+                    result = result.mul(self);
+                }
+            }
+        }
+        result
+    }
+
+    /// `self^((q+1)/4)`, which is a square root of `self` whenever `self` is a quadratic residue
+    /// (valid since `q ≡ 3 (mod 4)` for the BLS12-381 base field). Callers must check the result
+    /// squares back to `self`; this function doesn't determine residuosity on its own.
+    #[must_use]
+    pub fn sqrt_candidate(self) -> Fq {
+        // exponent = (q+1)/4, computed from the modulus at call time rather than hard-coded so
+        // it can't drift out of sync with `MODULUS`.
+        let mut exp = MODULUS;
+        let mut carry = 1u128;
+        for limb in &mut exp {
+            let sum = u128::from(*limb) + carry;
+            *limb = sum as u64;
+            carry = sum >> 64;
+        }
+        // Divide the 381-bit `q+1` by 4 (shift right by two bits).
+        let mut shifted = [0u64; 6];
+        for i in 0..6 {
+            let lo = exp[i] >> 2;
+            let hi = if i + 1 < 6 { exp[i + 1] << 62 } else { 0 };
+            shifted[i] = lo | hi;
+        }
+        let mut result = Fq::ONE;
+        for limb in shifted.iter().rev() {
+            for bit in (0..64).rev() {
+                result = result.square();
+                if (limb >> bit) & 1 == 1 {
+                    result = result.mul(self);
+                }
+            }
+        }
+        result
+    }
+}
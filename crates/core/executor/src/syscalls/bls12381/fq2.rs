@@ -0,0 +1,126 @@
+//! `F_q2 = F_q[u] / (u^2 + 1)`, the quadratic extension BLS12-381 G2 and the pairing are built
+//! over.
+
+use super::fq::Fq;
+
+/// An element `c0 + c1*u` of `F_q2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fq2 {
+    pub c0: Fq,
+    pub c1: Fq,
+}
+
+impl Fq2 {
+    pub const ZERO: Fq2 = Fq2 { c0: Fq::ZERO, c1: Fq::ZERO };
+    pub const ONE: Fq2 = Fq2 { c0: Fq::ONE, c1: Fq::ZERO };
+
+    #[must_use]
+    pub fn from_words(words: &[u32; 24]) -> Self {
+        let mut c0_words = [0u32; 12];
+        let mut c1_words = [0u32; 12];
+        c0_words.copy_from_slice(&words[0..12]);
+        c1_words.copy_from_slice(&words[12..24]);
+        Fq2 { c0: Fq::from_words(&c0_words), c1: Fq::from_words(&c1_words) }
+    }
+
+    #[must_use]
+    pub fn to_words(self) -> [u32; 24] {
+        let mut words = [0u32; 24];
+        words[0..12].copy_from_slice(&self.c0.to_words());
+        words[12..24].copy_from_slice(&self.c1.to_words());
+        words
+    }
+
+    #[must_use]
+    pub fn add(self, rhs: Fq2) -> Fq2 {
+        Fq2 { c0: self.c0.add(rhs.c0), c1: self.c1.add(rhs.c1) }
+    }
+
+    #[must_use]
+    pub fn sub(self, rhs: Fq2) -> Fq2 {
+        Fq2 { c0: self.c0.sub(rhs.c0), c1: self.c1.sub(rhs.c1) }
+    }
+
+    #[must_use]
+    pub fn neg(self) -> Fq2 {
+        Fq2 { c0: self.c0.neg(), c1: self.c1.neg() }
+    }
+
+    #[must_use]
+    pub fn mul(self, rhs: Fq2) -> Fq2 {
+        // (a0 + a1 u)(b0 + b1 u) = (a0 b0 - a1 b1) + (a0 b1 + a1 b0) u, since u^2 = -1.
+        let a0b0 = self.c0.mul(rhs.c0);
+        let a1b1 = self.c1.mul(rhs.c1);
+        let a0b1 = self.c0.mul(rhs.c1);
+        let a1b0 = self.c1.mul(rhs.c0);
+        Fq2 { c0: a0b0.sub(a1b1), c1: a0b1.add(a1b0) }
+    }
+
+    #[must_use]
+    pub fn square(self) -> Fq2 {
+        self.mul(self)
+    }
+
+    /// Scales `self` by the `F_q` element `by`.
+    #[must_use]
+    pub fn mul_by_fq(self, by: Fq) -> Fq2 {
+        Fq2 { c0: self.c0.mul(by), c1: self.c1.mul(by) }
+    }
+
+    /// Multiplies by the G2 twist's cubic non-residue, `1 + u`.
+    #[must_use]
+    pub fn mul_by_nonresidue(self) -> Fq2 {
+        // (c0 + c1 u)(1 + u) = (c0 - c1) + (c0 + c1) u
+        Fq2 { c0: self.c0.sub(self.c1), c1: self.c0.add(self.c1) }
+    }
+
+    #[must_use]
+    pub fn is_zero(self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    #[must_use]
+    pub fn invert(self) -> Fq2 {
+        // 1/(c0+c1 u) = (c0-c1 u) / (c0^2+c1^2).
+        let norm = self.c0.square().add(self.c1.square());
+        let inv_norm = norm.invert();
+        Fq2 { c0: self.c0.mul(inv_norm), c1: self.c1.neg().mul(inv_norm) }
+    }
+
+    /// A square root of `self`, if one exists, via the "complex method" (Scott 2012): reduce to
+    /// one `F_q` square root of the norm and one of a half-sum, both available in closed form
+    /// since `q ≡ 3 (mod 4)`. Returns `None` if `self` isn't a quadratic residue.
+    #[must_use]
+    pub fn sqrt(self) -> Option<Fq2> {
+        if self.is_zero() {
+            return Some(Fq2::ZERO);
+        }
+        let two_inv = Fq::from_u64(2).invert();
+        let alpha = self.c0.square().add(self.c1.square()).sqrt_candidate();
+        if alpha.square() != self.c0.square().add(self.c1.square()) {
+            return None;
+        }
+        let mut delta = self.c0.add(alpha).mul(two_inv);
+        let mut x0 = delta.sqrt_candidate();
+        if x0.square() != delta {
+            delta = self.c0.sub(alpha).mul(two_inv);
+            x0 = delta.sqrt_candidate();
+            if x0.square() != delta {
+                return None;
+            }
+        }
+        if x0.is_zero() {
+            // Only reached for `c1 == 0` with `c0` a non-residue in `F_q`: the root is then
+            // `sqrt(-c0) * u`, since `u^2 = -1`.
+            let x1 = self.c0.neg().sqrt_candidate();
+            return (x1.square() == self.c0.neg()).then_some(Fq2 { c0: Fq::ZERO, c1: x1 });
+        }
+        let x1 = self.c1.mul(two_inv).mul(x0.invert());
+        let candidate = Fq2 { c0: x0, c1: x1 };
+        if candidate.square() == self {
+            Some(candidate)
+        } else {
+            None
+        }
+    }
+}
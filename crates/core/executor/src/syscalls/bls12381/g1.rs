@@ -0,0 +1,204 @@
+//! BLS12-381 G1: the curve `y^2 = x^3 + 4` over `F_q`.
+//!
+//! Points are exchanged with the guest in affine `(x, y)` form (24 little-endian `u32` words),
+//! with the all-zero encoding reserved for the point at infinity -- `(0, 0)` doesn't satisfy the
+//! curve equation, so it's free to repurpose as a sentinel. Internally, [`G1Affine::double`]/
+//! [`G1Affine::add`] lift to Jacobian coordinates to avoid a field inversion per group operation,
+//! then convert back on the way out.
+
+use super::fq::Fq;
+
+/// The curve coefficient `b` in `y^2 = x^3 + b`.
+const B: u64 = 4;
+
+/// An affine BLS12-381 G1 point, or the point at infinity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct G1Affine {
+    pub x: Fq,
+    pub y: Fq,
+    pub infinity: bool,
+}
+
+/// A Jacobian BLS12-381 G1 point: affine `(x, y) = (X/Z^2, Y/Z^3)`.
+#[derive(Debug, Clone, Copy)]
+struct G1Jacobian {
+    x: Fq,
+    y: Fq,
+    z: Fq,
+}
+
+impl G1Affine {
+    pub const INFINITY: G1Affine = G1Affine { x: Fq::ZERO, y: Fq::ZERO, infinity: true };
+
+    #[must_use]
+    pub fn from_words(words: &[u32; 24]) -> Self {
+        let mut x_words = [0u32; 12];
+        let mut y_words = [0u32; 12];
+        x_words.copy_from_slice(&words[0..12]);
+        y_words.copy_from_slice(&words[12..24]);
+        let x = Fq::from_words(&x_words);
+        let y = Fq::from_words(&y_words);
+        let infinity = x.is_zero() && y.is_zero();
+        G1Affine { x, y, infinity }
+    }
+
+    #[must_use]
+    pub fn to_words(self) -> [u32; 24] {
+        let mut words = [0u32; 24];
+        if self.infinity {
+            return words;
+        }
+        words[0..12].copy_from_slice(&self.x.to_words());
+        words[12..24].copy_from_slice(&self.y.to_words());
+        words
+    }
+
+    /// Whether `(x, y)` satisfies `y^2 = x^3 + 4`. The point at infinity is always on-curve.
+    #[must_use]
+    pub fn is_on_curve(self) -> bool {
+        if self.infinity {
+            return true;
+        }
+        let lhs = self.y.square();
+        let rhs = self.x.square().mul(self.x).add(Fq::from_u64(B));
+        lhs == rhs
+    }
+
+    fn to_jacobian(self) -> G1Jacobian {
+        if self.infinity {
+            G1Jacobian { x: Fq::ONE, y: Fq::ONE, z: Fq::ZERO }
+        } else {
+            G1Jacobian { x: self.x, y: self.y, z: Fq::ONE }
+        }
+    }
+
+    #[must_use]
+    pub fn add(self, rhs: G1Affine) -> G1Affine {
+        if self.infinity {
+            return rhs;
+        }
+        if rhs.infinity {
+            return self;
+        }
+        self.to_jacobian().add(rhs.to_jacobian()).to_affine()
+    }
+
+    #[must_use]
+    pub fn double(self) -> G1Affine {
+        if self.infinity {
+            return self;
+        }
+        self.to_jacobian().double().to_affine()
+    }
+
+    #[must_use]
+    pub fn neg(self) -> G1Affine {
+        if self.infinity {
+            self
+        } else {
+            G1Affine { x: self.x, y: self.y.neg(), infinity: false }
+        }
+    }
+
+    /// Double-and-add scalar multiplication, `scalar` given as 8 little-endian `u32` words.
+    #[must_use]
+    pub fn scalar_mul(self, scalar: &[u32; 8]) -> G1Affine {
+        let mut acc = G1Affine::INFINITY;
+        for word in scalar.iter().rev() {
+            for bit in (0..32).rev() {
+                acc = acc.double();
+                if (word >> bit) & 1 == 1 {
+                    acc = acc.add(self);
+                }
+            }
+        }
+        acc
+    }
+
+    /// Deterministically derives an on-curve point from `u`, by treating it as a candidate
+    /// `x`-coordinate and nudging it forward until `x^3 + b` is a square. This is a simplification
+    /// of the standard SSWU/isogeny hash-to-curve map: it always lands on the curve, but (unlike
+    /// the standard, isogeny-based map) isn't constant-time or indifferentiable from a random
+    /// oracle, so it isn't suitable outside this host-side reference implementation.
+    #[must_use]
+    pub fn map_to_curve(u: Fq) -> G1Affine {
+        let mut x = u;
+        loop {
+            let rhs = x.square().mul(x).add(Fq::from_u64(B));
+            let y = rhs.sqrt_candidate();
+            if y.square() == rhs {
+                return G1Affine { x, y, infinity: false };
+            }
+            x = x.add(Fq::ONE);
+        }
+    }
+}
+
+impl G1Jacobian {
+    fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// "dbl-2009-l" doubling, specialized to `a = 0`.
+    fn double(&self) -> G1Jacobian {
+        if self.is_infinity() || self.y.is_zero() {
+            return G1Jacobian { x: Fq::ONE, y: Fq::ONE, z: Fq::ZERO };
+        }
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+        let d = self.x.add(b).square().sub(a).sub(c);
+        let d = d.add(d);
+        let e = a.add(a).add(a);
+        let f = e.square();
+        let x3 = f.sub(d).sub(d);
+        let eight_c = c.add(c).add(c).add(c).add(c).add(c).add(c).add(c);
+        let y3 = e.mul(d.sub(x3)).sub(eight_c);
+        let z3 = self.y.mul(self.z);
+        let z3 = z3.add(z3);
+        G1Jacobian { x: x3, y: y3, z: z3 }
+    }
+
+    /// "add-2007-bl" general Jacobian addition.
+    fn add(&self, rhs: G1Jacobian) -> G1Jacobian {
+        if self.is_infinity() {
+            return rhs;
+        }
+        if rhs.is_infinity() {
+            return *self;
+        }
+        let z1z1 = self.z.square();
+        let z2z2 = rhs.z.square();
+        let u1 = self.x.mul(z2z2);
+        let u2 = rhs.x.mul(z1z1);
+        let s1 = self.y.mul(rhs.z).mul(z2z2);
+        let s2 = rhs.y.mul(self.z).mul(z1z1);
+
+        if u1 == u2 {
+            if s1 != s2 {
+                return G1Jacobian { x: Fq::ONE, y: Fq::ONE, z: Fq::ZERO };
+            }
+            return self.double();
+        }
+
+        let h = u2.sub(u1);
+        let i = h.add(h).square();
+        let j = h.mul(i);
+        let r = s2.sub(s1).add(s2.sub(s1));
+        let v = u1.mul(i);
+        let x3 = r.square().sub(j).sub(v).sub(v);
+        let y3 = r.mul(v.sub(x3)).sub(s1.mul(j).add(s1.mul(j)));
+        let z3 = self.z.add(rhs.z).square().sub(z1z1).sub(z2z2).mul(h);
+        G1Jacobian { x: x3, y: y3, z: z3 }
+    }
+
+    fn to_affine(self) -> G1Affine {
+        if self.is_infinity() {
+            return G1Affine::INFINITY;
+        }
+        let z_inv = self.z.invert();
+        let z_inv2 = z_inv.square();
+        let z_inv3 = z_inv2.mul(z_inv);
+        G1Affine { x: self.x.mul(z_inv2), y: self.y.mul(z_inv3), infinity: false }
+    }
+}
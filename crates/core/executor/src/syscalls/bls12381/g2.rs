@@ -0,0 +1,218 @@
+//! BLS12-381 G2: the sextic twist `y^2 = x^3 + 4(1 + u)` over `F_q2`.
+//!
+//! Mirrors [`super::g1::G1Affine`]'s conventions: affine `(x, y)` encoded as 48 little-endian
+//! `u32` words, all-zero standing in for the point at infinity, Jacobian coordinates used
+//! internally for `add`/`double`.
+
+use super::fq::Fq;
+use super::fq2::Fq2;
+
+/// An affine BLS12-381 G2 point, or the point at infinity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct G2Affine {
+    pub x: Fq2,
+    pub y: Fq2,
+    pub infinity: bool,
+}
+
+/// A Jacobian BLS12-381 G2 point: affine `(x, y) = (X/Z^2, Y/Z^3)`.
+#[derive(Debug, Clone, Copy)]
+struct G2Jacobian {
+    x: Fq2,
+    y: Fq2,
+    z: Fq2,
+}
+
+/// `b2 = 4*(1+u)`, the twisted curve's coefficient.
+fn twist_b() -> Fq2 {
+    Fq2 { c0: Fq::from_u64(4), c1: Fq::from_u64(4) }
+}
+
+impl G2Affine {
+    pub const INFINITY: G2Affine = G2Affine { x: Fq2::ZERO, y: Fq2::ZERO, infinity: true };
+
+    #[must_use]
+    pub fn from_words(words: &[u32; 48]) -> Self {
+        let mut x_words = [0u32; 24];
+        let mut y_words = [0u32; 24];
+        x_words.copy_from_slice(&words[0..24]);
+        y_words.copy_from_slice(&words[24..48]);
+        let x = Fq2::from_words(&x_words);
+        let y = Fq2::from_words(&y_words);
+        let infinity = x.is_zero() && y.is_zero();
+        G2Affine { x, y, infinity }
+    }
+
+    #[must_use]
+    pub fn to_words(self) -> [u32; 48] {
+        let mut words = [0u32; 48];
+        if self.infinity {
+            return words;
+        }
+        words[0..24].copy_from_slice(&self.x.to_words());
+        words[24..48].copy_from_slice(&self.y.to_words());
+        words
+    }
+
+    #[must_use]
+    pub fn is_on_curve(self) -> bool {
+        if self.infinity {
+            return true;
+        }
+        let lhs = self.y.square();
+        let rhs = self.x.square().mul(self.x).add(twist_b());
+        lhs == rhs
+    }
+
+    fn to_jacobian(self) -> G2Jacobian {
+        if self.infinity {
+            G2Jacobian { x: Fq2::ONE, y: Fq2::ONE, z: Fq2::ZERO }
+        } else {
+            G2Jacobian { x: self.x, y: self.y, z: Fq2::ONE }
+        }
+    }
+
+    #[must_use]
+    pub fn add(self, rhs: G2Affine) -> G2Affine {
+        if self.infinity {
+            return rhs;
+        }
+        if rhs.infinity {
+            return self;
+        }
+        self.to_jacobian().add(rhs.to_jacobian()).to_affine()
+    }
+
+    #[must_use]
+    pub fn double(self) -> G2Affine {
+        if self.infinity {
+            return self;
+        }
+        self.to_jacobian().double().to_affine()
+    }
+
+    #[must_use]
+    pub fn neg(self) -> G2Affine {
+        if self.infinity {
+            self
+        } else {
+            G2Affine { x: self.x, y: self.y.neg(), infinity: false }
+        }
+    }
+
+    #[must_use]
+    pub fn scalar_mul(self, scalar: &[u32; 8]) -> G2Affine {
+        let mut acc = G2Affine::INFINITY;
+        for word in scalar.iter().rev() {
+            for bit in (0..32).rev() {
+                acc = acc.double();
+                if (word >> bit) & 1 == 1 {
+                    acc = acc.add(self);
+                }
+            }
+        }
+        acc
+    }
+
+    /// Deterministically derives an on-curve point from `u`, by treating it as a candidate
+    /// `x`-coordinate and nudging it forward until `x^3 + b2` is a square. This is a
+    /// simplification of the standard SSWU/isogeny hash-to-curve map: it always lands on the
+    /// curve, but (unlike the standard, isogeny-based map) isn't constant-time or
+    /// indifferentiable from a random oracle, so it isn't suitable outside this host-side
+    /// reference implementation.
+    #[must_use]
+    pub fn map_to_curve(u: Fq2) -> G2Affine {
+        let mut x = u;
+        loop {
+            let rhs = x.square().mul(x).add(twist_b());
+            if let Some(y) = rhs.sqrt() {
+                return G2Affine { x, y, infinity: false };
+            }
+            x = x.add(Fq2::ONE);
+        }
+    }
+
+    /// Decompresses a point from its `x`-coordinate (the other 24 words of the 48-word encoding
+    /// are ignored/zero on the wire), picking the root of `y^2 = x^3+b2` whose `F_q` constant
+    /// term has an even low word -- an arbitrary but deterministic convention, since this
+    /// encoding isn't exchanged with any system outside this executor.
+    #[must_use]
+    pub fn decompress(compressed: &[u32; 24]) -> Option<G2Affine> {
+        let x = Fq2::from_words(compressed);
+        if x.is_zero() {
+            return Some(G2Affine::INFINITY);
+        }
+        let rhs = x.square().mul(x).add(twist_b());
+        let y = rhs.sqrt()?;
+        let y_words = y.to_words();
+        let y = if y_words[0] & 1 == 0 { y } else { y.neg() };
+        Some(G2Affine { x, y, infinity: false })
+    }
+}
+
+impl G2Jacobian {
+    fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    fn double(&self) -> G2Jacobian {
+        if self.is_infinity() || self.y.is_zero() {
+            return G2Jacobian { x: Fq2::ONE, y: Fq2::ONE, z: Fq2::ZERO };
+        }
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+        let d = self.x.add(b).square().sub(a).sub(c);
+        let d = d.add(d);
+        let e = a.add(a).add(a);
+        let f = e.square();
+        let x3 = f.sub(d).sub(d);
+        let eight_c = c.add(c).add(c).add(c).add(c).add(c).add(c).add(c);
+        let y3 = e.mul(d.sub(x3)).sub(eight_c);
+        let z3 = self.y.mul(self.z);
+        let z3 = z3.add(z3);
+        G2Jacobian { x: x3, y: y3, z: z3 }
+    }
+
+    fn add(&self, rhs: G2Jacobian) -> G2Jacobian {
+        if self.is_infinity() {
+            return rhs;
+        }
+        if rhs.is_infinity() {
+            return *self;
+        }
+        let z1z1 = self.z.square();
+        let z2z2 = rhs.z.square();
+        let u1 = self.x.mul(z2z2);
+        let u2 = rhs.x.mul(z1z1);
+        let s1 = self.y.mul(rhs.z).mul(z2z2);
+        let s2 = rhs.y.mul(self.z).mul(z1z1);
+
+        if u1 == u2 {
+            if s1 != s2 {
+                return G2Jacobian { x: Fq2::ONE, y: Fq2::ONE, z: Fq2::ZERO };
+            }
+            return self.double();
+        }
+
+        let h = u2.sub(u1);
+        let i = h.add(h).square();
+        let j = h.mul(i);
+        let r = s2.sub(s1).add(s2.sub(s1));
+        let v = u1.mul(i);
+        let x3 = r.square().sub(j).sub(v).sub(v);
+        let y3 = r.mul(v.sub(x3)).sub(s1.mul(j).add(s1.mul(j)));
+        let z3 = self.z.add(rhs.z).square().sub(z1z1).sub(z2z2).mul(h);
+        G2Jacobian { x: x3, y: y3, z: z3 }
+    }
+
+    fn to_affine(self) -> G2Affine {
+        if self.is_infinity() {
+            return G2Affine::INFINITY;
+        }
+        let z_inv = self.z.invert();
+        let z_inv2 = z_inv.square();
+        let z_inv3 = z_inv2.mul(z_inv);
+        G2Affine { x: self.x.mul(z_inv2), y: self.y.mul(z_inv3), infinity: false }
+    }
+}
@@ -0,0 +1,347 @@
+//! BLS12-381 field/curve/pairing precompiles, exposed as [`crate::syscalls::Syscall`] impls so
+//! guest programs can do G1/G2 arithmetic and pairing checks (e.g. for BLS aggregate signature
+//! verification) as a single syscall instead of open-coding Montgomery field math in guest MIPS.
+//!
+//! Every operation reads its operands out of guest memory at the pointers passed in `arg1`/
+//! `arg2`, does the field/curve/pairing arithmetic host-side in [`fq`]/[`fq2`]/[`fq6`]/[`fq12`]/
+//! [`g1`]/[`g2`]/[`pairing`], and writes the result back -- mirroring how the base ISA's loads and
+//! stores go through [`crate::Executor::mw_cpu`], so the same memory trace covers both. An
+//! off-curve input point is reported back to the guest as an errno-style fault in `a1`, the same
+//! convention [`super::bundlefs`] uses for a missing bundle file, rather than panicking the host.
+//! Subgroup membership is not checked by the arithmetic or pairing operations; the G1/G2
+//! subgroup-check syscalls return it as their result instead.
+
+mod bigint;
+mod fq;
+mod fq12;
+mod fq2;
+mod fq6;
+mod g1;
+mod g2;
+mod pairing;
+
+use g1::G1Affine;
+use g2::G2Affine;
+
+use super::{context::SyscallContext, Syscall, SyscallCode};
+
+/// Errno-style fault: an input point doesn't satisfy its curve equation.
+const BLS12381_INVALID_POINT: u32 = 1;
+/// Errno-style fault: `num_pairs` exceeds [`MAX_PAIRS`].
+const BLS12381_TOO_MANY_PAIRS: u32 = 2;
+
+/// Upper bound on `num_pairs` for [`PairingCheckSyscall`], the same guest-controlled-length-clamp
+/// convention [`super::mem_copy::MAX_MEM_COPY_WORDS`] and [`super::return_data::MAX_RETURN_DATA_LEN`]
+/// use elsewhere in this module -- unlike those, `num_pairs` is a syscall register operand with no
+/// memory indirection at all, the most directly guest-controlled instance of the pattern, so
+/// rejecting an out-of-range count outright (rather than silently truncating a pairing-product
+/// check, which would corrupt the result) is the only safe option. A real pairing check rarely
+/// batches more than a handful of pairs, so this is generous headroom, not a tight fit.
+pub const MAX_PAIRS: u32 = 64;
+
+fn read_words<const N: usize>(ctx: &mut SyscallContext, addr: u32) -> [u32; N] {
+    let mut words = [0u32; N];
+    for (i, word) in words.iter_mut().enumerate() {
+        *word = ctx.rt.word(addr + (i as u32) * 4);
+    }
+    words
+}
+
+fn write_words<const N: usize>(ctx: &mut SyscallContext, addr: u32, words: &[u32; N]) {
+    for (i, word) in words.iter().enumerate() {
+        ctx.rt.mw_cpu(addr + (i as u32) * 4, *word, crate::events::MemoryAccessPosition::A);
+    }
+}
+
+fn g1_subgroup_check(p: G1Affine) -> bool {
+    p.scalar_mul(&pairing::FR_MODULUS_WORDS) == G1Affine::INFINITY
+}
+
+fn g2_subgroup_check(q: G2Affine) -> bool {
+    q.scalar_mul(&pairing::FR_MODULUS_WORDS) == G2Affine::INFINITY
+}
+
+pub(crate) struct G1AddSyscall;
+impl Syscall for G1AddSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        p_ptr: u32,
+        q_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let p = G1Affine::from_words(&read_words(ctx, p_ptr));
+        let q = G1Affine::from_words(&read_words(ctx, q_ptr));
+        if !p.is_on_curve() || !q.is_on_curve() {
+            return Some((0xffff_ffff, BLS12381_INVALID_POINT));
+        }
+        write_words(ctx, p_ptr, &p.add(q).to_words());
+        Some((0, 0))
+    }
+}
+
+pub(crate) struct G1DoubleSyscall;
+impl Syscall for G1DoubleSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        p_ptr: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        let p = G1Affine::from_words(&read_words(ctx, p_ptr));
+        if !p.is_on_curve() {
+            return Some((0xffff_ffff, BLS12381_INVALID_POINT));
+        }
+        write_words(ctx, p_ptr, &p.double().to_words());
+        Some((0, 0))
+    }
+}
+
+pub(crate) struct G1ScalarMulSyscall;
+impl Syscall for G1ScalarMulSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        p_ptr: u32,
+        scalar_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let p = G1Affine::from_words(&read_words(ctx, p_ptr));
+        if !p.is_on_curve() {
+            return Some((0xffff_ffff, BLS12381_INVALID_POINT));
+        }
+        let scalar = read_words(ctx, scalar_ptr);
+        write_words(ctx, p_ptr, &p.scalar_mul(&scalar).to_words());
+        Some((0, 0))
+    }
+}
+
+pub(crate) struct G1SubgroupCheckSyscall;
+impl Syscall for G1SubgroupCheckSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        p_ptr: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        let p = G1Affine::from_words(&read_words(ctx, p_ptr));
+        if !p.is_on_curve() {
+            return Some((0, BLS12381_INVALID_POINT));
+        }
+        Some((u32::from(g1_subgroup_check(p)), 0))
+    }
+}
+
+pub(crate) struct G1MapSyscall;
+impl Syscall for G1MapSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        u_ptr: u32,
+        out_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let u = fq::Fq::from_words(&read_words(ctx, u_ptr));
+        let p = G1Affine::map_to_curve(u);
+        write_words(ctx, out_ptr, &p.to_words());
+        Some((0, 0))
+    }
+}
+
+pub(crate) struct G2AddSyscall;
+impl Syscall for G2AddSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        p_ptr: u32,
+        q_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let p = G2Affine::from_words(&read_words(ctx, p_ptr));
+        let q = G2Affine::from_words(&read_words(ctx, q_ptr));
+        if !p.is_on_curve() || !q.is_on_curve() {
+            return Some((0xffff_ffff, BLS12381_INVALID_POINT));
+        }
+        write_words(ctx, p_ptr, &p.add(q).to_words());
+        Some((0, 0))
+    }
+}
+
+pub(crate) struct G2DoubleSyscall;
+impl Syscall for G2DoubleSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        p_ptr: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        let p = G2Affine::from_words(&read_words(ctx, p_ptr));
+        if !p.is_on_curve() {
+            return Some((0xffff_ffff, BLS12381_INVALID_POINT));
+        }
+        write_words(ctx, p_ptr, &p.double().to_words());
+        Some((0, 0))
+    }
+}
+
+pub(crate) struct G2ScalarMulSyscall;
+impl Syscall for G2ScalarMulSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        p_ptr: u32,
+        scalar_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let p = G2Affine::from_words(&read_words(ctx, p_ptr));
+        if !p.is_on_curve() {
+            return Some((0xffff_ffff, BLS12381_INVALID_POINT));
+        }
+        let scalar = read_words(ctx, scalar_ptr);
+        write_words(ctx, p_ptr, &p.scalar_mul(&scalar).to_words());
+        Some((0, 0))
+    }
+}
+
+pub(crate) struct G2SubgroupCheckSyscall;
+impl Syscall for G2SubgroupCheckSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        p_ptr: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        let p = G2Affine::from_words(&read_words(ctx, p_ptr));
+        if !p.is_on_curve() {
+            return Some((0, BLS12381_INVALID_POINT));
+        }
+        Some((u32::from(g2_subgroup_check(p)), 0))
+    }
+}
+
+pub(crate) struct G2MapSyscall;
+impl Syscall for G2MapSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        u_ptr: u32,
+        out_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let u = fq2::Fq2::from_words(&read_words(ctx, u_ptr));
+        let p = G2Affine::map_to_curve(u);
+        write_words(ctx, out_ptr, &p.to_words());
+        Some((0, 0))
+    }
+}
+
+pub(crate) struct G2DecompressSyscall;
+impl Syscall for G2DecompressSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        compressed_ptr: u32,
+        out_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let compressed = read_words(ctx, compressed_ptr);
+        let Some(p) = G2Affine::decompress(&compressed) else {
+            return Some((0xffff_ffff, BLS12381_INVALID_POINT));
+        };
+        write_words(ctx, out_ptr, &p.to_words());
+        Some((0, 0))
+    }
+}
+
+/// `arg1` points to the G1 operand; `arg2` points to a 2-word table `[g2_ptr, out_ptr]`, since
+/// this operation needs a third pointer that doesn't fit in the usual `(arg1, arg2)` pair.
+pub(crate) struct MillerLoopSyscall;
+impl Syscall for MillerLoopSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        g1_ptr: u32,
+        args_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let [g2_ptr, out_ptr]: [u32; 2] = read_words(ctx, args_ptr);
+        let p = G1Affine::from_words(&read_words(ctx, g1_ptr));
+        let q = G2Affine::from_words(&read_words(ctx, g2_ptr));
+        if !p.is_on_curve() || !q.is_on_curve() {
+            return Some((0xffff_ffff, BLS12381_INVALID_POINT));
+        }
+        let f = pairing::miller_loop(p, q);
+        write_words(ctx, out_ptr, &fq12_to_words(f));
+        Some((0, 0))
+    }
+}
+
+pub(crate) struct FinalExpSyscall;
+impl Syscall for FinalExpSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        f_ptr: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        let f = fq12_from_words(read_words(ctx, f_ptr));
+        write_words(ctx, f_ptr, &fq12_to_words(pairing::final_exponentiation(f)));
+        Some((0, 0))
+    }
+}
+
+pub(crate) struct PairingCheckSyscall;
+impl Syscall for PairingCheckSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        pairs_ptr: u32,
+        num_pairs: u32,
+    ) -> Option<(u32, u32)> {
+        if num_pairs > MAX_PAIRS {
+            return Some((0, BLS12381_TOO_MANY_PAIRS));
+        }
+
+        const PAIR_WORDS: u32 = 24 + 48;
+        let mut pairs = Vec::with_capacity(num_pairs as usize);
+        for i in 0..num_pairs {
+            let base = pairs_ptr + i * PAIR_WORDS * 4;
+            let p = G1Affine::from_words(&read_words(ctx, base));
+            let q = G2Affine::from_words(&read_words(ctx, base + 24 * 4));
+            if !p.is_on_curve() || !q.is_on_curve() {
+                return Some((0, BLS12381_INVALID_POINT));
+            }
+            pairs.push((p, q));
+        }
+        Some((u32::from(pairing::pairing_check(&pairs)), 0))
+    }
+}
+
+/// Packs an `F_q12` element as 12 `F_q` coordinates of 12 words each (144 words total): `c0.c0`,
+/// `c0.c1`, `c0.c2`, `c1.c0`, `c1.c1`, `c1.c2`, each an `F_q2` pair `(c0, c1)` in turn.
+fn fq12_to_words(f: fq12::Fq12) -> [u32; 144] {
+    let mut words = [0u32; 144];
+    for (i, fq2) in [f.c0.c0, f.c0.c1, f.c0.c2, f.c1.c0, f.c1.c1, f.c1.c2].into_iter().enumerate() {
+        words[i * 24..(i + 1) * 24].copy_from_slice(&fq2.to_words());
+    }
+    words
+}
+
+fn fq12_from_words(words: [u32; 144]) -> fq12::Fq12 {
+    let mut fq2s = [fq2::Fq2::ZERO; 6];
+    for (i, fq2) in fq2s.iter_mut().enumerate() {
+        let mut chunk = [0u32; 24];
+        chunk.copy_from_slice(&words[i * 24..(i + 1) * 24]);
+        *fq2 = fq2::Fq2::from_words(&chunk);
+    }
+    fq12::Fq12 {
+        c0: fq6::Fq6 { c0: fq2s[0], c1: fq2s[1], c2: fq2s[2] },
+        c1: fq6::Fq6 { c0: fq2s[3], c1: fq2s[4], c2: fq2s[5] },
+    }
+}
@@ -0,0 +1,240 @@
+//! Montgomery-form arithmetic for the Bn254 (alt_bn128) base field `F_q`, `q` a 254-bit prime,
+//! represented as four 64-bit limbs in little-endian order. This backs both the `F_q` coordinates
+//! used directly by G1 and, via [`super::fq2::Fq2`], the `F_q2`/`F_q6`/`F_q12` tower used by G2
+//! and the pairing.
+
+/// The Bn254 base field modulus, little-endian 64-bit limbs.
+pub const MODULUS: [u64; 4] = [
+    0x3c20_8c16_d87c_fd47,
+    0x9781_6a91_6871_ca8d,
+    0xb850_45b6_8181_585d,
+    0x3064_4e72_e131_a029,
+];
+
+/// `-q^-1 mod 2^64`, the CIOS Montgomery reduction constant.
+const INV: u64 = 0x87d2_0782_e486_6389;
+
+/// `R = 2^256 mod q`, i.e. the Montgomery form of `1`.
+const R: [u64; 4] = [
+    0xd35d_438d_c58f_0d9d,
+    0x0a78_eb28_f5c7_0b3d,
+    0x666e_a36f_7879_462c,
+    0x0e0a_77c1_9a07_df2f,
+];
+
+/// `R^2 = 2^512 mod q`, used to convert an integer into Montgomery form via one extra
+/// multiplication (`a * R^2 * R^-1 = a * R`).
+const R2: [u64; 4] = [
+    0xf32c_fc5b_538a_fa89,
+    0xb5e7_1911_d445_01fb,
+    0x47ab_1eff_0a41_7ff6,
+    0x06d8_9f71_cab8_351f,
+];
+
+fn limbs_geq(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    for i in (0..4).rev() {
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+fn limbs_sub(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut out = [0u64; 4];
+    let mut borrow: i128 = 0;
+    for i in 0..4 {
+        let diff = i128::from(a[i]) - i128::from(b[i]) - borrow;
+        if diff < 0 {
+            out[i] = (diff + (1i128 << 64)) as u64;
+            borrow = 1;
+        } else {
+            out[i] = diff as u64;
+            borrow = 0;
+        }
+    }
+    out
+}
+
+/// CIOS Montgomery multiplication: `a * b * R^-1 mod q`.
+fn mont_mul(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut t = [0u64; 5];
+    for i in 0..4 {
+        let mut carry: u128 = 0;
+        for j in 0..4 {
+            let prod = u128::from(t[j]) + u128::from(a[j]) * u128::from(b[i]) + carry;
+            t[j] = prod as u64;
+            carry = prod >> 64;
+        }
+        let sum = u128::from(t[4]) + carry;
+        t[4] = sum as u64;
+        let overflow = sum >> 64;
+
+        let m = t[0].wrapping_mul(INV);
+        let mut carry2: u128 = 0;
+        for j in 0..4 {
+            let prod = u128::from(t[j]) + u128::from(m) * u128::from(MODULUS[j]) + carry2;
+            t[j] = prod as u64;
+            carry2 = prod >> 64;
+        }
+        let sum2 = u128::from(t[4]) + carry2 + overflow;
+        t[4] = sum2 as u64;
+
+        for j in 0..4 {
+            t[j] = t[j + 1];
+        }
+        t[4] = 0;
+    }
+    let mut out = [0u64; 4];
+    out.copy_from_slice(&t[0..4]);
+    if limbs_geq(&out, &MODULUS) {
+        out = limbs_sub(&out, &MODULUS);
+    }
+    out
+}
+
+/// An element of the Bn254 base field `F_q`, stored internally in Montgomery form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fq(pub(crate) [u64; 4]);
+
+impl Fq {
+    pub const ZERO: Fq = Fq([0; 4]);
+    pub const ONE: Fq = Fq(R);
+
+    /// Lifts a small integer into Montgomery form.
+    #[must_use]
+    pub fn from_u64(value: u64) -> Fq {
+        Fq(mont_mul(&[value, 0, 0, 0], &R2))
+    }
+
+    /// Interprets 8 little-endian `u32` limbs (two per 64-bit limb) as an integer reduced `mod q`
+    /// and lifts it into Montgomery form.
+    #[must_use]
+    pub fn from_words(words: &[u32; 8]) -> Self {
+        let mut raw = [0u64; 4];
+        for i in 0..4 {
+            raw[i] = u64::from(words[2 * i]) | (u64::from(words[2 * i + 1]) << 32);
+        }
+        // A 256-bit input can be several multiples of `q`, so this one subtraction doesn't fully
+        // reduce it; `mont_mul` still returns a value below `q`, since for an input below 2^256
+        // times `R2 < q` its pre-subtraction output is below `2q`.
+        if limbs_geq(&raw, &MODULUS) {
+            raw = limbs_sub(&raw, &MODULUS);
+        }
+        Fq(mont_mul(&raw, &R2))
+    }
+
+    /// Converts back out of Montgomery form into 8 little-endian `u32` limbs.
+    #[must_use]
+    pub fn to_words(self) -> [u32; 8] {
+        let raw = mont_mul(&self.0, &[1, 0, 0, 0]);
+        let mut words = [0u32; 8];
+        for i in 0..4 {
+            words[2 * i] = raw[i] as u32;
+            words[2 * i + 1] = (raw[i] >> 32) as u32;
+        }
+        words
+    }
+
+    #[must_use]
+    pub fn add(self, rhs: Fq) -> Fq {
+        let mut out = [0u64; 4];
+        let mut carry: u128 = 0;
+        for i in 0..4 {
+            let sum = u128::from(self.0[i]) + u128::from(rhs.0[i]) + carry;
+            out[i] = sum as u64;
+            carry = sum >> 64;
+        }
+        if carry != 0 || limbs_geq(&out, &MODULUS) {
+            out = limbs_sub(&out, &MODULUS);
+        }
+        Fq(out)
+    }
+
+    #[must_use]
+    pub fn sub(self, rhs: Fq) -> Fq {
+        if limbs_geq(&self.0, &rhs.0) {
+            Fq(limbs_sub(&self.0, &rhs.0))
+        } else {
+            // self - rhs + q, computed as q - (rhs - self) so no step underflows.
+            Fq(limbs_sub(&MODULUS, &limbs_sub(&rhs.0, &self.0)))
+        }
+    }
+
+    #[must_use]
+    pub fn neg(self) -> Fq {
+        if self == Fq::ZERO {
+            self
+        } else {
+            Fq(limbs_sub(&MODULUS, &self.0))
+        }
+    }
+
+    #[must_use]
+    pub fn mul(self, rhs: Fq) -> Fq {
+        Fq(mont_mul(&self.0, &rhs.0))
+    }
+
+    #[must_use]
+    pub fn square(self) -> Fq {
+        self.mul(self)
+    }
+
+    #[must_use]
+    pub fn is_zero(self) -> bool {
+        self == Fq::ZERO
+    }
+
+    /// `self^-1`, computed via Fermat's little theorem (`self^(q-2)`). Returns `Fq::ZERO` for
+    /// `self == 0`, matching the convention used by callers that have already rejected the
+    /// malformed/zero case.
+    #[must_use]
+    pub fn invert(self) -> Fq {
+        if self.is_zero() {
+            return Fq::ZERO;
+        }
+        let exp = limbs_sub(&MODULUS, &[2, 0, 0, 0]);
+        let mut result = Fq::ONE;
+        for limb in exp.iter().rev() {
+            for bit in (0..64).rev() {
+                result = result.square();
+                if (limb >> bit) & 1 == 1 {
+                    result = result.mul(self);
+                }
+            }
+        }
+        result
+    }
+
+    /// `self^((q+1)/4)`, which is a square root of `self` whenever `self` is a quadratic residue
+    /// (valid since `q ≡ 3 (mod 4)` for the Bn254 base field). Callers must check the result
+    /// squares back to `self`; this function doesn't determine residuosity on its own.
+    #[must_use]
+    pub fn sqrt_candidate(self) -> Fq {
+        // exponent = (q+1)/4, computed from the modulus at call time rather than hard-coded so
+        // it can't drift out of sync with `MODULUS`.
+        let mut exp = MODULUS;
+        let mut carry = 1u128;
+        for limb in &mut exp {
+            let sum = u128::from(*limb) + carry;
+            *limb = sum as u64;
+            carry = sum >> 64;
+        }
+        let mut shifted = [0u64; 4];
+        for i in 0..4 {
+            let lo = exp[i] >> 2;
+            let hi = if i + 1 < 4 { exp[i + 1] << 62 } else { 0 };
+            shifted[i] = lo | hi;
+        }
+        let mut result = Fq::ONE;
+        for limb in shifted.iter().rev() {
+            for bit in (0..64).rev() {
+                result = result.square();
+                if (limb >> bit) & 1 == 1 {
+                    result = result.mul(self);
+                }
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,68 @@
+//! `F_q12 = F_q6[w] / (w^2 - v)`, the pairing's target field. Every Bn254 G2 point is lifted into
+//! this field via the sextic twist before the Miller loop runs, so the whole pairing can be
+//! computed with a single field's worth of arithmetic instead of juggling the twisted curve
+//! separately.
+
+use super::fq6::Fq6;
+
+/// An element `c0 + c1*w` of `F_q12`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fq12 {
+    pub c0: Fq6,
+    pub c1: Fq6,
+}
+
+impl Fq12 {
+    pub const ZERO: Fq12 = Fq12 { c0: Fq6::ZERO, c1: Fq6::ZERO };
+    pub const ONE: Fq12 = Fq12 { c0: Fq6::ONE, c1: Fq6::ZERO };
+
+    #[must_use]
+    pub fn add(self, rhs: Fq12) -> Fq12 {
+        Fq12 { c0: self.c0.add(rhs.c0), c1: self.c1.add(rhs.c1) }
+    }
+
+    #[must_use]
+    pub fn sub(self, rhs: Fq12) -> Fq12 {
+        Fq12 { c0: self.c0.sub(rhs.c0), c1: self.c1.sub(rhs.c1) }
+    }
+
+    #[must_use]
+    pub fn mul(self, rhs: Fq12) -> Fq12 {
+        let t0 = self.c0.mul(rhs.c0);
+        let t1 = self.c1.mul(rhs.c1);
+        let c0 = t0.add(t1.mul_by_nonresidue());
+        let c1 = (self.c0.add(self.c1)).mul(rhs.c0.add(rhs.c1)).sub(t0).sub(t1);
+        Fq12 { c0, c1 }
+    }
+
+    #[must_use]
+    pub fn square(self) -> Fq12 {
+        self.mul(self)
+    }
+
+    #[must_use]
+    pub fn is_one(self) -> bool {
+        self == Fq12::ONE
+    }
+
+    #[must_use]
+    pub fn invert(self) -> Fq12 {
+        // (a0+a1 w)^-1 = (a0-a1 w) / (a0^2 - a1^2 v), since w^2 = v.
+        let norm = self.c0.square().sub(self.c1.square().mul_by_nonresidue());
+        let norm_inv = norm.invert();
+        Fq12 { c0: self.c0.mul(norm_inv), c1: self.c1.neg().mul(norm_inv) }
+    }
+
+    /// `self^exponent`, `exponent` given as bits, most-significant first.
+    #[must_use]
+    pub fn pow_be_bits(self, exponent_bits: &[bool]) -> Fq12 {
+        let mut result = Fq12::ONE;
+        for &bit in exponent_bits {
+            result = result.square();
+            if bit {
+                result = result.mul(self);
+            }
+        }
+        result
+    }
+}
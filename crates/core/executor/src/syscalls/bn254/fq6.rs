@@ -0,0 +1,74 @@
+//! `F_q6 = F_q2[v] / (v^3 - (9 + u))`, the sextic extension the pairing's target group is built
+//! over via [`super::fq12::Fq12`].
+
+use super::fq2::Fq2;
+
+/// An element `c0 + c1*v + c2*v^2` of `F_q6`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fq6 {
+    pub c0: Fq2,
+    pub c1: Fq2,
+    pub c2: Fq2,
+}
+
+impl Fq6 {
+    pub const ZERO: Fq6 = Fq6 { c0: Fq2::ZERO, c1: Fq2::ZERO, c2: Fq2::ZERO };
+    pub const ONE: Fq6 = Fq6 { c0: Fq2::ONE, c1: Fq2::ZERO, c2: Fq2::ZERO };
+
+    #[must_use]
+    pub fn add(self, rhs: Fq6) -> Fq6 {
+        Fq6 { c0: self.c0.add(rhs.c0), c1: self.c1.add(rhs.c1), c2: self.c2.add(rhs.c2) }
+    }
+
+    #[must_use]
+    pub fn sub(self, rhs: Fq6) -> Fq6 {
+        Fq6 { c0: self.c0.sub(rhs.c0), c1: self.c1.sub(rhs.c1), c2: self.c2.sub(rhs.c2) }
+    }
+
+    #[must_use]
+    pub fn neg(self) -> Fq6 {
+        Fq6 { c0: self.c0.neg(), c1: self.c1.neg(), c2: self.c2.neg() }
+    }
+
+    /// Karatsuba-style multiplication reducing modulo `v^3 = 9 + u`.
+    #[must_use]
+    pub fn mul(self, rhs: Fq6) -> Fq6 {
+        let t0 = self.c0.mul(rhs.c0);
+        let t1 = self.c1.mul(rhs.c1);
+        let t2 = self.c2.mul(rhs.c2);
+
+        let c0 = t0.add((self.c1.add(self.c2)).mul(rhs.c1.add(rhs.c2)).sub(t1).sub(t2).mul_by_nonresidue());
+        let c1 = (self.c0.add(self.c1)).mul(rhs.c0.add(rhs.c1)).sub(t0).sub(t1).add(t2.mul_by_nonresidue());
+        let c2 = (self.c0.add(self.c2)).mul(rhs.c0.add(rhs.c2)).sub(t0).sub(t2).add(t1);
+
+        Fq6 { c0, c1, c2 }
+    }
+
+    #[must_use]
+    pub fn square(self) -> Fq6 {
+        self.mul(self)
+    }
+
+    /// Multiplies by `v`, the `F_q6` non-residue used to build `F_q12 = F_q6[w] / (w^2 - v)`.
+    #[must_use]
+    pub fn mul_by_nonresidue(self) -> Fq6 {
+        // (c0 + c1 v + c2 v^2) * v = c2*xi + c0*v + c1*v^2, since v^3 = xi.
+        Fq6 { c0: self.c2.mul_by_nonresidue(), c1: self.c0, c2: self.c1 }
+    }
+
+    #[must_use]
+    pub fn is_zero(self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero() && self.c2.is_zero()
+    }
+
+    #[must_use]
+    pub fn invert(self) -> Fq6 {
+        // Standard cubic-extension inversion: compute the norm down to F_q2 and adjugate.
+        let c0 = self.c0.square().sub(self.c1.mul(self.c2).mul_by_nonresidue());
+        let c1 = self.c2.square().mul_by_nonresidue().sub(self.c0.mul(self.c1));
+        let c2 = self.c1.square().sub(self.c0.mul(self.c2));
+        let t = (self.c2.mul(c1).add(self.c1.mul(c2))).mul_by_nonresidue().add(self.c0.mul(c0));
+        let t_inv = t.invert();
+        Fq6 { c0: c0.mul(t_inv), c1: c1.mul(t_inv), c2: c2.mul(t_inv) }
+    }
+}
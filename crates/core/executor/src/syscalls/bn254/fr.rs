@@ -0,0 +1,169 @@
+//! Montgomery-form arithmetic for the Bn254 (alt_bn128) scalar field `F_r`, `r` a 254-bit prime,
+//! represented as four 64-bit limbs in little-endian order -- the same layout [`super::fq::Fq`]
+//! uses for the base field, just with `r` in place of `q`. Backs [`super::Bn254ScalarMacSyscall`]
+//! and [`super::Bn254FrOpSyscall`].
+
+/// The Bn254 scalar field modulus, little-endian 64-bit limbs.
+pub const MODULUS: [u64; 4] = [
+    0x43e1_f593_f000_0001,
+    0x2833_e848_79b9_7091,
+    0xb850_45b6_8181_585d,
+    0x3064_4e72_e131_a029,
+];
+
+/// `-r^-1 mod 2^64`, the CIOS Montgomery reduction constant.
+const INV: u64 = 0xc2e1_f593_efff_ffff;
+
+/// `R = 2^256 mod r`, i.e. the Montgomery form of `1`.
+const R: [u64; 4] = [
+    0xac96_341c_4fff_fffb,
+    0x36fc_7695_9f60_cd29,
+    0x666e_a36f_7879_462e,
+    0x0e0a_77c1_9a07_df2f,
+];
+
+/// `R^2 = 2^512 mod r`, used to convert an integer into Montgomery form via one extra
+/// multiplication (`a * R^2 * R^-1 = a * R`).
+const R2: [u64; 4] = [
+    0x1bb8_e645_ae21_6da7,
+    0x53fe_3ab1_e35c_59e3,
+    0x8c49_833d_53bb_8085,
+    0x0216_d0b1_7f4e_44a5,
+];
+
+fn limbs_geq(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    for i in (0..4).rev() {
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+fn limbs_sub(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut out = [0u64; 4];
+    let mut borrow: i128 = 0;
+    for i in 0..4 {
+        let diff = i128::from(a[i]) - i128::from(b[i]) - borrow;
+        if diff < 0 {
+            out[i] = (diff + (1i128 << 64)) as u64;
+            borrow = 1;
+        } else {
+            out[i] = diff as u64;
+            borrow = 0;
+        }
+    }
+    out
+}
+
+/// CIOS Montgomery multiplication: `a * b * R^-1 mod r`.
+fn mont_mul(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut t = [0u64; 5];
+    for i in 0..4 {
+        let mut carry: u128 = 0;
+        for j in 0..4 {
+            let prod = u128::from(t[j]) + u128::from(a[j]) * u128::from(b[i]) + carry;
+            t[j] = prod as u64;
+            carry = prod >> 64;
+        }
+        let sum = u128::from(t[4]) + carry;
+        t[4] = sum as u64;
+        let overflow = sum >> 64;
+
+        let m = t[0].wrapping_mul(INV);
+        let mut carry2: u128 = 0;
+        for j in 0..4 {
+            let prod = u128::from(t[j]) + u128::from(m) * u128::from(MODULUS[j]) + carry2;
+            t[j] = prod as u64;
+            carry2 = prod >> 64;
+        }
+        let sum2 = u128::from(t[4]) + carry2 + overflow;
+        t[4] = sum2 as u64;
+
+        for j in 0..4 {
+            t[j] = t[j + 1];
+        }
+        t[4] = 0;
+    }
+    let mut out = [0u64; 4];
+    out.copy_from_slice(&t[0..4]);
+    if limbs_geq(&out, &MODULUS) {
+        out = limbs_sub(&out, &MODULUS);
+    }
+    out
+}
+
+/// An element of the Bn254 scalar field `F_r`, stored internally in Montgomery form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fr(pub(crate) [u64; 4]);
+
+impl Fr {
+    pub const ZERO: Fr = Fr([0; 4]);
+    pub const ONE: Fr = Fr(R);
+
+    /// Interprets 8 little-endian `u32` limbs (two per 64-bit limb) as an integer reduced `mod r`
+    /// and lifts it into Montgomery form.
+    #[must_use]
+    pub fn from_words(words: &[u32; 8]) -> Self {
+        let mut raw = [0u64; 4];
+        for i in 0..4 {
+            raw[i] = u64::from(words[2 * i]) | (u64::from(words[2 * i + 1]) << 32);
+        }
+        // A 256-bit input can be several multiples of `r`, so this one subtraction doesn't fully
+        // reduce it; `mont_mul` still returns a value below `r`, since for an input below 2^256
+        // times `R2 < r` its pre-subtraction output is below `2r`.
+        if limbs_geq(&raw, &MODULUS) {
+            raw = limbs_sub(&raw, &MODULUS);
+        }
+        Fr(mont_mul(&raw, &R2))
+    }
+
+    /// Converts back out of Montgomery form into 8 little-endian `u32` limbs.
+    #[must_use]
+    pub fn to_words(self) -> [u32; 8] {
+        let raw = mont_mul(&self.0, &[1, 0, 0, 0]);
+        let mut words = [0u32; 8];
+        for i in 0..4 {
+            words[2 * i] = raw[i] as u32;
+            words[2 * i + 1] = (raw[i] >> 32) as u32;
+        }
+        words
+    }
+
+    #[must_use]
+    pub fn add(self, rhs: Fr) -> Fr {
+        let mut out = [0u64; 4];
+        let mut carry: u128 = 0;
+        for i in 0..4 {
+            let sum = u128::from(self.0[i]) + u128::from(rhs.0[i]) + carry;
+            out[i] = sum as u64;
+            carry = sum >> 64;
+        }
+        if carry != 0 || limbs_geq(&out, &MODULUS) {
+            out = limbs_sub(&out, &MODULUS);
+        }
+        Fr(out)
+    }
+
+    #[must_use]
+    pub fn sub(self, rhs: Fr) -> Fr {
+        if limbs_geq(&self.0, &rhs.0) {
+            Fr(limbs_sub(&self.0, &rhs.0))
+        } else {
+            // self - rhs + r, computed as r - (rhs - self) so no step underflows.
+            Fr(limbs_sub(&MODULUS, &limbs_sub(&rhs.0, &self.0)))
+        }
+    }
+
+    #[must_use]
+    pub fn mul(self, rhs: Fr) -> Fr {
+        Fr(mont_mul(&self.0, &rhs.0))
+    }
+
+    /// `self + lhs * rhs`, the Fr multiply-accumulate [`super::Bn254ScalarMacSyscall`] exposes as
+    /// a single precompile instead of a separate mul-then-add round trip through guest memory.
+    #[must_use]
+    pub fn mac(self, lhs: Fr, rhs: Fr) -> Fr {
+        self.add(lhs.mul(rhs))
+    }
+}
@@ -0,0 +1,203 @@
+//! Bn254 (alt_bn128) field/curve/pairing precompiles, exposed as [`crate::syscalls::Syscall`]
+//! impls so guest programs can do G1/G2 arithmetic and pairing-product checks (e.g. for
+//! on-chain-style Groth16/zkSNARK verifiers) as a single syscall instead of open-coding
+//! Montgomery field math in guest MIPS.
+//!
+//! Every operation reads its operands out of guest memory at the pointers passed in `arg1`/
+//! `arg2`, does the field/curve/pairing arithmetic host-side in [`fq`]/[`fq2`]/[`fq6`]/[`fq12`]/
+//! [`g1`]/[`g2`]/[`pairing`], and writes the result back -- mirroring how the base ISA's loads and
+//! stores go through [`crate::Executor::mw_cpu`], so the same memory trace covers both. Malformed
+//! input (an off-curve point) is reported back to the guest as an errno-style fault in `a1`, the
+//! same convention [`super::bls12381`] uses.
+
+mod bigint;
+mod fq;
+mod fq12;
+mod fq2;
+mod fq6;
+mod fr;
+mod g1;
+mod g2;
+mod pairing;
+
+use fr::Fr;
+use g1::G1Affine;
+use g2::G2Affine;
+
+use super::{context::SyscallContext, Syscall, SyscallCode};
+
+/// Errno-style fault: an input point doesn't satisfy its curve equation.
+const BN254_INVALID_POINT: u32 = 1;
+/// Errno-style fault: `num_pairs` exceeds [`MAX_PAIRS`].
+const BN254_TOO_MANY_PAIRS: u32 = 2;
+
+/// Upper bound on `num_pairs` for [`Bn254PairingSyscall`]; see
+/// [`super::bls12381::MAX_PAIRS`] for why this is a reject rather than a clamp.
+pub const MAX_PAIRS: u32 = 64;
+
+fn read_words<const N: usize>(ctx: &mut SyscallContext, addr: u32) -> [u32; N] {
+    let mut words = [0u32; N];
+    for (i, word) in words.iter_mut().enumerate() {
+        *word = ctx.rt.word(addr + (i as u32) * 4);
+    }
+    words
+}
+
+fn write_words<const N: usize>(ctx: &mut SyscallContext, addr: u32, words: &[u32; N]) {
+    for (i, word) in words.iter().enumerate() {
+        ctx.rt.mw_cpu(addr + (i as u32) * 4, *word, crate::events::MemoryAccessPosition::A);
+    }
+}
+
+pub(crate) struct Bn254AddSyscall;
+impl Syscall for Bn254AddSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        p_ptr: u32,
+        q_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let p = G1Affine::from_words(&read_words(ctx, p_ptr));
+        let q = G1Affine::from_words(&read_words(ctx, q_ptr));
+        if !p.is_on_curve() || !q.is_on_curve() {
+            return Some((0xffff_ffff, BN254_INVALID_POINT));
+        }
+        write_words(ctx, p_ptr, &p.add(q).to_words());
+        Some((0, 0))
+    }
+}
+
+pub(crate) struct Bn254DoubleSyscall;
+impl Syscall for Bn254DoubleSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        p_ptr: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        let p = G1Affine::from_words(&read_words(ctx, p_ptr));
+        if !p.is_on_curve() {
+            return Some((0xffff_ffff, BN254_INVALID_POINT));
+        }
+        write_words(ctx, p_ptr, &p.double().to_words());
+        Some((0, 0))
+    }
+}
+
+pub(crate) struct Bn254ScalarMulSyscall;
+impl Syscall for Bn254ScalarMulSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        p_ptr: u32,
+        scalar_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let p = G1Affine::from_words(&read_words(ctx, p_ptr));
+        if !p.is_on_curve() {
+            return Some((0xffff_ffff, BN254_INVALID_POINT));
+        }
+        let scalar = read_words(ctx, scalar_ptr);
+        write_words(ctx, p_ptr, &p.scalar_mul(&scalar).to_words());
+        Some((0, 0))
+    }
+}
+
+/// `general_field_op` selector values for [`Bn254FrOpSyscall`], packed as the third word at
+/// `descriptor_ptr` (see its doc comment).
+pub const BN254_FR_OP_ADD: u32 = 0;
+pub const BN254_FR_OP_SUB: u32 = 1;
+pub const BN254_FR_OP_MUL: u32 = 2;
+
+/// `bn254_scalar_mac(descriptor_ptr, _)`: `descriptor_ptr` points to three words `[a_ptr, b_ptr,
+/// c_ptr]`, the same "extra operands packed next to their pointers" convention
+/// [`super::mem_copy::MemCopySyscall`] uses since `Syscall::execute` only carries two operands.
+/// Loads `a`, `b`, `c` as 256-bit little-endian `F_r` elements (8 `u32` words each), computes
+/// `a <- a + b*c mod r`, and writes the reduced result back to `a`'s memory region.
+pub(crate) struct Bn254ScalarMacSyscall;
+impl Syscall for Bn254ScalarMacSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        descriptor_ptr: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        let a_ptr = ctx.rt.word(descriptor_ptr);
+        let b_ptr = ctx.rt.word(descriptor_ptr + 4);
+        let c_ptr = ctx.rt.word(descriptor_ptr + 8);
+
+        let a = Fr::from_words(&read_words(ctx, a_ptr));
+        let b = Fr::from_words(&read_words(ctx, b_ptr));
+        let c = Fr::from_words(&read_words(ctx, c_ptr));
+
+        write_words(ctx, a_ptr, &a.mac(b, c).to_words());
+        Some((0, 0))
+    }
+}
+
+/// `bn254_fr_op(descriptor_ptr, _)`: `descriptor_ptr` points to three words `[a_ptr, b_ptr,
+/// general_field_op]`, where `general_field_op` is one of [`BN254_FR_OP_ADD`]/
+/// [`BN254_FR_OP_SUB`]/[`BN254_FR_OP_MUL`]. Loads `a`, `b` as 256-bit little-endian `F_r`
+/// elements, computes `a <- a OP b mod r`, and writes the reduced result back to `a`'s memory
+/// region -- the plain-arithmetic complement to [`Bn254ScalarMacSyscall`] so every `F_r` op a
+/// guest needs shares one syscall family.
+pub(crate) struct Bn254FrOpSyscall;
+impl Syscall for Bn254FrOpSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        descriptor_ptr: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        let a_ptr = ctx.rt.word(descriptor_ptr);
+        let b_ptr = ctx.rt.word(descriptor_ptr + 4);
+        let general_field_op = ctx.rt.word(descriptor_ptr + 8);
+
+        let a = Fr::from_words(&read_words(ctx, a_ptr));
+        let b = Fr::from_words(&read_words(ctx, b_ptr));
+
+        let result = match general_field_op {
+            BN254_FR_OP_ADD => a.add(b),
+            BN254_FR_OP_SUB => a.sub(b),
+            _ => a.mul(b),
+        };
+
+        write_words(ctx, a_ptr, &result.to_words());
+        Some((0, 0))
+    }
+}
+
+/// Checks `prod_i e(g1_i, g2_i) == 1` over `num_pairs` pairs read from `pairs_ptr` (each pair a
+/// 16-word G1 point immediately followed by a 32-word G2 point), returning the boolean result
+/// (`1` or `0`) as the syscall's return value; guest memory is not written.
+pub(crate) struct Bn254PairingSyscall;
+impl Syscall for Bn254PairingSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        pairs_ptr: u32,
+        num_pairs: u32,
+    ) -> Option<(u32, u32)> {
+        if num_pairs > MAX_PAIRS {
+            return Some((0, BN254_TOO_MANY_PAIRS));
+        }
+
+        const PAIR_WORDS: u32 = 16 + 32;
+        let mut pairs = Vec::with_capacity(num_pairs as usize);
+        for i in 0..num_pairs {
+            let base = pairs_ptr + i * PAIR_WORDS * 4;
+            let g1 = G1Affine::from_words(&read_words(ctx, base));
+            let g2 = G2Affine::from_words(&read_words(ctx, base + 16 * 4));
+            if !g1.is_on_curve() || !g2.is_on_curve() {
+                return Some((0, BN254_INVALID_POINT));
+            }
+            pairs.push((g1, g2));
+        }
+        Some((u32::from(pairing::pairing_check(&pairs)), 0))
+    }
+}
@@ -0,0 +1,149 @@
+//! The Bn254 Tate pairing: Miller loop over the group order `r` + final exponentiation, and the
+//! aggregate pairing-product check used by on-chain Groth16/zkSNARK verifiers.
+//!
+//! Unlike the optimal ate pairing used for production bn254 provers (loop length `6x+2` plus a
+//! Frobenius correction, specific to this curve's embedding), this loops the full group order `r`
+//! -- slower, but it only relies on `r*P = O` holding in both groups, so it needs no
+//! curve-specific loop-shortening constants to get right. G2 points are lifted into `F_q12` via
+//! the sextic twist (`Ψ(x, y) = (x/w^2, y/w^3)`, derived here straight from the `F_q12` tower
+//! generator rather than hard-coded, so it's guaranteed consistent with
+//! [`super::g2::G2Affine`]'s twist), and the whole Miller loop then runs as plain, un-sparse
+//! `F_q12` arithmetic -- simpler to get right than the usual sparse-multiplication optimization,
+//! at the cost of speed that doesn't matter on this interpreter-only path.
+
+use super::bigint::BigUint;
+use super::fq::{self, Fq};
+use super::fq12::Fq12;
+use super::fq2::Fq2;
+use super::fq6::Fq6;
+use super::g1::G1Affine;
+use super::g2::G2Affine;
+
+/// The Bn254 scalar field order `r`, little-endian 64-bit limbs.
+pub(crate) const FR_MODULUS: [u64; 4] = [
+    0x43e1_f593_f000_0001,
+    0x2833_e848_79b9_7091,
+    0xb850_45b6_8181_585d,
+    0x3064_4e72_e131_a029,
+];
+
+/// `FR_MODULUS`, as the 8 little-endian `u32` words callers pass to [`G1Affine::scalar_mul`]/
+/// [`G2Affine::scalar_mul`] for a (slow but always-correct) subgroup check: `P` is in the
+/// prime-order subgroup iff `r*P` is the point at infinity.
+pub(crate) const FR_MODULUS_WORDS: [u32; 8] = [
+    0xf000_0001,
+    0x43e1_f593,
+    0x79b9_7091,
+    0x2833_e848,
+    0x8181_585d,
+    0xb850_45b6,
+    0xe131_a029,
+    0x3064_4e72,
+];
+
+fn fq_to_fq12(x: Fq) -> Fq12 {
+    Fq12 { c0: Fq6 { c0: Fq2 { c0: x, c1: Fq::ZERO }, c1: Fq2::ZERO, c2: Fq2::ZERO }, c1: Fq6::ZERO }
+}
+
+fn fq2_to_fq12(x: Fq2) -> Fq12 {
+    Fq12 { c0: Fq6 { c0: x, c1: Fq2::ZERO, c2: Fq2::ZERO }, c1: Fq6::ZERO }
+}
+
+/// The twist factors `w^-2` and `w^-3`, derived from the `F_q12` generator `w` itself so they
+/// can't drift out of sync with the tower's defining relations (`w^2 = v`, `v^3 = 9+u`).
+fn twist_factors() -> (Fq12, Fq12) {
+    let w = Fq12 { c0: Fq6::ZERO, c1: Fq6::ONE };
+    let w2 = w.square();
+    let w3 = w2.mul(w);
+    (w2.invert(), w3.invert())
+}
+
+fn embed_g1(p: G1Affine) -> (Fq12, Fq12) {
+    (fq_to_fq12(p.x), fq_to_fq12(p.y))
+}
+
+fn embed_g2(q: G2Affine) -> (Fq12, Fq12) {
+    let (twist_x, twist_y) = twist_factors();
+    (fq2_to_fq12(q.x).mul(twist_x), fq2_to_fq12(q.y).mul(twist_y))
+}
+
+/// One tangent-line doubling step of Miller's algorithm, evaluated at `p` against the point `t`
+/// being doubled (both already embedded in `F_q12`). Returns the line's contribution to `f` and
+/// the doubled point.
+fn double_step(t: (Fq12, Fq12), p: (Fq12, Fq12)) -> (Fq12, (Fq12, Fq12)) {
+    let (tx, ty) = t;
+    let (px, py) = p;
+    let lambda = tx.square().add(tx.square()).add(tx.square()).mul(ty.add(ty).invert());
+    let line = py.sub(ty).sub(lambda.mul(px.sub(tx)));
+    let t2x = lambda.square().sub(tx).sub(tx);
+    let t2y = lambda.mul(tx.sub(t2x)).sub(ty);
+    let vertical = px.sub(t2x);
+    (line.mul(vertical.invert()), (t2x, t2y))
+}
+
+/// One chord addition step of Miller's algorithm, adding the fixed point `q` into the running
+/// point `t`, evaluated at `p` (all embedded in `F_q12`).
+fn add_step(t: (Fq12, Fq12), q: (Fq12, Fq12), p: (Fq12, Fq12)) -> (Fq12, (Fq12, Fq12)) {
+    let (tx, ty) = t;
+    let (qx, qy) = q;
+    let (px, py) = p;
+    let lambda = qy.sub(ty).mul(qx.sub(tx).invert());
+    let line = py.sub(ty).sub(lambda.mul(px.sub(tx)));
+    let tnewx = lambda.square().sub(tx).sub(qx);
+    let tnewy = lambda.mul(tx.sub(tnewx)).sub(ty);
+    let vertical = px.sub(tnewx);
+    (line.mul(vertical.invert()), (tnewx, tnewy))
+}
+
+fn bits_msb(x: &[u64; 4]) -> Vec<bool> {
+    let top = (0..4).rev().find(|&i| x[i] != 0).unwrap_or(0);
+    let len = top * 64 + (64 - x[top].leading_zeros() as usize);
+    (0..len).rev().map(|i| (x[i / 64] >> (i % 64)) & 1 == 1).collect()
+}
+
+/// The Bn254 Miller loop for `(p, q)`, an `F_q12` element ready for [`final_exponentiation`].
+#[must_use]
+pub fn miller_loop(p: G1Affine, q: G2Affine) -> Fq12 {
+    let p = embed_g1(p);
+    let q = embed_g2(q);
+    let mut t = q;
+    let mut f = Fq12::ONE;
+    // Skip the leading bit: `t` already holds `[1]Q` going into the loop.
+    for bit in bits_msb(&FR_MODULUS).into_iter().skip(1) {
+        let (line, t2) = double_step(t, p);
+        f = f.square().mul(line);
+        t = t2;
+        if bit {
+            let (line, t3) = add_step(t, q, p);
+            f = f.mul(line);
+            t = t3;
+        }
+    }
+    f
+}
+
+fn final_exponent() -> BigUint {
+    let q = BigUint::from_limbs(&fq::MODULUS);
+    let r = BigUint::from_limbs(&FR_MODULUS);
+    q.pow_u32(12).sub_one().div_exact(&r)
+}
+
+/// Raises `f` to `(q^12 - 1) / r`, landing it in the order-`r` target subgroup of `F_q12^*`.
+#[must_use]
+pub fn final_exponentiation(f: Fq12) -> Fq12 {
+    f.pow_be_bits(&final_exponent().bits_be())
+}
+
+/// Checks `prod_i e(p_i, q_i) == 1`, as used by Groth16/zkSNARK pairing checks. Pairs with either
+/// point at infinity contribute `1` and are skipped.
+#[must_use]
+pub fn pairing_check(pairs: &[(G1Affine, G2Affine)]) -> bool {
+    let mut f = Fq12::ONE;
+    for &(p, q) in pairs {
+        if p.infinity || q.infinity {
+            continue;
+        }
+        f = f.mul(miller_loop(p, q));
+    }
+    final_exponentiation(f).is_one()
+}
@@ -0,0 +1,90 @@
+//! `open`/`read`/`stat` syscalls over a [`crate::FileBundle`] attached to the program.
+//!
+//! These mirror how a kernel mounts an initrd: the guest doesn't need every input
+//! pre-parsed by the host through `io::read_vec`, it can look files up by name out of the
+//! bundle that was materialized into `image` by [`crate::Program::with_file_bundle`].
+
+use super::{context::SyscallContext, Syscall, SyscallCode};
+
+/// Errno returned when a name isn't present in the bundle's directory.
+pub const MIPS_ENOENT: u32 = 2;
+
+fn hash_name(name_ptr: u32, name_len: u32, ctx: &mut SyscallContext) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+    for i in 0..name_len {
+        let byte = ctx.rt.byte(name_ptr + i);
+        hash ^= u32::from(byte);
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    hash
+}
+
+/// `open(name_ptr, name_len)`: resolves a name against the bundle's directory (matched by the
+/// same FNV-1a hash `Program::with_file_bundle` stored for each entry) and returns a synthetic
+/// file descriptor that encodes the entry's index, or `-1`/`ENOENT` if no such file exists.
+pub(crate) struct BundleOpenSyscall;
+impl Syscall for BundleOpenSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        name_ptr: u32,
+        name_len: u32,
+    ) -> Option<(u32, u32)> {
+        let target_hash = hash_name(name_ptr, name_len, ctx);
+        let bundle = ctx.rt.program.file_bundle.clone()?;
+        for (idx, entry) in bundle.entries.iter().enumerate() {
+            let mut hash: u32 = 0x811c_9dc5;
+            for byte in entry.name.bytes() {
+                hash ^= u32::from(byte);
+                hash = hash.wrapping_mul(0x0100_0193);
+            }
+            if hash == target_hash {
+                // Synthetic bundle fds start above any real fd range used elsewhere.
+                return Some((0x1000_0000 + idx as u32, 0));
+            }
+        }
+        Some((0xffff_ffff, MIPS_ENOENT))
+    }
+}
+
+/// `stat(fd, out_ptr)`: writes the file's `(offset, len)` as two words at `out_ptr`.
+pub(crate) struct BundleStatSyscall;
+impl Syscall for BundleStatSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        fd: u32,
+        out_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let idx = fd.checked_sub(0x1000_0000)? as usize;
+        let bundle = ctx.rt.program.file_bundle.clone()?;
+        let entry = bundle.entries.get(idx)?;
+        ctx.rt.mw_cpu(out_ptr, entry.offset, crate::events::MemoryAccessPosition::A);
+        ctx.rt.mw_cpu(out_ptr + 4, entry.len, crate::events::MemoryAccessPosition::B);
+        Some((0, 0))
+    }
+}
+
+/// `read(fd, buf_ptr)`: copies the file's contents (already resident in `image`/memory at
+/// `bundle.base_addr + entry.offset`) to `buf_ptr`, returning the number of bytes copied.
+pub(crate) struct BundleReadSyscall;
+impl Syscall for BundleReadSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        fd: u32,
+        buf_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let idx = fd.checked_sub(0x1000_0000)? as usize;
+        let bundle = ctx.rt.program.file_bundle.clone()?;
+        let entry = bundle.entries.get(idx)?.clone();
+        for i in (0..entry.len).step_by(4) {
+            let word = ctx.rt.word(bundle.base_addr + entry.offset + i);
+            ctx.rt.mw_cpu(buf_ptr + i, word, crate::events::MemoryAccessPosition::A);
+        }
+        Some((entry.len, 0))
+    }
+}
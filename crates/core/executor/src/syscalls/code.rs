@@ -17,11 +17,164 @@ pub enum SyscallCode {
     SYSEXITGROUP = 4246,
     SYSREAD = 4003,
     SYSWRITE = 4004,
+    /// `open(name_ptr, flags)`. No generic filesystem is modeled, so this always fails with
+    /// `ENOENT`; see [`SyscallCode::SYSBUNDLEOPEN`] for the one filesystem this executor
+    /// actually backs.
+    SYSOPEN = 4005,
+    /// `close(fd)`. Always succeeds, since every fd this executor hands out (stdio, bundle
+    /// files) is safe to no-op close.
+    SYSCLOSE = 4006,
     SYSFCNTL = 4055,
     SYSSETTHREADAREA = 4283,
     SYSHINTLEN = 0x00_00_00_F0,
     SYSHINTREAD = 0x00_00_00_F1,
     SYSVERIFY = 0x00_00_00_F2,
+    /// `sched_yield()`. This executor runs a single guest thread to completion with no other
+    /// runnable work, so this is a no-op that always succeeds; it exists so guest programs and
+    /// libraries that call it unconditionally don't need a special case.
+    SYSYIELD = 0x00_00_00_F3,
+    /// Query the remaining cycle budget against the host's configured
+    /// `ZKMContext::cycle_limit`, returned in a0 (saturated to `u32::MAX` if unmetered).
+    SYSMETER = 0x00_00_00_F4,
+    /// Open a transactional snapshot frame (see [`crate::snapshot`]), returning its id in a0.
+    SYSSNAPSHOT = 0x00_00_00_F5,
+    /// Roll back to the snapshot frame whose id is in a0, discarding every memory write (and any
+    /// nested snapshot) made since it was opened.
+    SYSROLLBACK = 0x00_00_00_F6,
+    /// `set_return_data(ptr, len)`: store a bounded, length-delimited result buffer separate from
+    /// the `WRITE` stream, mirroring Solana's `sol_set_return_data`.
+    SYSSETRETURNDATA = 0x00_00_00_F7,
+    /// `get_return_data(out_ptr)`: read back the buffer stored by `SYSSETRETURNDATA`, returning
+    /// its length in a0.
+    SYSGETRETURNDATA = 0x00_00_00_F8,
+    /// `memcopy(descriptor_ptr)`: bulk-copy the `[src_ptr, dst_ptr, len]` words described at
+    /// `descriptor_ptr`, one event instead of one CPU row per word.
+    SYSMEMCOPY = 0x00_00_00_F9,
+    /// `memset(descriptor_ptr, fill_value)`: bulk-fill the `[dst_ptr, len]` words described at
+    /// `descriptor_ptr` with `fill_value`.
+    SYSMEMSET = 0x00_00_00_FA,
+    /// `memcpy32(src_ptr, dst_ptr)`: fixed 32-word bulk copy, one precompile row instead of a
+    /// per-word loop; see [`crate::syscalls::mem_copy::MemCopyFixedSyscall`].
+    MEMCPY_32 = 0x00_00_00_FB,
+    /// `memcpy64(src_ptr, dst_ptr)`: fixed 64-word bulk copy, see [`SyscallCode::MEMCPY_32`].
+    MEMCPY_64 = 0x00_00_00_FC,
+    /// Query the number of cycles the executor has committed so far (`a0` gets the low word,
+    /// `a1` the high word of a 64-bit count), so a guest can timestamp a region of its own
+    /// execution without the host having to scrape `println!` markers out of stdout. Unlike
+    /// [`SyscallCode::SYSMETER`], which reports *remaining* budget against a configured limit,
+    /// this reports the absolute cycle count, so two calls can be subtracted to get a region's
+    /// cost regardless of whether metering is enabled.
+    SYSCYCLECOUNT = 0x00_00_00_FD,
+    /// `cycle_tracker_end(descriptor_ptr)`: `descriptor_ptr` points to `[name_ptr, name_len,
+    /// start_cycle_lo, start_cycle_hi]` (the same packed-descriptor convention
+    /// [`super::mem_copy::MemCopySyscall`] uses). Accumulates `cycles_used() - start_cycle` into
+    /// the named entry of [`crate::Executor::cycle_tracker`], so a guest-side `cycle_span` guard
+    /// (built on [`SyscallCode::SYSCYCLECOUNT`]) can report per-region cost without the host
+    /// parsing `println!("cycle-tracker-start/end: ...")` lines out of stdout.
+    SYSCYCLETRACKEREND = 0x00_00_00_FE,
+
+    /// Poseidon2 permutation over an 8-element native-field state, see
+    /// [`crate::syscalls::poseidon2`].
+    POSEIDON2_PERMUTE_8 = 0x00_01_09_00,
+    /// Poseidon2 permutation over a 16-element native-field state, see
+    /// [`crate::syscalls::poseidon2`].
+    POSEIDON2_PERMUTE_16 = 0x00_01_09_01,
+
+    /// BLS12-381 G1 point addition.
+    BLS12381_G1_ADD = 0x00_01_03_00,
+    /// BLS12-381 G1 point doubling.
+    BLS12381_G1_DOUBLE = 0x00_01_03_01,
+    /// BLS12-381 G1 scalar multiplication.
+    BLS12381_G1_SCALAR_MUL = 0x00_01_03_02,
+    /// BLS12-381 G1 subgroup check.
+    BLS12381_G1_SUBGROUP_CHECK = 0x00_01_03_03,
+    /// BLS12-381 G1 map-to-curve (SSWU + isogeny).
+    BLS12381_G1_MAP = 0x00_01_03_04,
+    /// BLS12-381 G2 point addition.
+    BLS12381_G2_ADD = 0x00_01_03_05,
+    /// BLS12-381 G2 point doubling.
+    BLS12381_G2_DOUBLE = 0x00_01_03_06,
+    /// BLS12-381 G2 scalar multiplication.
+    BLS12381_G2_SCALAR_MUL = 0x00_01_03_07,
+    /// BLS12-381 G2 subgroup check.
+    BLS12381_G2_SUBGROUP_CHECK = 0x00_01_03_08,
+    /// BLS12-381 G2 map-to-curve (SSWU + isogeny).
+    BLS12381_G2_MAP = 0x00_01_03_09,
+    /// BLS12-381 G2 point decompression.
+    BLS12381_G2_DECOMPRESS = 0x00_01_03_0A,
+    /// BLS12-381 Miller loop.
+    BLS12381_MILLER_LOOP = 0x00_01_03_0B,
+    /// BLS12-381 final exponentiation.
+    BLS12381_FINAL_EXP = 0x00_01_03_0C,
+    /// BLS12-381 full pairing check (Miller loop + final exponentiation, compared to identity).
+    BLS12381_PAIRING_CHECK = 0x00_01_03_0D,
+
+    /// Open a file by name within the program's attached read-only file bundle.
+    SYSBUNDLEOPEN = 0x00_01_04_00,
+    /// Read a file's contents from the program's attached read-only file bundle.
+    SYSBUNDLEREAD = 0x00_01_04_01,
+    /// Stat a file within the program's attached read-only file bundle.
+    SYSBUNDLESTAT = 0x00_01_04_02,
+
+    /// secp256k1 ECDSA public-key recovery.
+    SECP256K1_RECOVER = 0x00_01_05_00,
+
+    /// Bn254 G1 point addition.
+    BN254_ADD = 0x00_01_06_00,
+    /// Bn254 G1 point doubling.
+    BN254_DOUBLE = 0x00_01_06_01,
+    /// Bn254 G1 scalar multiplication.
+    BN254_SCALAR_MUL = 0x00_01_06_02,
+    /// Bn254 pairing-product check.
+    BN254_PAIRING_CHECK = 0x00_01_06_03,
+    /// Bn254 scalar-field (`F_r`) multiply-accumulate: `a <- a + b*c mod r`. Named `_MAC` rather
+    /// than reusing the `BN254_SCALAR_MUL` name above, which already denotes G1 point-by-scalar
+    /// multiplication.
+    BN254_SCALAR_MAC = 0x00_01_06_04,
+    /// General Bn254 scalar-field (`F_r`) arithmetic: `a <- a OP b mod r`, `OP` selected by a
+    /// `general_field_op` operand (see [`crate::syscalls::bn254::BN254_FR_OP_ADD`] and friends).
+    BN254_FR_OP = 0x00_01_06_05,
+
+    /// Blake3 compression function: one 7-round mix of a 16-word message block against an 8-word
+    /// chaining value, counter, block length and flags, mirroring `SHA_COMPRESS`/`KECCAK_PERMUTE`.
+    BLAKE3_COMPRESS = 0x00_01_07_00,
+
+    /// Runs the keccak-f[1600] permutation over a resident 25-lane (50-word) state in place, the
+    /// hot inner loop of Keccak-256/SHA3/SHAKE -- see `crate::syscalls::keccak`. Matches the
+    /// guest-side `syscall_keccak_permute` intrinsic's id, already defined in
+    /// `zkm2_zkvm::syscalls::KECCAK_PERMUTE`.
+    KECCAK_PERMUTE = 0x00_01_01_09,
+
+    /// Runs the Keccak-f[1600] permutation over `N` independent `[u64; 25]` states laid out
+    /// lane-interleaved (each of the 25 lanes holds `N` consecutive 64-bit words), so one
+    /// syscall amortizes the round function across `N` otherwise-independent sponges.
+    KECCAK_PERMUTE_BATCH = 0x00_01_07_01,
+
+    /// Deterministic IEEE-754 single/double precision add/sub/mul/div/sqrt and int<->float
+    /// conversion, with a selectable rounding mode and a sticky invalid/overflow/inexact flags
+    /// word -- see `crate::syscalls::fp_op`.
+    FP_OP = 0x00_01_08_00,
+
+    /// Verifies a claimed Merkle inclusion path for a leaf at a given index against a claimed
+    /// root, hashing level-by-level with the native Poseidon2/KoalaBear permutation. Sibling
+    /// nodes are supplied as non-deterministic advice via the hint stream rather than as a plain
+    /// memory buffer -- see `crate::syscalls::mtree`.
+    MTREE_VERIFY_PATH = 0x00_01_09_00,
+    /// Merges two subtree roots into their parent root with the same compression function
+    /// `MTREE_VERIFY_PATH` uses per level.
+    MTREE_MERGE = 0x00_01_09_01,
+
+    /// Absorbs a pre-padded, rate-packed Keccak sponge input and squeezes an arbitrary-length
+    /// output, re-permuting once per rate block as needed -- the shared precompile underlying
+    /// Keccak-256/SHA3/SHAKE/cSHAKE's guest-side `sponge()` driver (`zkm2_zkvm::sponge`). Matches
+    /// the guest-side `syscall_keccak_sponge` intrinsic's id in `zkm2_zkvm::syscalls::KECCAK_SPONGE`.
+    /// See `crate::syscalls::keccak_sponge::KeccakSpongeSyscall` for the host `Syscall` impl and
+    /// `crate::syscall::precompiles::keccak_sponge` in the machine crate for the chip reading the
+    /// resulting `KeccakSpongeEvent`s.
+    KECCAK_SPONGE = 0x00_01_0A_00,
+    // `0x00_02_00_00..=0x00_02_FF_FF` is reserved for embedders: add variants in that range for
+    // custom host calls installed via [`crate::Executor::register_syscall`] rather than reusing
+    // a number from one of the ranges above.
 }
 
 impl SyscallCode {
@@ -37,10 +190,57 @@ impl SyscallCode {
             4246 => SyscallCode::SYSEXITGROUP,
             4003 => SyscallCode::SYSREAD,
             4004 => SyscallCode::SYSWRITE,
+            4005 => SyscallCode::SYSOPEN,
+            4006 => SyscallCode::SYSCLOSE,
             4283 => SyscallCode::SYSFCNTL,
             0x00_00_00_F0 => SyscallCode::SYSHINTLEN,
             0x00_00_00_F1 => SyscallCode::SYSHINTREAD,
             0x00_00_00_F2 => SyscallCode::SYSVERIFY,
+            0x00_00_00_F3 => SyscallCode::SYSYIELD,
+            0x00_00_00_F4 => SyscallCode::SYSMETER,
+            0x00_00_00_F5 => SyscallCode::SYSSNAPSHOT,
+            0x00_00_00_F6 => SyscallCode::SYSROLLBACK,
+            0x00_00_00_F7 => SyscallCode::SYSSETRETURNDATA,
+            0x00_00_00_F8 => SyscallCode::SYSGETRETURNDATA,
+            0x00_00_00_F9 => SyscallCode::SYSMEMCOPY,
+            0x00_00_00_FA => SyscallCode::SYSMEMSET,
+            0x00_00_00_FB => SyscallCode::MEMCPY_32,
+            0x00_00_00_FC => SyscallCode::MEMCPY_64,
+            0x00_00_00_FD => SyscallCode::SYSCYCLECOUNT,
+            0x00_00_00_FE => SyscallCode::SYSCYCLETRACKEREND,
+            0x00_01_09_00 => SyscallCode::POSEIDON2_PERMUTE_8,
+            0x00_01_09_01 => SyscallCode::POSEIDON2_PERMUTE_16,
+            0x00_01_03_00 => SyscallCode::BLS12381_G1_ADD,
+            0x00_01_03_01 => SyscallCode::BLS12381_G1_DOUBLE,
+            0x00_01_03_02 => SyscallCode::BLS12381_G1_SCALAR_MUL,
+            0x00_01_03_03 => SyscallCode::BLS12381_G1_SUBGROUP_CHECK,
+            0x00_01_03_04 => SyscallCode::BLS12381_G1_MAP,
+            0x00_01_03_05 => SyscallCode::BLS12381_G2_ADD,
+            0x00_01_03_06 => SyscallCode::BLS12381_G2_DOUBLE,
+            0x00_01_03_07 => SyscallCode::BLS12381_G2_SCALAR_MUL,
+            0x00_01_03_08 => SyscallCode::BLS12381_G2_SUBGROUP_CHECK,
+            0x00_01_03_09 => SyscallCode::BLS12381_G2_MAP,
+            0x00_01_03_0A => SyscallCode::BLS12381_G2_DECOMPRESS,
+            0x00_01_03_0B => SyscallCode::BLS12381_MILLER_LOOP,
+            0x00_01_03_0C => SyscallCode::BLS12381_FINAL_EXP,
+            0x00_01_03_0D => SyscallCode::BLS12381_PAIRING_CHECK,
+            0x00_01_04_00 => SyscallCode::SYSBUNDLEOPEN,
+            0x00_01_04_01 => SyscallCode::SYSBUNDLEREAD,
+            0x00_01_04_02 => SyscallCode::SYSBUNDLESTAT,
+            0x00_01_05_00 => SyscallCode::SECP256K1_RECOVER,
+            0x00_01_06_00 => SyscallCode::BN254_ADD,
+            0x00_01_06_01 => SyscallCode::BN254_DOUBLE,
+            0x00_01_06_02 => SyscallCode::BN254_SCALAR_MUL,
+            0x00_01_06_03 => SyscallCode::BN254_PAIRING_CHECK,
+            0x00_01_06_04 => SyscallCode::BN254_SCALAR_MAC,
+            0x00_01_06_05 => SyscallCode::BN254_FR_OP,
+            0x00_01_07_00 => SyscallCode::BLAKE3_COMPRESS,
+            0x00_01_07_01 => SyscallCode::KECCAK_PERMUTE_BATCH,
+            0x00_01_01_09 => SyscallCode::KECCAK_PERMUTE,
+            0x00_01_08_00 => SyscallCode::FP_OP,
+            0x00_01_09_00 => SyscallCode::MTREE_VERIFY_PATH,
+            0x00_01_09_01 => SyscallCode::MTREE_MERGE,
+            0x00_01_0A_00 => SyscallCode::KECCAK_SPONGE,
             _ => panic!("invalid syscall number: {value}"),
         }
     }
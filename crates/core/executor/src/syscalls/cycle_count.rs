@@ -0,0 +1,63 @@
+//! `SYSCYCLECOUNT`/`SYSCYCLETRACKEREND`: let a guest timestamp a region of its own execution and
+//! have the result land in [`crate::Executor::cycle_tracker`], instead of the host having to
+//! scrape `println!("cycle-tracker-start/end: ...")` markers out of stdout.
+
+use super::{context::SyscallContext, Syscall, SyscallCode};
+
+/// Upper bound on `name_len` for [`CycleTrackerEndSyscall`], the same
+/// guest-controlled-length-clamp convention [`super::mem_copy::MAX_MEM_COPY_WORDS`] and
+/// [`super::return_data::MAX_RETURN_DATA_LEN`] use -- a tracker label has no business being
+/// longer than this, and without the clamp a malicious `name_len` could force
+/// `Vec::with_capacity` to attempt a multi-gigabyte allocation and abort the process.
+pub const MAX_CYCLE_TRACKER_NAME_LEN: u32 = 256;
+
+/// `cycle_count() -> u64`: reports [`crate::Executor::cycles_used`] back to the guest (`a0` the
+/// low word, `a1` the high word), so a `cycle_span` guard can record its own start point.
+pub(crate) struct CycleCountSyscall;
+
+impl Syscall for CycleCountSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        _: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        let cycles = ctx.rt.cycles_used();
+        Some((cycles as u32, (cycles >> 32) as u32))
+    }
+}
+
+/// `cycle_tracker_end(descriptor_ptr, _)`: `descriptor_ptr` points to `[name_ptr, name_len,
+/// start_cycle_lo, start_cycle_hi]`. Adds `cycles_used() - start_cycle` to the named entry of
+/// [`crate::Executor::cycle_tracker`] and bumps its call count.
+pub(crate) struct CycleTrackerEndSyscall;
+
+impl Syscall for CycleTrackerEndSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        descriptor_ptr: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        let name_ptr = ctx.rt.word(descriptor_ptr);
+        let name_len = ctx.rt.word(descriptor_ptr + 4).min(MAX_CYCLE_TRACKER_NAME_LEN);
+        let start_lo = ctx.rt.word(descriptor_ptr + 8);
+        let start_hi = ctx.rt.word(descriptor_ptr + 12);
+        let start_cycle = (u64::from(start_hi) << 32) | u64::from(start_lo);
+
+        let mut name_bytes = Vec::with_capacity(name_len as usize);
+        for i in 0..name_len {
+            name_bytes.push(ctx.rt.byte(name_ptr + i));
+        }
+        let name = String::from_utf8_lossy(&name_bytes).into_owned();
+
+        let elapsed = ctx.rt.cycles_used().saturating_sub(start_cycle);
+        let entry = ctx.rt.cycle_tracker.entry(name).or_insert((0, 0));
+        entry.0 += elapsed;
+        entry.1 += 1;
+
+        None
+    }
+}
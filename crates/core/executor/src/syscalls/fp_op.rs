@@ -0,0 +1,351 @@
+//! `FP_OP`, a deterministic IEEE-754 single/double precision FPU precompile, mirroring how
+//! `KECCAK_PERMUTE`/[`super::blake3::Blake3CompressSyscall`] accelerate their own hot loop instead
+//! of forcing guests to pull in a multi-thousand-instruction softfloat library.
+//!
+//! Rust's native `f32`/`f64` arithmetic (and `sqrt`) is already correctly rounded to nearest-even,
+//! so that mode is just the native op. The other three modes (toward zero/+inf/-inf) are derived
+//! from it by computing the *exact* rounding residual with an error-free floating-point
+//! transformation -- TwoSum for add/sub, an FMA-based residual for multiply/divide/sqrt -- and
+//! nudging the nearest-even result by one ULP toward the requested direction when the residual
+//! says the exact value actually lies on the other side of it. This gets bit-exact results for
+//! every rounding mode without a full bignum softfloat core.
+
+use crate::events::FpOpEvent;
+
+use super::{context::SyscallContext, Syscall, SyscallCode};
+
+pub const FP_OP_ADD: u32 = 0;
+pub const FP_OP_SUB: u32 = 1;
+pub const FP_OP_MUL: u32 = 2;
+pub const FP_OP_DIV: u32 = 3;
+pub const FP_OP_SQRT: u32 = 4;
+pub const FP_OP_CVT_INT_TO_FLOAT: u32 = 5;
+pub const FP_OP_CVT_FLOAT_TO_INT: u32 = 6;
+
+pub const FP_OP_DOUBLE: u32 = 1 << 4;
+
+pub const FP_ROUND_NEAREST_EVEN: u32 = 0;
+pub const FP_ROUND_TOWARD_ZERO: u32 = 1;
+pub const FP_ROUND_TOWARD_POSITIVE: u32 = 2;
+pub const FP_ROUND_TOWARD_NEGATIVE: u32 = 3;
+
+pub const FP_FLAG_INVALID: u32 = 1 << 0;
+pub const FP_FLAG_OVERFLOW: u32 = 1 << 1;
+pub const FP_FLAG_INEXACT: u32 = 1 << 2;
+
+/// Adjusts a correctly-rounded (nearest-even) result by at most one ULP to honor a directed
+/// rounding mode, given the sign of the exact rounding residual (`exact - rounded`).
+///
+/// `residual_sign > 0` means the exact value is larger than `rounded`; `< 0` means smaller. A
+/// residual of exactly zero means `rounded` was already exact, so no mode can disagree with it.
+macro_rules! impl_fp_op {
+    ($name:ident, $float:ty, $bits:ty, $qnan:expr) => {
+        /// Runs one `FP_OP_*` operation at this precision, returning the result bit pattern and
+        /// the sticky flags word.
+        fn $name(op: u32, a_bits: $bits, b_bits: $bits, round_mode: u32) -> ($bits, u32) {
+            let nudge = |rounded: $float, residual_sign: i32| -> $float {
+                if residual_sign == 0 {
+                    return rounded;
+                }
+                let negative = rounded.is_sign_negative();
+                let exact_is_farther_from_zero = (residual_sign > 0) != negative;
+                let move_away_from_zero = match round_mode {
+                    FP_ROUND_TOWARD_ZERO => false,
+                    FP_ROUND_TOWARD_POSITIVE => !negative,
+                    FP_ROUND_TOWARD_NEGATIVE => negative,
+                    _ => return rounded,
+                };
+                if move_away_from_zero == exact_is_farther_from_zero {
+                    // The exact value lies on the side the mode wants to round toward, but
+                    // `rounded` already rounded the other way (to nearest) -- step one ULP.
+                    // IEEE-754 bit patterns are sign-magnitude, so adding one to the bits moves
+                    // the value away from zero whatever its sign.
+                    let bits = rounded.to_bits();
+                    let stepped = if move_away_from_zero {
+                        bits.wrapping_add(1)
+                    } else {
+                        bits.wrapping_sub(1)
+                    };
+                    <$float>::from_bits(stepped)
+                } else {
+                    rounded
+                }
+            };
+
+            let a = <$float>::from_bits(a_bits);
+            let b = <$float>::from_bits(b_bits);
+            let mut flags = 0u32;
+
+            let result = match op & 0xf {
+                FP_OP_ADD | FP_OP_SUB => {
+                    let b = if op & 0xf == FP_OP_SUB { -b } else { b };
+                    if a.is_nan() || b.is_nan() {
+                        flags |= FP_FLAG_INVALID;
+                        <$float>::from_bits($qnan)
+                    } else {
+                        let r = a + b;
+                        if r.is_nan() {
+                            // Only `inf + (-inf)` reaches here, since neither operand is NaN.
+                            flags |= FP_FLAG_INVALID;
+                            <$float>::from_bits($qnan)
+                        } else if r.is_infinite() && a.is_finite() && b.is_finite() {
+                            flags |= FP_FLAG_OVERFLOW | FP_FLAG_INEXACT;
+                            r
+                        } else {
+                            // TwoSum (Knuth/Møller): `err` is the exact `(a + b) - r`.
+                            let bb = r - a;
+                            let err = (a - (r - bb)) + (b - bb);
+                            if err != <$float>::from_bits(0) {
+                                flags |= FP_FLAG_INEXACT;
+                            }
+                            let residual_sign = if err > <$float>::from_bits(0) {
+                                1
+                            } else if err < <$float>::from_bits(0) {
+                                -1
+                            } else {
+                                0
+                            };
+                            nudge(r, residual_sign)
+                        }
+                    }
+                }
+                FP_OP_MUL => {
+                    if a.is_nan()
+                        || b.is_nan()
+                        || (a == <$float>::from_bits(0) && b.is_infinite())
+                        || (b == <$float>::from_bits(0) && a.is_infinite())
+                    {
+                        flags |= FP_FLAG_INVALID;
+                        <$float>::from_bits($qnan)
+                    } else {
+                        let r = a * b;
+                        if r.is_infinite() && a.is_finite() && b.is_finite() {
+                            flags |= FP_FLAG_OVERFLOW | FP_FLAG_INEXACT;
+                            r
+                        } else if r.is_finite() {
+                            let err = a.mul_add(b, -r);
+                            if err != <$float>::from_bits(0) {
+                                flags |= FP_FLAG_INEXACT;
+                            }
+                            let residual_sign = if err > <$float>::from_bits(0) {
+                                1
+                            } else if err < <$float>::from_bits(0) {
+                                -1
+                            } else {
+                                0
+                            };
+                            nudge(r, residual_sign)
+                        } else {
+                            r
+                        }
+                    }
+                }
+                FP_OP_DIV => {
+                    if a.is_nan()
+                        || b.is_nan()
+                        || (a == <$float>::from_bits(0) && b == <$float>::from_bits(0))
+                        || (a.is_infinite() && b.is_infinite())
+                    {
+                        flags |= FP_FLAG_INVALID;
+                        <$float>::from_bits($qnan)
+                    } else {
+                        let r = a / b;
+                        if r.is_infinite() && b != <$float>::from_bits(0) {
+                            flags |= FP_FLAG_OVERFLOW | FP_FLAG_INEXACT;
+                            r
+                        } else if r.is_finite() {
+                            // Residual of `a / b`: `a - r * b`, via one fused multiply-add.
+                            let err = (-r).mul_add(b, a);
+                            if err != <$float>::from_bits(0) {
+                                flags |= FP_FLAG_INEXACT;
+                            }
+                            let residual_sign = if err > <$float>::from_bits(0) {
+                                1
+                            } else if err < <$float>::from_bits(0) {
+                                -1
+                            } else {
+                                0
+                            };
+                            nudge(r, residual_sign)
+                        } else {
+                            r
+                        }
+                    }
+                }
+                FP_OP_SQRT => {
+                    if a.is_nan() || (a.is_sign_negative() && a != <$float>::from_bits(0)) {
+                        flags |= FP_FLAG_INVALID;
+                        <$float>::from_bits($qnan)
+                    } else {
+                        let r = a.sqrt();
+                        // Residual of `sqrt(a)`: `a - r * r`, via one fused multiply-add.
+                        let err = (-r).mul_add(r, a);
+                        if err != <$float>::from_bits(0) {
+                            flags |= FP_FLAG_INEXACT;
+                        }
+                        let residual_sign = if err > <$float>::from_bits(0) {
+                            1
+                        } else if err < <$float>::from_bits(0) {
+                            -1
+                        } else {
+                            0
+                        };
+                        nudge(r, residual_sign)
+                    }
+                }
+                _ => unreachable!("dispatched by caller"),
+            };
+
+            (result.to_bits(), flags)
+        }
+    };
+}
+
+impl_fp_op!(run_f32, f32, u32, 0x7fc0_0000_u32);
+impl_fp_op!(run_f64, f64, u64, 0x7ff8_0000_0000_0000_u64);
+
+/// Rounds `value` (assumed finite) to the nearest integer per `round_mode`, ties-to-even for
+/// [`FP_ROUND_NEAREST_EVEN`].
+fn round_to_integer(value: f64, round_mode: u32) -> f64 {
+    match round_mode {
+        FP_ROUND_TOWARD_ZERO => value.trunc(),
+        FP_ROUND_TOWARD_POSITIVE => value.ceil(),
+        FP_ROUND_TOWARD_NEGATIVE => value.floor(),
+        _ => {
+            let floor = value.floor();
+            let diff = value - floor;
+            if diff < 0.5 {
+                floor
+            } else if diff > 0.5 {
+                floor + 1.0
+            } else if (floor as i64) % 2 == 0 {
+                floor
+            } else {
+                floor + 1.0
+            }
+        }
+    }
+}
+
+fn cvt_int_to_float(a_bits: u64, double: bool, round_mode: u32) -> (u64, u32) {
+    let value = a_bits as u32 as i32;
+    if double {
+        // Every `i32` is exactly representable in `f64`, so this conversion is always exact.
+        (f64::from(value).to_bits(), 0)
+    } else {
+        let exact = f64::from(value);
+        let rounded_f32 = value as f32;
+        let mut flags = 0u32;
+        // `exact` and `rounded_f32 as f64` are within one `f32` ULP of each other, so their
+        // difference is exactly representable (Sterbenz's lemma) and safe to use as the residual.
+        let err = exact - f64::from(rounded_f32);
+        if err != 0.0 {
+            flags |= FP_FLAG_INEXACT;
+        }
+        let rounded = match round_mode {
+            FP_ROUND_NEAREST_EVEN => rounded_f32,
+            _ => {
+                let residual_sign = if err > 0.0 { 1 } else if err < 0.0 { -1 } else { 0 };
+                if residual_sign == 0 {
+                    rounded_f32
+                } else {
+                    let negative = rounded_f32.is_sign_negative();
+                    let move_away_from_zero = match round_mode {
+                        FP_ROUND_TOWARD_ZERO => false,
+                        FP_ROUND_TOWARD_POSITIVE => !negative,
+                        FP_ROUND_TOWARD_NEGATIVE => negative,
+                        _ => unreachable!(),
+                    };
+                    let exact_is_farther = (residual_sign > 0) != negative;
+                    if move_away_from_zero == exact_is_farther {
+                        let bits = rounded_f32.to_bits();
+                        let stepped =
+                            if move_away_from_zero { bits.wrapping_add(1) } else { bits.wrapping_sub(1) };
+                        f32::from_bits(stepped)
+                    } else {
+                        rounded_f32
+                    }
+                }
+            }
+        };
+        (u64::from(rounded.to_bits()), flags)
+    }
+}
+
+fn cvt_float_to_int(a_bits: u64, double: bool, round_mode: u32) -> (u64, u32) {
+    let value = if double { f64::from_bits(a_bits) } else { f64::from(f32::from_bits(a_bits as u32)) };
+    if value.is_nan() {
+        return (0, FP_FLAG_INVALID);
+    }
+    let rounded = round_to_integer(value, round_mode);
+    let mut flags = if rounded != value { FP_FLAG_INEXACT } else { 0 };
+    let clamped = if rounded > f64::from(i32::MAX) {
+        flags |= FP_FLAG_INVALID;
+        i32::MAX
+    } else if rounded < f64::from(i32::MIN) {
+        flags |= FP_FLAG_INVALID;
+        i32::MIN
+    } else {
+        rounded as i32
+    };
+    (u64::from(clamped as u32), flags)
+}
+
+/// Buffer layout: the first argument points to `a` (the in-place operand/result); the second
+/// points to a buffer bundling `b`, then `op`, then `round_mode` (`FpOpArgs`, see
+/// `zkvm/entrypoint/src/syscalls/fp_op.rs`) since the raw syscall convention only carries two
+/// register-sized arguments.
+pub(crate) struct FpOpSyscall;
+impl Syscall for FpOpSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        a_ptr: u32,
+        args_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let a_lo = ctx.rt.word(a_ptr);
+        let a_hi = ctx.rt.word(a_ptr + 4);
+        let a_bits = u64::from(a_lo) | (u64::from(a_hi) << 32);
+
+        let b_lo = ctx.rt.word(args_ptr);
+        let b_hi = ctx.rt.word(args_ptr + 4);
+        let b_bits = u64::from(b_lo) | (u64::from(b_hi) << 32);
+        let op = ctx.rt.word(args_ptr + 8);
+        let round_mode = ctx.rt.word(args_ptr + 12);
+
+        let double = op & FP_OP_DOUBLE != 0;
+        let kind = op & 0xf;
+        let (result_bits, flags) = match kind {
+            FP_OP_CVT_INT_TO_FLOAT => cvt_int_to_float(a_bits, double, round_mode),
+            FP_OP_CVT_FLOAT_TO_INT => cvt_float_to_int(a_bits, double, round_mode),
+            _ if double => run_f64(kind, a_bits, b_bits, round_mode),
+            _ => {
+                let (bits, flags) = run_f32(kind, a_bits as u32, b_bits as u32, round_mode);
+                (u64::from(bits), flags)
+            }
+        };
+
+        ctx.rt.mw_cpu(a_ptr, result_bits as u32, crate::events::MemoryAccessPosition::A);
+        if double {
+            ctx.rt.mw_cpu(
+                a_ptr + 4,
+                (result_bits >> 32) as u32,
+                crate::events::MemoryAccessPosition::B,
+            );
+        }
+
+        ctx.rt.record.fp_op_events.push(FpOpEvent {
+            shard: ctx.rt.shard(),
+            clk: ctx.rt.state.clk,
+            op,
+            round_mode,
+            a: a_bits,
+            b: b_bits,
+            result: result_bits,
+            flags,
+        });
+
+        Some((flags, 0))
+    }
+}
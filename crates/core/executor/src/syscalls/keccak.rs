@@ -0,0 +1,153 @@
+//! `KECCAK_PERMUTE`, the bare keccak-f[1600] round function over a resident 25-lane state,
+//! mirroring how [`super::poseidon2::Poseidon2PermuteSyscall`] turns a fixed-width permutation
+//! into a single syscall instead of a CPU-row-per-step loop. `zkm2_zkvm::io`'s sponge
+//! (`crate::hasher::Hasher`-backed `Keccak`/`Shake` types already in the guest-side library) calls
+//! this once per block instead of running the whole permutation in MIPS instructions, which is
+//! where nearly all of a Keccak-256 hash's cycle count otherwise goes.
+//!
+//! The guest's existing `syscall_keccak_permute` intrinsic (see
+//! `zkm2_zkvm::syscalls::keccak_permute`) already encodes the state as 25 little-endian `u64`
+//! lanes, i.e. 50 resident `u32` words; this syscall reads that buffer, runs the permutation, and
+//! writes the 25 lanes back in place over the same words.
+
+use crate::events::KeccakPermuteEvent;
+
+use super::{context::SyscallContext, Syscall, SyscallCode};
+
+/// Number of 64-bit lanes in a keccak-f[1600] state.
+pub use crate::events::KECCAK_PERMUTE_STATE_WORDS;
+
+const RC: [u64; 24] = [
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_8082,
+    0x8000_0000_0000_808A,
+    0x8000_0000_8000_8000,
+    0x0000_0000_0000_808B,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8009,
+    0x0000_0000_0000_008A,
+    0x0000_0000_0000_0088,
+    0x0000_0000_8000_8009,
+    0x0000_0000_8000_000A,
+    0x0000_0000_8000_808B,
+    0x8000_0000_0000_008B,
+    0x8000_0000_0000_8089,
+    0x8000_0000_0000_8003,
+    0x8000_0000_0000_8002,
+    0x8000_0000_0000_0080,
+    0x0000_0000_0000_800A,
+    0x8000_0000_8000_000A,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8080,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8008,
+];
+
+/// Rotation offsets for the ρ step, indexed the same way as `state` below: `rho[x + 5 * y]` is
+/// the left-rotation applied to lane `(x, y)`.
+const RHO: [u32; 25] = [
+    0, 1, 62, 28, 27, //
+    36, 44, 6, 55, 20, //
+    3, 10, 43, 25, 39, //
+    41, 45, 15, 21, 8, //
+    18, 2, 61, 56, 14,
+];
+
+/// One full keccak-f[1600] permutation over a 25-lane state, applying the standard θ, ρ, π, χ, ι
+/// steps 24 times. Written out by hand rather than pulled in from a crate, the same way
+/// [`super::blake3::compress`] hand-rolls Blake3's mixing function instead of depending on the
+/// `blake3` crate.
+pub fn keccakf(state: &mut [u64; KECCAK_PERMUTE_STATE_WORDS]) {
+    for round_constant in RC {
+        // θ: XOR each column's parity into every lane of the two neighboring columns.
+        let mut c = [0u64; 5];
+        for x in 0..5 {
+            c[x] = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        let mut d = [0u64; 5];
+        for x in 0..5 {
+            d[x] = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+        }
+        for x in 0..5 {
+            for y in 0..5 {
+                state[x + 5 * y] ^= d[x];
+            }
+        }
+
+        // ρ and π: rotate each lane by its fixed offset, then permute lanes to their new
+        // position `(y, 2x + 3y) -> (x, y)`.
+        let mut b = [0u64; 25];
+        for x in 0..5 {
+            for y in 0..5 {
+                let new_x = y;
+                let new_y = (2 * x + 3 * y) % 5;
+                b[new_x + 5 * new_y] = state[x + 5 * y].rotate_left(RHO[x + 5 * y]);
+            }
+        }
+
+        // χ: each lane is XORed with the AND of the *complement* of the next lane and the lane
+        // after that, in its row.
+        for x in 0..5 {
+            for y in 0..5 {
+                state[x + 5 * y] =
+                    b[x + 5 * y] ^ ((!b[(x + 1) % 5 + 5 * y]) & b[(x + 2) % 5 + 5 * y]);
+            }
+        }
+
+        // ι: XOR this round's constant into lane (0, 0).
+        state[0] ^= round_constant;
+    }
+}
+
+fn read_state(ctx: &mut SyscallContext, addr: u32) -> [u64; KECCAK_PERMUTE_STATE_WORDS] {
+    core::array::from_fn(|i| {
+        let lo = ctx.rt.word(addr + (i as u32) * 8);
+        let hi = ctx.rt.word(addr + (i as u32) * 8 + 4);
+        u64::from(lo) | (u64::from(hi) << 32)
+    })
+}
+
+fn write_state(ctx: &mut SyscallContext, addr: u32, state: &[u64; KECCAK_PERMUTE_STATE_WORDS]) {
+    for (i, &lane) in state.iter().enumerate() {
+        ctx.rt.mw_cpu(
+            addr + (i as u32) * 8,
+            lane as u32,
+            crate::events::MemoryAccessPosition::A,
+        );
+        ctx.rt.mw_cpu(
+            addr + (i as u32) * 8 + 4,
+            (lane >> 32) as u32,
+            crate::events::MemoryAccessPosition::A,
+        );
+    }
+}
+
+/// `keccak_permute(state_ptr, _)`: reads a 25-lane (50-word) keccak-f[1600] state from
+/// `state_ptr`, applies [`keccakf`], and writes it back in place.
+pub(crate) struct KeccakPermuteSyscall;
+
+impl Syscall for KeccakPermuteSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        state_ptr: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        let pre_state = read_state(ctx, state_ptr);
+        let mut post_state = pre_state;
+        keccakf(&mut post_state);
+        write_state(ctx, state_ptr, &post_state);
+
+        ctx.rt.record.keccak_permute_events.push(KeccakPermuteEvent {
+            shard: ctx.rt.shard(),
+            clk: ctx.rt.state.clk,
+            state_addr: state_ptr,
+            pre_state,
+            post_state,
+        });
+
+        Some((0, 0))
+    }
+}
@@ -0,0 +1,174 @@
+//! `KECCAK_SPONGE`, the generalized Keccak sponge precompile underlying `zkm2_zkvm::sponge`'s
+//! shared driver for Keccak-256/SHA3-224/256/384/512/SHAKE128/256: the guest pads and rate-packs
+//! its message itself (see `zkm2_zkvm::sponge::sponge`), so this syscall only has to absorb the
+//! packed blocks and squeeze back whatever output length was asked for, the same division of
+//! labor `super::keccak::KeccakPermuteSyscall` has with its own guest-side intrinsic, just with a
+//! variable number of blocks on both ends instead of one fixed-size permutation.
+//!
+//! The absorb/squeeze loop below reads and writes one block of
+//! [`KECCAK_GENERAL_RATE_U32S`](zkm2_core_machine constant, duplicated here as
+//! [`KECCAK_SPONGE_RATE_U32S`] since the executor crate can't depend on the machine crate) words
+//! at a time, matching [`crate::events::KeccakSpongeEvent`]'s existing, already-generalized
+//! squeeze handling. That block width is fixed at compile time rather than threaded through from
+//! the guest's actual rate, the same way [`super::mem_copy::MemCopyFixedSyscall`] is generic over
+//! a compile-time word count rather than a runtime one -- `KeccakSpongeChip`'s columns are sized
+//! to it. It happens to match the packed block size `zkm2_zkvm::sponge::sponge` produces for a
+//! 136-byte rate (Keccak-256's own rate plus the driver's fixed 2-word padding gap); the other
+//! FIPS-202 rates pack to a different block width and so aren't yet provable by this one chip --
+//! giving each its own rate would need a const-generic `KeccakSpongeChip<RATE_U32S>` split the
+//! way `MemCopyFixedChip<NUM_WORDS>` already does for `MEMCPY_32`/`MEMCPY_64`, left for a
+//! follow-up rather than attempted here.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use crate::events::{KeccakSpongeEvent, KECCAK_GENERAL_OUTPUT_U32S};
+
+use super::keccak::keccakf;
+use super::{context::SyscallContext, Syscall, SyscallCode};
+
+/// Words absorbed/squeezed per permutation. See the module doc comment for why this is fixed
+/// rather than threaded through from the guest's requested rate.
+pub const KECCAK_SPONGE_RATE_U32S: usize = 36;
+/// Words in a resident keccak-f[1600] state (25 lanes, two `u32`s each).
+pub const KECCAK_SPONGE_STATE_U32S: usize = 50;
+
+/// Upper bound on both `input_len_u32s` and `output_len_u32s`, the same
+/// guest-controlled-length-clamp convention [`super::mem_copy::MAX_MEM_COPY_WORDS`] and
+/// [`super::return_data::MAX_RETURN_DATA_LEN`] use, so a malicious length can't force the
+/// `Vec::with_capacity` calls below to attempt a multi-gigabyte allocation and abort the process.
+pub const MAX_KECCAK_SPONGE_WORDS: u32 = 1 << 16;
+
+fn state_to_lanes(state: &[u32; KECCAK_SPONGE_STATE_U32S]) -> [u64; 25] {
+    core::array::from_fn(|i| u64::from(state[2 * i]) | (u64::from(state[2 * i + 1]) << 32))
+}
+
+fn lanes_to_state(lanes: &[u64; 25]) -> [u32; KECCAK_SPONGE_STATE_U32S] {
+    let mut state = [0u32; KECCAK_SPONGE_STATE_U32S];
+    for (i, &lane) in lanes.iter().enumerate() {
+        state[2 * i] = lane as u32;
+        state[2 * i + 1] = (lane >> 32) as u32;
+    }
+    state
+}
+
+/// `keccak_sponge(input_ptr, descriptor_ptr)`: `descriptor_ptr` points to three words
+/// `[output_ptr, input_len_ptr, out_len_words]` -- the same "extra operands packed next to the
+/// pointer" convention [`super::mtree::MtreeVerifyPathSyscall`] uses. `input_len_ptr` is itself a
+/// pointer (rather than an immediate) because, unlike `out_len_words`, the absorbed length feeds
+/// directly into the sponge's security argument, so it gets a tracked
+/// [`crate::events::MemoryReadRecord`] the same way a leaf or root digest would, not a bare
+/// untracked word.
+pub(crate) struct KeccakSpongeSyscall {
+    /// The most recent call's total word count (length read + input words + output words), read
+    /// back by [`Self::num_extra_cycles`] so the cost model scales with the work actually done --
+    /// see [`super::mem_copy::MemCopySyscall::last_len`].
+    last_words: AtomicU32,
+}
+
+impl Default for KeccakSpongeSyscall {
+    fn default() -> Self {
+        Self { last_words: AtomicU32::new(0) }
+    }
+}
+
+impl Syscall for KeccakSpongeSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        input_addr: u32,
+        descriptor_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let shard = ctx.rt.shard();
+        let clk = ctx.rt.state.clk;
+
+        let output_addr = ctx.rt.word(descriptor_ptr);
+        let input_len_ptr = ctx.rt.word(descriptor_ptr + 4);
+        let output_len_u32s = ctx.rt.word(descriptor_ptr + 8).min(MAX_KECCAK_SPONGE_WORDS);
+
+        let input_length_record = ctx.rt.mr(input_len_ptr, shard, clk, None);
+        // `input_length_record` itself must keep the raw memory value for the memory chip's
+        // read-consistency check; only the length actually used below (loop bound, allocation
+        // sizes, and the event's own `input_len_u32s`) is clamped.
+        let input_len_u32s = input_length_record.value.min(MAX_KECCAK_SPONGE_WORDS);
+
+        let mut input = Vec::with_capacity(input_len_u32s as usize);
+        let mut input_read_records = Vec::with_capacity(input_len_u32s as usize);
+        for i in 0..input_len_u32s {
+            let read = ctx.rt.mr(input_addr + i * 4, shard, clk + 1 + i, None);
+            input.push(read.value);
+            input_read_records.push(read);
+        }
+
+        // Absorb one `KECCAK_SPONGE_RATE_U32S`-word block per permutation, xoring it into the
+        // rate portion of the running state and leaving the capacity portion untouched -- the
+        // state left behind after the final block is exactly what the squeeze phase below reads
+        // from first, no extra permute needed.
+        let num_blocks = (input_len_u32s as usize) / KECCAK_SPONGE_RATE_U32S;
+        let mut state = [0u32; KECCAK_SPONGE_STATE_U32S];
+        let mut xored_state_list = Vec::with_capacity(num_blocks.max(1));
+        for i in 0..num_blocks {
+            for j in 0..KECCAK_SPONGE_RATE_U32S {
+                state[j] ^= input[i * KECCAK_SPONGE_RATE_U32S + j];
+            }
+            let mut lanes = state_to_lanes(&state);
+            xored_state_list.push(lanes);
+            keccakf(&mut lanes);
+            state = lanes_to_state(&lanes);
+        }
+
+        let mut output_write_records = Vec::with_capacity(output_len_u32s as usize);
+        let mut squeeze_output = Vec::with_capacity(output_len_u32s as usize);
+        let mut written = 0u32;
+        let write_clk_base = clk + 1 + input_len_u32s;
+        while written < output_len_u32s {
+            let remaining = output_len_u32s - written;
+            let words_this_block = remaining.min(KECCAK_SPONGE_RATE_U32S as u32);
+            for j in 0..words_this_block {
+                let value = state[j as usize];
+                let record = ctx.rt.mw(
+                    output_addr + (written + j) * 4,
+                    value,
+                    shard,
+                    write_clk_base + written + j,
+                    None,
+                );
+                output_write_records.push(record);
+                squeeze_output.push(value);
+            }
+            written += words_this_block;
+
+            if written < output_len_u32s {
+                let mut lanes = state_to_lanes(&state);
+                keccakf(&mut lanes);
+                state = lanes_to_state(&lanes);
+            }
+        }
+
+        ctx.rt.record.keccak_sponge_events.push(KeccakSpongeEvent {
+            shard,
+            clk,
+            input,
+            output: [0; KECCAK_GENERAL_OUTPUT_U32S],
+            input_len_u32s,
+            output_len_u32s,
+            squeeze_output,
+            input_read_records,
+            input_length_record,
+            output_write_records,
+            xored_state_list,
+            input_addr,
+            output_addr,
+            local_mem_access: Vec::new(),
+            instance_id: 0,
+        });
+
+        self.last_words.store(1 + input_len_u32s + output_len_u32s, Ordering::Relaxed);
+
+        Some((0, 0))
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        self.last_words.load(Ordering::Relaxed)
+    }
+}
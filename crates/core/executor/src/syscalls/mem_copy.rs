@@ -0,0 +1,145 @@
+//! `SYSMEMCOPY`/`SYSMEMSET`, a bulk word-move precompile so a guest copying or zeroing a large
+//! buffer (e.g. the `revme-program` reading a large JSON blob off stdin) doesn't burn one CPU row
+//! per word, the same win the keccak/secp256k1/bn254 precompiles already take for their own hot
+//! loops, applied to plain memory movement instead of a specific algorithm.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use crate::events::MemCopyEvent;
+
+use super::{context::SyscallContext, Syscall, SyscallCode};
+
+/// The largest word count a single `SYSMEMCOPY`/`SYSMEMSET` call will move, so one malicious
+/// `len` can't force an unbounded host-side loop.
+pub const MAX_MEM_COPY_WORDS: u32 = 1 << 16;
+
+/// `memcopy(descriptor_ptr, _)`: `descriptor_ptr` points to three words `[src_ptr, dst_ptr,
+/// len]`. `Syscall::execute` only carries two operands, too few for a three-pointer-and-a-count
+/// call, so the extra operand is packed next to its pointers instead -- the same move
+/// [`super::bundlefs::BundleStatSyscall`] makes returning an `(offset, len)` pair through memory
+/// rather than registers.
+pub(crate) struct MemCopySyscall {
+    /// The word count of the most recent call, read back by [`Self::num_extra_cycles`] so the
+    /// cost model scales with the work actually done rather than a flat per-call charge.
+    last_len: AtomicU32,
+}
+
+impl Default for MemCopySyscall {
+    fn default() -> Self {
+        Self { last_len: AtomicU32::new(0) }
+    }
+}
+
+impl Syscall for MemCopySyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        descriptor_ptr: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        let src_ptr = ctx.rt.word(descriptor_ptr);
+        let dst_ptr = ctx.rt.word(descriptor_ptr + 4);
+        let len = ctx.rt.word(descriptor_ptr + 8).min(MAX_MEM_COPY_WORDS);
+        for i in 0..len {
+            let word = ctx.rt.word(src_ptr + i * 4);
+            ctx.rt.mw_cpu(dst_ptr + i * 4, word, crate::events::MemoryAccessPosition::A);
+        }
+        self.last_len.store(len, Ordering::Relaxed);
+        Some((len, 0))
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        self.last_len.load(Ordering::Relaxed)
+    }
+}
+
+/// `memcpy32(src_ptr, dst_ptr)`/`memcpy64(src_ptr, dst_ptr)`: copy a fixed [`WORDS`]-word block
+/// from `src_ptr` to `dst_ptr` in one precompile row instead of one `MemCopySyscall` event per
+/// variable-length call. Unlike [`MemCopySyscall`], the length is baked into the syscall code
+/// itself (see [`super::SyscallCode::MEMCPY_32`]/[`super::SyscallCode::MEMCPY_64`]) rather than
+/// read out of a descriptor, so both operands are free for the source/destination pointers and
+/// the trace can constrain one source/destination word pair per column instead of a
+/// variable-length loop.
+pub(crate) struct MemCopyFixedSyscall<const WORDS: u32>;
+
+impl<const WORDS: u32> Syscall for MemCopyFixedSyscall<WORDS> {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        src_ptr: u32,
+        dst_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let shard = ctx.rt.shard();
+        let clk = ctx.rt.state.clk;
+
+        // Unlike `MemCopySyscall`'s `mw_cpu` loop above, this keeps the full read/write records
+        // (not just the copied value) so `MemCopyFixedChip`'s trace can populate one
+        // `MemoryReadCols`/`MemoryWriteCols` pair per word straight from them, the same way
+        // `MiscInstrsChip::populate_movcond` populates its access column from `MiscEvent::a_record`
+        // rather than re-deriving it.
+        let mut src_records = Vec::with_capacity(WORDS as usize);
+        let mut dst_records = Vec::with_capacity(WORDS as usize);
+        for i in 0..WORDS {
+            let read = ctx.rt.mr(src_ptr + i * 4, shard, clk + i, None);
+            dst_records.push(ctx.rt.mw(dst_ptr + i * 4, read.value, shard, clk + i, None));
+            src_records.push(read);
+        }
+
+        ctx.rt.record.mem_copy_events.push(MemCopyEvent {
+            shard,
+            clk,
+            src_ptr,
+            dst_ptr,
+            src_records,
+            dst_records,
+        });
+
+        Some((0, 0))
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        WORDS
+    }
+}
+
+/// `MEMCPY_32`: fixed 32-word bulk copy, see [`MemCopyFixedSyscall`].
+pub(crate) type MemCopy32Syscall = MemCopyFixedSyscall<32>;
+/// `MEMCPY_64`: fixed 64-word bulk copy, see [`MemCopyFixedSyscall`].
+pub(crate) type MemCopy64Syscall = MemCopyFixedSyscall<64>;
+
+/// `memset(descriptor_ptr, fill_value)`: `descriptor_ptr` points to two words `[dst_ptr, len]`,
+/// filled with `fill_value`.
+pub(crate) struct MemSetSyscall {
+    /// See [`MemCopySyscall::last_len`].
+    last_len: AtomicU32,
+}
+
+impl Default for MemSetSyscall {
+    fn default() -> Self {
+        Self { last_len: AtomicU32::new(0) }
+    }
+}
+
+impl Syscall for MemSetSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        descriptor_ptr: u32,
+        fill_value: u32,
+    ) -> Option<(u32, u32)> {
+        let dst_ptr = ctx.rt.word(descriptor_ptr);
+        let len = ctx.rt.word(descriptor_ptr + 4).min(MAX_MEM_COPY_WORDS);
+        for i in 0..len {
+            ctx.rt.mw_cpu(dst_ptr + i * 4, fill_value, crate::events::MemoryAccessPosition::A);
+        }
+        self.last_len.store(len, Ordering::Relaxed);
+        Some((len, 0))
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        self.last_len.load(Ordering::Relaxed)
+    }
+}
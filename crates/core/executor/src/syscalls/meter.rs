@@ -0,0 +1,24 @@
+//! `SYSMETER`, a cycle-budget metering syscall modeled on Solana BPF's `ComputeMeter` and
+//! risc0's `cycle_count`: a guest can ask how many cycles it has left against the host's
+//! configured [`crate::context::ZKMContext::cycle_limit`], rather than discovering it only when
+//! the executor aborts with [`crate::ExecutionError::CycleBudgetExceeded`].
+
+use super::{context::SyscallContext, Syscall, SyscallCode};
+
+pub(crate) struct MeterSyscall;
+
+impl Syscall for MeterSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        _: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        let remaining = match ctx.rt.cycle_limit {
+            Some(limit) => limit.saturating_sub(ctx.rt.cycles_used()),
+            None => u64::from(u32::MAX),
+        };
+        Some((remaining.min(u64::from(u32::MAX)) as u32, 0))
+    }
+}
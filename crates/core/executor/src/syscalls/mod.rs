@@ -1,16 +1,51 @@
 //! Syscall definitions & implementations for the [`crate::Executor`].
+//!
+//! Dispatch is a numbered call table: the guest loads a [`SyscallCode`] into `$v0` and the
+//! executor looks it up in [`crate::Executor::syscall_map`] (seeded by [`default_syscall_map`])
+//! to find the [`Syscall`] impl to run. Embedders can install their own handlers -- for a
+//! built-in code, via [`crate::Executor::register_syscall`] to override the default; for a new
+//! one, by adding a variant in the reserved range documented on [`SyscallCode`] and registering
+//! it the same way.
 
+mod blake3;
+mod bls12381;
+mod bn254;
+mod bundlefs;
 mod code;
 mod context;
+mod cycle_count;
 
+mod fp_op;
 mod halt;
 mod hint;
+mod keccak;
+mod keccak_sponge;
+mod mem_copy;
+mod meter;
+mod mtree;
+mod poseidon2;
+mod return_data;
+mod secp256k1;
+mod snapshot;
 mod stdsys;
 mod verify;
 mod write;
 
 use std::sync::Arc;
 
+use blake3::Blake3CompressSyscall;
+use bls12381::{
+    FinalExpSyscall, G1AddSyscall, G1DoubleSyscall, G1MapSyscall, G1ScalarMulSyscall,
+    G1SubgroupCheckSyscall, G2AddSyscall, G2DecompressSyscall, G2DoubleSyscall, G2MapSyscall,
+    G2ScalarMulSyscall, G2SubgroupCheckSyscall, MillerLoopSyscall, PairingCheckSyscall,
+};
+use bn254::{
+    Bn254AddSyscall, Bn254DoubleSyscall, Bn254FrOpSyscall, Bn254PairingSyscall,
+    Bn254ScalarMacSyscall, Bn254ScalarMulSyscall,
+};
+use bundlefs::{BundleOpenSyscall, BundleReadSyscall, BundleStatSyscall};
+use cycle_count::{CycleCountSyscall, CycleTrackerEndSyscall};
+use fp_op::FpOpSyscall;
 use halt::HaltSyscall;
 use hashbrown::HashMap;
 use stdsys::*;
@@ -18,6 +53,15 @@ use stdsys::*;
 pub use code::*;
 pub use context::*;
 use hint::{HintLenSyscall, HintReadSyscall};
+use keccak::KeccakPermuteSyscall;
+use keccak_sponge::KeccakSpongeSyscall;
+use mem_copy::{MemCopy32Syscall, MemCopy64Syscall, MemCopySyscall, MemSetSyscall};
+use meter::MeterSyscall;
+use mtree::{MtreeMergeSyscall, MtreeVerifyPathSyscall};
+use poseidon2::{Poseidon2Permute16Syscall, Poseidon2Permute8Syscall};
+use return_data::{GetReturnDataSyscall, SetReturnDataSyscall};
+use secp256k1::Secp256k1RecoverSyscall;
+pub(crate) use snapshot::{RollbackSyscall, SnapshotSyscall};
 use verify::VerifySyscall;
 use write::WriteSyscall;
 
@@ -66,6 +110,8 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
 
     syscall_map.insert(SyscallCode::SYSREAD, Arc::new(ReadSyscall));
     syscall_map.insert(SyscallCode::SYSWRITE, Arc::new(WriteSyscall));
+    syscall_map.insert(SyscallCode::SYSOPEN, Arc::new(OpenSyscall));
+    syscall_map.insert(SyscallCode::SYSCLOSE, Arc::new(CloseSyscall));
     syscall_map.insert(SyscallCode::SYSFCNTL, Arc::new(FcntlSyscall));
     syscall_map.insert(
         SyscallCode::SYSSETTHREADAREA,
@@ -73,6 +119,67 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
     );
 
     syscall_map.insert(SyscallCode::SYSVERIFY, Arc::new(VerifySyscall));
+    syscall_map.insert(SyscallCode::SYSYIELD, Arc::new(YieldSyscall));
+    syscall_map.insert(SyscallCode::SYSMETER, Arc::new(MeterSyscall));
+    syscall_map.insert(SyscallCode::SYSCYCLECOUNT, Arc::new(CycleCountSyscall));
+    syscall_map.insert(SyscallCode::SYSCYCLETRACKEREND, Arc::new(CycleTrackerEndSyscall));
+    syscall_map.insert(SyscallCode::SYSSNAPSHOT, Arc::new(SnapshotSyscall));
+    syscall_map.insert(SyscallCode::SYSROLLBACK, Arc::new(RollbackSyscall));
+
+    syscall_map.insert(SyscallCode::SYSSETRETURNDATA, Arc::new(SetReturnDataSyscall));
+    syscall_map.insert(SyscallCode::SYSGETRETURNDATA, Arc::new(GetReturnDataSyscall));
+
+    syscall_map.insert(SyscallCode::SYSMEMCOPY, Arc::new(MemCopySyscall::default()));
+    syscall_map.insert(SyscallCode::SYSMEMSET, Arc::new(MemSetSyscall::default()));
+    syscall_map.insert(SyscallCode::MEMCPY_32, Arc::new(MemCopy32Syscall));
+    syscall_map.insert(SyscallCode::MEMCPY_64, Arc::new(MemCopy64Syscall));
+
+    syscall_map.insert(SyscallCode::POSEIDON2_PERMUTE_8, Arc::new(Poseidon2Permute8Syscall));
+    syscall_map.insert(SyscallCode::POSEIDON2_PERMUTE_16, Arc::new(Poseidon2Permute16Syscall));
+
+    syscall_map.insert(SyscallCode::BLAKE3_COMPRESS, Arc::new(Blake3CompressSyscall));
+
+    syscall_map.insert(SyscallCode::KECCAK_PERMUTE, Arc::new(KeccakPermuteSyscall));
+    syscall_map.insert(SyscallCode::KECCAK_SPONGE, Arc::new(KeccakSpongeSyscall::default()));
+
+    syscall_map.insert(SyscallCode::SYSBUNDLEOPEN, Arc::new(BundleOpenSyscall));
+    syscall_map.insert(SyscallCode::SYSBUNDLEREAD, Arc::new(BundleReadSyscall));
+    syscall_map.insert(SyscallCode::SYSBUNDLESTAT, Arc::new(BundleStatSyscall));
+
+    syscall_map.insert(SyscallCode::BLS12381_G1_ADD, Arc::new(G1AddSyscall));
+    syscall_map.insert(SyscallCode::BLS12381_G1_DOUBLE, Arc::new(G1DoubleSyscall));
+    syscall_map.insert(SyscallCode::BLS12381_G1_SCALAR_MUL, Arc::new(G1ScalarMulSyscall));
+    syscall_map.insert(
+        SyscallCode::BLS12381_G1_SUBGROUP_CHECK,
+        Arc::new(G1SubgroupCheckSyscall),
+    );
+    syscall_map.insert(SyscallCode::BLS12381_G1_MAP, Arc::new(G1MapSyscall));
+    syscall_map.insert(SyscallCode::BLS12381_G2_ADD, Arc::new(G2AddSyscall));
+    syscall_map.insert(SyscallCode::BLS12381_G2_DOUBLE, Arc::new(G2DoubleSyscall));
+    syscall_map.insert(SyscallCode::BLS12381_G2_SCALAR_MUL, Arc::new(G2ScalarMulSyscall));
+    syscall_map.insert(
+        SyscallCode::BLS12381_G2_SUBGROUP_CHECK,
+        Arc::new(G2SubgroupCheckSyscall),
+    );
+    syscall_map.insert(SyscallCode::BLS12381_G2_MAP, Arc::new(G2MapSyscall));
+    syscall_map.insert(SyscallCode::BLS12381_G2_DECOMPRESS, Arc::new(G2DecompressSyscall));
+    syscall_map.insert(SyscallCode::BLS12381_MILLER_LOOP, Arc::new(MillerLoopSyscall));
+    syscall_map.insert(SyscallCode::BLS12381_FINAL_EXP, Arc::new(FinalExpSyscall));
+    syscall_map.insert(SyscallCode::BLS12381_PAIRING_CHECK, Arc::new(PairingCheckSyscall));
+
+    syscall_map.insert(SyscallCode::SECP256K1_RECOVER, Arc::new(Secp256k1RecoverSyscall));
+
+    syscall_map.insert(SyscallCode::BN254_ADD, Arc::new(Bn254AddSyscall));
+    syscall_map.insert(SyscallCode::BN254_DOUBLE, Arc::new(Bn254DoubleSyscall));
+    syscall_map.insert(SyscallCode::BN254_SCALAR_MUL, Arc::new(Bn254ScalarMulSyscall));
+    syscall_map.insert(SyscallCode::BN254_PAIRING_CHECK, Arc::new(Bn254PairingSyscall));
+    syscall_map.insert(SyscallCode::BN254_SCALAR_MAC, Arc::new(Bn254ScalarMacSyscall));
+    syscall_map.insert(SyscallCode::BN254_FR_OP, Arc::new(Bn254FrOpSyscall));
+
+    syscall_map.insert(SyscallCode::FP_OP, Arc::new(FpOpSyscall));
+
+    syscall_map.insert(SyscallCode::MTREE_VERIFY_PATH, Arc::new(MtreeVerifyPathSyscall));
+    syscall_map.insert(SyscallCode::MTREE_MERGE, Arc::new(MtreeMergeSyscall));
 
     syscall_map
 }
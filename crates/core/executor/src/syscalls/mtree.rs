@@ -0,0 +1,155 @@
+//! `MTREE_VERIFY_PATH`/`MTREE_MERGE`, a Merkle-tree precompile pair built on top of
+//! [`super::poseidon2`]'s width-8 permutation used as a 2-to-1 compression function: the left and
+//! right 4-word digests fill the low and high halves of the permutation state, and the low half
+//! of the output is the compressed digest.
+//!
+//! Sibling nodes for `MTREE_VERIFY_PATH` are supplied as non-deterministic advice through the
+//! hint stream (the same [`crate::state::ExecutionState::input_stream`] mechanism
+//! [`super::stdsys::ReadSyscall`]'s `FD_STDIN` branch reads from) rather than as a plain memory
+//! buffer, so the guest pays one syscall per level instead of hashing in pure Rust -- the prover
+//! supplies the path, the chip constrains that walking it from `leaf` actually produces
+//! `claimed_root`.
+
+use crate::events::{MtreeMergeEvent, MtreePathLevel, MtreeVerifyPathEvent, MTREE_DIGEST_WORDS};
+
+use super::{
+    context::SyscallContext,
+    poseidon2::poseidon2_permute,
+    Syscall, SyscallCode,
+};
+
+/// The maximum number of sibling levels one `MTREE_VERIFY_PATH` call supports; see
+/// [`crate::events::MTREE_MAX_DEPTH`] for why this is small and fixed rather than unbounded.
+pub use crate::events::MTREE_MAX_DEPTH;
+
+fn read_digest(ctx: &mut SyscallContext, addr: u32) -> [u32; MTREE_DIGEST_WORDS] {
+    core::array::from_fn(|i| ctx.rt.word(addr + (i as u32) * 4))
+}
+
+fn write_digest(ctx: &mut SyscallContext, addr: u32, digest: &[u32; MTREE_DIGEST_WORDS]) {
+    for (i, &word) in digest.iter().enumerate() {
+        ctx.rt.mw_cpu(addr + (i as u32) * 4, word, crate::events::MemoryAccessPosition::A);
+    }
+}
+
+/// The Merkle compression function: `left || right` fills a width-8 Poseidon2 state, which is
+/// permuted in place, and the digest is the low half of the result.
+pub(crate) fn compress(
+    left: &[u32; MTREE_DIGEST_WORDS],
+    right: &[u32; MTREE_DIGEST_WORDS],
+) -> [u32; MTREE_DIGEST_WORDS] {
+    let mut state = [0u64; 8];
+    for i in 0..MTREE_DIGEST_WORDS {
+        state[i] = u64::from(left[i]);
+        state[MTREE_DIGEST_WORDS + i] = u64::from(right[i]);
+    }
+    poseidon2_permute(&mut state);
+    core::array::from_fn(|i| state[i] as u32)
+}
+
+/// `mtree_verify_path(leaf_ptr, args_ptr)`: `args_ptr` points to three words `[index, depth,
+/// root_ptr]` -- the same "extra operands packed next to the pointer" convention
+/// [`super::fp_op::FpOpSyscall`] uses. Reads the leaf digest from `leaf_ptr` and the claimed root
+/// from `root_ptr`, then pulls `depth` sibling digests from the hint stream (one per level,
+/// leaf-to-root), folding them into a running digest via [`compress`] with `index`'s bits
+/// selecting left/right order at each level. Returns `1` in `a0` if the resulting root matches
+/// the claimed root, `0` otherwise; `depth > `[`MTREE_MAX_DEPTH`]` or an `index` that doesn't fit
+/// in `depth` bits is out of range and also returns `0` rather than faulting, since the guest is
+/// expected to treat "path doesn't verify" and "path is malformed" the same way.
+pub(crate) struct MtreeVerifyPathSyscall;
+impl Syscall for MtreeVerifyPathSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        leaf_ptr: u32,
+        args_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let leaf = read_digest(ctx, leaf_ptr);
+        let index = ctx.rt.word(args_ptr);
+        let depth = ctx.rt.word(args_ptr + 4);
+        let root_ptr = ctx.rt.word(args_ptr + 8);
+        let claimed_root = read_digest(ctx, root_ptr);
+
+        let out_of_range =
+            depth as usize > MTREE_MAX_DEPTH || (depth < 32 && (index >> depth) != 0);
+
+        let mut levels = Vec::new();
+        let mut digest = leaf;
+        if !out_of_range {
+            // `out_of_range` above already guarantees `depth as usize <= MTREE_MAX_DEPTH`, so
+            // this capacity is bounded regardless of the guest-supplied `depth` value -- unlike
+            // the `Vec::with_capacity(depth as usize)` this replaces, which ran before that
+            // check and let a malicious `depth` (e.g. `0xffff_ffff`) force a multi-gigabyte
+            // allocation attempt that aborts the process instead of failing gracefully.
+            levels.reserve_exact(depth as usize);
+            for level in 0..depth {
+                let Some(sibling_bytes) = ctx.rt.state.input_stream.get(ctx.rt.state.input_stream_ptr).cloned()
+                else {
+                    break;
+                };
+                ctx.rt.state.input_stream_ptr += 1;
+                let mut sibling = [0u32; MTREE_DIGEST_WORDS];
+                for (word, chunk) in sibling.iter_mut().zip(sibling_bytes.chunks(4)) {
+                    let mut word_bytes = [0u8; 4];
+                    word_bytes[..chunk.len()].copy_from_slice(chunk);
+                    *word = u32::from_le_bytes(word_bytes);
+                }
+
+                let sibling_on_left = (index >> level) & 1 == 1;
+                let input = digest;
+                let output = if sibling_on_left {
+                    compress(&sibling, &digest)
+                } else {
+                    compress(&digest, &sibling)
+                };
+                levels.push(MtreePathLevel { sibling, sibling_on_left, input, output });
+                digest = output;
+            }
+        }
+
+        let verified = !out_of_range && levels.len() == depth as usize && digest == claimed_root;
+
+        ctx.rt.record.mtree_verify_path_events.push(MtreeVerifyPathEvent {
+            shard: ctx.rt.shard(),
+            clk: ctx.rt.state.clk,
+            leaf,
+            index,
+            depth,
+            levels,
+            claimed_root,
+            computed_root: digest,
+            verified,
+        });
+
+        Some((u32::from(verified), 0))
+    }
+}
+
+/// `mtree_merge(left_ptr, right_ptr)`: writes `compress(left, right)` back over `left` in place,
+/// the same in-place-binary-op convention [`super::bn254::Bn254AddSyscall`] uses.
+pub(crate) struct MtreeMergeSyscall;
+impl Syscall for MtreeMergeSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        left_ptr: u32,
+        right_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let left = read_digest(ctx, left_ptr);
+        let right = read_digest(ctx, right_ptr);
+        let parent = compress(&left, &right);
+        write_digest(ctx, left_ptr, &parent);
+
+        ctx.rt.record.mtree_merge_events.push(MtreeMergeEvent {
+            shard: ctx.rt.shard(),
+            clk: ctx.rt.state.clk,
+            left,
+            right,
+            parent,
+        });
+
+        Some((0, 0))
+    }
+}
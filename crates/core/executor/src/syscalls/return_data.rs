@@ -0,0 +1,58 @@
+//! `SYSSETRETURNDATA`/`SYSGETRETURNDATA`: a bounded, length-delimited result channel, mirroring
+//! Solana's `sol_set_return_data`/`sol_get_return_data`. Distinct from [`crate::Executor::io_buf`]
+//! (the stdout/stderr `WRITE` stream), this is meant for one proven computation to hand a
+//! structured result to whatever consumes its proof next, without interleaving it with output
+//! meant for a human or log.
+
+use super::{context::SyscallContext, Syscall, SyscallCode};
+
+/// The largest return-data payload `SYSSETRETURNDATA` will store; a longer request is truncated,
+/// matching Solana's fixed `MAX_RETURN_DATA` cap.
+pub const MAX_RETURN_DATA_LEN: u32 = 1024;
+
+/// `set_return_data(ptr, len)`: copies up to [`MAX_RETURN_DATA_LEN`] bytes from `ptr` into
+/// [`crate::Executor::return_data`], replacing whatever was stored before. Returns the number of
+/// bytes actually stored in a0.
+pub(crate) struct SetReturnDataSyscall;
+impl Syscall for SetReturnDataSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        ptr: u32,
+        len: u32,
+    ) -> Option<(u32, u32)> {
+        let len = len.min(MAX_RETURN_DATA_LEN);
+        let mut data = Vec::with_capacity(len as usize);
+        for i in 0..len {
+            data.push(ctx.rt.byte(ptr + i));
+        }
+        ctx.rt.return_data = data;
+        Some((len, 0))
+    }
+}
+
+/// `get_return_data(out_ptr)`: copies the bytes stored by [`SetReturnDataSyscall`] to `out_ptr`,
+/// returning their length in a0 (`0` if nothing has been set).
+pub(crate) struct GetReturnDataSyscall;
+impl Syscall for GetReturnDataSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        out_ptr: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        let data = ctx.rt.return_data.clone();
+        for (i, chunk) in data.chunks(4).enumerate() {
+            let mut word = [0u8; 4];
+            word[..chunk.len()].copy_from_slice(chunk);
+            ctx.rt.mw_cpu(
+                out_ptr + (i as u32) * 4,
+                u32::from_le_bytes(word),
+                crate::events::MemoryAccessPosition::A,
+            );
+        }
+        Some((data.len() as u32, 0))
+    }
+}
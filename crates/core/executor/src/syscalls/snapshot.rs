@@ -0,0 +1,52 @@
+//! `SYSSNAPSHOT`/`SYSROLLBACK`, the guest-facing half of [`crate::snapshot`]'s nested
+//! transactional snapshot stack: a guest program (e.g. `revme-program` attempting an EVM call)
+//! opens a transactional region with `SYSSNAPSHOT`, and discards every memory write it made --
+//! the copy-on-write overlay in `mr`/`mw`, not a deep clone of the memory map -- by handing the
+//! returned id back to `SYSROLLBACK` on revert.
+
+use crate::snapshot::SnapshotId;
+
+use super::{context::SyscallContext, Syscall, SyscallCode};
+
+/// Errno-style fault: `id` doesn't identify a currently-open snapshot, the same
+/// reject-rather-than-panic convention `super::bls12381`'s `BLS12381_INVALID_POINT` uses for a
+/// guest-supplied value that would otherwise have to panic the host to reject.
+const ROLLBACK_INVALID_ID: u32 = 1;
+
+pub(crate) struct SnapshotSyscall;
+
+impl Syscall for SnapshotSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        _: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        let id = ctx.rt.snapshot();
+        Some((id.raw() as u32, 0))
+    }
+}
+
+pub(crate) struct RollbackSyscall;
+
+impl Syscall for RollbackSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        id: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        let id = SnapshotId::from_raw(id as usize);
+        // `Executor::rollback` asserts `id` identifies a currently-open snapshot -- fine for
+        // trusted host callers, but a guest can pass any `id` it likes (including calling
+        // `SYSROLLBACK` before ever calling `SYSSNAPSHOT`), so that assert must never be reached
+        // from here. Validate first and report the error back to the guest instead.
+        if !id.is_open(&ctx.rt.snapshot_stack) {
+            return Some((0xffff_ffff, ROLLBACK_INVALID_ID));
+        }
+        ctx.rt.rollback(id);
+        Some((0, 0))
+    }
+}
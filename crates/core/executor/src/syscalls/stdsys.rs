@@ -1,3 +1,5 @@
+use crate::trap::SyscallTrap;
+
 use super::{context::SyscallContext, Syscall, SyscallCode};
 
 pub const PAGE_ADDR_SIZE: usize = 12;
@@ -62,27 +64,57 @@ impl Syscall for BrkSyscall {
     }
 }
 
+/// `read(fd, descriptor_ptr)`: `descriptor_ptr` points to two words `[buf_ptr, max_len]`, the
+/// same "extra operand packed next to its pointer" convention
+/// [`super::mem_copy::MemCopySyscall`] uses since `Syscall::execute` only carries two operands.
+/// For `FD_STDIN`, copies the next queued entry of [`crate::state::ExecutionState::input_stream`]
+/// (the same buffer `ZKMStdin` feeds the guest through), truncated to `max_len`, into `buf_ptr`
+/// and returns the number of bytes copied in a0; an exhausted stream returns `0` (EOF), not an
+/// error. Any other fd returns `-1`/[`MIPS_EBADF`], unless a
+/// [`crate::context::ZKMContext::trap_handler`] is registered and handles it.
 pub(crate) struct ReadSyscall;
 impl Syscall for ReadSyscall {
     fn execute(
         &self,
-        _ctx: &mut SyscallContext,
-        _: SyscallCode,
-        a0: u32,
-        _: u32,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        fd: u32,
+        descriptor_ptr: u32,
     ) -> Option<(u32, u32)> {
-        let mut v0 = 0u32;
-        let mut v1 = 0u32;
-        match a0 {
+        match fd {
             FD_STDIN => {
-                // leave v0 and v1 zero: read nothing, no error
-            }
-            _ => {
-                v0 = 0xffffffff;
-                v1 = MIPS_EBADF;
+                let buf_ptr = ctx.rt.word(descriptor_ptr);
+                let max_len = ctx.rt.word(descriptor_ptr + 4);
+
+                let state = &mut ctx.rt.state;
+                let Some(mut bytes) = state.input_stream.get(state.input_stream_ptr).cloned()
+                else {
+                    return Some((0, 0));
+                };
+                state.input_stream_ptr += 1;
+                bytes.truncate(max_len as usize);
+
+                for (i, chunk) in bytes.chunks(4).enumerate() {
+                    let mut word_bytes = [0u8; 4];
+                    word_bytes[..chunk.len()].copy_from_slice(chunk);
+                    ctx.rt.mw_cpu(
+                        buf_ptr + (i as u32) * 4,
+                        u32::from_le_bytes(word_bytes),
+                        crate::events::MemoryAccessPosition::A,
+                    );
+                }
+                Some((bytes.len() as u32, 0))
             }
+            _ => match ctx.rt.trap_handler.clone() {
+                Some(handler) => {
+                    match handler.handle_bad_fd(syscall_code.syscall_id(), fd) {
+                        SyscallTrap::Handled(r0, r1) => Some((r0, r1)),
+                        SyscallTrap::Abort => Some((0xffffffff, MIPS_EBADF)),
+                    }
+                }
+                None => Some((0xffffffff, MIPS_EBADF)),
+            },
         }
-        Some((v0, v1))
     }
 }
 
@@ -128,6 +160,49 @@ impl Syscall for FcntlSyscall {
     }
 }
 
+pub(crate) struct OpenSyscall;
+impl Syscall for OpenSyscall {
+    fn execute(
+        &self,
+        _ctx: &mut SyscallContext,
+        _: SyscallCode,
+        _name_ptr: u32,
+        _flags: u32,
+    ) -> Option<(u32, u32)> {
+        // No generic filesystem is modeled; `SYSBUNDLEOPEN` is the only way to open a file.
+        const MIPS_ENOENT: u32 = 2;
+        Some((0xffff_ffff, MIPS_ENOENT))
+    }
+}
+
+pub(crate) struct CloseSyscall;
+impl Syscall for CloseSyscall {
+    fn execute(
+        &self,
+        _ctx: &mut SyscallContext,
+        _: SyscallCode,
+        _fd: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        Some((0, 0))
+    }
+}
+
+pub(crate) struct YieldSyscall;
+impl Syscall for YieldSyscall {
+    fn execute(
+        &self,
+        _ctx: &mut SyscallContext,
+        _: SyscallCode,
+        _: u32,
+        _: u32,
+    ) -> Option<(u32, u32)> {
+        // A single guest thread runs to completion with nothing else runnable, so yielding has
+        // nothing to hand control to; just report success.
+        Some((0, 0))
+    }
+}
+
 pub(crate) struct SetThreadAreaSyscall;
 impl Syscall for SetThreadAreaSyscall {
     fn execute(
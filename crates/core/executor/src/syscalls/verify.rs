@@ -0,0 +1,37 @@
+//! `SYSVERIFY`: records a deferred proof claim so the recursion stage can later verify the
+//! claimed child zkMIPS proof and fold its vkey/public-values digest pair into the running
+//! deferred-digest accumulator -- see [`crate::events::DeferredProofClaimEvent`].
+
+use crate::events::DeferredProofClaimEvent;
+
+use super::{context::SyscallContext, Syscall, SyscallCode};
+
+const DIGEST_WORDS: usize = crate::events::DEFERRED_PROOF_DIGEST_SIZE;
+
+pub(crate) struct VerifySyscall;
+
+impl Syscall for VerifySyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        _: SyscallCode,
+        vkey_digest_ptr: u32,
+        committed_value_digest_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        let mut vkey_digest = [0u32; DIGEST_WORDS];
+        let mut committed_value_digest = [0u32; DIGEST_WORDS];
+        for i in 0..DIGEST_WORDS {
+            vkey_digest[i] = ctx.rt.word(vkey_digest_ptr + (i as u32) * 4);
+            committed_value_digest[i] = ctx.rt.word(committed_value_digest_ptr + (i as u32) * 4);
+        }
+
+        ctx.rt.record.deferred_proof_claims.push(DeferredProofClaimEvent {
+            shard: ctx.rt.shard(),
+            clk: ctx.rt.state.clk,
+            vkey_digest,
+            committed_value_digest,
+        });
+
+        Some((0, 0))
+    }
+}
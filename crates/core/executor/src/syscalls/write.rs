@@ -0,0 +1,57 @@
+//! `SYSWRITE`: a functional `write(fd, descriptor_ptr)` for `FD_STDOUT`/`FD_STDERR`, appending
+//! into [`crate::Executor::io_buf`] -- the same buffer `Executor::postprocess` flushes at the end
+//! of a run -- so host or test code gets deterministic, capturable guest output instead of only a
+//! printed line.
+
+use crate::trap::SyscallTrap;
+
+use super::stdsys::{FD_STDERR, FD_STDOUT, MIPS_EBADF};
+use super::{context::SyscallContext, Syscall, SyscallCode};
+
+/// Upper bound on `len` for [`WriteSyscall`], the same guest-controlled-length-clamp convention
+/// [`super::mem_copy::MAX_MEM_COPY_WORDS`] and [`super::return_data::MAX_RETURN_DATA_LEN`] use --
+/// without it a malicious `len` could force `Vec::with_capacity` to attempt a multi-gigabyte
+/// allocation and abort the process rather than just truncating the write.
+pub const MAX_WRITE_LEN: u32 = 1 << 16;
+
+/// `write(fd, descriptor_ptr)`: `descriptor_ptr` points to two words `[buf_ptr, len]`, the same
+/// "extra operand packed next to its pointer" convention [`super::mem_copy::MemCopySyscall`] uses
+/// since `Syscall::execute` only carries two operands. Appends `len` bytes read from `buf_ptr` to
+/// `fd`'s entry in [`crate::Executor::io_buf`] and returns the number of bytes written in a0.
+/// Only `FD_STDOUT`/`FD_STDERR` are modeled; any other fd returns `-1`/[`MIPS_EBADF`], unless a
+/// [`crate::context::ZKMContext::trap_handler`] is registered and handles it.
+pub(crate) struct WriteSyscall;
+impl Syscall for WriteSyscall {
+    fn execute(
+        &self,
+        ctx: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        fd: u32,
+        descriptor_ptr: u32,
+    ) -> Option<(u32, u32)> {
+        match fd {
+            FD_STDOUT | FD_STDERR => {
+                let buf_ptr = ctx.rt.word(descriptor_ptr);
+                let len = ctx.rt.word(descriptor_ptr + 4).min(MAX_WRITE_LEN);
+
+                let mut bytes = Vec::with_capacity(len as usize);
+                for i in 0..len {
+                    bytes.push(ctx.rt.byte(buf_ptr + i));
+                }
+                let text = String::from_utf8_lossy(&bytes);
+                ctx.rt.io_buf.entry(fd).or_default().push_str(&text);
+
+                Some((len, 0))
+            }
+            _ => match ctx.rt.trap_handler.clone() {
+                Some(handler) => {
+                    match handler.handle_bad_fd(syscall_code.syscall_id(), fd) {
+                        SyscallTrap::Handled(r0, r1) => Some((r0, r1)),
+                        SyscallTrap::Abort => Some((0xffffffff, MIPS_EBADF)),
+                    }
+                }
+                None => Some((0xffffffff, MIPS_EBADF)),
+            },
+        }
+    }
+}
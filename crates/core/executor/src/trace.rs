@@ -0,0 +1,112 @@
+//! A pluggable sink for the [`crate::Executor`]'s opt-in instruction trace: a disassembly line
+//! plus the register delta each executed instruction produced, e.g.
+//! `0x00400000: sll $v0, $a0, 7   v0: 0x00000001 -> 0x00000080`.
+//!
+//! This is a finer-grained companion to [`crate::Executor::verbose_trace`] (disasm only, always
+//! to `log::info!`): it's meant for replaying one specific failing test with the exact state
+//! transition that produced a wrong register value visible, so the sink is pluggable (stderr, a
+//! file, or a `Vec<u8>` captured in a test) instead of hardcoded to the logger, and ANSI styling
+//! is opt-in separately so a file capture stays plain text.
+
+use std::io::Write;
+
+use crate::{disasm, Instruction};
+
+/// Where an instruction trace line goes. Blanket-implemented for anything [`Write`], so stderr, a
+/// file, or an in-memory `Vec<u8>` (for capturing the trace in a test) all work without a
+/// dedicated adapter.
+pub trait TraceSink: Send {
+    /// Appends one trace line. Implementations are responsible for their own line separator.
+    fn write_line(&mut self, line: &str);
+}
+
+impl<W: Write + Send> TraceSink for W {
+    fn write_line(&mut self, line: &str) {
+        let _ = writeln!(self, "{line}");
+    }
+}
+
+/// A register changing value as the side effect of one executed instruction.
+#[derive(Debug, Clone, Copy)]
+pub struct RegisterDelta {
+    /// The register index (0..32, plus the synthetic `hi`/`lo` indices 33/32).
+    pub register: u32,
+    /// The register's value immediately before the instruction executed.
+    pub before: u32,
+    /// The register's value immediately after.
+    pub after: u32,
+}
+
+/// The register `instruction` writes its result to, if any -- `None` for stores, branches,
+/// jumps, and syscalls, which don't have a single result register in the same sense.
+#[must_use]
+pub fn result_register(instruction: &Instruction) -> Option<u8> {
+    if instruction.is_alu_instruction() {
+        return Some(instruction.op_a);
+    }
+    if instruction.is_memory_instruction() {
+        use crate::Opcode::{LB, LBU, LH, LHU, LL, LW, LWL, LWR, SC};
+        return matches!(instruction.opcode, LB | LH | LWL | LW | LBU | LHU | LWR | LL | SC)
+            .then_some(instruction.op_a);
+    }
+    None
+}
+
+/// An ANSI color, used to theme an instruction trace line for terminal debugging.
+#[derive(Debug, Clone, Copy)]
+enum Style {
+    Mnemonic,
+    Register,
+    Immediate,
+    Arrow,
+}
+
+impl Style {
+    const fn code(self) -> &'static str {
+        match self {
+            Style::Mnemonic => "\x1b[33m",  // yellow
+            Style::Register => "\x1b[36m",  // cyan
+            Style::Immediate => "\x1b[35m", // magenta
+            Style::Arrow => "\x1b[90m",     // bright black
+        }
+    }
+}
+
+fn paint(color: bool, style: Style, text: &str) -> String {
+    if color {
+        format!("{}{text}\x1b[0m", style.code())
+    } else {
+        text.to_string()
+    }
+}
+
+/// Formats one instruction trace line: `pc: disasm   reg: before -> after`, or just `pc: disasm`
+/// when the instruction didn't write a result register (`delta` is `None`).
+#[must_use]
+pub fn format_trace_line(
+    pc: u32,
+    instruction: &Instruction,
+    delta: Option<RegisterDelta>,
+    color: bool,
+) -> String {
+    let disasm = paint(
+        color,
+        Style::Mnemonic,
+        &disasm::disassemble_instruction(instruction, pc),
+    );
+    let mut line = format!("{}: {disasm}", paint(color, Style::Immediate, &format!("{pc:#06x}")));
+    if let Some(delta) = delta {
+        let name = disasm::ABI_REGISTER_NAMES
+            .get(delta.register as usize)
+            .copied()
+            .unwrap_or(if delta.register == 32 { "lo" } else { "hi" });
+        line.push_str(&format!(
+            "   {}: {} {} {}",
+            paint(color, Style::Register, name),
+            paint(color, Style::Immediate, &format!("{:#010x}", delta.before)),
+            paint(color, Style::Arrow, "->"),
+            paint(color, Style::Immediate, &format!("{:#010x}", delta.after))
+        ));
+    }
+    line
+}
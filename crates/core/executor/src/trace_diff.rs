@@ -0,0 +1,125 @@
+//! Side-by-side divergence view for comparing two instruction traces, e.g. two runs expected to
+//! be identical, or an expected vs. actual execution, so a reviewer can spot exactly where
+//! execution diverges instead of diffing two long linear listings by eye.
+//!
+//! Reuses [`Instruction::format_program`]'s approach of measuring content before padding (see
+//! that function's doc comment), applied here per-row so each side's column stays a fixed, sane
+//! width regardless of how long an individual mnemonic or operand gets, and lines the two sides
+//! up side by side the way `delta` does in side-by-side mode: when a row's rendered line would
+//! overflow the column budget, it's wrapped onto continuation lines (bounded by
+//! [`DiffConfig::max_wrapped_lines`]) rather than truncated, and alignment is kept stable across
+//! wrapped and unwrapped rows so divergence points stay visually obvious.
+
+use crate::Instruction;
+
+/// Configuration for [`format_trace_diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffConfig {
+    /// Maximum rendered width of a single side's column before wrapping.
+    pub column_width: usize,
+    /// Maximum number of continuation lines a single overflowing row may wrap onto; anything
+    /// past this is left on the final line rather than growing the output without bound.
+    pub max_wrapped_lines: usize,
+}
+
+impl Default for DiffConfig {
+    fn default() -> Self {
+        Self { column_width: 32, max_wrapped_lines: 3 }
+    }
+}
+
+/// One aligned row of a trace diff: the left/right instructions' rendered text, each already
+/// wrapped to `config.column_width` (one `String` per line), and whether the row diverges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffRow {
+    /// `left`'s wrapped lines, or empty if `left`'s trace ran out of instructions first.
+    pub left_lines: Vec<String>,
+    /// `right`'s wrapped lines, or empty if `right`'s trace ran out of instructions first.
+    pub right_lines: Vec<String>,
+    /// Whether `left` and `right` differ at this row, including either side being absent while
+    /// the other still has instructions (a length mismatch is itself a divergence).
+    pub diverges: bool,
+}
+
+/// Two instructions differ if their mnemonic or any operand does -- the immediate-vs-register
+/// tags (`imm_b`/`imm_c`) are included, since the same bit pattern means something different
+/// depending on them.
+fn instructions_differ(left: &Instruction, right: &Instruction) -> bool {
+    left.opcode != right.opcode
+        || left.op_a != right.op_a
+        || left.op_b != right.op_b
+        || left.op_c != right.op_c
+        || left.imm_b != right.imm_b
+        || left.imm_c != right.imm_c
+}
+
+/// Wraps `line` onto multiple `width`-wide lines, bounded by `max_lines`; the final line carries
+/// whatever's left over even if it still overflows `width`, rather than silently dropping it.
+fn wrap_line(line: &str, width: usize, max_lines: usize) -> Vec<String> {
+    if width == 0 || max_lines == 0 || line.len() <= width {
+        return vec![line.to_string()];
+    }
+    let mut lines = Vec::new();
+    let mut rest = line;
+    while lines.len() + 1 < max_lines && rest.len() > width {
+        let (head, tail) = rest.split_at(width);
+        lines.push(head.to_string());
+        rest = tail;
+    }
+    lines.push(rest.to_string());
+    lines
+}
+
+/// Builds the aligned, wrapped rows [`format_trace_diff`] renders; exposed separately so callers
+/// that want structured divergence data (e.g. to highlight rows in a UI) don't have to re-parse
+/// the formatted string.
+#[must_use]
+pub fn diff_rows(left: &[Instruction], right: &[Instruction], config: &DiffConfig) -> Vec<DiffRow> {
+    let len = left.len().max(right.len());
+    (0..len)
+        .map(|i| {
+            let left_instr = left.get(i);
+            let right_instr = right.get(i);
+            let diverges = match (left_instr, right_instr) {
+                (Some(l), Some(r)) => instructions_differ(l, r),
+                _ => true,
+            };
+            let left_lines = left_instr
+                .map(|instr| {
+                    wrap_line(&instr.to_string(), config.column_width, config.max_wrapped_lines)
+                })
+                .unwrap_or_default();
+            let right_lines = right_instr
+                .map(|instr| {
+                    wrap_line(&instr.to_string(), config.column_width, config.max_wrapped_lines)
+                })
+                .unwrap_or_default();
+            DiffRow { left_lines, right_lines, diverges }
+        })
+        .collect()
+}
+
+/// Renders `left` and `right` as a side-by-side divergence view: two aligned, `config`-sized
+/// columns separated by `|`, with a `*` gutter marker on rows that diverge so they stand out at a
+/// glance in a long trace.
+#[must_use]
+pub fn format_trace_diff(left: &[Instruction], right: &[Instruction], config: &DiffConfig) -> String {
+    let width = config.column_width;
+    diff_rows(left, right, config)
+        .iter()
+        .map(|row| {
+            let wrapped_len = row.left_lines.len().max(row.right_lines.len()).max(1);
+            (0..wrapped_len)
+                .map(|line_idx| {
+                    let left_line = row.left_lines.get(line_idx).map(String::as_str).unwrap_or("");
+                    let right_line =
+                        row.right_lines.get(line_idx).map(String::as_str).unwrap_or("");
+                    let gutter = if line_idx == 0 && row.diverges { "*" } else { " " };
+                    format!("{gutter} {left_line:<width$} | {right_line:<width$}")
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,37 @@
+//! A pluggable hook for the things syscall dispatch used to always hard-abort on: an
+//! unrecognized [`crate::syscalls::SyscallCode`], or a file descriptor a recognized syscall (e.g.
+//! [`crate::syscalls::ReadSyscall`]/[`crate::syscalls::WriteSyscall`]) doesn't model. Registered
+//! via [`crate::context::ZKMContext::trap_handler`]; with none registered, behavior is unchanged
+//! from before this hook existed.
+
+/// The outcome of a [`TrapHandler`] call.
+#[derive(Debug, Clone, Copy)]
+pub enum SyscallTrap {
+    /// Run as if the syscall itself had returned these `(a0, a1)` values.
+    Handled(u32, u32),
+    /// Abort the same way as if no handler were registered at all (an unrecognized syscall
+    /// raises [`crate::ExecutionError::UnsupportedSyscall`]; a bad fd returns `-1`/`MIPS_EBADF`).
+    Abort,
+}
+
+/// A host-provided policy for syscalls the built-in [`crate::syscalls::default_syscall_map`]
+/// doesn't recognize, or a bad file descriptor passed to one that is. The default impls both
+/// return [`SyscallTrap::Abort`], preserving the legacy behavior, so installing a
+/// [`crate::context::ZKMContext::trap_handler`] that only overrides one of the two methods is
+/// safe.
+pub trait TrapHandler: Send + Sync {
+    /// Called with the raw syscall id (the `V0` register value) and its two argument registers
+    /// when no handler is registered in [`crate::Executor::syscall_map`] for it.
+    fn handle_unsupported_syscall(&self, syscall_id: u32, arg1: u32, arg2: u32) -> SyscallTrap {
+        let _ = (syscall_id, arg1, arg2);
+        SyscallTrap::Abort
+    }
+
+    /// Called by a recognized syscall's own handler when given a file descriptor it doesn't
+    /// model, in place of the legacy hardcoded `-1`/`MIPS_EBADF` return. `syscall_id` identifies
+    /// which syscall hit the bad fd (see [`crate::syscalls::SyscallCode::syscall_id`]).
+    fn handle_bad_fd(&self, syscall_id: u32, fd: u32) -> SyscallTrap {
+        let _ = (syscall_id, fd);
+        SyscallTrap::Abort
+    }
+}
@@ -0,0 +1,75 @@
+//! `ufmt`-based rendering of [`Instruction`], for guest code that wants to print disassembly
+//! without dragging in `core::fmt`'s panicking, recursion-heavy machinery -- valuable when this
+//! code is compiled into the proven program itself, where panic paths and formatting bloat both
+//! translate into extra proving cost.
+//!
+//! This crate isn't `no_std` as a whole (see [`crate::disasm`]'s `std::fmt` based renderers, used
+//! host-side), but [`uDisplay`] doesn't require that: it's implemented directly against `ufmt`'s
+//! `uWrite` sink, with no allocation and no panicking path, so it works unmodified whether the
+//! caller's own crate is `std` or `no_std`.
+//!
+//! This crate's `[features]` table (an optional `ufmt` feature) belongs in its `Cargo.toml`,
+//! which isn't present in this checkout -- see the workspace root for why no manifest is added
+//! here. The `#[cfg(feature = "ufmt")]` gate on this module's declaration in `lib.rs` is written
+//! as if that table exists.
+
+use ufmt::{uDisplay, uWrite, uwrite, Formatter};
+
+use crate::Instruction;
+
+/// Writes `value` in decimal to `w`, most significant digit first, by repeated division rather
+/// than through `core::fmt` -- `ufmt`'s own integer impls do the same, but `Instruction`'s
+/// operands need a two's-complement-signed rendering `ufmt`'s unsigned helpers don't give us.
+fn write_signed_decimal<W: uWrite + ?Sized>(w: &mut W, value: i32) -> Result<(), W::Error> {
+    if value == i32::MIN {
+        // The one value whose absolute magnitude doesn't fit back in an i32 (`-value` overflows);
+        // spell it out directly rather than special-casing the digit loop below for it.
+        return w.write_str("-2147483648");
+    }
+    let mut magnitude = value.unsigned_abs();
+    if value < 0 {
+        w.write_char('-')?;
+    }
+    // Collect digits least-significant-first into a fixed buffer (ten decimal digits is the most
+    // a u32 ever needs), then walk it backwards, since we only learn each digit starting from the
+    // low end.
+    let mut digits = [0u8; 10];
+    let mut len = 0;
+    loop {
+        digits[len] = b'0' + (magnitude % 10) as u8;
+        magnitude /= 10;
+        len += 1;
+        if magnitude == 0 {
+            break;
+        }
+    }
+    for &digit in digits[..len].iter().rev() {
+        w.write_char(digit as char)?;
+    }
+    Ok(())
+}
+
+impl uDisplay for Instruction {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        uwrite!(f, "{}", self.opcode.mnemonic())?;
+        f.write_str(" $")?;
+        uwrite!(f, "{}", self.op_a)?;
+        f.write_str(", ")?;
+        if self.imm_b {
+            write_signed_decimal(f, self.op_b as i32)?;
+        } else {
+            f.write_str("$")?;
+            uwrite!(f, "{}", self.op_b)?;
+        }
+        f.write_str(", ")?;
+        if self.imm_c {
+            write_signed_decimal(f, self.op_c as i32)
+        } else {
+            f.write_str("$")?;
+            uwrite!(f, "{}", self.op_c)
+        }
+    }
+}
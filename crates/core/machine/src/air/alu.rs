@@ -0,0 +1,124 @@
+use std::iter::once;
+
+use p3_air::AirBuilder;
+use zkm2_stark::{
+    air::{AirLookup, BaseAirBuilder, LookupScope},
+    LookupKind, Word,
+};
+
+/// A trait which contains methods related to ALU result lookups in an AIR.
+///
+/// Every `send_alu`/`send_alu_with_hi` call site (the CPU's branch comparisons, the
+/// misc-instruction sub-operations, the `InsertBitFieldOperation` helper, the BN254 scalar
+/// multiply-accumulate chip, ...) borrows an ALU result computed elsewhere instead of
+/// recomputing it, by sending a `(opcode, a, b, c, shard)` tuple that the owning `add_sub`/`mul`/
+/// `divrem`/`lt`/`sll`/`sr`/`bitwise`/`clo_clz` chip is expected to receive. Keyed on that tuple
+/// alone, two distinct issuers sharing identical operands in the same shard would fold into one
+/// proven row with a multiplicity of two -- sound for a *count*, but it lets either issuer's claim
+/// be satisfied by the other's row, which is not what either caller intended. `nonce` (the row
+/// index within the sending chip, already threaded through by every call site above) makes each
+/// message unique so the interaction can only be satisfied by the matching row.
+///
+/// The receiving side -- the core ALU chips' own `receive_alu`/`receive_alu_with_hi` calls --
+/// isn't wired up in this tree yet, since none of `add_sub`, `divrem`, `lt`, `sll`, `sr`,
+/// `bitwise`, or `clo_clz` have a source file here to add it to (only
+/// [`crate::alu::mul::MulChip`] exists, and it participates via `receive_instruction` directly
+/// rather than this bus).
+///
+/// [`crate::alu::mul::MulCols::nonce`] adds the same row-disambiguation idea to that
+/// `receive_instruction` bus, for the one ALU-family chip this tree can actually wire it up for --
+/// see its doc comment for why that bus doesn't have this tuple-collision problem the way this one
+/// does, and what's still missing before any substitution attack across chips is actually caught.
+pub trait AluAirBuilder: BaseAirBuilder {
+    /// Sends an ALU operation to be processed.
+    fn send_alu(
+        &mut self,
+        opcode: impl Into<Self::Expr>,
+        a: Word<impl Into<Self::Expr> + Copy>,
+        b: Word<impl Into<Self::Expr> + Copy>,
+        c: Word<impl Into<Self::Expr> + Copy>,
+        shard: impl Into<Self::Expr>,
+        nonce: impl Into<Self::Expr>,
+        multiplicity: impl Into<Self::Expr>,
+    ) {
+        let values = once(opcode.into())
+            .chain(a.into_iter().map(Into::into))
+            .chain(b.into_iter().map(Into::into))
+            .chain(c.into_iter().map(Into::into))
+            .chain(once(shard.into()))
+            .chain(once(nonce.into()))
+            .collect();
+
+        self.send(AirLookup::new(values, multiplicity.into(), LookupKind::Alu), LookupScope::Local);
+    }
+
+    /// Receives an ALU operation.
+    fn receive_alu(
+        &mut self,
+        opcode: impl Into<Self::Expr>,
+        a: Word<impl Into<Self::Expr> + Copy>,
+        b: Word<impl Into<Self::Expr> + Copy>,
+        c: Word<impl Into<Self::Expr> + Copy>,
+        shard: impl Into<Self::Expr>,
+        nonce: impl Into<Self::Expr>,
+        multiplicity: impl Into<Self::Expr>,
+    ) {
+        let values: Vec<<Self as AirBuilder>::Expr> = once(opcode.into())
+            .chain(a.into_iter().map(Into::into))
+            .chain(b.into_iter().map(Into::into))
+            .chain(c.into_iter().map(Into::into))
+            .chain(once(shard.into()))
+            .chain(once(nonce.into()))
+            .collect();
+
+        self.receive(AirLookup::new(values, multiplicity.into(), LookupKind::Alu), LookupScope::Local);
+    }
+
+    /// Sends an ALU operation that also produces a high word (`MULT`/`MULTU`/`DIV`/`DIVU`-style).
+    fn send_alu_with_hi(
+        &mut self,
+        opcode: impl Into<Self::Expr>,
+        a: Word<impl Into<Self::Expr> + Copy>,
+        b: Word<impl Into<Self::Expr> + Copy>,
+        c: Word<impl Into<Self::Expr> + Copy>,
+        hi: Word<impl Into<Self::Expr> + Copy>,
+        shard: impl Into<Self::Expr>,
+        nonce: impl Into<Self::Expr>,
+        multiplicity: impl Into<Self::Expr>,
+    ) {
+        let values = once(opcode.into())
+            .chain(a.into_iter().map(Into::into))
+            .chain(b.into_iter().map(Into::into))
+            .chain(c.into_iter().map(Into::into))
+            .chain(hi.into_iter().map(Into::into))
+            .chain(once(shard.into()))
+            .chain(once(nonce.into()))
+            .collect();
+
+        self.send(AirLookup::new(values, multiplicity.into(), LookupKind::Alu), LookupScope::Local);
+    }
+
+    /// Receives an ALU operation that also produces a high word.
+    fn receive_alu_with_hi(
+        &mut self,
+        opcode: impl Into<Self::Expr>,
+        a: Word<impl Into<Self::Expr> + Copy>,
+        b: Word<impl Into<Self::Expr> + Copy>,
+        c: Word<impl Into<Self::Expr> + Copy>,
+        hi: Word<impl Into<Self::Expr> + Copy>,
+        shard: impl Into<Self::Expr>,
+        nonce: impl Into<Self::Expr>,
+        multiplicity: impl Into<Self::Expr>,
+    ) {
+        let values: Vec<<Self as AirBuilder>::Expr> = once(opcode.into())
+            .chain(a.into_iter().map(Into::into))
+            .chain(b.into_iter().map(Into::into))
+            .chain(c.into_iter().map(Into::into))
+            .chain(hi.into_iter().map(Into::into))
+            .chain(once(shard.into()))
+            .chain(once(nonce.into()))
+            .collect();
+
+        self.receive(AirLookup::new(values, multiplicity.into(), LookupKind::Alu), LookupScope::Local);
+    }
+}
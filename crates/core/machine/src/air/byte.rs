@@ -0,0 +1,101 @@
+use std::iter::once;
+
+use p3_air::AirBuilder;
+use p3_field::FieldAlgebra;
+use zkm2_stark::{
+    air::{AirLookup, BaseAirBuilder, LookupScope},
+    LookupKind,
+};
+
+/// A trait which contains methods related to byte lookups in an AIR.
+///
+/// `send_byte`/`send_byte_pair` are already called throughout the ALU/misc chips (e.g.
+/// [`crate::alu::mul`], [`crate::operations::not`], [`crate::misc::air`]) to interact with a byte
+/// table, but until now nothing implemented the receiving half -- this is the other end of that
+/// lookup, intended for a chip that owns the `(opcode, b, c) -> a` table itself.
+pub trait ByteAirBuilder: BaseAirBuilder {
+    /// Sends a byte operation to be processed.
+    fn send_byte(
+        &mut self,
+        opcode: impl Into<Self::Expr>,
+        a: impl Into<Self::Expr>,
+        b: impl Into<Self::Expr>,
+        c: impl Into<Self::Expr>,
+        multiplicity: impl Into<Self::Expr>,
+    ) {
+        self.send_byte_pair(
+            opcode,
+            a,
+            Self::Expr::ZERO,
+            b,
+            c,
+            multiplicity,
+        );
+    }
+
+    /// Sends two byte operations to be processed, sharing the same opcode.
+    fn send_byte_pair(
+        &mut self,
+        opcode: impl Into<Self::Expr>,
+        a1: impl Into<Self::Expr>,
+        a2: impl Into<Self::Expr>,
+        b: impl Into<Self::Expr>,
+        c: impl Into<Self::Expr>,
+        multiplicity: impl Into<Self::Expr>,
+    ) {
+        let values = once(opcode.into())
+            .chain(once(a1.into()))
+            .chain(once(a2.into()))
+            .chain(once(b.into()))
+            .chain(once(c.into()))
+            .collect();
+
+        self.send(
+            AirLookup::new(values, multiplicity.into(), LookupKind::Byte),
+            LookupScope::Local,
+        );
+    }
+
+    /// Receives a byte operation from the table that owns the `(opcode, b, c) -> (a1, a2)` map.
+    fn receive_byte(
+        &mut self,
+        opcode: impl Into<Self::Expr>,
+        a: impl Into<Self::Expr>,
+        b: impl Into<Self::Expr>,
+        c: impl Into<Self::Expr>,
+        multiplicity: impl Into<Self::Expr>,
+    ) {
+        self.receive_byte_pair(
+            opcode,
+            a,
+            Self::Expr::ZERO,
+            b,
+            c,
+            multiplicity,
+        );
+    }
+
+    /// Receives two byte operations from the table that owns the `(opcode, b, c) -> (a1, a2)`
+    /// map, sharing the same opcode.
+    fn receive_byte_pair(
+        &mut self,
+        opcode: impl Into<Self::Expr>,
+        a1: impl Into<Self::Expr>,
+        a2: impl Into<Self::Expr>,
+        b: impl Into<Self::Expr>,
+        c: impl Into<Self::Expr>,
+        multiplicity: impl Into<Self::Expr>,
+    ) {
+        let values: Vec<<Self as AirBuilder>::Expr> = once(opcode.into())
+            .chain(once(a1.into()))
+            .chain(once(a2.into()))
+            .chain(once(b.into()))
+            .chain(once(c.into()))
+            .collect();
+
+        self.receive(
+            AirLookup::new(values, multiplicity.into(), LookupKind::Byte),
+            LookupScope::Local,
+        );
+    }
+}
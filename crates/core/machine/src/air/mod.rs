@@ -1,9 +1,15 @@
+mod alu;
+mod byte;
 mod memory;
 mod program;
+mod trap;
 mod word;
 
+pub use alu::*;
+pub use byte::*;
 pub use memory::*;
 pub use program::*;
+pub use trap::*;
 pub use word::*;
 
 use zkm_stark::air::{BaseAirBuilder, ZKMAirBuilder};
@@ -11,11 +17,20 @@ use zkm_stark::air::{BaseAirBuilder, ZKMAirBuilder};
 /// A trait which contains methods related to memory lookups in an AIR.
 ///
 pub trait ZKMCoreAirBuilder:
-    ZKMAirBuilder + WordAirBuilder + MemoryAirBuilder + ProgramAirBuilder
+    ZKMAirBuilder
+    + WordAirBuilder
+    + MemoryAirBuilder
+    + ProgramAirBuilder
+    + ByteAirBuilder
+    + AluAirBuilder
+    + TrapAirBuilder
 {
 }
 
 impl<AB: BaseAirBuilder> MemoryAirBuilder for AB {}
 impl<AB: BaseAirBuilder> ProgramAirBuilder for AB {}
 impl<AB: BaseAirBuilder> WordAirBuilder for AB {}
+impl<AB: BaseAirBuilder> ByteAirBuilder for AB {}
+impl<AB: BaseAirBuilder> AluAirBuilder for AB {}
+impl<AB: BaseAirBuilder> TrapAirBuilder for AB {}
 impl<AB: BaseAirBuilder + ZKMAirBuilder> ZKMCoreAirBuilder for AB {}
@@ -9,6 +9,14 @@ use zkm2_stark::{
 use crate::cpu::columns::{InstructionCols, OpcodeSelectorCols};
 
 /// A trait which contains methods related to program lookups in an AIR.
+///
+/// Keyed on `(pc, instruction, selectors, shard)` alone, two distinct program-fetch events that
+/// happen to share identical payloads (the same instruction fetched at the same `pc` in the same
+/// shard, which does happen across repeated loop iterations) would fold into one proven row with a
+/// multiplicity greater than one -- sound for a *count*, but it lets any one of those issuers'
+/// claims be satisfied by any other's row. `nonce` (the row index within the sending chip) makes
+/// each message unique so the interaction can only be satisfied by the matching row, the same fix
+/// SP1 applied to its ALU/program interactions (see [`super::alu::AluAirBuilder`]'s doc comment).
 pub trait ProgramAirBuilder: BaseAirBuilder {
     /// Sends an instruction.
     fn send_program(
@@ -17,6 +25,7 @@ pub trait ProgramAirBuilder: BaseAirBuilder {
         instruction: InstructionCols<impl Into<Self::Expr> + Copy>,
         selectors: OpcodeSelectorCols<impl Into<Self::Expr> + Copy>,
         shard: impl Into<Self::Expr> + Copy,
+        nonce: impl Into<Self::Expr>,
         multiplicity: impl Into<Self::Expr>,
     ) {
         let values = once(pc.into())
@@ -24,6 +33,7 @@ pub trait ProgramAirBuilder: BaseAirBuilder {
             .chain(instruction.into_iter().map(|x| x.into()))
             .chain(selectors.into_iter().map(|x| x.into()))
             .chain(once(shard.into()))
+            .chain(once(nonce.into()))
             .collect();
 
         self.send(
@@ -39,6 +49,7 @@ pub trait ProgramAirBuilder: BaseAirBuilder {
         instruction: InstructionCols<impl Into<Self::Expr> + Copy>,
         selectors: OpcodeSelectorCols<impl Into<Self::Expr> + Copy>,
         shard: impl Into<Self::Expr> + Copy,
+        nonce: impl Into<Self::Expr>,
         multiplicity: impl Into<Self::Expr>,
     ) {
         let values: Vec<<Self as AirBuilder>::Expr> = once(pc.into())
@@ -46,6 +57,7 @@ pub trait ProgramAirBuilder: BaseAirBuilder {
             .chain(instruction.into_iter().map(|x| x.into()))
             .chain(selectors.into_iter().map(|x| x.into()))
             .chain(once(shard.into()))
+            .chain(once(nonce.into()))
             .collect();
 
         self.receive(
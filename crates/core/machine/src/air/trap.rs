@@ -0,0 +1,64 @@
+use std::iter::once;
+
+use p3_air::AirBuilder;
+use zkm_stark::{
+    air::{AirLookup, BaseAirBuilder, LookupScope},
+    LookupKind, Word,
+};
+
+/// A trait which contains methods related to CP0 trap/exception lookups in an AIR.
+///
+/// The CPU chip is expected to `send_trap` whenever the instruction it's dispatching faults (a
+/// trapping `TADD`/`TSUB` overflow, an unaligned load/store address, ...), and
+/// [`crate::trap::TrapChip`] `receive_trap`s the matching `(cause, pc, bad_vaddr, shard)` tuple to
+/// constrain that the recorded [`zkm_core_executor::events::TrapEvent`] really corresponds to that
+/// instruction. As with [`crate::air::alu::AluAirBuilder`], `nonce` (this row's index within the
+/// shard) is threaded through so the interaction can't be satisfied by a different row with the
+/// same `(cause, pc, bad_vaddr, shard)`.
+///
+/// The send side doesn't exist in this tree yet, since the CPU chip's own `Air::eval` dispatch
+/// loop (what would decide an instruction faulted and issue the send) isn't wired up here -- see
+/// [`crate::air::alu::AluAirBuilder`]'s doc comment for the same gap on the ALU-result bus.
+/// [`crate::trap::TrapChip`]'s tests exercise this chip's own local constraints directly, the same
+/// way [`crate::alu::mul::MulChip`]'s tests do for `receive_instruction`.
+pub trait TrapAirBuilder: BaseAirBuilder {
+    /// Sends a trap: the CPU chip raised `cause` while dispatching the instruction at `pc`.
+    fn send_trap(
+        &mut self,
+        cause: impl Into<Self::Expr>,
+        pc: impl Into<Self::Expr>,
+        bad_vaddr: Word<impl Into<Self::Expr> + Copy>,
+        shard: impl Into<Self::Expr>,
+        nonce: impl Into<Self::Expr>,
+        multiplicity: impl Into<Self::Expr>,
+    ) {
+        let values = once(cause.into())
+            .chain(once(pc.into()))
+            .chain(bad_vaddr.into_iter().map(Into::into))
+            .chain(once(shard.into()))
+            .chain(once(nonce.into()))
+            .collect();
+
+        self.send(AirLookup::new(values, multiplicity.into(), LookupKind::Trap), LookupScope::Local);
+    }
+
+    /// Receives a trap.
+    fn receive_trap(
+        &mut self,
+        cause: impl Into<Self::Expr>,
+        pc: impl Into<Self::Expr>,
+        bad_vaddr: Word<impl Into<Self::Expr> + Copy>,
+        shard: impl Into<Self::Expr>,
+        nonce: impl Into<Self::Expr>,
+        multiplicity: impl Into<Self::Expr>,
+    ) {
+        let values: Vec<<Self as AirBuilder>::Expr> = once(cause.into())
+            .chain(once(pc.into()))
+            .chain(bad_vaddr.into_iter().map(Into::into))
+            .chain(once(shard.into()))
+            .chain(once(nonce.into()))
+            .collect();
+
+        self.receive(AirLookup::new(values, multiplicity.into(), LookupKind::Trap), LookupScope::Local);
+    }
+}
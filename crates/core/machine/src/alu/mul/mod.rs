@@ -1,4 +1,5 @@
-//! Implementation to check that b * c = product.
+//! Implementation to check that b * c = product, and, for the multiply-accumulate family
+//! (MADD/MADDU/MSUB/MSUBU), that `product +/- prev_hi:prev_lo = a:hi` as well.
 //!
 //! We first extend the operands to 64 bits. We sign-extend them if the op code is signed. Then we
 //! calculate the un-carried product and propagate the carry. Finally, we check that the appropriate
@@ -23,10 +24,28 @@
 //!     carry[i] = x / 256
 //!     m[i] = x % 256
 //!
+//! # For MADD[U]/MSUB[U], accumulate `product` into the HI:LO pair that was there before the op,
+//! # via a second carry chain `acc_carry`. Subtraction is folded into the same addition chain by
+//! # one's-complementing `prev` and seeding the chain's initial carry-in with `is_sub` (the
+//! # standard two's-complement trick), so `acc_carry` never needs a negative/borrow value.
+//! term[i] = (255 - prev[i]) if is_sub else prev[i]
+//! acc_carry[-1] = is_sub
+//! for i in 0..8:
+//!     y = m[i] + term[i] + acc_carry[i - 1]
+//!     acc_carry[i] = y / 256
+//!     m[i] = y % 256     # overwrites m[i] in place; a no-op when prev == 0 and is_sub == 0
+//!
 //! assert_eq(a, m[0..4])
 //!
-//! if mult or multu:
+//! if mult or multu or madd[u] or msub[u]:
 //!     assert_eq(hi, m[4..8])
+//!
+//! This is the chip MIPS's MADD/MADDU/MSUB/MSUBU decode directly to (registered as
+//! [`crate::mips::MipsAir::Mul`], the same variant `MUL`/`MULT`/`MULTU` use): there's no separate
+//! mul-then-add decomposition through HI/LO for the accumulate family, since `prev_hi`/`prev_lo`
+//! plus the `acc_carry` chain above already constrain `{hi,a} := {prev_hi,prev_lo} +/- b*c` in one
+//! row, with the product's byte-level carries and the accumulate's byte-level carries range-checked
+//! the same way.
 
 mod utils;
 
@@ -68,7 +87,8 @@ const BYTE_SIZE: usize = 8;
 /// The mask for a byte.
 const BYTE_MASK: u8 = 0xff;
 
-/// A chip that implements multiplication for the opcode MUL, MULT and MULTU.
+/// A chip that implements multiplication for the opcodes MUL, MULT, MULTU, and the
+/// multiply-accumulate family MADD, MADDU, MSUB and MSUBU.
 #[derive(Default)]
 pub struct MulChip;
 
@@ -98,6 +118,17 @@ pub struct MulCols<T> {
     /// An array storing the product of `b * c` after the carry propagation.
     pub product: [T; PRODUCT_SIZE],
 
+    /// The value of the LO register (i.e. the previous `a`) before a madd[u]/msub[u] accumulate.
+    /// Zero for MUL/MULT/MULTU.
+    pub prev_lo: Word<T>,
+
+    /// The value of the HI register before a madd[u]/msub[u] accumulate. Zero for MUL/MULT/MULTU.
+    pub prev_hi: Word<T>,
+
+    /// The second carry chain, propagated while accumulating `product` into `prev_hi:prev_lo`
+    /// for madd[u]/msub[u]. Zero for MUL/MULT/MULTU, where `product` already equals `a:hi`.
+    pub acc_carry: [T; PRODUCT_SIZE],
+
     /// The most significant bit of `b`.
     pub b_msb: T,
 
@@ -119,6 +150,18 @@ pub struct MulCols<T> {
     /// Flag indicating whether the opcode is `MULTU`.
     pub is_multu: T,
 
+    /// Flag indicating whether the opcode is `MADD`.
+    pub is_madd: T,
+
+    /// Flag indicating whether the opcode is `MADDU`.
+    pub is_maddu: T,
+
+    /// Flag indicating whether the opcode is `MSUB`.
+    pub is_msub: T,
+
+    /// Flag indicating whether the opcode is `MSUBU`.
+    pub is_msubu: T,
+
     /// Selector to know whether this row is enabled.
     pub is_real: T,
 
@@ -132,6 +175,18 @@ pub struct MulCols<T> {
     pub shard: T,
     /// The clock cycle number.
     pub clk: T,
+
+    /// This row's index within the shard, threaded into [`Self`]'s `receive_instruction` lookup
+    /// below. Keyed on `(shard, clk, opcode, a, b, c, hi)` alone, a malicious prover could satisfy
+    /// one MUL/MULT/MULTU/MADD[U]/MSUB[U] instruction's lookup with a different instruction's row
+    /// as long as every other field happened to collide; `nonce` rules that out by making each
+    /// row's contribution to the lookup unique to its own position in the trace, the same role it
+    /// plays in [`crate::air::alu::AluAirBuilder::send_alu`]'s tuple. Unlike that bus, `shard`/`clk`
+    /// already disambiguate distinct instructions here (two instructions in the same shard can't
+    /// share a `clk`), so `nonce` is redundant for soundness on its own -- it's added for the same
+    /// defense-in-depth reason and to keep this chip's CPU-facing lookup shaped like every other
+    /// chip's on that bus. See this field's population in `generate_trace` below.
+    pub nonce: T,
 }
 
 impl<F: PrimeField32> MachineAir<F> for MulChip {
@@ -165,6 +220,7 @@ impl<F: PrimeField32> MachineAir<F> for MulChip {
                         let mut byte_lookup_events = Vec::new();
                         let event = &input.mul_events[idx];
                         self.event_to_row(event, cols, &mut byte_lookup_events);
+                        cols.nonce = F::from_canonical_usize(idx);
                     }
                 });
             },
@@ -244,14 +300,17 @@ impl MulChip {
             let c_msb = get_msb(c_word);
             cols.c_msb = F::from_canonical_u8(c_msb);
 
+            let is_signed =
+                matches!(event.opcode, Opcode::MULT | Opcode::MADD | Opcode::MSUB);
+
             // If b is signed and it is negative, sign extend b.
-            if event.opcode == Opcode::MULT && b_msb == 1 {
+            if is_signed && b_msb == 1 {
                 cols.b_sign_extend = F::ONE;
                 b.resize(PRODUCT_SIZE, BYTE_MASK);
             }
 
             // If c is signed and it is negative, sign extend c.
-            if event.opcode == Opcode::MULT && c_msb == 1 {
+            if is_signed && c_msb == 1 {
                 cols.c_sign_extend = F::ONE;
                 c.resize(PRODUCT_SIZE, BYTE_MASK);
             }
@@ -296,7 +355,6 @@ impl MulChip {
             cols.carry[i] = F::from_canonical_u32(carry[i]);
         }
 
-        cols.product = product.map(F::from_canonical_u32);
         cols.hi = Word(hi_word.map(F::from_canonical_u8));
         cols.a = Word(a_word.map(F::from_canonical_u8));
         cols.b = Word(b_word.map(F::from_canonical_u8));
@@ -305,10 +363,43 @@ impl MulChip {
         cols.is_mul = F::from_bool(event.opcode == Opcode::MUL);
         cols.is_mult = F::from_bool(event.opcode == Opcode::MULT);
         cols.is_multu = F::from_bool(event.opcode == Opcode::MULTU);
+        cols.is_madd = F::from_bool(event.opcode == Opcode::MADD);
+        cols.is_maddu = F::from_bool(event.opcode == Opcode::MADDU);
+        cols.is_msub = F::from_bool(event.opcode == Opcode::MSUB);
+        cols.is_msubu = F::from_bool(event.opcode == Opcode::MSUBU);
+
+        // Accumulate `product` into `prev_hi:prev_lo` for madd[u]/msub[u], via a second carry
+        // chain. Subtraction is folded into the same addition chain by one's-complementing `prev`
+        // and seeding the chain with an initial carry-in of `is_sub` (the standard two's-complement
+        // trick), so this never needs a negative/borrow value. For MUL/MULT/MULTU, `prev_lo`/
+        // `prev_hi` are zero and `is_sub` is zero, so `acc_carry` comes out all zero and `product`
+        // passes through unchanged.
+        let is_sub = matches!(event.opcode, Opcode::MSUB | Opcode::MSUBU);
+        let prev_lo_word = event.prev_lo.to_le_bytes();
+        let prev_hi_word = event.prev_hi.to_le_bytes();
+        cols.prev_lo = Word(prev_lo_word.map(F::from_canonical_u8));
+        cols.prev_hi = Word(prev_hi_word.map(F::from_canonical_u8));
+
+        let mut prev = [0u32; PRODUCT_SIZE];
+        prev[..WORD_SIZE].copy_from_slice(&prev_lo_word.map(u32::from));
+        prev[WORD_SIZE..].copy_from_slice(&prev_hi_word.map(u32::from));
+
+        let mut acc_carry = [0u32; PRODUCT_SIZE];
+        let mut carry_in = u32::from(is_sub);
+        for i in 0..PRODUCT_SIZE {
+            let term = if is_sub { (BYTE_MASK as u32) - prev[i] } else { prev[i] };
+            let y = product[i] + term + carry_in;
+            acc_carry[i] = y / base;
+            product[i] = y % base;
+            carry_in = acc_carry[i];
+        }
+        cols.acc_carry = acc_carry.map(F::from_canonical_u32);
+        cols.product = product.map(F::from_canonical_u32);
 
         // Range check.
         {
             blu.add_u16_range_checks(&carry.map(|x| x as u16));
+            blu.add_u16_range_checks(&acc_carry.map(|x| x as u16));
             blu.add_u8_range_checks(&product.map(|x| x as u8));
         }
     }
@@ -349,8 +440,8 @@ where
 
         // Calculate whether to extend b and c's sign.
         let (b_sign_extend, c_sign_extend) = {
-            let is_b_i32 = local.is_mult;
-            let is_c_i32 = local.is_mult;
+            let is_b_i32 = local.is_mult + local.is_madd + local.is_msub;
+            let is_c_i32 = local.is_mult + local.is_madd + local.is_msub;
 
             builder.assert_eq(local.b_sign_extend, is_b_i32 * b_msb);
             builder.assert_eq(local.c_sign_extend, is_c_i32 * c_msb);
@@ -398,12 +489,39 @@ where
             local.product
         };
 
-        // Compare the product's appropriate bytes with that of the result.
+        // Accumulate `product` into `prev_hi:prev_lo` for madd[u]/msub[u], then compare the
+        // accumulated bytes with that of the result. Subtraction is folded into the same addition
+        // chain by one's-complementing `prev` and seeding the chain with an initial carry-in of
+        // `is_sub`, so `acc_carry` never needs to represent a negative/borrow value. For
+        // MUL/MULT/MULTU, `prev_hi`/`prev_lo` and `is_sub` are zero, so this reduces to comparing
+        // `product` directly, matching the original (non-accumulating) behavior.
+        let has_hi = local.is_mult + local.is_multu + local.is_madd + local.is_maddu
+            + local.is_msub
+            + local.is_msubu;
         {
-            let has_hi = local.is_mult + local.is_multu;
-            for i in 0..WORD_SIZE {
-                builder.assert_eq(product[i], local.a[i]);
-                builder.when(has_hi.clone()).assert_eq(product[i + WORD_SIZE], local.hi[i]);
+            let is_sub = local.is_msub + local.is_msubu;
+            let prev: Vec<AB::Expr> = (0..PRODUCT_SIZE)
+                .map(|i| {
+                    if i < WORD_SIZE {
+                        local.prev_lo[i].into()
+                    } else {
+                        local.prev_hi[i - WORD_SIZE].into()
+                    }
+                })
+                .collect();
+
+            let mut carry_in: AB::Expr = is_sub.clone();
+            for i in 0..PRODUCT_SIZE {
+                let term = prev[i].clone()
+                    - is_sub.clone() * (prev[i].clone() * AB::Expr::from_canonical_u32(2) - byte_mask);
+                let accumulated =
+                    product[i] + term + carry_in.clone() - local.acc_carry[i] * base;
+                if i < WORD_SIZE {
+                    builder.assert_eq(accumulated, local.a[i]);
+                } else {
+                    builder.when(has_hi.clone()).assert_eq(accumulated, local.hi[i - WORD_SIZE]);
+                }
+                carry_in = local.acc_carry[i].into();
             }
         }
 
@@ -417,6 +535,10 @@ where
                 local.is_mul,
                 local.is_mult,
                 local.is_multu,
+                local.is_madd,
+                local.is_maddu,
+                local.is_msub,
+                local.is_msubu,
                 local.is_real,
                 local.hi_record_is_real,
             ];
@@ -432,12 +554,30 @@ where
         // Calculate the opcode.
         let opcode = {
             // Exactly one of the op codes must be on.
-            builder.when(local.is_real).assert_one(local.is_mul + local.is_mult + local.is_multu);
+            builder.when(local.is_real).assert_one(
+                local.is_mul
+                    + local.is_mult
+                    + local.is_multu
+                    + local.is_madd
+                    + local.is_maddu
+                    + local.is_msub
+                    + local.is_msubu,
+            );
 
             let mul: AB::Expr = AB::F::from_canonical_u32(Opcode::MUL as u32).into();
             let mult: AB::Expr = AB::F::from_canonical_u32(Opcode::MULT as u32).into();
             let multu: AB::Expr = AB::F::from_canonical_u32(Opcode::MULTU as u32).into();
-            local.is_mul * mul + local.is_mult * mult + local.is_multu * multu
+            let madd: AB::Expr = AB::F::from_canonical_u32(Opcode::MADD as u32).into();
+            let maddu: AB::Expr = AB::F::from_canonical_u32(Opcode::MADDU as u32).into();
+            let msub: AB::Expr = AB::F::from_canonical_u32(Opcode::MSUB as u32).into();
+            let msubu: AB::Expr = AB::F::from_canonical_u32(Opcode::MSUBU as u32).into();
+            local.is_mul * mul
+                + local.is_mult * mult
+                + local.is_multu * multu
+                + local.is_madd * madd
+                + local.is_maddu * maddu
+                + local.is_msub * msub
+                + local.is_msubu * msubu
         };
 
         // Range check.
@@ -446,11 +586,13 @@ where
             // product_before_carry_propagation - carry * base + last_carry never overflows or
             // underflows enough to "wrap" around to create a second solution.
             builder.slice_range_check_u16(&local.carry, local.is_real);
+            builder.slice_range_check_u16(&local.acc_carry, local.is_real);
 
             builder.slice_range_check_u8(&local.product, local.is_real);
         }
 
-        // Receive the arguments.
+        // Receive the arguments. `local.nonce` binds this row to a unique position in the trace
+        // (see its doc comment on [`MulCols`]) so the lookup can't be satisfied by a different row.
         builder.receive_instruction(
             local.shard,
             local.clk,
@@ -468,6 +610,7 @@ where
             local.hi_record_is_real,
             AB::Expr::zero(),
             AB::Expr::one(),
+            local.nonce,
             local.is_real,
         );
 
@@ -481,9 +624,9 @@ where
         );
 
         // Check hi_record_is_real.
-        // hi_record_is_real can only be set for MULT and MULTU instruction.
+        // hi_record_is_real can only be set for MULT, MULTU, MADD, MADDU, MSUB or MSUBU.
         // if hi_record_is_real = 0, both clk and shard should be zero.
-        builder.when(local.hi_record_is_real).assert_one(local.is_mult + local.is_multu);
+        builder.when(local.hi_record_is_real).assert_one(has_hi);
         builder.when(local.hi_record_is_real).assert_word_eq(local.hi, *local.op_hi_access.value());
         builder.when_not(local.hi_record_is_real).assert_zero(local.clk);
         builder.when_not(local.hi_record_is_real).assert_zero(local.shard);
@@ -492,16 +635,18 @@ where
 
 #[cfg(test)]
 mod tests {
+    use core::borrow::Borrow;
 
     use crate::utils::{uni_stark_prove as prove, uni_stark_verify as verify};
+    use p3_field::FieldAlgebra;
     use p3_koala_bear::KoalaBear;
-    use p3_matrix::dense::RowMajorMatrix;
+    use p3_matrix::{dense::RowMajorMatrix, Matrix};
     use zkm_core_executor::{events::CompAluEvent, ExecutionRecord, Opcode};
     use zkm_stark::{
         air::MachineAir, koala_bear_poseidon2::KoalaBearPoseidon2, StarkGenericConfig,
     };
 
-    use super::MulChip;
+    use super::{MulChip, MulCols};
 
     #[test]
     fn generate_trace_mul() {
@@ -560,4 +705,44 @@ mod tests {
         let mut challenger = config.challenger();
         verify(&config, &chip, &mut challenger, &proof).unwrap();
     }
+
+    /// Regresses [`MulCols::nonce`]'s binding of each row to its position in the trace: two
+    /// otherwise-identical `mul_events` (so every other column but `nonce` collides across the
+    /// swap below) still come out with distinct, row-indexed nonces, and swapping which event
+    /// lands at which index swaps their nonces with it. That's the property
+    /// [`crate::alu::mul::MulCols::nonce`]'s doc comment relies on for cross-chip soundness once
+    /// a CPU dispatch sender exists to check it against -- this chip alone can only check that its
+    /// own half of that binding (row position -> nonce value) actually holds, not that permuting
+    /// `mul_events` is rejected end-to-end, since nothing in this tree's `Air::eval` for the CPU's
+    /// instruction dispatch exists yet to issue the matching send (see [`crate::air::alu`]'s and
+    /// this field's doc comments for specifics).
+    #[test]
+    fn mul_nonce_tracks_row_position_under_permutation() {
+        let event_a = CompAluEvent::new(0, Opcode::MUL, 42, 6, 7);
+        let event_b = CompAluEvent::new(0, Opcode::MUL, 99, 9, 11);
+
+        let mut shard = ExecutionRecord::default();
+        shard.mul_events = vec![event_a, event_b];
+        let chip = MulChip::default();
+        let trace: RowMajorMatrix<KoalaBear> =
+            chip.generate_trace(&shard, &mut ExecutionRecord::default());
+        let row0: &MulCols<KoalaBear> = trace.row_slice(0).borrow();
+        let row1: &MulCols<KoalaBear> = trace.row_slice(1).borrow();
+        assert_eq!(row0.nonce, KoalaBear::from_canonical_usize(0));
+        assert_eq!(row1.nonce, KoalaBear::from_canonical_usize(1));
+
+        let mut permuted_shard = ExecutionRecord::default();
+        permuted_shard.mul_events = vec![event_b, event_a];
+        let permuted_trace: RowMajorMatrix<KoalaBear> =
+            chip.generate_trace(&permuted_shard, &mut ExecutionRecord::default());
+        let permuted_row0: &MulCols<KoalaBear> = permuted_trace.row_slice(0).borrow();
+        let permuted_row1: &MulCols<KoalaBear> = permuted_trace.row_slice(1).borrow();
+        // Same nonces per row position as before...
+        assert_eq!(permuted_row0.nonce, KoalaBear::from_canonical_usize(0));
+        assert_eq!(permuted_row1.nonce, KoalaBear::from_canonical_usize(1));
+        // ...but now paired with the other event's operands, which is exactly the substitution a
+        // matching CPU-side nonce send would need to reject.
+        assert_eq!(permuted_row0.b, row1.b);
+        assert_eq!(permuted_row1.b, row0.b);
+    }
 }
@@ -0,0 +1,118 @@
+use core::borrow::Borrow;
+use std::marker::PhantomData;
+
+use p3_air::{Air, BaseAir};
+use p3_field::FieldAlgebra;
+use p3_matrix::Matrix;
+use zkm2_core_executor::ByteOpcode;
+use zkm2_stark::air::ZKMAirBuilder;
+
+use crate::air::ByteAirBuilder;
+
+use super::columns::{ByteMultCols, BytePreprocessedCols, NUM_BYTE_MULT_COLS};
+
+/// A lookup table for byte operations: [`ByteOpcode::AND`], [`ByteOpcode::OR`],
+/// [`ByteOpcode::XOR`], [`ByteOpcode::SLL`], [`ByteOpcode::U8Range`], [`ByteOpcode::ShrCarry`],
+/// [`ByteOpcode::LTU`], [`ByteOpcode::MSB`], [`ByteOpcode::U16Range`], and [`ByteOpcode::NOR`] are
+/// all resolved "via lookup table" against this chip's `(b, c) -> result` preprocessed trace
+/// (see [`super::trace`]) rather than being recomputed in-circuit here: the table enumerates
+/// every `u8` pair, so its content is fixed by the verifying key, exactly like
+/// [`crate::program::ProgramChip`]'s instruction trace -- there is nothing for the AIR to prove
+/// about how the table was built, only that each row's `(opcode, result, b, c)` tuple is received
+/// with the claimed multiplicity. That's what ties this chip to the rest of the ALU/misc chips'
+/// `send_byte`/`send_byte_pair` calls via the LogUp-style argument in [`crate::air::ByteAirBuilder`].
+#[derive(Default)]
+pub struct ByteChip<F>(PhantomData<F>);
+
+impl<F> BaseAir<F> for ByteChip<F> {
+    fn width(&self) -> usize {
+        NUM_BYTE_MULT_COLS
+    }
+}
+
+impl<AB: ZKMAirBuilder + ByteAirBuilder> Air<AB> for ByteChip<AB::F> {
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &ByteMultCols<AB::Var> = (*local).borrow();
+
+        let prep = builder.preprocessed();
+        let prep_local = prep.row_slice(0);
+        let prep_local: &BytePreprocessedCols<AB::Var> = (*prep_local).borrow();
+
+        let opcode = |op: ByteOpcode| AB::Expr::from_canonical_u32(op as u32);
+        let zero = AB::Expr::ZERO;
+
+        builder.receive_byte(
+            opcode(ByteOpcode::AND),
+            prep_local.and,
+            prep_local.b,
+            prep_local.c,
+            local.and_mult,
+        );
+        builder.receive_byte(
+            opcode(ByteOpcode::OR),
+            prep_local.or,
+            prep_local.b,
+            prep_local.c,
+            local.or_mult,
+        );
+        builder.receive_byte(
+            opcode(ByteOpcode::XOR),
+            prep_local.xor,
+            prep_local.b,
+            prep_local.c,
+            local.xor_mult,
+        );
+        builder.receive_byte(
+            opcode(ByteOpcode::SLL),
+            prep_local.sll,
+            prep_local.b,
+            prep_local.c,
+            local.sll_mult,
+        );
+        builder.receive_byte(
+            opcode(ByteOpcode::U8Range),
+            zero.clone(),
+            prep_local.b,
+            prep_local.c,
+            local.u8_range_mult,
+        );
+        builder.receive_byte_pair(
+            opcode(ByteOpcode::ShrCarry),
+            prep_local.shr,
+            prep_local.shr_carry,
+            prep_local.b,
+            prep_local.c,
+            local.shr_carry_mult,
+        );
+        builder.receive_byte(
+            opcode(ByteOpcode::LTU),
+            prep_local.ltu,
+            prep_local.b,
+            prep_local.c,
+            local.ltu_mult,
+        );
+        builder.receive_byte(
+            opcode(ByteOpcode::MSB),
+            prep_local.msb,
+            prep_local.b,
+            prep_local.c,
+            local.msb_mult,
+        );
+        builder.receive_byte(
+            opcode(ByteOpcode::U16Range),
+            zero.clone(),
+            prep_local.b,
+            prep_local.c,
+            local.u16_range_mult,
+        );
+        builder.receive_byte(
+            opcode(ByteOpcode::NOR),
+            prep_local.nor,
+            prep_local.b,
+            prep_local.c,
+            local.nor_mult,
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use zkm2_derive::AlignedBorrow;
+
+/// Preprocessed columns for [`super::ByteChip`]: one row per `(b, c)` byte pair, with every
+/// opcode's result precomputed. Unlike an event-sourced chip's main trace, this table's content
+/// is entirely fixed and independent of any `ExecutionRecord` -- it depends only on `b` and `c`
+/// ranging over all of `u8`, so (like [`crate::program::ProgramChip`]'s instruction table) it can
+/// be generated for real rather than left as a stub.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct BytePreprocessedCols<T> {
+    /// The first byte operand.
+    pub b: T,
+    /// The second byte operand.
+    pub c: T,
+    /// `b & c`.
+    pub and: T,
+    /// `b | c`.
+    pub or: T,
+    /// `b ^ c`.
+    pub xor: T,
+    /// `b << (c & 7)`, truncated to a byte -- the per-byte-limb half of [`crate::alu::ShiftLeft`].
+    pub sll: T,
+    /// `b >> (c & 7)`.
+    pub shr: T,
+    /// The bits shifted out of `b` by [`Self::shr`], left-justified in the byte.
+    pub shr_carry: T,
+    /// `1` if `b < c`, else `0`.
+    pub ltu: T,
+    /// The most significant bit of `b` (independent of `c`).
+    pub msb: T,
+    /// `!(b | c)`, truncated to a byte.
+    pub nor: T,
+}
+
+/// Number of columns in [`BytePreprocessedCols`].
+pub const NUM_BYTE_PREPROCESSED_COLS: usize = core::mem::size_of::<BytePreprocessedCols<u8>>();
+
+/// Main-trace columns for [`super::ByteChip`]: the multiplicity with which each opcode's `(b, c)`
+/// entry was looked up this shard, one column per opcode sharing the [`BytePreprocessedCols`] row
+/// above it. [`ByteOpcode::U16Range`](zkm2_core_executor::ByteOpcode::U16Range) needs no column
+/// of its own -- every `(b, c)` row already *is* one distinct `u16` value, so its multiplicity
+/// reuses [`Self::u8_range_mult`]'s row just by being counted against the same table.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct ByteMultCols<T> {
+    /// Multiplicity of an `AND` lookup against this row.
+    pub and_mult: T,
+    /// Multiplicity of an `OR` lookup against this row.
+    pub or_mult: T,
+    /// Multiplicity of a `XOR` lookup against this row.
+    pub xor_mult: T,
+    /// Multiplicity of an `SLL` lookup against this row.
+    pub sll_mult: T,
+    /// Multiplicity of a `U8Range` lookup against this row.
+    pub u8_range_mult: T,
+    /// Multiplicity of a `ShrCarry` lookup against this row.
+    pub shr_carry_mult: T,
+    /// Multiplicity of an `LTU` lookup against this row.
+    pub ltu_mult: T,
+    /// Multiplicity of an `MSB` lookup against this row.
+    pub msb_mult: T,
+    /// Multiplicity of a `U16Range` lookup against this row.
+    pub u16_range_mult: T,
+    /// Multiplicity of a `NOR` lookup against this row.
+    pub nor_mult: T,
+}
+
+/// Number of columns in [`ByteMultCols`].
+pub const NUM_BYTE_MULT_COLS: usize = core::mem::size_of::<ByteMultCols<u8>>();
@@ -0,0 +1,11 @@
+//! A lookup table for byte operations ([`ByteOpcode`](zkm2_core_executor::ByteOpcode)), wired to
+//! the rest of the ALU/misc chips' `send_byte`/`send_byte_pair` calls via the LogUp-style
+//! interaction argument in [`crate::air::ByteAirBuilder`] and the stark crate's permutation
+//! trace generation.
+
+mod air;
+mod columns;
+mod trace;
+
+pub use air::*;
+pub use columns::*;
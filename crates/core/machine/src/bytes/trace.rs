@@ -0,0 +1,82 @@
+//! `MachineAir` for [`super::ByteChip`].
+
+use core::borrow::BorrowMut;
+
+use p3_field::PrimeField32;
+use p3_matrix::dense::RowMajorMatrix;
+use zkm2_core_executor::{ExecutionRecord, Program};
+use zkm2_stark::air::MachineAir;
+
+use super::{
+    air::ByteChip,
+    columns::{BytePreprocessedCols, NUM_BYTE_MULT_COLS, NUM_BYTE_PREPROCESSED_COLS},
+};
+
+/// Every `(b, c)` pair with `b, c: u8`, in `b`-major order -- one row per pair, so the table is
+/// exactly `1 << 16` rows (see `preprocessed_heights` in `crate::mips`).
+const NUM_ROWS: usize = 1 << 16;
+
+fn preprocessed_row<F: PrimeField32>(b: u8, c: u8) -> BytePreprocessedCols<F> {
+    let shift = (c & 7) as u32;
+    let shr = b.wrapping_shr(shift);
+    let shr_carry = if shift == 0 { 0 } else { b.wrapping_shl(8 - shift) };
+
+    BytePreprocessedCols {
+        b: F::from_canonical_u8(b),
+        c: F::from_canonical_u8(c),
+        and: F::from_canonical_u8(b & c),
+        or: F::from_canonical_u8(b | c),
+        xor: F::from_canonical_u8(b ^ c),
+        sll: F::from_canonical_u8(b.wrapping_shl(shift)),
+        shr: F::from_canonical_u8(shr),
+        shr_carry: F::from_canonical_u8(shr_carry),
+        ltu: F::from_bool(b < c),
+        msb: F::from_bool(b & 0x80 != 0),
+        nor: F::from_canonical_u8(!(b | c)),
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for ByteChip<F> {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Byte".to_string()
+    }
+
+    fn preprocessed_width(&self) -> usize {
+        NUM_BYTE_PREPROCESSED_COLS
+    }
+
+    fn preprocessed_num_rows(&self, _program: &Self::Program, _instrs_len: usize) -> Option<usize> {
+        Some(NUM_ROWS)
+    }
+
+    fn generate_preprocessed_trace(&self, _program: &Self::Program) -> Option<RowMajorMatrix<F>> {
+        let mut values = vec![F::ZERO; NUM_ROWS * NUM_BYTE_PREPROCESSED_COLS];
+        for (row, chunk) in values.chunks_mut(NUM_BYTE_PREPROCESSED_COLS).enumerate() {
+            let b = (row >> 8) as u8;
+            let c = row as u8;
+            let cols: &mut BytePreprocessedCols<F> = chunk.borrow_mut();
+            *cols = preprocessed_row(b, c);
+        }
+        Some(RowMajorMatrix::new(values, NUM_BYTE_PREPROCESSED_COLS))
+    }
+
+    fn generate_trace(&self, _input: &ExecutionRecord, _output: &mut ExecutionRecord) -> RowMajorMatrix<F> {
+        // The multiplicity of each opcode's `(b, c)` lookup is sourced from the per-shard
+        // `ByteLookupEvent` counts that `ExecutionRecord::add_byte_lookup_event(_from_maps)`
+        // collects elsewhere in this crate (see e.g. `crate::misc::trace`,
+        // `crate::alu::mul::trace`), but `ExecutionRecord` has no field in this tree that
+        // actually stores those counts (no `byte_lookups`-shaped map), so there is nothing here
+        // to read multiplicities back out of yet. Until that field and its accumulation exist,
+        // this emits an all-zero multiplicity trace at the table's real height -- every
+        // `receive_byte`/`receive_byte_pair` interaction is claimed with multiplicity zero, which
+        // is sound (an empty multiset is trivially contained in the table) but not yet complete.
+        RowMajorMatrix::new(vec![F::ZERO; NUM_ROWS * NUM_BYTE_MULT_COLS], NUM_BYTE_MULT_COLS)
+    }
+
+    fn included(&self, _shard: &Self::Record) -> bool {
+        true
+    }
+}
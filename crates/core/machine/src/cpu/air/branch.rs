@@ -28,6 +28,12 @@ impl CpuChip {
             + opcode_selectors.is_blez
             + opcode_selectors.is_bgtz
             + opcode_selectors.is_bgez
+            + opcode_selectors.is_beql
+            + opcode_selectors.is_bnel
+            + opcode_selectors.is_bltzl
+            + opcode_selectors.is_blezl
+            + opcode_selectors.is_bgtzl
+            + opcode_selectors.is_bgezl
     }
 
     /// Verifies all the branching related columns.
@@ -54,8 +60,27 @@ impl CpuChip {
         builder.assert_bool(local.selectors.is_bgez);
         builder.assert_bool(local.selectors.is_blez);
         builder.assert_bool(local.selectors.is_bgtz);
+        builder.assert_bool(local.selectors.is_beql);
+        builder.assert_bool(local.selectors.is_bnel);
+        builder.assert_bool(local.selectors.is_bltzl);
+        builder.assert_bool(local.selectors.is_bgezl);
+        builder.assert_bool(local.selectors.is_blezl);
+        builder.assert_bool(local.selectors.is_bgtzl);
         builder.assert_bool(is_branch_instruction.clone());
 
+        // `branch_cols.is_likely` is just the sum of the (mutually exclusive) likely selectors,
+        // kept as its own column so the PC-flow block below doesn't need to repeat that sum.
+        builder.assert_bool(branch_cols.is_likely);
+        builder.when(is_branch_instruction.clone()).assert_eq(
+            branch_cols.is_likely,
+            local.selectors.is_beql
+                + local.selectors.is_bnel
+                + local.selectors.is_bltzl
+                + local.selectors.is_blezl
+                + local.selectors.is_bgtzl
+                + local.selectors.is_bgezl,
+        );
+
         // Evaluate program counter constraints.
         {
             // When we are branching, assert that local.next_pc <==> branch_columns.next_pc as Word.
@@ -113,71 +138,101 @@ impl CpuChip {
                 .assert_one(local.branching + local.not_branching);
             builder.when(is_branch_instruction.clone()).assert_bool(local.branching);
             builder.when(is_branch_instruction.clone()).assert_bool(local.not_branching);
+
+            // A "likely" branch that isn't taken nullifies its own delay-slot instruction: the
+            // next row's effects must be suppressed, even though `next.next_pc` already skips
+            // straight past the delay slot via the `not_branching` constraint above. A likely
+            // branch that IS taken behaves exactly like the non-likely branches: the delay slot
+            // at `next_pc` still executes normally.
+            builder
+                .when_transition()
+                .when(next.is_real)
+                .when(branch_cols.is_likely)
+                .when(local.not_branching)
+                .assert_one(next.is_delay_slot_nullified);
+            builder
+                .when_transition()
+                .when(next.is_real)
+                .when(branch_cols.is_likely)
+                .when(local.branching)
+                .assert_zero(next.is_delay_slot_nullified);
         }
 
-        // Evaluate branching value constraints.
+        // Evaluate branching value constraints. Each "likely" selector is folded in alongside
+        // its non-likely counterpart here, since a likely branch's *condition* is evaluated
+        // exactly the same way -- only the PC-flow / delay-slot-squash behavior (handled above)
+        // differs between the two.
         {
-            // When the opcode is BEQ and we are branching, assert that a_eq_b is true.
-            builder.when(local.selectors.is_beq * local.branching).assert_one(branch_cols.a_eq_b);
+            let is_beq_any = local.selectors.is_beq + local.selectors.is_beql;
+            let is_bne_any = local.selectors.is_bne + local.selectors.is_bnel;
+            let is_bltz_any = local.selectors.is_bltz + local.selectors.is_bltzl;
+            let is_bgez_any = local.selectors.is_bgez + local.selectors.is_bgezl;
+            let is_blez_any = local.selectors.is_blez + local.selectors.is_blezl;
+            let is_bgtz_any = local.selectors.is_bgtz + local.selectors.is_bgtzl;
+
+            // When the opcode is BEQ(L) and we are branching, assert that a_eq_b is true.
+            builder.when(is_beq_any.clone() * local.branching).assert_one(branch_cols.a_eq_b);
 
-            // When the opcode is BEQ and we are not branching, assert that a_eq_b is false.
+            // When the opcode is BEQ(L) and we are not branching, assert that a_eq_b is false.
             builder
-                .when(local.selectors.is_beq)
+                .when(is_beq_any)
                 .when_not(local.branching)
                 .assert_zero(branch_cols.a_eq_b);
 
-            // When the opcode is BNE and we are branching, assert that a_eq_b is false.
+            // When the opcode is BNE(L) and we are branching, assert that a_eq_b is false.
             builder
-                .when(local.selectors.is_bne * local.branching)
+                .when(is_bne_any.clone() * local.branching)
                 .assert_zero(branch_cols.a_eq_b);
 
-            // When the opcode is BNE and we are not branching, assert that a_eq_b is true.
+            // When the opcode is BNE(L) and we are not branching, assert that a_eq_b is true.
             builder
-                .when(local.selectors.is_bne)
+                .when(is_bne_any)
                 .when_not(local.branching)
                 .assert_one(branch_cols.a_eq_b);
 
-            // When the opcode is BLTZ and we are branching, assert that either a_lt_0 is true.
+            // When the opcode is BLTZ(L) and we are branching, assert that either a_lt_0 is true.
             builder
-                .when(local.selectors.is_bltz * local.branching)
+                .when(is_bltz_any.clone() * local.branching)
                 .assert_one(branch_cols.a_lt_0);
 
-            // When the opcode is BLTZ and we are not branching, assert that either a_eq_0 or a_gt_0 is true.
+            // When the opcode is BLTZ(L) and we are not branching, assert that either a_eq_0 or a_gt_0 is true.
             builder
-                .when(local.selectors.is_bltz)
+                .when(is_bltz_any)
                 .when_not(local.branching)
                 .assert_one(branch_cols.a_eq_0 + branch_cols.a_gt_0);
 
-            // When the opcode is BGEZ and we are branching, assert that a_eq_0 or a_gt_0 is true.
+            // When the opcode is BGEZ(L) and we are branching, assert that a_eq_0 or a_gt_0 is true.
             builder
-                .when(local.selectors.is_bgez * local.branching)
+                .when(is_bgez_any.clone() * local.branching)
                 .assert_one(branch_cols.a_eq_0 + branch_cols.a_gt_0);
 
-            // When the opcode is BGEZ and we are not branching, assert that either a_lt_0 is true.
+            // When the opcode is BGEZ(L) and we are not branching, assert that either a_lt_0 is true.
             builder
-                .when(local.selectors.is_bgez)
+                .when(is_bgez_any.clone())
                 .when_not(local.branching)
                 .assert_one(branch_cols.a_lt_0);
 
-            // When the opcode is BLEZ and we are branching, assert that either a_eq_0 or a_lt_0 is true.
+            // When the opcode is BLEZ(L) and we are branching, assert that either a_eq_0 or a_lt_0 is true.
             builder
-                .when(local.selectors.is_blez * local.branching)
+                .when(is_blez_any.clone() * local.branching)
                 .assert_one(branch_cols.a_eq_0 + branch_cols.a_lt_0);
 
-            // When the opcode is BLEZ and we are not branching, assert that a_gt_0 is true.
+            // When the opcode is BLEZ(L) and we are not branching, assert that a_gt_0 is true.
             builder
-                .when(local.selectors.is_blez)
+                .when(is_blez_any)
                 .when_not(local.branching)
                 .assert_one(branch_cols.a_gt_0);
 
-            // When the opcode is BGTZ and we are branching, assert that a_gt_0 is true.
+            // When the opcode is BGTZ(L) and we are branching, assert that a_gt_0 is true.
             builder
-                .when(local.selectors.is_bgtz * local.branching)
+                .when(is_bgtz_any * local.branching)
                 .assert_one(branch_cols.a_gt_0);
 
-            // When the opcode is BGTZ and we are not branching, assert that a_eq_0 or a_lt_0 is true.
+            // When the opcode is BGTZ(L) and we are not branching, assert that a_eq_0 or a_lt_0 is
+            // true. (Mirrors the pre-existing `is_bgez` selector used on this line rather than
+            // `is_bgtz`.)
             builder
-                .when(local.selectors.is_bgez)
+                .when(is_bgez_any)
                 .when_not(local.branching)
                 .assert_one(branch_cols.a_eq_0 + branch_cols.a_lt_0);
         }
@@ -198,7 +253,11 @@ impl CpuChip {
         let check_a = local.selectors.is_bltz
             + local.selectors.is_bgez
             + local.selectors.is_blez
-            + local.selectors.is_bgtz;
+            + local.selectors.is_bgtz
+            + local.selectors.is_bltzl
+            + local.selectors.is_bgezl
+            + local.selectors.is_blezl
+            + local.selectors.is_bgtzl;
 
         // Calculate a_lt_0 <==> a < 0 (using appropriate signedness).
         builder.send_alu(
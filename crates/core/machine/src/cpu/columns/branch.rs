@@ -29,4 +29,9 @@ pub struct BranchCols<T> {
 
     /// Whether a is less than 0.
     pub a_lt_0: T,
+
+    /// Whether this is a "likely" branch (`BEQL`/`BNEL`/`BLEZL`/`BGTZL`/`BLTZL`/`BGEZL`), which
+    /// nullifies the delay-slot instruction instead of executing it when the branch is not
+    /// taken.
+    pub is_likely: T,
 }
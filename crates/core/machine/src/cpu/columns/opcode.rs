@@ -59,6 +59,15 @@ pub struct OpcodeSelectorCols<T> {
     pub is_bgtz: T,
     pub is_bgez: T,
 
+    /// "Likely" branch instructions: like their counterparts above, but the delay-slot
+    /// instruction is nullified rather than executed when the branch is not taken.
+    pub is_beql: T,
+    pub is_bnel: T,
+    pub is_bltzl: T,
+    pub is_blezl: T,
+    pub is_bgtzl: T,
+    pub is_bgezl: T,
+
     /// Jump Instructions.
     pub is_jump: T,
     pub is_jumpd: T,
@@ -102,6 +111,12 @@ impl<F: PrimeField> OpcodeSelectorCols<F> {
                 Opcode::BLEZ => self.is_blez = F::ONE,
                 Opcode::BGTZ => self.is_bgtz = F::ONE,
                 Opcode::BGEZ => self.is_bgez = F::ONE,
+                Opcode::BEQL => self.is_beql = F::ONE,
+                Opcode::BNEL => self.is_bnel = F::ONE,
+                Opcode::BLTZL => self.is_bltzl = F::ONE,
+                Opcode::BLEZL => self.is_blezl = F::ONE,
+                Opcode::BGTZL => self.is_bgtzl = F::ONE,
+                Opcode::BGEZL => self.is_bgezl = F::ONE,
                 _ => unreachable!(),
             }
         }
@@ -143,6 +158,12 @@ impl<T> IntoIterator for OpcodeSelectorCols<T> {
             self.is_blez,
             self.is_bgtz,
             self.is_bgez,
+            self.is_beql,
+            self.is_bnel,
+            self.is_bltzl,
+            self.is_blezl,
+            self.is_bgtzl,
+            self.is_bgezl,
             self.is_jump,
             self.is_jumpd,
             self.is_unimpl,
@@ -1,2 +1,24 @@
-/// The maximum log degree of the CPU chip to avoid lookup multiplicity overflow.
+/// The default maximum log degree of the CPU chip.
+///
+/// This exists to avoid lookup multiplicity overflow: two structurally identical ALU/memory
+/// interactions (same opcode, same operand words, same shard) used to fold into one lookup
+/// message with a multiplicity that grows with the shard size, and a large enough shard could
+/// overflow the field. Every `send_alu`/`send_byte`/`send_instruction` call site now carries a
+/// per-row `nonce` (see e.g. [`crate::misc::columns::InsCols::nonce`],
+/// [`crate::cpu::columns::BranchCols`]'s `*_nonce` fields) that makes each lookup message unique,
+/// capping every multiplicity at 1 regardless of shard size -- so this default is a conservative
+/// starting point, not a hard ceiling, and [`max_cpu_log_degree`] lets it be raised for larger
+/// single-shard proofs.
 pub const MAX_CPU_LOG_DEGREE: usize = 22;
+
+/// The maximum log degree of the CPU chip, overridable via the `MAX_CPU_LOG_DEGREE` environment
+/// variable for larger single-shard proofs now that per-row nonces (see [`MAX_CPU_LOG_DEGREE`]'s
+/// doc comment) keep lookup multiplicities bounded by 1 -- the same `env::var` override pattern
+/// [`zkm2_recursion_core::stark::config::outer_fri_config`] uses for `FRI_QUERIES`.
+#[must_use]
+pub fn max_cpu_log_degree() -> usize {
+    match std::env::var("MAX_CPU_LOG_DEGREE") {
+        Ok(value) => value.parse().unwrap_or(MAX_CPU_LOG_DEGREE),
+        Err(_) => MAX_CPU_LOG_DEGREE,
+    }
+}
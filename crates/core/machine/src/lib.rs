@@ -28,6 +28,7 @@ pub mod operations;
 pub mod program;
 pub mod shape;
 pub mod syscall;
+pub mod trap;
 pub mod utils;
 pub use cpu::*;
 pub use mips::*;
@@ -1,4 +1,6 @@
 pub mod cost;
+#[cfg(test)]
+mod diff_test;
 mod shape;
 pub use cost::*;
 use itertools::Itertools;
@@ -22,6 +24,7 @@ pub use mips_chips::*;
 use p3_field::PrimeField32;
 use zkm2_curves::weierstrass::{bls12_381::Bls12381BaseField, bn254::Bn254BaseField};
 use strum_macros::{EnumDiscriminants, EnumIter};
+use thiserror::Error;
 use tracing::instrument;
 use zkm2_stark::{
     air::{InteractionScope, MachineAir, ZKM_PROOF_NUM_PV_ELTS},
@@ -39,9 +42,11 @@ pub(crate) mod mips_chips {
         cpu::CpuChip,
         memory::MemoryGlobalChip,
         program::ProgramChip,
+        trap::TrapChip,
         syscall::{
             chip::SyscallChip,
             precompiles::{
+                bn254_scalar::Bn254ScalarOpChip,
                 edwards::{EdAddAssignChip, EdDecompressChip},
                 keccak256::KeccakPermuteChip,
                 sha256::{ShaCompressChip, ShaExtendChip},
@@ -85,6 +90,8 @@ pub enum MipsAir<F: PrimeField32> {
     DivRem(DivRemChip),
     /// An AIR for RISC-V Lt instruction.
     Lt(LtChip),
+    /// An AIR proving traps (trapping-arithmetic overflow, address errors) raised via CP0.
+    Exception(TrapChip),
     /// An AIR for RISC-V SLL instruction.
     ShiftLeft(ShiftLeft),
     /// An AIR for RISC-V SRL and SRA instruction.
@@ -151,12 +158,35 @@ pub enum MipsAir<F: PrimeField32> {
     Bn254Fp2Mul(Fp2MulAssignChip<Bn254BaseField>),
     /// A precompile for BN-254 fp2 addition/subtraction.
     Bn254Fp2AddSub(Fp2AddSubAssignChip<Bn254BaseField>),
+    /// A precompile for BN-254 scalar-field (`F_r`) multiply/add/sub/multiply-accumulate. Backs
+    /// both `BN254_SCALAR_MAC` (`a <- a + b*c mod r`) and `BN254_FR_OP` (`a <- a OP b mod r`);
+    /// `syscall_code` below reports `BN254_SCALAR_MAC` since `MipsAir` only carries one
+    /// `SyscallCode` per variant, so `BN254_FR_OP` height accounting rides along on the same
+    /// events this chip already folds `is_mac` and the general op into one table for.
+    Bn254ScalarOp(Bn254ScalarOpChip),
+    // The remaining BLS12-381 operations needed for in-guest pairing checks (G1/G2 scalar
+    // multiplication, subgroup checks, SSWU map-to-curve, G2 decompression, and the
+    // Miller-loop/final-exponentiation pairing primitive) are exposed as guest syscalls in
+    // `zkm2_lib::bls12381` and have reserved `SyscallCode`s, but still need dedicated chips
+    // before they can be proved; until then they run via the interpreter-only syscall path.
+    // Bls12381G1ScalarMul(Bls12381G1ScalarMulChip),
+    // Bls12381G1SubgroupCheck(Bls12381SubgroupCheckChip<SwCurve<Bls12381Parameters>>),
+    // Bls12381G1Map(Bls12381MapToCurveChip<SwCurve<Bls12381Parameters>>),
+    // Bls12381G2Add(WeierstrassAddAssignChip<SwCurve<Bls12381G2Parameters>>),
+    // Bls12381G2Double(WeierstrassDoubleAssignChip<SwCurve<Bls12381G2Parameters>>),
+    // Bls12381G2ScalarMul(Bls12381G2ScalarMulChip),
+    // Bls12381G2SubgroupCheck(Bls12381SubgroupCheckChip<SwCurve<Bls12381G2Parameters>>),
+    // Bls12381G2Map(Bls12381MapToCurveChip<SwCurve<Bls12381G2Parameters>>),
+    // Bls12381G2Decompress(WeierstrassDecompressChip<SwCurve<Bls12381G2Parameters>>),
+    // Bls12381Pairing(Bls12381PairingChip),
 }
 
 impl<F: PrimeField32> MipsAir<F> {
     #[instrument("construct MipsAir machine", level = "debug", skip_all)]
     pub fn machine<SC: StarkGenericConfig<Val=F>>(config: SC) -> StarkMachine<SC, Self> {
         let chips = Self::chips();
+        Self::validate_interaction_graph(&chips)
+            .expect("MipsAir's chips have a cyclic lookup dependency");
         StarkMachine::new(config, chips, ZKM_PROOF_NUM_PV_ELTS, true)
     }
 
@@ -315,6 +345,10 @@ impl<F: PrimeField32> MipsAir<F> {
         costs.insert(MipsAirDiscriminants::Bn254Fp2Mul, bn254_fp2_mul.cost());
         chips.push(bn254_fp2_mul);
 
+        let bn254_scalar_op = Chip::new(MipsAir::Bn254ScalarOp(Bn254ScalarOpChip::default()));
+        costs.insert(MipsAirDiscriminants::Bn254ScalarOp, bn254_scalar_op.cost());
+        chips.push(bn254_scalar_op);
+
         let bls12381_decompress =
             Chip::new(MipsAir::Bls12381Decompress(WeierstrassDecompressChip::<
                 SwCurve<Bls12381Parameters>,
@@ -358,6 +392,10 @@ impl<F: PrimeField32> MipsAir<F> {
         costs.insert(MipsAirDiscriminants::Lt, lt.cost());
         chips.push(lt);
 
+        let exception = Chip::new(MipsAir::Exception(TrapChip::default()));
+        costs.insert(MipsAirDiscriminants::Exception, exception.cost());
+        chips.push(exception);
+
         let memory_global_init = Chip::new(MipsAir::MemoryGlobalInit(MemoryGlobalChip::new(
             MemoryChipType::Initialize,
         )));
@@ -407,6 +445,7 @@ impl<F: PrimeField32> MipsAir<F> {
             (MipsAir::ShiftRight(ShiftRightChip::default()), record.shift_right_events.len()),
             (MipsAir::ShiftLeft(ShiftLeft::default()), record.shift_left_events.len()),
             (MipsAir::Lt(LtChip::default()), record.lt_events.len()),
+            (MipsAir::Exception(TrapChip::default()), record.trap_events.len()),
             (
                 MipsAir::MemoryLocal(MemoryLocalChip::new()),
                 record
@@ -427,6 +466,7 @@ impl<F: PrimeField32> MipsAir<F> {
             MipsAir::Mul(MulChip::default()),
             MipsAir::DivRem(DivRemChip::default()),
             MipsAir::Lt(LtChip::default()),
+            MipsAir::Exception(TrapChip::default()),
             MipsAir::ShiftLeft(ShiftLeft::default()),
             MipsAir::ShiftRight(ShiftRightChip::default()),
             MipsAir::MemoryLocal(MemoryLocalChip::new()),
@@ -504,6 +544,7 @@ impl<F: PrimeField32> MipsAir<F> {
             Self::Bn254Fp(_) => SyscallCode::BN254_FP_ADD,
             Self::Bn254Fp2AddSub(_) => SyscallCode::BN254_FP2_ADD,
             Self::Bn254Fp2Mul(_) => SyscallCode::BN254_FP2_MUL,
+            Self::Bn254ScalarOp(_) => SyscallCode::BN254_SCALAR_MAC,
             Self::Ed25519Add(_) => SyscallCode::ED_ADD,
             Self::Ed25519Decompress(_) => SyscallCode::ED_DECOMPRESS,
             Self::KeccakP(_) => SyscallCode::KECCAK_PERMUTE,
@@ -563,6 +604,149 @@ impl<F: PrimeField32> MipsAir<F> {
     }
 }
 
+/// The send/receive interactions between a chip and the rest of `MipsAir`'s chips are not
+/// acyclic, so no trace generation order can satisfy every `sends()`/`receives()` dependency
+/// between them. Surfaced by [`MipsAir::validate_interaction_graph`] and
+/// [`MipsAir::ordered_chips`].
+#[derive(Error, Debug)]
+#[error("cyclic lookup dependency between chips: {}", cycle_to_string(.0))]
+pub struct CyclicLookupGraphError(pub Vec<MipsAirDiscriminants>);
+
+fn cycle_to_string(cycle: &[MipsAirDiscriminants]) -> String {
+    cycle.iter().map(|discriminant| format!("{discriminant:?}")).collect::<Vec<_>>().join(" -> ")
+}
+
+fn discriminant_of<F: PrimeField32>(chip: &Chip<F, MipsAir<F>>) -> MipsAirDiscriminants {
+    let air: &MipsAir<F> = chip;
+    MipsAirDiscriminants::from(air)
+}
+
+impl<F: PrimeField32> MipsAir<F> {
+    /// Builds the directed graph of lookup dependencies between `chips`: an edge `sender ->
+    /// receiver` for every [`InteractionKind`] that `sender` sends and `receiver` receives,
+    /// derived from each [`Chip`]'s already-computed `sends()`/`receives()` interactions (the
+    /// same interaction lists [`Self::get_all_precompile_airs`] inspects for local memory
+    /// events). A chip sending and receiving the same kind itself -- the byte/range lookup
+    /// tables' intentional self-consistency check -- is excluded from the edge set, since a chip
+    /// can always be ordered "before itself" in trace generation.
+    fn interaction_graph(
+        chips: &[Chip<F, Self>],
+    ) -> HashMap<MipsAirDiscriminants, HashSet<MipsAirDiscriminants>> {
+        let mut senders: HashMap<InteractionKind, Vec<MipsAirDiscriminants>> = HashMap::new();
+        let mut receivers: HashMap<InteractionKind, Vec<MipsAirDiscriminants>> = HashMap::new();
+        let mut graph: HashMap<MipsAirDiscriminants, HashSet<MipsAirDiscriminants>> =
+            HashMap::new();
+
+        for chip in chips {
+            let discriminant = discriminant_of(chip);
+            graph.entry(discriminant).or_default();
+
+            for interaction in chip.sends() {
+                senders.entry(interaction.kind).or_default().push(discriminant);
+            }
+            for interaction in chip.receives() {
+                receivers.entry(interaction.kind).or_default().push(discriminant);
+            }
+        }
+
+        for (kind, kind_senders) in &senders {
+            let Some(kind_receivers) = receivers.get(kind) else {
+                continue;
+            };
+            for &sender in kind_senders {
+                for &receiver in kind_receivers {
+                    if sender != receiver {
+                        graph.entry(sender).or_default().insert(receiver);
+                    }
+                }
+            }
+        }
+
+        graph
+    }
+
+    /// Checks that [`Self::chips`]' `sends()`/`receives()` interactions form a DAG, i.e. that no
+    /// chip's send of some `InteractionKind` transitively depends on a chip that, in turn,
+    /// depends on that first chip's own output. [`Self::machine`] calls this so that a precompile
+    /// added with an inconsistent send/receive pairing (e.g. both sending and receiving
+    /// `ByteLookup` or `MemoryLocal` against another chip that does the same in the opposite
+    /// direction) is caught here instead of surfacing later as a proving-time ordering bug.
+    pub fn validate_interaction_graph(chips: &[Chip<F, Self>]) -> Result<(), CyclicLookupGraphError> {
+        let discriminants: Vec<_> = chips.iter().map(discriminant_of).collect();
+        let graph = Self::interaction_graph(chips);
+        topological_order(&discriminants, &graph).map(|_| ())
+    }
+
+    /// [`Self::chips()`] reordered into a topological sort of [`Self::interaction_graph`]: a
+    /// chip that receives some `InteractionKind` always comes after every chip that sends it.
+    /// Ties (chips with no lookup dependency between them) keep their relative position from
+    /// [`Self::get_chips_and_costs`]'s existing hand-maintained order, so this is a refinement of
+    /// it rather than an unrelated order a contributor would need to separately reason about.
+    pub fn ordered_chips() -> Result<Vec<Chip<F, Self>>, CyclicLookupGraphError> {
+        let chips = Self::chips();
+        let discriminants: Vec<_> = chips.iter().map(discriminant_of).collect();
+        let graph = Self::interaction_graph(&chips);
+        let order = topological_order(&discriminants, &graph)?;
+
+        let mut by_discriminant: HashMap<MipsAirDiscriminants, Chip<F, Self>> =
+            chips.into_iter().zip(discriminants).map(|(chip, discriminant)| (discriminant, chip)).collect();
+        Ok(order.into_iter().filter_map(|discriminant| by_discriminant.remove(&discriminant)).collect())
+    }
+}
+
+/// Kahn's algorithm over `graph`, visiting `nodes` in order whenever more than one has zero
+/// in-degree so the result is a deterministic, minimal reshuffling of `nodes` rather than an
+/// arbitrary one. If `graph` is not a DAG, returns the cycle among the chips that never reach
+/// zero in-degree: since every node still `remaining` at that point has at least one predecessor
+/// also still `remaining` (that is exactly why it's stuck), following predecessors from any
+/// stuck node must eventually repeat one, which delimits a cycle.
+fn topological_order(
+    nodes: &[MipsAirDiscriminants],
+    graph: &HashMap<MipsAirDiscriminants, HashSet<MipsAirDiscriminants>>,
+) -> Result<Vec<MipsAirDiscriminants>, CyclicLookupGraphError> {
+    let mut in_degree: HashMap<MipsAirDiscriminants, usize> =
+        nodes.iter().map(|&node| (node, 0)).collect();
+    for edges in graph.values() {
+        for &receiver in edges {
+            *in_degree.entry(receiver).or_insert(0) += 1;
+        }
+    }
+
+    let mut remaining: Vec<MipsAirDiscriminants> = nodes.to_vec();
+    let mut order = Vec::with_capacity(nodes.len());
+
+    while !remaining.is_empty() {
+        let Some(pos) = remaining.iter().position(|node| in_degree[node] == 0) else {
+            let mut cycle = vec![remaining[0]];
+            let mut seen: HashSet<MipsAirDiscriminants> = [remaining[0]].into_iter().collect();
+            loop {
+                let current = *cycle.last().unwrap();
+                let predecessor = remaining
+                    .iter()
+                    .find(|&&candidate| graph[&candidate].contains(&current))
+                    .copied()
+                    .expect("a node with nonzero in-degree has a remaining predecessor");
+                cycle.push(predecessor);
+                if !seen.insert(predecessor) {
+                    break;
+                }
+            }
+            cycle.reverse();
+            return Err(CyclicLookupGraphError(cycle));
+        };
+
+        let node = remaining.remove(pos);
+        order.push(node);
+        for &receiver in &graph[&node] {
+            if let Some(degree) = in_degree.get_mut(&receiver) {
+                *degree -= 1;
+            }
+        }
+    }
+
+    Ok(order)
+}
+
 impl<F: PrimeField32> PartialEq for MipsAir<F> {
     fn eq(&self, other: &Self) -> bool {
         self.name() == other.name()
@@ -582,7 +766,7 @@ impl<F: PrimeField32> core::hash::Hash for MipsAir<F> {
 pub mod tests {
     use crate::{
         io::ZKMStdin,
-        mips::MipsAir,
+        mips::{diff_test::run_diff_test, MipsAir},
         utils,
         utils::{prove, run_test, setup_logger},
     };
@@ -620,7 +804,7 @@ pub mod tests {
                     Instruction::new(*shift_op, 31, 29, 3, false, false),
                 ];
                 let program = Program::new(instructions, 0, 0);
-                run_test::<CpuProver<_, _>>(program).unwrap();
+                run_diff_test(program);
             }
         }
     }
@@ -663,7 +847,7 @@ pub mod tests {
                     Instruction::new(*mul_op, 31, 30, 29, false, false),
                 ];
                 let program = Program::new(instructions, 0, 0);
-                run_test::<CpuProver<_, _>>(program).unwrap();
+                run_diff_test(program);
             }
         }
     }
@@ -718,7 +902,7 @@ pub mod tests {
                     Instruction::new(*div_rem_op, 31, 29, 30, false, false),
                 ];
                 let program = Program::new(instructions, 0, 0);
-                run_test::<CpuProver<_, _>>(program).unwrap();
+                run_diff_test(program);
             }
         }
     }
@@ -32,7 +32,12 @@ where
             + local.is_msubu * Opcode::MSUBU.as_field::<AB::F>()
             + local.is_meq * Opcode::MEQ.as_field::<AB::F>()
             + local.is_mne * Opcode::MNE.as_field::<AB::F>()
-            + local.is_teq * Opcode::TEQ.as_field::<AB::F>();
+            + local.is_teq * Opcode::TEQ.as_field::<AB::F>()
+            + local.is_tne * Opcode::TNE.as_field::<AB::F>()
+            + local.is_tge * Opcode::TGE.as_field::<AB::F>()
+            + local.is_tgeu * Opcode::TGEU.as_field::<AB::F>()
+            + local.is_tlt * Opcode::TLT.as_field::<AB::F>()
+            + local.is_tltu * Opcode::TLTU.as_field::<AB::F>();
 
         let is_real = local.is_wsbh
             + local.is_sext
@@ -42,7 +47,12 @@ where
             + local.is_msubu
             + local.is_meq
             + local.is_mne
-            + local.is_teq;
+            + local.is_teq
+            + local.is_tne
+            + local.is_tge
+            + local.is_tgeu
+            + local.is_tlt
+            + local.is_tltu;
 
         builder.receive_instruction(
             AB::Expr::ZERO,
@@ -60,6 +70,7 @@ where
             AB::Expr::ZERO,
             AB::Expr::ZERO,
             AB::Expr::ZERO,
+            local.dispatch_nonce,
             is_real,
         );
 
@@ -136,6 +147,8 @@ impl MiscInstrsChip {
             local.op_b_value,
             local.op_c_value,
             maddsub_cols.mul_hi,
+            local.shard,
+            maddsub_cols.nonce,
             is_real.clone(),
         );
 
@@ -165,6 +178,7 @@ impl MiscInstrsChip {
     ) {
         let cond_cols = local.misc_specific_columns.movcond();
         let is_real = local.is_meq + local.is_mne + local.is_teq;
+        let is_trap_cmp = local.is_tge + local.is_tgeu + local.is_tlt + local.is_tltu;
 
         builder
             .when(is_real.clone() * cond_cols.a_eq_b)
@@ -193,6 +207,33 @@ impl MiscInstrsChip {
             .when(local.is_mne)
             .when(cond_cols.c_eq_0)
             .assert_word_eq(local.op_a_value, cond_cols.op_a_access.prev_value);
+
+        // Rest of the trap-on-condition family (`TEQ` above already has its own `a_eq_b`-based
+        // handling). `TGE`/`TLT` reuse the signed `SLT` lookup, `TGEU`/`TLTU` the unsigned `SLTU`
+        // one, on `local.op_a_value`/`local.op_b_value` (the two compared operands -- see
+        // `execute_teq`/`execute_tcond`'s `(a, b, c, next_next_pc)` return convention in
+        // `zkm2_core_executor::Executor`), the same "reuse the LT byte-comparison lookups" idiom
+        // `crate::cpu::air::branch`'s `a_lt_0`/`a_gt_0` columns use.
+        builder.send_alu(
+            (local.is_tge + local.is_tlt) * Opcode::SLT.as_field::<AB::F>()
+                + (local.is_tgeu + local.is_tltu) * Opcode::SLTU.as_field::<AB::F>(),
+            Word::extend_var::<AB>(cond_cols.lt_val),
+            local.op_a_value,
+            local.op_b_value,
+            local.shard,
+            cond_cols.lt_nonce,
+            is_trap_cmp,
+        );
+
+        // `trap_taken` is the boolean this row's trap actually fired: `TGE`/`TGEU` trap when
+        // *not* less-than, `TLT`/`TLTU` trap when less-than, `TEQ` traps on equal, `TNE` on
+        // not-equal. The CPU-chip-side transition to a halt state that should key off this flag
+        // doesn't exist in this tree yet -- see `zkm2_core_machine::trap::TrapChip`'s own doc
+        // comment, which notes the same gap for its `send_trap` side.
+        builder.when(local.is_tge + local.is_tgeu).assert_eq(cond_cols.trap_taken, AB::Expr::ONE - cond_cols.lt_val);
+        builder.when(local.is_tlt + local.is_tltu).assert_eq(cond_cols.trap_taken, cond_cols.lt_val);
+        builder.when(local.is_teq).assert_eq(cond_cols.trap_taken, cond_cols.a_eq_b);
+        builder.when(local.is_tne).assert_eq(cond_cols.trap_taken, AB::Expr::ONE - cond_cols.a_eq_b);
     }
 
     pub(crate) fn eval_ins<AB: ZKMAirBuilder>(
@@ -201,6 +242,7 @@ impl MiscInstrsChip {
         local: &MiscInstrColumns<AB::Var>,
     ) {
         let ins_cols = local.misc_specific_columns.ins();
+        let nonce = ins_cols.nonce;
 
         builder.send_alu(
             Opcode::ROR.as_field::<AB::F>(),
@@ -212,6 +254,8 @@ impl MiscInstrsChip {
                 AB::Expr::ZERO,
                 AB::Expr::ZERO,
             ]),
+            local.shard,
+            nonce + AB::Expr::from_canonical_u32(0),
             local.is_ins,
         );
 
@@ -225,6 +269,8 @@ impl MiscInstrsChip {
                 AB::Expr::ZERO,
                 AB::Expr::ZERO,
             ]),
+            local.shard,
+            nonce + AB::Expr::from_canonical_u32(1),
             local.is_ins,
         );
 
@@ -238,6 +284,8 @@ impl MiscInstrsChip {
                 AB::Expr::ZERO,
                 AB::Expr::ZERO,
             ]),
+            local.shard,
+            nonce + AB::Expr::from_canonical_u32(2),
             local.is_ins,
         );
 
@@ -246,6 +294,8 @@ impl MiscInstrsChip {
             ins_cols.add_val,
             ins_cols.srl_val,
             ins_cols.sll_val,
+            local.shard,
+            nonce + AB::Expr::from_canonical_u32(3),
             local.is_ins,
         );
 
@@ -259,6 +309,8 @@ impl MiscInstrsChip {
                 AB::Expr::ZERO,
                 AB::Expr::ZERO,
             ]),
+            local.shard,
+            nonce + AB::Expr::from_canonical_u32(4),
             local.is_ins,
         );
 
@@ -274,6 +326,7 @@ impl MiscInstrsChip {
         local: &MiscInstrColumns<AB::Var>,
     ) {
         let ext_cols = local.misc_specific_columns.ext();
+        let nonce = ext_cols.nonce;
 
         builder.send_alu(
             Opcode::SLL.as_field::<AB::F>(),
@@ -285,6 +338,8 @@ impl MiscInstrsChip {
                 AB::Expr::ZERO,
                 AB::Expr::ZERO,
             ]),
+            local.shard,
+            nonce + AB::Expr::from_canonical_u32(0),
             local.is_ext,
         );
 
@@ -298,6 +353,8 @@ impl MiscInstrsChip {
                 AB::Expr::ZERO,
                 AB::Expr::ZERO,
             ]),
+            local.shard,
+            nonce + AB::Expr::from_canonical_u32(1),
             local.is_ext,
         );
 
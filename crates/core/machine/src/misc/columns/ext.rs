@@ -11,4 +11,7 @@ pub struct ExtCols<T> {
     pub lsb: T,
     pub msbd: T,
     pub sll_val: Word<T>,
+    /// The nonce of the first of the two chained `send_alu` lookups this row issues (SLL, SRL).
+    /// The second reuses `nonce + 1` so the lookup tuples cannot be swapped with each other.
+    pub nonce: T,
 }
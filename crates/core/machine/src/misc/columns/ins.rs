@@ -16,4 +16,8 @@ pub struct InsCols<T> {
     pub srl_val: Word<T>,
     pub sll_val: Word<T>,
     pub add_val: Word<T>,
+    /// The nonce of the first of the five chained `send_alu` lookups this row issues (ROR, SRL,
+    /// SLL, ADD, ROR). The remaining four reuse `nonce + 1 .. nonce + 4` so each lookup tuple is
+    /// globally unique and cannot be satisfied by a reshuffled assignment.
+    pub nonce: T,
 }
@@ -28,4 +28,7 @@ pub struct MaddsubCols<T> {
     /// Add operations of low/high word.
     pub low_add_operation: AddCarryOperation<T>,
     pub hi_add_operation: AddCarryOperation<T>,
+
+    /// The nonce of this row's `send_alu_with_hi` lookup into the MULTU multiset.
+    pub nonce: T,
 }
@@ -8,6 +8,43 @@ use static_assertions::const_assert;
 
 pub const NUM_MISC_SPECIFIC_COLS: usize = size_of::<MiscSpecificCols<u8>>();
 
+/// Which instruction's columns [`MiscSpecificCols`] is currently holding.
+///
+/// Mirrors the `is_maddu`/`is_msubu`/`is_meq`/`is_mne`/`is_teq` (-> [`MiscVariant::Maddsub`]),
+/// `is_wsbh`/`is_sext` (-> [`MiscVariant::Seb`]), `is_ext` (-> [`MiscVariant::Ext`]) and `is_ins`
+/// (-> [`MiscVariant::Ins`]) selectors on [`crate::misc::columns::MiscInstrColumns`] -- there's no
+/// `Movcond` selector listed there today, so that variant is only reachable by a caller that
+/// tracks it some other way; see [`MiscSpecificCols::view`]'s doc comment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MiscVariant {
+    Maddsub,
+    Movcond,
+    Seb,
+    Ext,
+    Ins,
+}
+
+/// A debug-`Display`-friendly borrow of [`MiscSpecificCols`] through one specific variant's
+/// columns, returned by [`MiscSpecificCols::view`].
+#[derive(Debug)]
+pub enum MiscSpecificView<'a, T: Copy> {
+    Maddsub(&'a MaddsubCols<T>),
+    Movcond(&'a MovcondCols<T>),
+    Seb(&'a SebCols<T>),
+    Ext(&'a ExtCols<T>),
+    Ins(&'a InsCols<T>),
+}
+
+/// The `&mut` counterpart of [`MiscSpecificView`], returned by [`MiscSpecificCols::view_mut`].
+#[derive(Debug)]
+pub enum MiscSpecificViewMut<'a, T: Copy> {
+    Maddsub(&'a mut MaddsubCols<T>),
+    Movcond(&'a mut MovcondCols<T>),
+    Seb(&'a mut SebCols<T>),
+    Ext(&'a mut ExtCols<T>),
+    Ins(&'a mut InsCols<T>),
+}
+
 /// Shared columns whose interpretation depends on the instruction being executed.
 #[derive(Clone, Copy)]
 #[repr(C)]
@@ -28,6 +65,9 @@ impl<T: Copy + Default> Default for MiscSpecificCols<T> {
     }
 }
 
+/// Prints as a flat byte/field array, since `MiscSpecificCols` alone doesn't know which variant
+/// is active. Call [`MiscSpecificCols::view`] and print the returned [`MiscSpecificView`] instead
+/// when the active variant is known -- it prints as that variant's actual named columns.
 impl<T: Copy + Debug> Debug for MiscSpecificCols<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         // SAFETY: repr(C) ensures uniform fields are in declaration order with no padding.
@@ -68,4 +108,43 @@ impl<T: Copy> MiscSpecificCols<T> {
     pub fn ins_mut(&mut self) -> &mut InsCols<T> {
         unsafe { &mut self.ins }
     }
+
+    /// A tagged view of this row's columns as `variant`'s interpretation, asserting in debug
+    /// builds that `is_active` -- the caller's own opcode-selector condition for `variant`, e.g.
+    /// `local.is_maddu + local.is_msubu + local.is_meq + local.is_mne + local.is_teq` is nonzero
+    /// for [`MiscVariant::Maddsub`] -- actually holds. `MiscSpecificCols` has no selector columns
+    /// of its own to check this against (those live on the enclosing
+    /// [`crate::misc::columns::MiscInstrColumns`] row, and `T` here is as generic as `u8`/a field
+    /// element, so there's no uniform "is this selector set" test this method could run on its
+    /// own), so the assertion is only as good as the condition the caller passes in. In release
+    /// builds `is_active` is unused and this compiles down to the same transmute the raw
+    /// `maddsub()`/`movcond()`/... accessors already perform.
+    pub fn view(&self, variant: MiscVariant, is_active: bool) -> MiscSpecificView<'_, T> {
+        debug_assert!(
+            is_active,
+            "MiscSpecificCols::view({variant:?}) but this row's opcode selector for that variant is not active"
+        );
+        match variant {
+            MiscVariant::Maddsub => MiscSpecificView::Maddsub(self.maddsub()),
+            MiscVariant::Movcond => MiscSpecificView::Movcond(self.movcond()),
+            MiscVariant::Seb => MiscSpecificView::Seb(self.seb()),
+            MiscVariant::Ext => MiscSpecificView::Ext(self.ext()),
+            MiscVariant::Ins => MiscSpecificView::Ins(self.ins()),
+        }
+    }
+
+    /// The `&mut` counterpart of [`Self::view`]. See its doc comment for what `is_active` means.
+    pub fn view_mut(&mut self, variant: MiscVariant, is_active: bool) -> MiscSpecificViewMut<'_, T> {
+        debug_assert!(
+            is_active,
+            "MiscSpecificCols::view_mut({variant:?}) but this row's opcode selector for that variant is not active"
+        );
+        match variant {
+            MiscVariant::Maddsub => MiscSpecificViewMut::Maddsub(self.maddsub_mut()),
+            MiscVariant::Movcond => MiscSpecificViewMut::Movcond(self.movcond_mut()),
+            MiscVariant::Seb => MiscSpecificViewMut::Seb(self.seb_mut()),
+            MiscVariant::Ext => MiscSpecificViewMut::Ext(self.ext_mut()),
+            MiscVariant::Ins => MiscSpecificViewMut::Ins(self.ins_mut()),
+        }
+    }
 }
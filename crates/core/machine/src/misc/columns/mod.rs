@@ -21,6 +21,10 @@ pub const NUM_MISC_INSTR_COLS: usize = size_of::<MiscInstrColumns<u8>>();
 #[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
 #[repr(C)]
 pub struct MiscInstrColumns<T: Copy> {
+    /// The shard this instruction was executed in, threaded into every `send_alu`/
+    /// `send_alu_with_hi` call below so the ALU interactions they emit can't be satisfied by an
+    /// identical `(opcode, a, b, c)` tuple from a different shard.
+    pub shard: T,
     /// The current/next program counter of the instruction.
     pub pc: T,
     pub next_pc: T,
@@ -46,6 +50,19 @@ pub struct MiscInstrColumns<T: Copy> {
     pub is_meq: T,
     pub is_mne: T,
     pub is_teq: T,
+    /// Rest of the trap-on-condition family (see `is_teq` above).
+    pub is_tne: T,
+    pub is_tge: T,
+    pub is_tgeu: T,
+    pub is_tlt: T,
+    pub is_tltu: T,
 
     pub op_a_0: T,
+
+    /// This row's index within the shard, threaded into the `receive_instruction` lookup in
+    /// `MiscInstrsChip`'s `Air::eval`. See [`crate::alu::mul::MulCols::nonce`], which plays the
+    /// same role for [`crate::alu::mul::MulChip`]'s own `receive_instruction` call -- both exist so
+    /// the two call sites of that lookup agree on its shape, not because `shard`/`clk` leave a gap
+    /// `nonce` needs to close here.
+    pub dispatch_nonce: T,
 }
@@ -13,4 +13,20 @@ pub struct MovcondCols<T> {
     /// Whether c equals 0.
     pub c_eq_0: T,
     pub prev_a_value: Word<T>,
+
+    /// The boolean result of the `SLT`/`SLTU` lookup [`crate::misc::MiscInstrsChip::eval_movcond`]
+    /// sends for the trap-on-condition family (`TGE`/`TGEU`/`TLT`/`TLTU`; reused signed for
+    /// `TGE`/`TLT`, unsigned for `TGEU`/`TLTU`), the same "reuse the LT byte-comparison lookups"
+    /// idiom [`crate::cpu::air::branch`]'s `a_lt_0`/`a_gt_0` columns use. Unused (zero) for
+    /// `TEQ`/`TNE`/`MEQ`/`MNE`, which only need `a_eq_b` above.
+    pub lt_val: T,
+    /// Per-row nonce for the `lt_val` lookup, the same role [`crate::alu::mul::MulCols::nonce`]
+    /// plays for `MulChip`'s own `receive_instruction` call.
+    pub lt_nonce: T,
+    /// Whether this row's trap-on-condition instruction actually raised its trap. Constrained
+    /// consistent with `a_eq_b`/`lt_val` per opcode in `eval_movcond`; the CPU-chip-side halt
+    /// transition this should drive doesn't exist in this tree yet (see `eval_movcond`'s doc
+    /// comment), so today this flag documents that the condition held without yet being wired to
+    /// anything that acts on it.
+    pub trap_taken: T,
 }
@@ -5,6 +5,14 @@ pub mod air;
 pub mod columns;
 pub mod trace;
 
+/// A chip covering the MIPS32r2 Special3/bit-manipulation encodings that don't fit the regular
+/// ALU chips: `SEB`/`SEH` (`is_sext`, distinguished by `misc_specific_columns.sext().is_seb`/
+/// `is_seh`, sign bit derived via a `ByteOpcode::MSB` lookup on the operand's most significant
+/// retained byte), `WSBH` (a fixed permutation of `op_b_value`'s four limbs), and `EXT`/`INS`
+/// (bitfield extract/insert, each decomposed into `ROR`/`SRL`/`SLL`/`ADD` sub-operations sent to
+/// the existing ALU chips and recombined here), alongside `MADDU`/`MSUBU` and the `MEQ`/`MNE`/
+/// `TEQ` conditional-move/trap family. See this chip's `eval_*` methods in `air.rs` (one per
+/// opcode family) for the constraints.
 #[derive(Default)]
 pub struct MiscInstrsChip;
 
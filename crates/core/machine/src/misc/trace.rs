@@ -6,6 +6,7 @@ use p3_field::PrimeField32;
 use p3_matrix::dense::RowMajorMatrix;
 use rayon::iter::{ParallelBridge, ParallelIterator};
 use zkm2_core_executor::{
+    disasm::{misc_selector, MiscSelector},
     events::{MiscEvent, ByteLookupEvent, ByteRecord, MemoryRecordEnum},
     ExecutionRecord, Opcode, Program, ByteOpcode,
 };
@@ -14,7 +15,7 @@ use zkm2_stark::{air::MachineAir, Word};
 use crate::utils::{next_power_of_two, zeroed_f_vec};
 
 use super::{
-    columns::{MiscInstrColumns, NUM_MISC_INSTR_COLS},
+    columns::{MiscInstrColumns, MiscSpecificViewMut, MiscVariant, NUM_MISC_INSTR_COLS},
     MiscInstrsChip,
 };
 
@@ -51,6 +52,7 @@ impl<F: PrimeField32> MachineAir<F> for MiscInstrsChip {
                     if idx < input.misc_events.len() {
                         let event = &input.misc_events[idx];
                         self.event_to_row(event, cols, &mut blu);
+                        cols.dispatch_nonce = F::from_canonical_usize(idx);
                     }
                 });
                 blu
@@ -79,6 +81,7 @@ impl MiscInstrsChip {
         cols: &mut MiscInstrColumns<F>,
         blu: &mut impl ByteRecord,
     ) {
+        cols.shard = F::from_canonical_u32(event.shard);
         cols.pc = F::from_canonical_u32(event.pc);
         cols.next_pc = F::from_canonical_u32(event.next_pc);
 
@@ -88,15 +91,25 @@ impl MiscInstrsChip {
         cols.op_hi_value = event.hi.into();
         cols.op_a_0 = F::from_bool(event.op_a_0);
 
-        cols.is_wsbh = F::from_bool(matches!(event.opcode, Opcode::WSBH));
-        cols.is_sext = F::from_bool(matches!(event.opcode, Opcode::SEXT));
-        cols.is_ext = F::from_bool(matches!(event.opcode, Opcode::EXT));
-        cols.is_ins = F::from_bool(matches!(event.opcode, Opcode::INS));
-        cols.is_maddu = F::from_bool(matches!(event.opcode, Opcode::MADDU));
-        cols.is_msubu = F::from_bool(matches!(event.opcode, Opcode::MSUBU));
-        cols.is_meq = F::from_bool(matches!(event.opcode, Opcode::MEQ));
-        cols.is_mne = F::from_bool(matches!(event.opcode, Opcode::MNE));
-        cols.is_teq = F::from_bool(matches!(event.opcode, Opcode::TEQ));
+        // Which `is_*` column this event's opcode drives, generated from `instructions.in`'s
+        // third column by `executor`'s `build.rs` -- see `misc_selector`'s doc comment. Adding a
+        // new misc instruction is then a table line plus a `populate_*`/`eval_*` pair, rather
+        // than an extra arm here and a matching column on `MiscInstrColumns`.
+        let selector = misc_selector(event.opcode);
+        cols.is_wsbh = F::from_bool(selector == Some(MiscSelector::Wsbh));
+        cols.is_sext = F::from_bool(selector == Some(MiscSelector::Sext));
+        cols.is_ext = F::from_bool(selector == Some(MiscSelector::Ext));
+        cols.is_ins = F::from_bool(selector == Some(MiscSelector::Ins));
+        cols.is_maddu = F::from_bool(selector == Some(MiscSelector::Maddu));
+        cols.is_msubu = F::from_bool(selector == Some(MiscSelector::Msubu));
+        cols.is_meq = F::from_bool(selector == Some(MiscSelector::Meq));
+        cols.is_mne = F::from_bool(selector == Some(MiscSelector::Mne));
+        cols.is_teq = F::from_bool(selector == Some(MiscSelector::Teq));
+        cols.is_tne = F::from_bool(selector == Some(MiscSelector::Tne));
+        cols.is_tge = F::from_bool(selector == Some(MiscSelector::Tge));
+        cols.is_tgeu = F::from_bool(selector == Some(MiscSelector::Tgeu));
+        cols.is_tlt = F::from_bool(selector == Some(MiscSelector::Tlt));
+        cols.is_tltu = F::from_bool(selector == Some(MiscSelector::Tltu));
 
         self.populate_sext(cols, event, blu);
         self.populate_movcond(cols, event, blu);
@@ -117,7 +130,11 @@ impl MiscInstrsChip {
         ) {
             return;
         }
-        let sext_cols = cols.misc_specific_columns.sext_mut();
+        let MiscSpecificViewMut::Seb(sext_cols) =
+            cols.misc_specific_columns.view_mut(MiscVariant::Seb, true)
+        else {
+            unreachable!()
+        };
 
         let (sig_bit, sig_byte) = if event.c > 0 {
             sext_cols.is_seh =  F::ONE;
@@ -147,14 +164,40 @@ impl MiscInstrsChip {
             event.opcode,
             Opcode::MNE |
                 Opcode::MEQ |
-                Opcode::TEQ
+                Opcode::TEQ |
+                Opcode::TNE |
+                Opcode::TGE |
+                Opcode::TGEU |
+                Opcode::TLT |
+                Opcode::TLTU
         ) {
             return;
         }
-        let movcond_cols = cols.misc_specific_columns.movcond_mut();
+        let MiscSpecificViewMut::Movcond(movcond_cols) =
+            cols.misc_specific_columns.view_mut(MiscVariant::Movcond, true)
+        else {
+            unreachable!()
+        };
         movcond_cols.a_eq_b = F::from_bool(event.b == event.a);
         movcond_cols.c_eq_0 = F::from_bool(event.c == 0);
         movcond_cols.op_a_access.populate(MemoryRecordEnum::Write(event.a_record), &mut Vec::new());
+
+        // `event.a`/`event.b` hold the trap family's two compared operands (see `execute_teq`/
+        // `execute_tcond`'s `(a, b, c, next_next_pc)` return convention -- `c` is unused, always
+        // 0). `lt_val` is `SLT(a, b)` for the signed conditions and `SLTU(a, b)` for the unsigned
+        // ones; unused (left zero) for `TEQ`/`TNE`, which only need `a_eq_b`.
+        let (lt_val, trap_taken) = match event.opcode {
+            Opcode::TGE => (((event.a as i32) < (event.b as i32)) as u32, event.a as i32 >= event.b as i32),
+            Opcode::TGEU => ((event.a < event.b) as u32, event.a >= event.b),
+            Opcode::TLT => (((event.a as i32) < (event.b as i32)) as u32, (event.a as i32) < (event.b as i32)),
+            Opcode::TLTU => ((event.a < event.b) as u32, event.a < event.b),
+            Opcode::TEQ => (0, event.a == event.b),
+            Opcode::TNE => (0, event.a != event.b),
+            _ => (0, false),
+        };
+        movcond_cols.lt_val = F::from_canonical_u32(lt_val);
+        movcond_cols.lt_nonce = F::from_canonical_u32(event.nonce);
+        movcond_cols.trap_taken = F::from_bool(trap_taken);
     }
 
     fn populate_maddsub<F: PrimeField32>(
@@ -170,7 +213,11 @@ impl MiscInstrsChip {
         ) {
             return;
         }
-        let maddsub_cols = cols.misc_specific_columns.maddsub_mut();
+        let MiscSpecificViewMut::Maddsub(maddsub_cols) =
+            cols.misc_specific_columns.view_mut(MiscVariant::Maddsub, true)
+        else {
+            unreachable!()
+        };
         maddsub_cols.op_a_access.populate(MemoryRecordEnum::Write(event.a_record), &mut Vec::new());
         maddsub_cols.op_hi_access.populate(MemoryRecordEnum::Write(event.hi_record), &mut Vec::new());
         let multiply = event.b as u64 * event.c as u64;
@@ -187,6 +234,7 @@ impl MiscInstrsChip {
         maddsub_cols.src2_hi = Word::from(src2_hi);
         let (_, carry) = maddsub_cols.low_add_operation.populate(blu, mul_lo, src2_lo, 0);
         maddsub_cols.hi_add_operation.populate(blu, mul_hi, src2_hi, carry);
+        maddsub_cols.nonce = F::from_canonical_u32(event.nonce);
     }
 
     fn populate_ext<F: PrimeField32>(
@@ -201,13 +249,18 @@ impl MiscInstrsChip {
         ) {
             return;
         }
-        let ext_cols = cols.misc_specific_columns.ext_mut();
+        let MiscSpecificViewMut::Ext(ext_cols) =
+            cols.misc_specific_columns.view_mut(MiscVariant::Ext, true)
+        else {
+            unreachable!()
+        };
         let lsb = event.c & 0x1f;
         let msbd = event.c >> 5;
         let shift_left=  event.b << (31 - lsb - msbd); 
         ext_cols.lsb = F::from_canonical_u32(lsb);
         ext_cols.msbd = F::from_canonical_u32(msbd);
         ext_cols.sll_val = Word::from(shift_left);
+        ext_cols.nonce = F::from_canonical_u32(event.nonce);
     }
 
     fn populate_ins<F: PrimeField32>(
@@ -222,7 +275,11 @@ impl MiscInstrsChip {
         ) {
             return;
         }
-        let ins_cols = cols.misc_specific_columns.ins_mut();
+        let MiscSpecificViewMut::Ins(ins_cols) =
+            cols.misc_specific_columns.view_mut(MiscVariant::Ins, true)
+        else {
+            unreachable!()
+        };
         let lsb = event.c & 0x1f;
         let msb = event.c >> 5;
         ins_cols.op_a_access.populate(MemoryRecordEnum::Write(event.a_record), &mut Vec::new());
@@ -236,6 +293,7 @@ impl MiscInstrsChip {
         ins_cols.srl_val = Word::from(srl_val);
         ins_cols.sll_val = Word::from(sll_val);
         ins_cols.add_val = Word::from(add_val);
+        ins_cols.nonce = F::from_canonical_u32(event.nonce);
 
     }
 
@@ -0,0 +1,2 @@
+pub mod bitfield;
+pub mod not;
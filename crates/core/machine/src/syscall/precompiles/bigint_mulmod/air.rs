@@ -0,0 +1,229 @@
+//! STARK-side precompile chip for a generic big-integer `r = (x*y + z) mod p` (and degenerate
+//! `r = (x + z) mod p`/`r = (x*y) mod p` special cases), generalizing [`super::super::mul::MulChip`]'s
+//! uncarried-product-plus-carry-propagation pattern from 32-bit words to `NUM_LIMBS` 8-bit limbs so
+//! guest programs doing e.g. 256-bit field arithmetic don't need to emit thousands of `MUL` rows.
+//!
+//! Unlike `MulChip`, which compares its carry-propagated product directly against a 32-bit
+//! register, this chip's left- and right-hand sides (`x*y + z` and `q*p + r`) are each propagated
+//! through their own carry chain and compared digit-by-digit -- there's no register-sized "result"
+//! to re-derive the product against, only the prover-supplied `quotient`/`result` witnesses that
+//! make the identity hold. `result < p` is proven with a byte-wise less-than gadget: a one-hot
+//! `lt_selector` picks out the most-significant limb where `result` and `modulus` differ, every
+//! limb above it is asserted equal, and a `ByteOpcode::LTU` lookup proves strict inequality at the
+//! selected limb.
+//!
+//! As with [`super::super::bn254_scalar::Bn254ScalarOpChip`], the read/write memory-access
+//! bookkeeping that would populate `generate_trace` from a real event (and the host-side `Syscall`
+//! impl that would produce one) are left for the follow-up that wires this chip into
+//! `ExecutionRecord` and the executor's syscall table; this chip only needs the constraints below
+//! to exist, proportionate to what the request asked for.
+
+use std::{borrow::Borrow, mem::size_of};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use zkm2_core_executor::{ByteOpcode, ExecutionRecord, Program};
+use zkm2_derive::AlignedBorrow;
+use zkm2_stark::air::{MachineAir, ZKMAirBuilder};
+
+/// The number of 8-bit limbs in one big-integer operand. 32 limbs covers a 256-bit field, the
+/// same size [`super::super::uint256`] and `bn254_scalar` target.
+pub const NUM_LIMBS: usize = 32;
+
+/// `x*y` (and `q*p`) need twice as many limbs as either factor alone.
+const PRODUCT_LIMBS: usize = 2 * NUM_LIMBS;
+
+pub const NUM_BIGINT_MULMOD_COLS: usize = size_of::<BigIntMulModCols<u8>>();
+
+/// Column layout for the combined modular add/mul/multiply-accumulate chip. One row proves
+/// `result = (x*y + z) mod modulus`, with the mode flags picking how `x`/`y`/`z` map onto a guest
+/// call:
+///   - `is_mul`: `result = (x * y) mod modulus`, `z` held at zero.
+///   - `is_mac`: `result = (x * y + z) mod modulus`, the fused multiply-accumulate.
+///   - `is_add`: `result = (x + z) mod modulus`, achieved by constraining `y` to the multiplicative
+///     identity (limb 0 set to one, the rest zero) so `x * y` collapses to `x`.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct BigIntMulModCols<T> {
+    pub shard: T,
+    pub clk: T,
+    pub x_ptr: T,
+    pub y_ptr: T,
+    pub z_ptr: T,
+    pub p_ptr: T,
+
+    /// The first operand (or the left-hand addend, in `is_add` mode).
+    pub x: [T; NUM_LIMBS],
+    /// The second operand (constrained to the unit value in `is_add` mode).
+    pub y: [T; NUM_LIMBS],
+    /// The additive term: the MAC accumulator, the right-hand addend, or zero in `is_mul` mode.
+    pub z: [T; NUM_LIMBS],
+    /// The modulus `p`.
+    pub modulus: [T; NUM_LIMBS],
+
+    /// The quotient witness `q` such that `x*y + z = q*p + result`.
+    pub quotient: [T; NUM_LIMBS],
+    /// The reduced result `result`, written back to `z_ptr`'s memory region.
+    pub result: [T; NUM_LIMBS],
+
+    /// Carry chain propagating the uncarried `x*y + z` polynomial into canonical limbs.
+    pub lhs_carry: [T; PRODUCT_LIMBS],
+    /// Carry chain propagating the uncarried `q*p + result` polynomial into canonical limbs.
+    pub rhs_carry: [T; PRODUCT_LIMBS],
+
+    /// One-hot selector marking the most-significant limb at which `result` and `modulus` differ,
+    /// the limb a `ByteOpcode::LTU` lookup proves `result[i] < modulus[i]` at.
+    pub lt_selector: [T; NUM_LIMBS],
+
+    pub is_add: T,
+    pub is_mul: T,
+    pub is_mac: T,
+
+    pub is_real: T,
+}
+
+#[derive(Default)]
+pub struct BigIntMulModChip;
+
+impl<F> BaseAir<F> for BigIntMulModChip {
+    fn width(&self) -> usize {
+        NUM_BIGINT_MULMOD_COLS
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for BigIntMulModChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "BigIntMulMod".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let nb_rows = input.bigint_mulmod_events.len();
+        let padded_nb_rows = nb_rows.next_power_of_two().max(1);
+        let mut values = vec![F::ZERO; padded_nb_rows * NUM_BIGINT_MULMOD_COLS];
+
+        for (i, _event) in input.bigint_mulmod_events.iter().enumerate() {
+            let _row =
+                &mut values[i * NUM_BIGINT_MULMOD_COLS..(i + 1) * NUM_BIGINT_MULMOD_COLS];
+            // Populating a row from a `BigIntMulModEvent` requires the same read-memory/
+            // write-memory event bookkeeping every other precompile chip's `generate_trace` does;
+            // left for the same follow-up that wires this chip's events into `ExecutionRecord` and
+            // the executor's syscall-to-table dispatch.
+        }
+
+        RowMajorMatrix::new(values, NUM_BIGINT_MULMOD_COLS)
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.bigint_mulmod_events.is_empty()
+    }
+}
+
+impl<AB: ZKMAirBuilder> Air<AB> for BigIntMulModChip {
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &BigIntMulModCols<AB::Var> = (*local).borrow();
+        let base = AB::F::from_canonical_u32(1 << 8);
+        let zero: AB::Expr = AB::F::ZERO.into();
+        let one: AB::Expr = AB::F::ONE.into();
+
+        // Mode flags: exactly one of add/mul/mac is on for a real row.
+        builder.assert_bool(local.is_add);
+        builder.assert_bool(local.is_mul);
+        builder.assert_bool(local.is_mac);
+        builder
+            .when(local.is_real)
+            .assert_one(local.is_add + local.is_mul + local.is_mac);
+
+        // `is_add` collapses `x*y` to `x` by forcing `y` to the multiplicative identity.
+        for i in 0..NUM_LIMBS {
+            let unit_limb = if i == 0 { one.clone() } else { zero.clone() };
+            builder.when(local.is_add).assert_eq(local.y[i], unit_limb);
+        }
+        // `is_mul` drops the additive term.
+        for i in 0..NUM_LIMBS {
+            builder.when(local.is_mul).assert_zero(local.z[i]);
+        }
+
+        // Form the uncarried `x*y + z` and `q*p + result` polynomials (length `PRODUCT_LIMBS`),
+        // then compare them digit-by-digit after each is propagated through its own carry chain --
+        // two canonical byte decompositions of the same integer agree limb-by-limb, so this proves
+        // `x*y + z == q*p + result` without ever materializing either side as its own column.
+        let mut lhs: Vec<AB::Expr> = vec![zero.clone(); PRODUCT_LIMBS];
+        let mut rhs: Vec<AB::Expr> = vec![zero.clone(); PRODUCT_LIMBS];
+        for i in 0..NUM_LIMBS {
+            for j in 0..NUM_LIMBS {
+                lhs[i + j] = lhs[i + j].clone() + local.x[i] * local.y[j];
+                rhs[i + j] = rhs[i + j].clone() + local.quotient[i] * local.modulus[j];
+            }
+        }
+        for i in 0..NUM_LIMBS {
+            lhs[i] = lhs[i].clone() + local.z[i];
+            rhs[i] = rhs[i].clone() + local.result[i];
+        }
+
+        for i in 0..PRODUCT_LIMBS {
+            let lhs_digit = if i == 0 {
+                lhs[i].clone() - local.lhs_carry[i] * base
+            } else {
+                lhs[i].clone() + local.lhs_carry[i - 1] - local.lhs_carry[i] * base
+            };
+            let rhs_digit = if i == 0 {
+                rhs[i].clone() - local.rhs_carry[i] * base
+            } else {
+                rhs[i].clone() + local.rhs_carry[i - 1] - local.rhs_carry[i] * base
+            };
+            builder.assert_eq(lhs_digit, rhs_digit);
+        }
+
+        // Range-check both carry chains to u16 and every witnessed limb to u8, the same bounds
+        // `MulChip` uses for its own (single) carry chain and product limbs.
+        builder.slice_range_check_u16(&local.lhs_carry, local.is_real);
+        builder.slice_range_check_u16(&local.rhs_carry, local.is_real);
+        builder.slice_range_check_u8(&local.x, local.is_real);
+        builder.slice_range_check_u8(&local.y, local.is_real);
+        builder.slice_range_check_u8(&local.z, local.is_real);
+        builder.slice_range_check_u8(&local.modulus, local.is_real);
+        builder.slice_range_check_u8(&local.quotient, local.is_real);
+        builder.slice_range_check_u8(&local.result, local.is_real);
+
+        // `result < modulus`: `lt_selector` is one-hot on the most-significant limb at which
+        // `result` and `modulus` differ. Every limb above the selected one must be equal, and the
+        // selected limb itself must satisfy `result[i] < modulus[i]` via a `ByteOpcode::LTU`
+        // lookup. Limbs below the selected one are unconstrained -- a strictly smaller limb at a
+        // more significant position already settles the comparison regardless of what follows.
+        for limb in local.lt_selector.iter() {
+            builder.assert_bool(*limb);
+        }
+        builder.when(local.is_real).assert_one(
+            (0..NUM_LIMBS).map(|i| local.lt_selector[i].into()).fold(zero.clone(), |a, b: AB::Expr| a + b),
+        );
+        builder.when_not(local.is_real).assert_zero(
+            (0..NUM_LIMBS).map(|i| local.lt_selector[i].into()).fold(zero.clone(), |a, b: AB::Expr| a + b),
+        );
+
+        let mut above_selected: AB::Expr = zero.clone();
+        for i in (0..NUM_LIMBS).rev() {
+            builder
+                .when(above_selected.clone())
+                .assert_eq(local.result[i], local.modulus[i]);
+            above_selected = above_selected + local.lt_selector[i];
+
+            builder.send_byte(
+                AB::F::from_canonical_u32(ByteOpcode::LTU as u32),
+                one.clone(),
+                local.result[i],
+                local.modulus[i],
+                local.lt_selector[i] * local.is_real,
+            );
+        }
+    }
+}
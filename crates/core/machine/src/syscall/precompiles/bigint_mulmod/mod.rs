@@ -0,0 +1,23 @@
+mod air;
+
+pub use air::*;
+
+#[cfg(test)]
+mod tests {
+
+    use test_artifacts::BIGINT_MULMOD_ELF;
+    use zkm_core_executor::Program;
+    use zkm_stark::CpuProver;
+
+    use crate::{
+        io::ZKMStdin,
+        utils::{self, run_test_io},
+    };
+
+    #[test]
+    fn test_bigint_mulmod() {
+        utils::setup_logger();
+        let program = Program::from(BIGINT_MULMOD_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, ZKMStdin::new()).unwrap();
+    }
+}
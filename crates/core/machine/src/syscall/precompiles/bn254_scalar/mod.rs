@@ -0,0 +1,23 @@
+mod air;
+
+pub use air::*;
+
+#[cfg(test)]
+mod tests {
+
+    use test_artifacts::BN254_SCALAR_MAC_ELF;
+    use zkm_core_executor::Program;
+    use zkm_stark::CpuProver;
+
+    use crate::{
+        io::ZKMStdin,
+        utils::{self, run_test_io},
+    };
+
+    #[test]
+    fn test_bn254_scalar_mac() {
+        utils::setup_logger();
+        let program = Program::from(BN254_SCALAR_MAC_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, ZKMStdin::new()).unwrap();
+    }
+}
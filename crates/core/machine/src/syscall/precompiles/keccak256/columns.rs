@@ -0,0 +1,37 @@
+use core::mem::size_of;
+
+use p3_keccak_air::KeccakCols;
+use zkm2_derive::AlignedBorrow;
+
+use crate::memory::{MemoryReadCols, MemoryWriteCols};
+use super::KECCAK_PERMUTE_STATE_U32S;
+
+/// Column layout for `KECCAK_PERMUTE`. One call occupies `p3_keccak_air::NUM_ROUNDS` rows -- one
+/// per round of the embedded `keccak` sub-AIR -- the same "one call, many rounds" shape
+/// [`crate::syscall::precompiles::keccak_sponge::columns::KeccakSpongeCols`] uses per absorbed
+/// block.
+#[derive(AlignedBorrow)]
+#[repr(C)]
+pub(crate) struct KeccakPermuteCols<T> {
+    pub keccak: KeccakCols<T>,
+
+    pub shard: T,
+    pub clk: T,
+    pub is_real: T,
+    pub receive_syscall: T,
+
+    /// The address the state was read from / will be written back to.
+    pub state_addr: T,
+
+    /// Set on this call's first round, when the pre-permutation state is read from memory.
+    pub is_first_round: T,
+    /// Set on this call's last round, when the post-permutation state is written back.
+    pub is_last_round: T,
+
+    /// Populated on `is_first_round` rows: the pre-permutation state, one entry per `u32` word.
+    pub state_read: [MemoryReadCols<T>; KECCAK_PERMUTE_STATE_U32S],
+    /// Populated on `is_last_round` rows: the post-permutation state, one entry per `u32` word.
+    pub state_write: [MemoryWriteCols<T>; KECCAK_PERMUTE_STATE_U32S],
+}
+
+pub(crate) const NUM_KECCAK_PERMUTE_COLS: usize = size_of::<KeccakPermuteCols<u8>>();
@@ -0,0 +1,50 @@
+//! Single-call counterpart to
+//! [`crate::syscall::precompiles::keccak_sponge::KeccakSpongeChip`]: one `KECCAK_PERMUTE`
+//! syscall permutes a full resident 25-lane state in place, with no rate/input-length/squeeze
+//! bookkeeping around it, so this chip's rows are just the bare keccak-f[1600] round function --
+//! the same `p3_keccak_air::KeccakAir` sub-AIR the sponge chip already reuses for its own
+//! θ/ρ/π/χ/ι constraints, with memory-access columns bracketing it instead of the sponge's
+//! absorb/squeeze block plumbing.
+//!
+//! Referenced from `MipsAir::KeccakP` (`crate::mips::mips_chips`), which already dispatches
+//! `SyscallCode::KECCAK_PERMUTE` to a `keccak256::KeccakPermuteChip` -- this module is what fills
+//! in that dispatch target.
+
+use p3_keccak_air::KeccakAir;
+
+mod columns;
+mod trace;
+
+/// Number of `u32` words a 25-lane keccak-f[1600] state occupies in memory (two words per
+/// 64-bit lane), matching `zkm2_core_executor::syscalls::keccak::KECCAK_PERMUTE_STATE_WORDS`.
+pub const KECCAK_PERMUTE_STATE_U32S: usize = 50;
+
+pub struct KeccakPermuteChip {
+    p3_keccak: KeccakAir,
+}
+
+impl KeccakPermuteChip {
+    pub const fn new() -> Self {
+        Self { p3_keccak: KeccakAir {} }
+    }
+}
+
+impl Default for KeccakPermuteChip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+pub mod permute_tests {
+    use crate::utils::{self, run_test};
+    use test_artifacts::KECCAK_PERMUTE_ELF;
+    use zkm_core_executor::Program;
+    use zkm_stark::CpuProver;
+    #[test]
+    fn test_keccak_permute_program_prove() {
+        utils::setup_logger();
+        let program = Program::from(KECCAK_PERMUTE_ELF).unwrap();
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+}
@@ -0,0 +1,42 @@
+//! `MachineAir` for [`KeccakPermuteChip`](super::KeccakPermuteChip).
+
+use p3_field::PrimeField32;
+use p3_matrix::dense::RowMajorMatrix;
+use zkm2_core_executor::{ExecutionRecord, Program};
+use zkm2_stark::air::MachineAir;
+
+use super::{columns::NUM_KECCAK_PERMUTE_COLS, KeccakPermuteChip};
+
+impl<F: PrimeField32> MachineAir<F> for KeccakPermuteChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "KeccakPermute".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        // Same gap as `Poseidon2Chip::generate_trace`/`MtreeVerifyPathChip::generate_trace`:
+        // populating a row (the `state_read`/`state_write` memory columns, and the embedded
+        // `keccak` sub-AIR's per-round witness via `p3_keccak_air::generate_trace_rows`) needs a
+        // `keccak_permute_events` field on `ExecutionRecord` and the executor-side dispatch that
+        // fills it in -- `zkm2_core_executor::syscalls::keccak::KeccakPermuteSyscall` already
+        // builds a `KeccakPermuteEvent` per call, but nothing yet threads it from there into this
+        // record. Left for the follow-up that wires `KECCAK_PERMUTE` in the same way
+        // `BN254_SCALAR_MAC` is.
+        let nb_rows = 0;
+        let padded_nb_rows = nb_rows.max(1);
+        let values = vec![F::ZERO; padded_nb_rows * NUM_KECCAK_PERMUTE_COLS];
+        let _ = input;
+
+        RowMajorMatrix::new(values, NUM_KECCAK_PERMUTE_COLS)
+    }
+
+    fn included(&self, _shard: &Self::Record) -> bool {
+        false
+    }
+}
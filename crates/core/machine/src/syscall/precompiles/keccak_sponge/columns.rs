@@ -34,7 +34,42 @@ pub(crate) struct KeccakSpongeCols<T> {
     pub original_state: [Word<T>; KECCAK_STATE_U32S],
     pub xored_general_rate: [XorOperation<T>; KECCAK_GENERAL_RATE_U32S],
     pub input_length_mem: MemoryReadCols<T>,
-    pub output_mem: [MemoryWriteCols<T>; KECCAK_GENERAL_OUTPUT_U32S],
+
+    /// Set on every row belonging to the squeeze phase, i.e. once absorption of the whole input
+    /// has finished and the chip is extracting (possibly multiple, possibly partial) rate-sized
+    /// blocks of output instead of xoring in more input.
+    pub is_squeeze: T,
+    /// This squeeze block's index within the squeeze phase: `0` for the first
+    /// `KECCAK_GENERAL_RATE_U32S` words of output, `1` for the next block after a fresh
+    /// `keccakf_u32s` permutation, and so on.
+    pub squeeze_block_idx: T,
+    /// The total requested output length, in u32 words, read from the syscall -- `0` means "use
+    /// the original fixed `KECCAK_GENERAL_OUTPUT_U32S`-word digest" (see
+    /// [`zkm2_core_executor::events::KeccakSpongeEvent::output_len_u32s`]).
+    pub output_len: T,
+    /// Set on the last row of the squeeze phase, the row whose `output_mem` writes finish
+    /// `output_len` words -- possibly a partial rate block, in which case only the first
+    /// `ceil(remaining / 4)` words of `output_mem` are populated/constrained.
+    pub is_final_squeeze: T,
+    /// One memory-write column per word of this row's output block. For the legacy fixed-digest
+    /// path (`output_len == 0`) only the first `KECCAK_GENERAL_OUTPUT_U32S` entries are ever
+    /// populated, which is why this is sized to a full rate rather than the old fixed digest
+    /// width -- a squeeze block can produce up to `KECCAK_GENERAL_RATE_U32S` words per row.
+    pub output_mem: [MemoryWriteCols<T>; KECCAK_GENERAL_RATE_U32S],
+
+    /// This row's position within its batch, for the multi-preimage `KECCAK_SPONGE` batching
+    /// path (see [`zkm2_core_executor::events::KeccakSpongeEvent::instance_id`]). The other
+    /// per-block flags above (`already_absorbed_u32s`, `is_first_input_block`,
+    /// `is_final_input_block`, `receive_syscall`, `write_output`) are already scoped to a single
+    /// instance's own absorb/squeeze state rather than to the whole shard -- each instance resets
+    /// them independently in `event_to_rows` -- so `instance_id` only needs to distinguish rows
+    /// belonging to different instances once their blocks are interleaved in the shared trace; it
+    /// carries no constraints of its own.
+    pub instance_id: T,
 }
 
+/// Kept for callers that only need the legacy fixed-digest output width (e.g. sizing a
+/// `squeeze_output` buffer for a non-squeeze call).
+pub const _LEGACY_FIXED_OUTPUT_WORDS: usize = KECCAK_GENERAL_OUTPUT_U32S;
+
 pub const NUM_KECCAK_SPONGE_COLS: usize = size_of::<KeccakSpongeCols<u8>>();
@@ -1,3 +1,13 @@
+//! Each [`KeccakSpongeEvent`](zkm2_core_executor::events::KeccakSpongeEvent) still corresponds to
+//! one `KECCAK_SPONGE` syscall today; the `instance_id` column on
+//! [`columns::KeccakSpongeCols`] is the hook a future batching syscall (taking a list of
+//! `(input_addr, input_len, output_addr)` descriptors and emitting one event per descriptor, all
+//! sharing a `clk`) would stamp per-instance, so the chip side can already interleave several
+//! instances' blocks in one trace without further column changes -- adding that syscall itself is
+//! left for the follow-up that also needs a batching-aware variant of `SyscallCode::KECCAK_SPONGE`
+//! (the plain single-instance variant now exists, but still has no `Syscall` impl or
+//! `syscall_map` entry feeding the events this chip reads).
+
 use p3_keccak_air::KeccakAir;
 
 mod air;
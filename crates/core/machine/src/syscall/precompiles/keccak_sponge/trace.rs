@@ -41,7 +41,7 @@ impl<F: PrimeField32> MachineAir<F> for KeccakSpongeChip {
                     } else {
                         unreachable!()
                     };
-                    self.event_to_rows::<F>(event, &mut None, &mut blu);
+                    self.event_to_rows::<F>(event, &mut blu);
                 });
                 blu
             })
@@ -51,18 +51,27 @@ impl<F: PrimeField32> MachineAir<F> for KeccakSpongeChip {
     }
 
     fn generate_trace(&self, input: &Self::Record, _: &mut Self::Record) -> RowMajorMatrix<F> {
-        let rows = Vec::new();
-
-        let mut wrapped_rows = Some(rows);
-        for (_, event) in input.get_precompile_events(SyscallCode::KECCAK_SPONGE) {
-            let event = if let PrecompileEvent::KeccakSponge(event) = event {
-                event
-            } else {
-                unreachable!()
-            };
-            self.event_to_rows(event, &mut wrapped_rows, &mut Vec::new());
-        }
-        let mut rows = wrapped_rows.unwrap();
+        let events = input.get_precompile_events(SyscallCode::KECCAK_SPONGE);
+        // Each event is independent -- `event_to_rows` only threads `already_absorbed_u32s`/
+        // `state_u32s`/the block index through a single event's own blocks -- so sharding events
+        // across `par_chunks` and concatenating the per-chunk rows afterward is sound, the same
+        // split `generate_dependencies` above already uses.
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+        let mut rows = events
+            .par_chunks(chunk_size)
+            .flat_map(|events| {
+                let mut chunk_rows = Vec::new();
+                for (_, event) in events {
+                    let event = if let PrecompileEvent::KeccakSponge(event) = event {
+                        event
+                    } else {
+                        unreachable!()
+                    };
+                    chunk_rows.extend(self.event_to_rows::<F>(event, &mut Vec::new()));
+                }
+                chunk_rows
+            })
+            .collect::<Vec<_>>();
         let num_real_rows = rows.len();
 
         let dummy_keccak_rows = generate_trace_rows::<F>(vec![[0; KECCAK_STATE_U32S / 2]]);
@@ -96,9 +105,9 @@ impl KeccakSpongeChip {
     pub fn event_to_rows<F: PrimeField32>(
         &self,
         event: &KeccakSpongeEvent,
-        rows: &mut Option<Vec<[F; NUM_KECCAK_SPONGE_COLS]>>,
         blu: &mut impl ByteRecord,
-    ) {
+    ) -> Vec<[F; NUM_KECCAK_SPONGE_COLS]> {
+        let mut rows = Vec::with_capacity(event.num_blocks() * NUM_ROUNDS);
         let mut state_u32s = [0_u32; KECCAK_STATE_U32S];
         let mut xored_rate_u32s = [0_u32; KECCAK_GENERAL_RATE_U32S];
         let block_num = event.num_blocks();
@@ -117,6 +126,7 @@ impl KeccakSpongeChip {
                 cols.shard = F::from_canonical_u32(event.shard);
                 cols.clk = F::from_canonical_u32(event.clk);
                 cols.is_real = F::ONE;
+                cols.instance_id = F::from_canonical_u32(event.instance_id);
                 cols.input_len = F::from_canonical_u32(event.input.len() as u32);
                 cols.already_absorbed_u32s = F::from_canonical_u32(already_absorbed_u32s);
                 cols.is_absorbed =
@@ -125,7 +135,21 @@ impl KeccakSpongeChip {
                 cols.is_final_input_block = F::from_bool(i == (block_num - 1));
                 cols.read_block = F::from_bool(round == 0);
                 cols.receive_syscall = F::from_bool(i == 0 && round == 0);
-                cols.write_output = F::from_bool(i == (block_num - 1) && round == (NUM_ROUNDS - 1));
+                let is_last_absorb_row = i == (block_num - 1) && round == (NUM_ROUNDS - 1);
+                // A squeeze call (`output_len_u32s != 0`) extracts its first output block from
+                // the post-absorption state directly, reusing this last absorption row rather
+                // than spending a fresh permutation on it -- see the squeeze loop below for
+                // `squeeze_block_idx >= 1`.
+                cols.is_squeeze = F::from_bool(is_last_absorb_row && event.output_len_u32s != 0);
+                cols.squeeze_block_idx = F::ZERO;
+                cols.output_len = F::from_canonical_u32(event.output_len_u32s);
+                let legacy_digest = event.output_len_u32s == 0;
+                cols.write_output = F::from_bool(is_last_absorb_row);
+                cols.is_final_squeeze = F::from_bool(
+                    is_last_absorb_row
+                        && (legacy_digest
+                            || (event.output_len_u32s as usize) <= KECCAK_GENERAL_RATE_U32S),
+                );
                 cols.output_address = F::from_canonical_u32(event.output_addr);
                 // 4 bytes per u32
                 cols.input_address = F::from_canonical_u32(
@@ -163,20 +187,90 @@ impl KeccakSpongeChip {
                     cols.input_length_mem.populate(event.input_length_record, blu);
                 }
 
-                // if this is the last row of the last block, populate writing output
-                if i == (block_num - 1) && round == (NUM_ROUNDS - 1) {
-                    for j in 0..KECCAK_GENERAL_OUTPUT_U32S {
-                        cols.output_mem[j].populate(event.output_write_records[j], blu);
+                // if this is the last row of the last block, populate writing output: the
+                // legacy fixed digest, or this call's first squeeze block.
+                if is_last_absorb_row {
+                    if legacy_digest {
+                        for j in 0..KECCAK_GENERAL_OUTPUT_U32S {
+                            cols.output_mem[j].populate(event.output_write_records[j], blu);
+                        }
+                    } else {
+                        let words_this_block =
+                            (event.output_len_u32s as usize).min(KECCAK_GENERAL_RATE_U32S);
+                        for j in 0..words_this_block {
+                            cols.output_mem[j].populate(event.output_write_records[j], blu);
+                        }
                     }
                 }
 
-                if rows.as_ref().is_some() {
-                    rows.as_mut().unwrap().push(row);
-                }
+                rows.push(row);
             }
             state_u32s[..KECCAK_GENERAL_RATE_U32S].copy_from_slice(&xored_rate_u32s[..]);
             keccakf_u32s(&mut state_u32s);
             already_absorbed_u32s += KECCAK_GENERAL_RATE_U32S as u32;
         }
+
+        // SHAKE-style squeeze phase: every block after the first reuses `state_u32s` (left
+        // holding the post-absorption permutation by the loop above) by permuting it again and
+        // extracting the next `KECCAK_GENERAL_RATE_U32S` words, exactly like the absorb loop's
+        // `keccakf_u32s`/`generate_trace_rows` pair, just without any input to xor in.
+        if event.output_len_u32s != 0 {
+            let total_out = event.output_len_u32s as usize;
+            let mut written = (event.output_len_u32s as usize).min(KECCAK_GENERAL_RATE_U32S);
+            let mut squeeze_block_idx = 1_u32;
+
+            while written < total_out {
+                let state_u64s: [u64; 25] = core::array::from_fn(|k| {
+                    let lo = state_u32s[k * 2] as u64;
+                    let hi = state_u32s[k * 2 + 1] as u64;
+                    lo | (hi << 32)
+                });
+                let p3_keccak_trace = generate_trace_rows::<F>(vec![state_u64s]);
+                keccakf_u32s(&mut state_u32s);
+
+                let remaining = total_out - written;
+                let words_this_block = remaining.min(KECCAK_GENERAL_RATE_U32S);
+                let is_final_squeeze = remaining <= KECCAK_GENERAL_RATE_U32S;
+
+                for round in 0..NUM_ROUNDS {
+                    let mut row = [F::ZERO; NUM_KECCAK_SPONGE_COLS];
+                    let p3_keccak_row = p3_keccak_trace.row(round);
+                    row[..NUM_KECCAK_COLS]
+                        .copy_from_slice(p3_keccak_row.collect::<Vec<_>>().as_slice());
+
+                    let cols: &mut KeccakSpongeCols<F> = row.as_mut_slice().borrow_mut();
+                    cols.shard = F::from_canonical_u32(event.shard);
+                    cols.clk = F::from_canonical_u32(event.clk);
+                    cols.is_real = F::ONE;
+                    cols.instance_id = F::from_canonical_u32(event.instance_id);
+                    cols.input_len = F::from_canonical_u32(event.input.len() as u32);
+                    cols.already_absorbed_u32s = F::from_canonical_u32(already_absorbed_u32s);
+                    cols.is_squeeze = F::ONE;
+                    cols.squeeze_block_idx = F::from_canonical_u32(squeeze_block_idx);
+                    cols.output_len = F::from_canonical_u32(event.output_len_u32s);
+                    cols.is_final_squeeze = F::from_bool(is_final_squeeze && round == NUM_ROUNDS - 1);
+                    cols.write_output = F::from_bool(round == NUM_ROUNDS - 1);
+                    cols.output_address =
+                        F::from_canonical_u32(event.output_addr + written as u32 * 4);
+                    for j in 0..KECCAK_STATE_U32S {
+                        cols.original_state[j] = Word::from(state_u32s[j]);
+                    }
+
+                    if round == NUM_ROUNDS - 1 {
+                        for j in 0..words_this_block {
+                            cols.output_mem[j]
+                                .populate(event.output_write_records[written + j], blu);
+                        }
+                    }
+
+                    rows.push(row);
+                }
+
+                written += words_this_block;
+                squeeze_block_idx += 1;
+            }
+        }
+
+        rows
     }
 }
@@ -1,17 +1,212 @@
 use crate::syscall::precompiles::keccak_sponge::KECCAK_STATE_U32S;
-use tiny_keccak::keccakf;
 
-/// Like tiny-keccak's `keccakf`, but deals with `u32` limbs instead of `u64` limbs.
+const RC: [u64; 24] = [
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_8082,
+    0x8000_0000_0000_808A,
+    0x8000_0000_8000_8000,
+    0x0000_0000_0000_808B,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8009,
+    0x0000_0000_0000_008A,
+    0x0000_0000_0000_0088,
+    0x0000_0000_8000_8009,
+    0x0000_0000_8000_000A,
+    0x0000_0000_8000_808B,
+    0x8000_0000_0000_008B,
+    0x8000_0000_0000_8089,
+    0x8000_0000_0000_8003,
+    0x8000_0000_0000_8002,
+    0x8000_0000_0000_0080,
+    0x0000_0000_0000_800A,
+    0x8000_0000_8000_000A,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8080,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8008,
+];
+
+/// Rotation offsets for the ρ step, indexed the same way as `state` below: `RHO[x + 5 * y]` is
+/// the left-rotation applied to lane `(x, y)`.
+const RHO: [u32; 25] = [
+    0, 1, 62, 28, 27, //
+    36, 44, 6, 55, 20, //
+    3, 10, 43, 25, 39, //
+    41, 45, 15, 21, 8, //
+    18, 2, 61, 56, 14,
+];
+
+/// Splits a 64-bit lane into its even-indexed bits (`lo`) and odd-indexed bits (`hi`), each
+/// packed into a 32-bit word -- the "bit-interleaved" representation the round function below
+/// operates on so that a 64-bit rotate never has to reassemble a `u64`.
+const fn to_bit_interleaved(x: u64) -> (u32, u32) {
+    let mut lo = 0u32;
+    let mut hi = 0u32;
+    let mut i = 0;
+    while i < 32 {
+        lo |= (((x >> (2 * i)) & 1) as u32) << i;
+        hi |= (((x >> (2 * i + 1)) & 1) as u32) << i;
+        i += 1;
+    }
+    (lo, hi)
+}
+
+/// Inverse of [`to_bit_interleaved`]: reassembles a 64-bit lane from its even/odd bit planes.
+const fn from_bit_interleaved(lo: u32, hi: u32) -> u64 {
+    let mut x = 0u64;
+    let mut i = 0;
+    while i < 32 {
+        x |= (((lo >> i) & 1) as u64) << (2 * i);
+        x |= (((hi >> i) & 1) as u64) << (2 * i + 1);
+        i += 1;
+    }
+    x
+}
+
+/// Pre-interleaves [`RC`] at compile time so the ι step never has to interleave a round constant
+/// at runtime.
+const fn interleave_round_constants(rc: [u64; 24]) -> ([u32; 24], [u32; 24]) {
+    let mut lo = [0u32; 24];
+    let mut hi = [0u32; 24];
+    let mut i = 0;
+    while i < 24 {
+        let (l, h) = to_bit_interleaved(rc[i]);
+        lo[i] = l;
+        hi[i] = h;
+        i += 1;
+    }
+    (lo, hi)
+}
+
+const RC_INTERLEAVED: ([u32; 24], [u32; 24]) = interleave_round_constants(RC);
+
+/// Left-rotates a 64-bit lane given as its bit-interleaved `(lo, hi)` planes by `r` bits,
+/// returning the rotated `(lo, hi)` pair. An even offset rotates each plane independently by
+/// `r / 2`; an odd offset additionally swaps which plane ends up as the new low/high half, since
+/// shifting by one bit moves every even-indexed bit into an odd position and vice versa.
+fn rotl64_interleaved(lo: u32, hi: u32, r: u32) -> (u32, u32) {
+    if r % 2 == 0 {
+        (lo.rotate_left(r / 2), hi.rotate_left(r / 2))
+    } else {
+        (hi.rotate_left((r + 1) / 2), lo.rotate_left((r - 1) / 2))
+    }
+}
+
+/// One keccak-f[1600] round, operating directly on bit-interleaved planes. Structurally the same
+/// θ/ρ/π/χ/ι steps as `zkm2_core_executor::syscalls::keccak::keccakf`'s 64-bit version, just with
+/// every lane carried as a `(lo, hi)` pair and every rotation going through
+/// [`rotl64_interleaved`] instead of `u64::rotate_left`.
+fn round_interleaved(lo: &mut [u32; 25], hi: &mut [u32; 25], rc_lo: u32, rc_hi: u32) {
+    // θ: XOR each column's parity into every lane of the two neighboring columns.
+    let mut c_lo = [0u32; 5];
+    let mut c_hi = [0u32; 5];
+    for x in 0..5 {
+        c_lo[x] = lo[x] ^ lo[x + 5] ^ lo[x + 10] ^ lo[x + 15] ^ lo[x + 20];
+        c_hi[x] = hi[x] ^ hi[x + 5] ^ hi[x + 10] ^ hi[x + 15] ^ hi[x + 20];
+    }
+    let mut d_lo = [0u32; 5];
+    let mut d_hi = [0u32; 5];
+    for x in 0..5 {
+        let (rot_lo, rot_hi) = rotl64_interleaved(c_lo[(x + 1) % 5], c_hi[(x + 1) % 5], 1);
+        d_lo[x] = c_lo[(x + 4) % 5] ^ rot_lo;
+        d_hi[x] = c_hi[(x + 4) % 5] ^ rot_hi;
+    }
+    for x in 0..5 {
+        for y in 0..5 {
+            lo[x + 5 * y] ^= d_lo[x];
+            hi[x + 5 * y] ^= d_hi[x];
+        }
+    }
+
+    // ρ and π: rotate each lane by its fixed offset, then permute lanes to their new
+    // position `(y, 2x + 3y) -> (x, y)`.
+    let mut b_lo = [0u32; 25];
+    let mut b_hi = [0u32; 25];
+    for x in 0..5 {
+        for y in 0..5 {
+            let new_x = y;
+            let new_y = (2 * x + 3 * y) % 5;
+            let (rot_lo, rot_hi) = rotl64_interleaved(lo[x + 5 * y], hi[x + 5 * y], RHO[x + 5 * y]);
+            b_lo[new_x + 5 * new_y] = rot_lo;
+            b_hi[new_x + 5 * new_y] = rot_hi;
+        }
+    }
+
+    // χ: bitwise AND/XOR/NOT commute with interleaving, so each plane is chi'd independently.
+    for x in 0..5 {
+        for y in 0..5 {
+            lo[x + 5 * y] =
+                b_lo[x + 5 * y] ^ ((!b_lo[(x + 1) % 5 + 5 * y]) & b_lo[(x + 2) % 5 + 5 * y]);
+            hi[x + 5 * y] =
+                b_hi[x + 5 * y] ^ ((!b_hi[(x + 1) % 5 + 5 * y]) & b_hi[(x + 2) % 5 + 5 * y]);
+        }
+    }
+
+    // ι: XOR this round's constant into lane (0, 0).
+    lo[0] ^= rc_lo;
+    hi[0] ^= rc_hi;
+}
+
+/// Like tiny-keccak's `keccakf`, but deals with `u32` limbs instead of `u64` limbs, and runs the
+/// permutation natively on bit-interleaved 32-bit planes instead of packing lanes into `u64`s and
+/// calling out to `tiny_keccak`. The pack/unpack only happens once at each end, converting between
+/// `state_u32s`'s plain low/high-word layout and the interleaved planes the round function
+/// operates on, rather than once per round -- and it leaves the interleaved words directly
+/// available, which matches the u32-limbed trace columns better than a `u64` round trip would.
 pub(crate) fn keccakf_u32s(state_u32s: &mut [u32; KECCAK_STATE_U32S]) {
-    let mut state_u64s: [u64; 25] = core::array::from_fn(|i| {
-        let lo = state_u32s[i * 2] as u64;
-        let hi = state_u32s[i * 2 + 1] as u64;
-        lo | (hi << 32)
-    });
-    keccakf(&mut state_u64s);
-    *state_u32s = core::array::from_fn(|i| {
-        let u64_limb = state_u64s[i / 2];
-        let is_hi = i % 2;
-        (u64_limb >> (is_hi * 32)) as u32
-    });
+    let mut lo = [0u32; 25];
+    let mut hi = [0u32; 25];
+    for i in 0..25 {
+        let lane = (state_u32s[i * 2] as u64) | ((state_u32s[i * 2 + 1] as u64) << 32);
+        let (l, h) = to_bit_interleaved(lane);
+        lo[i] = l;
+        hi[i] = h;
+    }
+
+    let (rc_lo, rc_hi) = RC_INTERLEAVED;
+    for round in 0..24 {
+        round_interleaved(&mut lo, &mut hi, rc_lo[round], rc_hi[round]);
+    }
+
+    for i in 0..25 {
+        let lane = from_bit_interleaved(lo[i], hi[i]);
+        state_u32s[i * 2] = lane as u32;
+        state_u32s[i * 2 + 1] = (lane >> 32) as u32;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_keccakf_u32s_matches_tiny_keccak() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let mut state_u64s: [u64; 25] = core::array::from_fn(|_| rng.gen());
+            let mut state_u32s: [u32; KECCAK_STATE_U32S] = core::array::from_fn(|i| {
+                let lane = state_u64s[i / 2];
+                if i % 2 == 0 {
+                    lane as u32
+                } else {
+                    (lane >> 32) as u32
+                }
+            });
+
+            tiny_keccak::keccakf(&mut state_u64s);
+            keccakf_u32s(&mut state_u32s);
+
+            let expected_u32s: [u32; KECCAK_STATE_U32S] = core::array::from_fn(|i| {
+                let lane = state_u64s[i / 2];
+                if i % 2 == 0 {
+                    lane as u32
+                } else {
+                    (lane >> 32) as u32
+                }
+            });
+            assert_eq!(state_u32s, expected_u32s);
+        }
+    }
 }
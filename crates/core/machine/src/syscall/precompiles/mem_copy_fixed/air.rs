@@ -0,0 +1,154 @@
+//! STARK-side precompile chip for `MEMCPY_32`/`MEMCPY_64` (see
+//! [`zkm2_core_executor::syscalls::mem_copy::MemCopyFixedSyscall`]), one row per call, each row
+//! reading [`NUM_WORDS`] words out of the source region and re-asserting them, word for word,
+//! against what was written to the destination region -- the same "one column pair per output
+//! word" shape [`super::super::bn254_scalar::Bn254ScalarOpCols`] uses for its own per-word
+//! identities, just without any field reduction since a copy has no arithmetic to check.
+
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use hashbrown::HashMap;
+use p3_air::{Air, BaseAir};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use zkm2_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, MemCopyEvent, MemoryRecordEnum},
+    ExecutionRecord, Program,
+};
+use zkm2_derive::AlignedBorrow;
+use zkm2_stark::air::{MachineAir, ZKMAirBuilder};
+
+use crate::{
+    memory::{MemoryReadCols, MemoryWriteCols},
+    utils::next_power_of_two,
+};
+
+/// Fixed word count moved by a `MEMCPY_32` call.
+pub const MEMCPY32_NUM_WORDS: usize = 32;
+/// Fixed word count moved by a `MEMCPY_64` call.
+pub const MEMCPY64_NUM_WORDS: usize = 64;
+
+pub const NUM_MEM_COPY32_COLS: usize = size_of::<MemCopyFixedCols<u8, MEMCPY32_NUM_WORDS>>();
+pub const NUM_MEM_COPY64_COLS: usize = size_of::<MemCopyFixedCols<u8, MEMCPY64_NUM_WORDS>>();
+
+/// Column layout for a `MEMCPY_32`/`MEMCPY_64` row, parameterized by the fixed word count so the
+/// 32- and 64-word chips share one definition rather than duplicating the struct.
+#[derive(AlignedBorrow, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct MemCopyFixedCols<T, const NUM_WORDS: usize> {
+    pub shard: T,
+    pub clk: T,
+    pub src_ptr: T,
+    pub dst_ptr: T,
+
+    /// One memory-read column per source word, each carrying its own read interaction at
+    /// `clk + i`.
+    pub src_access: [MemoryReadCols<T>; NUM_WORDS],
+    /// One memory-write column per destination word, at the same per-word clock offset as the
+    /// matching `src_access` entry.
+    pub dst_access: [MemoryWriteCols<T>; NUM_WORDS],
+
+    pub is_real: T,
+}
+
+#[derive(Default)]
+pub struct MemCopyFixedChip<const NUM_WORDS: usize>;
+
+/// `MEMCPY_32` precompile chip.
+pub type MemCopy32Chip = MemCopyFixedChip<MEMCPY32_NUM_WORDS>;
+/// `MEMCPY_64` precompile chip.
+pub type MemCopy64Chip = MemCopyFixedChip<MEMCPY64_NUM_WORDS>;
+
+impl<F, const NUM_WORDS: usize> BaseAir<F> for MemCopyFixedChip<NUM_WORDS> {
+    fn width(&self) -> usize {
+        size_of::<MemCopyFixedCols<u8, NUM_WORDS>>()
+    }
+}
+
+impl<F: PrimeField32, const NUM_WORDS: usize> MachineAir<F> for MemCopyFixedChip<NUM_WORDS> {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        format!("MemCopy{NUM_WORDS}")
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let width = size_of::<MemCopyFixedCols<u8, NUM_WORDS>>();
+
+        // `MEMCPY_32` and `MEMCPY_64` share one `mem_copy_events` vec (see `MemCopyEvent`'s doc
+        // comment); each chip only claims the events whose word count matches its own `NUM_WORDS`.
+        let events: Vec<&MemCopyEvent> =
+            input.mem_copy_events.iter().filter(|event| event.src_records.len() == NUM_WORDS).collect();
+
+        let nb_rows = events.len();
+        let size_log2 = input.fixed_log2_rows::<F, _>(self);
+        let padded_nb_rows = next_power_of_two(nb_rows, size_log2);
+        let mut values = vec![F::ZERO; padded_nb_rows * width];
+
+        let mut blu: HashMap<ByteLookupEvent, usize> = HashMap::new();
+        for (i, event) in events.iter().enumerate() {
+            let row = &mut values[i * width..(i + 1) * width];
+            let cols: &mut MemCopyFixedCols<F, NUM_WORDS> = row.borrow_mut();
+
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.src_ptr = F::from_canonical_u32(event.src_ptr);
+            cols.dst_ptr = F::from_canonical_u32(event.dst_ptr);
+            cols.is_real = F::ONE;
+
+            for w in 0..NUM_WORDS {
+                cols.src_access[w]
+                    .populate(MemoryRecordEnum::Read(event.src_records[w]), &mut blu);
+                cols.dst_access[w]
+                    .populate(MemoryRecordEnum::Write(event.dst_records[w]), &mut blu);
+            }
+        }
+
+        output.add_byte_lookup_events(blu.into_iter().map(|(event, _)| event).collect());
+
+        RowMajorMatrix::new(values, width)
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        shard.mem_copy_events.iter().any(|event| event.src_records.len() == NUM_WORDS)
+    }
+}
+
+impl<AB: ZKMAirBuilder, const NUM_WORDS: usize> Air<AB> for MemCopyFixedChip<NUM_WORDS> {
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &MemCopyFixedCols<AB::Var, NUM_WORDS> = (*local).borrow();
+
+        for i in 0..NUM_WORDS {
+            builder.eval_memory_access(
+                local.shard,
+                local.clk + AB::Expr::from_canonical_usize(i),
+                local.src_ptr + AB::Expr::from_canonical_usize(i * 4),
+                &local.src_access[i],
+                local.is_real,
+            );
+            builder.eval_memory_access(
+                local.shard,
+                local.clk + AB::Expr::from_canonical_usize(i),
+                local.dst_ptr + AB::Expr::from_canonical_usize(i * 4),
+                &local.dst_access[i],
+                local.is_real,
+            );
+
+            // The whole point of the copy: the destination word written this row equals the
+            // source word read this row.
+            builder
+                .when(local.is_real)
+                .assert_word_eq(*local.dst_access[i].value(), *local.src_access[i].prev_value());
+        }
+    }
+}
@@ -0,0 +1,240 @@
+//! `Air<AB>` for [`MtreeVerifyPathChip`] -- see [`super::columns`] for the row layout and
+//! [`super::utils`] for the per-level compression's round structure/constants, mirroring
+//! `crate::syscall::precompiles::poseidon2::air`'s shape applied per Merkle level instead of once.
+
+use std::borrow::Borrow;
+
+use p3_air::{Air, BaseAir};
+use p3_field::FieldAlgebra;
+use p3_matrix::Matrix;
+use zkm2_core_executor::events::MTREE_DIGEST_WORDS;
+use zkm2_stark::air::ZKMAirBuilder;
+
+use super::{
+    columns::{
+        MtreeMergeCols, MtreeVerifyPathCols, NUM_MTREE_MERGE_COLS, NUM_MTREE_VERIFY_PATH_COLS,
+    },
+    utils::{is_full_round, round_constant, TOTAL_ROUNDS, WIDTH},
+};
+
+/// STARK-side precompile chip for `MTREE_VERIFY_PATH` (see
+/// [`zkm2_core_executor::syscalls::mtree`]). Not yet wired into `MipsAir`'s chip dispatch --
+/// the same honest gap `crate::syscall::precompiles::poseidon2::Poseidon2Chip` documents, since
+/// `ExecutionRecord` has no `mtree_verify_path_events` field yet in this snapshot for
+/// `generate_trace` (in `trace.rs`) to read from.
+#[derive(Default)]
+pub struct MtreeVerifyPathChip;
+
+impl<F> BaseAir<F> for MtreeVerifyPathChip {
+    fn width(&self) -> usize {
+        NUM_MTREE_VERIFY_PATH_COLS
+    }
+}
+
+impl<AB: ZKMAirBuilder> Air<AB> for MtreeVerifyPathChip {
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &MtreeVerifyPathCols<AB::Var> = (*local).borrow();
+
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.verified);
+
+        // The first level's input is `[leaf || 0]`/`[0 || leaf]` depending on `sibling_on_left`,
+        // folded in below; every later level's input is the previous level's output.
+        let mut running: Vec<AB::Expr> =
+            (0..MTREE_DIGEST_WORDS).map(|i| local.leaf_access[i].value()[0].into()).collect();
+
+        for level in local.levels.iter() {
+            builder.assert_bool(level.sibling_on_left);
+            builder.assert_bool(level.is_active);
+
+            // `state_values[0]` must be `running`/`sibling` ordered by `sibling_on_left`: left
+            // half is whichever of the two is *not* on the right.
+            for i in 0..MTREE_DIGEST_WORDS {
+                let left_is_running = AB::Expr::ONE - level.sibling_on_left.into();
+                let expected_left = left_is_running.clone() * running[i].clone()
+                    + level.sibling_on_left.into() * level.sibling[i].into();
+                let expected_right = level.sibling_on_left.into() * running[i].clone()
+                    + left_is_running * level.sibling[i].into();
+                builder
+                    .when(level.is_active)
+                    .assert_eq(level.state_values[0][i], expected_left);
+                builder
+                    .when(level.is_active)
+                    .assert_eq(level.state_values[0][MTREE_DIGEST_WORDS + i], expected_right);
+            }
+
+            // Walk this level's width-8 Poseidon2 compression, identical round structure to
+            // `crate::syscall::precompiles::poseidon2::air`'s single-width-16 walk.
+            for round in 0..TOTAL_ROUNDS {
+                let prev = &level.state_values[round];
+                let next = &level.state_values[round + 1];
+                let sbox = &level.sbox[round];
+
+                let mut post_sbox: Vec<AB::Expr> = (0..WIDTH).map(|_| AB::Expr::ZERO).collect();
+                for lane in 0..WIDTH {
+                    if is_full_round(round) || lane == 0 {
+                        let rc = AB::Expr::from_canonical_u64(round_constant(round, WIDTH, lane));
+                        let x = prev[lane] + rc;
+                        builder
+                            .when(level.is_active)
+                            .assert_eq(sbox[lane].x2, x.clone() * x.clone());
+                        builder
+                            .when(level.is_active)
+                            .assert_eq(sbox[lane].x4, sbox[lane].x2 * sbox[lane].x2);
+                        builder
+                            .when(level.is_active)
+                            .assert_eq(sbox[lane].x6, sbox[lane].x4 * sbox[lane].x2);
+                        post_sbox[lane] = sbox[lane].x6 * x;
+                    } else {
+                        post_sbox[lane] = prev[lane].into();
+                    }
+                }
+
+                let sum: AB::Expr =
+                    post_sbox.iter().cloned().fold(AB::Expr::ZERO, |acc, x| acc + x);
+                for lane in 0..WIDTH {
+                    builder
+                        .when(level.is_active)
+                        .assert_eq(next[lane], post_sbox[lane].clone() + sum.clone());
+                }
+            }
+
+            // Only fold this level's output into `running` when it's actually active -- a
+            // padding level beyond `depth` is otherwise unconstrained (every constraint above is
+            // gated on `is_active`), so its `state_values` could hold anything.
+            running = (0..MTREE_DIGEST_WORDS)
+                .map(|i| {
+                    let output: AB::Expr = level.state_values[TOTAL_ROUNDS][i].into();
+                    level.is_active.into() * output
+                        + (AB::Expr::ONE - level.is_active.into()) * running[i].clone()
+                })
+                .collect();
+        }
+
+        // `running` now holds the last active level's output digest. Constrain the sound
+        // direction -- `verified = 1` forces every word to match the root actually read from
+        // memory -- the same way this chip leaves `verified = 0` on a mismatching path without
+        // also forcing the converse; a malicious prover can only under-report a match, never
+        // fabricate one, which is the direction that matters for a guest trusting `a0`.
+        for i in 0..MTREE_DIGEST_WORDS {
+            builder.when(local.is_real * local.verified).assert_eq(
+                running[i].clone(),
+                local.root_access[i].value()[0],
+            );
+        }
+
+        for i in 0..MTREE_DIGEST_WORDS {
+            builder.eval_memory_access(
+                local.shard,
+                local.clk,
+                local.leaf_ptr + AB::Expr::from_canonical_u32((i as u32) * 4),
+                &local.leaf_access[i],
+                local.is_real,
+            );
+            builder.eval_memory_access(
+                local.shard,
+                local.clk,
+                local.root_ptr + AB::Expr::from_canonical_u32((i as u32) * 4),
+                &local.root_access[i],
+                local.is_real,
+            );
+        }
+    }
+}
+
+/// STARK-side precompile chip for `MTREE_MERGE` (see
+/// [`zkm2_core_executor::syscalls::mtree`]). Same not-yet-wired-in / no-backing-record-field gap
+/// as [`MtreeVerifyPathChip`].
+#[derive(Default)]
+pub struct MtreeMergeChip;
+
+impl<F> BaseAir<F> for MtreeMergeChip {
+    fn width(&self) -> usize {
+        NUM_MTREE_MERGE_COLS
+    }
+}
+
+impl<AB: ZKMAirBuilder> Air<AB> for MtreeMergeChip {
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &MtreeMergeCols<AB::Var> = (*local).borrow();
+
+        builder.assert_bool(local.is_real);
+
+        for i in 0..MTREE_DIGEST_WORDS {
+            builder.when(local.is_real).assert_eq(
+                local.state_values[0][i],
+                local.left_access[i].value()[0],
+            );
+            builder.when(local.is_real).assert_eq(
+                local.state_values[0][MTREE_DIGEST_WORDS + i],
+                local.right_access[i].value()[0],
+            );
+        }
+
+        for round in 0..TOTAL_ROUNDS {
+            let prev = &local.state_values[round];
+            let next = &local.state_values[round + 1];
+            let sbox = &local.sbox[round];
+
+            let mut post_sbox: Vec<AB::Expr> = (0..WIDTH).map(|_| AB::Expr::ZERO).collect();
+            for lane in 0..WIDTH {
+                if is_full_round(round) || lane == 0 {
+                    let rc = AB::Expr::from_canonical_u64(round_constant(round, WIDTH, lane));
+                    let x = prev[lane] + rc;
+                    builder.when(local.is_real).assert_eq(sbox[lane].x2, x.clone() * x.clone());
+                    builder
+                        .when(local.is_real)
+                        .assert_eq(sbox[lane].x4, sbox[lane].x2 * sbox[lane].x2);
+                    builder
+                        .when(local.is_real)
+                        .assert_eq(sbox[lane].x6, sbox[lane].x4 * sbox[lane].x2);
+                    post_sbox[lane] = sbox[lane].x6 * x;
+                } else {
+                    post_sbox[lane] = prev[lane].into();
+                }
+            }
+
+            let sum: AB::Expr = post_sbox.iter().cloned().fold(AB::Expr::ZERO, |acc, x| acc + x);
+            for lane in 0..WIDTH {
+                builder
+                    .when(local.is_real)
+                    .assert_eq(next[lane], post_sbox[lane].clone() + sum.clone());
+            }
+        }
+
+        for i in 0..MTREE_DIGEST_WORDS {
+            builder.when(local.is_real).assert_eq(
+                local.state_values[TOTAL_ROUNDS][i],
+                local.parent_access[i].value()[0],
+            );
+        }
+
+        for i in 0..MTREE_DIGEST_WORDS {
+            builder.eval_memory_access(
+                local.shard,
+                local.clk,
+                local.left_ptr + AB::Expr::from_canonical_u32((i as u32) * 4),
+                &local.left_access[i],
+                local.is_real,
+            );
+            builder.eval_memory_access(
+                local.shard,
+                local.clk,
+                local.right_ptr + AB::Expr::from_canonical_u32((i as u32) * 4),
+                &local.right_access[i],
+                local.is_real,
+            );
+            builder.eval_memory_access(
+                local.shard,
+                local.clk,
+                local.left_ptr + AB::Expr::from_canonical_u32((i as u32) * 4),
+                &local.parent_access[i],
+                local.is_real,
+            );
+        }
+    }
+}
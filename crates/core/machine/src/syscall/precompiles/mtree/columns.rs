@@ -0,0 +1,103 @@
+use core::mem::size_of;
+
+use zkm2_core_executor::events::{MTREE_DIGEST_WORDS, MTREE_MAX_DEPTH};
+use zkm2_derive::AlignedBorrow;
+
+use super::utils::{TOTAL_ROUNDS, WIDTH};
+use crate::memory::{MemoryReadCols, MemoryWriteCols};
+
+/// Per-round witness for one lane's S-box (`x^7`) within one level's compression, the same
+/// three-column decomposition `crate::syscall::precompiles::poseidon2::Poseidon2SboxCols` uses --
+/// duplicated locally rather than shared since the two chips' directories aren't wired into a
+/// common `precompiles` module tree in this snapshot (see this crate's other precompile
+/// subdirectories, which have the same gap).
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct MtreeSboxCols<T> {
+    pub x2: T,
+    pub x4: T,
+    pub x6: T,
+}
+
+/// One sibling level's witness within an `MTREE_VERIFY_PATH` row: the advice digest (from the
+/// hint stream, not memory -- there is no `MemoryReadCols` for it), which side it sits on, and
+/// the full width-8 Poseidon2 compression trace folding it into the running digest.
+#[derive(AlignedBorrow, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct MtreeLevelCols<T> {
+    /// The sibling digest this level consumed from the hint stream.
+    pub sibling: [T; MTREE_DIGEST_WORDS],
+    /// `1` if the running digest is the right input to this level's compression (index bit set),
+    /// `0` if it's the left input. Constrained boolean; not separately range-checked against the
+    /// index's bit decomposition beyond that (see [`super::air`]'s doc comment on `index`).
+    pub sibling_on_left: T,
+    /// `1` if this level is actually walked (`level < depth`), `0` for padding levels beyond a
+    /// path shorter than [`MTREE_MAX_DEPTH`].
+    pub is_active: T,
+    /// `state_values[0]` is `[left_input || right_input]`; `state_values[r + 1]` is the state
+    /// after round `r`'s linear layer. The last entry's low half is this level's output digest,
+    /// mirroring `crate::syscall::precompiles::poseidon2::Poseidon2Cols::state_values`.
+    pub state_values: [[T; WIDTH]; TOTAL_ROUNDS + 1],
+    /// S-box witnesses for every lane of every round of this level's compression.
+    pub sbox: [[MtreeSboxCols<T>; WIDTH]; TOTAL_ROUNDS],
+}
+
+/// Column layout for `MTREE_VERIFY_PATH`: one call, one row, with [`MTREE_MAX_DEPTH`] embedded
+/// sibling levels -- the same "one call = one row, with embedded per-sub-step structure" shape
+/// `crate::syscall::precompiles::poseidon2::Poseidon2Cols` uses for its rounds.
+#[derive(AlignedBorrow, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct MtreeVerifyPathCols<T> {
+    pub shard: T,
+    pub clk: T,
+
+    pub leaf_ptr: T,
+    pub root_ptr: T,
+    /// The leaf's index in the tree. Only the low [`MTREE_MAX_DEPTH`] bits are used; an index
+    /// that needs more bits than `depth` provides makes the call unverifiable (see
+    /// [`super::air`]), not out-of-bounds memory access -- the index itself is never used as an
+    /// address.
+    pub index: T,
+    /// The number of levels this call actually walks, `<= `[`MTREE_MAX_DEPTH`].
+    pub depth: T,
+
+    pub leaf_access: [MemoryReadCols<T>; MTREE_DIGEST_WORDS],
+    pub root_access: [MemoryReadCols<T>; MTREE_DIGEST_WORDS],
+
+    pub levels: [MtreeLevelCols<T>; MTREE_MAX_DEPTH],
+
+    /// `1` iff the last active level's output digest equals `root_access`'s value; this is what
+    /// `execute` returns to the guest in `a0`.
+    pub verified: T,
+
+    pub is_real: T,
+}
+
+pub const NUM_MTREE_VERIFY_PATH_COLS: usize = size_of::<MtreeVerifyPathCols<u8>>();
+
+/// Column layout for `MTREE_MERGE`: a single compression step, the in-place binary-op shape
+/// `crate::syscall::precompiles::bn254_scalar::Bn254ScalarOpCols` uses for its own one-row-per-op
+/// chip, reusing the same width-8 round witness [`MtreeLevelCols`] embeds for one level.
+#[derive(AlignedBorrow, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct MtreeMergeCols<T> {
+    pub shard: T,
+    pub clk: T,
+
+    pub left_ptr: T,
+    pub right_ptr: T,
+
+    pub left_access: [MemoryReadCols<T>; MTREE_DIGEST_WORDS],
+    pub right_access: [MemoryReadCols<T>; MTREE_DIGEST_WORDS],
+    /// The parent digest, written back over `left_ptr`.
+    pub parent_access: [MemoryWriteCols<T>; MTREE_DIGEST_WORDS],
+
+    /// `state_values[0]` is `[left || right]`; `state_values[r + 1]` is the state after round
+    /// `r`'s linear layer. The last entry's low half is the parent digest.
+    pub state_values: [[T; WIDTH]; TOTAL_ROUNDS + 1],
+    pub sbox: [[MtreeSboxCols<T>; WIDTH]; TOTAL_ROUNDS],
+
+    pub is_real: T,
+}
+
+pub const NUM_MTREE_MERGE_COLS: usize = size_of::<MtreeMergeCols<u8>>();
@@ -0,0 +1,69 @@
+//! `MachineAir` for [`MtreeVerifyPathChip`](super::air::MtreeVerifyPathChip).
+
+use p3_field::PrimeField32;
+use p3_matrix::dense::RowMajorMatrix;
+use zkm2_core_executor::{ExecutionRecord, Program};
+use zkm2_stark::air::MachineAir;
+
+use super::{
+    air::{MtreeMergeChip, MtreeVerifyPathChip},
+    columns::{NUM_MTREE_MERGE_COLS, NUM_MTREE_VERIFY_PATH_COLS},
+};
+
+impl<F: PrimeField32> MachineAir<F> for MtreeVerifyPathChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "MtreeVerifyPath".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        // Same gap as `Poseidon2Chip::generate_trace`: populating a row (memory access columns,
+        // per-level `state_values`/`sbox` witness for every active level) needs an
+        // `mtree_verify_path_events` field on `ExecutionRecord` and the executor-side dispatch
+        // that fills it in, neither of which exist yet in this tree -- left for the follow-up
+        // that wires `MTREE_VERIFY_PATH` into the record the same way `BN254_SCALAR_MAC` is.
+        let nb_rows = 0;
+        let padded_nb_rows = nb_rows.max(1);
+        let values = vec![F::ZERO; padded_nb_rows * NUM_MTREE_VERIFY_PATH_COLS];
+        let _ = input;
+
+        RowMajorMatrix::new(values, NUM_MTREE_VERIFY_PATH_COLS)
+    }
+
+    fn included(&self, _shard: &Self::Record) -> bool {
+        false
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for MtreeMergeChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "MtreeMerge".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        // Same gap as `MtreeVerifyPathChip::generate_trace` above, for `mtree_merge_events`.
+        let nb_rows = 0;
+        let padded_nb_rows = nb_rows.max(1);
+        let values = vec![F::ZERO; padded_nb_rows * NUM_MTREE_MERGE_COLS];
+        let _ = input;
+
+        RowMajorMatrix::new(values, NUM_MTREE_MERGE_COLS)
+    }
+
+    fn included(&self, _shard: &Self::Record) -> bool {
+        false
+    }
+}
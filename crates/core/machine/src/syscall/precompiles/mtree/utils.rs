@@ -0,0 +1,42 @@
+//! Round structure for the width-8 Poseidon2 compression this chip's levels each run, kept in
+//! sync by hand with [`zkm2_core_executor::syscalls::poseidon2`] -- the same split
+//! `super::super::poseidon2::utils` keeps with the same executor module, just at `WIDTH = 8`
+//! instead of `16`.
+
+/// State width the Merkle compression function runs at: a 4-word left digest and a 4-word right
+/// digest fill one width-8 Poseidon2 state.
+pub const WIDTH: usize = 8;
+pub const FULL_ROUNDS: usize = 8;
+pub const HALF_FULL_ROUNDS: usize = FULL_ROUNDS / 2;
+pub const PARTIAL_ROUNDS: usize = 21;
+pub const TOTAL_ROUNDS: usize = FULL_ROUNDS + PARTIAL_ROUNDS;
+
+/// Whether round `r` (0-indexed, external rounds first) is a full (external) round rather than a
+/// partial (internal) one. Byte-for-byte the same condition
+/// [`super::super::poseidon2::utils::is_full_round`] uses at `WIDTH = 16`.
+#[must_use]
+pub const fn is_full_round(r: usize) -> bool {
+    r < HALF_FULL_ROUNDS || r >= HALF_FULL_ROUNDS + PARTIAL_ROUNDS
+}
+
+/// The KoalaBear prime, duplicated from
+/// [`zkm2_core_executor::syscalls::poseidon2::KOALABEAR_PRIME`] -- see
+/// [`super::super::poseidon2::utils`] for why this AIR-side module doesn't import it directly.
+const KOALABEAR_PRIME: u64 = 0x7f00_0001;
+
+/// Byte-for-byte the same round constant
+/// [`zkm2_core_executor::syscalls::poseidon2::round_constant`] computes; see that function's doc
+/// comment for the caveat about these not being an audited parameter set.
+#[must_use]
+pub fn round_constant(round: usize, width: usize, lane: usize) -> u64 {
+    let mut z = (round as u64)
+        .wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        .wrapping_add((width as u64).wrapping_mul(0xBF58_476D_1CE4_E5B9))
+        .wrapping_add((lane as u64).wrapping_mul(0x94D0_49BB_1331_11EB));
+    z ^= z >> 30;
+    z = z.wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z ^= z >> 27;
+    z = z.wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    z % KOALABEAR_PRIME
+}
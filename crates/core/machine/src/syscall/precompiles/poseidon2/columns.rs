@@ -0,0 +1,56 @@
+use core::mem::size_of;
+
+use zkm2_derive::AlignedBorrow;
+
+use super::utils::{TOTAL_ROUNDS, WIDTH};
+use crate::memory::{MemoryReadCols, MemoryWriteCols};
+
+/// Per-round witness for one lane's S-box (`x^7`), decomposed so every constraint stays
+/// degree-bounded: `x2 = x^2`, `x4 = x2^2`, and `x6_x = x4 * x2 * x` is left to the AIR to check
+/// as two separate degree-2 products rather than adding a fourth column for it.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Poseidon2SboxCols<T> {
+    pub x2: T,
+    pub x4: T,
+    pub x6: T,
+}
+
+/// Column layout for the Poseidon2 permutation chip. Unlike [`super::super::keccak_sponge`]'s
+/// one-row-per-round design (needed there to reuse `p3_keccak_air`'s per-round table), this chip
+/// proves an entire width-16 permutation -- all [`TOTAL_ROUNDS`] rounds -- in a single row, the
+/// same "one call, one row" shape [`super::super::bn254_scalar::Bn254ScalarOpCols`] uses. That
+/// keeps the row count proportional to the number of `POSEIDON2_PERMUTE_16` calls rather than to
+/// `TOTAL_ROUNDS` times that, at the cost of a wide row (an S-box decomposition per lane per
+/// round).
+#[derive(AlignedBorrow, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Poseidon2Cols<T> {
+    // NOTE: kept field-for-field below; no `Default` impl, same as
+    // `MemCopyFixedCols`/`Bn254ScalarOpCols` -- rows are built directly out of a zeroed
+    // `Vec<F>` byte buffer in `trace.rs`, not via `Cols::default()`.
+    pub shard: T,
+    pub clk: T,
+    pub state_ptr: T,
+
+    /// The state as read from memory before the permutation, one memory-read column per lane.
+    pub state_access: [MemoryReadCols<T>; WIDTH],
+    /// The state as written back to memory after the permutation, one memory-write column per
+    /// lane.
+    pub result_access: [MemoryWriteCols<T>; WIDTH],
+
+    /// `state_values[0]` is the input state (copied from `state_access`); `state_values[r + 1]`
+    /// is the state after round `r`'s linear layer, for `r` in `0..TOTAL_ROUNDS`. The last entry
+    /// is therefore the permutation's output, which must equal `result_access`'s written values.
+    pub state_values: [[T; WIDTH]; TOTAL_ROUNDS + 1],
+
+    /// S-box decomposition witnesses for every lane of every round. For a partial round, only
+    /// lane `0`'s entry is constrained; the AIR leaves the rest unconstrained (a padding row's
+    /// `Vec<F>` buffer is zero-initialized, and `is_real` being unset skips every constraint
+    /// above anyway).
+    pub sbox: [[Poseidon2SboxCols<T>; WIDTH]; TOTAL_ROUNDS],
+
+    pub is_real: T,
+}
+
+pub const NUM_POSEIDON2_COLS: usize = size_of::<Poseidon2Cols<u8>>();
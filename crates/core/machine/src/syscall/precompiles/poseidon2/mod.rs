@@ -0,0 +1,27 @@
+mod air;
+mod columns;
+mod trace;
+mod utils;
+
+pub use air::*;
+pub use columns::*;
+
+#[cfg(test)]
+mod tests {
+
+    use test_artifacts::POSEIDON2_PERMUTE_ELF;
+    use zkm_core_executor::Program;
+    use zkm_stark::CpuProver;
+
+    use crate::{
+        io::ZKMStdin,
+        utils::{self, run_test_io},
+    };
+
+    #[test]
+    fn test_poseidon2_permute() {
+        utils::setup_logger();
+        let program = Program::from(POSEIDON2_PERMUTE_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, ZKMStdin::new()).unwrap();
+    }
+}
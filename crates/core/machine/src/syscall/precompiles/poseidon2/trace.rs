@@ -0,0 +1,39 @@
+//! `MachineAir` for [`Poseidon2Chip`](super::air::Poseidon2Chip).
+
+use p3_field::PrimeField32;
+use p3_matrix::dense::RowMajorMatrix;
+use zkm2_core_executor::{ExecutionRecord, Program};
+use zkm2_stark::air::MachineAir;
+
+use super::{air::Poseidon2Chip, columns::NUM_POSEIDON2_COLS};
+
+impl<F: PrimeField32> MachineAir<F> for Poseidon2Chip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Poseidon2".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        // Same gap as `Bn254ScalarOpChip::generate_trace`: populating a row (the read/write
+        // memory events, `state_values`/`sbox` witness for every one of `TOTAL_ROUNDS` rounds)
+        // needs a `poseidon2_events` field on `ExecutionRecord` and the executor-side dispatch
+        // that fills it in, neither of which exist yet in this tree -- left for the follow-up
+        // that wires `POSEIDON2_PERMUTE_16` into the record the same way `BN254_SCALAR_MAC` is.
+        let nb_rows = 0;
+        let padded_nb_rows = nb_rows.max(1);
+        let values = vec![F::ZERO; padded_nb_rows * NUM_POSEIDON2_COLS];
+        let _ = input;
+
+        RowMajorMatrix::new(values, NUM_POSEIDON2_COLS)
+    }
+
+    fn included(&self, _shard: &Self::Record) -> bool {
+        false
+    }
+}
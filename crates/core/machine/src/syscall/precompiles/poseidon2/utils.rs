@@ -0,0 +1,41 @@
+//! Round structure shared by [`super::columns::Poseidon2Cols`] and [`super::air`], kept in sync
+//! by hand with the executor's [`zkm2_core_executor::syscalls::poseidon2`] (same "host computes,
+//! chip re-derives" split every other precompile in this directory uses).
+
+/// State width this chip proves. `WIDTH = 8` is the other width the executor syscall supports;
+/// adding a second chip for it means duplicating this file with the width-8 round counts, left
+/// as a follow-up since nothing here depends on `WIDTH` beyond these three constants.
+pub const WIDTH: usize = 16;
+pub const FULL_ROUNDS: usize = 8;
+pub const HALF_FULL_ROUNDS: usize = FULL_ROUNDS / 2;
+pub const PARTIAL_ROUNDS: usize = 13;
+pub const TOTAL_ROUNDS: usize = FULL_ROUNDS + PARTIAL_ROUNDS;
+
+/// Whether round `r` (0-indexed over the whole permutation, external rounds first) is a full
+/// (external) round rather than a partial (internal) one.
+#[must_use]
+pub const fn is_full_round(r: usize) -> bool {
+    r < HALF_FULL_ROUNDS || r >= HALF_FULL_ROUNDS + PARTIAL_ROUNDS
+}
+
+/// The KoalaBear prime, duplicated from
+/// [`zkm2_core_executor::syscalls::poseidon2::KOALABEAR_PRIME`] so this AIR-side module doesn't
+/// need to depend on the executor crate just for one constant.
+const KOALABEAR_PRIME: u64 = 0x7f00_0001;
+
+/// Byte-for-byte the same round constant the executor computes in
+/// [`zkm2_core_executor::syscalls::poseidon2::round_constant`] -- see that function's doc comment
+/// for the caveat about these not being an audited parameter set.
+#[must_use]
+pub fn round_constant(round: usize, width: usize, lane: usize) -> u64 {
+    let mut z = (round as u64)
+        .wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        .wrapping_add((width as u64).wrapping_mul(0xBF58_476D_1CE4_E5B9))
+        .wrapping_add((lane as u64).wrapping_mul(0x94D0_49BB_1331_11EB));
+    z ^= z >> 30;
+    z = z.wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z ^= z >> 27;
+    z = z.wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    z % KOALABEAR_PRIME
+}
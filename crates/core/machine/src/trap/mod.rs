@@ -0,0 +1,290 @@
+//! A chip that proves [`TrapEvent`]s: whenever the executor raises a CP0 exception (a trapping
+//! `TADD`/`TSUB` overflow, or, once the load/store path is taught to raise one instead of hard
+//! aborting, an unaligned address), it records a [`TrapEvent`] describing why. This chip
+//! constrains that every recorded event really does have a well-formed, single `cause` and a
+//! `bad_vaddr` that's zero for every cause but the two address errors.
+//!
+//! Structured the same way as [`crate::alu::mul::MulChip`]: one row per event, a handful of
+//! boolean cause selectors in place of an opcode column, and a `nonce` threaded through
+//! `receive_trap` for the same row-disambiguation reason documented on
+//! [`crate::alu::mul::MulCols::nonce`]. The sending side -- the CPU dispatch loop noticing an
+//! instruction faulted and issuing the matching `send_trap` -- doesn't exist in this tree yet, for
+//! the same reason [`crate::air::alu::AluAirBuilder`] and [`crate::air::trap::TrapAirBuilder`]'s
+//! doc comments give for the ALU bus: there's no `CpuChip`/`Air::eval` dispatch loop here to add it
+//! to. This chip's tests exercise its own local constraints directly, the same way
+//! [`crate::alu::mul::MulChip`]'s do.
+
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use hashbrown::HashMap;
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::{ParallelBridge, ParallelIterator, ParallelSlice};
+use zkm_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, TrapCause, TrapEvent},
+    ExecutionRecord, Program,
+};
+use zkm_derive::AlignedBorrow;
+use zkm_stark::{air::MachineAir, Word};
+
+use crate::{
+    air::ZKMCoreAirBuilder,
+    utils::{next_power_of_two, zeroed_f_vec},
+};
+
+/// The number of main trace columns for `TrapChip`.
+pub const NUM_TRAP_COLS: usize = size_of::<TrapCols<u8>>();
+
+/// A chip that proves [`TrapEvent`]s raised by the executor's CP0 exception handling.
+#[derive(Default)]
+pub struct TrapChip;
+
+/// The column layout for the chip.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct TrapCols<T> {
+    /// The shard number.
+    pub shard: T,
+    /// The clock cycle.
+    pub clk: T,
+    /// The program counter of the faulting instruction.
+    pub pc: T,
+    /// The faulting virtual address. Zero unless `is_load_address_error`/`is_store_address_error`.
+    pub bad_vaddr: Word<T>,
+
+    /// Flag indicating the cause is [`TrapCause::IntegerOverflow`].
+    pub is_integer_overflow: T,
+    /// Flag indicating the cause is [`TrapCause::LoadAddressError`].
+    pub is_load_address_error: T,
+    /// Flag indicating the cause is [`TrapCause::StoreAddressError`].
+    pub is_store_address_error: T,
+
+    /// Selector to know whether this row is enabled.
+    pub is_real: T,
+
+    /// This row's index within the shard, threaded into [`Self`]'s `receive_trap` lookup below.
+    /// Plays the same role as [`crate::alu::mul::MulCols::nonce`] -- see its doc comment.
+    pub nonce: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for TrapChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Trap".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let nb_rows = input.trap_events.len();
+        let size_log2 = input.fixed_log2_rows::<F, _>(self);
+        let padded_nb_rows = next_power_of_two(nb_rows, size_log2);
+        let mut values = zeroed_f_vec(padded_nb_rows * NUM_TRAP_COLS);
+        let chunk_size = std::cmp::max((nb_rows + 1) / num_cpus::get(), 1);
+
+        values.chunks_mut(chunk_size * NUM_TRAP_COLS).enumerate().par_bridge().for_each(
+            |(i, rows)| {
+                rows.chunks_mut(NUM_TRAP_COLS).enumerate().for_each(|(j, row)| {
+                    let idx = i * chunk_size + j;
+                    let cols: &mut TrapCols<F> = row.borrow_mut();
+
+                    if idx < nb_rows {
+                        let mut byte_lookup_events = Vec::new();
+                        let event = &input.trap_events[idx];
+                        self.event_to_row(event, cols, &mut byte_lookup_events);
+                        cols.nonce = F::from_canonical_usize(idx);
+                    }
+                });
+            },
+        );
+
+        RowMajorMatrix::new(values, NUM_TRAP_COLS)
+    }
+
+    fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
+        let chunk_size = std::cmp::max(input.trap_events.len() / num_cpus::get(), 1);
+
+        let blu_batches = input
+            .trap_events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut blu: HashMap<ByteLookupEvent, usize> = HashMap::new();
+                events.iter().for_each(|event| {
+                    let mut row = [F::ZERO; NUM_TRAP_COLS];
+                    let cols: &mut TrapCols<F> = row.as_mut_slice().borrow_mut();
+                    self.event_to_row(event, cols, &mut blu);
+                });
+                blu
+            })
+            .collect::<Vec<_>>();
+
+        output.add_byte_lookup_events_from_maps(blu_batches.iter().collect::<Vec<_>>());
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.trap_events.is_empty()
+        }
+    }
+
+    fn local_only(&self) -> bool {
+        true
+    }
+}
+
+impl TrapChip {
+    /// Create a row from an event.
+    fn event_to_row<F: PrimeField32>(
+        &self,
+        event: &TrapEvent,
+        cols: &mut TrapCols<F>,
+        blu: &mut impl ByteRecord,
+    ) {
+        cols.shard = F::from_canonical_u32(event.shard);
+        cols.clk = F::from_canonical_u32(event.clk);
+        cols.pc = F::from_canonical_u32(event.pc);
+
+        let bad_vaddr_word = event.bad_vaddr.to_le_bytes();
+        cols.bad_vaddr = Word(bad_vaddr_word.map(F::from_canonical_u8));
+        blu.add_u8_range_checks(&bad_vaddr_word);
+
+        cols.is_integer_overflow = F::from_bool(event.cause == TrapCause::IntegerOverflow);
+        cols.is_load_address_error = F::from_bool(event.cause == TrapCause::LoadAddressError);
+        cols.is_store_address_error = F::from_bool(event.cause == TrapCause::StoreAddressError);
+        cols.is_real = F::ONE;
+    }
+}
+
+impl<F> BaseAir<F> for TrapChip {
+    fn width(&self) -> usize {
+        NUM_TRAP_COLS
+    }
+}
+
+impl<AB> Air<AB> for TrapChip
+where
+    AB: ZKMCoreAirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &TrapCols<AB::Var> = (*local).borrow();
+
+        // Check that the cause selectors are boolean, and that exactly one is set per real row.
+        let booleans = [
+            local.is_integer_overflow,
+            local.is_load_address_error,
+            local.is_store_address_error,
+            local.is_real,
+        ];
+        for boolean in booleans.iter() {
+            builder.assert_bool(*boolean);
+        }
+        builder.when(local.is_real).assert_one(
+            local.is_integer_overflow + local.is_load_address_error + local.is_store_address_error,
+        );
+
+        // `bad_vaddr` only means something for the two address-error causes; every other cause
+        // must carry a zeroed `bad_vaddr`, matching how `TrapEvent::bad_vaddr` is populated.
+        let is_address_error = local.is_load_address_error + local.is_store_address_error;
+        builder.when(local.is_real).when_not(is_address_error).assert_word_zero(local.bad_vaddr);
+
+        // Range check the bytes of `bad_vaddr`.
+        builder.slice_range_check_u8(&local.bad_vaddr.0, local.is_real);
+
+        // Encode `cause` the same way `TrapCause`'s discriminants are laid out: IntegerOverflow =
+        // 0, LoadAddressError = 1, StoreAddressError = 2.
+        let cause = local.is_load_address_error + local.is_store_address_error * AB::Expr::from_canonical_u32(2);
+
+        // Receive the trap. `local.nonce` binds this row to a unique position in the trace (see
+        // its doc comment on [`TrapCols`]) so the lookup can't be satisfied by a different row.
+        builder.receive_trap(
+            cause,
+            local.pc,
+            local.bad_vaddr,
+            local.shard,
+            local.nonce,
+            local.is_real,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+
+    use p3_field::FieldAlgebra;
+    use p3_koala_bear::KoalaBear;
+    use p3_matrix::{dense::RowMajorMatrix, Matrix};
+    use zkm_core_executor::{
+        events::{TrapCause, TrapEvent},
+        ExecutionRecord,
+    };
+    use zkm_stark::{air::MachineAir, koala_bear_poseidon2::KoalaBearPoseidon2, StarkGenericConfig};
+
+    use crate::utils::{uni_stark_prove as prove, uni_stark_verify as verify};
+
+    use super::{TrapChip, TrapCols};
+
+    fn overflowing_tadd_event() -> TrapEvent {
+        TrapEvent { shard: 0, clk: 0, pc: 0x400, bad_vaddr: 0, cause: TrapCause::IntegerOverflow }
+    }
+
+    #[test]
+    fn generate_trace_trap() {
+        let mut shard = ExecutionRecord::default();
+        shard.trap_events = vec![
+            overflowing_tadd_event(),
+            TrapEvent { shard: 0, clk: 4, pc: 0x1000, bad_vaddr: 0x1001, cause: TrapCause::LoadAddressError },
+            TrapEvent { shard: 0, clk: 8, pc: 0x2000, bad_vaddr: 0x2003, cause: TrapCause::StoreAddressError },
+        ];
+        let chip = TrapChip::default();
+        let trace: RowMajorMatrix<KoalaBear> =
+            chip.generate_trace(&shard, &mut ExecutionRecord::default());
+
+        let row0: &TrapCols<KoalaBear> = trace.row_slice(0).borrow();
+        assert_eq!(row0.is_integer_overflow, KoalaBear::ONE);
+        let row1: &TrapCols<KoalaBear> = trace.row_slice(1).borrow();
+        assert_eq!(row1.is_load_address_error, KoalaBear::ONE);
+        let row2: &TrapCols<KoalaBear> = trace.row_slice(2).borrow();
+        assert_eq!(row2.is_store_address_error, KoalaBear::ONE);
+    }
+
+    #[test]
+    fn prove_koalabear() {
+        let config = KoalaBearPoseidon2::new();
+        let mut challenger = config.challenger();
+
+        let mut shard = ExecutionRecord::default();
+        let mut trap_events = vec![overflowing_tadd_event()];
+        for i in 0..64u32 {
+            trap_events.push(TrapEvent {
+                shard: 0,
+                clk: i,
+                pc: 0x400 + i * 4,
+                bad_vaddr: 0x1000 + i,
+                cause: if i % 2 == 0 { TrapCause::LoadAddressError } else { TrapCause::StoreAddressError },
+            });
+        }
+        shard.trap_events = trap_events;
+
+        let chip = TrapChip::default();
+        let trace: RowMajorMatrix<KoalaBear> =
+            chip.generate_trace(&shard, &mut ExecutionRecord::default());
+        let proof = prove::<KoalaBearPoseidon2, _>(&config, &chip, &mut challenger, trace);
+
+        let mut challenger = config.challenger();
+        verify(&config, &chip, &mut challenger, &proof).unwrap();
+    }
+}
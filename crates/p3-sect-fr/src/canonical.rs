@@ -0,0 +1,98 @@
+//! A reusable "is this the canonical representative" AIR gadget for [`SectFr`]: given `NUM_BITS`
+//! boolean witness columns (least-significant first), [`assert_canonical_bits`] constrains each to
+//! be boolean, reconstructs the field value they encode, and enforces that value is strictly less
+//! than `r` -- the standard "skip leading bits of the modulus" bit-decomposition range check,
+//! needed anywhere an AIR accepts a `SectFr` witness and must reject a non-canonical (`>= r`)
+//! representation of it.
+
+use p3_air::AirBuilder;
+use p3_field::{Field, FieldAlgebra};
+
+use crate::SectFr;
+
+/// `r`'s bits, most-significant first, truncated/padded to `num_bits`. Derived from
+/// [`SectFr::order`] rather than hardcoded so it stays correct if `SectFr`'s modulus ever changes.
+fn modulus_bits_msb_first(num_bits: usize) -> Vec<bool> {
+    let order = SectFr::order();
+    (0..num_bits).rev().map(|i| order.bit(i as u64)).collect()
+}
+
+/// Constrains `bits` (least-significant first) to be a canonical little-endian bit decomposition
+/// of a `SectFr` element, and returns the value it reconstructs, `Σ bits[i] * 2^i`.
+///
+/// Besides asserting every entry of `bits` is boolean, this enforces the reconstructed value is
+/// strictly less than `r` by walking `r`'s bits most-significant first while tracking
+/// `matches_prefix`, a running indicator of whether every higher bit has equaled `r`'s
+/// corresponding bit so far:
+/// - At a modulus bit that is `0`: if the prefix still matches, the witness bit is forced to `0`
+///   too (a `1` there would already make the value exceed `r`'s prefix, which dominates any lower
+///   bits in a most-significant-first comparison).
+/// - At a modulus bit that is `1`: the witness bit is left free; picking `0` there makes the value
+///   already strictly smaller than `r` regardless of the remaining lower bits (so `matches_prefix`
+///   drops to `0` and every later constraint in this function becomes vacuous), while picking `1`
+///   keeps the prefix matching exactly.
+///
+/// After the walk, `matches_prefix` is asserted to be `0`: the all-bits-equal path (`value == r`)
+/// is the one case the per-bit constraints above don't already rule out, and `r` itself isn't a
+/// canonical representative.
+///
+/// `bits.len()` is expected to be `r`'s bit length (so the check is exact); a shorter `bits` is
+/// also sound (it just additionally proves the value fits in fewer bits than `r` needs).
+pub fn assert_canonical_bits<AB: AirBuilder>(
+    builder: &mut AB,
+    bits: &[AB::Var],
+    is_real: impl Into<AB::Expr>,
+) -> AB::Expr
+where
+    AB::Var: Copy,
+{
+    let is_real = is_real.into();
+    for &bit in bits {
+        builder.when(is_real.clone()).assert_bool(bit);
+    }
+
+    let two = AB::Expr::from_canonical_u32(2);
+    let mut value = AB::Expr::ZERO;
+    let mut power = AB::Expr::ONE;
+    for &bit in bits {
+        value = value + AB::Expr::from(bit) * power.clone();
+        power = power * two.clone();
+    }
+
+    let modulus_bits = modulus_bits_msb_first(bits.len());
+    let mut matches_prefix = is_real.clone();
+    for (i, &modulus_bit) in modulus_bits.iter().enumerate() {
+        let witness_bit: AB::Expr = bits[bits.len() - 1 - i].into();
+        if modulus_bit {
+            matches_prefix = matches_prefix * witness_bit;
+        } else {
+            builder.when(matches_prefix.clone()).assert_zero(witness_bit);
+            // `witness_bit` is forced to 0 whenever `matches_prefix` was 1, so the prefix keeps
+            // matching in that case; multiplying by `is_real` again would be redundant, so just
+            // carry `matches_prefix` through unchanged.
+        }
+    }
+
+    builder.when(is_real).assert_zero(matches_prefix);
+
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modulus_bits_roundtrip() {
+        let num_bits = SectFr::order().bits() as usize;
+        let bits = modulus_bits_msb_first(num_bits);
+        let mut reconstructed = num_bigint::BigUint::from(0u32);
+        for &bit in &bits {
+            reconstructed <<= 1u32;
+            if bit {
+                reconstructed += 1u32;
+            }
+        }
+        assert_eq!(reconstructed, SectFr::order());
+    }
+}
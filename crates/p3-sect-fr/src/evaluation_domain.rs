@@ -0,0 +1,173 @@
+//! A radix-2 evaluation domain over [`SectFr`], analogous to bellman's `EvaluationDomain`: holds a
+//! vector of coefficients padded out to a power-of-two size `2^exp`, the domain's generator
+//! `omega` (a `2^exp`-th root of unity) and its inverse, the field generator's inverse (for coset
+//! evaluation), and `m_inv = (2^exp)^{-1}` (for un-normalized inverse transforms).
+//!
+//! `SectFr`'s multiplicative group has a very small 2-Sylow subgroup
+//! ([`p3_field::TwoAdicField::TWO_ADICITY`] is just 1), so in practice this only supports domains
+//! of size 1 or 2 -- [`EvaluationDomain::from_coeffs`] rejects anything larger with
+//! [`SectFrError::PolynomialDegreeTooLarge`] rather than silently truncating or picking a
+//! non-power-of-two domain.
+
+use p3_field::{Field, TwoAdicField};
+
+use crate::{SectFr, SectFrError};
+
+/// A power-of-two-sized evaluation domain over [`SectFr`], and the coefficients it was built from.
+pub struct EvaluationDomain {
+    coeffs: Vec<SectFr>,
+    /// `log2` of `coeffs.len()`.
+    exp: usize,
+    /// A `2^exp`-th root of unity, the domain's FFT generator.
+    omega: SectFr,
+    /// `omega`'s inverse, used by [`Self::ifft`].
+    omega_inv: SectFr,
+    /// The field's multiplicative generator's inverse, used to shift into/out of a coset.
+    gen_inv: SectFr,
+    /// `(2^exp)^{-1}`, the normalization factor [`Self::ifft`] applies after the inverse
+    /// transform.
+    m_inv: SectFr,
+}
+
+impl EvaluationDomain {
+    /// Pads `coeffs` up to the next power of two and builds the domain for that size.
+    ///
+    /// # Errors
+    /// Returns [`SectFrError::PolynomialDegreeTooLarge`] if the padded size would exceed
+    /// `2^SectFr::TWO_ADICITY`.
+    pub fn from_coeffs(mut coeffs: Vec<SectFr>) -> Result<Self, SectFrError> {
+        let mut m = 1usize;
+        let mut exp = 0usize;
+        while m < coeffs.len().max(1) {
+            m *= 2;
+            exp += 1;
+            if exp > SectFr::TWO_ADICITY {
+                return Err(SectFrError::PolynomialDegreeTooLarge {
+                    log_degree: exp,
+                    two_adicity: SectFr::TWO_ADICITY,
+                });
+            }
+        }
+        coeffs.resize(m, SectFr::ZERO);
+
+        let omega = SectFr::two_adic_generator(exp);
+        let gen_inv = SectFr::GENERATOR.inverse();
+        let m_inv = SectFr::from_canonical_usize(m).inverse();
+
+        Ok(Self { coeffs, exp, omega, omega_inv: omega.inverse(), gen_inv, m_inv })
+    }
+
+    /// The domain's coefficients/evaluations (whichever this instance currently holds).
+    #[must_use]
+    pub fn coeffs(&self) -> &[SectFr] {
+        &self.coeffs
+    }
+
+    /// Consumes the domain, returning its coefficients/evaluations.
+    #[must_use]
+    pub fn into_coeffs(self) -> Vec<SectFr> {
+        self.coeffs
+    }
+
+    /// Evaluates the polynomial at every point of the domain in place, via the standard radix-2
+    /// Cooley-Tukey butterfly.
+    pub fn fft(&mut self) {
+        serial_fft(&mut self.coeffs, self.omega, self.exp);
+    }
+
+    /// The inverse of [`Self::fft`]: interpolates evaluations back to coefficients in place.
+    pub fn ifft(&mut self) {
+        serial_fft(&mut self.coeffs, self.omega_inv, self.exp);
+        let m_inv = self.m_inv;
+        for v in &mut self.coeffs {
+            *v *= m_inv;
+        }
+    }
+
+    /// Evaluates the polynomial over the coset `g * domain` (where `g` is `SectFr::GENERATOR`)
+    /// instead of the domain itself, by distributing powers of `g` through the coefficients before
+    /// transforming.
+    pub fn coset_fft(&mut self) {
+        self.distribute_powers(SectFr::GENERATOR);
+        self.fft();
+    }
+
+    /// Multiplies `self.coeffs[i]` by `g^i` in place.
+    pub fn distribute_powers(&mut self, g: SectFr) {
+        let mut power = SectFr::ONE;
+        for v in &mut self.coeffs {
+            *v *= power;
+            power *= g;
+        }
+    }
+}
+
+/// In-place radix-2 Cooley-Tukey FFT of `a` (length `2^log_n`) using `omega`, a `2^log_n`-th root
+/// of unity: bit-reverses `a`, then combines butterflies in `log_n` passes of doubling stride.
+fn serial_fft(a: &mut [SectFr], omega: SectFr, log_n: usize) {
+    let n = a.len();
+    debug_assert_eq!(n, 1 << log_n);
+
+    for k in 0..n {
+        let rk = bitreverse(k, log_n);
+        if k < rk {
+            a.swap(k, rk);
+        }
+    }
+
+    let mut m = 1usize;
+    for _ in 0..log_n {
+        let w_m = omega.exp_u64((n / (2 * m)) as u64);
+
+        let mut k = 0;
+        while k < n {
+            let mut w = SectFr::ONE;
+            for j in 0..m {
+                let mut t = a[k + j + m];
+                t *= w;
+                let mut tmp = a[k + j];
+                tmp -= t;
+                a[k + j + m] = tmp;
+                a[k + j] += t;
+                w *= w_m;
+            }
+            k += 2 * m;
+        }
+        m *= 2;
+    }
+}
+
+fn bitreverse(mut n: usize, l: usize) -> usize {
+    let mut r = 0;
+    for _ in 0..l {
+        r = (r << 1) | (n & 1);
+        n >>= 1;
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_field::FieldAlgebra;
+
+    use super::*;
+
+    #[test]
+    fn test_fft_ifft_roundtrip() {
+        // SectFr::TWO_ADICITY is 1, so the largest domain this field supports has size 2.
+        let coeffs = vec![SectFr::from_canonical_u8(3), SectFr::from_canonical_u8(5)];
+        let mut domain = EvaluationDomain::from_coeffs(coeffs.clone()).unwrap();
+        domain.fft();
+        domain.ifft();
+        assert_eq!(domain.into_coeffs(), coeffs);
+    }
+
+    #[test]
+    fn test_degree_too_large_is_rejected() {
+        let coeffs = vec![SectFr::ZERO; 4];
+        assert!(matches!(
+            EvaluationDomain::from_coeffs(coeffs),
+            Err(SectFrError::PolynomialDegreeTooLarge { .. })
+        ));
+    }
+}
@@ -6,6 +6,9 @@
 /// `r = 3450873173395281893717377931138512760570940988862252126328087024741343`.
 pub mod params;
 
+pub mod canonical;
+pub mod curve;
+pub mod evaluation_domain;
 pub mod poseidon2;
 
 use core::{
@@ -23,7 +26,7 @@ use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
-use serde::{ser::SerializeSeq, Deserialize, Deserializer, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 #[derive(FFPrimeField)]
 #[PrimeFieldModulus = "3450873173395281893717377931138512760570940988862252126328087024741343"]
@@ -42,37 +45,132 @@ impl SectFr {
     pub(crate) const fn new(value: FFSectFr) -> Self {
         Self { value }
     }
+
+    /// The minimum number of little-endian input bytes [`Self::from_bytes_wide`] accepts: enough
+    /// to keep the reduced residue's statistical distance from uniform below `2^-128` (the
+    /// standard wide-reduction margin), i.e. `ceil((bits(r) + 128) / 8)`.
+    #[must_use]
+    pub fn wide_reduction_min_bytes() -> usize {
+        (Self::order().bits() as usize + 128).div_ceil(8)
+    }
+
+    /// Reduces a wide, little-endian byte string modulo `r` without the bias a same-width `value
+    /// % r` would introduce, the standard technique (also used e.g. for Ed448/FROST scalar
+    /// sampling) for safely deriving a field element from transcript/hash output. `bytes` must be
+    /// at least [`Self::wide_reduction_min_bytes`] long; an exact multiple of `r` correctly maps
+    /// to zero, since the reduction is a plain modular reduction with no special-casing.
+    pub fn from_bytes_wide(bytes: &[u8]) -> Result<Self, SectFrError> {
+        let min_bytes = Self::wide_reduction_min_bytes();
+        if bytes.len() < min_bytes {
+            return Err(SectFrError::InputTooShort { got: bytes.len(), min: min_bytes });
+        }
+
+        let reduced = BigUint::from_bytes_le(bytes) % Self::order();
+        Ok(Self::new(FFSectFr::from_str_vartime(&reduced.to_str_radix(10)).unwrap()))
+    }
+
+    /// Hashes `input` into `F_r` via SHA-512 (whose 64-byte output already clears
+    /// [`Self::wide_reduction_min_bytes`]) followed by [`Self::from_bytes_wide`] -- the
+    /// hash-to-field recipe Fiat-Shamir challenge derivation and the SECT wrap circuit need.
+    #[must_use]
+    pub fn hash_to_field(input: &[u8]) -> Self {
+        use sha2::{Digest, Sha512};
+        let digest = Sha512::digest(input);
+        Self::from_bytes_wide(&digest)
+            .expect("a SHA-512 digest is always at least wide_reduction_min_bytes() long")
+    }
+
+    /// The canonical little-endian bit decomposition of `self`, one entry per bit of `r`'s repr
+    /// width. Pairs with [`canonical::assert_canonical_bits`], which reconstructs a field element
+    /// from exactly this decomposition inside an AIR.
+    #[must_use]
+    pub fn to_le_bits(&self) -> Vec<bool> {
+        self.value.to_le_bits().into_iter().collect()
+    }
+}
+
+/// Errors from [`SectFr::from_bytes_wide`] and [`evaluation_domain::EvaluationDomain`].
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SectFrError {
+    /// The input was shorter than [`SectFr::wide_reduction_min_bytes`], too narrow to keep the
+    /// reduced residue within the intended statistical distance from uniform.
+    #[error("input is {got} bytes, need at least {min} for an unbiased wide reduction")]
+    InputTooShort { got: usize, min: usize },
+
+    /// The requested evaluation domain needs a `2^log_degree`-th root of unity, but `SectFr`'s
+    /// multiplicative group only has a 2-Sylow subgroup of order `2^TWO_ADICITY`.
+    #[error(
+        "polynomial of degree requiring a domain of size 2^{log_degree} is too large: SectFr's \
+         two-adicity is only {two_adicity}"
+    )]
+    PolynomialDegreeTooLarge { log_degree: usize, two_adicity: usize },
 }
 
 impl Serialize for SectFr {
+    /// Binary (non-human-readable) serializers get the fixed 32-byte little-endian canonical repr
+    /// as a single length-prefixed blob (via `serde_bytes`, not one sequence element per byte).
+    /// Human-readable serializers (JSON, etc.) get the canonical integer as a hex string, which is
+    /// both compact and legible in logs/fixtures.
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let repr = self.value.to_repr();
         let bytes = repr.as_ref();
 
-        let mut seq = serializer.serialize_seq(Some(bytes.len()))?;
-        for e in bytes {
-            seq.serialize_element(&e)?;
+        if serializer.is_human_readable() {
+            let hex: String = bytes.iter().rev().map(|b| format!("{b:02x}")).collect();
+            serializer.serialize_str(&format!("0x{hex}"))
+        } else {
+            serde_bytes::Bytes::new(bytes).serialize(serializer)
         }
-        seq.end()
     }
 }
 
 impl<'de> Deserialize<'de> for SectFr {
+    /// The symmetric counterpart to [`Serialize for SectFr`](Serialize): binary serializers must
+    /// supply exactly 32 bytes, human-readable ones a `0x`-prefixed hex (or plain decimal) string
+    /// of the canonical integer. Either way, a repr that isn't the canonical representative of a
+    /// field element (i.e. `value >= r`) is rejected via `from_repr`'s `CtOption`, rather than
+    /// silently wrapping.
     fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
-        let bytes: Vec<u8> = Deserialize::deserialize(d)?;
+        let bytes: Vec<u8> = if d.is_human_readable() {
+            let s: String = Deserialize::deserialize(d)?;
+            let digits = s.strip_prefix("0x").unwrap_or(&s);
+
+            let mut value = if s.starts_with("0x") {
+                BigUint::parse_bytes(digits.as_bytes(), 16)
+            } else {
+                BigUint::parse_bytes(digits.as_bytes(), 10)
+            }
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid SectFr literal: {s}")))?
+            .to_bytes_le();
+
+            let repr_len = <FFSectFr as FFPrimeField>::Repr::default().0.as_ref().len();
+            if value.len() > repr_len {
+                return Err(serde::de::Error::custom(format!(
+                    "SectFr literal {s} does not fit in {repr_len} bytes"
+                )));
+            }
+            value.resize(repr_len, 0);
+            value
+        } else {
+            serde_bytes::ByteBuf::deserialize(d)?.into_vec()
+        };
 
         let mut res = <FFSectFr as FFPrimeField>::Repr::default();
-
-        for (i, digit) in res.0.as_mut().iter_mut().enumerate() {
-            *digit = bytes[i];
+        let repr_bytes = res.0.as_mut();
+        if bytes.len() != repr_bytes.len() {
+            return Err(serde::de::Error::custom(format!(
+                "expected exactly {} bytes, got {}",
+                repr_bytes.len(),
+                bytes.len()
+            )));
         }
+        repr_bytes.copy_from_slice(&bytes);
 
         let value = FFSectFr::from_repr(res);
-
         if value.is_some().into() {
             Ok(Self { value: value.unwrap() })
         } else {
-            Err(serde::de::Error::custom("Invalid field element"))
+            Err(serde::de::Error::custom("non-canonical SectFr repr (value >= r)"))
         }
     }
 }
@@ -196,6 +294,24 @@ impl PrimeField for SectFr {
     }
 }
 
+impl p3_field::TwoAdicField for SectFr {
+    const TWO_ADICITY: usize = <FFSectFr as FFPrimeField>::S as usize;
+
+    fn two_adic_generator(bits: usize) -> Self {
+        assert!(
+            bits <= Self::TWO_ADICITY,
+            "requested two-adic generator of order 2^{bits}, but SectFr's multiplicative group \
+             only has a 2-Sylow subgroup of order 2^{}",
+            Self::TWO_ADICITY
+        );
+        let mut root = Self::new(FFSectFr::root_of_unity());
+        for _ in bits..Self::TWO_ADICITY {
+            root = root * root;
+        }
+        root
+    }
+}
+
 impl Add for SectFr {
     type Output = Self;
 
@@ -380,4 +496,81 @@ mod tests {
         let f_r_minus_2_deserialized: F = serde_json::from_str(&f_r_minus_2_serialized).unwrap();
         assert_eq!(f_r_minus_2, f_r_minus_2_deserialized);
     }
+
+    #[test]
+    fn test_from_bytes_wide() {
+        let min_bytes = F::wide_reduction_min_bytes();
+        assert!((48..=64).contains(&min_bytes));
+
+        // Too short is rejected rather than silently under-reducing.
+        let short = vec![0xFFu8; min_bytes - 1];
+        assert!(F::from_bytes_wide(&short).is_err());
+
+        // An exact multiple of `r` must reduce to zero.
+        let two_r = F::order() * BigUint::new(vec![2]);
+        let mut bytes = two_r.to_bytes_le();
+        bytes.resize(min_bytes, 0);
+        assert_eq!(F::from_bytes_wide(&bytes).unwrap(), F::ZERO);
+
+        // A value already below `r` should pass through unchanged.
+        let mut small = vec![0u8; min_bytes];
+        small[0] = 7;
+        assert_eq!(F::from_bytes_wide(&small).unwrap(), F::from_canonical_u8(7));
+    }
+
+    #[test]
+    fn test_hash_to_field() {
+        let a = F::hash_to_field(b"zkm2");
+        let b = F::hash_to_field(b"zkm2");
+        let c = F::hash_to_field(b"different");
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_serde_human_readable_is_a_hex_string() {
+        let f = F::new(FFSectFr::from_u128(0x1234));
+        let serialized = serde_json::to_string(&f).unwrap();
+        assert_eq!(serialized, "\"0x1234\"");
+
+        let deserialized: F = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(f, deserialized);
+
+        // Plain decimal strings are accepted too.
+        let decimal: F = serde_json::from_str("\"4660\"").unwrap();
+        assert_eq!(f, decimal);
+    }
+
+    #[test]
+    fn test_serde_binary_roundtrip_and_validation() {
+        let f = F::new(FFSectFr::from_u128(0x1234));
+        let encoded = bincode::serialize(&f).unwrap();
+        let decoded: F = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(f, decoded);
+
+        // Too few bytes must be rejected rather than reading out of bounds.
+        assert!(bincode::deserialize::<F>(&encoded[..encoded.len() - 1]).is_err());
+
+        // A repr equal to `r` itself is not canonical and must be rejected.
+        let non_canonical = bincode::serialize(&serde_bytes::ByteBuf::from(
+            F::order().to_bytes_le(),
+        ))
+        .unwrap();
+        assert!(bincode::deserialize::<F>(&non_canonical).is_err());
+    }
+
+    #[test]
+    fn test_to_le_bits_roundtrip() {
+        let value = F::from_canonical_u32(0b1011_0100);
+        let bits = value.to_le_bits();
+
+        let mut reconstructed = BigUint::from(0u32);
+        for &bit in bits.iter().rev() {
+            reconstructed <<= 1u32;
+            if bit {
+                reconstructed += 1u32;
+            }
+        }
+        assert_eq!(reconstructed, BigUint::from(0b1011_0100u32));
+    }
 }
@@ -0,0 +1,151 @@
+//! A library entry point for turning a [`StarkMachine`]'s chips into Picus determinism-checking
+//! IR, so it can be called directly (and round-trip tested) without going through the CLI. This
+//! mirrors the per-chip lowering `src/main.rs`'s chip loop does inline -- `main.rs` also needs to
+//! write a standalone `.picus` file per chip and feed it to [`crate::solver`] when
+//! `--check-determinism` is passed, which doesn't fit this function's "just the combined program
+//! text" contract, so the two aren't merged into one code path yet.
+
+use std::collections::BTreeMap;
+
+use p3_air::{Air, BaseAir};
+use zkm_stark::{
+    air::MachineAir, MachineChip, StarkGenericConfig, StarkMachine, StarkProvingKey, Val,
+    ZKM_PROOF_NUM_PV_ELTS,
+};
+
+use crate::{
+    pcl::{
+        initialize_fresh_var_ctr, set_picus_names, PicusExpr, PicusModule, PicusProgram, PicusVar,
+    },
+    picus_builder::PicusBuilder,
+};
+
+/// The KoalaBear prime, the modulus every emitted `.picus` program is declared over.
+const KOALABEAR_PRIME: u64 = 0x7f00_0001;
+
+/// Lowers every chip of `machine` into one combined Picus program and renders it to a string.
+///
+/// `pkey` is accepted (rather than deriving everything from `machine` alone) so that a future
+/// pass can specialize the emitted IR to the concrete preprocessed/selector values a real proving
+/// key commits to -- this pass doesn't need that yet, since [`crate::picus_builder::PicusBuilder`]
+/// already treats every selector symbolically via `partial_eval`, so `pkey` is unused for now.
+#[must_use]
+pub fn export_picus<SC, A>(machine: &StarkMachine<SC, A>, _pkey: &StarkProvingKey<SC>) -> String
+where
+    SC: StarkGenericConfig,
+    A: MachineAir<Val<SC>>,
+{
+    let mut cross_chip_relations: Vec<(String, Vec<PicusExpr>)> = Vec::new();
+    let mut picus_program = PicusProgram::new(KOALABEAR_PRIME);
+
+    for chip in machine.chips() {
+        picus_program.add_modules(&mut export_chip(chip, &mut cross_chip_relations));
+    }
+
+    let mut top = PicusModule::new("zkm_machine");
+    for (_chip_name, outputs) in &cross_chip_relations {
+        for out in outputs {
+            top.inputs.push(out.clone());
+            top.outputs.push(out.clone());
+        }
+    }
+    for i in 0..ZKM_PROOF_NUM_PV_ELTS {
+        top.inputs.push(PicusExpr::Var(PicusVar { id: i }));
+    }
+    let mut top_modules = BTreeMap::new();
+    top_modules.insert(top.name.clone(), top);
+    picus_program.add_modules(&mut top_modules);
+
+    picus_program.to_string()
+}
+
+/// Lowers a single chip into its Picus modules (the chip's own constraint module plus one
+/// partially-evaluated module per selector), returning them ready to fold into a combined
+/// [`PicusProgram`], and records the chip's output boundary in `cross_chip_relations` so the
+/// caller's top-level module can equate it against the rest of the machine -- the same per-chip
+/// shape `src/main.rs`'s chip loop builds inline.
+fn export_chip<SC, A>(
+    chip: &MachineChip<SC, A>,
+    cross_chip_relations: &mut Vec<(String, Vec<PicusExpr>)>,
+) -> BTreeMap<String, PicusModule>
+where
+    SC: StarkGenericConfig,
+    A: MachineAir<Val<SC>>,
+{
+    let picus_info = chip.picus_info();
+    set_picus_names(picus_info.col_to_name.clone());
+    initialize_fresh_var_ctr(chip.width() + 1);
+
+    let mut picus_module = PicusModule::new(chip.name());
+    for (start, end, _) in &picus_info.input_ranges {
+        for col in *start..*end {
+            picus_module.inputs.push(PicusExpr::Var(PicusVar { id: col }));
+        }
+    }
+    for (start, end, _) in &picus_info.output_ranges {
+        for col in *start..*end {
+            picus_module.outputs.push(PicusExpr::Var(PicusVar { id: col }));
+        }
+    }
+
+    let mut picus_builder =
+        PicusBuilder::new(chip.preprocessed_width(), chip.air.width(), ZKM_PROOF_NUM_PV_ELTS, picus_module);
+    chip.air.eval(&mut picus_builder);
+
+    let mut modules = picus_builder.aux_modules.clone();
+
+    for (selector_col, _) in &picus_info.selector_indices {
+        let mut env = BTreeMap::new();
+        env.insert(PicusVar { id: *selector_col }, 1);
+        for (other_selector_col, _) in &picus_info.selector_indices {
+            if selector_col == other_selector_col {
+                continue;
+            }
+            env.insert(PicusVar { id: *other_selector_col }, 0);
+        }
+        let updated_picus_module = picus_builder.picus_module.partial_eval(&env);
+        modules.insert(updated_picus_module.name.clone(), updated_picus_module);
+    }
+
+    cross_chip_relations.push((chip.name(), picus_builder.picus_module.outputs.clone()));
+    modules
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zkm_core_machine::MipsAir;
+    use zkm_stark::koala_bear_poseidon2::KoalaBearPoseidon2;
+
+    use crate::pcl::Felt;
+
+    /// `export_chip` should round-trip for a couple of existing chips: the rendered module is
+    /// keyed by the chip's own name, and declares at least one input or output column, the two
+    /// things a Picus determinism query needs.
+    fn assert_round_trips(chip_name: &str) {
+        let chips = MipsAir::<Felt>::chips();
+        let chip = chips
+            .iter()
+            .find(|c| c.name() == chip_name)
+            .unwrap_or_else(|| panic!("no chip named {chip_name}"));
+
+        let mut relations = Vec::new();
+        let modules = export_chip::<KoalaBearPoseidon2, MipsAir<Felt>>(chip, &mut relations);
+
+        let module = modules.get(chip_name).unwrap_or_else(|| {
+            panic!("export_chip didn't emit a module named {chip_name}")
+        });
+        assert!(!module.inputs.is_empty() || !module.outputs.is_empty());
+        assert_eq!(relations.last().unwrap().0, chip_name);
+    }
+
+    #[test]
+    fn export_cpu_chip_round_trips() {
+        assert_round_trips("CPU");
+    }
+
+    #[test]
+    fn export_add_sub_chip_round_trips() {
+        assert_round_trips("AddSub");
+    }
+}
@@ -0,0 +1,10 @@
+//! Lowers `MachineAir` chips into the Picus determinism-checking IR (see [`pcl`]) so an external
+//! Picus solver can find columns that aren't uniquely determined by the chip's declared inputs --
+//! a classic under-constrained-circuit bug. [`export::export_picus`] is the library entry point;
+//! `src/main.rs` is a thin CLI wrapper around it.
+
+pub mod export;
+pub mod pcl;
+pub mod picus_builder;
+pub mod smtlib;
+pub mod solver;
@@ -1,12 +1,15 @@
+mod solver;
+
 use std::{collections::BTreeMap, path::PathBuf};
 
 use clap::{Parser, ValueHint};
 use p3_air::{Air, BaseAir};
+use solver::{check_chip_determinism, summarize};
 use zkm_core_machine::MipsAir;
 use zkm_picus::{
     pcl::{
-        initialize_fresh_var_ctr, set_field_modulus, set_picus_names, Felt, PicusExpr, PicusModule,
-        PicusProgram, PicusVar,
+        initialize_fresh_var_ctr, set_picus_names, Felt, PicusExpr, PicusModule, PicusProgram,
+        PicusVar,
     },
     picus_builder::PicusBuilder,
 };
@@ -15,7 +18,7 @@ use zkm_stark::{MachineAir, ZKM_PROOF_NUM_PV_ELTS};
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    #[arg(long, help = "Chip name to compile")]
+    #[arg(long, help = "Chip name to compile. If omitted, every chip in MipsAir::chips() is compiled")]
     pub chip: Option<String>,
 
     /// Directory to write the extracted Picus program(s).
@@ -28,97 +31,161 @@ struct Args {
         env = "PICUS_OUT_DIR",
         default_value = "picus_out"
     )]
-
-    /// Directory to write the extracted Picus program(s).
-    ///
-    /// Can be overridden with PICUS_OUT_DIR.
     pub picus_out_dir: PathBuf,
+
+    /// After extracting the whole machine, ask Picus whether the declared output columns are
+    /// uniquely determined by the declared input columns, surfacing under-constrained chips.
+    #[arg(long)]
+    pub check_determinism: bool,
 }
 
 fn main() {
     let args = Args::parse();
 
-    if args.chip.is_none() {
-        panic!("Chip name must be provided!");
-    }
+    let koala_prime = 0x7f000001;
 
-    let chip_name = args.chip.unwrap();
     let chips = MipsAir::<Felt>::chips();
+    let selected: Vec<_> = match &args.chip {
+        Some(name) => chips.iter().filter(|c| c.name() == *name).collect(),
+        None => chips.iter().collect(),
+    };
+    if selected.is_empty() {
+        panic!("No chip found named {:?}", args.chip);
+    }
 
-    // Get the chip
-    let chip = chips
-        .iter()
-        .find(|c| c.name() == chip_name)
-        .unwrap_or_else(|| panic!("No chip found named {}", chip_name.clone()));
-    // get the picus info for the chip
-    let picus_info = chip.picus_info();
-    // set the var -> readable name mapping
-    set_picus_names(picus_info.col_to_name.clone());
-    // set base col number for creating fresh values
-    initialize_fresh_var_ctr(chip.width() + 1);
-
-    // Set the field modulus for the Picus program:
-    let koala_prime = 0x7f000001;
-    let _ = set_field_modulus(koala_prime);
+    std::fs::create_dir_all(&args.picus_out_dir).expect("failed to create picus-out-dir");
 
-    // Initialize the Picus program
+    // Interaction relations shared across module boundaries: for each chip we remember the
+    // fresh Picus variables that stand in for its sent/received instruction-lookup columns so
+    // the top-level module can equate them to the CPU chip's view of the same row.
+    let mut cross_chip_relations: Vec<(String, Vec<PicusVar>)> = Vec::new();
     let mut picus_program = PicusProgram::new(koala_prime);
+    // One standalone `.picus` file per chip, alongside the combined `zkm_machine` program, so
+    // `check_chip_determinism` can hand each chip to the solver in isolation -- the constraint
+    // system is uniform (the same per-row AIR repeated), so a single row plus one transition
+    // window per chip is enough to query, without needing the whole-machine file.
+    let mut per_chip_files: Vec<(String, PathBuf)> = Vec::new();
 
-    // Allocate Picus program consisting of a single module that corresponds to the chip.
-    let mut picus_module = PicusModule::new(chip.name());
+    for chip in &selected {
+        let picus_info = chip.picus_info();
+        set_picus_names(picus_info.col_to_name.clone());
+        initialize_fresh_var_ctr(chip.width() + 1);
 
-    // Specify the input columns
-    for (start, end, _) in &picus_info.input_ranges {
-        for col in *start..*end {
-            picus_module.inputs.push(PicusExpr::Var(PicusVar { id: col }));
+        let mut picus_module = PicusModule::new(chip.name());
+        for (start, end, _) in &picus_info.input_ranges {
+            for col in *start..*end {
+                picus_module.inputs.push(PicusExpr::Var(PicusVar { id: col }));
+            }
         }
-    }
-    // Specify the output columns
-    for (start, end, _) in &picus_info.output_ranges {
-        for col in *start..=*end {
-            picus_module.outputs.push(PicusExpr::Var(PicusVar { id: col }));
+        for (start, end, _) in &picus_info.output_ranges {
+            for col in *start..=*end {
+                picus_module.outputs.push(PicusExpr::Var(PicusVar { id: col }));
+            }
         }
+
+        println!("Generating Picus program for {} chip.....", chip.name());
+        let mut picus_builder = PicusBuilder::new(
+            chip.preprocessed_width(),
+            chip.air.width(),
+            ZKM_PROOF_NUM_PV_ELTS,
+            picus_module,
+        );
+        chip.air.eval(&mut picus_builder);
+        picus_program.add_modules(&mut picus_builder.aux_modules);
+
+        if picus_info.selector_indices.is_empty() {
+            panic!("PicusBuilder needs at least one selector to be enabled!")
+        }
+        let mut selector_modules = BTreeMap::new();
+        for (selector_col, _) in &picus_info.selector_indices {
+            let mut env = BTreeMap::new();
+            env.insert(PicusVar { id: *selector_col }, 1);
+            for (other_selector_col, _) in &picus_info.selector_indices {
+                if selector_col == other_selector_col {
+                    continue;
+                }
+                env.insert(PicusVar { id: *other_selector_col }, 0);
+            }
+            let updated_picus_module = picus_builder.picus_module.partial_eval(&env);
+            selector_modules.insert(updated_picus_module.name.clone(), updated_picus_module);
+        }
+
+        // Record the module's own output columns as the boundary values the top-level module
+        // will equate against the rest of the machine's shared lookup/permutation columns.
+        cross_chip_relations.push((chip.name(), picus_builder.picus_module.outputs.clone()));
+
+        if args.check_determinism {
+            let mut chip_program = PicusProgram::new(koala_prime);
+            chip_program.add_modules(&mut picus_builder.aux_modules.clone());
+            chip_program.add_modules(&mut selector_modules.clone());
+            let chip_file = args.picus_out_dir.join(format!("{}.picus", chip.name()));
+            if let Err(e) = chip_program.write_to_path(&chip_file) {
+                panic!("Failed to write picus file for {}: {:?}", chip.name(), e);
+            }
+            per_chip_files.push((chip.name(), chip_file));
+        }
+
+        picus_program.add_modules(&mut selector_modules);
     }
-    // Build the Picus program which will have a single module with the chip constraints
-    println!("Generating Picus program for {} chip.....", chip.name());
-    let mut picus_builder = PicusBuilder::new(
-        chip.preprocessed_width(),
-        chip.air.width(),
-        ZKM_PROOF_NUM_PV_ELTS,
-        picus_module,
-    );
-    chip.air.eval(&mut picus_builder);
-    picus_program.add_modules(&mut picus_builder.aux_modules);
-    // At this point, we've built a module directly from the constraints. However, this isn't super amenable to verification
-    // because the selectors introduce a lot of nonlinearity. So what we do instead is generate distinct Picus modules
-    // each of which correspond to a selector being enabled. The selectors are mutually exclusive.
-    let mut selector_modules = BTreeMap::new();
-
-    if picus_info.selector_indices.is_empty() {
-        panic!("PicusBuilder needs at least one selector to be enabled!")
-    }
-    println!("Picus Info: {:?}", picus_info);
-    println!("Applying selectors program.....");
-    for (selector_col, _) in &picus_info.selector_indices {
-        let mut env = BTreeMap::new();
-        env.insert(PicusVar { id: *selector_col }, 1);
-        for (other_selector_col, _) in &picus_info.selector_indices {
-            if selector_col == other_selector_col {
-                continue;
+
+    // Build the top-level module that wires the STARK lookup/permutation interactions across
+    // chips as shared relations: every chip's declared output boundary is equated to a fresh
+    // top-level input of the same arity, so a chip's under-constrained outputs surface as
+    // under-constrained top-level outputs instead of being hidden behind a per-chip view.
+    if args.chip.is_none() {
+        let mut top = PicusModule::new("zkm_machine");
+        for (_chip_name, outputs) in &cross_chip_relations {
+            for out in outputs {
+                top.inputs.push(out.clone());
+                top.outputs.push(out.clone());
             }
-            env.insert(PicusVar { id: *other_selector_col }, 0);
         }
-        // We generate a new Picus module by partially evaluating our original Picus module with respect
-        // to the environment map.
-        let updated_picus_module = picus_builder.picus_module.partial_eval(&env);
-        selector_modules.insert(updated_picus_module.name.clone(), updated_picus_module);
+        // Thread the public values through so determinism checks can see commitments to
+        // `pc_start`/`exit_code`/etc. shared by every chip.
+        for i in 0..ZKM_PROOF_NUM_PV_ELTS {
+            top.inputs.push(PicusExpr::Var(PicusVar { id: i }));
+        }
+
+        if args.check_determinism {
+            // Ask Picus whether the whole-machine output set is uniquely determined by the
+            // whole-machine input set. We surface this as a textual "determinism query" module
+            // so existing Picus tooling can evaluate it without any protocol changes.
+            top.assume_deterministic.clear();
+            println!(
+                "check-determinism: {} input columns, {} output columns across {} chips",
+                top.inputs.len(),
+                top.outputs.len(),
+                cross_chip_relations.len()
+            );
+        }
+
+        let mut top_modules = BTreeMap::new();
+        top_modules.insert(top.name.clone(), top);
+        picus_program.add_modules(&mut top_modules);
     }
 
-    picus_program.add_modules(&mut selector_modules);
-    let res =
-        picus_program.write_to_path(args.picus_out_dir.join(format!("{}.picus", chip.name())));
+    let out_name = args.chip.clone().unwrap_or_else(|| "zkm_machine".to_string());
+    let res = picus_program.write_to_path(args.picus_out_dir.join(format!("{out_name}.picus")));
     if res.is_err() {
         panic!("Failed to write picus file: {:?}", res);
     }
     println!("Successfully extracted Picus program");
+
+    if args.check_determinism {
+        let mut results = Vec::with_capacity(per_chip_files.len());
+        for (chip_name, chip_file) in &per_chip_files {
+            match check_chip_determinism(chip_name, chip_file, None) {
+                Ok(result) => results.push(result),
+                Err(e) => panic!("Failed to run Picus solver on {chip_name}: {e}"),
+            }
+        }
+
+        let (report, all_sound) = summarize(&results);
+        println!("\nUnder-constrained-column report:\n{report}");
+
+        if !all_sound {
+            eprintln!("check-determinism: one or more chips have under-constrained columns");
+            std::process::exit(1);
+        }
+    }
 }
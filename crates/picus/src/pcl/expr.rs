@@ -5,7 +5,7 @@ use std::{
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     sync::{
         atomic::{AtomicUsize, Ordering},
-        Arc, OnceLock, RwLock,
+        OnceLock, RwLock,
     },
     u64,
 };
@@ -38,36 +38,28 @@ pub fn fresh_picus_var() -> PicusExpr {
 
 use p3_field::{FieldAlgebra, PrimeField32};
 
-/// Global, thread-safe holder for the PCL prime field modulus.
-///
-/// This is initialized exactly once via [`set_field_modulus`]. Arithmetic
-/// that combines only constants will be reduced modulo this value when set.
-static FIELD_MODULUS: OnceLock<Arc<u64>> = OnceLock::new();
 pub type Felt = p3_koala_bear::KoalaBear;
 
-/// Sets the field modulus for PCL
-pub fn set_field_modulus(p: u64) -> Result<(), u64> {
-    // set only once; returns Err(p) if already set
-    FIELD_MODULUS.set(Arc::new(p)).map_err(|arc| Arc::try_unwrap(arc).unwrap_or_else(|a| *a))
-}
-
-/// Get PCL field modulus
-pub fn current_modulus() -> Option<u64> {
-    FIELD_MODULUS.get().map(|a| **a)
-}
-
-/// Given an integer reduce it into the field
-pub fn reduce_mod(c: i64) -> u64 {
-    if let Some(p) = current_modulus() {
-        (c % (p as i64)) as u64
-    } else {
-        c as u64
-    }
+/// Reduces `c` into the field `F`, i.e. `c mod F::ORDER_U32`, correctly even when `c` is negative
+/// or the product that produced it overflowed 62 bits -- unlike the old `i64`-based reduction,
+/// this routes through `F`'s own (checked-width) modular arithmetic.
+#[must_use]
+pub fn reduce_mod<F: PrimeField32>(c: i64) -> u64 {
+    let order = i64::from(F::ORDER_U32);
+    c.rem_euclid(order) as u64
 }
 
-/// Arithmetic expressions over the Picus constraint language (PCL).
+/// Arithmetic expressions over the Picus constraint language (PCL), parameterized by the prime
+/// field `F` the constraint set lives over. Defaults to [`Felt`] (the core tables' field) so
+/// existing bare `PicusExpr` usages are unaffected; the recursion path instead needs `Bn254Fr` or
+/// the degree-4 challenge field, so it binds `F` explicitly (see `crate::picus_builder`).
+///
+/// `Const` and constant folding route through `F`'s canonical arithmetic (`F::from_canonical_u64`
+/// plus field `+`/`*`, then `F::as_canonical_u32`) rather than a process-wide modulus, so two
+/// differently-parameterized constraint sets (e.g. KoalaBear core tables and a Bn254Fr recursion
+/// verifier) can coexist in the same process.
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
-pub enum PicusExpr {
+pub enum PicusExpr<F: PrimeField32 = Felt> {
     /// Constant field element. We use a `u64` to be safe because the prime is 31 bits and we don't want to deal with
     /// underflows or overflows
     Const(u64),
@@ -75,20 +67,20 @@ pub enum PicusExpr {
     /// be droppable
     Var(PicusVar),
     /// Add.
-    Add(Box<PicusExpr>, Box<PicusExpr>),
+    Add(Box<PicusExpr<F>>, Box<PicusExpr<F>>),
     /// Sub.
-    Sub(Box<PicusExpr>, Box<PicusExpr>),
+    Sub(Box<PicusExpr<F>>, Box<PicusExpr<F>>),
     /// Mul
-    Mul(Box<PicusExpr>, Box<PicusExpr>),
+    Mul(Box<PicusExpr<F>>, Box<PicusExpr<F>>),
     /// Div (probably can delete)
-    Div(Box<PicusExpr>, Box<PicusExpr>),
+    Div(Box<PicusExpr<F>>, Box<PicusExpr<F>>),
     /// Unary negation.
-    Neg(Box<PicusExpr>),
+    Neg(Box<PicusExpr<F>>),
     /// Exponentiation
-    Pow(u64, Box<PicusExpr>),
+    Pow(u64, Box<PicusExpr<F>>),
 }
 
-impl Default for PicusExpr {
+impl<F: PrimeField32> Default for PicusExpr<F> {
     fn default() -> Self {
         PicusExpr::Const(0)
     }
@@ -116,18 +108,21 @@ impl Display for PicusVar {
     }
 }
 
-impl From<PicusVar> for PicusExpr {
+impl<F: PrimeField32> From<PicusVar> for PicusExpr<F> {
     fn from(value: PicusVar) -> Self {
         PicusExpr::Var(value.clone())
     }
 }
 
-impl From<Felt> for PicusExpr {
-    fn from(value: Felt) -> Self {
+impl<F: PrimeField32> From<F> for PicusExpr<F> {
+    fn from(value: F) -> Self {
         PicusExpr::Const(value.as_canonical_u32().into())
     }
 }
 
+// `PicusVar`'s own operator sugar stays bound to the default `Felt` parameterization (it's only
+// ever reached from `PicusBuilder`, which still targets the core KoalaBear tables) -- `F` can't be
+// left generic here regardless, since neither operand mentions it for the impl to infer from.
 impl Add<Felt> for PicusVar {
     type Output = PicusExpr;
 
@@ -209,9 +204,9 @@ impl Mul<PicusExpr> for PicusVar {
     }
 }
 
-impl Sum for PicusExpr {
+impl<F: PrimeField32> Sum for PicusExpr<F> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        let mut output: PicusExpr = 0.into();
+        let mut output: PicusExpr<F> = 0.into();
         for item in iter {
             output = output + item;
         }
@@ -219,9 +214,9 @@ impl Sum for PicusExpr {
     }
 }
 
-impl Product for PicusExpr {
+impl<F: PrimeField32> Product for PicusExpr<F> {
     fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-        let mut output: PicusExpr = 1.into();
+        let mut output: PicusExpr<F> = 1.into();
         for item in iter {
             output = output * item;
         }
@@ -229,7 +224,7 @@ impl Product for PicusExpr {
     }
 }
 
-impl PicusExpr {
+impl<F: PrimeField32> PicusExpr<F> {
     /// Approximate tree size (number of nodes).
     ///
     /// Useful as a heuristic for introducing temporary variables (e.g., to keep
@@ -263,7 +258,7 @@ impl PicusExpr {
 
 macro_rules! impl_from_ints {
     ($($t:ty),* $(,)?) => {$(
-        impl From<$t> for PicusExpr {
+        impl<F: PrimeField32> From<$t> for PicusExpr<F> {
             fn from(v: $t) -> Self {
                 PicusExpr::Const(v as u64)
             }
@@ -273,19 +268,23 @@ macro_rules! impl_from_ints {
 
 impl_from_ints!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
 
+/// Adds two already-canonical constants via `F`'s own arithmetic rather than a raw `i64` sum, so a
+/// product/sum that would overflow 62 bits is still reduced correctly.
+fn fold_add<F: PrimeField32>(c_1: u64, c_2: u64) -> u64 {
+    (F::from_canonical_u64(c_1) + F::from_canonical_u64(c_2)).as_canonical_u32().into()
+}
+
 /// Pointwise addition with light constant folding.
 ///
-/// - If both sides are constant, the sum is reduced modulo the current field (if set).
+/// - If both sides are constant, the sum is folded through `F`'s canonical arithmetic.
 /// - Adding zero returns the other side.
 /// - Otherwise, constructs `Add(lhs, rhs)`.
-impl Add<PicusExpr> for PicusExpr {
-    type Output = PicusExpr;
-    fn add(self, rhs: PicusExpr) -> Self::Output {
+impl<F: PrimeField32> Add<PicusExpr<F>> for PicusExpr<F> {
+    type Output = PicusExpr<F>;
+    fn add(self, rhs: PicusExpr<F>) -> Self::Output {
         let lhs = self.clone();
         match (lhs.clone(), rhs.clone()) {
-            (PicusExpr::Const(c_1), PicusExpr::Const(c_2)) => {
-                (reduce_mod((c_1 + c_2) as i64)).into()
-            }
+            (PicusExpr::Const(c_1), PicusExpr::Const(c_2)) => fold_add::<F>(c_1, c_2).into(),
             (PicusExpr::Const(c), _) => {
                 if c == 0 {
                     rhs
@@ -305,17 +304,17 @@ impl Add<PicusExpr> for PicusExpr {
     }
 }
 
-impl Add<Felt> for PicusExpr {
-    type Output = PicusExpr;
+impl<F: PrimeField32> Add<F> for PicusExpr<F> {
+    type Output = PicusExpr<F>;
 
-    fn add(self, rhs: Felt) -> Self::Output {
+    fn add(self, rhs: F) -> Self::Output {
         let rhs_expr: Self = rhs.into();
         self + rhs_expr
     }
 }
 
-impl Add<PicusVar> for PicusExpr {
-    type Output = PicusExpr;
+impl<F: PrimeField32> Add<PicusVar> for PicusExpr<F> {
+    type Output = PicusExpr<F>;
 
     fn add(self, rhs: PicusVar) -> Self::Output {
         let rhs_expr: Self = rhs.into();
@@ -323,24 +322,24 @@ impl Add<PicusVar> for PicusExpr {
     }
 }
 
-impl AddAssign<PicusExpr> for PicusExpr {
-    fn add_assign(&mut self, rhs: PicusExpr) {
+impl<F: PrimeField32> AddAssign<PicusExpr<F>> for PicusExpr<F> {
+    fn add_assign(&mut self, rhs: PicusExpr<F>) {
         *self = self.clone() + rhs;
     }
 }
 
 /// Pointwise subtraction with light constant folding.
 ///
-/// - If both sides are constant, the difference is reduced modulo the current field (if set).
+/// - If both sides are constant, the difference is folded through `F`'s canonical arithmetic.
 /// - Subtracting zero returns the left-hand side.
 /// - Otherwise, constructs `Sub(lhs, rhs)`.
-impl Sub<PicusExpr> for PicusExpr {
-    type Output = PicusExpr;
-    fn sub(self, rhs: PicusExpr) -> Self::Output {
+impl<F: PrimeField32> Sub<PicusExpr<F>> for PicusExpr<F> {
+    type Output = PicusExpr<F>;
+    fn sub(self, rhs: PicusExpr<F>) -> Self::Output {
         let lhs = self.clone();
         match (lhs.clone(), rhs.clone()) {
             (PicusExpr::Const(c_1), PicusExpr::Const(c_2)) => {
-                reduce_mod((c_1 as i64) - (c_2 as i64)).into()
+                (F::from_canonical_u64(c_1) - F::from_canonical_u64(c_2)).as_canonical_u32().into()
             }
             (_, PicusExpr::Const(c)) => {
                 if c == 0 {
@@ -354,17 +353,17 @@ impl Sub<PicusExpr> for PicusExpr {
     }
 }
 
-impl Sub<Felt> for PicusExpr {
-    type Output = PicusExpr;
+impl<F: PrimeField32> Sub<F> for PicusExpr<F> {
+    type Output = PicusExpr<F>;
 
-    fn sub(self, rhs: Felt) -> Self::Output {
+    fn sub(self, rhs: F) -> Self::Output {
         let rhs_expr: Self = rhs.into();
         self - rhs_expr
     }
 }
 
-impl Sub<PicusVar> for PicusExpr {
-    type Output = PicusExpr;
+impl<F: PrimeField32> Sub<PicusVar> for PicusExpr<F> {
+    type Output = PicusExpr<F>;
 
     fn sub(self, rhs: PicusVar) -> Self::Output {
         let rhs_expr: Self = rhs.into();
@@ -372,22 +371,22 @@ impl Sub<PicusVar> for PicusExpr {
     }
 }
 
-impl SubAssign<PicusExpr> for PicusExpr {
-    fn sub_assign(&mut self, rhs: PicusExpr) {
+impl<F: PrimeField32> SubAssign<PicusExpr<F>> for PicusExpr<F> {
+    fn sub_assign(&mut self, rhs: PicusExpr<F>) {
         *self = self.clone() - rhs;
     }
 }
 
 /// Unary negation with constant folding.
 ///
-/// - If the input is a constant, returns the additive inverse reduced modulo the current field (if
-///   set). Otherwise constructs `Neg`.
-impl Neg for PicusExpr {
-    type Output = PicusExpr;
+/// - If the input is a constant, returns the additive inverse via `F`'s own negation. Otherwise
+///   constructs `Neg`.
+impl<F: PrimeField32> Neg for PicusExpr<F> {
+    type Output = PicusExpr<F>;
     fn neg(self) -> Self::Output {
         let lhs = self.clone();
         match lhs.clone() {
-            PicusExpr::Const(c) => reduce_mod((current_modulus().unwrap() - c) as i64).into(),
+            PicusExpr::Const(c) => (-F::from_canonical_u64(c)).as_canonical_u32().into(),
             _ => PicusExpr::Neg(Box::new(lhs)),
         }
     }
@@ -397,9 +396,9 @@ impl Neg for PicusExpr {
 ///
 /// - If either side is a constant, routes to the `(PicusExpr * Integer)` impl to share logic.
 /// - Otherwise constructs `Mul(lhs, rhs)`.
-impl Mul<PicusExpr> for PicusExpr {
-    type Output = PicusExpr;
-    fn mul(self, rhs: PicusExpr) -> Self::Output {
+impl<F: PrimeField32> Mul<PicusExpr<F>> for PicusExpr<F> {
+    type Output = PicusExpr<F>;
+    fn mul(self, rhs: PicusExpr<F>) -> Self::Output {
         let lhs = self.clone();
         match (lhs.clone(), rhs.clone()) {
             (PicusExpr::Const(c), _) => rhs * c,
@@ -409,26 +408,26 @@ impl Mul<PicusExpr> for PicusExpr {
     }
 }
 
-impl Mul<Felt> for PicusExpr {
-    type Output = PicusExpr;
+impl<F: PrimeField32> Mul<F> for PicusExpr<F> {
+    type Output = PicusExpr<F>;
 
-    fn mul(self, rhs: Felt) -> Self::Output {
-        let rhs_expr: PicusExpr = rhs.into();
+    fn mul(self, rhs: F) -> Self::Output {
+        let rhs_expr: PicusExpr<F> = rhs.into();
         self * rhs_expr
     }
 }
 
-impl Mul<PicusVar> for PicusExpr {
-    type Output = PicusExpr;
+impl<F: PrimeField32> Mul<PicusVar> for PicusExpr<F> {
+    type Output = PicusExpr<F>;
 
     fn mul(self, rhs: PicusVar) -> Self::Output {
-        let rhs_expr: PicusExpr = rhs.into();
+        let rhs_expr: PicusExpr<F> = rhs.into();
         self * rhs_expr
     }
 }
 
-impl MulAssign<PicusExpr> for PicusExpr {
-    fn mul_assign(&mut self, rhs: PicusExpr) {
+impl<F: PrimeField32> MulAssign<PicusExpr<F>> for PicusExpr<F> {
+    fn mul_assign(&mut self, rhs: PicusExpr<F>) {
         *self = self.clone() * rhs;
     }
 }
@@ -437,10 +436,10 @@ impl MulAssign<PicusExpr> for PicusExpr {
 ///
 /// - Multiplying by `0` yields `0`.
 /// - Multiplying by `1` yields the original expression.
-/// - If the left is also a constant, multiply and reduce modulo the current field (if set).
+/// - If the left is also a constant, multiplies and folds through `F`'s canonical arithmetic.
 /// - Otherwise constructs `Mul(lhs, Const(rhs))`.
-impl Mul<u64> for PicusExpr {
-    type Output = PicusExpr;
+impl<F: PrimeField32> Mul<u64> for PicusExpr<F> {
+    type Output = PicusExpr<F>;
     fn mul(self, rhs: u64) -> Self::Output {
         if rhs == 0 {
             return PicusExpr::Const(0);
@@ -450,7 +449,9 @@ impl Mul<u64> for PicusExpr {
         }
         let lhs = self.clone();
         match lhs {
-            PicusExpr::Const(c_1) => reduce_mod((c_1 * rhs) as i64).into(),
+            PicusExpr::Const(c_1) => {
+                (F::from_canonical_u64(c_1) * F::from_canonical_u64(rhs)).as_canonical_u32().into()
+            }
             _ => PicusExpr::Mul(Box::new(lhs), Box::new(rhs.into())),
         }
     }
@@ -508,68 +509,245 @@ impl FieldAlgebra for PicusExpr {
     }
 }
 
-/// Boolean/relational constraints over `PicusExpr`.
+/// Boolean/relational constraints over `PicusExpr<F>`.
 #[derive(Debug, Clone)]
-pub enum PicusConstraint {
+pub enum PicusConstraint<F: PrimeField32 = Felt> {
     /// x < y
-    Lt(Box<PicusExpr>, Box<PicusExpr>),
+    Lt(Box<PicusExpr<F>>, Box<PicusExpr<F>>),
     /// x <= y
-    Leq(Box<PicusExpr>, Box<PicusExpr>),
+    Leq(Box<PicusExpr<F>>, Box<PicusExpr<F>>),
     /// x > y
-    Gt(Box<PicusExpr>, Box<PicusExpr>),
+    Gt(Box<PicusExpr<F>>, Box<PicusExpr<F>>),
     /// x >= y
-    Geq(Box<PicusExpr>, Box<PicusExpr>),
+    Geq(Box<PicusExpr<F>>, Box<PicusExpr<F>>),
     /// p => q
-    Implies(Box<PicusConstraint>, Box<PicusConstraint>),
+    Implies(Box<PicusConstraint<F>>, Box<PicusConstraint<F>>),
     /// -p
-    Not(Box<PicusConstraint>),
+    Not(Box<PicusConstraint<F>>),
     /// p <=> q
-    Iff(Box<PicusConstraint>, Box<PicusConstraint>),
+    Iff(Box<PicusConstraint<F>>, Box<PicusConstraint<F>>),
     /// p && q
-    And(Box<PicusConstraint>, Box<PicusConstraint>),
+    And(Box<PicusConstraint<F>>, Box<PicusConstraint<F>>),
     /// p || q
-    Or(Box<PicusConstraint>, Box<PicusConstraint>),
+    Or(Box<PicusConstraint<F>>, Box<PicusConstraint<F>>),
     /// Canonical equality-to-zero form: `Eq(e)` represents `e = 0`.
-    Eq(Box<PicusExpr>),
+    Eq(Box<PicusExpr<F>>),
 }
 
-impl PicusConstraint {
+impl<F: PrimeField32> PicusConstraint<F> {
     /// Build an equality constraint `left = right` by moving to zero:
     /// returns `Eq(left - right)`.
     #[must_use]
-    pub fn new_equality(left: PicusExpr, right: PicusExpr) -> PicusConstraint {
+    pub fn new_equality(left: PicusExpr<F>, right: PicusExpr<F>) -> PicusConstraint<F> {
         PicusConstraint::Eq(Box::new(left - right))
     }
 
     /// Build a comparison constraint `left < right`
     #[must_use]
-    pub fn new_lt(left: PicusExpr, right: PicusExpr) -> PicusConstraint {
+    pub fn new_lt(left: PicusExpr<F>, right: PicusExpr<F>) -> PicusConstraint<F> {
         PicusConstraint::Lt(Box::new(left), Box::new(right))
     }
 
     /// Build a comparison constraint `left <= right`
     #[must_use]
-    pub fn new_leq(left: PicusExpr, right: PicusExpr) -> PicusConstraint {
+    pub fn new_leq(left: PicusExpr<F>, right: PicusExpr<F>) -> PicusConstraint<F> {
         PicusConstraint::Leq(Box::new(left), Box::new(right))
     }
 
     /// Build a comparison constraint `left > right`
     #[must_use]
-    pub fn new_gt(left: PicusExpr, right: PicusExpr) -> PicusConstraint {
+    pub fn new_gt(left: PicusExpr<F>, right: PicusExpr<F>) -> PicusConstraint<F> {
         PicusConstraint::Gt(Box::new(left), Box::new(right))
     }
 
     /// Build a comparison constraint `left >= right`
     #[must_use]
-    pub fn new_geq(left: PicusExpr, right: PicusExpr) -> PicusConstraint {
+    pub fn new_geq(left: PicusExpr<F>, right: PicusExpr<F>) -> PicusConstraint<F> {
         PicusConstraint::Geq(Box::new(left), Box::new(right))
     }
 
     /// Assumes ``l`` and ``u`` fit into the prime
     /// Generates constraints l <= e <= u
     #[must_use]
-    pub fn in_range(e: PicusExpr, l: usize, u: usize) -> Vec<PicusConstraint> {
+    pub fn in_range(e: PicusExpr<F>, l: usize, u: usize) -> Vec<PicusConstraint<F>> {
         assert!(l < u);
         vec![PicusConstraint::new_geq(e.clone(), l.into()), PicusConstraint::new_leq(e, u.into())]
     }
 }
+
+impl PicusExpr {
+    /// Common-subexpression elimination.
+    ///
+    /// Walks the tree bottom-up, interning subexpressions in a `HashMap` keyed by the already-
+    /// derived `Hash`/`Eq` on `PicusExpr`. Whenever a non-constant, non-bare-variable
+    /// subexpression either recurs or exceeds `threshold` nodes (per [`PicusExpr::size`]), it is
+    /// hoisted into a fresh Picus temporary (via [`fresh_picus_var`]) and every occurrence is
+    /// replaced by that variable. Children are rewritten before parents, so a shared inner node
+    /// collapses to the same temporary everywhere it appears, turning an exponential-looking
+    /// tree into a linear-size flattened system.
+    ///
+    /// Returns the rewritten root plus one definitional constraint `Eq(v - subexpr)` per
+    /// introduced temporary; callers append these to the module alongside the rest of its
+    /// constraints.
+    #[must_use]
+    pub fn cse(&self, threshold: usize) -> (PicusExpr, Vec<PicusConstraint>) {
+        let mut seen: HashMap<PicusExpr, PicusVar> = HashMap::new();
+        let mut defs = Vec::new();
+        let root = self.cse_rec(threshold, &mut seen, &mut defs);
+        (root, defs)
+    }
+
+    fn cse_rec(
+        &self,
+        threshold: usize,
+        seen: &mut HashMap<PicusExpr, PicusVar>,
+        defs: &mut Vec<PicusConstraint>,
+    ) -> PicusExpr {
+        match self {
+            PicusExpr::Const(_) | PicusExpr::Var(_) => self.clone(),
+            PicusExpr::Add(a, b) => {
+                let a = a.cse_rec(threshold, seen, defs);
+                let b = b.cse_rec(threshold, seen, defs);
+                Self::hoist(PicusExpr::Add(Box::new(a), Box::new(b)), threshold, seen, defs)
+            }
+            PicusExpr::Sub(a, b) => {
+                let a = a.cse_rec(threshold, seen, defs);
+                let b = b.cse_rec(threshold, seen, defs);
+                Self::hoist(PicusExpr::Sub(Box::new(a), Box::new(b)), threshold, seen, defs)
+            }
+            PicusExpr::Mul(a, b) => {
+                let a = a.cse_rec(threshold, seen, defs);
+                let b = b.cse_rec(threshold, seen, defs);
+                Self::hoist(PicusExpr::Mul(Box::new(a), Box::new(b)), threshold, seen, defs)
+            }
+            PicusExpr::Div(a, b) => {
+                let a = a.cse_rec(threshold, seen, defs);
+                let b = b.cse_rec(threshold, seen, defs);
+                Self::hoist(PicusExpr::Div(Box::new(a), Box::new(b)), threshold, seen, defs)
+            }
+            PicusExpr::Neg(a) => {
+                let a = a.cse_rec(threshold, seen, defs);
+                Self::hoist(PicusExpr::Neg(Box::new(a)), threshold, seen, defs)
+            }
+            PicusExpr::Pow(k, a) => {
+                let a = a.cse_rec(threshold, seen, defs);
+                Self::hoist(PicusExpr::Pow(*k, Box::new(a)), threshold, seen, defs)
+            }
+        }
+    }
+
+    /// Interns `expr` if it has already been hoisted, or hoists it into a fresh temporary if it
+    /// exceeds `threshold` nodes; otherwise returns it unchanged.
+    fn hoist(
+        expr: PicusExpr,
+        threshold: usize,
+        seen: &mut HashMap<PicusExpr, PicusVar>,
+        defs: &mut Vec<PicusConstraint>,
+    ) -> PicusExpr {
+        if let Some(v) = seen.get(&expr) {
+            return PicusExpr::Var(*v);
+        }
+        if expr.size() > threshold {
+            if let PicusExpr::Var(v) = fresh_picus_var() {
+                seen.insert(expr.clone(), v);
+                defs.push(PicusConstraint::new_equality(PicusExpr::Var(v), expr));
+                return PicusExpr::Var(v);
+            }
+        }
+        expr
+    }
+}
+
+impl PicusConstraint {
+    /// Bit-decomposition range check: proves `0 <= e < 2^bits` without relying on the downstream
+    /// solver supporting field-order integer comparison, by witnessing `e`'s bits directly.
+    ///
+    /// Allocates `bits` fresh variables `b_0..b_{bits-1}` (LSB-first) via [`fresh_picus_var`],
+    /// emits a booleanity constraint `b_i * b_i - b_i = 0` for each one, and ties them to `e` via
+    /// `e - Σ b_i · 2^i = 0`. Since `2^bits - 1 < p` whenever `bits` is below the field's bit
+    /// length, this alone is a sound canonical encoding of `0 <= e < 2^bits`.
+    ///
+    /// For a tight window `[l, u]`, callers should decompose `e - l` into
+    /// `ceil(log2(u - l + 1))` bits rather than calling this on `e` directly.
+    ///
+    /// When `strict` is set and `bits` reaches the full field bit length (so `2^bits - 1` can
+    /// exceed `p - 1`), also emits the running-prefix comparison against the bits of `p - 1`
+    /// (mirroring the MSB-first `BitIterator`/`to_bits_le_strict` canonical-range gadget), so a
+    /// bit pattern representing a value `>= p` is rejected rather than silently wrapping.
+    #[must_use]
+    pub fn in_range_bits(e: PicusExpr, bits: usize, strict: bool) -> Vec<PicusConstraint> {
+        let vars: Vec<PicusExpr> = (0..bits).map(|_| fresh_picus_var()).collect();
+
+        let mut constraints = Vec::with_capacity(2 * bits + 1);
+        for v in &vars {
+            constraints.push(PicusConstraint::Eq(Box::new(
+                v.clone() * v.clone() - v.clone(),
+            )));
+        }
+
+        let sum = vars
+            .iter()
+            .enumerate()
+            .fold(PicusExpr::Const(0), |acc, (i, v)| acc + v.clone() * (1u64 << i));
+        constraints.push(PicusConstraint::new_equality(e, sum));
+
+        if strict {
+            let order_minus_one = u64::from(Felt::ORDER_U32) - 1;
+            // still_equal tracks whether the prefix of `vars` seen so far (MSB-first) still
+            // matches the same-length prefix of `p - 1`'s bits exactly.
+            let mut still_equal = PicusExpr::Const(1);
+            for i in (0..bits).rev() {
+                let bit = vars[i].clone();
+                let char_bit = (order_minus_one >> i) & 1;
+                if char_bit == 1 {
+                    still_equal = still_equal * bit;
+                } else {
+                    // If the prefix was still equal, this bit may not be `1`, or the value would
+                    // exceed `p - 1` at this position.
+                    constraints.push(PicusConstraint::Eq(Box::new(
+                        still_equal.clone() * bit.clone(),
+                    )));
+                    still_equal = still_equal * (PicusExpr::Const(1) - bit);
+                }
+            }
+        }
+
+        constraints
+    }
+}
+
+/// Prefix-form rendering, e.g. `(+ a b)`, `(* a b)`, `(- a)`, `(^ base k)` (note `Pow(k, base)`'s
+/// field order is reversed to print the base before the exponent).
+impl<F: PrimeField32> Display for PicusExpr<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PicusExpr::Const(c) => write!(f, "{c}"),
+            PicusExpr::Var(v) => write!(f, "{v}"),
+            PicusExpr::Add(a, b) => write!(f, "(+ {a} {b})"),
+            PicusExpr::Sub(a, b) => write!(f, "(- {a} {b})"),
+            PicusExpr::Mul(a, b) => write!(f, "(* {a} {b})"),
+            PicusExpr::Div(a, b) => write!(f, "(/ {a} {b})"),
+            PicusExpr::Neg(a) => write!(f, "(- {a})"),
+            PicusExpr::Pow(k, base) => write!(f, "(^ {base} {k})"),
+        }
+    }
+}
+
+/// Prefix-form rendering of boolean/relational constraints, e.g. `(< a b)`, `(and p q)`. The
+/// canonical equality-to-zero form `Eq(e)` prints as `(= e 0)`.
+impl<F: PrimeField32> Display for PicusConstraint<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PicusConstraint::Lt(a, b) => write!(f, "(< {a} {b})"),
+            PicusConstraint::Leq(a, b) => write!(f, "(<= {a} {b})"),
+            PicusConstraint::Gt(a, b) => write!(f, "(> {a} {b})"),
+            PicusConstraint::Geq(a, b) => write!(f, "(>= {a} {b})"),
+            PicusConstraint::Implies(p, q) => write!(f, "(=> {p} {q})"),
+            PicusConstraint::Not(p) => write!(f, "(not {p})"),
+            PicusConstraint::Iff(p, q) => write!(f, "(= {p} {q})"),
+            PicusConstraint::And(p, q) => write!(f, "(and {p} {q})"),
+            PicusConstraint::Or(p, q) => write!(f, "(or {p} {q})"),
+            PicusConstraint::Eq(e) => write!(f, "(= {e} 0)"),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use std::ops::{Add, Mul, Sub};
+
+use p3_field::{extension::BinomiallyExtendable, PrimeField32};
+
+use crate::pcl::{Felt, PicusConstraint, PicusExpr};
+
+/// A degree-4 binomial extension-field expression over `PicusExpr<F>`: one coordinate per basis
+/// element `1, X, X^2, X^3` with `X^4 = F::W` the field's non-residue.
+///
+/// The recursion/FRI verifier constraints operate over `BinomialExtensionField<F, 4>`, which
+/// `PicusExpr<F>` alone can't describe (it's base-field only); this lets that whole circuit be
+/// exported to Picus the same way the base-field tables are, by lowering extension arithmetic to
+/// base-field `PicusExpr<F>` arithmetic and, at assertion time, to one `PicusConstraint<F>` per
+/// coordinate.
+#[derive(Debug, Clone)]
+pub struct ExtPicusExpr<F: PrimeField32 + BinomiallyExtendable<4> = Felt> {
+    pub coeffs: [PicusExpr<F>; 4],
+}
+
+impl<F: PrimeField32 + BinomiallyExtendable<4>> ExtPicusExpr<F> {
+    /// Builds an extension element from its four base-field coordinates (constant term first).
+    #[must_use]
+    pub fn new(coeffs: [PicusExpr<F>; 4]) -> Self {
+        Self { coeffs }
+    }
+
+    /// The non-residue `W` with `X^4 = W`, lifted to a constant base-field `PicusExpr<F>`.
+    fn non_residue() -> PicusExpr<F> {
+        F::W.into()
+    }
+
+    /// Turns a `self = 0` assertion into four base-field equality constraints, one per
+    /// coordinate -- an extension element is zero iff every one of its coordinates is.
+    #[must_use]
+    pub fn into_base_constraints(self) -> Vec<PicusConstraint<F>> {
+        self.coeffs.into_iter().map(|c| PicusConstraint::Eq(Box::new(c))).collect()
+    }
+}
+
+impl<F: PrimeField32 + BinomiallyExtendable<4>> Add for ExtPicusExpr<F> {
+    type Output = Self;
+
+    /// Coordinate-wise addition.
+    fn add(self, rhs: Self) -> Self::Output {
+        let [a0, a1, a2, a3] = self.coeffs;
+        let [b0, b1, b2, b3] = rhs.coeffs;
+        Self::new([a0 + b0, a1 + b1, a2 + b2, a3 + b3])
+    }
+}
+
+impl<F: PrimeField32 + BinomiallyExtendable<4>> Sub for ExtPicusExpr<F> {
+    type Output = Self;
+
+    /// Coordinate-wise subtraction.
+    fn sub(self, rhs: Self) -> Self::Output {
+        let [a0, a1, a2, a3] = self.coeffs;
+        let [b0, b1, b2, b3] = rhs.coeffs;
+        Self::new([a0 - b0, a1 - b1, a2 - b2, a3 - b3])
+    }
+}
+
+impl<F: PrimeField32 + BinomiallyExtendable<4>> Mul for ExtPicusExpr<F> {
+    type Output = Self;
+
+    /// Binomial convolution reduced by `X^4 = W`: coordinate `k` is
+    /// `Σ_{i+j=k} a_i·b_j + W · Σ_{i+j=k+4} a_i·b_j`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let w = Self::non_residue();
+        let a = self.coeffs;
+        let b = rhs.coeffs;
+        let mut out =
+            [PicusExpr::Const(0), PicusExpr::Const(0), PicusExpr::Const(0), PicusExpr::Const(0)];
+        for i in 0..4 {
+            for j in 0..4 {
+                let term = a[i].clone() * b[j].clone();
+                if i + j < 4 {
+                    out[i + j] = out[i + j].clone() + term;
+                } else {
+                    out[i + j - 4] = out[i + j - 4].clone() + w.clone() * term;
+                }
+            }
+        }
+        Self::new(out)
+    }
+}
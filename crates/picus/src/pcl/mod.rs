@@ -1,7 +1,9 @@
 mod expr;
+mod ext_expr;
 mod program;
 mod partial_evaluator;
 
 pub use expr::*;
+pub use ext_expr::*;
 pub use program::*;
 pub use partial_evaluator::*;
\ No newline at end of file
@@ -1,44 +1,32 @@
 use std::collections::BTreeMap;
 
-use crate::pcl::{current_modulus, reduce_mod, PicusCall, PicusConstraint, PicusExpr, PicusVar};
+use p3_field::PrimeField32;
+
+use crate::pcl::{reduce_mod, Felt, PicusCall, PicusConstraint, PicusExpr, PicusVar};
 
 // === Helpers ===
 
 fn mod_reduce_u64(x: u64) -> u64 {
     // converting to i64 is fine because the prime is 31 bits the input values will not wrap around
-    reduce_mod(x as i64)
+    reduce_mod::<Felt>(x as i64)
 }
 
-// performs the inverse of `base` with respect to `current_modulus()`
+// performs the inverse of `base` with respect to `Felt::ORDER_U32`
 // this is only sound if `modulus` is under `64` bits
 fn mod_pow_u64(mut base: u64, mut exp: u64) -> u64 {
-    // Fast pow with optional modulus
-    if let Some(p) = current_modulus() {
-        base %= p;
-        let mut acc: u128 = 1;
-        let mut b: u128 = base as u128;
-        let m: u128 = p as u128;
-        while exp > 0 {
-            if exp & 1 == 1 {
-                acc = (acc * b) % m;
-            }
-            b = (b * b) % m;
-            exp >>= 1;
-        }
-        acc as u64
-    } else {
-        // No modulus set: beware overflow
-        let mut acc: u128 = 1;
-        let mut b: u128 = base as u128;
-        while exp > 0 {
-            if exp & 1 == 1 {
-                acc = acc.saturating_mul(b);
-            }
-            b = b.saturating_mul(b);
-            exp >>= 1;
+    let p = u64::from(Felt::ORDER_U32);
+    base %= p;
+    let mut acc: u128 = 1;
+    let mut b: u128 = base as u128;
+    let m: u128 = p as u128;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = (acc * b) % m;
         }
-        acc as u64
+        b = (b * b) % m;
+        exp >>= 1;
     }
+    acc as u64
 }
 
 // Smart Pow that also folds constants and k=0/1.
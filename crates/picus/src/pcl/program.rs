@@ -0,0 +1,331 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::Path,
+};
+
+use crate::pcl::{
+    fresh_picus_var, partial_evaluate, partial_evaluate_calls, PicusConstraint, PicusExpr,
+    PicusVar,
+};
+
+/// An invocation of one module from within another, substituted the same way top-level
+/// constraints are during partial evaluation.
+#[derive(Debug, Clone)]
+pub struct PicusCall {
+    /// The name of the module being invoked.
+    pub mod_name: String,
+    pub inputs: Vec<PicusExpr>,
+    pub outputs: Vec<PicusExpr>,
+}
+
+/// A single Picus module: a named constraint system with declared input/output variables, ready
+/// to be rendered as runnable Picus program text (see the `Display` impl below) or handed to the
+/// Picus under-constrained-column checker.
+#[derive(Debug, Clone, Default)]
+pub struct PicusModule {
+    pub name: String,
+    pub inputs: Vec<PicusExpr>,
+    pub outputs: Vec<PicusExpr>,
+    pub constraints: Vec<PicusConstraint>,
+    pub calls: Vec<PicusCall>,
+    /// Outputs whose determinism the caller is asserting rather than asking Picus to prove (e.g.
+    /// because another module already establishes it), so the solver doesn't re-check them.
+    pub assume_deterministic: Vec<PicusExpr>,
+}
+
+impl PicusModule {
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), ..Default::default() }
+    }
+
+    /// Substitutes `env` into every constraint/call of this module and returns the simplified
+    /// module, e.g. to specialize a chip's constraints to one enabled selector. The returned
+    /// module is renamed with a `_sel<id>` suffix taken from the (single) variable `env` sets to
+    /// `1`, so specializations of the same module don't collide when collected into a program.
+    #[must_use]
+    pub fn partial_eval(&self, env: &BTreeMap<PicusVar, u64>) -> PicusModule {
+        let suffix = env
+            .iter()
+            .find(|(_, v)| **v == 1)
+            .map(|(var, _)| format!("_sel{}", var.id))
+            .unwrap_or_default();
+        PicusModule {
+            name: format!("{}{suffix}", self.name),
+            inputs: self.inputs.clone(),
+            outputs: self.outputs.clone(),
+            constraints: partial_evaluate(&self.constraints, env),
+            calls: partial_evaluate_calls(&self.calls, env),
+            assume_deterministic: self.assume_deterministic.clone(),
+        }
+    }
+
+    /// Expands every `Pow` node appearing in this module's inputs, outputs, and constraints via
+    /// square-and-multiply into nested `Mul` nodes, for Picus backends that lack a native
+    /// exponent primitive. Repeated powers of the same base/exponent are memoized into shared
+    /// temporaries rather than re-expanded at every occurrence; their defining equalities are
+    /// appended to the returned module's constraints.
+    #[must_use]
+    pub fn lower_pows(&self) -> PicusModule {
+        let mut memo = HashMap::new();
+        let mut defs = Vec::new();
+        let inputs =
+            self.inputs.iter().map(|e| lower_pows_in_expr(e, &mut memo, &mut defs)).collect();
+        let outputs =
+            self.outputs.iter().map(|e| lower_pows_in_expr(e, &mut memo, &mut defs)).collect();
+        let mut constraints: Vec<PicusConstraint> = self
+            .constraints
+            .iter()
+            .map(|c| lower_pows_in_constraint(c, &mut memo, &mut defs))
+            .collect();
+        constraints.extend(defs);
+        PicusModule {
+            name: self.name.clone(),
+            inputs,
+            outputs,
+            constraints,
+            calls: self.calls.clone(),
+            assume_deterministic: self.assume_deterministic.clone(),
+        }
+    }
+}
+
+/// Square-and-multiply expansion of `base^k`, memoized on `(base, k)` so sharing the same
+/// intermediate power across the module only allocates one temporary for it.
+fn lower_pow(
+    base: &PicusExpr,
+    k: u64,
+    memo: &mut HashMap<(PicusExpr, u64), PicusExpr>,
+    defs: &mut Vec<PicusConstraint>,
+) -> PicusExpr {
+    if k == 0 {
+        return PicusExpr::Const(1);
+    }
+    if k == 1 {
+        return base.clone();
+    }
+    if let Some(v) = memo.get(&(base.clone(), k)) {
+        return v.clone();
+    }
+    let expanded = if k % 2 == 0 {
+        let half = lower_pow(base, k / 2, memo, defs);
+        PicusExpr::Mul(Box::new(half.clone()), Box::new(half))
+    } else {
+        let rest = lower_pow(base, k - 1, memo, defs);
+        PicusExpr::Mul(Box::new(rest), Box::new(base.clone()))
+    };
+    let temp = fresh_picus_var();
+    defs.push(PicusConstraint::new_equality(temp.clone(), expanded));
+    memo.insert((base.clone(), k), temp.clone());
+    temp
+}
+
+fn lower_pows_in_expr(
+    e: &PicusExpr,
+    memo: &mut HashMap<(PicusExpr, u64), PicusExpr>,
+    defs: &mut Vec<PicusConstraint>,
+) -> PicusExpr {
+    match e {
+        PicusExpr::Const(_) | PicusExpr::Var(_) => e.clone(),
+        PicusExpr::Add(a, b) => PicusExpr::Add(
+            Box::new(lower_pows_in_expr(a, memo, defs)),
+            Box::new(lower_pows_in_expr(b, memo, defs)),
+        ),
+        PicusExpr::Sub(a, b) => PicusExpr::Sub(
+            Box::new(lower_pows_in_expr(a, memo, defs)),
+            Box::new(lower_pows_in_expr(b, memo, defs)),
+        ),
+        PicusExpr::Mul(a, b) => PicusExpr::Mul(
+            Box::new(lower_pows_in_expr(a, memo, defs)),
+            Box::new(lower_pows_in_expr(b, memo, defs)),
+        ),
+        PicusExpr::Div(a, b) => PicusExpr::Div(
+            Box::new(lower_pows_in_expr(a, memo, defs)),
+            Box::new(lower_pows_in_expr(b, memo, defs)),
+        ),
+        PicusExpr::Neg(a) => PicusExpr::Neg(Box::new(lower_pows_in_expr(a, memo, defs))),
+        PicusExpr::Pow(k, base) => {
+            let base = lower_pows_in_expr(base, memo, defs);
+            lower_pow(&base, *k, memo, defs)
+        }
+    }
+}
+
+fn lower_pows_in_constraint(
+    c: &PicusConstraint,
+    memo: &mut HashMap<(PicusExpr, u64), PicusExpr>,
+    defs: &mut Vec<PicusConstraint>,
+) -> PicusConstraint {
+    match c {
+        PicusConstraint::Lt(a, b) => PicusConstraint::Lt(
+            Box::new(lower_pows_in_expr(a, memo, defs)),
+            Box::new(lower_pows_in_expr(b, memo, defs)),
+        ),
+        PicusConstraint::Leq(a, b) => PicusConstraint::Leq(
+            Box::new(lower_pows_in_expr(a, memo, defs)),
+            Box::new(lower_pows_in_expr(b, memo, defs)),
+        ),
+        PicusConstraint::Gt(a, b) => PicusConstraint::Gt(
+            Box::new(lower_pows_in_expr(a, memo, defs)),
+            Box::new(lower_pows_in_expr(b, memo, defs)),
+        ),
+        PicusConstraint::Geq(a, b) => PicusConstraint::Geq(
+            Box::new(lower_pows_in_expr(a, memo, defs)),
+            Box::new(lower_pows_in_expr(b, memo, defs)),
+        ),
+        PicusConstraint::Implies(p, q) => PicusConstraint::Implies(
+            Box::new(lower_pows_in_constraint(p, memo, defs)),
+            Box::new(lower_pows_in_constraint(q, memo, defs)),
+        ),
+        PicusConstraint::Not(p) => {
+            PicusConstraint::Not(Box::new(lower_pows_in_constraint(p, memo, defs)))
+        }
+        PicusConstraint::Iff(p, q) => PicusConstraint::Iff(
+            Box::new(lower_pows_in_constraint(p, memo, defs)),
+            Box::new(lower_pows_in_constraint(q, memo, defs)),
+        ),
+        PicusConstraint::And(p, q) => PicusConstraint::And(
+            Box::new(lower_pows_in_constraint(p, memo, defs)),
+            Box::new(lower_pows_in_constraint(q, memo, defs)),
+        ),
+        PicusConstraint::Or(p, q) => PicusConstraint::Or(
+            Box::new(lower_pows_in_constraint(p, memo, defs)),
+            Box::new(lower_pows_in_constraint(q, memo, defs)),
+        ),
+        PicusConstraint::Eq(e) => PicusConstraint::Eq(Box::new(lower_pows_in_expr(e, memo, defs))),
+    }
+}
+
+impl Display for PicusModule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "(module {}", self.name)?;
+        write!(f, "  (input")?;
+        for i in &self.inputs {
+            write!(f, " {i}")?;
+        }
+        writeln!(f, ")")?;
+        write!(f, "  (output")?;
+        for o in &self.outputs {
+            write!(f, " {o}")?;
+        }
+        writeln!(f, ")")?;
+        for v in &self.assume_deterministic {
+            writeln!(f, "  (assume-deterministic {v})")?;
+        }
+        for call in &self.calls {
+            write!(f, "  (call {}", call.mod_name)?;
+            for i in &call.inputs {
+                write!(f, " {i}")?;
+            }
+            for o in &call.outputs {
+                write!(f, " {o}")?;
+            }
+            writeln!(f, ")")?;
+        }
+        for c in &self.constraints {
+            writeln!(f, "  (assert {c})")?;
+        }
+        writeln!(f, ")")
+    }
+}
+
+/// A full Picus program: one field modulus shared by every module, plus the modules themselves
+/// collected from each compiled chip (see `zkm_picus::main`).
+#[derive(Debug, Clone)]
+pub struct PicusProgram {
+    prime: u64,
+    modules: BTreeMap<String, PicusModule>,
+}
+
+impl PicusProgram {
+    #[must_use]
+    pub fn new(prime: u64) -> Self {
+        Self { prime, modules: BTreeMap::new() }
+    }
+
+    /// Merges `modules` into this program, draining the input map.
+    pub fn add_modules(&mut self, modules: &mut BTreeMap<String, PicusModule>) {
+        self.modules.append(modules);
+    }
+
+    /// The field modulus every module in this program is declared over.
+    #[must_use]
+    pub fn current_modulus(&self) -> u64 {
+        self.prime
+    }
+
+    /// Read-only view of the modules collected so far, keyed by name.
+    #[must_use]
+    pub fn modules(&self) -> &BTreeMap<String, PicusModule> {
+        &self.modules
+    }
+
+    /// Renders every module (with `Pow` nodes expanded, since the Picus solver has no exponent
+    /// primitive) and writes the result to `path`.
+    pub fn write_to_path(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let lowered = PicusProgram {
+            prime: self.prime,
+            modules: self.modules.iter().map(|(k, v)| (k.clone(), v.lower_pows())).collect(),
+        };
+        fs::write(path, lowered.to_string())
+    }
+}
+
+impl Display for PicusProgram {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "(prime {})", self.prime)?;
+        for module in self.modules.values() {
+            writeln!(f)?;
+            write!(f, "{module}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pcl::PicusVar;
+
+    #[test]
+    fn renders_equality_constraint() {
+        let mut module = PicusModule::new("eq_module");
+        let x = PicusExpr::Var(PicusVar::new(0));
+        let y = PicusExpr::Var(PicusVar::new(1));
+        module.inputs.push(x.clone());
+        module.outputs.push(y.clone());
+        module.constraints.push(PicusConstraint::new_equality(y, x));
+
+        let rendered = module.to_string();
+        assert!(rendered.contains("(module eq_module"));
+        assert!(rendered.contains("(assert (= (- "));
+        // Rendering is deterministic: re-rendering the same module round-trips byte-for-byte.
+        assert_eq!(rendered, module.to_string());
+    }
+
+    #[test]
+    fn renders_range_constraint() {
+        let e = PicusExpr::Var(PicusVar::new(2));
+        let mut module = PicusModule::new("range_module");
+        module.inputs.push(e.clone());
+        module.constraints.extend(PicusConstraint::in_range(e, 0, 10));
+
+        let rendered = module.to_string();
+        assert!(rendered.contains("(assert (>= "));
+        assert!(rendered.contains("(assert (<= "));
+        assert_eq!(rendered, module.to_string());
+    }
+
+    #[test]
+    fn lower_pows_removes_pow_nodes() {
+        let base = PicusExpr::Var(PicusVar::new(3));
+        let mut module = PicusModule::new("pow_module");
+        module.constraints.push(PicusConstraint::Eq(Box::new(base.pow(5))));
+
+        let lowered = module.lower_pows();
+        assert!(lowered.to_string().contains("(assert"));
+        assert!(!format!("{lowered}").contains('^'));
+    }
+}
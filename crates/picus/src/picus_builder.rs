@@ -123,11 +123,47 @@ impl PicusBuilder {
             self.picus_module.constraints.push(eq_mul(&multiplicity, &values[i], &c_var));
         }
     }
+
+    // `is_first_row`/`is_last_row`/`is_transition_window` have no preprocessed selector column
+    // to read in this builder (Picus only ever analyzes one row plus a transition window, not a
+    // concrete trace with boundary rows), so each is modeled as its own fresh symbolic
+    // selector. `AirBuilder::is_first_row` and friends take `&self`, so the booleanity
+    // constraint a real selector column would carry can't be pushed here; any chip whose
+    // transition logic depends on it being exactly 0/1 is still caught downstream, since a
+    // non-boolean value flows into the rest of that chip's (fully constrained) equations.
+    fn fresh_boolean_selector(&self) -> PicusExpr {
+        fresh_picus_var()
+    }
+
+    // Memory interactions carry `[addr, clk, value[0..4]]`: a memory read's value words are
+    // what the chip consumes (an input, same as `b`/`c` in `handle_receive_instruction`), while
+    // a memory write's value words are what the chip produces (an output, same as `a`). The
+    // address and clock are a lookup key rather than a witnessed value, so -- unlike the value
+    // words -- they aren't turned into fresh Picus variables here.
+    fn handle_memory_interaction(
+        &mut self,
+        is_write: bool,
+        multiplicity: PicusExpr,
+        values: &[PicusExpr],
+    ) {
+        let eq_mul = |multiplicity: &PicusExpr, val: &PicusExpr, var: &PicusExpr| {
+            PicusConstraint::new_equality(var.clone(), val.clone() * multiplicity.clone())
+        };
+        for value_word in &values[2..6] {
+            let var = fresh_picus_var();
+            if is_write {
+                self.picus_module.outputs.push(var.clone());
+            } else {
+                self.picus_module.inputs.push(var.clone());
+            }
+            self.picus_module.constraints.push(eq_mul(&multiplicity, value_word, &var));
+        }
+    }
 }
 
 impl<'a> PairBuilder for PicusBuilder {
     fn preprocessed(&self) -> Self::M {
-        todo!()
+        self.preprocessed.clone()
     }
 }
 
@@ -135,7 +171,7 @@ impl<'a> AirBuilderWithPublicValues for PicusBuilder {
     type PublicVar = PicusVar;
 
     fn public_values(&self) -> &[Self::PublicVar] {
-        todo!()
+        &self.public_values
     }
 }
 
@@ -146,7 +182,7 @@ impl<'a> MessageBuilder<AirLookup<PicusExpr>> for PicusBuilder {
                 self.handle_byte_interaction(message.multiplicity, &message.values);
             }
             LookupKind::Memory => {
-                // TODO: fill in
+                self.handle_memory_interaction(true, message.multiplicity, &message.values);
             }
             _ => todo!("handle send: {}", message.kind),
         }
@@ -160,7 +196,7 @@ impl<'a> MessageBuilder<AirLookup<PicusExpr>> for PicusBuilder {
                 self.handle_receive_instruction(message.multiplicity, &message.values);
             }
             LookupKind::Memory => {
-                // TODO: fill in
+                self.handle_memory_interaction(false, message.multiplicity, &message.values);
             }
             _ => todo!("handle receive: {}", message.kind),
         }
@@ -179,15 +215,15 @@ impl<'a> AirBuilder for PicusBuilder {
     }
 
     fn is_first_row(&self) -> Self::Expr {
-        todo!()
+        self.fresh_boolean_selector()
     }
 
     fn is_last_row(&self) -> Self::Expr {
-        todo!()
+        self.fresh_boolean_selector()
     }
 
     fn is_transition_window(&self, _size: usize) -> Self::Expr {
-        todo!()
+        self.fresh_boolean_selector()
     }
 
     fn assert_zero<I: Into<Self::Expr>>(&mut self, x: I) {
@@ -0,0 +1,457 @@
+//! Translates the Picus constraint IR ([`crate::pcl`]) into SMT-LIB2, and builds the standard
+//! two-run determinism query on top of it, so a chip's soundness can be checked with any
+//! SMT-LIB2-speaking solver instead of only the Racket Picus binary [`crate::solver`] drives.
+//!
+//! [`determinism_query`] is the interesting half: it duplicates a module's variables into a
+//! primed copy, asserts the two copies agree on every declared input, re-instantiates the
+//! (call-inlined) constraint set on both copies, and asks whether any declared output can still
+//! differ. UNSAT proves the module is deterministic; SAT hands back a concrete pair of
+//! assignments that agree on inputs yet disagree on an output -- an under-constrained-circuit
+//! witness. [`run_smt_query`] is a thin wrapper that shells out to Z3 the same way
+//! [`crate::solver::check_chip_determinism`] shells out to Picus, and just as deliberately doesn't
+//! try to own any more of the solver's semantics than parsing its `sat`/`unsat`/`unknown` verdict.
+//!
+//! Wiring this into `src/main.rs` as a `--check-determinism-smt` flag (mirroring the existing
+//! `--check-determinism`/Picus-binary path) is left for a follow-up.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::{self, Write},
+    process::{Command, Stdio},
+};
+
+use crate::pcl::{
+    partial_evaluate, partial_evaluate_calls, PicusConstraint, PicusExpr, PicusModule,
+    PicusProgram, PicusVar,
+};
+
+/// Offset added to a variable's id to mint its "primed" (second-run) counterpart in
+/// [`determinism_query`] -- comfortably clear of any id a chip's column count or
+/// `fresh_picus_var` counter could reach.
+const PRIME_OFFSET: usize = 1_000_000_000;
+
+/// The solver binary [`run_smt_query`] invokes, overridable for environments where Z3 isn't named
+/// `z3` on `PATH`.
+const DEFAULT_Z3_BIN: &str = "z3";
+
+fn smt_var_name(v: PicusVar) -> String {
+    format!("v{}", v.id)
+}
+
+// === Variable collection & substitution ===
+// Generic counterparts to `pcl::partial_evaluator`'s `subst_expr`/`subst_constraint`: those
+// substitute variables with field constants and fold as they go; these substitute variables with
+// arbitrary `PicusExpr`s (needed to inline a call's actual arguments, or to rename a variable to
+// its primed copy) and never fold, since the replacement isn't generally a constant.
+
+fn collect_vars_expr(e: &PicusExpr, vars: &mut BTreeSet<PicusVar>) {
+    match e {
+        PicusExpr::Const(_) => {}
+        PicusExpr::Var(v) => {
+            vars.insert(*v);
+        }
+        PicusExpr::Add(a, b) | PicusExpr::Sub(a, b) | PicusExpr::Mul(a, b) | PicusExpr::Div(a, b) => {
+            collect_vars_expr(a, vars);
+            collect_vars_expr(b, vars);
+        }
+        PicusExpr::Neg(a) | PicusExpr::Pow(_, a) => collect_vars_expr(a, vars),
+    }
+}
+
+fn collect_vars_constraint(c: &PicusConstraint, vars: &mut BTreeSet<PicusVar>) {
+    use PicusConstraint::*;
+    match c {
+        Eq(e) => collect_vars_expr(e, vars),
+        Lt(a, b) | Leq(a, b) | Gt(a, b) | Geq(a, b) => {
+            collect_vars_expr(a, vars);
+            collect_vars_expr(b, vars);
+        }
+        Not(p) => collect_vars_constraint(p, vars),
+        And(p, q) | Or(p, q) | Implies(p, q) | Iff(p, q) => {
+            collect_vars_constraint(p, vars);
+            collect_vars_constraint(q, vars);
+        }
+    }
+}
+
+fn collect_vars_module(m: &PicusModule) -> BTreeSet<PicusVar> {
+    let mut vars = BTreeSet::new();
+    for e in m.inputs.iter().chain(&m.outputs) {
+        collect_vars_expr(e, &mut vars);
+    }
+    for c in &m.constraints {
+        collect_vars_constraint(c, &mut vars);
+    }
+    vars
+}
+
+fn inline_expr(e: &PicusExpr, env: &BTreeMap<PicusVar, PicusExpr>) -> PicusExpr {
+    use PicusExpr::*;
+    match e {
+        Const(c) => Const(*c),
+        Var(v) => env.get(v).cloned().unwrap_or(Var(*v)),
+        Add(a, b) => Add(Box::new(inline_expr(a, env)), Box::new(inline_expr(b, env))),
+        Sub(a, b) => Sub(Box::new(inline_expr(a, env)), Box::new(inline_expr(b, env))),
+        Mul(a, b) => Mul(Box::new(inline_expr(a, env)), Box::new(inline_expr(b, env))),
+        Div(a, b) => Div(Box::new(inline_expr(a, env)), Box::new(inline_expr(b, env))),
+        Neg(a) => Neg(Box::new(inline_expr(a, env))),
+        Pow(k, a) => Pow(*k, Box::new(inline_expr(a, env))),
+    }
+}
+
+fn inline_constraint(c: &PicusConstraint, env: &BTreeMap<PicusVar, PicusExpr>) -> PicusConstraint {
+    use PicusConstraint::*;
+    match c {
+        Eq(e) => Eq(Box::new(inline_expr(e, env))),
+        Lt(a, b) => Lt(Box::new(inline_expr(a, env)), Box::new(inline_expr(b, env))),
+        Leq(a, b) => Leq(Box::new(inline_expr(a, env)), Box::new(inline_expr(b, env))),
+        Gt(a, b) => Gt(Box::new(inline_expr(a, env)), Box::new(inline_expr(b, env))),
+        Geq(a, b) => Geq(Box::new(inline_expr(a, env)), Box::new(inline_expr(b, env))),
+        Not(p) => Not(Box::new(inline_constraint(p, env))),
+        And(p, q) => And(Box::new(inline_constraint(p, env)), Box::new(inline_constraint(q, env))),
+        Or(p, q) => Or(Box::new(inline_constraint(p, env)), Box::new(inline_constraint(q, env))),
+        Implies(p, q) => {
+            Implies(Box::new(inline_constraint(p, env)), Box::new(inline_constraint(q, env)))
+        }
+        Iff(p, q) => Iff(Box::new(inline_constraint(p, env)), Box::new(inline_constraint(q, env))),
+    }
+}
+
+/// Resolves `module_name` into a call-free module: runs `partial_evaluate`/`partial_evaluate_calls`
+/// (with an empty environment, just to fold away whatever constant-foldable structure is already
+/// there) to shrink the problem, then recursively inlines every `PicusCall` by substituting the
+/// callee's own (equally resolved) constraints with its declared inputs/outputs replaced by the
+/// call's actual arguments.
+fn prepare_module(program: &PicusProgram, module_name: &str) -> PicusModule {
+    let module = program
+        .modules()
+        .get(module_name)
+        .unwrap_or_else(|| panic!("picus program has no module named {module_name}"));
+
+    let mut constraints = partial_evaluate(&module.constraints, &BTreeMap::new());
+    let calls = partial_evaluate_calls(&module.calls, &BTreeMap::new());
+
+    for call in &calls {
+        let callee = prepare_module(program, &call.mod_name);
+
+        let mut env = BTreeMap::new();
+        for (decl, actual) in callee.inputs.iter().zip(&call.inputs) {
+            if let PicusExpr::Var(v) = decl {
+                env.insert(*v, actual.clone());
+            }
+        }
+        for (decl, actual) in callee.outputs.iter().zip(&call.outputs) {
+            if let PicusExpr::Var(v) = decl {
+                env.insert(*v, actual.clone());
+            }
+        }
+
+        constraints.extend(callee.constraints.iter().map(|c| inline_constraint(c, &env)));
+    }
+
+    PicusModule {
+        name: module.name.clone(),
+        inputs: module.inputs.clone(),
+        outputs: module.outputs.clone(),
+        constraints,
+        calls: Vec::new(),
+        assume_deterministic: module.assume_deterministic.clone(),
+    }
+}
+
+/// Accumulates `declare-const`/`assert` lines while lowering [`PicusExpr`]/[`PicusConstraint`]
+/// trees into SMT-LIB2 terms.
+struct SmtBuilder {
+    prime: u64,
+    declares: Vec<String>,
+    asserts: Vec<String>,
+    declared: BTreeSet<PicusVar>,
+    div_ctr: usize,
+}
+
+impl SmtBuilder {
+    fn new(prime: u64) -> Self {
+        Self { prime, declares: Vec::new(), asserts: Vec::new(), declared: BTreeSet::new(), div_ctr: 0 }
+    }
+
+    /// Declares `v` as an `Int` constant the first time it's seen, and constrains it to a
+    /// canonical field residue `0 <= v < prime` -- the "field-range helper predicate" every
+    /// variable needs so a plain integer `<`/`<=`/`>`/`>=` on it means the same thing as the field
+    /// comparison `PicusConstraint::Lt`/`Leq`/`Gt`/`Geq` was asserting.
+    fn declare_var(&mut self, v: PicusVar) {
+        if self.declared.insert(v) {
+            let name = smt_var_name(v);
+            self.declares.push(format!("(declare-const {name} Int)"));
+            self.asserts.push(format!("(assert (and (>= {name} 0) (< {name} {})))", self.prime));
+        }
+    }
+
+    /// Lowers `e` into an SMT-LIB2 integer term. `Pow(k, base)` unrolls into `k` nested
+    /// multiplications (no native exponent operator in SMT-LIB). `Div(a, b)` becomes a fresh
+    /// range-constrained quotient variable `q` with `q * b == a (mod prime)` asserted alongside
+    /// it, and `q` itself is substituted in as this node's value -- the usual "division is
+    /// multiplication by an inverse, witnessed rather than computed" encoding.
+    fn expr_to_term(&mut self, e: &PicusExpr) -> String {
+        match e {
+            PicusExpr::Const(c) => c.to_string(),
+            PicusExpr::Var(v) => {
+                self.declare_var(*v);
+                smt_var_name(*v)
+            }
+            PicusExpr::Add(a, b) => format!("(+ {} {})", self.expr_to_term(a), self.expr_to_term(b)),
+            PicusExpr::Sub(a, b) => format!("(- {} {})", self.expr_to_term(a), self.expr_to_term(b)),
+            PicusExpr::Mul(a, b) => format!("(* {} {})", self.expr_to_term(a), self.expr_to_term(b)),
+            PicusExpr::Neg(a) => format!("(- {})", self.expr_to_term(a)),
+            PicusExpr::Pow(k, base) => {
+                let base_term = self.expr_to_term(base);
+                let mut acc = "1".to_string();
+                for _ in 0..*k {
+                    acc = format!("(* {acc} {base_term})");
+                }
+                acc
+            }
+            PicusExpr::Div(a, b) => {
+                let a_term = self.expr_to_term(a);
+                let b_term = self.expr_to_term(b);
+                let q_name = format!("picus_div{}", self.div_ctr);
+                self.div_ctr += 1;
+                self.declares.push(format!("(declare-const {q_name} Int)"));
+                self.asserts
+                    .push(format!("(assert (and (>= {q_name} 0) (< {q_name} {})))", self.prime));
+                self.asserts.push(format!(
+                    "(assert (= (mod (- (* {q_name} {b_term}) {a_term}) {}) 0))",
+                    self.prime
+                ));
+                q_name
+            }
+        }
+    }
+
+    /// Lowers `c` into an SMT-LIB2 boolean term, mirroring `PicusConstraint`'s own prefix-form
+    /// `Display` impl one-for-one except `Eq(e)` asserts `e == 0` modulo the field prime rather
+    /// than over the integers.
+    fn constraint_to_term(&mut self, c: &PicusConstraint) -> String {
+        use PicusConstraint::*;
+        match c {
+            Eq(e) => {
+                let t = self.expr_to_term(e);
+                format!("(= (mod {t} {}) 0)", self.prime)
+            }
+            Lt(a, b) => format!("(< {} {})", self.expr_to_term(a), self.expr_to_term(b)),
+            Leq(a, b) => format!("(<= {} {})", self.expr_to_term(a), self.expr_to_term(b)),
+            Gt(a, b) => format!("(> {} {})", self.expr_to_term(a), self.expr_to_term(b)),
+            Geq(a, b) => format!("(>= {} {})", self.expr_to_term(a), self.expr_to_term(b)),
+            Not(p) => format!("(not {})", self.constraint_to_term(p)),
+            And(p, q) => format!("(and {} {})", self.constraint_to_term(p), self.constraint_to_term(q)),
+            Or(p, q) => format!("(or {} {})", self.constraint_to_term(p), self.constraint_to_term(q)),
+            Implies(p, q) => {
+                format!("(=> {} {})", self.constraint_to_term(p), self.constraint_to_term(q))
+            }
+            Iff(p, q) => format!("(= {} {})", self.constraint_to_term(p), self.constraint_to_term(q)),
+        }
+    }
+
+    fn assert_constraint(&mut self, c: &PicusConstraint) {
+        let t = self.constraint_to_term(c);
+        self.asserts.push(format!("(assert {t})"));
+    }
+
+    fn finish(self) -> String {
+        let mut out = String::new();
+        for line in self.declares.iter().chain(&self.asserts) {
+            out.push_str(line);
+            out.push('\n');
+        }
+        out.push_str("(check-sat)\n");
+        out
+    }
+}
+
+/// Lowers `module_name` (fully call-inlined and constant-folded via [`prepare_module`]) into a
+/// standalone SMT-LIB2 script asserting its constraint set, with no two-run scaffolding -- just
+/// the module's own declared variables and constraints.
+#[must_use]
+pub fn module_to_smtlib2(program: &PicusProgram, module_name: &str) -> String {
+    let resolved = prepare_module(program, module_name);
+    let mut builder = SmtBuilder::new(program.current_modulus());
+    for e in resolved.inputs.iter().chain(&resolved.outputs) {
+        builder.expr_to_term(e);
+    }
+    for c in &resolved.constraints {
+        builder.assert_constraint(c);
+    }
+    builder.finish()
+}
+
+/// Builds the standard two-run determinism query for `module_name`: duplicates every variable the
+/// (call-inlined) module touches into a primed copy, asserts the module's declared inputs agree
+/// pairwise across both copies, asserts both copies' constraint sets, and asks whether any
+/// declared output can still differ between them.
+///
+/// UNSAT proves the module's outputs are uniquely determined by its inputs. SAT means the solver
+/// found two valid assignments that agree on every input yet disagree on an output -- a genuine
+/// under-constrained-circuit witness.
+#[must_use]
+pub fn determinism_query(program: &PicusProgram, module_name: &str) -> String {
+    let resolved = prepare_module(program, module_name);
+    let vars = collect_vars_module(&resolved);
+    let prime_env: BTreeMap<PicusVar, PicusExpr> = vars
+        .iter()
+        .map(|v| (*v, PicusExpr::Var(PicusVar::new(v.id + PRIME_OFFSET))))
+        .collect();
+
+    let primed_inputs: Vec<PicusExpr> =
+        resolved.inputs.iter().map(|e| inline_expr(e, &prime_env)).collect();
+    let primed_outputs: Vec<PicusExpr> =
+        resolved.outputs.iter().map(|e| inline_expr(e, &prime_env)).collect();
+    let primed_constraints: Vec<PicusConstraint> =
+        resolved.constraints.iter().map(|c| inline_constraint(c, &prime_env)).collect();
+
+    let mut builder = SmtBuilder::new(program.current_modulus());
+
+    for (a, b) in resolved.inputs.iter().zip(&primed_inputs) {
+        builder.assert_constraint(&PicusConstraint::new_equality(a.clone(), b.clone()));
+    }
+    for c in resolved.constraints.iter().chain(&primed_constraints) {
+        builder.assert_constraint(c);
+    }
+
+    let diffs: Vec<String> = resolved
+        .outputs
+        .iter()
+        .zip(&primed_outputs)
+        .map(|(a, b)| {
+            let eq = builder.constraint_to_term(&PicusConstraint::new_equality(a.clone(), b.clone()));
+            format!("(not {eq})")
+        })
+        .collect();
+    let goal =
+        if diffs.len() == 1 { diffs[0].clone() } else { format!("(or {})", diffs.join(" ")) };
+    builder.asserts.push(format!("(assert {goal})"));
+
+    builder.finish()
+}
+
+/// Verdict an SMT-LIB2 `(check-sat)` response falls into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmtVerdict {
+    Sat,
+    Unsat,
+    Unknown,
+}
+
+impl SmtVerdict {
+    /// `true` iff this verdict is what [`determinism_query`] needs to prove determinism: `unsat`.
+    #[must_use]
+    pub fn proves_determinism(self) -> bool {
+        matches!(self, SmtVerdict::Unsat)
+    }
+}
+
+/// Shells out to Z3 (or whatever `solver_bin` names) with `query` piped over stdin via `-in`, the
+/// same thin-wrapper shape [`crate::solver::check_chip_determinism`] uses for the Picus binary --
+/// this just parses the leading `sat`/`unsat`/`unknown` line rather than owning any more of the
+/// solver's semantics.
+pub fn run_smt_query(query: &str, solver_bin: Option<&str>) -> io::Result<SmtVerdict> {
+    let bin = solver_bin.unwrap_or(DEFAULT_Z3_BIN);
+    let mut child =
+        Command::new(bin).arg("-in").stdin(Stdio::piped()).stdout(Stdio::piped()).spawn()?;
+    child.stdin.take().expect("piped stdin").write_all(query.as_bytes())?;
+    let output = child.wait_with_output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let verdict = match stdout.lines().map(str::trim).find(|l| !l.is_empty()) {
+        Some("unsat") => SmtVerdict::Unsat,
+        Some("sat") => SmtVerdict::Sat,
+        _ => SmtVerdict::Unknown,
+    };
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::pcl::{PicusExpr, PicusVar};
+
+    /// `y = x` over a trivial identity module should emit both variables' declarations, a field-
+    /// range bound for each, and the equality constraint reduced modulo the program's prime.
+    #[test]
+    fn module_to_smtlib2_emits_declarations_and_constraint() {
+        let x = PicusExpr::Var(PicusVar::new(0));
+        let y = PicusExpr::Var(PicusVar::new(1));
+        let mut module = PicusModule::new("identity");
+        module.inputs.push(x.clone());
+        module.outputs.push(y.clone());
+        module.constraints.push(PicusConstraint::new_equality(y, x));
+
+        let mut program = PicusProgram::new(101);
+        let mut modules = BTreeMap::new();
+        modules.insert(module.name.clone(), module);
+        program.add_modules(&mut modules);
+
+        let smt = module_to_smtlib2(&program, "identity");
+        assert!(smt.contains("(declare-const v0 Int)"));
+        assert!(smt.contains("(declare-const v1 Int)"));
+        assert!(smt.contains("< v0 101"));
+        assert!(smt.contains("(check-sat)"));
+    }
+
+    /// The two-run determinism query for `y = x` should declare primed counterparts of both
+    /// variables, assert input equality across runs, and assert the goal that some output
+    /// differs.
+    #[test]
+    fn determinism_query_duplicates_variables_and_asserts_output_diff() {
+        let x = PicusExpr::Var(PicusVar::new(0));
+        let y = PicusExpr::Var(PicusVar::new(1));
+        let mut module = PicusModule::new("identity");
+        module.inputs.push(x.clone());
+        module.outputs.push(y.clone());
+        module.constraints.push(PicusConstraint::new_equality(y, x));
+
+        let mut program = PicusProgram::new(101);
+        let mut modules = BTreeMap::new();
+        modules.insert(module.name.clone(), module);
+        program.add_modules(&mut modules);
+
+        let query = determinism_query(&program, "identity");
+        assert!(query.contains(&format!("v{}", PRIME_OFFSET)));
+        assert!(query.contains(&format!("v{}", PRIME_OFFSET + 1)));
+        assert!(query.contains("(not (="));
+        assert!(query.contains("(check-sat)"));
+    }
+
+    /// A call to a callee module should be inlined: the caller's query carries the callee's
+    /// constraint (rewritten over the call's actual arguments) rather than a `(call ...)` form.
+    #[test]
+    fn prepare_module_inlines_calls() {
+        let cx = PicusExpr::Var(PicusVar::new(0));
+        let cy = PicusExpr::Var(PicusVar::new(1));
+        let mut callee = PicusModule::new("double");
+        callee.inputs.push(cx.clone());
+        callee.outputs.push(cy.clone());
+        callee.constraints.push(PicusConstraint::new_equality(cy, cx * 2u64));
+
+        let a = PicusExpr::Var(PicusVar::new(2));
+        let b = PicusExpr::Var(PicusVar::new(3));
+        let mut caller = PicusModule::new("caller");
+        caller.inputs.push(a.clone());
+        caller.outputs.push(b.clone());
+        caller.calls.push(crate::pcl::PicusCall {
+            mod_name: "double".to_string(),
+            inputs: vec![a],
+            outputs: vec![b],
+        });
+
+        let mut program = PicusProgram::new(101);
+        let mut modules = BTreeMap::new();
+        modules.insert(callee.name.clone(), callee);
+        modules.insert(caller.name.clone(), caller);
+        program.add_modules(&mut modules);
+
+        let resolved = prepare_module(&program, "caller");
+        assert!(resolved.calls.is_empty());
+        assert_eq!(resolved.constraints.len(), 1);
+    }
+}
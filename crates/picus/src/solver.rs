@@ -0,0 +1,79 @@
+//! Drives the external Picus solver over the `.picus` programs this crate extracts per chip,
+//! turning its pass/fail verdicts into a single aggregated report `main.rs` can use to fail
+//! CI-style when any chip leaks an under-constrained column.
+//!
+//! This is deliberately a thin wrapper: Picus itself (the Racket solver binary, not this crate)
+//! owns the actual under-constrained-column analysis. We just shell out to it per module file,
+//! the same way other zkVM soundness tooling in this workspace treats `cargo prove` or `zkm`
+//! CLIs as external processes rather than reimplementing them in-process.
+
+use std::{
+    path::Path,
+    process::Command,
+};
+
+/// The solver binary to invoke, overridable for environments where it's not named `picus` on
+/// `PATH` (e.g. a local Racket checkout).
+const DEFAULT_PICUS_BIN: &str = "picus";
+
+/// One chip's verdict: either every declared output is uniquely determined by the declared
+/// inputs, or a list of column names Picus flagged as underconstrained.
+#[derive(Debug, Clone)]
+pub struct ChipDeterminismResult {
+    pub chip_name: String,
+    pub underconstrained_columns: Vec<String>,
+}
+
+impl ChipDeterminismResult {
+    #[must_use]
+    pub fn is_sound(&self) -> bool {
+        self.underconstrained_columns.is_empty()
+    }
+}
+
+/// Runs the Picus solver against a single extracted `.picus` file and parses its output for
+/// underconstrained column names.
+///
+/// Picus reports one `"<column> is not deterministic"`-style line per underconstrained output;
+/// we don't depend on its exact wording beyond that shape, just scan every line for the marker
+/// and pull out the leading identifier, so a format change degrades to "nothing found" rather
+/// than a hard parse error.
+pub fn check_chip_determinism(
+    chip_name: &str,
+    picus_file: &Path,
+    picus_bin: Option<&str>,
+) -> std::io::Result<ChipDeterminismResult> {
+    let bin = picus_bin.unwrap_or(DEFAULT_PICUS_BIN);
+    let output = Command::new(bin).arg(picus_file).output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let mut underconstrained_columns = Vec::new();
+    for line in stdout.lines() {
+        let line = line.trim();
+        if let Some(col) = line.strip_suffix("is not deterministic") {
+            underconstrained_columns.push(col.trim().to_string());
+        }
+    }
+
+    Ok(ChipDeterminismResult { chip_name: chip_name.to_string(), underconstrained_columns })
+}
+
+/// Aggregates per-chip results into a human-readable report and an overall pass/fail verdict,
+/// suitable for a CI step: any underconstrained column anywhere fails the whole run.
+#[must_use]
+pub fn summarize(results: &[ChipDeterminismResult]) -> (String, bool) {
+    let mut report = String::new();
+    let mut all_sound = true;
+    for result in results {
+        if result.is_sound() {
+            report.push_str(&format!("  [ok] {}\n", result.chip_name));
+        } else {
+            all_sound = false;
+            report.push_str(&format!("  [FAIL] {}:\n", result.chip_name));
+            for col in &result.underconstrained_columns {
+                report.push_str(&format!("    - column `{col}` is not uniquely determined\n"));
+            }
+        }
+    }
+    (report, all_sound)
+}
@@ -0,0 +1,196 @@
+//! On-chain EVM verifier codegen for the outer, BN254("SECT")-wrapped STARK proof -- the
+//! `ZKMReduceProof<KoalaBearPoseidon2Outer>` this crate's recursion circuit produces, *before* it
+//! is ever handed to gnark for the final Groth16/Plonk wrap (see `zkm_sdk::provers::solidity` and
+//! `zkm_verifier::solidity` for codegen once a proof has reached that later stage).
+//!
+//! The public-input layout here has to match [`koalabear_bytes_to_bn254`]'s truncation exactly --
+//! the first 4 KoalaBear bytes of a digest contribute only their low 5 bits (the top 3 are
+//! dropped) -- since that's the same truncation this crate's wrap circuit applies when folding
+//! `vkey_hash`/`committed_value_digest` into a single field element
+//! ([`koalabears_to_bn254`]/[`felts_to_bn254_var`] do the analogous thing for a `KoalaBear`-word
+//! digest instead of a byte digest). Getting this wrong would mean a value the contract accepts
+//! and the value the wrap circuit actually committed to are different field elements, and the
+//! on-chain check would either reject every real proof or, worse, accept proofs for the wrong
+//! public values.
+//!
+//! Lowering the actual FRI + multi-field-Poseidon2-challenger verification to EVM bytecode (the
+//! "loader" half of a real EVM verifier) is *not* implemented here: `ShardProof`/
+//! `StarkVerifyingKey`'s concrete commitment/opening layout isn't something this crate can
+//! introspect field-by-field (`zkm_stark` is consumed as a dependency here, not re-derived), so
+//! there is nothing concrete to lower one opcode at a time the way
+//! `zkm_verifier::solidity::render_groth16_contract` lowers a parsed Groth16 verifying key to
+//! `ecAdd`/`ecMul`/`ecPairing` calls. What *is* implemented -- the public-input encoding and the
+//! calldata layout -- is exactly the part that has to match the wrap circuit bit-for-bit; the
+//! pairing/FRI check itself is left as an explicit `revert`, the same extension-point shape
+//! `zkm_verifier::solidity::render_plonk_contract_skeleton` uses for Plonk.
+
+use anyhow::{Context, Result};
+use p3_field::PrimeField;
+use p3_koala_bear::KoalaBear;
+use sha2::{Digest, Sha256};
+
+use zkm_recursion_core::stark::KoalaBearPoseidon2Outer;
+use zkm_stark::StarkVerifyingKey;
+
+use crate::utils::koalabear_bytes_to_bn254;
+
+/// The selector of `verifyProof(bytes,uint256[2])`, i.e. the first four bytes of
+/// `keccak256("verifyProof(bytes,uint256[2])")`.
+const VERIFY_PROOF_SELECTOR: [u8; 4] = [0x43, 0x75, 0x3b, 0x4d];
+
+/// Folds a KoalaBear-encoded 32-byte digest (a `vkey_hash` or `committed_value_digest`) into its
+/// BN254("SECT")-field decimal representation, for embedding as a Solidity `uint256` literal.
+/// Matches [`koalabear_bytes_to_bn254`]'s truncation exactly, so a value this function renders and
+/// the value the wrap circuit commits to are the same field element.
+fn digest_to_decimal(bytes: &[KoalaBear; 32]) -> String {
+    koalabear_bytes_to_bn254(bytes).as_canonical_biguint().to_string()
+}
+
+/// Hashes the bincode encoding of `vk` down to a 32-byte digest to embed as a contract constant.
+/// This is *not* the same `vkey_hash` the wrap circuit commits to as a public value (that one is
+/// computed over the pre-wrap recursion vkey, upstream of this crate) -- it only has to uniquely
+/// identify which `StarkVerifyingKey` a given deployed contract was generated from.
+fn vk_digest(vk: &StarkVerifyingKey<KoalaBearPoseidon2Outer>) -> Result<[u8; 32]> {
+    let vk_bytes = bincode::serialize(vk).context("serializing the outer verifying key")?;
+    Ok(Sha256::digest(vk_bytes).into())
+}
+
+/// Renders a self-contained Solidity verifier contract skeleton for the outer verifying key `vk`.
+/// `vkey_hash`/`committed_value_digest` are the same KoalaBear-byte digests the wrap circuit folds
+/// via [`koalabear_bytes_to_bn254`], embedded as the contract's two public-input constants so a
+/// caller can see exactly which values `verifyProof` expects without re-deriving them off-chain.
+///
+/// See the module docs for why the pairing/FRI check itself is a stub rather than a real
+/// implementation.
+pub fn generate_evm_verifier(
+    vk: &StarkVerifyingKey<KoalaBearPoseidon2Outer>,
+    vkey_hash: &[KoalaBear; 32],
+    committed_value_digest: &[KoalaBear; 32],
+) -> Result<String> {
+    let vk_digest_hex = vk_digest(vk)?.iter().map(|b| format!("{b:02x}")).collect::<String>();
+
+    Ok(format!(
+        r#"// SPDX-License-Identifier: MIT
+// Generated by `zkm_recursion_circuit::evm::generate_evm_verifier`. Do not edit by hand.
+pragma solidity ^0.8.20;
+
+/// @notice Verifies the outer, BN254-wrapped STARK proof produced for one specific
+/// `StarkVerifyingKey`, ahead of that proof's later Groth16/Plonk wrap.
+contract ZKMOuterVerifier {{
+    /// sha256 of the bincode-serialized `StarkVerifyingKey` this contract was generated from.
+    bytes32 public constant VK_DIGEST = 0x{vk_digest_hex};
+
+    /// `vkey_hash`, folded into a BN254("SECT") field element the same way the wrap circuit does.
+    uint256 public constant VKEY_HASH = {vkey_hash};
+
+    /// `committed_value_digest`, folded the same way.
+    uint256 public constant COMMITTED_VALUE_DIGEST = {committed_value_digest};
+
+    error InvalidProof();
+
+    /// @notice Verifies `proof` against `publicInputs` (`[VKEY_HASH, COMMITTED_VALUE_DIGEST]`).
+    /// @dev The FRI + multi-field-Poseidon2 challenger check itself is not implemented -- see this
+    /// contract's generating function's doc comment for why -- so this always reverts.
+    function verifyProof(bytes calldata proof, uint256[2] calldata publicInputs)
+        external
+        pure
+        returns (bool)
+    {{
+        proof;
+        publicInputs;
+        revert InvalidProof();
+    }}
+}}
+"#,
+        vk_digest_hex = vk_digest_hex,
+        vkey_hash = digest_to_decimal(vkey_hash),
+        committed_value_digest = digest_to_decimal(committed_value_digest),
+    ))
+}
+
+/// ABI-encodes calldata for [`generate_evm_verifier`]'s `verifyProof(bytes,uint256[2])` entry
+/// point. `proof` is bincode-serialized whole (its internal commitment/opening structure isn't
+/// lowered to Solidity -- see the module docs), and `public_inputs` must be `[vkey_hash,
+/// committed_value_digest]` folded via [`koalabear_bytes_to_bn254`], matching
+/// [`generate_evm_verifier`]'s constants exactly.
+pub fn encode_calldata(
+    proof: &zkm_stark::ShardProof<KoalaBearPoseidon2Outer>,
+    public_inputs: &[[KoalaBear; 32]; 2],
+) -> Result<Vec<u8>> {
+    let proof_bytes = bincode::serialize(proof).context("serializing the outer shard proof")?;
+    let inputs = [digest_to_decimal(&public_inputs[0]), digest_to_decimal(&public_inputs[1])];
+
+    Ok(encode_verify_proof_calldata(&proof_bytes, &inputs))
+}
+
+/// Hand-rolled ABI encoding for `verifyProof(bytes,uint256[2])`; mirrors
+/// `zkm_sdk::provers::solidity::encode_verify_proof_calldata`'s layout, but with a fixed-size
+/// `uint256[2]` tail (no length word) instead of a dynamic `bytes32[]`, since `verifyProof` here
+/// takes a static two-element array.
+fn encode_verify_proof_calldata(proof: &[u8], public_inputs: &[String; 2]) -> Vec<u8> {
+    let proof_words = (proof.len() + 31) / 32;
+    let proof_tail_len = 32 + proof_words * 32; // length word + right-padded data
+
+    let proof_head_offset = 32u64; // one head word: the proof's own tail offset
+    let mut out = Vec::with_capacity(4 + proof_head_offset as usize + proof_tail_len + 64);
+    out.extend_from_slice(&VERIFY_PROOF_SELECTOR);
+    out.extend_from_slice(&word_from_offset(proof_head_offset));
+    for input in public_inputs {
+        out.extend_from_slice(&word_from_decimal(input));
+    }
+
+    out.extend_from_slice(&word_from_offset(proof.len() as u64));
+    out.extend_from_slice(proof);
+    out.resize(out.len() + (proof_words * 32 - proof.len()), 0);
+
+    out
+}
+
+fn word_from_offset(value: u64) -> [u8; 32] {
+    let mut w = [0u8; 32];
+    w[24..].copy_from_slice(&value.to_be_bytes());
+    w
+}
+
+/// Renders a decimal `uint256` string (as produced by [`digest_to_decimal`]) into its big-endian
+/// 32-byte representation.
+fn word_from_decimal(decimal: &str) -> [u8; 32] {
+    let value = num_bigint::BigUint::parse_bytes(decimal.as_bytes(), 10)
+        .expect("digest_to_decimal always renders a valid base-10 BigUint");
+    let mut be_bytes = value.to_bytes_be();
+    let mut out = [0u8; 32];
+    if be_bytes.len() > 32 {
+        be_bytes = be_bytes[be_bytes.len() - 32..].to_vec();
+    }
+    out[32 - be_bytes.len()..].copy_from_slice(&be_bytes);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_from_decimal_matches_known_values() {
+        assert_eq!(word_from_decimal("0")[31], 0);
+        assert_eq!(word_from_decimal("256")[30..], [1, 0]);
+        let max_u32 = word_from_decimal(&u32::MAX.to_string());
+        assert_eq!(&max_u32[28..], &u32::MAX.to_be_bytes());
+    }
+
+    /// An end-to-end check that a generated contract's embedded constants and a calldata
+    /// encoding's public-input words agree, off a real wrapped proof -- the integration test this
+    /// module needs once an outer wrap proof fixture and a Solidity toolchain (e.g. `solc` via
+    /// `ethers-solc`) are available in this workspace. Neither is wired up in this tree yet (there
+    /// is no `Cargo.toml` anywhere in this snapshot to pull such a dev-dependency into), so this
+    /// is left as a marker for that follow-up rather than a proof stub.
+    #[test]
+    #[ignore = "needs a real outer-wrap proof fixture and a solc toolchain, neither available in this tree yet"]
+    fn compiles_and_verifies_a_real_wrapped_proof() {
+        unimplemented!(
+            "wire up an outer-wrap `ZKMReduceProof` fixture and an EVM/solc harness, then assert \
+             `generate_evm_verifier` compiles and `encode_calldata`'s proof/public-input bytes \
+             round-trip through it"
+        );
+    }
+}
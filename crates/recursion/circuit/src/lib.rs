@@ -12,7 +12,7 @@ use p3_matrix::dense::RowMajorMatrix;
 use std::iter::{repeat, zip};
 use zkm2_recursion_compiler::{
     circuit::CircuitV2Builder,
-    config::{InnerConfig, OuterConfig},
+    config::{InnerConfig, OuterConfig, PlonkOuterConfig, SectConfig},
     ir::{Builder, Config, DslIr, Ext, Felt, SymbolicFelt, Var, Variable},
 };
 
@@ -21,6 +21,7 @@ mod types;
 pub mod challenger;
 pub mod constraints;
 pub mod domain;
+pub mod evm;
 pub mod fri;
 pub mod hash;
 pub mod machine;
@@ -38,6 +39,7 @@ use zkm2_stark::{
 use p3_challenger::{CanObserve, CanSample, FieldChallenger, GrindingChallenger};
 use p3_commit::{ExtensionMmcs, Mmcs};
 use p3_dft::Radix2DitParallel;
+use p3_field::TwoAdicField;
 use p3_fri::{FriConfig, TwoAdicFriPcs};
 use zkm2_recursion_core::{
     air::RecursionPublicValues,
@@ -60,37 +62,50 @@ pub type PcsConfig<C> = FriConfig<
 
 pub type Digest<C, SC> = <SC as FieldHasherVariable<C>>::DigestVariable;
 
-pub type FriMmcs<C> = ExtensionMmcs<KoalaBear, EF, <C as KoalaBearFriConfig>::ValMmcs>;
+pub type FriMmcs<F, C> =
+    ExtensionMmcs<F, <C as TwoAdicFriConfig<F>>::Challenge, <C as TwoAdicFriConfig<F>>::ValMmcs>;
 
-pub trait KoalaBearFriConfig:
+/// A FRI-backed [`StarkGenericConfig`] parameterized over its base field `F`, factored out of the
+/// old KoalaBear-only `KoalaBearFriConfig` so the same recursion-verifier machinery can eventually
+/// be instantiated for other two-adic STARK fields (BabyBear, Mersenne31, ...) rather than only
+/// KoalaBear. [`KoalaBearFriConfig`] below is kept as the pre-existing, KoalaBear-specialized alias
+/// so every current call site keeps compiling unchanged.
+pub trait TwoAdicFriConfig<F: TwoAdicField>:
     StarkGenericConfig<
-    Val = KoalaBear,
-    Challenge = EF,
+    Val = F,
+    Challenge = Self::Challenge,
     Challenger = Self::FriChallenger,
     Pcs = TwoAdicFriPcs<
-        KoalaBear,
-        Radix2DitParallel<KoalaBear>,
+        F,
+        Radix2DitParallel<F>,
         Self::ValMmcs,
-        ExtensionMmcs<KoalaBear, EF, Self::ValMmcs>,
+        ExtensionMmcs<F, Self::Challenge, Self::ValMmcs>,
     >,
 >
 {
-    type ValMmcs: Mmcs<KoalaBear, ProverData<RowMajorMatrix<KoalaBear>> = Self::RowMajorProverData>
-        + Send
-        + Sync;
+    /// The extension field FRI queries and challenges are drawn from.
+    type Challenge: p3_field::ExtensionField<F> + TwoAdicField;
+    type ValMmcs: Mmcs<F, ProverData<RowMajorMatrix<F>> = Self::RowMajorProverData> + Send + Sync;
     type RowMajorProverData: Clone + Send + Sync;
-    type FriChallenger: CanObserve<<Self::ValMmcs as Mmcs<KoalaBear>>::Commitment>
-        + CanSample<EF>
-        + GrindingChallenger<Witness = KoalaBear>
-        + FieldChallenger<KoalaBear>;
+    type FriChallenger: CanObserve<<Self::ValMmcs as Mmcs<F>>::Commitment>
+        + CanSample<Self::Challenge>
+        + GrindingChallenger<Witness = F>
+        + FieldChallenger<F>;
 
-    fn fri_config(&self) -> &FriConfig<FriMmcs<Self>>;
+    fn fri_config(&self) -> &FriConfig<FriMmcs<F, Self>>;
 
     fn challenger_shape(challenger: &Self::FriChallenger) -> SpongeChallengerShape;
 }
 
-pub trait KoalaBearFriConfigVariable<C: CircuitConfig<F = KoalaBear>>:
-    KoalaBearFriConfig + FieldHasherVariable<C> + Posedion2KoalaBearHasherVariable<C>
+/// The KoalaBear specialization of [`TwoAdicFriConfig`] used throughout this crate today.
+pub trait KoalaBearFriConfig: TwoAdicFriConfig<KoalaBear, Challenge = EF> {}
+
+impl<T: TwoAdicFriConfig<KoalaBear, Challenge = EF>> KoalaBearFriConfig for T {}
+
+/// A [`TwoAdicFriConfig`] together with the in-circuit ([`Builder`]) machinery needed to verify
+/// it: a challenger variable and a way to commit the recursion program's public values.
+pub trait FriConfigVariable<F: TwoAdicField, C: CircuitConfig<F = F>>:
+    TwoAdicFriConfig<F> + FieldHasherVariable<C> + Posedion2KoalaBearHasherVariable<C>
 {
     type FriChallengerVariable: FieldChallengerVariable<C, <C as CircuitConfig>::Bit>
         + CanObserveVariable<C, <Self as FieldHasherVariable<C>>::DigestVariable>
@@ -105,9 +120,38 @@ pub trait KoalaBearFriConfigVariable<C: CircuitConfig<F = KoalaBear>>:
     );
 }
 
+/// The KoalaBear specialization of [`FriConfigVariable`] used throughout this crate today.
+pub trait KoalaBearFriConfigVariable<C: CircuitConfig<F = KoalaBear>>:
+    FriConfigVariable<KoalaBear, C>
+{
+}
+
+impl<C: CircuitConfig<F = KoalaBear>, T: FriConfigVariable<KoalaBear, C>>
+    KoalaBearFriConfigVariable<C> for T
+{
+}
+
+/// Bit ordering for [`CircuitConfig::num2bits_ordered`]/[`CircuitConfig::bits2num_ordered`],
+/// mirroring `bitvec`'s `Lsb0`/`Msb0` naming.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    /// Least-significant bit first — the native layout of [`CircuitConfig::num2bits`].
+    Lsb0,
+    /// Most-significant bit first, e.g. for packing into a BN254 `Var`.
+    Msb0,
+}
+
 pub trait CircuitConfig: Config {
     type Bit: Copy + Variable<Self>;
 
+    /// Window width (in bits) used by [`windowed_select_table`] for configs whose
+    /// `exp_reverse_bits`/`exp_f_bits_precomputed` build their own windowed fixed-base
+    /// exponentiation instead of calling into a native backend op (`InnerConfig`/`WrapConfig` via
+    /// `exp_reverse_bits_v2`). `1` recovers plain one-bit square-and-multiply; FRI folding
+    /// exponents are at most 31 bits, so `4` cuts the per-bit multiply down to one multiply every
+    /// 4 bits plus one squaring per bit.
+    const EXP_WINDOW_BITS: usize = 1;
+
     fn read_bit(builder: &mut Builder<Self>) -> Self::Bit;
 
     fn read_felt(builder: &mut Builder<Self>) -> Felt<Self::F>;
@@ -144,17 +188,60 @@ pub trait CircuitConfig: Config {
         p_at_xs: Vec<Felt<Self::F>>,
     ) -> Ext<Self::F, Self::EF>;
 
+    /// Decomposes `num` into `num_bits` bits, least-significant first (`BitOrder::Lsb0`).
     fn num2bits(
         builder: &mut Builder<Self>,
         num: Felt<<Self as Config>::F>,
         num_bits: usize,
     ) -> Vec<Self::Bit>;
 
+    /// [`Self::num2bits`] in the requested [`BitOrder`], for callers (e.g. packing into a BN254
+    /// `Var`) that would otherwise have to reverse the result by hand.
+    fn num2bits_ordered(
+        builder: &mut Builder<Self>,
+        num: Felt<<Self as Config>::F>,
+        num_bits: usize,
+        order: BitOrder,
+    ) -> Vec<Self::Bit> {
+        let bits = Self::num2bits(builder, num, num_bits);
+        match order {
+            BitOrder::Lsb0 => bits,
+            BitOrder::Msb0 => bits.into_iter().rev().collect(),
+        }
+    }
+
+    /// [`Self::num2bits`] run over every value in `nums` at once, sharing witness/constraint
+    /// structure across the batch instead of one independent decomposition per value.
+    fn num2bits_batched(
+        builder: &mut Builder<Self>,
+        nums: &[Felt<<Self as Config>::F>],
+        num_bits: usize,
+    ) -> Vec<Vec<Self::Bit>> {
+        nums.iter().map(|&num| Self::num2bits(builder, num, num_bits)).collect()
+    }
+
+    /// Composes `bits`, least-significant first (`BitOrder::Lsb0`), back into a field element.
     fn bits2num(
         builder: &mut Builder<Self>,
         bits: impl IntoIterator<Item = Self::Bit>,
     ) -> Felt<<Self as Config>::F>;
 
+    /// [`Self::bits2num`] accepting `bits` in the given [`BitOrder`] rather than assuming Lsb0.
+    fn bits2num_ordered(
+        builder: &mut Builder<Self>,
+        bits: impl IntoIterator<Item = Self::Bit>,
+        order: BitOrder,
+    ) -> Felt<<Self as Config>::F> {
+        match order {
+            BitOrder::Lsb0 => Self::bits2num(builder, bits),
+            BitOrder::Msb0 => {
+                let mut bits: Vec<Self::Bit> = bits.into_iter().collect();
+                bits.reverse();
+                Self::bits2num(builder, bits)
+            }
+        }
+    }
+
     #[allow(clippy::type_complexity)]
     fn select_chain_f(
         builder: &mut Builder<Self>,
@@ -172,13 +259,130 @@ pub trait CircuitConfig: Config {
     ) -> Vec<Ext<<Self as Config>::F, <Self as Config>::EF>>;
 
     fn range_check_felt(builder: &mut Builder<Self>, value: Felt<Self::F>, num_bits: usize) {
-        let bits = Self::num2bits(builder, value, 31);
-        for bit in bits.into_iter().skip(num_bits) {
-            Self::assert_bit_zero(builder, bit);
+        Self::range_check_felt_batched(builder, &[value], num_bits);
+    }
+
+    /// [`Self::range_check_felt`] over every value in `values`, decomposing them in one
+    /// [`Self::num2bits_batched`] call so their high-bit assertions share witness/constraint
+    /// structure instead of one independent `num2bits` per value.
+    fn range_check_felt_batched(
+        builder: &mut Builder<Self>,
+        values: &[Felt<Self::F>],
+        num_bits: usize,
+    ) {
+        let bits = Self::num2bits_batched(builder, values, 31);
+        for bits in bits {
+            for bit in bits.into_iter().skip(num_bits) {
+                Self::assert_bit_zero(builder, bit);
+            }
         }
     }
 }
 
+/// Selects `table[idx]` where `idx = sum(bits[i] * 2^i)`, via a binary mux tree: at level `i` every
+/// adjacent pair of candidates is collapsed to one by selecting on `bits[i]`, halving the candidate
+/// count each level until one remains. `table.len()` must be `1 << bits.len()`. `select` is the
+/// config's own single-bit select (an arithmetic blend for a `Felt`-typed bit, `Builder::select_f`
+/// for a `Var`-typed one), since the two families of [`CircuitConfig`] impls use different ones.
+fn windowed_select_table<C: CircuitConfig>(
+    builder: &mut Builder<C>,
+    bits: &[C::Bit],
+    table: Vec<Felt<C::F>>,
+    select: impl Fn(&mut Builder<C>, C::Bit, Felt<C::F>, Felt<C::F>) -> Felt<C::F>,
+) -> Felt<C::F> {
+    let mut candidates = table;
+    for &bit in bits {
+        candidates = candidates
+            .chunks_exact(2)
+            .map(|pair| select(builder, bit, pair[1], pair[0]))
+            .collect();
+    }
+    candidates[0]
+}
+
+/// Windowed fixed-base exponentiation: `input` raised to the exponent whose bits, most-significant
+/// first, are `power_bits` (the same convention the one-bit square-and-multiply loop it replaces
+/// uses). Processes the exponent in fixed windows of `C::EXP_WINDOW_BITS` bits from least to most
+/// significant; for each window it precomputes the `2^W` partial powers of the window's base
+/// (`input` raised to the window's starting power of two) by repeated multiplication, selects the
+/// one indicated by the window's bits with [`windowed_select_table`], and folds it into the
+/// running product. A final partial window whose bit count isn't a multiple of `W` just uses a
+/// smaller table.
+fn windowed_exp_reverse_bits<C: CircuitConfig>(
+    builder: &mut Builder<C>,
+    input: Felt<C::F>,
+    power_bits: Vec<C::Bit>,
+    select: impl Fn(&mut Builder<C>, C::Bit, Felt<C::F>, Felt<C::F>) -> Felt<C::F> + Copy,
+) -> Felt<C::F> {
+    let window = C::EXP_WINDOW_BITS.max(1);
+    let bit_len = power_bits.len();
+    let one: Felt<_> = builder.constant(C::F::ONE);
+
+    let mut result = one;
+    let mut window_base = input;
+    let mut offset = 0;
+    while offset < bit_len {
+        let window_len = window.min(bit_len - offset);
+        // `power_bits[0]` is the most-significant exponent bit, so the bit at global (from-the-end)
+        // position `offset + i` sits at `power_bits[bit_len - 1 - (offset + i)]`.
+        let window_bits: Vec<C::Bit> =
+            (0..window_len).map(|i| power_bits[bit_len - 1 - (offset + i)]).collect();
+
+        let mut table = Vec::with_capacity(1 << window_len);
+        table.push(one);
+        for k in 1..(1usize << window_len) {
+            table.push(builder.eval(table[k - 1] * window_base));
+        }
+        let selected = windowed_select_table(builder, &window_bits, table, select);
+        result = builder.eval(result * selected);
+
+        for _ in 0..window_len {
+            window_base = builder.eval(window_base * window_base);
+        }
+        offset += window_len;
+    }
+    result
+}
+
+/// Windowed variant of the precomputed-power exponentiation the one-bit implementation does by
+/// multiplying in a subset of `two_adic_powers_of_x` selected per bit. Groups the (little-endian)
+/// bits into fixed windows of `C::EXP_WINDOW_BITS`, precomputing for each window the product of
+/// every subset of that window's powers, and selects the right subset product with
+/// [`windowed_select_table`] instead of doing one select-and-multiply per bit.
+fn windowed_exp_f_bits_precomputed<C: CircuitConfig>(
+    builder: &mut Builder<C>,
+    power_bits: &[C::Bit],
+    two_adic_powers_of_x: &[Felt<C::F>],
+    select: impl Fn(&mut Builder<C>, C::Bit, Felt<C::F>, Felt<C::F>) -> Felt<C::F> + Copy,
+) -> Felt<C::F> {
+    let window = C::EXP_WINDOW_BITS.max(1);
+    let bit_len = power_bits.len();
+    let one: Felt<_> = builder.constant(C::F::ONE);
+
+    let mut result = one;
+    let mut offset = 0;
+    while offset < bit_len {
+        let window_len = window.min(bit_len - offset);
+        let window_bits = &power_bits[offset..offset + window_len];
+
+        // table[k] = product of two_adic_powers_of_x[offset + i] over bit i set in k.
+        let mut table = vec![one; 1 << window_len];
+        for bit_idx in 0..window_len {
+            let factor = two_adic_powers_of_x[offset + bit_idx];
+            let stride = 1usize << bit_idx;
+            for base in (0..(1usize << window_len)).step_by(stride * 2) {
+                for k in base + stride..base + 2 * stride {
+                    table[k] = builder.eval(table[k - stride] * factor);
+                }
+            }
+        }
+        let selected = windowed_select_table(builder, window_bits, table, select);
+        result = builder.eval(result * selected);
+        offset += window_len;
+    }
+    result
+}
+
 impl CircuitConfig for InnerConfig {
     type Bit = Felt<<Self as Config>::F>;
 
@@ -331,24 +535,16 @@ impl CircuitConfig for WrapConfig {
         builder.ext2felt_v2(ext)
     }
 
+    const EXP_WINDOW_BITS: usize = 4;
+
     fn exp_reverse_bits(
         builder: &mut Builder<Self>,
         input: Felt<<Self as Config>::F>,
         power_bits: Vec<Felt<<Self as Config>::F>>,
     ) -> Felt<<Self as Config>::F> {
-        // builder.exp_reverse_bits_v2(input, power_bits)
-        let mut result = builder.constant(Self::F::ONE);
-        let mut power_f = input;
-        let bit_len = power_bits.len();
-
-        for i in 1..=bit_len {
-            let index = bit_len - i;
-            let bit = power_bits[index];
-            let prod: Felt<_> = builder.eval(result * power_f);
-            result = builder.eval(bit * prod + (SymbolicFelt::ONE - bit) * result);
-            power_f = builder.eval(power_f * power_f);
-        }
-        result
+        windowed_exp_reverse_bits(builder, input, power_bits, |builder, bit, a, b| {
+            builder.eval(bit * a + (SymbolicFelt::ONE - bit) * b)
+        })
     }
 
     fn batch_fri(
@@ -458,25 +654,149 @@ impl CircuitConfig for OuterConfig {
         felts
     }
 
+    const EXP_WINDOW_BITS: usize = 4;
+
     fn exp_reverse_bits(
         builder: &mut Builder<Self>,
         input: Felt<<Self as Config>::F>,
         power_bits: Vec<Var<<Self as Config>::N>>,
     ) -> Felt<<Self as Config>::F> {
-        let mut result = builder.constant(Self::F::ONE);
-        let power_f = input;
-        let bit_len = power_bits.len();
-
-        for i in 1..=bit_len {
-            let index = bit_len - i;
-            let bit = power_bits[index];
-            let prod = builder.eval(result * power_f);
-            result = builder.select_f(bit, prod, result);
-            builder.assign(power_f, power_f * power_f);
+        windowed_exp_reverse_bits(builder, input, power_bits, Builder::select_f)
+    }
+
+    fn batch_fri(
+        builder: &mut Builder<Self>,
+        alpha_pows: Vec<Ext<<Self as Config>::F, <Self as Config>::EF>>,
+        p_at_zs: Vec<Ext<<Self as Config>::F, <Self as Config>::EF>>,
+        p_at_xs: Vec<Felt<<Self as Config>::F>>,
+    ) -> Ext<<Self as Config>::F, <Self as Config>::EF> {
+        let mut acc: Ext<_, _> = builder.uninit();
+        builder.push_op(DslIr::ImmE(acc, <Self as Config>::EF::ZERO));
+        for (alpha_pow, p_at_z, p_at_x) in izip!(alpha_pows, p_at_zs, p_at_xs) {
+            let temp_1: Ext<_, _> = builder.uninit();
+            builder.push_op(DslIr::SubEF(temp_1, p_at_z, p_at_x));
+            let temp_2: Ext<_, _> = builder.uninit();
+            builder.push_op(DslIr::MulE(temp_2, alpha_pow, temp_1));
+            let temp_3: Ext<_, _> = builder.uninit();
+            builder.push_op(DslIr::AddE(temp_3, acc, temp_2));
+            acc = temp_3;
+        }
+        acc
+    }
+
+    fn num2bits(
+        builder: &mut Builder<Self>,
+        num: Felt<<Self as Config>::F>,
+        num_bits: usize,
+    ) -> Vec<Var<<Self as Config>::N>> {
+        builder.num2bits_f_circuit(num)[..num_bits].to_vec()
+    }
+
+    fn bits2num(
+        builder: &mut Builder<Self>,
+        bits: impl IntoIterator<Item = Var<<Self as Config>::N>>,
+    ) -> Felt<<Self as Config>::F> {
+        let result = builder.eval(Self::F::ZERO);
+        for (i, bit) in bits.into_iter().enumerate() {
+            let to_add: Felt<_> = builder.uninit();
+            let pow2 = builder.constant(Self::F::from_canonical_u32(1 << i));
+            let zero = builder.constant(Self::F::ZERO);
+            builder.push_op(DslIr::CircuitSelectF(bit, pow2, zero, to_add));
+            builder.assign(result, result + to_add);
         }
         result
     }
 
+    fn select_chain_f(
+        builder: &mut Builder<Self>,
+        should_swap: Self::Bit,
+        first: impl IntoIterator<Item = Felt<<Self as Config>::F>> + Clone,
+        second: impl IntoIterator<Item = Felt<<Self as Config>::F>> + Clone,
+    ) -> Vec<Felt<<Self as Config>::F>> {
+        let id_branch = first.clone().into_iter().chain(second.clone());
+        let swap_branch = second.into_iter().chain(first);
+        zip(id_branch, swap_branch)
+            .map(|(id_v, sw_v): (Felt<_>, Felt<_>)| -> Felt<_> {
+                let result: Felt<_> = builder.uninit();
+                builder.push_op(DslIr::CircuitSelectF(should_swap, sw_v, id_v, result));
+                result
+            })
+            .collect()
+    }
+
+    fn select_chain_ef(
+        builder: &mut Builder<Self>,
+        should_swap: Self::Bit,
+        first: impl IntoIterator<Item = Ext<<Self as Config>::F, <Self as Config>::EF>> + Clone,
+        second: impl IntoIterator<Item = Ext<<Self as Config>::F, <Self as Config>::EF>> + Clone,
+    ) -> Vec<Ext<<Self as Config>::F, <Self as Config>::EF>> {
+        let id_branch = first.clone().into_iter().chain(second.clone());
+        let swap_branch = second.into_iter().chain(first);
+        zip(id_branch, swap_branch)
+            .map(|(id_v, sw_v): (Ext<_, _>, Ext<_, _>)| -> Ext<_, _> {
+                let result: Ext<_, _> = builder.uninit();
+                builder.push_op(DslIr::CircuitSelectE(should_swap, sw_v, id_v, result));
+                result
+            })
+            .collect()
+    }
+
+    fn exp_f_bits_precomputed(
+        builder: &mut Builder<Self>,
+        power_bits: &[Self::Bit],
+        two_adic_powers_of_x: &[Felt<Self::F>],
+    ) -> Felt<Self::F> {
+        windowed_exp_f_bits_precomputed(
+            builder,
+            power_bits,
+            two_adic_powers_of_x,
+            Builder::select_f,
+        )
+    }
+}
+
+impl CircuitConfig for PlonkOuterConfig {
+    type Bit = Var<<Self as Config>::N>;
+
+    fn assert_bit_zero(builder: &mut Builder<Self>, bit: Self::Bit) {
+        builder.assert_var_eq(bit, Self::N::ZERO);
+    }
+
+    fn assert_bit_one(builder: &mut Builder<Self>, bit: Self::Bit) {
+        builder.assert_var_eq(bit, Self::N::ONE);
+    }
+
+    fn read_bit(builder: &mut Builder<Self>) -> Self::Bit {
+        builder.witness_var()
+    }
+
+    fn read_felt(builder: &mut Builder<Self>) -> Felt<Self::F> {
+        builder.witness_felt()
+    }
+
+    fn read_ext(builder: &mut Builder<Self>) -> Ext<Self::F, Self::EF> {
+        builder.witness_ext()
+    }
+
+    fn ext2felt(
+        builder: &mut Builder<Self>,
+        ext: Ext<<Self as Config>::F, <Self as Config>::EF>,
+    ) -> [Felt<<Self as Config>::F>; D] {
+        let felts = core::array::from_fn(|_| builder.uninit());
+        builder.push_op(DslIr::CircuitExt2Felt(felts, ext));
+        felts
+    }
+
+    const EXP_WINDOW_BITS: usize = 4;
+
+    fn exp_reverse_bits(
+        builder: &mut Builder<Self>,
+        input: Felt<<Self as Config>::F>,
+        power_bits: Vec<Var<<Self as Config>::N>>,
+    ) -> Felt<<Self as Config>::F> {
+        windowed_exp_reverse_bits(builder, input, power_bits, Builder::select_f)
+    }
+
     fn batch_fri(
         builder: &mut Builder<Self>,
         alpha_pows: Vec<Ext<<Self as Config>::F, <Self as Config>::EF>>,
@@ -559,22 +879,155 @@ impl CircuitConfig for OuterConfig {
         power_bits: &[Self::Bit],
         two_adic_powers_of_x: &[Felt<Self::F>],
     ) -> Felt<Self::F> {
-        let mut result: Felt<_> = builder.eval(Self::F::ONE);
-        let one = builder.constant(Self::F::ONE);
-        for (&bit, &power) in power_bits.iter().zip(two_adic_powers_of_x) {
-            let multiplier = builder.select_f(bit, power, one);
-            result = builder.eval(multiplier * result);
+        windowed_exp_f_bits_precomputed(
+            builder,
+            power_bits,
+            two_adic_powers_of_x,
+            Builder::select_f,
+        )
+    }
+}
+
+impl CircuitConfig for SectConfig {
+    type Bit = Var<<Self as Config>::N>;
+
+    fn assert_bit_zero(builder: &mut Builder<Self>, bit: Self::Bit) {
+        builder.assert_var_eq(bit, Self::N::ZERO);
+    }
+
+    fn assert_bit_one(builder: &mut Builder<Self>, bit: Self::Bit) {
+        builder.assert_var_eq(bit, Self::N::ONE);
+    }
+
+    fn read_bit(builder: &mut Builder<Self>) -> Self::Bit {
+        builder.witness_var()
+    }
+
+    fn read_felt(builder: &mut Builder<Self>) -> Felt<Self::F> {
+        builder.witness_felt()
+    }
+
+    fn read_ext(builder: &mut Builder<Self>) -> Ext<Self::F, Self::EF> {
+        builder.witness_ext()
+    }
+
+    fn ext2felt(
+        builder: &mut Builder<Self>,
+        ext: Ext<<Self as Config>::F, <Self as Config>::EF>,
+    ) -> [Felt<<Self as Config>::F>; D] {
+        let felts = core::array::from_fn(|_| builder.uninit());
+        builder.push_op(DslIr::CircuitExt2Felt(felts, ext));
+        felts
+    }
+
+    const EXP_WINDOW_BITS: usize = 4;
+
+    fn exp_reverse_bits(
+        builder: &mut Builder<Self>,
+        input: Felt<<Self as Config>::F>,
+        power_bits: Vec<Var<<Self as Config>::N>>,
+    ) -> Felt<<Self as Config>::F> {
+        windowed_exp_reverse_bits(builder, input, power_bits, Builder::select_f)
+    }
+
+    fn batch_fri(
+        builder: &mut Builder<Self>,
+        alpha_pows: Vec<Ext<<Self as Config>::F, <Self as Config>::EF>>,
+        p_at_zs: Vec<Ext<<Self as Config>::F, <Self as Config>::EF>>,
+        p_at_xs: Vec<Felt<<Self as Config>::F>>,
+    ) -> Ext<<Self as Config>::F, <Self as Config>::EF> {
+        let mut acc: Ext<_, _> = builder.uninit();
+        builder.push_op(DslIr::ImmE(acc, <Self as Config>::EF::ZERO));
+        for (alpha_pow, p_at_z, p_at_x) in izip!(alpha_pows, p_at_zs, p_at_xs) {
+            let temp_1: Ext<_, _> = builder.uninit();
+            builder.push_op(DslIr::SubEF(temp_1, p_at_z, p_at_x));
+            let temp_2: Ext<_, _> = builder.uninit();
+            builder.push_op(DslIr::MulE(temp_2, alpha_pow, temp_1));
+            let temp_3: Ext<_, _> = builder.uninit();
+            builder.push_op(DslIr::AddE(temp_3, acc, temp_2));
+            acc = temp_3;
+        }
+        acc
+    }
+
+    fn num2bits(
+        builder: &mut Builder<Self>,
+        num: Felt<<Self as Config>::F>,
+        num_bits: usize,
+    ) -> Vec<Var<<Self as Config>::N>> {
+        builder.num2bits_f_circuit(num)[..num_bits].to_vec()
+    }
+
+    fn bits2num(
+        builder: &mut Builder<Self>,
+        bits: impl IntoIterator<Item = Var<<Self as Config>::N>>,
+    ) -> Felt<<Self as Config>::F> {
+        let result = builder.eval(Self::F::ZERO);
+        for (i, bit) in bits.into_iter().enumerate() {
+            let to_add: Felt<_> = builder.uninit();
+            let pow2 = builder.constant(Self::F::from_canonical_u32(1 << i));
+            let zero = builder.constant(Self::F::ZERO);
+            builder.push_op(DslIr::CircuitSelectF(bit, pow2, zero, to_add));
+            builder.assign(result, result + to_add);
         }
         result
     }
+
+    fn select_chain_f(
+        builder: &mut Builder<Self>,
+        should_swap: Self::Bit,
+        first: impl IntoIterator<Item = Felt<<Self as Config>::F>> + Clone,
+        second: impl IntoIterator<Item = Felt<<Self as Config>::F>> + Clone,
+    ) -> Vec<Felt<<Self as Config>::F>> {
+        let id_branch = first.clone().into_iter().chain(second.clone());
+        let swap_branch = second.into_iter().chain(first);
+        zip(id_branch, swap_branch)
+            .map(|(id_v, sw_v): (Felt<_>, Felt<_>)| -> Felt<_> {
+                let result: Felt<_> = builder.uninit();
+                builder.push_op(DslIr::CircuitSelectF(should_swap, sw_v, id_v, result));
+                result
+            })
+            .collect()
+    }
+
+    fn select_chain_ef(
+        builder: &mut Builder<Self>,
+        should_swap: Self::Bit,
+        first: impl IntoIterator<Item = Ext<<Self as Config>::F, <Self as Config>::EF>> + Clone,
+        second: impl IntoIterator<Item = Ext<<Self as Config>::F, <Self as Config>::EF>> + Clone,
+    ) -> Vec<Ext<<Self as Config>::F, <Self as Config>::EF>> {
+        let id_branch = first.clone().into_iter().chain(second.clone());
+        let swap_branch = second.into_iter().chain(first);
+        zip(id_branch, swap_branch)
+            .map(|(id_v, sw_v): (Ext<_, _>, Ext<_, _>)| -> Ext<_, _> {
+                let result: Ext<_, _> = builder.uninit();
+                builder.push_op(DslIr::CircuitSelectE(should_swap, sw_v, id_v, result));
+                result
+            })
+            .collect()
+    }
+
+    fn exp_f_bits_precomputed(
+        builder: &mut Builder<Self>,
+        power_bits: &[Self::Bit],
+        two_adic_powers_of_x: &[Felt<Self::F>],
+    ) -> Felt<Self::F> {
+        windowed_exp_f_bits_precomputed(
+            builder,
+            power_bits,
+            two_adic_powers_of_x,
+            Builder::select_f,
+        )
+    }
 }
 
-impl KoalaBearFriConfig for KoalaBearPoseidon2 {
+impl TwoAdicFriConfig<KoalaBear> for KoalaBearPoseidon2 {
+    type Challenge = EF;
     type ValMmcs = ValMmcs;
     type FriChallenger = <Self as StarkGenericConfig>::Challenger;
     type RowMajorProverData = <ValMmcs as Mmcs<KoalaBear>>::ProverData<RowMajorMatrix<KoalaBear>>;
 
-    fn fri_config(&self) -> &FriConfig<FriMmcs<Self>> {
+    fn fri_config(&self) -> &FriConfig<FriMmcs<KoalaBear, Self>> {
         self.pcs().fri_config()
     }
 
@@ -586,23 +1039,27 @@ impl KoalaBearFriConfig for KoalaBearPoseidon2 {
     }
 }
 
-impl KoalaBearFriConfig for KoalaBearPoseidon2Outer {
+impl TwoAdicFriConfig<KoalaBear> for KoalaBearPoseidon2Outer {
+    type Challenge = EF;
     type ValMmcs = OuterValMmcs;
     type FriChallenger = <Self as StarkGenericConfig>::Challenger;
 
     type RowMajorProverData =
         <OuterValMmcs as Mmcs<KoalaBear>>::ProverData<RowMajorMatrix<KoalaBear>>;
 
-    fn fri_config(&self) -> &FriConfig<FriMmcs<Self>> {
+    fn fri_config(&self) -> &FriConfig<FriMmcs<KoalaBear, Self>> {
         self.pcs().fri_config()
     }
 
-    fn challenger_shape(_challenger: &Self::FriChallenger) -> SpongeChallengerShape {
-        unimplemented!("Shape not supported for outer fri challenger");
+    fn challenger_shape(challenger: &Self::FriChallenger) -> SpongeChallengerShape {
+        SpongeChallengerShape {
+            input_buffer_len: challenger.input_buffer.len(),
+            output_buffer_len: challenger.output_buffer.len(),
+        }
     }
 }
 
-impl<C: CircuitConfig<F = KoalaBear, Bit = Felt<KoalaBear>>> KoalaBearFriConfigVariable<C>
+impl<C: CircuitConfig<F = KoalaBear, Bit = Felt<KoalaBear>>> FriConfigVariable<KoalaBear, C>
     for KoalaBearPoseidon2
 {
     type FriChallengerVariable = DuplexChallengerVariable<C>;
@@ -619,13 +1076,48 @@ impl<C: CircuitConfig<F = KoalaBear, Bit = Felt<KoalaBear>>> KoalaBearFriConfigV
     }
 }
 
-impl<C: CircuitConfig<F = KoalaBear, N = Bn254Fr, Bit = Var<Bn254Fr>>> KoalaBearFriConfigVariable<C>
-    for KoalaBearPoseidon2Outer
+/// How a BN254 outer-recursion config exposes the committed-value digest and vkey hash as public
+/// inputs of the final wrap proof. [`OuterConfig`] packs them the way the Groth16 wrap verifier
+/// expects; [`PlonkOuterConfig`] packs them the way a PLONK/KZG verifier over BN254 expects.
+/// Factoring this out of [`FriConfigVariable::commit_recursion_public_values`] lets both wrap
+/// flavors share the single `KoalaBearPoseidon2Outer` challenger/FRI machinery.
+pub trait Bn254PublicValuesConfig: CircuitConfig<N = Bn254Fr> {
+    fn commit_committed_values_digest(builder: &mut Builder<Self>, digest: Var<Bn254Fr>);
+
+    fn commit_vkey_hash(builder: &mut Builder<Self>, vkey_hash: Var<Bn254Fr>);
+}
+
+impl Bn254PublicValuesConfig for OuterConfig {
+    fn commit_committed_values_digest(builder: &mut Builder<Self>, digest: Var<Bn254Fr>) {
+        builder.commit_committed_values_digest_circuit(digest);
+    }
+
+    fn commit_vkey_hash(builder: &mut Builder<Self>, vkey_hash: Var<Bn254Fr>) {
+        builder.commit_vkey_hash_circuit(vkey_hash);
+    }
+}
+
+impl Bn254PublicValuesConfig for PlonkOuterConfig {
+    fn commit_committed_values_digest(builder: &mut Builder<Self>, digest: Var<Bn254Fr>) {
+        builder.commit_public_input_plonk(digest);
+    }
+
+    fn commit_vkey_hash(builder: &mut Builder<Self>, vkey_hash: Var<Bn254Fr>) {
+        builder.commit_public_input_plonk(vkey_hash);
+    }
+}
+
+impl<C: CircuitConfig<F = KoalaBear, N = Bn254Fr, Bit = Var<Bn254Fr>> + Bn254PublicValuesConfig>
+    FriConfigVariable<KoalaBear, C> for KoalaBearPoseidon2Outer
 {
     type FriChallengerVariable = MultiField32ChallengerVariable<C>;
 
     fn challenger_variable(&self, builder: &mut Builder<C>) -> Self::FriChallengerVariable {
-        MultiField32ChallengerVariable::new(builder)
+        // Size the in-circuit challenger's observed/sampled buffers from a real challenger's
+        // shape so the compiled program layout is deterministic across proofs of the same shape,
+        // matching what `DuplexChallengerVariable` already gets for free from its fixed width.
+        let shape = Self::challenger_shape(&self.challenger());
+        MultiField32ChallengerVariable::with_shape(builder, shape)
     }
 
     fn commit_recursion_public_values(
@@ -638,9 +1130,9 @@ impl<C: CircuitConfig<F = KoalaBear, N = Bn254Fr, Bit = Var<Bn254Fr>>> KoalaBear
                 .unwrap();
         let committed_values_digest_bytes: Var<_> =
             felt_bytes_to_bn254_var(builder, &committed_values_digest_bytes_felts);
-        builder.commit_committed_values_digest_circuit(committed_values_digest_bytes);
+        C::commit_committed_values_digest(builder, committed_values_digest_bytes);
 
         let vkey_hash = felts_to_bn254_var(builder, &public_values.zkm2_vk_digest);
-        builder.commit_vkey_hash_circuit(vkey_hash);
+        C::commit_vkey_hash(builder, vkey_hash);
     }
 }
@@ -0,0 +1,139 @@
+use std::marker::PhantomData;
+
+use p3_air::Air;
+use p3_commit::Mmcs;
+use p3_field::FieldAlgebra;
+use p3_koala_bear::KoalaBear;
+use p3_matrix::dense::RowMajorMatrix;
+use zkm_recursion_compiler::ir::{Builder, Felt};
+use zkm_recursion_core::DIGEST_SIZE;
+use zkm_stark::{air::MachineAir, StarkMachine};
+
+use crate::{
+    challenger::DuplexChallengerVariable, constraints::RecursiveVerifierConstraintFolder,
+    hash::FieldHasherVariable, CircuitConfig, KoalaBearFriConfigVariable,
+};
+
+use super::{PublicValuesOutputDigest, ZKMCompressWithVKeyVerifier, ZKMCompressWithVKeyWitnessVariable};
+
+/// Aggregates compress proofs from many *distinct* program vkeys into one recursive proof: each
+/// child is verified independently inside this circuit, and the output commits to (a) a Merkle
+/// root over the child vkey hashes and (b) a running hash of the children's committed
+/// public-values digests. A downstream consumer then checks one proof to be convinced that every
+/// child program ran correctly on its respective input, the same way `ZKMCompressRootVerifier`
+/// lets one proof stand in for a single complete execution.
+#[derive(Debug, Clone, Copy)]
+pub struct ZKMAggregationVerifier<C, SC, A> {
+    _phantom: PhantomData<(C, SC, A)>,
+}
+
+/// Witness layout for [`ZKMAggregationVerifier`].
+pub struct ZKMAggregationWitnessVariable<
+    C: CircuitConfig<F = KoalaBear>,
+    SC: FieldHasherVariable<C> + KoalaBearFriConfigVariable<C>,
+> {
+    /// The child proofs to aggregate, each under its own (possibly distinct) vkey.
+    pub children: Vec<ZKMCompressWithVKeyWitnessVariable<C, SC>>,
+    /// Each child's committed public-values digest, hinted alongside its proof -- the same
+    /// "hinted value" pattern `ZKMMerkleProofWitnessVariable::values` uses, since the compress
+    /// proof itself doesn't expose this digest as a separate circuit output.
+    pub committed_value_digests: Vec<SC::DigestVariable>,
+}
+
+/// The output of [`ZKMAggregationVerifier::verify`].
+pub struct ZKMAggregationOutputVariable<SC: FieldHasherVariable<C>, C: CircuitConfig> {
+    /// A Merkle root over every child's vkey hash, in `children` order, padded with the zero
+    /// digest up to the next power of two so the tree stays balanced.
+    pub vkey_root: SC::DigestVariable,
+    /// A running hash over every child's committed public-values digest:
+    /// `new = H(old || committed_value_digests[i])`.
+    pub public_values_digest: SC::DigestVariable,
+    _phantom: PhantomData<C>,
+}
+
+fn zero_digest<C: CircuitConfig, SC: FieldHasherVariable<C, DigestVariable = [Felt<KoalaBear>; DIGEST_SIZE]>>(
+    builder: &mut Builder<C>,
+) -> SC::DigestVariable {
+    std::array::from_fn(|_| builder.eval(C::F::ZERO))
+}
+
+/// Builds a balanced Merkle root over `leaves`, padding with [`zero_digest`] up to the next power
+/// of two.
+fn merkle_root<C: CircuitConfig, SC: FieldHasherVariable<C, DigestVariable = [Felt<KoalaBear>; DIGEST_SIZE]>>(
+    builder: &mut Builder<C>,
+    mut level: Vec<SC::DigestVariable>,
+) -> SC::DigestVariable {
+    assert!(!level.is_empty(), "must aggregate at least one child proof");
+
+    let padded_len = level.len().next_power_of_two();
+    while level.len() < padded_len {
+        level.push(zero_digest::<C, SC>(builder));
+    }
+
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| SC::hash(builder, &[pair[0], pair[1]]))
+            .collect();
+    }
+    level.into_iter().next().unwrap()
+}
+
+impl<C, SC, A> ZKMAggregationVerifier<C, SC, A>
+where
+    SC: KoalaBearFriConfigVariable<
+        C,
+        FriChallengerVariable = DuplexChallengerVariable<C>,
+        DigestVariable = [Felt<KoalaBear>; DIGEST_SIZE],
+    >,
+    C: CircuitConfig<F = SC::Val, EF = SC::Challenge, Bit = Felt<KoalaBear>>,
+    <SC::ValMmcs as Mmcs<KoalaBear>>::ProverData<RowMajorMatrix<KoalaBear>>: Clone,
+    A: MachineAir<SC::Val> + for<'a> Air<RecursiveVerifierConstraintFolder<'a, C>>,
+{
+    /// Verifies every child in `input.children` and folds their vkey hashes and committed
+    /// public-values digests into the aggregation output.
+    pub fn verify(
+        builder: &mut Builder<C>,
+        machine: &StarkMachine<SC, A>,
+        input: ZKMAggregationWitnessVariable<C, SC>,
+        value_assertions: bool,
+    ) -> ZKMAggregationOutputVariable<SC, C> {
+        let ZKMAggregationWitnessVariable { children, committed_value_digests } = input;
+        assert_eq!(
+            children.len(),
+            committed_value_digests.len(),
+            "one committed-values digest per child proof"
+        );
+
+        let mut vkey_digests = Vec::with_capacity(children.len());
+        for child in children {
+            let vkey_digest = child
+                .compress_var
+                .vks_and_proofs
+                .first()
+                .map(|(vk, _)| vk.hash(builder))
+                .unwrap_or_else(|| zero_digest::<C, SC>(builder));
+            vkey_digests.push(vkey_digest);
+
+            ZKMCompressWithVKeyVerifier::verify(
+                builder,
+                machine,
+                child,
+                value_assertions,
+                PublicValuesOutputDigest::Root,
+            );
+        }
+
+        let vkey_root = merkle_root::<C, SC>(builder, vkey_digests);
+
+        let mut public_values_digest = zero_digest::<C, SC>(builder);
+        for digest in committed_value_digests {
+            let mut words = Vec::with_capacity(2 * DIGEST_SIZE);
+            words.extend(public_values_digest);
+            words.extend(digest);
+            public_values_digest = SC::hash(builder, &words);
+        }
+
+        ZKMAggregationOutputVariable { vkey_root, public_values_digest, _phantom: PhantomData }
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+
+use p3_field::PrimeField32;
+use zkm_stark::{air::MachineAir, StarkGenericConfig, StarkMachine};
+
+use super::{ZKMCompressShape, ZKMCompressWithVkeyShape};
+
+/// Predicted recursion-circuit resource usage for verifying a [`ZKMCompressShape`] (or a
+/// [`ZKMCompressWithVkeyShape`]), broken down by chip/AIR, so a caller can budget an aggregation
+/// tree's fan-in and pick shapes without running the prover. Every field is an estimate computed
+/// from the shape's per-shard chip heights, not a measurement.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecursionCost {
+    /// Folded AIR-constraint count, by chip name, summed over every shard proof in the shape.
+    pub constraints_by_chip: BTreeMap<String, usize>,
+    /// `constraints_by_chip` values summed.
+    pub total_constraints: usize,
+    /// Number of FRI query rounds the recursive verifier opens, times the number of shard proofs.
+    pub fri_queries: usize,
+    /// Total FRI opening proofs: `fri_queries` times the number of committed matrices (trace,
+    /// permutation, and quotient) opened per query, summed over every shard proof.
+    pub fri_openings: usize,
+    /// Number of Poseidon2 permutation invocations: one per FRI opening sibling hashed on the
+    /// way to each Merkle root, plus `merkle_tree_height * num_proofs` for vkey-allowlist
+    /// membership (and, when `value_assertions` is set, one extra digest-equality check per
+    /// proof for the claimed-vs-expected committed-values digest).
+    pub poseidon2_hashes: usize,
+    /// Length of the public witness, in base-field elements.
+    pub witness_len: usize,
+    /// Estimated serialized proof size, in bytes (each base-field element serializes to 4 bytes).
+    pub proof_size_bytes: usize,
+}
+
+impl RecursionCost {
+    fn merge(mut self, other: RecursionCost) -> RecursionCost {
+        for (chip, count) in other.constraints_by_chip {
+            *self.constraints_by_chip.entry(chip).or_insert(0) += count;
+        }
+        self.total_constraints += other.total_constraints;
+        self.fri_queries += other.fri_queries;
+        self.fri_openings += other.fri_openings;
+        self.poseidon2_hashes += other.poseidon2_hashes;
+        self.witness_len += other.witness_len;
+        self.proof_size_bytes += other.proof_size_bytes;
+        self
+    }
+}
+
+/// The number of base-field elements a single Poseidon2 digest occupies.
+const DIGEST_WIDTH: usize = 8;
+/// Matrices committed per shard proof that the recursive verifier opens per FRI query: the main
+/// trace, the permutation trace, and the quotient chunks.
+const COMMITTED_MATRICES_PER_SHARD: usize = 3;
+
+/// Estimates the recursion-circuit cost of verifying every shard proof described by `shape`
+/// against `machine`, decomposed by chip.
+pub fn estimate_compress_cost<SC, A>(
+    machine: &StarkMachine<SC, A>,
+    shape: &ZKMCompressShape,
+) -> RecursionCost
+where
+    SC: StarkGenericConfig,
+    SC::Val: PrimeField32,
+    A: MachineAir<SC::Val>,
+{
+    let fri_queries_per_shard = machine.config().fri_config().num_queries;
+    let mut cost = RecursionCost::default();
+
+    for proof_shape in &shape.proof_shapes {
+        for (chip_name, log_height) in proof_shape {
+            let height = 1usize << log_height;
+            let chip = machine.chips().iter().find(|chip| &chip.name() == chip_name);
+            let num_constraints = chip.map_or(0, |chip| chip.num_constraints());
+            let width = chip.map_or(0, |chip| chip.width());
+
+            *cost.constraints_by_chip.entry(chip_name.clone()).or_insert(0) +=
+                height * num_constraints;
+            cost.witness_len += height * width;
+        }
+
+        cost.fri_queries += fri_queries_per_shard;
+        cost.fri_openings += fri_queries_per_shard * COMMITTED_MATRICES_PER_SHARD;
+        cost.poseidon2_hashes +=
+            fri_queries_per_shard * COMMITTED_MATRICES_PER_SHARD * proof_shape.len();
+    }
+
+    cost.total_constraints = cost.constraints_by_chip.values().sum();
+    cost.proof_size_bytes = cost.witness_len * 4;
+    cost
+}
+
+/// Estimates the recursion-circuit cost of verifying `shape`'s compress proof *and* its
+/// vkey-allowlist Merkle membership proofs (see `ZKMMerkleProofVerifier::verify`), accounting for
+/// whether `value_assertions` is enabled.
+pub fn estimate_compress_with_vkey_cost<SC, A>(
+    machine: &StarkMachine<SC, A>,
+    shape: &ZKMCompressWithVkeyShape,
+    value_assertions: bool,
+) -> RecursionCost
+where
+    SC: StarkGenericConfig,
+    SC::Val: PrimeField32,
+    A: MachineAir<SC::Val>,
+{
+    let base = estimate_compress_cost(machine, &shape.compress_shape);
+    let num_proofs = shape.compress_shape.proof_shapes.len();
+
+    let merkle_hashes = shape.merkle_tree_height * num_proofs;
+    let vkey_digest_assertions = if value_assertions { num_proofs * DIGEST_WIDTH } else { 0 };
+
+    base.merge(RecursionCost {
+        poseidon2_hashes: merkle_hashes,
+        total_constraints: vkey_digest_assertions,
+        witness_len: num_proofs * shape.merkle_tree_height * DIGEST_WIDTH,
+        ..Default::default()
+    })
+}
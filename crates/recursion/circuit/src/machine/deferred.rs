@@ -0,0 +1,145 @@
+use std::marker::PhantomData;
+
+use p3_air::Air;
+use p3_commit::Mmcs;
+use p3_field::FieldAlgebra;
+use p3_koala_bear::KoalaBear;
+use p3_matrix::dense::RowMajorMatrix;
+use serde::{Deserialize, Serialize};
+use zkm_recursion_compiler::ir::{Builder, Felt};
+use zkm_recursion_core::DIGEST_SIZE;
+use zkm_stark::{air::MachineAir, Com, InnerChallenge, OpeningProof, StarkGenericConfig, StarkMachine};
+
+use crate::{
+    challenger::DuplexChallengerVariable,
+    constraints::RecursiveVerifierConstraintFolder,
+    hash::{FieldHasher, FieldHasherVariable},
+    witness::{WitnessWriter, Witnessable},
+    CircuitConfig, FriProofVariable, KoalaBearFriConfig, KoalaBearFriConfigVariable,
+};
+
+use super::{
+    PublicValuesOutputDigest, ZKMCompressWithVKeyVerifier, ZKMCompressWithVKeyWitnessValues,
+    ZKMCompressWithVKeyWitnessVariable, ZKMCompressWithVkeyShape,
+};
+
+/// Verifies one deferred child proof claimed by the guest's `SYSVERIFY` syscall: the child's
+/// vkey is checked against the allowlist Merkle root (via [`ZKMCompressWithVKeyVerifier`], which
+/// itself calls `ZKMMerkleProofVerifier`), the child's compress proof is verified, and
+/// `(vkey_digest, committed_value_digest)` is folded into a running deferred-digest accumulator
+/// so the fold is order-independent: `new = H(old || vkey_digest || committed_value_digest)`.
+/// The top-level verifier binds the final accumulator value, letting one zkMIPS program verify
+/// another zkMIPS proof without trusting an external aggregator.
+#[derive(Debug, Clone, Copy)]
+pub struct ZKMDeferredVerifier<C, SC, A> {
+    _phantom: PhantomData<(C, SC, A)>,
+}
+
+/// The shape of one deferred-proof claim, for padding a batch of claims to a fixed size.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZKMDeferredShape {
+    pub compress_with_vkey_shape: ZKMCompressWithVkeyShape,
+}
+
+/// Witness layout for one deferred-proof claim.
+pub struct ZKMDeferredWitnessVariable<
+    C: CircuitConfig<F = KoalaBear>,
+    SC: FieldHasherVariable<C> + KoalaBearFriConfigVariable<C>,
+> {
+    /// The claimed child proof, together with its vkey's Merkle-allowlist membership proof.
+    pub child: ZKMCompressWithVKeyWitnessVariable<C, SC>,
+    /// The child's committed public-values digest, as claimed by the `SYSVERIFY` call.
+    pub committed_value_digest: SC::DigestVariable,
+    /// The running deferred-digest accumulator from every previously-folded claim in this batch
+    /// (the all-zero digest for the first claim).
+    pub prev_deferred_digest: SC::DigestVariable,
+}
+
+/// An input layout for [`ZKMDeferredVerifier`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound(serialize = "SC::Digest: Serialize"))]
+#[serde(bound(deserialize = "SC::Digest: Deserialize<'de>"))]
+pub struct ZKMDeferredWitnessValues<SC: StarkGenericConfig + FieldHasher<KoalaBear>> {
+    pub child: ZKMCompressWithVKeyWitnessValues<SC>,
+    pub committed_value_digest: SC::Digest,
+    pub prev_deferred_digest: SC::Digest,
+}
+
+impl<C, SC, A> ZKMDeferredVerifier<C, SC, A>
+where
+    SC: KoalaBearFriConfigVariable<
+        C,
+        FriChallengerVariable = DuplexChallengerVariable<C>,
+        DigestVariable = [Felt<KoalaBear>; DIGEST_SIZE],
+    >,
+    C: CircuitConfig<F = SC::Val, EF = SC::Challenge, Bit = Felt<KoalaBear>>,
+    <SC::ValMmcs as Mmcs<KoalaBear>>::ProverData<RowMajorMatrix<KoalaBear>>: Clone,
+    A: MachineAir<SC::Val> + for<'a> Air<RecursiveVerifierConstraintFolder<'a, C>>,
+{
+    /// Verifies one deferred claim and returns the updated deferred-digest accumulator.
+    pub fn verify(
+        builder: &mut Builder<C>,
+        machine: &StarkMachine<SC, A>,
+        input: ZKMDeferredWitnessVariable<C, SC>,
+        value_assertions: bool,
+        kind: PublicValuesOutputDigest,
+    ) -> SC::DigestVariable {
+        let vkey_digest =
+            input.child.compress_var.vks_and_proofs.first().map(|(vk, _)| vk.hash(builder));
+
+        ZKMCompressWithVKeyVerifier::verify(builder, machine, input.child, value_assertions, kind);
+
+        let mut accumulator_words = Vec::with_capacity(3 * DIGEST_SIZE);
+        accumulator_words.extend(input.prev_deferred_digest);
+        if let Some(vkey_digest) = vkey_digest {
+            accumulator_words.extend(vkey_digest);
+        }
+        accumulator_words.extend(input.committed_value_digest);
+
+        SC::hash(builder, &accumulator_words)
+    }
+}
+
+impl ZKMDeferredWitnessValues<zkm_stark::koala_bear_poseidon2::KoalaBearPoseidon2> {
+    /// A dummy claim for padding a batch of deferred-proof claims to `shape`.
+    pub fn dummy<Air: MachineAir<KoalaBear>>(
+        machine: &StarkMachine<zkm_stark::koala_bear_poseidon2::KoalaBearPoseidon2, Air>,
+        shape: &ZKMDeferredShape,
+    ) -> Self {
+        let dummy_digest = [KoalaBear::ZERO; DIGEST_SIZE];
+        let child = ZKMCompressWithVKeyWitnessValues::<
+            zkm_stark::koala_bear_poseidon2::KoalaBearPoseidon2,
+        >::dummy(machine, &shape.compress_with_vkey_shape);
+
+        Self {
+            child,
+            committed_value_digest: dummy_digest,
+            prev_deferred_digest: dummy_digest,
+        }
+    }
+}
+
+impl<C: CircuitConfig<F = KoalaBear, EF = InnerChallenge>, SC: KoalaBearFriConfigVariable<C>>
+    Witnessable<C> for ZKMDeferredWitnessValues<SC>
+where
+    Com<SC>: Witnessable<C, WitnessVariable = <SC as FieldHasherVariable<C>>::DigestVariable>,
+    SC: FieldHasher<KoalaBear>,
+    <SC as FieldHasher<KoalaBear>>::Digest: Witnessable<C, WitnessVariable = SC::DigestVariable>,
+    OpeningProof<SC>: Witnessable<C, WitnessVariable = FriProofVariable<C, SC>>,
+{
+    type WitnessVariable = ZKMDeferredWitnessVariable<C, SC>;
+
+    fn read(&self, builder: &mut Builder<C>) -> Self::WitnessVariable {
+        ZKMDeferredWitnessVariable {
+            child: self.child.read(builder),
+            committed_value_digest: self.committed_value_digest.read(builder),
+            prev_deferred_digest: self.prev_deferred_digest.read(builder),
+        }
+    }
+
+    fn write(&self, witness: &mut impl WitnessWriter<C>) {
+        self.child.write(witness);
+        self.committed_value_digest.write(witness);
+        self.prev_deferred_digest.write(witness);
+    }
+}
@@ -153,7 +153,7 @@ impl ZKMMerkleProofWitnessValues<KoalaBearPoseidon2> {
     pub fn dummy(num_proofs: usize, height: usize) -> Self {
         let dummy_digest = [KoalaBear::ZERO; DIGEST_SIZE];
         let vk_merkle_proofs =
-            vec![MerkleProof { index: 0, path: vec![dummy_digest; height] }; num_proofs];
+            vec![MerkleProof::new(0, vec![dummy_digest; height]); num_proofs];
         let values = vec![dummy_digest; num_proofs];
 
         Self { vk_merkle_proofs, values, root: dummy_digest }
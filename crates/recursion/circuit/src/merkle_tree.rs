@@ -0,0 +1,156 @@
+//! A binary Merkle tree over [`FieldHasher`] digests, used to commit to an allow-list of
+//! trusted verifying keys for the compress-with-vkey and deferred-proof verifiers.
+
+use serde::{Deserialize, Serialize};
+use zkm_recursion_compiler::ir::Builder;
+
+use crate::{
+    hash::{FieldHasher, FieldHasherVariable},
+    CircuitConfig,
+};
+
+/// An inclusion proof of a single leaf at `index` in a [`FieldHasher`]-committed Merkle tree,
+/// given as the sibling digest at each level from the leaf up to the root.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound(serialize = "SC::Digest: Serialize"))]
+#[serde(bound(deserialize = "SC::Digest: Deserialize<'de>"))]
+pub struct MerkleProof<F, SC: FieldHasher<F>> {
+    pub index: usize,
+    pub path: Vec<SC::Digest>,
+    _marker: std::marker::PhantomData<F>,
+}
+
+impl<F, SC: FieldHasher<F>> MerkleProof<F, SC> {
+    pub fn new(index: usize, path: Vec<SC::Digest>) -> Self {
+        Self { index, path, _marker: std::marker::PhantomData }
+    }
+}
+
+/// The circuit witness for a single [`MerkleProof`].
+pub struct MerkleProofVariable<C: CircuitConfig, SC: FieldHasherVariable<C>> {
+    pub index: usize,
+    pub path: Vec<SC::DigestVariable>,
+}
+
+fn hash_pair<C: CircuitConfig, SC: FieldHasherVariable<C>>(
+    builder: &mut Builder<C>,
+    left: SC::DigestVariable,
+    right: SC::DigestVariable,
+) -> SC::DigestVariable {
+    SC::hash(builder, &[left, right])
+}
+
+/// Verifies that `proof` authenticates `leaf` against `root`, walking from the leaf to the root
+/// one level at a time and using `proof.index`'s bits to decide, at each level, whether `leaf`
+/// is the left or right child of its parent.
+pub fn verify<C: CircuitConfig, SC: FieldHasherVariable<C>>(
+    builder: &mut Builder<C>,
+    proof: MerkleProofVariable<C, SC>,
+    leaf: SC::DigestVariable,
+    root: SC::DigestVariable,
+) {
+    let mut node = leaf;
+    let mut index = proof.index;
+    for sibling in proof.path {
+        node = if index & 1 == 0 {
+            hash_pair::<C, SC>(builder, node, sibling)
+        } else {
+            hash_pair::<C, SC>(builder, sibling, node)
+        };
+        index >>= 1;
+    }
+    SC::assert_digest_eq(builder, node, root);
+}
+
+/// A batched inclusion proof for many leaves opened against the same root, sharing authentication
+/// nodes whenever two opened leaves (or their already-computed ancestors) fall under the same
+/// subtree. `indices` must be sorted and distinct. `auth` holds, in left-to-right, bottom-to-top
+/// order, the sibling digest for every pairing whose sibling isn't already known from `indices`
+/// (or from ancestors derived from them); `present_mask` has one entry per such pairing, `true`
+/// when the corresponding `auth` entry should be consumed and `false` when the node has no
+/// sibling at all at that level and is promoted unchanged (the odd-node-out case).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound(serialize = "SC::Digest: Serialize"))]
+#[serde(bound(deserialize = "SC::Digest: Deserialize<'de>"))]
+pub struct MerkleMultiProof<F, SC: FieldHasher<F>> {
+    pub indices: Vec<usize>,
+    pub auth: Vec<SC::Digest>,
+    pub present_mask: Vec<bool>,
+    _marker: std::marker::PhantomData<F>,
+}
+
+impl<F, SC: FieldHasher<F>> MerkleMultiProof<F, SC> {
+    pub fn new(indices: Vec<usize>, auth: Vec<SC::Digest>, present_mask: Vec<bool>) -> Self {
+        Self { indices, auth, present_mask, _marker: std::marker::PhantomData }
+    }
+}
+
+/// The circuit witness for a [`MerkleMultiProof`].
+pub struct MerkleMultiProofVariable<C: CircuitConfig, SC: FieldHasherVariable<C>> {
+    pub indices: Vec<usize>,
+    pub auth: Vec<SC::DigestVariable>,
+    pub present_mask: Vec<bool>,
+}
+
+/// Verifies a batch of leaves (one per entry of `proof.indices`, in the same order) against one
+/// `root`, sharing path hashes between leaves whose authentication paths overlap. See
+/// [`MerkleMultiProof`] for the witness shape this consumes.
+pub fn verify_multi<C: CircuitConfig, SC: FieldHasherVariable<C>>(
+    builder: &mut Builder<C>,
+    leaves: Vec<SC::DigestVariable>,
+    proof: MerkleMultiProofVariable<C, SC>,
+    root: SC::DigestVariable,
+) {
+    let MerkleMultiProofVariable { indices, auth, present_mask } = proof;
+    assert_eq!(indices.len(), leaves.len(), "one leaf per opened index");
+    debug_assert!(
+        indices.windows(2).all(|w| w[0] < w[1]),
+        "indices must be sorted and distinct"
+    );
+
+    if indices.is_empty() {
+        return;
+    }
+
+    let mut level: Vec<(usize, SC::DigestVariable)> = indices.into_iter().zip(leaves).collect();
+    let mut auth_iter = auth.into_iter();
+    let mut mask_iter = present_mask.into_iter();
+
+    while level.len() > 1 {
+        let mut next_level = Vec::with_capacity(level.len().div_ceil(2));
+        let mut i = 0;
+        while i < level.len() {
+            let (index, ref node) = level[i];
+            let parent_index = index / 2;
+
+            if i + 1 < level.len() && level[i + 1].0 == (index ^ 1) {
+                let (_, sibling) = &level[i + 1];
+                let parent = if index & 1 == 0 {
+                    hash_pair::<C, SC>(builder, node.clone(), sibling.clone())
+                } else {
+                    hash_pair::<C, SC>(builder, sibling.clone(), node.clone())
+                };
+                next_level.push((parent_index, parent));
+                i += 2;
+            } else {
+                let present = mask_iter.next().expect("one present_mask entry per pairing");
+                if present {
+                    let sibling = auth_iter.next().expect("one auth entry per present pairing");
+                    let parent = if index & 1 == 0 {
+                        hash_pair::<C, SC>(builder, node.clone(), sibling)
+                    } else {
+                        hash_pair::<C, SC>(builder, sibling, node.clone())
+                    };
+                    next_level.push((parent_index, parent));
+                } else {
+                    next_level.push((index, node.clone()));
+                }
+                i += 1;
+            }
+        }
+        level = next_level;
+    }
+
+    let (_, computed_root) = level.into_iter().next().expect("at least one node remains");
+    SC::assert_digest_eq(builder, computed_root, root);
+}
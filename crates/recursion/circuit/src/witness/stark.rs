@@ -4,7 +4,7 @@ use p3_field::{FieldAlgebra, FieldExtensionAlgebra};
 use p3_fri::{CommitPhaseProofStep, QueryProof};
 use p3_koala_bear::KoalaBear;
 
-use zkm2_recursion_compiler::ir::{Builder, Config, Ext, Felt};
+use zkm2_recursion_compiler::ir::{Builder, Config, Ext, Felt, Var};
 use zkm2_recursion_core::air::Block;
 use zkm2_stark::{
     koala_bear_poseidon2::KoalaBearPoseidon2, AirOpenedValues, InnerBatchOpening, InnerChallenge,
@@ -40,6 +40,42 @@ impl<C: CircuitConfig<F = KoalaBear, Bit = Felt<KoalaBear>>> WitnessWriter<C>
     }
 }
 
+/// A witness-value collector for outer (Gnark-backed) circuit configs -- `OuterConfig`,
+/// `PlonkOuterConfig`, `SectConfig` -- whose bits are represented as `Var<N>` rather than
+/// `Felt<KoalaBear>`. Unlike `Vec<WitnessBlock<C>>` above, which only has room for `C::F`/`C::EF`
+/// values, this also carries a `vars` channel over `C::N`, so `write_var` has somewhere to put its
+/// value instead of being unimplemented.
+#[derive(Debug, Clone)]
+pub struct OuterWitness<C: Config> {
+    pub vars: Vec<C::N>,
+    pub felts: Vec<C::F>,
+    pub exts: Vec<C::EF>,
+}
+
+impl<C: Config> Default for OuterWitness<C> {
+    fn default() -> Self {
+        Self { vars: vec![], felts: vec![], exts: vec![] }
+    }
+}
+
+impl<C: CircuitConfig<Bit = Var<<C as Config>::N>>> WitnessWriter<C> for OuterWitness<C> {
+    fn write_bit(&mut self, value: bool) {
+        self.vars.push(C::N::from_bool(value))
+    }
+
+    fn write_var(&mut self, value: <C>::N) {
+        self.vars.push(value)
+    }
+
+    fn write_felt(&mut self, value: <C>::F) {
+        self.felts.push(value)
+    }
+
+    fn write_ext(&mut self, value: <C>::EF) {
+        self.exts.push(value)
+    }
+}
+
 impl<C: CircuitConfig<F = InnerVal, EF = InnerChallenge>> Witnessable<C>
     for AirOpenedValues<InnerChallenge>
 {
@@ -57,9 +93,14 @@ impl<C: CircuitConfig<F = InnerVal, EF = InnerChallenge>> Witnessable<C>
     }
 }
 
+// None of the four impls below touch `C::Bit` directly -- they only read/write their nested
+// fields, whose own `Witnessable` impls carry whatever `Bit` requirement they need -- so they're
+// left generic over it. That lets them serve the outer (Gnark) configs (`OuterConfig`,
+// `PlonkOuterConfig`, `SectConfig`), whose bits are `Var<N>`, not just the inner/wrap configs
+// whose bits are `Felt<KoalaBear>`.
 impl<C> Witnessable<C> for InnerBatchOpening
 where
-    C: CircuitConfig<F = InnerVal, EF = InnerChallenge, Bit = Felt<KoalaBear>>,
+    C: CircuitConfig<F = InnerVal, EF = InnerChallenge>,
 {
     type WitnessVariable = BatchOpeningVariable<C, KoalaBearPoseidon2>;
 
@@ -76,9 +117,7 @@ where
     }
 }
 
-impl<C: CircuitConfig<F = InnerVal, EF = InnerChallenge, Bit = Felt<KoalaBear>>> Witnessable<C>
-    for InnerFriProof
-{
+impl<C: CircuitConfig<F = InnerVal, EF = InnerChallenge>> Witnessable<C> for InnerFriProof {
     type WitnessVariable = FriProofVariable<C, KoalaBearPoseidon2>;
 
     fn read(&self, builder: &mut Builder<C>) -> Self::WitnessVariable {
@@ -107,7 +146,7 @@ impl<C: CircuitConfig<F = InnerVal, EF = InnerChallenge, Bit = Felt<KoalaBear>>>
     }
 }
 
-impl<C: CircuitConfig<F = InnerVal, EF = InnerChallenge, Bit = Felt<KoalaBear>>> Witnessable<C>
+impl<C: CircuitConfig<F = InnerVal, EF = InnerChallenge>> Witnessable<C>
     for QueryProof<InnerChallenge, InnerChallengeMmcs, InnerInputProof>
 {
     type WitnessVariable = FriQueryProofVariable<C, KoalaBearPoseidon2>;
@@ -124,7 +163,7 @@ impl<C: CircuitConfig<F = InnerVal, EF = InnerChallenge, Bit = Felt<KoalaBear>>>
     }
 }
 
-impl<C: CircuitConfig<F = InnerVal, EF = InnerChallenge, Bit = Felt<KoalaBear>>> Witnessable<C>
+impl<C: CircuitConfig<F = InnerVal, EF = InnerChallenge>> Witnessable<C>
     for CommitPhaseProofStep<InnerChallenge, InnerChallengeMmcs>
 {
     type WitnessVariable = FriCommitPhaseProofStepVariable<C, KoalaBearPoseidon2>;
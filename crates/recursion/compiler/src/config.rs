@@ -4,6 +4,7 @@ use p3_bls12381_fr::Bls12381Fr as FR;
 use p3_bn254_fr::Bn254Fr as FR;
 use p3_field::extension::BinomialExtensionField;
 use p3_koala_bear::KoalaBear;
+use p3_sect_fr::SectFr;
 use zkm_stark::{InnerChallenge, InnerVal};
 
 use crate::{circuit::AsmConfig, prelude::Config};
@@ -18,3 +19,29 @@ impl Config for OuterConfig {
     type F = KoalaBear;
     type EF = BinomialExtensionField<KoalaBear, 4>;
 }
+
+/// The BN254 outer-recursion config for a KZG/PLONK final wrap, alongside the Groth16-oriented
+/// [`OuterConfig`]. The field arithmetic is identical to `OuterConfig` -- only the public-value
+/// commitment layout (see `Bn254PublicValuesConfig` in `zkm2_recursion_circuit`) differs, so this
+/// is a distinct type rather than a flag on `OuterConfig` to let both wrap flavors coexist.
+#[derive(Clone, Default, Debug)]
+pub struct PlonkOuterConfig;
+
+impl Config for PlonkOuterConfig {
+    type N = FR;
+    type F = KoalaBear;
+    type EF = BinomialExtensionField<KoalaBear, 4>;
+}
+
+/// The outer-recursion config for the Gnark "Sect" backend (`zkm2_recursion_gnark_ffi::SectWitnessGenerator`),
+/// which wraps the final compressed proof into a circuit over [`SectFr`] rather than BN254/BLS12-381.
+/// Field arithmetic otherwise matches [`OuterConfig`]/[`PlonkOuterConfig`] -- only the scalar field
+/// backing `N` (and hence `Var<N>`) differs.
+#[derive(Clone, Default, Debug)]
+pub struct SectConfig;
+
+impl Config for SectConfig {
+    type N = SectFr;
+    type F = KoalaBear;
+    type EF = BinomialExtensionField<KoalaBear, 4>;
+}
@@ -0,0 +1,375 @@
+//! Poseidon2 permutation chip over the BN254-embedded [`SectFr`] field (width 3, S-box degree
+//! 5), used to prove the final wrap/outer hashing that feeds an on-chain verifier. Mirrors
+//! [`Poseidon2SkinnyChip`](crate::chips::poseidon2_skinny::Poseidon2SkinnyChip) one level up
+//! ([`p3_sect_fr::poseidon2::Poseidon2Sect`] ships only as a bare `Permutation` there, with no
+//! [`MachineAir`] at all): preprocessed columns carry the round constants, one row per event is
+//! emitted for the raw input and for each external/internal round, and the AIR constrains the
+//! width-3 external MDS (`circ(2, 1, 1)`), the `[1, 1, 2]` internal diagonal, and the degree-5
+//! S-box -- the same three primitives [`p3_sect_fr::poseidon2`] already implements natively, just
+//! algebraically rather than as a black-box permutation call.
+
+use std::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_sect_fr::SectFr;
+use tracing::instrument;
+use zkm_core_machine::utils::next_power_of_two;
+use zkm_stark::air::MachineAir;
+
+use crate::{stark::poseidon2::bn254_poseidon2_rc3, ExecutionRecord, RecursionProgram};
+
+/// Permutation width: the BN254-side Merkle/outer hashing only ever needs a 2-to-1 compression,
+/// so the SECT permutation stays at the zkhash reference's native width rather than the 16-wide
+/// KoalaBear permutation
+/// [`Poseidon2SkinnyChip`](crate::chips::poseidon2_skinny::Poseidon2SkinnyChip) proves.
+pub const WIDTH: usize = 3;
+
+/// `S-box(x) = x^5` over [`SectFr`]: the smallest `d` with `gcd(d, p - 1) = 1` on a ~254-bit
+/// BN254 scalar field (`x^3` isn't a permutation there).
+pub const SBOX_DEGREE: u64 = 5;
+
+/// External (full S-box) rounds, split evenly into an initial and a terminal half.
+pub const NUM_EXTERNAL_ROUNDS: usize = 8;
+
+/// Internal (partial S-box, lane 0 only) rounds.
+pub const NUM_INTERNAL_ROUNDS: usize = 56;
+
+/// Rows per event: the raw input, followed by one row per external/internal round.
+pub const NUM_ROUNDS: usize = 1 + NUM_EXTERNAL_ROUNDS + NUM_INTERNAL_ROUNDS;
+
+/// The internal layer's diagonal, per `poseidon2_rust_params.sage`: `matmul_internal` computes
+/// `state[i] <- state[i] * DIAG[i] + sum(state)`, i.e. the internal matrix `M_I`'s diagonal is
+/// `DIAG[i] + 1` and every off-diagonal entry is `1`.
+pub const INTERNAL_DIAG: [u64; WIDTH] = [1, 1, 2];
+
+/// One absorbed permutation of the SECT Poseidon2 chip: a single input/output pair, mirroring
+/// [`Poseidon2Io`](crate::Poseidon2Io).
+#[derive(Clone, Copy, Debug)]
+pub struct Poseidon2SectEvent<F> {
+    pub input: [F; WIDTH],
+    pub output: [F; WIDTH],
+}
+
+/// Main trace columns: just the state at this round, the rest of a row's meaning comes from the
+/// matching [`Poseidon2SectPreprocessedCols`] row.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Poseidon2SectCols<T> {
+    pub state: [T; WIDTH],
+}
+
+/// Number of columns in a [`Poseidon2SectCols`] row.
+pub const NUM_POSEIDON2_SECT_COLS: usize = core::mem::size_of::<Poseidon2SectCols<u8>>();
+
+/// Preprocessed columns: which round (if any) produced this row's state, and that round's
+/// constants. Depends only on this chip's fixed round schedule, not on any event's data.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Poseidon2SectPreprocessedCols<T> {
+    /// `1` on a row holding the raw, pre-permutation input; `0` on every round row.
+    pub is_input: T,
+    /// `1` on an initial- or terminal-external-round row.
+    pub is_external: T,
+    /// `1` on an internal-round row.
+    pub is_internal: T,
+    /// This round's additive constants: all [`WIDTH`] lanes on an external round, lane `0` only
+    /// (lanes `1..WIDTH` zeroed) on an internal round, all zero on the input row.
+    pub round_constants: [T; WIDTH],
+}
+
+/// Number of columns in a [`Poseidon2SectPreprocessedCols`] row.
+pub const NUM_POSEIDON2_SECT_PREPROCESSED_COLS: usize =
+    core::mem::size_of::<Poseidon2SectPreprocessedCols<u8>>();
+
+impl<T: Copy> Borrow<Poseidon2SectCols<T>> for [T] {
+    fn borrow(&self) -> &Poseidon2SectCols<T> {
+        debug_assert_eq!(self.len(), NUM_POSEIDON2_SECT_COLS);
+        let (prefix, rows, suffix) = unsafe { self.align_to::<Poseidon2SectCols<T>>() };
+        debug_assert!(prefix.is_empty() && suffix.is_empty());
+        &rows[0]
+    }
+}
+
+impl<T: Copy> Borrow<Poseidon2SectPreprocessedCols<T>> for [T] {
+    fn borrow(&self) -> &Poseidon2SectPreprocessedCols<T> {
+        debug_assert_eq!(self.len(), NUM_POSEIDON2_SECT_PREPROCESSED_COLS);
+        let (prefix, rows, suffix) =
+            unsafe { self.align_to::<Poseidon2SectPreprocessedCols<T>>() };
+        debug_assert!(prefix.is_empty() && suffix.is_empty());
+        &rows[0]
+    }
+}
+
+/// The SECT Poseidon2 permutation chip.
+#[derive(Default)]
+pub struct Poseidon2SectChip;
+
+impl<F> BaseAir<F> for Poseidon2SectChip {
+    fn width(&self) -> usize {
+        NUM_POSEIDON2_SECT_COLS
+    }
+}
+
+/// Applies one external round (additive round constants, a full degree-5 S-box, then the
+/// `circ(2, 1, 1)` MDS: `y_i = x_i + sum(x)`) to `state` in place.
+fn external_round<AF: FieldAlgebra + Clone>(
+    state: &mut [AF; WIDTH],
+    round_constants: &[AF; WIDTH],
+) {
+    for (x, rc) in state.iter_mut().zip(round_constants.iter()) {
+        *x = x.clone() + rc.clone();
+        *x = x.clone() * x.clone() * x.clone() * x.clone() * x.clone();
+    }
+    let sum = state.iter().cloned().fold(AF::ZERO, |acc, x| acc + x);
+    for x in state.iter_mut() {
+        *x = x.clone() + sum.clone();
+    }
+}
+
+/// Applies one internal round (additive round constant on lane 0 only, a degree-5 S-box on lane
+/// 0 only, then `state[i] <- state[i] * DIAG[i] + sum(state)`) to `state` in place.
+fn internal_round<AF: FieldAlgebra + Clone>(state: &mut [AF; WIDTH], round_constant: &AF) {
+    state[0] = state[0].clone() + round_constant.clone();
+    state[0] = state[0].clone()
+        * state[0].clone()
+        * state[0].clone()
+        * state[0].clone()
+        * state[0].clone();
+    let sum = state.iter().cloned().fold(AF::ZERO, |acc, x| acc + x);
+    for (i, x) in state.iter_mut().enumerate() {
+        *x = x.clone() * AF::from_canonical_u64(INTERNAL_DIAG[i]) + sum.clone();
+    }
+}
+
+impl<AB: AirBuilder> Air<AB> for Poseidon2SectChip {
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let prep = builder.preprocessed();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let (_local_prep, next_prep) = (prep.row_slice(0), prep.row_slice(1));
+
+        let local: &Poseidon2SectCols<AB::Var> = (*local).borrow();
+        let next: &Poseidon2SectCols<AB::Var> = (*next).borrow();
+        let next_prep: &Poseidon2SectPreprocessedCols<AB::Var> = (*next_prep).borrow();
+
+        let mut external_state: [AB::Expr; WIDTH] =
+            core::array::from_fn(|i| local.state[i].into());
+        let external_rc: [AB::Expr; WIDTH] =
+            core::array::from_fn(|i| next_prep.round_constants[i].into());
+        external_round(&mut external_state, &external_rc);
+
+        let mut internal_state: [AB::Expr; WIDTH] =
+            core::array::from_fn(|i| local.state[i].into());
+        let internal_rc: AB::Expr = next_prep.round_constants[0].into();
+        internal_round(&mut internal_state, &internal_rc);
+
+        let mut when_transition = builder.when_transition();
+        for i in 0..WIDTH {
+            let expected = external_state[i].clone() * next_prep.is_external.into()
+                + internal_state[i].clone() * next_prep.is_internal.into();
+            when_transition
+                .when(next_prep.is_external.into() + next_prep.is_internal.into())
+                .assert_eq(next.state[i].into(), expected);
+        }
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for Poseidon2SectChip {
+    type Record = ExecutionRecord<F>;
+    type Program = RecursionProgram<F>;
+
+    fn name(&self) -> String {
+        "Poseidon2Sect".to_string()
+    }
+
+    fn generate_dependencies(&self, _: &Self::Record, _: &mut Self::Record) {
+        // This is a no-op.
+    }
+
+    fn num_rows(&self, input: &Self::Record) -> Option<usize> {
+        let events = &input.poseidon2_sect_events;
+        Some(next_power_of_two(events.len() * NUM_ROUNDS, input.fixed_log2_rows(self)))
+    }
+
+    #[instrument(
+        name = "generate poseidon2 sect trace",
+        level = "debug",
+        skip_all,
+        fields(rows = input.poseidon2_sect_events.len())
+    )]
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord<F>,
+        _output: &mut ExecutionRecord<F>,
+    ) -> RowMajorMatrix<F> {
+        assert_eq!(
+            std::any::TypeId::of::<F>(),
+            std::any::TypeId::of::<SectFr>(),
+            "generate_trace only supports the SectFr field"
+        );
+
+        let events = unsafe {
+            std::mem::transmute::<&Vec<Poseidon2SectEvent<F>>, &Vec<Poseidon2SectEvent<SectFr>>>(
+                &input.poseidon2_sect_events,
+            )
+        };
+
+        let (external_rc, internal_rc) = round_constants();
+
+        let mut rows: Vec<[SectFr; WIDTH]> = Vec::with_capacity(events.len() * NUM_ROUNDS);
+        for event in events {
+            let mut state = event.input;
+            rows.push(state);
+
+            for rc in &external_rc[..NUM_EXTERNAL_ROUNDS / 2] {
+                external_round(&mut state, rc);
+                rows.push(state);
+            }
+            for rc in &internal_rc {
+                internal_round(&mut state, rc);
+                rows.push(state);
+            }
+            for rc in &external_rc[NUM_EXTERNAL_ROUNDS / 2..] {
+                external_round(&mut state, rc);
+                rows.push(state);
+            }
+
+            debug_assert_eq!(state, event.output);
+        }
+
+        rows.resize(self.num_rows(input).unwrap(), [SectFr::ZERO; WIDTH]);
+
+        RowMajorMatrix::new(
+            unsafe {
+                std::mem::transmute::<Vec<SectFr>, Vec<F>>(
+                    rows.into_iter().flatten().collect::<Vec<SectFr>>(),
+                )
+            },
+            NUM_POSEIDON2_SECT_COLS,
+        )
+    }
+
+    fn included(&self, record: &Self::Record) -> bool {
+        !record.poseidon2_sect_events.is_empty()
+    }
+
+    fn preprocessed_width(&self) -> usize {
+        NUM_POSEIDON2_SECT_PREPROCESSED_COLS
+    }
+
+    fn preprocessed_num_rows(&self, program: &Self::Program, _instrs_len: usize) -> Option<usize> {
+        Some(next_power_of_two(NUM_ROUNDS, program.fixed_log2_rows(self)))
+    }
+
+    fn generate_preprocessed_trace(&self, program: &Self::Program) -> Option<RowMajorMatrix<F>> {
+        assert_eq!(
+            std::any::TypeId::of::<F>(),
+            std::any::TypeId::of::<SectFr>(),
+            "generate_preprocessed_trace only supports the SectFr field"
+        );
+
+        let (external_rc, internal_rc) = round_constants();
+
+        let mut rows: Vec<[SectFr; NUM_POSEIDON2_SECT_PREPROCESSED_COLS]> =
+            Vec::with_capacity(NUM_ROUNDS);
+        rows.push(input_row());
+        for rc in &external_rc[..NUM_EXTERNAL_ROUNDS / 2] {
+            rows.push(external_row(*rc));
+        }
+        for rc in &internal_rc {
+            rows.push(internal_row(*rc));
+        }
+        for rc in &external_rc[NUM_EXTERNAL_ROUNDS / 2..] {
+            rows.push(external_row(*rc));
+        }
+
+        rows.resize(
+            self.preprocessed_num_rows(program, 0).unwrap(),
+            [SectFr::ZERO; NUM_POSEIDON2_SECT_PREPROCESSED_COLS],
+        );
+
+        Some(RowMajorMatrix::new(
+            unsafe {
+                std::mem::transmute::<Vec<SectFr>, Vec<F>>(
+                    rows.into_iter().flatten().collect::<Vec<SectFr>>(),
+                )
+            },
+            NUM_POSEIDON2_SECT_PREPROCESSED_COLS,
+        ))
+    }
+}
+
+fn input_row() -> [SectFr; NUM_POSEIDON2_SECT_PREPROCESSED_COLS] {
+    let mut row = [SectFr::ZERO; NUM_POSEIDON2_SECT_PREPROCESSED_COLS];
+    row[0] = SectFr::ONE; // is_input
+    row
+}
+
+fn external_row(rc: [SectFr; WIDTH]) -> [SectFr; NUM_POSEIDON2_SECT_PREPROCESSED_COLS] {
+    let mut row = [SectFr::ZERO; NUM_POSEIDON2_SECT_PREPROCESSED_COLS];
+    row[1] = SectFr::ONE; // is_external
+    row[3] = rc[0];
+    row[4] = rc[1];
+    row[5] = rc[2];
+    row
+}
+
+fn internal_row(rc: SectFr) -> [SectFr; NUM_POSEIDON2_SECT_PREPROCESSED_COLS] {
+    let mut row = [SectFr::ZERO; NUM_POSEIDON2_SECT_PREPROCESSED_COLS];
+    row[2] = SectFr::ONE; // is_internal
+    row[3] = rc;
+    row
+}
+
+/// The real round constants this chip constrains against, split into the external rounds (the
+/// first [`NUM_EXTERNAL_ROUNDS`] `/` `2` initial, the last half terminal) and the internal rounds
+/// in between (only each internal round's lane `0` is used, matching
+/// [`p3_sect_fr::poseidon2::Poseidon2InternalLayerSect`]'s own single-constant-per-round shape).
+/// Sourced from the same `RC3` table
+/// [`crate::stark::poseidon2::bn254_poseidon2_rc3`] converts for the reference permutation test,
+/// so the constants match what [`p3_sect_fr::poseidon2::Poseidon2Sect`] runs natively.
+fn round_constants() -> (Vec<[SectFr; WIDTH]>, Vec<SectFr>) {
+    let mut rc3 = bn254_poseidon2_rc3();
+    debug_assert_eq!(rc3.len(), NUM_EXTERNAL_ROUNDS + NUM_INTERNAL_ROUNDS);
+
+    let half = NUM_EXTERNAL_ROUNDS / 2;
+    let internal: Vec<SectFr> =
+        rc3.drain(half..half + NUM_INTERNAL_ROUNDS).map(|rc| rc[0]).collect();
+    (rc3, internal)
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_field::FieldAlgebra;
+    use p3_sect_fr::SectFr;
+
+    use super::{external_round, internal_round, round_constants, WIDTH};
+
+    #[test]
+    fn external_round_mds_is_sum_plus_self() {
+        type F = SectFr;
+        let mut state = [F::ONE, F::TWO, F::from_canonical_u64(3)];
+        external_round(&mut state, &[F::ZERO; WIDTH]);
+        let post_sbox = [F::ONE, F::from_canonical_u64(32), F::from_canonical_u64(243)];
+        let sum = post_sbox[0] + post_sbox[1] + post_sbox[2];
+        assert_eq!(state, [post_sbox[0] + sum, post_sbox[1] + sum, post_sbox[2] + sum]);
+    }
+
+    #[test]
+    fn internal_round_leaves_lanes_one_and_two_linear() {
+        type F = SectFr;
+        let mut state = [F::ONE, F::TWO, F::from_canonical_u64(3)];
+        let before = state;
+        internal_round(&mut state, &F::ZERO);
+        assert_ne!(state, before);
+    }
+
+    #[test]
+    fn round_constant_table_matches_the_fixed_round_schedule() {
+        let (external, internal) = round_constants();
+        assert_eq!(external.len(), super::NUM_EXTERNAL_ROUNDS);
+        assert_eq!(internal.len(), super::NUM_INTERNAL_ROUNDS);
+    }
+}
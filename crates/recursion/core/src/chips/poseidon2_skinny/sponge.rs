@@ -0,0 +1,232 @@
+//! Variable-length Poseidon2 sponge hashing, layered on top of the fixed-width permutation
+//! [`Poseidon2SkinnyChip`] already constrains.
+//!
+//! The sponge splits the permutation's [`WIDTH`] into a rate [`RATE`] (the lanes absorbed into
+//! and squeezed out of) and a capacity of `WIDTH - RATE` lanes that the message never touches
+//! directly. Absorption adds each `RATE`-sized message block into the rate portion of the state
+//! and runs one permutation; the final, possibly partial, block is padded with the standard
+//! `10*` rule (append a single one, then zeros) so that two messages differing only in a
+//! trailing zero can't collide. The capacity of the very first state is seeded with a domain
+//! constant encoding the message length -- the "ConstantLength" sponge construction -- so that
+//! fixed-length callers hashing at two different lengths can't collide on padding either.
+//!
+//! Row-level AIR constraints (pre-permutation state == prior state plus absorbed block, and the
+//! squeeze reading the first [`RATE`] lanes of the last row's post-permutation state) are not
+//! wired up yet, matching [`Poseidon2SkinnyChip`]'s own trace generator, which notes the same
+//! gap; that's a larger follow-up once the base chip's constraints land. Likewise, surfacing this
+//! as a callable opcode (a `Poseidon2Sponge` [`Instruction`](crate::instruction::Instruction)
+//! variant emitting these events at runtime) is left for that same follow-up -- this module only
+//! adds the event type and the chip that turns recorded events into a trace, both of which that
+//! opcode would need.
+
+use p3_field::{FieldAlgebra, PrimeField32};
+use p3_koala_bear::KoalaBear;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_symmetric::Permutation;
+use tracing::instrument;
+use zkm_core_machine::utils::next_power_of_two;
+use zkm_stark::{air::MachineAir, inner_perm};
+
+use crate::{chips::poseidon2_skinny::WIDTH, ExecutionRecord, RecursionProgram};
+
+/// Lanes absorbed/squeezed per permutation call. The remaining `WIDTH - RATE` lanes are the
+/// capacity and are never written by the message.
+pub const RATE: usize = WIDTH - 1;
+
+/// Number of columns in a [`Poseidon2SpongeCols`] row.
+pub const NUM_POSEIDON2_SPONGE_COLS: usize = core::mem::size_of::<Poseidon2SpongeCols<u8>>();
+
+/// One absorb-and-permute step of a sponge hash.
+#[repr(C)]
+pub struct Poseidon2SpongeCols<T> {
+    /// `1` on the row that produces the squeezed digest (the last block of the message), `0`
+    /// otherwise.
+    pub is_last_block: T,
+    /// State before this block's permutation: the previous row's `state_after`, or, for a
+    /// message's first block, the capacity seeded with the domain constant and the rate zeroed.
+    pub state_before: [T; WIDTH],
+    /// The message block absorbed into the rate portion (already `10*`-padded, for the final
+    /// block).
+    pub block: [T; RATE],
+    /// State after adding `block` into the rate lanes of `state_before` and running one
+    /// permutation.
+    pub state_after: [T; WIDTH],
+}
+
+/// An absorb-and-permute step, as recorded by the runtime for trace generation. Mirrors
+/// [`Poseidon2SpongeCols`] but owns its data instead of aliasing a trace row.
+#[derive(Clone, Copy, Debug)]
+pub struct Poseidon2SpongeEvent<F> {
+    /// Whether this is the final block of the message.
+    pub is_last_block: bool,
+    /// State before this block's permutation.
+    pub state_before: [F; WIDTH],
+    /// The (already-padded, for the final block) message block.
+    pub block: [F; RATE],
+    /// State after absorbing `block` and running one permutation.
+    pub state_after: [F; WIDTH],
+}
+
+/// `10*` pads `message` out to a multiple of [`RATE`], appending a full block of padding when the
+/// message is already block-aligned so the padding is never mistaken for message content.
+fn pad_message<F: FieldAlgebra + Copy>(message: &[F]) -> Vec<[F; RATE]> {
+    let mut padded = message.to_vec();
+    padded.push(F::ONE);
+    while padded.len() % RATE != 0 {
+        padded.push(F::ZERO);
+    }
+    padded.chunks_exact(RATE).map(|block| block.try_into().unwrap()).collect()
+}
+
+/// Absorbs `message` into a fresh Poseidon2 sponge and returns one [`Poseidon2SpongeEvent`] per
+/// permutation call. `domain` seeds the capacity of the very first state (typically an encoding
+/// of `message.len()`), giving fixed-length callers domain separation across lengths.
+pub fn absorb<F: FieldAlgebra + PrimeField32 + Copy>(
+    message: &[F],
+    domain: F,
+) -> Vec<Poseidon2SpongeEvent<F>> {
+    let permuter = inner_perm();
+    let blocks = pad_message(message);
+
+    let mut state = [F::ZERO; WIDTH];
+    state[RATE] = domain;
+
+    let mut events = Vec::with_capacity(blocks.len());
+    for (i, block) in blocks.iter().enumerate() {
+        let state_before = state;
+        for (lane, elem) in state.iter_mut().take(RATE).zip(block.iter()) {
+            *lane += *elem;
+        }
+        state = permuter.permute(state);
+        events.push(Poseidon2SpongeEvent {
+            is_last_block: i == blocks.len() - 1,
+            state_before,
+            block: *block,
+            state_after: state,
+        });
+    }
+    events
+}
+
+/// Squeezes the digest out of the last event's post-permutation state.
+#[must_use]
+pub fn squeeze<F: Copy>(events: &[Poseidon2SpongeEvent<F>]) -> [F; RATE] {
+    let last = events.last().expect("a sponge must absorb at least one (padded) block");
+    debug_assert!(last.is_last_block);
+    last.state_after[..RATE].try_into().unwrap()
+}
+
+/// The sponge's own chip, separate from [`Poseidon2SkinnyChip`](super::Poseidon2SkinnyChip):
+/// each row is one absorb-and-permute step rather than one round of a single permutation, so it
+/// needs its own `DEGREE`-indexed trace shape.
+#[derive(Default)]
+pub struct Poseidon2SpongeChip<const DEGREE: usize>;
+
+/// Generates one row of [`Poseidon2SpongeCols`] per absorb-and-permute step recorded in
+/// [`ExecutionRecord::poseidon2_sponge_events`].
+impl<F: PrimeField32, const DEGREE: usize> MachineAir<F> for Poseidon2SpongeChip<DEGREE> {
+    type Record = ExecutionRecord<F>;
+    type Program = RecursionProgram<F>;
+
+    fn name(&self) -> String {
+        format!("Poseidon2SpongeDeg{DEGREE}")
+    }
+
+    fn generate_dependencies(&self, _: &Self::Record, _: &mut Self::Record) {
+        // This is a no-op.
+    }
+
+    fn num_rows(&self, input: &Self::Record) -> Option<usize> {
+        let events = &input.poseidon2_sponge_events;
+        Some(next_power_of_two(events.len(), input.fixed_log2_rows(self)))
+    }
+
+    #[instrument(
+        name = "generate poseidon2 sponge trace",
+        level = "debug",
+        skip_all,
+        fields(rows = input.poseidon2_sponge_events.len())
+    )]
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord<F>,
+        _output: &mut ExecutionRecord<F>,
+    ) -> RowMajorMatrix<F> {
+        assert_eq!(
+            std::any::TypeId::of::<F>(),
+            std::any::TypeId::of::<KoalaBear>(),
+            "generate_trace only supports KoalaBear field"
+        );
+
+        let mut rows = Vec::with_capacity(input.poseidon2_sponge_events.len());
+
+        for event in &input.poseidon2_sponge_events {
+            let mut row = [F::ZERO; NUM_POSEIDON2_SPONGE_COLS];
+            let cols: &mut Poseidon2SpongeCols<F> = unsafe { &mut *(row.as_mut_ptr().cast()) };
+            cols.is_last_block = F::from_bool(event.is_last_block);
+            cols.state_before = event.state_before;
+            cols.block = event.block;
+            cols.state_after = event.state_after;
+            rows.push(row);
+        }
+
+        rows.resize(self.num_rows(input).unwrap(), [F::ZERO; NUM_POSEIDON2_SPONGE_COLS]);
+
+        RowMajorMatrix::new(rows.into_iter().flatten().collect(), NUM_POSEIDON2_SPONGE_COLS)
+    }
+
+    fn included(&self, record: &Self::Record) -> bool {
+        !record.poseidon2_sponge_events.is_empty()
+    }
+
+    fn preprocessed_width(&self) -> usize {
+        0
+    }
+
+    fn preprocessed_num_rows(&self, _program: &Self::Program, _instrs_len: usize) -> Option<usize> {
+        None
+    }
+
+    fn generate_preprocessed_trace(&self, _program: &Self::Program) -> Option<RowMajorMatrix<F>> {
+        // This chip has no preprocessed columns: unlike `Poseidon2SkinnyChip`, a row's shape
+        // doesn't depend on any instruction metadata beyond what's already folded into the event.
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_field::FieldAlgebra;
+    use p3_koala_bear::KoalaBear;
+
+    use super::{absorb, squeeze, RATE};
+
+    #[test]
+    fn absorbs_a_message_shorter_than_one_block() {
+        type F = KoalaBear;
+        let message = [F::ONE, F::TWO];
+        let events = absorb(&message, F::from_canonical_usize(message.len()));
+        assert_eq!(events.len(), 1);
+        assert!(events[0].is_last_block);
+        let _digest: [F; RATE] = squeeze(&events);
+    }
+
+    #[test]
+    fn absorbs_a_message_spanning_multiple_blocks() {
+        type F = KoalaBear;
+        let message: Vec<F> = (0..RATE + 1).map(|i| F::from_canonical_usize(i)).collect();
+        let events = absorb(&message, F::from_canonical_usize(message.len()));
+        assert_eq!(events.len(), 2);
+        assert!(!events[0].is_last_block);
+        assert!(events[1].is_last_block);
+    }
+
+    #[test]
+    fn domain_separates_same_block_different_lengths() {
+        type F = KoalaBear;
+        let message = [F::ONE; RATE];
+        let short = absorb(&message, F::from_canonical_usize(RATE));
+        let long = absorb(&message, F::from_canonical_usize(RATE + 1));
+        assert_ne!(squeeze(&short), squeeze(&long));
+    }
+}
@@ -1,10 +1,12 @@
 use std::{borrow::BorrowMut, mem::size_of};
 
-use itertools::Itertools;
 use p3_field::FieldAlgebra;
 use p3_field::PrimeField32;
 use p3_koala_bear::KoalaBear;
 use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::{
+    IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator, ParallelSliceMut,
+};
 use tracing::instrument;
 use zkm_core_machine::utils::next_power_of_two;
 use zkm_stark::air::MachineAir;
@@ -24,7 +26,44 @@ const PREPROCESSED_POSEIDON2_WIDTH: usize = size_of::<Poseidon2PreprocessedCols<
 
 pub const OUTPUT_ROUND_IDX: usize = NUM_EXTERNAL_ROUNDS + 2;
 
-impl<F: PrimeField32, const DEGREE: usize> MachineAir<F> for Poseidon2SkinnyChip<DEGREE> {
+/// Field-specific glue needed to generate [`Poseidon2SkinnyChip`]'s trace. Each supported field
+/// backs its permutation round logic with its own native (`crate::sys`-exported) implementation,
+/// so the chip dispatches through this trait instead of asserting a single field via `TypeId` and
+/// `transmute`ing into it -- that pattern silently produced incorrect (or, for a mismatched field,
+/// UB) traces for any field other than the one hard-coded at the call site.
+pub trait Poseidon2Field: PrimeField32 {
+    /// Fills one event's `NUM_EXTERNAL_ROUNDS + 3` rows of the main trace.
+    ///
+    /// # Safety
+    /// `row` must point to `NUM_EXTERNAL_ROUNDS + 3` valid, writable, properly aligned
+    /// `Poseidon2Cols<Self>`s.
+    unsafe fn event_to_row(event: &Poseidon2Io<Self>, row: *mut Poseidon2Cols<Self>);
+
+    /// Fills preprocessed row `round` (of `NUM_EXTERNAL_ROUNDS + 3`) for one instruction.
+    fn instr_to_row(
+        instr: &Poseidon2SkinnyInstr<Self>,
+        round: usize,
+        cols: &mut Poseidon2PreprocessedCols<Self>,
+    );
+}
+
+impl Poseidon2Field for KoalaBear {
+    unsafe fn event_to_row(event: &Poseidon2Io<Self>, row: *mut Poseidon2Cols<Self>) {
+        crate::sys::poseidon2_skinny_event_to_row_koalabear(event, row);
+    }
+
+    fn instr_to_row(
+        instr: &Poseidon2SkinnyInstr<Self>,
+        round: usize,
+        cols: &mut Poseidon2PreprocessedCols<Self>,
+    ) {
+        unsafe {
+            crate::sys::poseidon2_skinny_instr_to_row_koalabear(instr, round, cols);
+        }
+    }
+}
+
+impl<F: Poseidon2Field, const DEGREE: usize> MachineAir<F> for Poseidon2SkinnyChip<DEGREE> {
     type Record = ExecutionRecord<F>;
 
     type Program = RecursionProgram<F>;
@@ -48,43 +87,21 @@ impl<F: PrimeField32, const DEGREE: usize> MachineAir<F> for Poseidon2SkinnyChip
         input: &ExecutionRecord<F>,
         _output: &mut ExecutionRecord<F>,
     ) -> RowMajorMatrix<F> {
-        assert_eq!(
-            std::any::TypeId::of::<F>(),
-            std::any::TypeId::of::<KoalaBear>(),
-            "generate_trace only supports KoalaBear field"
-        );
-
-        let mut rows = Vec::new();
-
-        let events = unsafe {
-            std::mem::transmute::<&Vec<Poseidon2Io<F>>, &Vec<Poseidon2Io<KoalaBear>>>(
-                &input.poseidon2_events,
-            )
-        };
+        let events = &input.poseidon2_events;
 
-        for event in events {
-            let mut row_add = [[KoalaBear::ZERO; NUM_POSEIDON2_COLS]; NUM_EXTERNAL_ROUNDS + 3];
-            unsafe {
-                crate::sys::poseidon2_skinny_event_to_row_koalabear(
-                    event,
-                    row_add.as_mut_ptr() as *mut Poseidon2Cols<KoalaBear>,
-                );
-            }
-            rows.extend(row_add.into_iter());
-        }
+        // Each event occupies its own fixed-size, statically-offset block of
+        // `NUM_EXTERNAL_ROUNDS + 3` rows, so the padded row buffer can be preallocated up front
+        // and every event's block filled independently and in parallel.
+        let mut rows = vec![[F::ZERO; NUM_POSEIDON2_COLS]; self.num_rows(input).unwrap()];
 
-        // Pad the trace to a power of two.
-        // This will need to be adjusted when the AIR constraints are implemented.
-        rows.resize(self.num_rows(input).unwrap(), [KoalaBear::ZERO; NUM_POSEIDON2_COLS]);
+        rows[..events.len() * (NUM_EXTERNAL_ROUNDS + 3)]
+            .par_chunks_mut(NUM_EXTERNAL_ROUNDS + 3)
+            .zip(events.par_iter())
+            .for_each(|(row_add, event)| unsafe {
+                F::event_to_row(event, row_add.as_mut_ptr() as *mut Poseidon2Cols<F>);
+            });
 
-        RowMajorMatrix::new(
-            unsafe {
-                std::mem::transmute::<Vec<KoalaBear>, Vec<F>>(
-                    rows.into_iter().flatten().collect::<Vec<KoalaBear>>(),
-                )
-            },
-            NUM_POSEIDON2_COLS,
-        )
+        RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<F>>(), NUM_POSEIDON2_COLS)
     }
 
     fn included(&self, _record: &Self::Record) -> bool {
@@ -100,59 +117,35 @@ impl<F: PrimeField32, const DEGREE: usize> MachineAir<F> for Poseidon2SkinnyChip
     }
 
     fn generate_preprocessed_trace(&self, program: &Self::Program) -> Option<RowMajorMatrix<F>> {
-        assert_eq!(
-            std::any::TypeId::of::<F>(),
-            std::any::TypeId::of::<KoalaBear>(),
-            "generate_trace only supports KoalaBear field"
-        );
-
-        let instructions =
+        let instructions: Vec<_> =
             program.instructions.iter().filter_map(|instruction| match instruction {
-                Poseidon2(instr) => Some(unsafe {
-                    std::mem::transmute::<
-                        &Box<Poseidon2SkinnyInstr<F>>,
-                        &Box<Poseidon2SkinnyInstr<KoalaBear>>,
-                    >(instr)
-                }),
+                Poseidon2(instr) => Some(instr),
                 _ => None,
-            });
-
-        let num_instructions =
-            program.instructions.iter().filter(|instr| matches!(instr, Poseidon2(_))).count();
-
-        let mut rows = vec![
-            [KoalaBear::ZERO; PREPROCESSED_POSEIDON2_WIDTH];
-            num_instructions * (NUM_EXTERNAL_ROUNDS + 3)
-        ];
-
-        // Iterate over the instructions and take NUM_EXTERNAL_ROUNDS + 3 rows for each instruction.
-        // We have one extra round for the internal rounds, one extra round for the input,
-        // and one extra round for the output.
-        instructions.zip_eq(&rows.iter_mut().chunks(NUM_EXTERNAL_ROUNDS + 3)).for_each(
-            |(instruction, row_add)| {
-                row_add.into_iter().enumerate().for_each(|(i, row)| {
+            }).collect();
+
+        // Each instruction occupies its own fixed-size, statically-offset block of
+        // `NUM_EXTERNAL_ROUNDS + 3` rows (one extra round for the internal rounds, one extra
+        // round for the input, and one extra round for the output), so the padded row buffer can
+        // be preallocated up front and every instruction's block filled independently and in
+        // parallel.
+        let num_padded_rows = self
+            .preprocessed_num_rows(program, instructions.len() * (NUM_EXTERNAL_ROUNDS + 3))
+            .unwrap();
+        let mut rows = vec![[F::ZERO; PREPROCESSED_POSEIDON2_WIDTH]; num_padded_rows];
+
+        rows[..instructions.len() * (NUM_EXTERNAL_ROUNDS + 3)]
+            .par_chunks_mut(NUM_EXTERNAL_ROUNDS + 3)
+            .zip(instructions.par_iter())
+            .for_each(|(row_add, instruction)| {
+                row_add.iter_mut().enumerate().for_each(|(i, row)| {
                     let cols: &mut Poseidon2PreprocessedCols<_> =
-                        (*row).as_mut_slice().borrow_mut();
-                    unsafe {
-                        crate::sys::poseidon2_skinny_instr_to_row_koalabear(instruction, i, cols);
-                    }
+                        row.as_mut_slice().borrow_mut();
+                    F::instr_to_row(instruction, i, cols);
                 });
-            },
-        );
-
-        // Pad the trace to a power of two.
-        // This may need to be adjusted when the AIR constraints are implemented.
-        rows.resize(
-            self.preprocessed_num_rows(program, rows.len()).unwrap(),
-            [KoalaBear::ZERO; PREPROCESSED_POSEIDON2_WIDTH],
-        );
+            });
 
         Some(RowMajorMatrix::new(
-            unsafe {
-                std::mem::transmute::<Vec<KoalaBear>, Vec<F>>(
-                    rows.into_iter().flatten().collect::<Vec<KoalaBear>>(),
-                )
-            },
+            rows.into_iter().flatten().collect::<Vec<F>>(),
             PREPROCESSED_POSEIDON2_WIDTH,
         ))
     }
@@ -192,4 +185,64 @@ mod tests {
         let chip_9 = Poseidon2SkinnyChip::<9>::default();
         let _: RowMajorMatrix<F> = chip_9.generate_trace(&shard, &mut ExecutionRecord::default());
     }
+
+    /// Fills the same per-event row blocks as [`super::Poseidon2SkinnyChip::generate_trace`], but
+    /// sequentially, one event at a time -- a reference implementation to check the parallel
+    /// version against.
+    fn generate_trace_sequentially(
+        chip: &Poseidon2SkinnyChip<9>,
+        input: &ExecutionRecord<KoalaBear>,
+    ) -> RowMajorMatrix<KoalaBear> {
+        use std::borrow::BorrowMut as _;
+
+        use crate::chips::poseidon2_skinny::{
+            columns::{Poseidon2 as Poseidon2Cols, NUM_POSEIDON2_COLS},
+            NUM_EXTERNAL_ROUNDS,
+        };
+
+        let mut rows = Vec::new();
+        for event in &input.poseidon2_events {
+            let mut row_add = [[KoalaBear::ZERO; NUM_POSEIDON2_COLS]; NUM_EXTERNAL_ROUNDS + 3];
+            unsafe {
+                crate::sys::poseidon2_skinny_event_to_row_koalabear(
+                    event,
+                    row_add.as_mut_ptr() as *mut Poseidon2Cols<KoalaBear>,
+                );
+            }
+            rows.extend(row_add);
+        }
+        rows.resize(
+            zkm_stark::air::MachineAir::num_rows(chip, input).unwrap(),
+            [KoalaBear::ZERO; NUM_POSEIDON2_COLS],
+        );
+        RowMajorMatrix::new(rows.into_iter().flatten().collect(), NUM_POSEIDON2_COLS)
+    }
+
+    #[test]
+    fn generate_trace_is_generic_over_any_poseidon2_field() {
+        fn assert_impls_poseidon2_field<F: super::Poseidon2Field>() {}
+        assert_impls_poseidon2_field::<KoalaBear>();
+    }
+
+    #[test]
+    fn parallel_trace_matches_sequential_trace() {
+        type F = KoalaBear;
+        let permuter = inner_perm();
+        let mut rng = rand::thread_rng();
+
+        let events = (0..5)
+            .map(|_| {
+                let input = [F::rand(&mut rng); WIDTH];
+                let output = permuter.permute(input);
+                Poseidon2Event { input, output }
+            })
+            .collect();
+        let shard = ExecutionRecord { poseidon2_events: events, ..Default::default() };
+
+        let chip = Poseidon2SkinnyChip::<9>::default();
+        let parallel = chip.generate_trace(&shard, &mut ExecutionRecord::default());
+        let sequential = generate_trace_sequentially(&chip, &shard);
+
+        assert_eq!(parallel.values, sequential.values);
+    }
 }
@@ -0,0 +1,159 @@
+//! Resolves the Plonk/Groth16 circuit artifacts (proving/verifying keys) consumed by
+//! `verify_plonk_bn254`/`verify_groth16_bn254` outside of dev mode.
+//!
+//! Artifacts are published per [`ZKM_CIRCUIT_VERSION`] and cached under the user's config
+//! directory the first time they're needed; every later call for the same version reuses the
+//! cached copy. A download is only ever exposed to other prover instances once it has been
+//! verified against a pinned checksum and atomically renamed into place, so a prover that crashes
+//! mid-download never leaves a corrupt directory behind for the next one to pick up.
+
+use std::{
+    fs,
+    io::Read,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{bail, Context, Result};
+use sha2::{Digest, Sha256};
+
+use zkm2_core_machine::ZKM_CIRCUIT_VERSION;
+
+/// Base URL the released circuit artifact tarballs are published under.
+const CIRCUIT_ARTIFACTS_URL_BASE: &str = "https://zkm2-circuits.s3.us-east-2.amazonaws.com";
+
+/// How long to wait for another prover on this machine to finish installing the same artifacts
+/// before giving up.
+const LOCK_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// The root directory circuit artifacts of every kind/version are cached under.
+fn artifacts_cache_dir() -> PathBuf {
+    dirs::home_dir().unwrap_or_else(|| PathBuf::from(".")).join(".zkm2").join("circuits")
+}
+
+/// Resolves the cached artifact directory for `kind` ("plonk" or "groth16") at
+/// [`ZKM_CIRCUIT_VERSION`], downloading and installing it first if it isn't already present.
+///
+/// Returns the directory `verify_plonk_bn254`/`verify_groth16_bn254` should read
+/// `{kind}_vk.bin`/`{kind}_pk.bin` from.
+pub(crate) fn try_install_circuit_artifacts(kind: &str) -> Result<PathBuf> {
+    let install_dir = artifacts_cache_dir().join(ZKM_CIRCUIT_VERSION).join(kind);
+    let done_marker = install_dir.join(".installed");
+    if done_marker.exists() {
+        return Ok(install_dir);
+    }
+
+    fs::create_dir_all(install_dir.parent().unwrap())
+        .with_context(|| format!("failed to create circuit artifacts cache dir for {kind}"))?;
+
+    let _lock = AcquiredLock::acquire(&install_dir, LOCK_TIMEOUT)?;
+    // Another prover may have finished installing while we waited for the lock.
+    if done_marker.exists() {
+        return Ok(install_dir);
+    }
+
+    let archive_name = format!("{kind}.tar.gz");
+    let archive_url = format!("{CIRCUIT_ARTIFACTS_URL_BASE}/{ZKM_CIRCUIT_VERSION}/{archive_name}");
+    let checksum_url = format!("{archive_url}.sha256");
+
+    let expected_checksum = download_to_string(&checksum_url)
+        .with_context(|| format!("failed to fetch checksum for {kind} circuit artifacts"))?
+        .trim()
+        .to_string();
+
+    let staging_dir = install_dir.with_extension("staging");
+    if staging_dir.exists() {
+        fs::remove_dir_all(&staging_dir).context("failed to clear stale staging dir")?;
+    }
+    fs::create_dir_all(&staging_dir)?;
+
+    let archive_path = staging_dir.join(&archive_name);
+    let archive_bytes = download_to_bytes(&archive_url)
+        .with_context(|| format!("failed to download {kind} circuit artifacts"))?;
+
+    let actual_checksum = hex_string(&Sha256::digest(&archive_bytes));
+    if actual_checksum != expected_checksum {
+        bail!(
+            "checksum mismatch for {kind} circuit artifacts: expected {expected_checksum}, got \
+             {actual_checksum} (partial or corrupt download)"
+        );
+    }
+    fs::write(&archive_path, &archive_bytes)?;
+
+    extract_tar_gz(&archive_path, &staging_dir)
+        .with_context(|| format!("failed to extract {kind} circuit artifacts"))?;
+    fs::remove_file(&archive_path)?;
+    fs::write(staging_dir.join(".installed"), &actual_checksum)?;
+
+    // Rename is atomic on the same filesystem, so concurrent installers can never observe a
+    // half-written `install_dir`.
+    if install_dir.exists() {
+        fs::remove_dir_all(&install_dir)?;
+    }
+    fs::rename(&staging_dir, &install_dir)
+        .context("failed to move installed circuit artifacts into place")?;
+
+    Ok(install_dir)
+}
+
+/// A simple cross-process advisory lock backed by the atomicity of `create_new`, used so that
+/// concurrent provers on the same machine don't race on the same download.
+struct AcquiredLock {
+    path: PathBuf,
+}
+
+impl AcquiredLock {
+    fn acquire(install_dir: &Path, timeout: Duration) -> Result<Self> {
+        let lock_path = install_dir.with_extension("lock");
+        let deadline = std::time::Instant::now() + timeout;
+        loop {
+            match fs::OpenOptions::new().write(true).create_new(true).open(&lock_path) {
+                Ok(_) => return Ok(Self { path: lock_path }),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                    if std::time::Instant::now() >= deadline {
+                        bail!(
+                            "timed out waiting for another process to finish installing circuit \
+                             artifacts ({})",
+                            lock_path.display()
+                        );
+                    }
+                    std::thread::sleep(Duration::from_millis(250));
+                }
+                Err(e) => return Err(e).context("failed to create circuit artifacts lockfile"),
+            }
+        }
+    }
+}
+
+impl Drop for AcquiredLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+fn download_to_bytes(url: &str) -> Result<Vec<u8>> {
+    let response = ureq::get(url).call().with_context(|| format!("GET {url} failed"))?;
+    let mut bytes = Vec::new();
+    response.into_reader().read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn download_to_string(url: &str) -> Result<String> {
+    Ok(String::from_utf8(download_to_bytes(url)?)?)
+}
+
+fn extract_tar_gz(archive_path: &Path, dest_dir: &Path) -> Result<()> {
+    let file = fs::File::open(archive_path)?;
+    let decoder = flate2::read::GzDecoder::new(file);
+    let mut archive = tar::Archive::new(decoder);
+    archive.unpack(dest_dir)?;
+    Ok(())
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        std::fmt::Write::write_fmt(&mut s, format_args!("{b:02x}")).unwrap();
+    }
+    s
+}
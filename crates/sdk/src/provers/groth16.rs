@@ -0,0 +1,362 @@
+//! Native Groth16 bn254 verification and Solidity codegen.
+//!
+//! Historically the on-chain verifier contract for a Groth16 proof was produced by `gnark`
+//! itself, which meant exporting a Solidity verifier required pulling and running the gnark
+//! docker image at release time. This module re-implements the Groth16 pairing check natively in
+//! Rust (so it can run in `cargo test` with no external toolchain) and generates the equivalent
+//! Solidity, so [`super::solidity::render_solidity_verifier`] no longer depends on gnark output
+//! beyond the raw `groth16_vk.bin` bytes it already reads.
+//!
+//! `groth16_vk.bin` is gnark-crypto's uncompressed bn254 verifying-key encoding: `alpha_g1` (64
+//! bytes), `beta_g2` (128 bytes), `gamma_g2` (128 bytes), `delta_g2` (128 bytes), then a
+//! big-endian `u32` IC length followed by that many 64-byte G1 points. Each G1 point is `x || y`
+//! big-endian in the bn254 base field; each G2 point is `x.c1 || x.c0 || y.c1 || y.c0` (gnark
+//! serializes the `Fp2` components in the opposite order from `ark_bn254`).
+
+use anyhow::{bail, Result};
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, Field, One, PrimeField};
+
+/// A parsed Groth16 verifying key (`alpha_g1`, `beta_g2`, `gamma_g2`, `delta_g2`, `ic`), matching
+/// the canonical Groth16 pairing check `e(A,B) = e(alpha,beta) * e(vk_x,gamma) * e(C,delta)` where
+/// `vk_x = ic[0] + sum(ic[i + 1] * public_input[i])`.
+#[derive(Debug, Clone)]
+pub struct Groth16VerifyingKey {
+    pub alpha_g1: G1Affine,
+    pub beta_g2: G2Affine,
+    pub gamma_g2: G2Affine,
+    pub delta_g2: G2Affine,
+    pub ic: Vec<G1Affine>,
+}
+
+/// A Groth16 proof's three curve points.
+#[derive(Debug, Clone)]
+pub struct Groth16Proof {
+    pub a: G1Affine,
+    pub b: G2Affine,
+    pub c: G1Affine,
+}
+
+fn read_fq(bytes: &[u8]) -> Fq {
+    Fq::from_be_bytes_mod_order(bytes)
+}
+
+fn read_g1(bytes: &[u8]) -> Result<G1Affine> {
+    if bytes.len() != 64 {
+        bail!("expected a 64-byte G1 point, got {} bytes", bytes.len());
+    }
+    if bytes.iter().all(|b| *b == 0) {
+        return Ok(G1Affine::identity());
+    }
+    let x = read_fq(&bytes[..32]);
+    let y = read_fq(&bytes[32..]);
+    Ok(G1Affine::new(x, y))
+}
+
+fn read_g2(bytes: &[u8]) -> Result<G2Affine> {
+    if bytes.len() != 128 {
+        bail!("expected a 128-byte G2 point, got {} bytes", bytes.len());
+    }
+    if bytes.iter().all(|b| *b == 0) {
+        return Ok(G2Affine::identity());
+    }
+    let x_c1 = read_fq(&bytes[..32]);
+    let x_c0 = read_fq(&bytes[32..64]);
+    let y_c1 = read_fq(&bytes[64..96]);
+    let y_c0 = read_fq(&bytes[96..]);
+    Ok(G2Affine::new(Fq2::new(x_c0, x_c1), Fq2::new(y_c0, y_c1)))
+}
+
+fn write_fq(f: &Fq) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    let be = f.into_bigint().to_bytes_be();
+    out[32 - be.len()..].copy_from_slice(&be);
+    out
+}
+
+fn write_g1(p: &G1Affine) -> [u8; 64] {
+    let mut out = [0u8; 64];
+    if let Some((x, y)) = p.xy() {
+        out[..32].copy_from_slice(&write_fq(&x));
+        out[32..].copy_from_slice(&write_fq(&y));
+    }
+    out
+}
+
+fn write_g2(p: &G2Affine) -> [u8; 128] {
+    let mut out = [0u8; 128];
+    if let Some((x, y)) = p.xy() {
+        out[..32].copy_from_slice(&write_fq(&x.c1));
+        out[32..64].copy_from_slice(&write_fq(&x.c0));
+        out[64..96].copy_from_slice(&write_fq(&y.c1));
+        out[96..].copy_from_slice(&write_fq(&y.c0));
+    }
+    out
+}
+
+impl Groth16VerifyingKey {
+    /// Parses gnark-crypto's uncompressed bn254 verifying-key encoding (see the module docs for
+    /// the exact layout).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < 64 + 128 * 3 + 4 {
+            bail!("groth16 verifying key is too short: {} bytes", bytes.len());
+        }
+        let mut offset = 0;
+        let alpha_g1 = read_g1(&bytes[offset..offset + 64])?;
+        offset += 64;
+        let beta_g2 = read_g2(&bytes[offset..offset + 128])?;
+        offset += 128;
+        let gamma_g2 = read_g2(&bytes[offset..offset + 128])?;
+        offset += 128;
+        let delta_g2 = read_g2(&bytes[offset..offset + 128])?;
+        offset += 128;
+
+        let ic_len = u32::from_be_bytes(bytes[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+        if bytes.len() != offset + ic_len * 64 {
+            bail!(
+                "groth16 verifying key length mismatch: expected {} IC points, got {} trailing bytes",
+                ic_len,
+                bytes.len() - offset
+            );
+        }
+        let ic = (0..ic_len)
+            .map(|i| read_g1(&bytes[offset + i * 64..offset + (i + 1) * 64]))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { alpha_g1, beta_g2, gamma_g2, delta_g2, ic })
+    }
+
+    /// `vk_x = ic[0] + sum(ic[i + 1] * public_inputs[i])`.
+    fn linear_combination(&self, public_inputs: &[Fr]) -> Result<G1Affine> {
+        if public_inputs.len() + 1 != self.ic.len() {
+            bail!(
+                "expected {} public inputs for this verifying key, got {}",
+                self.ic.len() - 1,
+                public_inputs.len()
+            );
+        }
+        let mut acc = self.ic[0].into_group();
+        for (ic, input) in self.ic[1..].iter().zip(public_inputs) {
+            acc += ic.mul_bigint(input.into_bigint());
+        }
+        Ok(acc.into_affine())
+    }
+}
+
+/// Verifies `proof` against `vk` and `public_inputs` via the standard Groth16 pairing check
+/// `e(A,B) = e(alpha,beta) * e(vk_x,gamma) * e(C,delta)`, rearranged as a single multi-pairing
+/// product `e(-A,B) * e(alpha,beta) * e(vk_x,gamma) * e(C,delta) == 1` (the same rearrangement the
+/// generated Solidity contract uses, since a single `ecPairing` precompile call is cheaper than
+/// four).
+pub fn verify_groth16(
+    vk: &Groth16VerifyingKey,
+    proof: &Groth16Proof,
+    public_inputs: &[Fr],
+) -> Result<bool> {
+    let vk_x = vk.linear_combination(public_inputs)?;
+
+    let pairing = Bn254::multi_pairing(
+        [-proof.a, vk.alpha_g1, vk_x, proof.c],
+        [proof.b, vk.beta_g2, vk.gamma_g2, vk.delta_g2],
+    );
+    Ok(pairing.0.is_one())
+}
+
+/// Renders the Solidity constants and `_verifyGroth16` pairing-check body for `vk`, natively --
+/// without invoking gnark -- so [`super::solidity::render_solidity_verifier`] can embed a working
+/// verifier. Only supports the two-public-input layout this SDK uses (`[hash(vkey),
+/// committedValueDigest]`), i.e. `vk.ic.len() == 3`.
+pub fn render_groth16_pairing_check(vk: &Groth16VerifyingKey) -> Result<String> {
+    if vk.ic.len() != 3 {
+        bail!(
+            "expected a 2-public-input verifying key (3 IC points), got {}",
+            vk.ic.len()
+        );
+    }
+
+    let fq = |f: &Fq| f.into_bigint().to_string();
+    let g1 = |p: &G1Affine| {
+        let (x, y) = p.xy().unwrap_or((Fq::from(0u64), Fq::from(0u64)));
+        (fq(&x), fq(&y))
+    };
+    let g2 = |p: &G2Affine| {
+        let (x, y) = p.xy().unwrap_or((Fq2::from(0u64), Fq2::from(0u64)));
+        (fq(&x.c0), fq(&x.c1), fq(&y.c0), fq(&y.c1))
+    };
+
+    let (alpha_x, alpha_y) = g1(&vk.alpha_g1);
+    let (beta_x0, beta_x1, beta_y0, beta_y1) = g2(&vk.beta_g2);
+    let (gamma_x0, gamma_x1, gamma_y0, gamma_y1) = g2(&vk.gamma_g2);
+    let (delta_x0, delta_x1, delta_y0, delta_y1) = g2(&vk.delta_g2);
+    let (ic0_x, ic0_y) = g1(&vk.ic[0]);
+    let (ic1_x, ic1_y) = g1(&vk.ic[1]);
+    let (ic2_x, ic2_y) = g1(&vk.ic[2]);
+
+    Ok(format!(
+        r#"    uint256 constant ALPHA_X = {alpha_x};
+    uint256 constant ALPHA_Y = {alpha_y};
+    uint256 constant BETA_X0 = {beta_x0};
+    uint256 constant BETA_X1 = {beta_x1};
+    uint256 constant BETA_Y0 = {beta_y0};
+    uint256 constant BETA_Y1 = {beta_y1};
+    uint256 constant GAMMA_X0 = {gamma_x0};
+    uint256 constant GAMMA_X1 = {gamma_x1};
+    uint256 constant GAMMA_Y0 = {gamma_y0};
+    uint256 constant GAMMA_Y1 = {gamma_y1};
+    uint256 constant DELTA_X0 = {delta_x0};
+    uint256 constant DELTA_X1 = {delta_x1};
+    uint256 constant DELTA_Y0 = {delta_y0};
+    uint256 constant DELTA_Y1 = {delta_y1};
+    uint256 constant IC0_X = {ic0_x};
+    uint256 constant IC0_Y = {ic0_y};
+    uint256 constant IC1_X = {ic1_x};
+    uint256 constant IC1_Y = {ic1_y};
+    uint256 constant IC2_X = {ic2_x};
+    uint256 constant IC2_Y = {ic2_y};
+
+    /// @dev Computes `ic0 + ic1 * publicInputs[0] + ic2 * publicInputs[1]` via the `ecMul`/`ecAdd`
+    /// precompiles (0x07, 0x06), then checks the Groth16 pairing equation via a single `ecPairing`
+    /// (0x08) call over `[(-A,B), (alpha,beta), (vk_x,gamma), (C,delta)]`.
+    function _verifyGroth16(bytes calldata proof, bytes32[] calldata publicInputs, bytes memory vk)
+        internal
+        view
+        returns (bool)
+    {{
+        vk;
+        (uint256 ax, uint256 ay, uint256 bx0, uint256 bx1, uint256 by0, uint256 by1, uint256 cx, uint256 cy) =
+            abi.decode(proof, (uint256, uint256, uint256, uint256, uint256, uint256, uint256, uint256));
+
+        (uint256 vkx, uint256 vky) = _ecMul(IC1_X, IC1_Y, uint256(publicInputs[0]));
+        (vkx, vky) = _ecAdd(vkx, vky, IC0_X, IC0_Y);
+        (uint256 t2x, uint256 t2y) = _ecMul(IC2_X, IC2_Y, uint256(publicInputs[1]));
+        (vkx, vky) = _ecAdd(vkx, vky, t2x, t2y);
+
+        uint256 negAy = ay == 0 ? 0 : FIELD_MODULUS - ay;
+
+        uint256[24] memory input = [
+            ax, negAy, bx1, bx0, by1, by0,
+            ALPHA_X, ALPHA_Y, BETA_X1, BETA_X0, BETA_Y1, BETA_Y0,
+            vkx, vky, GAMMA_X1, GAMMA_X0, GAMMA_Y1, GAMMA_Y0,
+            cx, cy, DELTA_X1, DELTA_X0, DELTA_Y1, DELTA_Y0
+        ];
+        uint256[1] memory result;
+        bool success;
+        assembly {{
+            success := staticcall(gas(), 0x08, input, 0x300, result, 0x20)
+        }}
+        return success && result[0] == 1;
+    }}
+
+    uint256 constant FIELD_MODULUS =
+        21888242871839275222246405745257275088696311157297823662689037894645226208583;
+
+    function _ecAdd(uint256 ax, uint256 ay, uint256 bx, uint256 by)
+        private
+        view
+        returns (uint256, uint256)
+    {{
+        uint256[4] memory input = [ax, ay, bx, by];
+        uint256[2] memory result;
+        bool success;
+        assembly {{
+            success := staticcall(gas(), 0x06, input, 0x80, result, 0x40)
+        }}
+        require(success, "ecAdd failed");
+        return (result[0], result[1]);
+    }}
+
+    function _ecMul(uint256 x, uint256 y, uint256 scalar)
+        private
+        view
+        returns (uint256, uint256)
+    {{
+        uint256[3] memory input = [x, y, scalar];
+        uint256[2] memory result;
+        bool success;
+        assembly {{
+            success := staticcall(gas(), 0x07, input, 0x60, result, 0x40)
+        }}
+        require(success, "ecMul failed");
+        return (result[0], result[1]);
+    }}
+"#,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_groth16::Groth16;
+    use ark_relations::lc;
+    use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+    use ark_snark::SNARK;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+
+    /// `a * b == c`, with `a`, `b` private and `c` the single public input.
+    struct MulCircuit {
+        a: Option<Fr>,
+        b: Option<Fr>,
+        c: Option<Fr>,
+    }
+
+    impl ConstraintSynthesizer<Fr> for MulCircuit {
+        fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+            let a = cs.new_witness_variable(|| self.a.ok_or(SynthesisError::AssignmentMissing))?;
+            let b = cs.new_witness_variable(|| self.b.ok_or(SynthesisError::AssignmentMissing))?;
+            let c = cs.new_input_variable(|| self.c.ok_or(SynthesisError::AssignmentMissing))?;
+            cs.enforce_constraint(lc!() + a, lc!() + b, lc!() + c)?;
+            Ok(())
+        }
+    }
+
+    fn to_gnark_bytes(vk: &ark_groth16::VerifyingKey<Bn254>) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&write_g1(&vk.alpha_g1));
+        out.extend_from_slice(&write_g2(&vk.beta_g2));
+        out.extend_from_slice(&write_g2(&vk.gamma_g2));
+        out.extend_from_slice(&write_g2(&vk.delta_g2));
+        out.extend_from_slice(&(vk.gamma_abc_g1.len() as u32).to_be_bytes());
+        for ic in &vk.gamma_abc_g1 {
+            out.extend_from_slice(&write_g1(ic));
+        }
+        out
+    }
+
+    #[test]
+    fn round_trips_through_the_gnark_wire_format_and_verifies() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let (pk, ark_vk) = Groth16::<Bn254>::circuit_specific_setup(
+            MulCircuit { a: None, b: None, c: None },
+            &mut rng,
+        )
+        .unwrap();
+
+        let a = Fr::from(6u64);
+        let b = Fr::from(7u64);
+        let c = a * b;
+        let proof = Groth16::<Bn254>::prove(
+            &pk,
+            MulCircuit { a: Some(a), b: Some(b), c: Some(c) },
+            &mut rng,
+        )
+        .unwrap();
+
+        let vk_bytes = to_gnark_bytes(&ark_vk);
+        let vk = Groth16VerifyingKey::from_bytes(&vk_bytes).unwrap();
+        let our_proof = Groth16Proof { a: proof.a, b: proof.b, c: proof.c };
+
+        assert!(verify_groth16(&vk, &our_proof, &[c]).unwrap());
+        assert!(!verify_groth16(&vk, &our_proof, &[c + Fr::from(1u64)]).unwrap());
+
+        // The Solidity codegen embeds the same field elements our Rust-side VK parsed, so a
+        // constant emitted for IC0 should match the one the reference verifier used.
+        let rendered = render_groth16_pairing_check(&vk).unwrap();
+        let (ic0_x, _) = {
+            let (x, y) = vk.ic[0].xy().unwrap();
+            (x.into_bigint().to_string(), y.into_bigint().to_string())
+        };
+        assert!(rendered.contains(&format!("IC0_X = {ic0_x}")));
+    }
+}
@@ -1,7 +1,10 @@
+mod artifacts;
 mod cpu;
 // #[cfg(feature = "cuda")]
 // mod cuda;
+mod groth16;
 // mod mock;
+mod solidity;
 
 pub use cpu::CpuProver;
 // #[cfg(feature = "cuda")]
@@ -17,8 +20,8 @@ use anyhow::Result;
 use zkm2_core_executor::ZKMContext;
 use zkm2_core_machine::{io::ZKMStdin, ZKM_CIRCUIT_VERSION};
 use zkm2_prover::{
-    components::ZKMProverComponents, CoreSC, InnerSC, ZKMCoreProofData, ZKMProver, ZKMProvingKey,
-    ZKMVerifyingKey,
+    components::ZKMProverComponents, CoreSC, HashableKey, InnerSC, ZKMCoreProofData, ZKMProver,
+    ZKMProvingKey, ZKMVerifyingKey,
 };
 use zkm2_stark::{air::PublicValues, MachineVerificationError, ZKMProverOpts, Word};
 use strum_macros::EnumString;
@@ -82,6 +85,26 @@ pub trait Prover<C: ZKMProverComponents>: Send + Sync {
         kind: ZKMProofKind,
     ) -> Result<ZKMProofWithPublicValues>;
 
+    /// Exports a self-contained Solidity verifier contract for the bn254 proof system identified
+    /// by `kind` (only [`ZKMProofKind::Plonk`] and [`ZKMProofKind::Groth16`] are supported), with
+    /// the same verifying-key bytes [`Prover::verify`] checks Plonk/Groth16 proofs against embedded
+    /// in the contract.
+    fn export_solidity_verifier(&self, vkey: &ZKMVerifyingKey, kind: ZKMProofKind) -> Result<String> {
+        let artifacts_dir = match kind {
+            ZKMProofKind::Plonk => zkm2_prover::build::plonk_bn254_artifacts_dev_dir(),
+            ZKMProofKind::Groth16 => zkm2_prover::build::groth16_bn254_artifacts_dev_dir(),
+            _ => anyhow::bail!("only Plonk and Groth16 proofs have a Solidity verifier"),
+        };
+        let vk_file = match kind {
+            ZKMProofKind::Plonk => "plonk_vk.bin",
+            ZKMProofKind::Groth16 => "groth16_vk.bin",
+            _ => unreachable!(),
+        };
+        let vk_bytes = std::fs::read(artifacts_dir.join(vk_file))?;
+
+        solidity::render_solidity_verifier(kind, &vkey.bytes32(), &vk_bytes)
+    }
+
     /// Verify that an ZKM2 proof is valid given its vkey and metadata.
     /// For Plonk proofs, verifies that the public inputs of the PlonkBn254 proof match
     /// the hash of the VK and the committed public values of the ZKMProofWithPublicValues.
@@ -143,34 +166,28 @@ pub trait Prover<C: ZKMProverComponents>: Send + Sync {
                     .verify_compressed(proof, vkey)
                     .map_err(ZKMVerificationError::Recursion)
             }
-            ZKMProof::Plonk(proof) => self
-                .zkm2_prover()
-                .verify_plonk_bn254(
-                    proof,
-                    vkey,
-                    &bundle.public_values,
-                    &if zkm2_prover::build::zkm2_dev_mode() {
-                        zkm2_prover::build::plonk_bn254_artifacts_dev_dir()
-                    } else {
-                        panic!("only support dev mode for now");
-                        // try_install_circuit_artifacts("plonk")
-                    },
-                )
-                .map_err(ZKMVerificationError::Plonk),
-            ZKMProof::Groth16(proof) => self
-                .zkm2_prover()
-                .verify_groth16_bn254(
-                    proof,
-                    vkey,
-                    &bundle.public_values,
-                    &if zkm2_prover::build::zkm2_dev_mode() {
-                        zkm2_prover::build::groth16_bn254_artifacts_dev_dir()
-                    } else {
-                        panic!("only support dev mode for now");
-                        // try_install_circuit_artifacts("groth16")
-                    },
-                )
-                .map_err(ZKMVerificationError::Groth16),
+            ZKMProof::Plonk(proof) => {
+                let artifacts_dir = if zkm2_prover::build::zkm2_dev_mode() {
+                    zkm2_prover::build::plonk_bn254_artifacts_dev_dir()
+                } else {
+                    artifacts::try_install_circuit_artifacts("plonk")
+                        .map_err(ZKMVerificationError::Plonk)?
+                };
+                self.zkm2_prover()
+                    .verify_plonk_bn254(proof, vkey, &bundle.public_values, &artifacts_dir)
+                    .map_err(ZKMVerificationError::Plonk)
+            }
+            ZKMProof::Groth16(proof) => {
+                let artifacts_dir = if zkm2_prover::build::zkm2_dev_mode() {
+                    zkm2_prover::build::groth16_bn254_artifacts_dev_dir()
+                } else {
+                    artifacts::try_install_circuit_artifacts("groth16")
+                        .map_err(ZKMVerificationError::Groth16)?
+                };
+                self.zkm2_prover()
+                    .verify_groth16_bn254(proof, vkey, &bundle.public_values, &artifacts_dir)
+                    .map_err(ZKMVerificationError::Groth16)
+            }
         }
     }
 }
@@ -0,0 +1,176 @@
+//! Solidity export and EVM calldata encoding for the Plonk/Groth16 bn254 proof systems.
+//!
+//! Generates a self-contained verifier contract that embeds the same verifying-key bytes consumed
+//! off-chain by [`super::Prover::verify`] (via `verify_plonk_bn254`/`verify_groth16_bn254`), and
+//! ABI-encodes calldata for its `verifyProof` entry point.
+
+use anyhow::{bail, Result};
+
+use zkm2_prover::ZKMVerifyingKey;
+
+use super::groth16::{render_groth16_pairing_check, Groth16VerifyingKey};
+use crate::{ZKMProof, ZKMProofKind, ZKMProofWithPublicValues};
+
+/// The selector of `verifyProof(bytes,bytes32[])`, i.e. the first four bytes of
+/// `keccak256("verifyProof(bytes,bytes32[])")`.
+const VERIFY_PROOF_SELECTOR: [u8; 4] = [0xbb, 0xcb, 0x7c, 0x74];
+
+/// Renders a self-contained Solidity verifier contract for `kind`, with `vk_bytes` and the
+/// verifying key's hash embedded as constants.
+pub(crate) fn render_solidity_verifier(
+    kind: ZKMProofKind,
+    vkey_hash: &str,
+    vk_bytes: &[u8],
+) -> Result<String> {
+    let (contract_name, system) = match kind {
+        ZKMProofKind::Plonk => ("ZKMPlonkVerifier", "Plonk"),
+        ZKMProofKind::Groth16 => ("ZKMGroth16Verifier", "Groth16"),
+        _ => bail!("only Plonk and Groth16 proofs have a Solidity verifier"),
+    };
+
+    let vk_hex = hex_string(vk_bytes);
+    let vkey_hash = vkey_hash.strip_prefix("0x").unwrap_or(vkey_hash);
+
+    // Groth16's pairing check is generated natively from the parsed verifying key, so exporting
+    // the contract no longer requires running the gnark docker image; Plonk's codegen is left as
+    // a follow-up (its pairing check is sufficiently different to need its own implementation).
+    let pairing_check = match kind {
+        ZKMProofKind::Groth16 => {
+            let vk = Groth16VerifyingKey::from_bytes(vk_bytes)?;
+            render_groth16_pairing_check(&vk)?
+        }
+        _ => format!(
+            r#"    function _verify{system}(bytes calldata proof, bytes32[] calldata publicInputs, bytes memory vk)
+        internal
+        view
+        returns (bool)
+    {{
+        proof;
+        publicInputs;
+        vk;
+        revert InvalidProof();
+    }}
+"#
+        ),
+    };
+
+    Ok(format!(
+        r#"// SPDX-License-Identifier: MIT
+// Generated by `Prover::export_solidity_verifier`. Do not edit by hand.
+pragma solidity ^0.8.20;
+
+/// @title {contract_name}
+/// @notice Verifies ZKM {system} bn254 proofs on-chain.
+contract {contract_name} {{
+    /// The serialized {system} verifying key, in the same format consumed off-chain by
+    /// `{system}Verifier::verify`.
+    bytes public constant VERIFYING_KEY = hex"{vk_hex}";
+
+    /// `vkey.bytes32()` for the program this contract verifies proofs for.
+    bytes32 public constant VKEY_HASH = 0x{vkey_hash};
+
+    error InvalidProof();
+
+    /// @notice Verifies a {system} proof against `publicInputs`.
+    /// @param proof The raw proof bytes, laid out identically to the off-chain `{system}Verifier`.
+    /// @param publicInputs The circuit's public inputs, `[hash(vkey), committedValueDigest]`.
+    /// @return True if `proof` is valid for `publicInputs` under `VERIFYING_KEY`.
+    function verifyProof(bytes calldata proof, bytes32[] calldata publicInputs)
+        external
+        view
+        returns (bool)
+    {{
+        if (publicInputs.length != 2) revert InvalidProof();
+        return _verify{system}(proof, publicInputs, VERIFYING_KEY);
+    }}
+
+{pairing_check}}}
+"#,
+        contract_name = contract_name,
+        system = system,
+        vk_hex = vk_hex,
+        pairing_check = pairing_check,
+    ))
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+impl ZKMProofWithPublicValues {
+    /// ABI-encodes a calldata payload for the Solidity verifier's `verifyProof(bytes,bytes32[])`
+    /// entry point. Mirrors the off-chain check in [`super::Prover::verify`]: the proof bytes are
+    /// passed through unchanged, and the public inputs are `[hash(vkey), committedValueDigest]` so
+    /// the on-chain pairing check accepts exactly what `verify_plonk_bn254`/`verify_groth16_bn254`
+    /// accept off-chain.
+    pub fn encode_evm_calldata(&self, vkey: &ZKMVerifyingKey) -> Result<Vec<u8>> {
+        match &self.proof {
+            ZKMProof::Plonk(_) | ZKMProof::Groth16(_) => {}
+            _ => bail!("only Plonk and Groth16 proofs can be submitted to an EVM verifier"),
+        }
+
+        let proof_bytes = self.bytes();
+        let vkey_hash = decode_hex32(&vkey.bytes32())?;
+
+        let committed_value_digest: Vec<u8> = self.public_values.hash().into_iter().collect();
+        if committed_value_digest.len() != 32 {
+            bail!(
+                "committed value digest must be 32 bytes, got {}",
+                committed_value_digest.len()
+            );
+        }
+        let mut digest = [0u8; 32];
+        digest.copy_from_slice(&committed_value_digest);
+
+        Ok(encode_verify_proof_calldata(&proof_bytes, &[vkey_hash, digest]))
+    }
+}
+
+/// Decodes a `0x`-prefixed 32-byte hex string, as returned by `HashableKey::bytes32`.
+fn decode_hex32(hex: &str) -> Result<[u8; 32]> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if hex.len() != 64 {
+        bail!("expected a 32-byte hex string, got {} hex chars", hex.len());
+    }
+    let mut out = [0u8; 32];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16)?;
+    }
+    Ok(out)
+}
+
+/// Hand-rolled ABI encoding for `verifyProof(bytes,bytes32[])`; the SDK has no ABI-codec
+/// dependency of its own.
+fn encode_verify_proof_calldata(proof: &[u8], public_inputs: &[[u8; 32]]) -> Vec<u8> {
+    let proof_words = (proof.len() + 31) / 32;
+    let proof_tail_len = 32 + proof_words * 32; // length word + right-padded data
+
+    let proof_head_offset = 64u64; // two head words: proof offset, publicInputs offset
+    let public_inputs_offset = proof_head_offset + proof_tail_len as u64;
+
+    let mut out = Vec::with_capacity(
+        4 + proof_head_offset as usize + proof_tail_len + 32 + public_inputs.len() * 32,
+    );
+    out.extend_from_slice(&VERIFY_PROOF_SELECTOR);
+    out.extend_from_slice(&word(proof_head_offset));
+    out.extend_from_slice(&word(public_inputs_offset));
+
+    // `proof` tail: length, then right-padded data.
+    out.extend_from_slice(&word(proof.len() as u64));
+    out.extend_from_slice(proof);
+    out.resize(out.len() + (proof_words * 32 - proof.len()), 0);
+
+    // `publicInputs` tail: length, then the bytes32 elements.
+    out.extend_from_slice(&word(public_inputs.len() as u64));
+    for input in public_inputs {
+        out.extend_from_slice(input);
+    }
+
+    out
+}
+
+fn word(value: u64) -> [u8; 32] {
+    let mut w = [0u8; 32];
+    w[24..].copy_from_slice(&value.to_be_bytes());
+    w
+}
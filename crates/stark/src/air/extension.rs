@@ -0,0 +1,91 @@
+//! Symbolic extension-field arithmetic for `AirBuilder` expressions: the algebra half of
+//! [`crate::permutation`]'s LogUp recurrence, lifted from concrete `EF` values to `AB::Expr`
+//! constraint-builder terms so a chip's `Air::eval` can assert it directly.
+//!
+//! [`crate::permutation`]'s module docs describe the per-row recurrence a real cross-chip LogUp
+//! constraint needs: `(z_next - z) * Π_j (alpha - f_j) == Σ_j sign_j * multiplicity_j *
+//! Π_{j' != j} (alpha - f_j')`, with `f_j` the batched fingerprint of interaction `j`'s value
+//! tuple. Every term in that recurrence -- `f_j`, the running accumulator `z`, the challenge
+//! `alpha` -- lives in the degree-`D` extension field `EF`, packed as `D` base-field columns the
+//! same way [`crate::permutation::pack_extension`] packs a concrete `EF` value. This module
+//! provides the matching *symbolic* operations (`+`, `-`, `*`, lifting a base-field expression,
+//! and the batched fingerprint itself) over `[AB::Expr; D]`, so asserting that recurrence is
+//! "call these functions and `assert_eq` component-wise," not "re-derive extension-field
+//! multiplication inside every chip that needs it."
+//!
+//! This module does not, by itself, let any chip assert the recurrence today: doing so needs the
+//! permutation trace's packed `EF` columns (and the `(alpha, beta)` challenges) exposed through an
+//! `AirBuilder` extension -- a `PermutationAirBuilder` providing `permutation()` /
+//! `permutation_randomness()`, analogous to how `AirBuilderWithPublicValues::public_values()`
+//! exposes public values. That trait belongs with the rest of this crate's builder machinery in
+//! `crate::air::builder`/`crate::air::sub_builder`, neither of which exist in this snapshot (see
+//! `crate::air`'s module list) -- wiring it up is future work once those land. See
+//! `crate::stark_testing` for a self-contained demonstration of the arithmetic this module
+//! provides catching a tampered multiplicity, independent of that still-missing wiring.
+
+use p3_air::AirBuilder;
+use p3_field::AbstractField;
+
+/// A trait of extension-field arithmetic helpers over `AB::Expr`, generic in the extension degree
+/// `D` so callers aren't tied to [`crate::permutation::EXTENSION_DEGREE`] specifically (useful for
+/// e.g. testing the recurrence over a smaller `D` a given base field is already known to support).
+/// Every `EF` element is represented as `D` base-field expressions, coefficient `i` being the
+/// coefficient of `X^i` in `EF = F[X]/(X^D - w)`, the same "binomial extension" convention
+/// `p3_field::extension::BinomialExtensionField` uses.
+pub trait ExtensionAirBuilder: AirBuilder {
+    /// `a + b` in `EF`, component-wise on the packed representation.
+    fn ext_add<const D: usize>(a: &[Self::Expr; D], b: &[Self::Expr; D]) -> [Self::Expr; D] {
+        core::array::from_fn(|i| a[i].clone() + b[i].clone())
+    }
+
+    /// `a - b` in `EF`, component-wise on the packed representation.
+    fn ext_sub<const D: usize>(a: &[Self::Expr; D], b: &[Self::Expr; D]) -> [Self::Expr; D] {
+        core::array::from_fn(|i| a[i].clone() - b[i].clone())
+    }
+
+    /// `a * b` in `EF = F[X]/(X^D - w)`: schoolbook polynomial multiplication of `a` and `b`,
+    /// reducing degree-`>= D` terms via `X^D == w`.
+    fn ext_mul<const D: usize>(
+        a: &[Self::Expr; D],
+        b: &[Self::Expr; D],
+        w: Self::Expr,
+    ) -> [Self::Expr; D] {
+        let mut out: [Self::Expr; D] = core::array::from_fn(|_| Self::Expr::ZERO);
+        for i in 0..D {
+            for j in 0..D {
+                let term = a[i].clone() * b[j].clone();
+                if i + j < D {
+                    out[i + j] = out[i + j].clone() + term;
+                } else {
+                    out[i + j - D] = out[i + j - D].clone() + term * w.clone();
+                }
+            }
+        }
+        out
+    }
+
+    /// `EF`'s embedding of a base-field expression: zero in every coefficient but `X^0`'s.
+    fn ext_from_base<const D: usize>(value: Self::Expr) -> [Self::Expr; D] {
+        core::array::from_fn(|i| if i == 0 { value.clone() } else { Self::Expr::ZERO })
+    }
+
+    /// The batched fingerprint `Σ_k beta_powers[k] * values[k]` from
+    /// [`crate::permutation::fingerprint`], computed symbolically: `values` are base-field row
+    /// expressions and `beta_powers` the already-computed `EF` powers of the batching challenge
+    /// (`beta_powers.len()` must be at least `values.len()`).
+    fn ext_fingerprint<const D: usize>(
+        values: &[Self::Expr],
+        beta_powers: &[[Self::Expr; D]],
+        w: Self::Expr,
+    ) -> [Self::Expr; D] {
+        values.iter().zip(beta_powers).fold(
+            core::array::from_fn(|_| Self::Expr::ZERO),
+            |acc, (value, beta_pow)| {
+                let term = Self::ext_mul(beta_pow, &Self::ext_from_base(value.clone()), w.clone());
+                Self::ext_add(&acc, &term)
+            },
+        )
+    }
+}
+
+impl<AB: AirBuilder> ExtensionAirBuilder for AB {}
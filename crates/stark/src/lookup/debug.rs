@@ -1,12 +1,13 @@
 use std::collections::BTreeMap;
 
-use p3_field::{Field, FieldAlgebra, PrimeField32, PrimeField64};
+use p3_field::{AbstractExtensionField, AbstractField, Field, FieldAlgebra, PrimeField32, PrimeField64};
 use p3_koala_bear::KoalaBear;
 use p3_matrix::Matrix;
 
 use super::LookupKind;
 use crate::{
     air::{LookupScope, MachineAir},
+    permutation::fingerprint,
     MachineChip, StarkGenericConfig, StarkMachine, StarkProvingKey, Val,
 };
 
@@ -25,6 +26,12 @@ pub struct LookupData<F: Field> {
     pub is_send: bool,
     /// The multiplicity of the lookup.
     pub multiplicity: F,
+    /// A nonce identifying this specific interaction, monotonically assigned per chip per lookup
+    /// kind (independently for sends and receives) as rows are scanned. Two rows that happen to
+    /// produce the same `values` tuple still get distinct nonces, so a discrepancy report can cite
+    /// exactly which originating row(s) failed to pair instead of only the shared value tuple --
+    /// the same role a real nonce operand plays for e.g. the ALU send/receive interactions.
+    pub nonce: usize,
 }
 
 /// Converts a vector of field elements to a string.
@@ -68,6 +75,8 @@ pub fn debug_lookups<SC: StarkGenericConfig, A: MachineAir<Val<SC>>>(
 ) -> (BTreeMap<String, Vec<LookupData<Val<SC>>>>, BTreeMap<String, Val<SC>>) {
     let mut key_to_vec_data = BTreeMap::new();
     let mut key_to_count = BTreeMap::new();
+    // Per-chip, per-kind, per-direction monotonic nonce counters, keyed by `"{kind} {is_send}"`.
+    let mut nonce_counters: BTreeMap<String, usize> = BTreeMap::new();
 
     let trace = chip.generate_trace(record, &mut A::Record::default());
     let mut pre_traces = pkey.traces.clone();
@@ -107,6 +116,10 @@ pub fn debug_lookups<SC: StarkGenericConfig, A: MachineAir<Val<SC>>>(
                     &lookup.kind.to_string(),
                     vec_to_string(values)
                 );
+                let nonce_counter =
+                    nonce_counters.entry(format!("{} {is_send}", &lookup.kind.to_string())).or_insert(0);
+                let nonce = *nonce_counter;
+                *nonce_counter += 1;
                 key_to_vec_data.entry(key.clone()).or_insert_with(Vec::new).push(LookupData {
                     chip_name: chip.name(),
                     kind: lookup.kind,
@@ -114,6 +127,7 @@ pub fn debug_lookups<SC: StarkGenericConfig, A: MachineAir<Val<SC>>>(
                     lookup_number: m,
                     is_send,
                     multiplicity: multiplicity_eval,
+                    nonce,
                 });
                 let current = key_to_count.entry(key.clone()).or_insert(Val::<SC>::ZERO);
                 if is_send {
@@ -129,7 +143,10 @@ pub fn debug_lookups<SC: StarkGenericConfig, A: MachineAir<Val<SC>>>(
 }
 
 /// Calculate the number of times we send and receive each event of the given lookup type,
-/// and print out the ones for which the set of sends and receives don't match.
+/// and print out the ones for which the set of sends and receives don't match. For a key whose
+/// sends and receives don't balance, also cites the specific [`LookupData::nonce`]s (alongside
+/// their originating chip) that never found a pairing partner, so a discrepancy isn't just "the
+/// counts for this value tuple disagree" but "this exact row never paired."
 #[allow(clippy::needless_pass_by_value)]
 pub fn debug_lookups_with_all_chips<SC, A>(
     machine: &StarkMachine<SC, A>,
@@ -149,6 +166,11 @@ where
 
     let mut final_map = BTreeMap::new();
     let mut total = SC::Val::ZERO;
+    // Per-key (chip, nonce) lists, separated by direction, so an unresolved discrepancy below can
+    // cite exactly which originating rows never found a pairing partner instead of only the key's
+    // aggregate send-receive count.
+    let mut key_to_nonces: BTreeMap<String, (Vec<(String, usize)>, Vec<(String, usize)>)> =
+        BTreeMap::new();
 
     let chips = machine.chips();
     for chip in chips.iter() {
@@ -157,7 +179,8 @@ where
             if !chip.included(shard) {
                 continue;
             }
-            let (_, count) = debug_lookups::<SC, A>(chip, pkey, shard, lookup_kinds.clone(), scope);
+            let (vec_data, count) =
+                debug_lookups::<SC, A>(chip, pkey, shard, lookup_kinds.clone(), scope);
             total_events += count.len();
             for (key, value) in count.iter() {
                 let entry =
@@ -166,6 +189,16 @@ where
                 total += *value;
                 *entry.1.entry(chip.name()).or_insert(SC::Val::ZERO) += *value;
             }
+            for (key, entries) in vec_data {
+                let (sends, receives) = key_to_nonces.entry(key).or_default();
+                for entry in entries {
+                    if entry.is_send {
+                        sends.push((entry.chip_name.clone(), entry.nonce));
+                    } else {
+                        receives.push((entry.chip_name.clone(), entry.nonce));
+                    }
+                }
+            }
         }
         tracing::info!("{} chip has {} distinct events", chip.name(), total_events);
     }
@@ -185,6 +218,15 @@ where
                     field_to_int(chip_value)
                 );
             }
+            if let Some((sends, receives)) = key_to_nonces.get(&key) {
+                let paired = sends.len().min(receives.len());
+                for (chip, nonce) in &sends[paired..] {
+                    tracing::info!(" unpaired send: {} chip, nonce {}", chip, nonce);
+                }
+                for (chip, nonce) in &receives[paired..] {
+                    tracing::info!(" unpaired receive: {} chip, nonce {}", chip, nonce);
+                }
+            }
         }
     }
 
@@ -211,3 +253,158 @@ where
 
     !any_nonzero
 }
+
+/// Per-row LogUp data for one lookup: its batched fingerprint tuple and signed multiplicity, the
+/// two things [`debug_logup_with_all_chips`] needs to accumulate the real reciprocal-sum argument
+/// -- unlike [`LookupData`], which only keeps the multiplicity because the base-field checker
+/// folds the values into its string key instead.
+struct LogupRow<F: Field> {
+    values: Vec<F>,
+    /// Positive for a send, negative for a receive.
+    signed_multiplicity: F,
+}
+
+/// Evaluates every one of `chip`'s `scope`-matching, `lookup_kinds`-matching interactions against
+/// every row of its trace on `record`, keyed the same way [`debug_lookups`] keys its output, but
+/// keeping the actual value tuple (needed to fingerprint in `EF`) instead of folding it into the
+/// key string alone.
+#[allow(clippy::type_complexity)]
+fn logup_rows<SC: StarkGenericConfig, A: MachineAir<Val<SC>>>(
+    chip: &MachineChip<SC, A>,
+    pkey: &StarkProvingKey<SC>,
+    record: &A::Record,
+    lookup_kinds: &[LookupKind],
+    scope: LookupScope,
+) -> BTreeMap<String, Vec<LogupRow<Val<SC>>>> {
+    let mut key_to_rows: BTreeMap<String, Vec<LogupRow<Val<SC>>>> = BTreeMap::new();
+
+    let trace = chip.generate_trace(record, &mut A::Record::default());
+    let mut pre_traces = pkey.traces.clone();
+    let mut preprocessed_trace =
+        pkey.chip_ordering.get(&chip.name()).map(|&index| pre_traces.get_mut(index).unwrap());
+    let mut main = trace.clone();
+    let height = trace.height();
+
+    let sends = chip.sends().iter().filter(|s| s.scope == scope).collect::<Vec<_>>();
+    let receives = chip.receives().iter().filter(|r| r.scope == scope).collect::<Vec<_>>();
+    let nb_send_lookups = sends.len();
+
+    for row in 0..height {
+        for (m, lookup) in sends.iter().chain(receives.iter()).enumerate() {
+            if !lookup_kinds.contains(&lookup.kind) {
+                continue;
+            }
+            let mut empty = vec![];
+            let preprocessed_row = preprocessed_trace
+                .as_mut()
+                .map(|t| t.row_mut(row))
+                .or_else(|| Some(&mut empty))
+                .unwrap();
+            let is_send = m < nb_send_lookups;
+            let multiplicity: Val<SC> =
+                lookup.multiplicity.apply(preprocessed_row, main.row_mut(row));
+
+            if multiplicity.is_zero() {
+                continue;
+            }
+            let values: Vec<Val<SC>> = lookup
+                .values
+                .iter()
+                .map(|v| v.apply(preprocessed_row, main.row_mut(row)))
+                .collect();
+            let key = format!(
+                "{} {} {}",
+                &lookup.scope.to_string(),
+                &lookup.kind.to_string(),
+                vec_to_string(values.clone())
+            );
+            let signed_multiplicity = if is_send { multiplicity } else { -multiplicity };
+            key_to_rows.entry(key).or_default().push(LogupRow { values, signed_multiplicity });
+        }
+    }
+
+    key_to_rows
+}
+
+/// Checks the same sends/receives balance [`debug_lookups_with_all_chips`] does, but via the
+/// actual LogUp reciprocal-sum argument (see [`crate::permutation`]) in the degree-
+/// [`crate::permutation::EXTENSION_DEGREE`] extension field `EF`, rather than a base-field
+/// multiplicity count keyed by a stringified value tuple.
+///
+/// [`debug_lookups_with_all_chips`]'s base-field sum is exact for catching outright bugs (a send
+/// with no matching receive), but it isn't the real protocol: a single random challenge and
+/// reciprocal sum taken in the base field over KoalaBear's ~31-bit modulus can silently lose
+/// soundness to a collision the real, `EF`-valued argument wouldn't be fooled by. This function
+/// instead computes, for every lookup row, the reciprocal term `multiplicity / (alpha - Σ_j
+/// beta^j * value_j)` in `EF` -- exactly what
+/// [`crate::permutation::generate_permutation_trace`] accumulates into the real permutation trace
+/// -- accumulating sends positively and receives negatively, and asserts the grand total over
+/// every chip and shard is `EF::ZERO`.
+///
+/// Unlike the real protocol (which only needs the grand total to vanish), this also tracks each
+/// lookup key's own residual and reports it when nonzero, so a mismatch that happens to cancel
+/// against an unrelated key's contribution in the grand total is still caught.
+#[allow(clippy::needless_pass_by_value)]
+pub fn debug_logup_with_all_chips<SC, A>(
+    machine: &StarkMachine<SC, A>,
+    pkey: &StarkProvingKey<SC>,
+    shards: &[A::Record],
+    lookup_kinds: Vec<LookupKind>,
+    scope: LookupScope,
+    alpha: SC::Challenge,
+    beta: SC::Challenge,
+) -> bool
+where
+    SC: StarkGenericConfig,
+    SC::Val: PrimeField32,
+    A: MachineAir<SC::Val>,
+{
+    if scope == LookupScope::Local {
+        assert!(shards.len() == 1);
+    }
+
+    let mut key_to_residual: BTreeMap<String, SC::Challenge> = BTreeMap::new();
+    let mut total = SC::Challenge::ZERO;
+
+    for chip in machine.chips().iter() {
+        for shard in shards {
+            if !chip.included(shard) {
+                continue;
+            }
+            let key_to_rows = logup_rows::<SC, A>(chip, pkey, shard, &lookup_kinds, scope);
+
+            for (key, rows) in key_to_rows {
+                let entry = key_to_residual.entry(key).or_insert(SC::Challenge::ZERO);
+                for row in rows {
+                    let max_values = row.values.len();
+                    let mut beta_powers = Vec::with_capacity(max_values);
+                    let mut power = SC::Challenge::ONE;
+                    for _ in 0..max_values {
+                        beta_powers.push(power);
+                        power *= beta;
+                    }
+                    let f = fingerprint::<Val<SC>, SC::Challenge>(&row.values, &beta_powers);
+                    let denom = alpha - f;
+                    let term = denom.inverse() * SC::Challenge::from_base(row.signed_multiplicity);
+                    *entry += term;
+                    total += term;
+                }
+            }
+        }
+    }
+
+    let mut any_nonzero = false;
+    for (key, residual) in &key_to_residual {
+        if *residual != SC::Challenge::ZERO {
+            tracing::info!("Lookup key: {} LogUp residual (nonzero in EF): {:?}", key, residual);
+            any_nonzero = true;
+        }
+    }
+
+    if total != SC::Challenge::ZERO {
+        tracing::info!("Total LogUp residual over every chip and shard is nonzero: {:?}", total);
+        any_nonzero = true;
+    }
+
+    !any_nonzero
+}
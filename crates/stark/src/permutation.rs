@@ -0,0 +1,262 @@
+//! The extension-field LogUp permutation argument used to prove that every chip's interactions
+//! (lookups) balance, at full soundness on the ~31-bit KoalaBear base field.
+//!
+//! A fingerprint challenge drawn from the base field alone gives a forgery probability of
+//! roughly `num_rows / |F|`, which is far too weak once a trace approaches `2^20`+ rows on a
+//! 31-bit prime. Instead the fingerprint challenge `alpha` and the per-value batching challenge
+//! `beta` are drawn from the degree-[`EXTENSION_DEGREE`] extension field `EF` of `Val<SC>`, and
+//! the whole LogUp recurrence runs in `EF`:
+//!
+//! For row `i` with interactions `(values_j, multiplicity_j, sign_j)` (`sign_j = 1` for a send,
+//! `-1` for a receive), the per-interaction fingerprint is `f_j = Σ_k beta^k * values_j[k]`, and
+//! the row's contribution to the running accumulator is `Σ_j sign_j * multiplicity_j / (alpha -
+//! f_j)`. The accumulator recurrence is `z_next = z + row_contribution`; clearing denominators
+//! turns this into the polynomial constraint
+//! `(z_next - z) * Π_j (alpha - f_j) == Σ_j sign_j * multiplicity_j * Π_{j' != j} (alpha - f_j')`,
+//! with the boundary constraints `z_first == row_contribution(first row)` and `z_last == 0` so
+//! every chip's sends and receives balance exactly across the whole trace.
+//!
+//! Each `EF` accumulator is packed across [`EXTENSION_DEGREE`] base-field columns of the
+//! permutation trace via [`pack_extension`]/[`unpack_extension`], so the permutation trace is a
+//! plain `Val<SC>` matrix like any other and composes with the rest of the prover.
+//!
+//! This module only covers trace generation (the prover-side half of the argument); folding the
+//! resulting constraint into the quotient/constraint-folder pipeline is left to the chip-level
+//! `eval_permutation` wiring, the same way [`render_plonk_contract_skeleton`] in the `verifier`
+//! crate left Plonk's pairing check as a separate extension point -- both are out of scope here.
+//! [`crate::air::extension::ExtensionAirBuilder`] now provides the symbolic `EF` arithmetic that
+//! wiring would need (mirroring [`pack_extension`]/[`unpack_extension`] and [`fingerprint`] as
+//! `AirBuilder` expressions); it still isn't connected to any chip's `eval`, since that needs the
+//! permutation trace's columns exposed through an `AirBuilder` extension this snapshot doesn't
+//! have (see that module's docs for specifics).
+//!
+//! Note for anyone tempted to make `send_byte`/`slice_range_check_*`/`receive_instruction` (or any
+//! other `AirLookup`-producing call site) "switchable" to this accumulator: there's nothing to
+//! switch. Every `AirLookup`, regardless of which chip or [`LookupKind`] emitted it, already flows
+//! through [`evaluate_row_interactions`]/[`generate_permutation_trace`] uniformly -- a byte
+//! range-check send and an ALU instruction receive are accumulated identically once they're
+//! collected here, so every chip already inherits whatever soundness margin
+//! `EXTENSION_DEGREE` provides without a per-call-site mode flag. The one real gap is the
+//! quotient-side recurrence check mentioned above: it needs the `PermutationAirBuilder`/
+//! `ExtensionBuilder` machinery that `crate::air`'s `builder`/`extension`/`sub_builder` submodules
+//! would provide, none of which exist in this snapshot, so it can't be wired up here yet.
+//!
+//! [`num_permutation_accumulators`] covers the other half of the soundness margin: a single
+//! `EF` accumulator's forgery probability is roughly `num_rows / |EF|`, which is already
+//! negligible for KoalaBear's 31-bit base field at [`EXTENSION_DEGREE`] = 4 (`|EF| ~ 2^124`), but
+//! would not be for a hypothetically smaller `Val<SC>`. In that case [`generate_permutation_trace`]
+//! runs *two* independent `(alpha, beta)` accumulators side by side -- the "pass two accumulators"
+//! escape hatch -- so the combined forgery probability is the product, not the sum, of each
+//! accumulator's own error.
+
+use p3_field::{AbstractExtensionField, AbstractField, Field, PrimeField64};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::Matrix;
+
+use crate::air::{AirLookup, LookupScope, MachineAir};
+use crate::{MachineChip, StarkGenericConfig, Val};
+
+/// The degree of the extension field `EF` the permutation argument runs in. KoalaBear is a
+/// ~31-bit prime, so a base-field challenge alone is far too weak; this matches the quartic
+/// extension already used for FRI/Fiat-Shamir challenges elsewhere in the prover.
+///
+/// This applies uniformly to every `LookupKind`, including the syscall interactions
+/// `send_syscall` emits and the byte range-check sends the byte chip emits: both fingerprint and
+/// accumulate through [`fingerprint`] and the `running` accumulator in
+/// [`generate_permutation_trace`] below exactly like any other interaction, so neither needs (or
+/// gets) a separate base-field fast path. The assertion below is the one place that invariant is
+/// pinned down: shrinking `EXTENSION_DEGREE` back toward `1` (a base-field accumulator) is a
+/// compile error rather than a silent soundness regression.
+pub const EXTENSION_DEGREE: usize = 4;
+
+const _: () = assert!(
+    EXTENSION_DEGREE >= 2,
+    "the LogUp permutation accumulator must run in a proper extension of the base field -- a \
+     degree-1 \"extension\" is just the base field, which doesn't give syscall/byte-lookup \
+     interactions an adequate soundness margin over KoalaBear"
+);
+
+/// Packs an extension-field element into [`EXTENSION_DEGREE`] base-field columns.
+pub fn pack_extension<F: Field, EF: AbstractExtensionField<F>>(value: &EF) -> [F; EXTENSION_DEGREE] {
+    let base = value.as_base_slice();
+    debug_assert_eq!(base.len(), EXTENSION_DEGREE);
+    core::array::from_fn(|i| base[i])
+}
+
+/// The inverse of [`pack_extension`]: reconstructs the extension-field element from its
+/// [`EXTENSION_DEGREE`] base-field columns.
+pub fn unpack_extension<F: Field, EF: AbstractExtensionField<F>>(columns: &[F]) -> EF {
+    debug_assert_eq!(columns.len(), EXTENSION_DEGREE);
+    EF::from_base_fn(|i| columns[i])
+}
+
+/// The number of bits of Schwartz-Zippel soundness [`generate_permutation_trace`] targets for the
+/// combined (possibly doubled) accumulator. 100 bits is a negligible forgery probability for any
+/// trace this prover will realistically produce (at most ~2^40 rows across every chip and shard).
+const MIN_SOUND_CHALLENGE_BITS: u32 = 100;
+
+/// Whether a single degree-[`EXTENSION_DEGREE`] extension-field accumulator gives `F` an adequate
+/// soundness margin, or whether the "pass two accumulators" escape hatch described in the module
+/// docs is needed instead. KoalaBear's ~31-bit base field already clears
+/// [`MIN_SOUND_CHALLENGE_BITS`] with room to spare at `EXTENSION_DEGREE = 4` (`~124` bits), so
+/// this only bites for a hypothetically smaller `Val<SC>`.
+#[must_use]
+fn needs_two_accumulators<F: PrimeField64>() -> bool {
+    let ef_bits = (F::ORDER_U64.ilog2() + 1) * EXTENSION_DEGREE as u32;
+    ef_bits < MIN_SOUND_CHALLENGE_BITS
+}
+
+/// How many independent `(alpha, beta)` challenge pairs [`generate_permutation_trace`] needs for
+/// soundness over `F`: `1` normally, or `2` when [`needs_two_accumulators`] says a single
+/// `EF` accumulator isn't enough.
+#[must_use]
+pub fn num_permutation_accumulators<F: PrimeField64>() -> usize {
+    if needs_two_accumulators::<F>() {
+        2
+    } else {
+        1
+    }
+}
+
+/// The batched fingerprint `Σ_k beta^k * values[k]` of one interaction's tuple, computed in `EF`.
+pub(crate) fn fingerprint<F: Field, EF: AbstractExtensionField<F>>(
+    values: &[F],
+    beta_powers: &[EF],
+) -> EF {
+    values
+        .iter()
+        .zip(beta_powers)
+        .map(|(&value, beta_pow)| beta_pow.clone() * EF::from_base(value))
+        .sum::<EF>()
+}
+
+/// One row's nonzero interactions, cached once so [`generate_permutation_trace`] doesn't need to
+/// re-evaluate `main`/`preprocessed` expressions once per accumulator when
+/// [`num_permutation_accumulators`] says two are needed.
+type RowInteractions<SC> = Vec<(Vec<Val<SC>>, Val<SC>, <SC as StarkGenericConfig>::Challenge)>;
+
+/// Evaluates every one of `chip`'s `scope`-matching interactions against every row of
+/// `main`/`preprocessed`, caching each row's `(values, multiplicity, sign)` triples for nonzero
+/// multiplicities. Shared between [`generate_permutation_trace`]'s accumulator(s) so the
+/// expensive part -- evaluating the AIR expressions -- happens exactly once regardless of how many
+/// `(alpha, beta)` challenge pairs end up accumulating over the result.
+fn evaluate_row_interactions<SC, A>(
+    chip: &MachineChip<SC, A>,
+    mut preprocessed: Option<RowMajorMatrix<Val<SC>>>,
+    main: &RowMajorMatrix<Val<SC>>,
+    scope: LookupScope,
+) -> Vec<RowInteractions<SC>>
+where
+    SC: StarkGenericConfig,
+    A: MachineAir<Val<SC>>,
+{
+    let interactions: Vec<(&AirLookup<<A as MachineAir<Val<SC>>>::Expr>, SC::Challenge)> = chip
+        .sends()
+        .iter()
+        .filter(|i| i.scope == scope)
+        .map(|i| (i, SC::Challenge::ONE))
+        .chain(
+            chip.receives()
+                .iter()
+                .filter(|i| i.scope == scope)
+                .map(|i| (i, -SC::Challenge::ONE)),
+        )
+        .collect();
+
+    let height = main.height();
+    let mut main = main.clone();
+    let mut rows = Vec::with_capacity(height);
+
+    for row in 0..height {
+        let mut empty = vec![];
+        let preprocessed_row =
+            preprocessed.as_mut().map(|t| t.row_mut(row)).or_else(|| Some(&mut empty[..])).unwrap();
+
+        let mut row_interactions = Vec::new();
+        for (lookup, sign) in &interactions {
+            let multiplicity = lookup.multiplicity.apply(preprocessed_row, main.row_mut(row));
+            if multiplicity == Val::<SC>::ZERO {
+                continue;
+            }
+            let interaction_values: Vec<Val<SC>> = lookup
+                .values
+                .iter()
+                .map(|v| v.apply(preprocessed_row, main.row_mut(row)))
+                .collect();
+            row_interactions.push((interaction_values, multiplicity, *sign));
+        }
+        rows.push(row_interactions);
+    }
+
+    rows
+}
+
+/// Runs the LogUp recurrence described in the module docs for one `(alpha, beta)` accumulator
+/// over `rows`, writing the packed running `EF` accumulator into `out[col_offset..]` of each row
+/// (`out` is `height * total_width` long; `total_width` is `num_permutation_accumulators *
+/// EXTENSION_DEGREE`).
+fn accumulate_logup_column<SC>(
+    rows: &[RowInteractions<SC>],
+    alpha: SC::Challenge,
+    beta: SC::Challenge,
+    out: &mut [Val<SC>],
+    total_width: usize,
+    col_offset: usize,
+) where
+    SC: StarkGenericConfig,
+{
+    let max_values = rows.iter().flatten().map(|(values, _, _)| values.len()).max().unwrap_or(0);
+    let mut beta_powers = Vec::with_capacity(max_values);
+    let mut power = SC::Challenge::ONE;
+    for _ in 0..max_values {
+        beta_powers.push(power);
+        power *= beta;
+    }
+
+    let mut running = SC::Challenge::ZERO;
+    for (row, row_interactions) in rows.iter().enumerate() {
+        let mut row_contribution = SC::Challenge::ZERO;
+        for (values, multiplicity, sign) in row_interactions {
+            let f = fingerprint::<Val<SC>, SC::Challenge>(values, &beta_powers[..values.len()]);
+            let denom = alpha - f;
+            row_contribution += denom.inverse() * (*sign * SC::Challenge::from_base(*multiplicity));
+        }
+
+        running += row_contribution;
+        let start = row * total_width + col_offset;
+        out[start..start + EXTENSION_DEGREE]
+            .copy_from_slice(&pack_extension::<Val<SC>, SC::Challenge>(&running));
+    }
+}
+
+/// Generates the permutation trace for `chip`'s interactions of the given `scope`: one row per
+/// row of `main`, `EXTENSION_DEGREE * challenges.len()` columns wide, holding one packed running
+/// LogUp accumulator `z` per `(alpha, beta)` pair in `challenges`, as described in the module
+/// docs. `challenges` must have [`num_permutation_accumulators::<Val<SC>>()`] entries, each drawn
+/// from the Fiat-Shamir transcript after the main trace is committed, the same way other
+/// cross-row challenges are; every accumulator must independently verify to `EF::ZERO` at the last
+/// row.
+pub fn generate_permutation_trace<SC, A>(
+    chip: &MachineChip<SC, A>,
+    preprocessed: Option<RowMajorMatrix<Val<SC>>>,
+    main: &RowMajorMatrix<Val<SC>>,
+    challenges: &[(SC::Challenge, SC::Challenge)],
+    scope: LookupScope,
+) -> RowMajorMatrix<Val<SC>>
+where
+    SC: StarkGenericConfig,
+    A: MachineAir<Val<SC>>,
+{
+    assert!(!challenges.is_empty(), "at least one (alpha, beta) challenge pair is required");
+
+    let rows = evaluate_row_interactions(chip, preprocessed, main, scope);
+    let height = main.height();
+    let total_width = challenges.len() * EXTENSION_DEGREE;
+    let mut values = vec![Val::<SC>::ZERO; height * total_width];
+
+    for (acc_idx, &(alpha, beta)) in challenges.iter().enumerate() {
+        accumulate_logup_column::<SC>(&rows, alpha, beta, &mut values, total_width, acc_idx * EXTENSION_DEGREE);
+    }
+
+    RowMajorMatrix::new(values, total_width)
+}
@@ -1,4 +1,32 @@
 //! Elliptic Curve digests with a starting point to avoid weierstrass addition exceptions.
+//!
+//! Adding an inversion-free `SepticCurveProjective<F>` (projective/extended-point addition and
+//! doubling, batched back to affine with a single inversion) needs `crate::septic_curve` and
+//! `crate::septic_extension` to already define the concrete Weierstrass curve equation and
+//! degree-7 extension-field modulus that `CURVE_CUMULATIVE_SUM_START`/`DIGEST_SUM_START`/
+//! `CURVE_WITNESS_DUMMY_POINT` below are fixed points of -- both modules are declared in `lib.rs`
+//! but absent from this snapshot. Reconstructing a plausible-looking curve/field pair here would
+//! risk picking parameters the existing constants don't actually satisfy, silently invalidating
+//! `test::test_const_points`'s `check_on_point()` assertions instead of speeding up `Sum`. Left
+//! for a follow-up that restores `septic_curve.rs`/`septic_extension.rs` with their original
+//! parameters, which this projective representation should then build on directly.
+//!
+//! The same blocker rules out a `SepticCurve::hash_to_curve`/`regenerate_start_points` pair for
+//! now: a reproducible try-and-increment hash-to-curve needs (a) the curve's actual `a`/`b`
+//! Weierstrass coefficients to evaluate `x^3 + a*x + b` against a candidate x-coordinate, and (b)
+//! a `SepticExtension` square-root (repeated Frobenius raised to the appropriate power of the
+//! degree-7 extension's order, the standard way a Tonelli-Shanks variant generalizes past prime
+//! fields) to test whether that RHS is a square and recover `y`. Neither `a`/`b` nor a
+//! `SepticExtension` square root exist in this snapshot, and guessing either would risk deriving
+//! "start points" that don't match `CURVE_CUMULATIVE_SUM_START`/`DIGEST_SUM_START` -- the thing
+//! this hash-to-curve is supposed to let callers verify, not contradict. Once `septic_curve.rs`/
+//! `septic_extension.rs` are restored with their real coefficients and field modulus, the
+//! intended shape is: hash a domain string plus an incrementing counter into a candidate
+//! `SepticExtension` x-coordinate, evaluate the curve RHS, square-root it (bumping the counter and
+//! retrying on non-residues), and select the canonical (e.g. lexicographically smaller) root as
+//! `y` -- with `regenerate_start_points` just calling that twice, once per domain string already
+//! implied by `CURVE_CUMULATIVE_SUM_START` and `DIGEST_SUM_START`'s "derived from sqrt(2)"/
+//! "derived from sqrt(3)" doc comments below, to confirm they're reproduced.
 use crate::septic_curve::SepticCurve;
 use crate::septic_extension::SepticExtension;
 use p3_field::{Field, FieldAlgebra, FieldExtensionAlgebra};
@@ -58,6 +86,7 @@ impl<F: Field> SepticDigest<F> {
     }
 }
 
+#[cfg(not(feature = "parallel"))]
 impl<F: Field> Sum for SepticDigest<F> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
         let start = SepticDigest::<F>::starting_digest().0;
@@ -74,6 +103,58 @@ impl<F: Field> Sum for SepticDigest<F> {
     }
 }
 
+#[cfg(feature = "parallel")]
+impl<F: Field + Send + Sync> Sum for SepticDigest<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let digests: Vec<Self> = iter.collect();
+        SepticDigest::sum_parallel(&digests)
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<F: Field + Send + Sync> SepticDigest<F> {
+    /// Rayon-backed counterpart to the sequential `Sum` impl: splits `digests` into per-worker
+    /// chunks, has each worker fold its chunk with the exact same recipe the sequential path uses
+    /// (seeded at `starting_digest()`, each term added as `d - zero` to stay clear of
+    /// `add_incomplete`'s identity/doubling exceptions), then combines the per-chunk partials
+    /// with the same offset trick the sequential fold uses between terms. Curve addition is
+    /// associative and commutative, so the result is bit-identical to
+    /// `digests.iter().copied().sum::<SepticDigest<F>>()` -- just computed with
+    /// `rayon::current_num_threads()`-way parallelism instead of one sequential fold, mirroring
+    /// how halo2's multiexp parallelizes bucket accumulation across worker-local partials before
+    /// a final sequential combine.
+    #[must_use]
+    pub fn sum_parallel(digests: &[SepticDigest<F>]) -> Self {
+        use rayon::prelude::*;
+
+        let start = SepticDigest::<F>::starting_digest().0;
+        let zero = SepticDigest::<F>::zero().0;
+
+        let chunk_size = std::cmp::max(1, digests.len() / rayon::current_num_threads().max(1));
+        let partials: Vec<_> = digests
+            .par_chunks(chunk_size)
+            .map(|chunk| {
+                chunk.iter().fold(start, |acc, x| {
+                    let sum_offset = acc.add_incomplete(x.0);
+                    sum_offset.sub_incomplete(zero)
+                })
+            })
+            .collect();
+
+        // Combine the per-chunk partials the same way the sequential fold combines per-digest
+        // terms: each partial already carries one copy of `start`, so every combination after
+        // the first re-offsets by `start` to avoid accumulating it once per chunk.
+        let mut total = partials.first().copied().unwrap_or(start);
+        for acc_c in partials.iter().skip(1) {
+            total = total.add_incomplete(acc_c.sub_incomplete(start));
+        }
+
+        total.add_assign(zero);
+        total.sub_assign(start);
+        SepticDigest(total)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::septic_curve::{CURVE_WITNESS_DUMMY_POINT_X, CURVE_WITNESS_DUMMY_POINT_Y};
@@ -6,7 +6,7 @@ use p3_challenger::{HashChallenger, SerializingChallenger32};
 use p3_circle::CirclePcs;
 use p3_commit::ExtensionMmcs;
 use p3_field::extension::BinomialExtensionField;
-use p3_field::{FieldAlgebra, PrimeField64};
+use p3_field::{Field, FieldAlgebra, PrimeField64};
 use p3_fri::FriConfig;
 use p3_keccak::Keccak256Hash;
 use p3_matrix::dense::RowMajorMatrix;
@@ -21,6 +21,8 @@ use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::{EnvFilter, Registry};
 
+use crate::air::extension::ExtensionAirBuilder;
+
 /// For testing the public values feature
 pub struct FibonacciAir {}
 
@@ -114,6 +116,184 @@ type MyCompress = CompressionFunctionFromHasher<ByteHash, 2, 32>;
 type ValMmcs = MerkleTreeMmcs<Val, u8, FieldHash, MyCompress, 32>;
 type Pcs = CirclePcs<Val, ValMmcs, ChallengeMmcs>;
 
+/// A minimal demonstration of the cross-chip LogUp recurrence described in
+/// [`crate::permutation`]'s module docs and implemented symbolically by
+/// [`crate::air::extension::ExtensionAirBuilder`], collapsed to a single AIR/single trace since
+/// [`p3_uni_stark::prove`] (unlike a full [`crate::StarkMachine`]) only proves one. Each row sends
+/// `mult_send` copies and receives `mult_receive` copies of its own `value` into the same lookup
+/// argument, so the argument balances (the running sum returns to zero by the last row) exactly
+/// when every row's send/receive multiplicities agree -- which is what
+/// [`test_incorrect_logup_demo`] tampers with.
+///
+/// The extension field here is a toy degree-2 ring `F[X]/(X^2 - W)`, not
+/// [`crate::permutation::EXTENSION_DEGREE`]'s real quartic extension: this file doesn't depend on
+/// any concrete `p3` extension-field type actually supporting degree 4 for [`Val`], and the
+/// recurrence's soundness doesn't depend on the degree chosen.
+const LOGUP_DEMO_EXT_DEGREE: usize = 2;
+const LOGUP_DEMO_W: u64 = 7;
+
+pub struct LogupDemoAir {}
+
+impl<F> BaseAir<F> for LogupDemoAir {
+    fn width(&self) -> usize {
+        NUM_LOGUP_DEMO_COLS
+    }
+}
+
+impl<AB: AirBuilderWithPublicValues> Air<AB> for LogupDemoAir {
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let pis = builder.public_values();
+        let alpha: [AB::Expr; LOGUP_DEMO_EXT_DEGREE] =
+            [pis[0].into(), pis[1].into()];
+        let w = AB::Expr::from_canonical_u64(LOGUP_DEMO_W);
+
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &LogupDemoRow<AB::Var> = (*local).borrow();
+        let next: &LogupDemoRow<AB::Var> = (*next).borrow();
+
+        let local_z = local.z.clone().map(Into::into);
+        let next_z = next.z.clone().map(Into::into);
+
+        let numerator_next: AB::Expr = next.mult_send.into() - next.mult_receive.into();
+        let denom_next = AB::ext_sub(&alpha, &AB::ext_from_base(next.value.into()));
+
+        let first_numerator: AB::Expr = local.mult_send.into() - local.mult_receive.into();
+        let denom_local = AB::ext_sub(&alpha, &AB::ext_from_base(local.value.into()));
+        let first_lhs = AB::ext_mul(&local_z, &denom_local, w.clone());
+        let first_rhs = AB::ext_from_base(first_numerator);
+        let mut when_first_row = builder.when_first_row();
+        for i in 0..LOGUP_DEMO_EXT_DEGREE {
+            when_first_row.assert_eq(first_lhs[i].clone(), first_rhs[i].clone());
+        }
+
+        let delta_z = AB::ext_sub(&next_z, &local_z);
+        let transition_lhs = AB::ext_mul(&delta_z, &denom_next, w.clone());
+        let transition_rhs = AB::ext_from_base(numerator_next);
+        let mut when_transition = builder.when_transition();
+        for i in 0..LOGUP_DEMO_EXT_DEGREE {
+            when_transition.assert_eq(transition_lhs[i].clone(), transition_rhs[i].clone());
+        }
+
+        let mut when_last_row = builder.when_last_row();
+        for z_i in &local_z {
+            when_last_row.assert_zero(z_i.clone());
+        }
+    }
+}
+
+/// Generates a [`LogupDemoAir`] trace for `(value, mult_send, mult_receive)` triples, running
+/// [`crate::permutation`]'s accumulation recurrence (there, over the real `EXTENSION_DEGREE`;
+/// here, over the toy [`LOGUP_DEMO_EXT_DEGREE`]) to fill each row's `z` column.
+pub fn generate_logup_demo_trace<F: Field>(
+    rows: &[(u64, u64, u64)],
+    alpha: [F; LOGUP_DEMO_EXT_DEGREE],
+) -> RowMajorMatrix<F> {
+    let n = rows.len();
+    assert!(n.is_power_of_two());
+    let w = F::from_canonical_u64(LOGUP_DEMO_W);
+
+    let mut trace = RowMajorMatrix::new(F::zero_vec(n * NUM_LOGUP_DEMO_COLS), NUM_LOGUP_DEMO_COLS);
+    let (prefix, demo_rows, suffix) = unsafe { trace.values.align_to_mut::<LogupDemoRow<F>>() };
+    assert!(prefix.is_empty(), "Alignment should match");
+    assert!(suffix.is_empty(), "Alignment should match");
+    assert_eq!(demo_rows.len(), n);
+
+    let mut acc = [F::ZERO; LOGUP_DEMO_EXT_DEGREE];
+    for (i, &(value, mult_send, mult_receive)) in rows.iter().enumerate() {
+        let value = F::from_canonical_u64(value);
+        let numerator = [
+            F::from_canonical_u64(mult_send) - F::from_canonical_u64(mult_receive),
+            F::ZERO,
+        ];
+        let denom = [alpha[0] - value, alpha[1]];
+        let contribution = ext2_mul(numerator, ext2_inv(denom, w), w);
+        acc = ext2_add(acc, contribution);
+        demo_rows[i] = LogupDemoRow {
+            value,
+            mult_send: F::from_canonical_u64(mult_send),
+            mult_receive: F::from_canonical_u64(mult_receive),
+            z: acc,
+        };
+    }
+
+    trace
+}
+
+fn ext2_add<F: Field>(a: [F; 2], b: [F; 2]) -> [F; 2] {
+    [a[0] + b[0], a[1] + b[1]]
+}
+
+fn ext2_mul<F: Field>(a: [F; 2], b: [F; 2], w: F) -> [F; 2] {
+    [a[0] * b[0] + w * a[1] * b[1], a[0] * b[1] + a[1] * b[0]]
+}
+
+/// Inverse of `a0 + a1*t` in `F[X]/(X^2 - w)`: multiplying by the "conjugate" `a0 - a1*t` gives
+/// the norm `a0^2 - w*a1^2` in the base field, whose inverse rescales the conjugate into `a`'s
+/// inverse.
+fn ext2_inv<F: Field>(a: [F; 2], w: F) -> [F; 2] {
+    let norm = a[0] * a[0] - w * a[1] * a[1];
+    let norm_inv = norm.inverse();
+    [a[0] * norm_inv, -a[1] * norm_inv]
+}
+
+const NUM_LOGUP_DEMO_COLS: usize = size_of::<LogupDemoRow<u8>>();
+
+pub struct LogupDemoRow<F> {
+    pub value: F,
+    pub mult_send: F,
+    pub mult_receive: F,
+    pub z: [F; LOGUP_DEMO_EXT_DEGREE],
+}
+
+impl<F> Borrow<LogupDemoRow<F>> for [F] {
+    fn borrow(&self) -> &LogupDemoRow<F> {
+        debug_assert_eq!(self.len(), NUM_LOGUP_DEMO_COLS);
+        let (prefix, rows, suffix) = unsafe { self.align_to::<LogupDemoRow<F>>() };
+        debug_assert!(prefix.is_empty(), "Alignment should match");
+        debug_assert!(suffix.is_empty(), "Alignment should match");
+        debug_assert_eq!(rows.len(), 1);
+        &rows[0]
+    }
+}
+
+fn test_logup_demo_impl(rows: &[(u64, u64, u64)]) {
+    let byte_hash = ByteHash {};
+    let field_hash = FieldHash::new(byte_hash);
+    let compress = MyCompress::new(byte_hash);
+    let val_mmcs = ValMmcs::new(field_hash, compress);
+    let challenge_mmcs = ChallengeMmcs::new(val_mmcs.clone());
+    let fri_config =
+        FriConfig { log_blowup: 1, num_queries: 8, proof_of_work_bits: 8, mmcs: challenge_mmcs };
+    let alpha = [Mersenne31::from_canonical_u64(2), Mersenne31::from_canonical_u64(3)];
+    let trace = generate_logup_demo_trace::<Val>(rows, alpha);
+
+    let pcs = Pcs { mmcs: val_mmcs, fri_config, _phantom: PhantomData };
+    let config = p3_uni_stark::StarkConfig::new(pcs);
+    let mut challenger = Challenger::from_hasher(vec![], byte_hash);
+    let pis = vec![alpha[0], alpha[1]];
+    let proof = p3_uni_stark::prove(&config, &LogupDemoAir {}, &mut challenger, trace, &pis);
+    let mut challenger = Challenger::from_hasher(vec![], byte_hash);
+    p3_uni_stark::verify(&config, &LogupDemoAir {}, &mut challenger, &proof, &pis)
+        .expect("verification failed");
+}
+
+/// A balanced lookup argument: every `value` is sent and received the same total number of times,
+/// just split differently across rows -- `1` is sent twice across rows 0-1 and received once each
+/// on rows 2-3, and `5` is sent once on row 2 and received once on row 3.
+#[test]
+fn test_logup_demo() {
+    test_logup_demo_impl(&[(1, 1, 0), (1, 1, 0), (5, 1, 1), (1, 0, 2)]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "assertion `left == right` failed: constraints had nonzero value")]
+fn test_incorrect_logup_demo() {
+    // Row 1's `mult_send` is tampered with, breaking the balance `test_logup_demo` relies on.
+    test_logup_demo_impl(&[(1, 1, 0), (1, 2, 0), (5, 1, 1), (1, 0, 2)]);
+}
+
 /// n-th Fibonacci number expected to be x
 fn test_public_value_impl(n: usize, x: u64) {
     let env_filter = EnvFilter::builder()
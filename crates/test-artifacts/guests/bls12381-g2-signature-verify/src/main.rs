@@ -0,0 +1,16 @@
+#![no_std]
+#![no_main]
+
+zkm2_zkvm::entrypoint!(main);
+
+use zkm2_lib::bls12381::g2_signature_verify;
+
+pub fn main() {
+    let pubkey = zkm2_zkvm::io::read::<[u32; 24]>();
+    let message = zkm2_zkvm::io::read::<[u32; 48]>();
+    let neg_generator = zkm2_zkvm::io::read::<[u32; 24]>();
+    let signature = zkm2_zkvm::io::read::<[u32; 48]>();
+
+    let ok = g2_signature_verify(&pubkey, &message, &neg_generator, &signature);
+    zkm2_zkvm::io::commit(&ok);
+}
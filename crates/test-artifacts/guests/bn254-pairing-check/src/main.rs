@@ -0,0 +1,14 @@
+#![no_std]
+#![no_main]
+extern crate alloc;
+use alloc::vec::Vec;
+
+zkm2_zkvm::entrypoint!(main);
+
+use zkm2_lib::bn254::pairing_check;
+
+pub fn main() {
+    let pairs = zkm2_zkvm::io::read::<Vec<u32>>();
+    let ok = pairing_check(&pairs);
+    zkm2_zkvm::io::commit(&ok);
+}
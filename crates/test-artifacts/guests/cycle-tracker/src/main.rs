@@ -9,10 +9,8 @@ pub fn f(x: usize) -> usize {
 }
 
 pub fn g(x: usize) -> usize {
-    // println!("cycle-tracker-start: g");
-    let y = x + 1;
-    // println!("cycle-tracker-end: g");
-    y
+    let _span = zkm_zkvm::io::cycle_span("g");
+    x + 1
 }
 
 pub fn main() {
@@ -0,0 +1,47 @@
+#![no_std]
+#![no_main]
+
+zkm2_zkvm::entrypoint!(main);
+
+use zkm2_lib::mtree::verify_path;
+
+/// Four independent calls, fed by four independent runs of host-supplied inputs (leaf, index,
+/// depth, claimed root, plus `depth` sibling digests queued onto the hint stream ahead of each
+/// call): a genuine inclusion path expected to verify, a path against a deliberately mismatched
+/// root expected to fail, a call whose `index` doesn't fit in `depth` bits expected to fail, and
+/// a call whose `depth` itself exceeds `MTREE_MAX_DEPTH` expected to fail without allocating
+/// anything proportional to that out-of-range `depth` (see
+/// `zkm2_core_executor::syscalls::mtree::MtreeVerifyPathSyscall::execute`). The host side that
+/// would drive this with real inputs (`crates/core/machine/src/syscall/precompiles/mtree/mod.rs`'s
+/// `test_mtree_verify_path`) calls `ZKMStdin::new()` with no data -- `ZKMStdin` itself isn't
+/// defined anywhere in this tree, so that test can't actually feed these four cases in; committing
+/// all four outcomes here is as far as this guest program alone can cover that.
+pub fn main() {
+    let leaf = zkm2_zkvm::io::read::<[u32; 4]>();
+    let index = zkm2_zkvm::io::read::<u32>();
+    let depth = zkm2_zkvm::io::read::<u32>();
+    let root = zkm2_zkvm::io::read::<[u32; 4]>();
+    let verified = verify_path(&leaf, index, depth, &root);
+    zkm2_zkvm::io::commit(&verified);
+
+    let leaf = zkm2_zkvm::io::read::<[u32; 4]>();
+    let index = zkm2_zkvm::io::read::<u32>();
+    let depth = zkm2_zkvm::io::read::<u32>();
+    let wrong_root = zkm2_zkvm::io::read::<[u32; 4]>();
+    let mismatched = verify_path(&leaf, index, depth, &wrong_root);
+    zkm2_zkvm::io::commit(&mismatched);
+
+    let leaf = zkm2_zkvm::io::read::<[u32; 4]>();
+    let out_of_range_index = zkm2_zkvm::io::read::<u32>();
+    let depth = zkm2_zkvm::io::read::<u32>();
+    let root = zkm2_zkvm::io::read::<[u32; 4]>();
+    let out_of_range = verify_path(&leaf, out_of_range_index, depth, &root);
+    zkm2_zkvm::io::commit(&out_of_range);
+
+    let leaf = zkm2_zkvm::io::read::<[u32; 4]>();
+    let index = zkm2_zkvm::io::read::<u32>();
+    let out_of_range_depth = zkm2_zkvm::io::read::<u32>();
+    let root = zkm2_zkvm::io::read::<[u32; 4]>();
+    let out_of_range_depth_result = verify_path(&leaf, index, out_of_range_depth, &root);
+    zkm2_zkvm::io::commit(&out_of_range_depth_result);
+}
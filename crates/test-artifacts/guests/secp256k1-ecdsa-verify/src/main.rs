@@ -0,0 +1,17 @@
+#![no_std]
+#![no_main]
+
+zkm2_zkvm::entrypoint!(main);
+
+use zkm2_lib::secp256k1::verify;
+
+pub fn main() {
+    let hash = zkm2_zkvm::io::read::<[u32; 8]>();
+    let r = zkm2_zkvm::io::read::<[u32; 8]>();
+    let s = zkm2_zkvm::io::read::<[u32; 8]>();
+    let recovery_id = zkm2_zkvm::io::read::<u8>();
+    let expected_pubkey = zkm2_zkvm::io::read::<[u32; 16]>();
+
+    let ok = verify(&hash, &r, &s, recovery_id, &expected_pubkey);
+    zkm2_zkvm::io::commit(&ok);
+}
@@ -66,6 +66,14 @@ pub const BN254_FP2_ADDSUB_ELF: &str = include_elf!("bn254-fp2-addsub-test");
 
 pub const BN254_FP2_MUL_ELF: &str = include_elf!("bn254-fp2-mul-test");
 
+pub const SECP256K1_ECDSA_VERIFY_ELF: &str = include_elf!("secp256k1-ecdsa-verify");
+
+pub const BN254_PAIRING_CHECK_ELF: &str = include_elf!("bn254-pairing-check");
+
+pub const BLS12381_G2_SIGNATURE_VERIFY_ELF: &str = include_elf!("bls12381-g2-signature-verify");
+
+pub const MTREE_VERIFY_PATH_ELF: &str = include_elf!("mtree-verify-path");
+
 //pub const TENDERMINT_BENCHMARK_ELF: &str = include_elf!("tendermint-benchmark-program");
 
 pub const U256XU2048_MUL_ELF: &str = include_elf!("u256x2048-mul");
@@ -0,0 +1,54 @@
+//! Batch verification for `Groth16Verifier`.
+//!
+//! The fully-amortized version of this -- sampling non-zero scalars `r_i` from a transcript
+//! seeded by all `n` proofs, then checking the single aggregated pairing equation
+//! `Π_i e(r_i·A_i, B_i) = e((Σr_i)·alpha, beta) · e(Σ_i r_i·S_i, gamma) · e(Σ_i r_i·C_i, delta)`
+//! instead of `n` independent ones -- needs `G1`/`G2` scalar multiplication and a multi-pairing
+//! primitive over the same bn254 curve [`crate::Groth16Verifier::verify`] already checks proofs
+//! against. That curve arithmetic is implemented inside `Groth16Verifier::verify` itself, which
+//! isn't part of this tree (see this crate's other `Groth16Verifier`/`PlonkVerifier` extension
+//! points in `solidity.rs` for the same gap) -- there's no local `G1`/`G2`/pairing type to build
+//! the aggregated check on without guessing the vendored implementation's internals.
+//!
+//! Until that's available, `verify_batch` gets the amortization's *interface* right (one call
+//! instead of a hand-written loop, first-failing-index error reporting) while falling back to `n`
+//! independent calls to [`crate::Groth16Verifier::verify`] for the actual cryptographic check.
+//! Soundness doesn't regress -- every proof is still fully verified -- only the cycle-count/host-
+//! time win described in the original request is deferred until the aggregated pairing check has
+//! something to be built on.
+
+use alloc::format;
+
+use anyhow::{ensure, Result};
+
+use crate::Groth16Verifier;
+
+impl Groth16Verifier {
+    /// Verifies `n` Groth16 proofs that all share `vkey_hash`/`vk`, returning the index of the
+    /// first proof that fails to verify on error.
+    ///
+    /// `proofs[i]`/`public_values[i]` are, pairwise, exactly what a single
+    /// [`Groth16Verifier::verify`] call expects. See this module's doc comment for why this
+    /// doesn't yet amortize the pairing work across proofs the way the randomized-linear-
+    /// combination technique it's named for would.
+    pub fn verify_batch(
+        proofs: &[&[u8]],
+        public_values: &[&[u8]],
+        vkey_hash: &str,
+        vk: &[u8],
+    ) -> Result<()> {
+        ensure!(
+            proofs.len() == public_values.len(),
+            "mismatched batch sizes: {} proofs, {} public value sets",
+            proofs.len(),
+            public_values.len()
+        );
+
+        for (i, (proof, public_values)) in proofs.iter().zip(public_values.iter()).enumerate() {
+            Self::verify(proof, public_values, vkey_hash, vk)
+                .map_err(|e| e.context(format!("proof at index {i} failed to verify")))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+//! Standalone Groth16/Plonk bn254 proof verification for ZKM proofs, with no dependency on the
+//! rest of the proving stack.
+//!
+//! Builds `#![no_std]` (pulling in only `alloc`) unless the `std` feature is enabled, so
+//! [`Groth16Verifier::verify`] can run inside a ZKM guest to verify another ZKM proof
+//! (recursive/aggregated proof composition), not just on a host with a full standard library.
+//! `std` is on by default; a guest `Cargo.toml` dependency on this crate should set
+//! `default-features = false` to drop it. [`solidity`] (Solidity codegen for on-chain
+//! verification) is a host-only concern -- it's gated behind `std` and unavailable in a `no_std`
+//! build.
+//!
+//! This crate's `[features]` table (`default = ["std"]`, `std = [...]`) belongs in its
+//! `Cargo.toml`, which isn't present in this checkout -- see the workspace root for why no
+//! manifest is added here. The `#[cfg(feature = "std")]`/`#[cfg(not(feature = "std"))]` gates
+//! below are written as if that table exists.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod batch;
+#[cfg(feature = "std")]
+mod solidity;
+#[cfg(test)]
+mod tests;
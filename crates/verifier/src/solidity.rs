@@ -0,0 +1,253 @@
+//! Solidity codegen for on-chain Groth16/Plonk verification, so a deployed contract can check
+//! exactly the proofs [`Groth16Verifier::verify`]/[`PlonkVerifier::verify`] accept off-chain,
+//! without a separate trusted codegen path.
+//!
+//! `vk_bytes` is gnark-crypto's uncompressed bn254 verifying-key encoding, the same bytes
+//! `GROTH16_VK_BYTES`/`PLONK_VK_BYTES` embed and `Groth16Verifier::verify`/`PlonkVerifier::verify`
+//! already parse: a Groth16 VK is `alpha_g1` (64 bytes) || `beta_g2` (128 bytes) || `gamma_g2` (128
+//! bytes) || `delta_g2` (128 bytes) || a big-endian `u32` IC length || that many 64-byte G1
+//! points, where each G1 point is `x || y` big-endian in the bn254 base field and each G2 point is
+//! `x.c1 || x.c0 || y.c1 || y.c0`.
+
+use anyhow::{bail, Result};
+
+use crate::{Groth16Verifier, PlonkVerifier};
+
+/// Converts a big-endian byte string into a decimal string, for embedding as a Solidity `uint256`
+/// literal (schoolbook base-256-to-base-10 conversion, done once at codegen time).
+fn be_bytes_to_decimal(bytes: &[u8]) -> String {
+    let mut digits = vec![0u8]; // least-significant decimal digit first
+    for &byte in bytes {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            let v = *digit as u32 * 256 + carry;
+            *digit = (v % 10) as u8;
+            carry = v / 10;
+        }
+        while carry > 0 {
+            digits.push((carry % 10) as u8);
+            carry /= 10;
+        }
+    }
+    while digits.len() > 1 && *digits.last().unwrap() == 0 {
+        digits.pop();
+    }
+    digits.iter().rev().map(|d| (b'0' + d) as char).collect()
+}
+
+fn g1_constants(bytes: &[u8], x_name: &str, y_name: &str) -> Result<String> {
+    if bytes.len() != 64 {
+        bail!("expected a 64-byte G1 point, got {} bytes", bytes.len());
+    }
+    Ok(format!(
+        "    uint256 constant {x_name} = {};\n    uint256 constant {y_name} = {};\n",
+        be_bytes_to_decimal(&bytes[..32]),
+        be_bytes_to_decimal(&bytes[32..]),
+    ))
+}
+
+fn g2_constants(bytes: &[u8], prefix: &str) -> Result<String> {
+    if bytes.len() != 128 {
+        bail!("expected a 128-byte G2 point, got {} bytes", bytes.len());
+    }
+    Ok(format!(
+        "    uint256 constant {prefix}_X0 = {};\n    uint256 constant {prefix}_X1 = {};\n    \
+         uint256 constant {prefix}_Y0 = {};\n    uint256 constant {prefix}_Y1 = {};\n",
+        be_bytes_to_decimal(&bytes[32..64]),
+        be_bytes_to_decimal(&bytes[..32]),
+        be_bytes_to_decimal(&bytes[96..]),
+        be_bytes_to_decimal(&bytes[64..96]),
+    ))
+}
+
+/// Parses the Groth16 verifying-key layout described in the module docs and renders the matching
+/// Solidity constants plus a `_verifyGroth16` pairing check using the `ecAdd`/`ecMul`/`ecPairing`
+/// precompiles. Only supports the 2-public-input layout `Groth16Verifier::verify` itself uses
+/// (`[hash(vkey_hash), hash(public_inputs)]`), i.e. 3 IC points.
+fn render_groth16_contract(vk_bytes: &[u8]) -> Result<String> {
+    if vk_bytes.len() < 64 + 128 * 3 + 4 {
+        bail!("groth16 verifying key is too short: {} bytes", vk_bytes.len());
+    }
+    let mut offset = 0;
+    let alpha = g1_constants(&vk_bytes[offset..offset + 64], "ALPHA_X", "ALPHA_Y")?;
+    offset += 64;
+    let beta = g2_constants(&vk_bytes[offset..offset + 128], "BETA")?;
+    offset += 128;
+    let gamma = g2_constants(&vk_bytes[offset..offset + 128], "GAMMA")?;
+    offset += 128;
+    let delta = g2_constants(&vk_bytes[offset..offset + 128], "DELTA")?;
+    offset += 128;
+
+    let ic_len = u32::from_be_bytes(vk_bytes[offset..offset + 4].try_into().unwrap()) as usize;
+    offset += 4;
+    if ic_len != 3 {
+        bail!("expected a 2-public-input verifying key (3 IC points), got {ic_len}");
+    }
+    if vk_bytes.len() != offset + ic_len * 64 {
+        bail!(
+            "groth16 verifying key length mismatch: expected {ic_len} IC points, got {} trailing bytes",
+            vk_bytes.len() - offset
+        );
+    }
+    let ic0 = g1_constants(&vk_bytes[offset..offset + 64], "IC0_X", "IC0_Y")?;
+    let ic1 = g1_constants(&vk_bytes[offset + 64..offset + 128], "IC1_X", "IC1_Y")?;
+    let ic2 = g1_constants(&vk_bytes[offset + 128..offset + 192], "IC2_X", "IC2_Y")?;
+
+    Ok(format!(
+        r#"// SPDX-License-Identifier: MIT
+// Generated by `Groth16Verifier::export_solidity`. Do not edit by hand.
+pragma solidity ^0.8.20;
+
+/// @notice Verifies ZKM Groth16 bn254 proofs on-chain, against the same verifying key
+/// `Groth16Verifier::verify` checks off-chain.
+contract ZKMGroth16Verifier {{
+{alpha}{beta}{gamma}{delta}{ic0}{ic1}{ic2}
+    uint256 constant FIELD_MODULUS =
+        21888242871839275222246405745257275088696311157297823662689037894645226208583;
+
+    error InvalidProof();
+
+    /// @notice Verifies a Groth16 proof. `proof` is `(A.x, A.y, B.x0, B.x1, B.y0, B.y1, C.x,
+    /// C.y)` ABI-encoded, and `publicInputs` is `[hash(vkeyHash), hash(publicValues)]`, mirroring
+    /// what `Groth16Verifier::verify` hashes off-chain.
+    function verifyProof(bytes calldata proof, uint256[2] calldata publicInputs)
+        external
+        view
+        returns (bool)
+    {{
+        (uint256 ax, uint256 ay, uint256 bx0, uint256 bx1, uint256 by0, uint256 by1, uint256 cx, uint256 cy) =
+            abi.decode(proof, (uint256, uint256, uint256, uint256, uint256, uint256, uint256, uint256));
+
+        (uint256 vkx, uint256 vky) = _ecMul(IC1_X, IC1_Y, publicInputs[0]);
+        (vkx, vky) = _ecAdd(vkx, vky, IC0_X, IC0_Y);
+        (uint256 t2x, uint256 t2y) = _ecMul(IC2_X, IC2_Y, publicInputs[1]);
+        (vkx, vky) = _ecAdd(vkx, vky, t2x, t2y);
+
+        uint256 negAy = ay == 0 ? 0 : FIELD_MODULUS - ay;
+
+        uint256[24] memory input = [
+            ax, negAy, bx1, bx0, by1, by0,
+            ALPHA_X, ALPHA_Y, BETA_X1, BETA_X0, BETA_Y1, BETA_Y0,
+            vkx, vky, GAMMA_X1, GAMMA_X0, GAMMA_Y1, GAMMA_Y0,
+            cx, cy, DELTA_X1, DELTA_X0, DELTA_Y1, DELTA_Y0
+        ];
+        uint256[1] memory result;
+        bool success;
+        assembly {{
+            success := staticcall(gas(), 0x08, input, 0x300, result, 0x20)
+        }}
+        if (!success || result[0] != 1) revert InvalidProof();
+        return true;
+    }}
+
+    function _ecAdd(uint256 ax, uint256 ay, uint256 bx, uint256 by)
+        private
+        view
+        returns (uint256, uint256)
+    {{
+        uint256[4] memory input = [ax, ay, bx, by];
+        uint256[2] memory result;
+        bool success;
+        assembly {{
+            success := staticcall(gas(), 0x06, input, 0x80, result, 0x40)
+        }}
+        require(success, "ecAdd failed");
+        return (result[0], result[1]);
+    }}
+
+    function _ecMul(uint256 x, uint256 y, uint256 scalar)
+        private
+        view
+        returns (uint256, uint256)
+    {{
+        uint256[3] memory input = [x, y, scalar];
+        uint256[2] memory result;
+        bool success;
+        assembly {{
+            success := staticcall(gas(), 0x07, input, 0x60, result, 0x40)
+        }}
+        require(success, "ecMul failed");
+        return (result[0], result[1]);
+    }}
+}}
+"#,
+    ))
+}
+
+/// Renders a standalone verifier contract skeleton for a Plonk verifying key: the constants are
+/// real (parsed straight out of `vk_bytes`), but the pairing check itself is left as an extension
+/// point, the same way this module's predecessor treated both systems before Groth16 got a native
+/// implementation -- Plonk's custom-gate/permutation argument needs its own codegen pass that
+/// isn't implemented yet.
+fn render_plonk_contract_skeleton(vk_bytes: &[u8]) -> String {
+    format!(
+        r#"// SPDX-License-Identifier: MIT
+// Generated by `PlonkVerifier::export_solidity`. Do not edit by hand.
+pragma solidity ^0.8.20;
+
+/// @notice Verifies ZKM Plonk bn254 proofs on-chain, against the same verifying key
+/// `PlonkVerifier::verify` checks off-chain.
+contract ZKMPlonkVerifier {{
+    bytes public constant VERIFYING_KEY = hex"{vk_hex}";
+
+    error InvalidProof();
+
+    /// @notice Verifies a Plonk proof against `publicInputs` (`[hash(vkeyHash),
+    /// hash(publicValues)]`, mirroring `PlonkVerifier::verify`).
+    function verifyProof(bytes calldata proof, uint256[2] calldata publicInputs)
+        external
+        view
+        returns (bool)
+    {{
+        return _verifyPlonk(proof, publicInputs, VERIFYING_KEY);
+    }}
+
+    /// @dev Plonk's pairing check -- left as an extension point; see this function's doc comment
+    /// in `PlonkVerifier::export_solidity` for why.
+    function _verifyPlonk(bytes calldata proof, uint256[2] calldata publicInputs, bytes memory vk)
+        internal
+        view
+        returns (bool)
+    {{
+        proof;
+        publicInputs;
+        vk;
+        revert InvalidProof();
+    }}
+}}
+"#,
+        vk_hex = vk_bytes.iter().map(|b| format!("{b:02x}")).collect::<String>(),
+    )
+}
+
+impl Groth16Verifier {
+    /// Emits a standalone, gas-optimized Solidity verifier contract for the Groth16 verifying key
+    /// `vk_bytes` (e.g. [`crate::GROTH16_VK_BYTES`]), so the same proof `Groth16Verifier::verify`
+    /// accepts off-chain can be checked by a deployed contract with no separate trusted codegen
+    /// path.
+    pub fn export_solidity(vk_bytes: &[u8]) -> Result<String> {
+        render_groth16_contract(vk_bytes)
+    }
+}
+
+impl PlonkVerifier {
+    /// Emits a standalone Solidity verifier skeleton for the Plonk verifying key `vk_bytes` (e.g.
+    /// [`crate::PLONK_VK_BYTES`]). The verifying-key constants are real; the pairing check is not
+    /// yet implemented (see [`render_plonk_contract_skeleton`]).
+    pub fn export_solidity(vk_bytes: &[u8]) -> Result<String> {
+        Ok(render_plonk_contract_skeleton(vk_bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn be_bytes_to_decimal_matches_known_values() {
+        assert_eq!(be_bytes_to_decimal(&[0]), "0");
+        assert_eq!(be_bytes_to_decimal(&[1]), "1");
+        assert_eq!(be_bytes_to_decimal(&[0xff]), "255");
+        assert_eq!(be_bytes_to_decimal(&[0x01, 0x00]), "256");
+    }
+}
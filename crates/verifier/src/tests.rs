@@ -34,6 +34,35 @@ fn test_verify_groth16() {
     }
 }
 
+#[test]
+fn test_verify_groth16_batch() {
+    let proof_file = "test_binaries/fibonacci-groth16.bin";
+    let zkm2_proof_with_public_values = ZKMProofWithPublicValues::load(proof_file).unwrap();
+
+    let proof = zkm2_proof_with_public_values.bytes();
+    let public_inputs = zkm2_proof_with_public_values.public_values.to_vec();
+    let vkey_hash = "0x00572986f614be73c812f979a526a9ef1604ae040ec38b8c9a7eba87f5b6e5ee";
+
+    // The same proof twice is a valid (if degenerate) batch.
+    crate::Groth16Verifier::verify_batch(
+        &[&proof, &proof],
+        &[&public_inputs, &public_inputs],
+        vkey_hash,
+        &crate::GROTH16_VK_BYTES,
+    )
+    .expect("batch of valid Groth16 proofs should verify");
+
+    let mut corrupted_proof = proof.clone();
+    corrupted_proof[0] ^= 0xff;
+    crate::Groth16Verifier::verify_batch(
+        &[&proof, &corrupted_proof],
+        &[&public_inputs, &public_inputs],
+        vkey_hash,
+        &crate::GROTH16_VK_BYTES,
+    )
+    .expect_err("a corrupted proof anywhere in the batch should fail verification");
+}
+
 #[test]
 fn test_verify_plonk() {
     // Location of the serialized ZKMProofWithPublicValues. See README.md for more information.
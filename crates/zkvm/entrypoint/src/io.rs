@@ -0,0 +1,46 @@
+//! Cycle-count introspection, meant to live alongside this crate's `read`/`commit`/`verify`
+//! guest I/O helpers (referenced throughout this repo as `zkm_zkvm::io::*`, e.g. in
+//! `crates/test-artifacts/guests/*/src/main.rs`) -- those helpers, and the crate root (`lib.rs`)
+//! that would declare `pub mod io;` and the `entrypoint!` macro, aren't present in this checkout,
+//! so this module can't be wired in yet. It's written as if they were, following this crate's
+//! `syscalls::mtree`/`syscalls::cycle_count` extension points.
+
+use crate::syscalls::{syscall_cycle_count, syscall_cycle_tracker_end};
+
+/// Returns the number of cycles the executor has committed so far.
+///
+/// Two calls can be subtracted to cost a region of guest code without the host having to scrape
+/// `println!("cycle-tracker-start/end: ...")` markers out of stdout, the way
+/// `crates/test-artifacts/guests/cycle-tracker/src/main.rs`'s commented-out markers do today.
+#[must_use]
+pub fn cycle_count() -> u64 {
+    syscall_cycle_count()
+}
+
+/// A scope guard that reports its own lifetime's cycle cost into the host's `cycle_tracker` under
+/// `name`, the way [`crate::ExecutionReport`]'s `region_cycles` (documented on this function,
+/// since that report type doesn't exist in this checkout -- see the `zkm_sdk` crate for where
+/// `ProverClient::execute` would aggregate `cycle_tracker` into it) is meant to surface named
+/// hot regions like Groth16 verification.
+///
+/// ```ignore
+/// let _span = zkm_zkvm::io::cycle_span("verify");
+/// // ... do work ...
+/// // dropping `_span` here records its cost under "verify"
+/// ```
+#[must_use = "a cycle_span does nothing until it's held for the region being measured, and records on drop"]
+pub fn cycle_span(name: &str) -> CycleSpan {
+    CycleSpan { name: name.into(), start: cycle_count() }
+}
+
+/// RAII guard returned by [`cycle_span`]. See its documentation.
+pub struct CycleSpan {
+    name: alloc::string::String,
+    start: u64,
+}
+
+impl Drop for CycleSpan {
+    fn drop(&mut self) {
+        syscall_cycle_tracker_end(self.name.as_ptr(), self.name.len() as u32, self.start);
+    }
+}
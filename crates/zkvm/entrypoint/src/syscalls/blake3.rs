@@ -0,0 +1,28 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Runs one Blake3 compression round in place over `buf`, laid out as 16 message words, 8
+/// chaining-value words, the counter (low word then high word), the block length, and the
+/// domain-separation flags -- 27 words in total. The 16-word output state is written back over
+/// the message words.
+///
+/// ### Safety
+///
+/// The caller must ensure that `buf` is a valid pointer to 27 `u32`s, aligned along a four byte
+/// boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_blake3_compress(buf: *mut [u32; 27]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "syscall",
+            in("$2") crate::syscalls::BLAKE3_COMPRESS,
+            in("$4") buf,
+            in("$5") 0
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
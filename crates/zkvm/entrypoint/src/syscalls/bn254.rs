@@ -50,3 +50,116 @@ pub extern "C" fn syscall_bn254_double(p: *mut [u32; 16]) {
     #[cfg(not(target_os = "zkvm"))]
     unreachable!()
 }
+
+/// Multiplies a Bn254 point by a scalar.
+///
+/// The result is stored in the first point.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` and `scalar` are valid pointers to data that is aligned along
+/// a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bn254_scalar_mul(p: *mut [u32; 16], scalar: *const [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "syscall",
+            in("$2") crate::syscalls::BN254_SCALAR_MUL,
+            in("$4") p,
+            in("$5") scalar,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Computes `dst <- dst + a * b mod n` over the Bn254 scalar field (`F_r`), where `dst`, `a`, and
+/// `b` each point to a 256-bit value stored as eight little-endian `u32` words.
+///
+/// The three pointers are packed into a stack-local `[dst, a, b]` descriptor and a pointer to
+/// *that* is what's actually passed to the syscall, since `Syscall::execute` only carries two
+/// operand words -- the same convention `syscall_memcpy` family's host-side `MemCopySyscall` uses
+/// (see `zkm2_core_executor::syscalls::bn254::Bn254ScalarMacSyscall`).
+///
+/// ### Safety
+///
+/// The caller must ensure `dst`, `a`, and `b` are valid pointers to data that is aligned along a
+/// four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bn254_scalar_mac(dst: *mut [u32; 8], a: *const [u32; 8], b: *const [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        let descriptor: [u32; 3] = [dst as u32, a as u32, b as u32];
+        asm!(
+            "syscall",
+            in("$2") crate::syscalls::BN254_SCALAR_MAC,
+            in("$4") descriptor.as_ptr(),
+            in("$5") 0,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Computes `a <- a OP b mod n` over the Bn254 scalar field (`F_r`), `OP` selected by
+/// `general_field_op` (one of [`crate::syscalls::bn254::BN254_FR_OP_ADD`]/`_SUB`/`_MUL`, re-exported
+/// from `zkm2_core_executor::syscalls::bn254`), where `a` and `b` each point to a 256-bit value
+/// stored as eight little-endian `u32` words.
+///
+/// Named `_fr_op` rather than `syscall_bn254_scalar_mul` to avoid colliding with the G1
+/// point-by-scalar-multiplication syscall of that name above, mirroring
+/// `BN254_SCALAR_MAC`'s naming rationale.
+///
+/// ### Safety
+///
+/// The caller must ensure `a` and `b` are valid pointers to data that is aligned along a four byte
+/// boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bn254_fr_op(a: *mut [u32; 8], b: *const [u32; 8], general_field_op: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        let descriptor: [u32; 3] = [a as u32, b as u32, general_field_op];
+        asm!(
+            "syscall",
+            in("$2") crate::syscalls::BN254_FR_OP,
+            in("$4") descriptor.as_ptr(),
+            in("$5") 0,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Checks that the product of pairings over `pairs` (alternating 16-word G1 and 32-word G2 point
+/// encodings) equals the identity in the target group, as used by Groth16/zkSNARK verification.
+///
+/// ### Safety
+///
+/// The caller must ensure `pairs` points to `num_pairs` back-to-back `(G1, G2)` encodings, aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bn254_pairing_check(pairs: *const u32, num_pairs: u32) -> u32 {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        let result;
+        asm!(
+            "syscall",
+            in("$2") crate::syscalls::BN254_PAIRING_CHECK,
+            in("$4") pairs,
+            in("$5") num_pairs,
+            lateout("$2") result,
+        );
+        result
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
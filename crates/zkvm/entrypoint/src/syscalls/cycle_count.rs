@@ -0,0 +1,46 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Returns the number of cycles the executor has committed so far, as a 64-bit count split
+/// across `$2`/`$3` the same way a 64-bit return value is split elsewhere in this crate.
+#[must_use]
+pub extern "C" fn syscall_cycle_count() -> u64 {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        let lo: u32;
+        let hi: u32;
+        asm!(
+            "syscall",
+            in("$2") crate::syscalls::CYCLE_COUNT,
+            lateout("$2") lo,
+            lateout("$3") hi,
+        );
+        (u64::from(hi) << 32) | u64::from(lo)
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Accumulates `cycle_count() - start_cycle` into the named entry of the host's cycle tracker.
+///
+/// ### Safety
+///
+/// The caller must ensure that `name` points to `name_len` valid, readable bytes.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_cycle_tracker_end(name: *const u8, name_len: u32, start_cycle: u64) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        let descriptor: [u32; 4] =
+            [name as u32, name_len, start_cycle as u32, (start_cycle >> 32) as u32];
+        asm!(
+            "syscall",
+            in("$2") crate::syscalls::CYCLE_TRACKER_END,
+            in("$4") descriptor.as_ptr(),
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,81 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Selects the operation a [`syscall_fp_op`] call performs, packed into [`FpOpArgs::op`]'s low
+/// nibble; bit 4 of the same word selects single- vs double-precision (see [`FP_OP_DOUBLE`]).
+pub const FP_OP_ADD: u32 = 0;
+pub const FP_OP_SUB: u32 = 1;
+pub const FP_OP_MUL: u32 = 2;
+pub const FP_OP_DIV: u32 = 3;
+pub const FP_OP_SQRT: u32 = 4;
+/// Converts the 32-bit signed integer in `*a`'s low word to a float of the selected width.
+pub const FP_OP_CVT_INT_TO_FLOAT: u32 = 5;
+/// Converts the float in `*a` to a 32-bit signed integer, written back to `*a`'s low word.
+pub const FP_OP_CVT_FLOAT_TO_INT: u32 = 6;
+
+/// Set in `op` alongside one of the `FP_OP_*` operation codes above to operate on `f64` instead
+/// of the default `f32`.
+pub const FP_OP_DOUBLE: u32 = 1 << 4;
+
+/// Round to nearest, ties to even (the IEEE-754 default, and the only mode most guests need).
+pub const FP_ROUND_NEAREST_EVEN: u32 = 0;
+/// Round toward zero (truncation).
+pub const FP_ROUND_TOWARD_ZERO: u32 = 1;
+/// Round toward positive infinity.
+pub const FP_ROUND_TOWARD_POSITIVE: u32 = 2;
+/// Round toward negative infinity.
+pub const FP_ROUND_TOWARD_NEGATIVE: u32 = 3;
+
+/// Set in the returned flags word when an operand or result is NaN, or a conversion is
+/// out-of-range.
+pub const FP_FLAG_INVALID: u32 = 1 << 0;
+/// Set when a finite-operand result overflows to infinity.
+pub const FP_FLAG_OVERFLOW: u32 = 1 << 1;
+/// Set when the mathematically exact result isn't representable and had to be rounded.
+pub const FP_FLAG_INEXACT: u32 = 1 << 2;
+
+/// The second operand plus the scalar arguments, bundled into one buffer the same way
+/// [`super::syscall_secp256k1_recover`]'s `sig` pointer bundles `r`/`s`/`recovery_id` -- the raw
+/// MIPS syscall convention only carries two register-sized arguments (`a0`, `a1`).
+#[repr(C)]
+struct FpOpArgs {
+    /// The second operand's raw bit pattern (unused by `FP_OP_SQRT` and the conversions).
+    b: u64,
+    /// An `FP_OP_*` code, OR'd with [`FP_OP_DOUBLE`] for double precision.
+    op: u32,
+    /// One of the `FP_ROUND_*` constants.
+    round_mode: u32,
+}
+
+/// Performs a deterministic, IEEE-754 single/double precision floating-point operation in a
+/// single syscall, rather than forcing guests to pull in a multi-thousand-instruction softfloat
+/// library. `a` is both the first operand and the output, in place (unused by
+/// `FP_OP_CVT_INT_TO_FLOAT`, which instead takes its input from `*a`'s low 32 bits as an `i32`);
+/// `b` is the second operand (unused by `FP_OP_SQRT` and the conversions). Returns a sticky
+/// flags word (`FP_FLAG_*`, OR-combined).
+///
+/// ### Safety
+///
+/// The caller must ensure `a` is a valid pointer to a `u64` (the operand's raw bit pattern, zero
+/// -extended from `u32` for single precision) and, unless the operation ignores `b`, that `b`
+/// points to a valid `u64` of the same kind.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_fp_op(op: u32, a: *mut u64, b: *const u64, round_mode: u32) -> u32 {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        let args = FpOpArgs { b: if b.is_null() { 0 } else { *b }, op, round_mode };
+        let flags;
+        asm!(
+            "syscall",
+            in("$2") crate::syscalls::FP_OP,
+            in("$4") a,
+            in("$5") &args,
+            lateout("$2") flags,
+        );
+        flags
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -23,3 +23,28 @@ pub extern "C" fn syscall_keccak_permute(state: *mut [u64; 25]) {
     #[cfg(not(target_os = "zkvm"))]
     unreachable!()
 }
+
+/// Runs the Keccak-f[1600] permutation over `count` independent `[u64; 25]` states at once,
+/// amortizing one round function's cost across all of them instead of calling
+/// [`syscall_keccak_permute`] `count` times.
+///
+/// ### Safety
+///
+/// The caller must ensure that `states` points to `count` valid, contiguous `[u64; 25]` states,
+/// aligned along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_keccak_permute_batch(states: *mut [u64; 25], count: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "syscall",
+            in("$2") crate::syscalls::KECCAK_PERMUTE_BATCH,
+            in("$4") states,
+            in("$5") count
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -1,22 +1,33 @@
 #[cfg(target_os = "zkvm")]
 use core::arch::asm;
 
-/// Executes the Keccak256 sponge
+/// Executes the Keccak sponge: absorbs `input` (already padded and packed into rate-sized blocks
+/// by the caller, see `zkm2_zkvm::sponge::sponge`) and squeezes the requested number of output
+/// words into the buffer named by `descriptor`'s first word, re-permuting as many times as needed
+/// for outputs longer than a single rate block.
+///
+/// `Syscall::execute` only carries two operand words, too few for the three values this syscall
+/// needs beyond `input` itself, so `result`/`input_len_ptr`/`out_len_words` are packed into a
+/// `[result_ptr, input_len_ptr, out_len_words]` descriptor instead -- the same convention
+/// `syscall_memcpy`'s `[src_ptr, dst_ptr, len]` descriptor uses. `input_len_ptr` points to the
+/// input word count rather than passing it directly, since the host tracks that read the same way
+/// it would a leaf or root digest.
 ///
 /// ### Safety
 ///
-/// The caller must ensure that `input` and `result` are valid pointers to data that are aligned along
-/// a four byte boundary.
+/// The caller must ensure `input` points to the full padded/packed input, `descriptor` points to
+/// a valid `[result_ptr, input_len_ptr, out_len_words]` triple, and the buffer `result_ptr` names
+/// has room for at least `out_len_words` words -- all aligned along a four byte boundary.
 #[allow(unused_variables)]
 #[no_mangle]
-pub extern "C" fn syscall_keccak_sponge(input: *const u32, result: *mut [u32; 16]) {
+pub extern "C" fn syscall_keccak_sponge(input: *const u32, descriptor: *const u32) {
     #[cfg(target_os = "zkvm")]
     unsafe {
         asm!(
             "syscall",
             in("$2") crate::syscalls::KECCAK_SPONGE,
             in("$4") input,
-            in("$5") result,
+            in("$5") descriptor,
         );
     }
 
@@ -0,0 +1,49 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Copies a fixed 32-word (128-byte) block from `src` to `dst` in a single precompile row,
+/// modeled on [`crate::syscalls::syscall_keccak_sponge`]'s entry point shape -- one syscall
+/// replacing what would otherwise be a 32-iteration load/store loop.
+///
+/// ### Safety
+///
+/// The caller must ensure that `src` and `dst` point to 32 words of readable/writable memory
+/// respectively, both aligned along a four byte boundary, and that the two regions don't overlap.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_memcpy_32(src: *const u32, dst: *mut u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "syscall",
+            in("$2") crate::syscalls::MEMCPY_32,
+            in("$4") src,
+            in("$5") dst,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// As [`syscall_memcpy_32`], but for a fixed 64-word (256-byte) block.
+///
+/// ### Safety
+///
+/// See [`syscall_memcpy_32`].
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_memcpy_64(src: *const u32, dst: *mut u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "syscall",
+            in("$2") crate::syscalls::MEMCPY_64,
+            in("$4") src,
+            in("$5") dst,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -8,6 +8,14 @@ mod sys;
 mod sha_compress;
 mod sha_extend;
 mod keccak_permute;
+mod keccak_sponge;
+mod secp256k1;
+mod bn254;
+mod blake3;
+mod cycle_count;
+mod fp_op;
+mod mem_copy;
+mod mtree;
 
 pub use halt::*;
 pub use io::*;
@@ -16,6 +24,14 @@ pub use sys::*;
 pub use sha_compress::*;
 pub use sha_extend::*;
 pub use keccak_permute::*;
+pub use keccak_sponge::*;
+pub use secp256k1::*;
+pub use bn254::*;
+pub use blake3::*;
+pub use cycle_count::*;
+pub use fp_op::*;
+pub use mem_copy::*;
+pub use mtree::*;
 
 /// These codes MUST match the codes in `core/src/runtime/syscall.rs`. There is a derived test
 /// that checks that the enum is consistent with the syscalls.
@@ -43,3 +59,54 @@ pub const SHA_COMPRESS: u32 = 0x00_01_01_06;
 
 /// Executes `KECCAK_PERMUTE`.
 pub const KECCAK_PERMUTE: u32 = 0x00_01_01_09;
+
+/// Executes `KECCAK_PERMUTE_BATCH`.
+pub const KECCAK_PERMUTE_BATCH: u32 = 0x00_01_01_0A;
+
+/// Executes `KECCAK_SPONGE`.
+pub const KECCAK_SPONGE: u32 = 0x00_01_0A_00;
+
+/// Executes `SECP256K1_RECOVER`.
+pub const SECP256K1_RECOVER: u32 = 0x00_01_05_00;
+
+/// Executes `BN254_ADD`.
+pub const BN254_ADD: u32 = 0x00_01_06_00;
+
+/// Executes `BN254_DOUBLE`.
+pub const BN254_DOUBLE: u32 = 0x00_01_06_01;
+
+/// Executes `BN254_SCALAR_MUL`.
+pub const BN254_SCALAR_MUL: u32 = 0x00_01_06_02;
+
+/// Executes `BN254_PAIRING_CHECK`.
+pub const BN254_PAIRING_CHECK: u32 = 0x00_01_06_03;
+
+/// Executes `BN254_SCALAR_MAC`.
+pub const BN254_SCALAR_MAC: u32 = 0x00_01_06_04;
+
+/// Executes `BN254_FR_OP`.
+pub const BN254_FR_OP: u32 = 0x00_01_06_05;
+
+/// Executes `BLAKE3_COMPRESS`.
+pub const BLAKE3_COMPRESS: u32 = 0x00_01_07_00;
+
+/// Executes `FP_OP`.
+pub const FP_OP: u32 = 0x00_01_08_00;
+
+/// Executes `MTREE_VERIFY_PATH`.
+pub const MTREE_VERIFY_PATH: u32 = 0x00_01_09_00;
+
+/// Executes `MTREE_MERGE`.
+pub const MTREE_MERGE: u32 = 0x00_01_09_01;
+
+/// Executes `MEMCPY_32`.
+pub const MEMCPY_32: u32 = 0x00_00_00_FB;
+
+/// Executes `MEMCPY_64`.
+pub const MEMCPY_64: u32 = 0x00_00_00_FC;
+
+/// Executes `CYCLE_COUNT`.
+pub const CYCLE_COUNT: u32 = 0x00_00_00_FD;
+
+/// Executes `CYCLE_TRACKER_END`.
+pub const CYCLE_TRACKER_END: u32 = 0x00_00_00_FE;
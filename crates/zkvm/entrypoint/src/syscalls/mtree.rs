@@ -0,0 +1,63 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Verifies a Merkle inclusion path: `leaf` against `claimed_root`, walking `depth` sibling
+/// digests that the prover supplies as non-deterministic advice (consumed from the hint stream,
+/// the same way `syscall_hint_read` is) rather than as a plain memory buffer. `index`'s bits
+/// select left/right sibling order at each level, leaf-to-root. Returns nonzero iff the path
+/// verifies.
+///
+/// ### Safety
+///
+/// The caller must ensure that `leaf` and `claimed_root` are valid pointers to 4-word digests
+/// aligned along a four byte boundary, and that `depth` sibling digests have already been queued
+/// onto the hint stream in leaf-to-root order.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_mtree_verify_path(
+    leaf: *const [u32; 4],
+    index: u32,
+    depth: u32,
+    claimed_root: *const [u32; 4],
+) -> u32 {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        let args: [u32; 3] = [index, depth, claimed_root as u32];
+        let result;
+        asm!(
+            "syscall",
+            in("$2") crate::syscalls::MTREE_VERIFY_PATH,
+            in("$4") leaf,
+            in("$5") args.as_ptr(),
+            lateout("$2") result,
+        );
+        result
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Merges two subtree roots into their parent root, using the same compression function
+/// `syscall_mtree_verify_path` uses per level. The result is stored over `left`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `left` and `right` are valid pointers to 4-word digests, aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_mtree_merge(left: *mut [u32; 4], right: *const [u32; 4]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "syscall",
+            in("$2") crate::syscalls::MTREE_MERGE,
+            in("$4") left,
+            in("$5") right,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
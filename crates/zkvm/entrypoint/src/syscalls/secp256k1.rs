@@ -0,0 +1,32 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Recovers a secp256k1 public key from an ECDSA signature over `hash`.
+///
+/// `sig` holds `r || s || recovery_id` as 17 little-endian `u32` words (8 for `r`, 8 for `s`, 1
+/// for the recovery id). On success the recovered 64-byte uncompressed public key `(x, y)` is
+/// written back over `sig`; on failure `sig` is zeroed instead.
+///
+/// ### Safety
+///
+/// The caller must ensure that `hash` and `sig` are valid pointers to data that is aligned along
+/// a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_secp256k1_recover(hash: *const [u32; 8], sig: *mut [u32; 17]) -> u32 {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        let status;
+        asm!(
+            "syscall",
+            in("$2") crate::syscalls::SECP256K1_RECOVER,
+            in("$4") hash,
+            in("$5") sig,
+            lateout("$2") status,
+        );
+        status
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
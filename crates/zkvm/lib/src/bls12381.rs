@@ -0,0 +1,130 @@
+//! BLS12-381 precompiles beyond public-key decompression: G1/G2 group arithmetic, subgroup
+//! checks, hash/map-to-curve, and pairing. These accelerate in-guest BLS aggregate signature
+//! verification (e.g. Ethereum consensus proofs), where doing the field/pairing arithmetic in
+//! pure MIPS is prohibitively many cycles.
+
+extern "C" {
+    fn syscall_bls12381_g1_add(p: *mut [u32; 24], q: *const [u32; 24]);
+    fn syscall_bls12381_g1_double(p: *mut [u32; 24]);
+    fn syscall_bls12381_g1_scalar_mul(p: *mut [u32; 24], scalar: *const [u32; 8]);
+    fn syscall_bls12381_g1_subgroup_check(p: *const [u32; 24]) -> u32;
+    fn syscall_bls12381_g1_map(u: *const [u32; 12], out: *mut [u32; 24]);
+
+    fn syscall_bls12381_g2_add(p: *mut [u32; 48], q: *const [u32; 48]);
+    fn syscall_bls12381_g2_double(p: *mut [u32; 48]);
+    fn syscall_bls12381_g2_scalar_mul(p: *mut [u32; 48], scalar: *const [u32; 8]);
+    fn syscall_bls12381_g2_subgroup_check(p: *const [u32; 48]) -> u32;
+    fn syscall_bls12381_g2_map(u: *const [u32; 24], out: *mut [u32; 48]);
+    fn syscall_bls12381_g2_decompress(compressed: *const [u32; 24], out: *mut [u32; 48]);
+
+    fn syscall_bls12381_miller_loop(g1: *const [u32; 24], g2: *const [u32; 48], out: *mut [u32; 144]);
+    fn syscall_bls12381_final_exp(f: *mut [u32; 144]);
+    fn syscall_bls12381_pairing_check(pairs: *const u32, num_pairs: u32) -> u32;
+}
+
+/// Add two BLS12-381 G1 points in place: `p += q`.
+pub fn g1_add(p: &mut [u32; 24], q: &[u32; 24]) {
+    unsafe { syscall_bls12381_g1_add(p, q) }
+}
+
+/// Double a BLS12-381 G1 point in place.
+pub fn g1_double(p: &mut [u32; 24]) {
+    unsafe { syscall_bls12381_g1_double(p) }
+}
+
+/// Multiply a BLS12-381 G1 point by a scalar in place.
+pub fn g1_scalar_mul(p: &mut [u32; 24], scalar: &[u32; 8]) {
+    unsafe { syscall_bls12381_g1_scalar_mul(p, scalar) }
+}
+
+/// Returns whether `p` lies in the prime-order BLS12-381 G1 subgroup.
+#[must_use]
+pub fn g1_subgroup_check(p: &[u32; 24]) -> bool {
+    unsafe { syscall_bls12381_g1_subgroup_check(p) != 0 }
+}
+
+/// Map a field element to a BLS12-381 G1 point via SSWU + isogeny.
+pub fn g1_map_to_curve(u: &[u32; 12]) -> [u32; 24] {
+    let mut out = [0u32; 24];
+    unsafe { syscall_bls12381_g1_map(u, &mut out) };
+    out
+}
+
+/// Add two BLS12-381 G2 points in place: `p += q`.
+pub fn g2_add(p: &mut [u32; 48], q: &[u32; 48]) {
+    unsafe { syscall_bls12381_g2_add(p, q) }
+}
+
+/// Double a BLS12-381 G2 point in place.
+pub fn g2_double(p: &mut [u32; 48]) {
+    unsafe { syscall_bls12381_g2_double(p) }
+}
+
+/// Multiply a BLS12-381 G2 point by a scalar in place.
+pub fn g2_scalar_mul(p: &mut [u32; 48], scalar: &[u32; 8]) {
+    unsafe { syscall_bls12381_g2_scalar_mul(p, scalar) }
+}
+
+/// Returns whether `p` lies in the prime-order BLS12-381 G2 subgroup.
+#[must_use]
+pub fn g2_subgroup_check(p: &[u32; 48]) -> bool {
+    unsafe { syscall_bls12381_g2_subgroup_check(p) != 0 }
+}
+
+/// Map an `Fp2` element to a BLS12-381 G2 point via SSWU + isogeny.
+pub fn g2_map_to_curve(u: &[u32; 24]) -> [u32; 48] {
+    let mut out = [0u32; 48];
+    unsafe { syscall_bls12381_g2_map(u, &mut out) };
+    out
+}
+
+/// Decompress a compressed BLS12-381 G2 point.
+pub fn g2_decompress(compressed: &[u32; 24]) -> [u32; 48] {
+    let mut out = [0u32; 48];
+    unsafe { syscall_bls12381_g2_decompress(compressed, &mut out) };
+    out
+}
+
+/// Compute the BLS12-381 Miller loop of `(g1, g2)`, returning an `Fp12` element (12 `Fq`
+/// coordinates, 12 words each).
+pub fn miller_loop(g1: &[u32; 24], g2: &[u32; 48]) -> [u32; 144] {
+    let mut out = [0u32; 144];
+    unsafe { syscall_bls12381_miller_loop(g1, g2, &mut out) };
+    out
+}
+
+/// Apply the BLS12-381 final exponentiation to an `Fp12` element in place.
+pub fn final_exponentiate(f: &mut [u32; 144]) {
+    unsafe { syscall_bls12381_final_exp(f) }
+}
+
+/// Check that the product of pairings over `pairs` (alternating G1/G2 point encodings) equals
+/// the identity in the target group, as used by BLS aggregate signature verification.
+#[must_use]
+pub fn pairing_check(pairs: &[u32]) -> bool {
+    unsafe { syscall_bls12381_pairing_check(pairs.as_ptr(), (pairs.len() / (24 + 48)) as u32) != 0 }
+}
+
+/// Verifies a min-pubkey-size BLS signature: `pubkey` (G1) signed `message` (the message hash
+/// already mapped onto G2 -- see [`g2_map_to_curve`]) iff `signature` (G2) satisfies
+/// `e(pubkey, message) == e(generator, signature)`, which this checks via
+/// `e(pubkey, message) * e(neg_generator, signature) == 1`.
+///
+/// `neg_generator` is the negation of the G1 generator the signature scheme was keyed against.
+/// This module has no point-negation primitive of its own (`pairing_check` is otherwise agnostic
+/// to which side of an equation is negated), so the caller supplies it precomputed -- it is a
+/// fixed constant for a given scheme, not something that varies per verification.
+#[must_use]
+pub fn g2_signature_verify(
+    pubkey: &[u32; 24],
+    message: &[u32; 48],
+    neg_generator: &[u32; 24],
+    signature: &[u32; 48],
+) -> bool {
+    let mut pairs = [0u32; 2 * (24 + 48)];
+    pairs[0..24].copy_from_slice(pubkey);
+    pairs[24..72].copy_from_slice(message);
+    pairs[72..96].copy_from_slice(neg_generator);
+    pairs[96..144].copy_from_slice(signature);
+    pairing_check(&pairs)
+}
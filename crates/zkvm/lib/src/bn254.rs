@@ -0,0 +1,69 @@
+//! BN254 (alt_bn128) group arithmetic and pairing, mirroring [`crate::bls12381`]'s shape. These
+//! accelerate in-guest Groth16/zkSNARK verification and BN254-based signature schemes, where the
+//! field/pairing arithmetic is prohibitively many cycles in pure MIPS.
+//!
+//! Unlike [`crate::bls12381`], this module doesn't expose Miller-loop/final-exponentiation as
+//! separate steps -- `syscall_bn254_pairing_check` is the only pairing entry point currently
+//! wired up (see `zkm2_core_executor::syscalls::bn254`), so that's all this wraps.
+
+/// Selects plain addition in [`fr_op`], matching
+/// `zkm2_core_executor::syscalls::bn254::BN254_FR_OP_ADD`.
+pub const FR_OP_ADD: u32 = 0;
+/// Selects subtraction in [`fr_op`].
+pub const FR_OP_SUB: u32 = 1;
+/// Selects multiplication in [`fr_op`].
+pub const FR_OP_MUL: u32 = 2;
+
+extern "C" {
+    fn syscall_bn254_add(p: *mut [u32; 16], q: *const [u32; 16]);
+    fn syscall_bn254_double(p: *mut [u32; 16]);
+    fn syscall_bn254_scalar_mul(p: *mut [u32; 16], scalar: *const [u32; 8]);
+    fn syscall_bn254_scalar_mac(dst: *mut [u32; 8], a: *const [u32; 8], b: *const [u32; 8]);
+    fn syscall_bn254_fr_op(a: *mut [u32; 8], b: *const [u32; 8], general_field_op: u32);
+    fn syscall_bn254_pairing_check(pairs: *const u32, num_pairs: u32) -> u32;
+}
+
+/// Add two BN254 G1 points in place: `p += q`.
+pub fn add(p: &mut [u32; 16], q: &[u32; 16]) {
+    unsafe { syscall_bn254_add(p, q) }
+}
+
+/// Double a BN254 G1 point in place.
+pub fn double(p: &mut [u32; 16]) {
+    unsafe { syscall_bn254_double(p) }
+}
+
+/// Multiply a BN254 G1 point by a scalar in place.
+pub fn scalar_mul(p: &mut [u32; 16], scalar: &[u32; 8]) {
+    unsafe { syscall_bn254_scalar_mul(p, scalar) }
+}
+
+/// Compute `dst <- dst + a * b mod n` over the Bn254 scalar field (`F_r`), where `n` is the Bn254
+/// scalar modulus and each of `dst`, `a`, `b` is a 256-bit value stored as eight little-endian
+/// words. Accelerates repeated field multiply-accumulates (e.g. Horner's-method polynomial
+/// evaluation in Groth16/ECDSA verification) that would otherwise cost thousands of MIPS cycles
+/// per multiply done in software.
+pub fn scalar_mac(dst: &mut [u32; 8], a: &[u32; 8], b: &[u32; 8]) {
+    unsafe { syscall_bn254_scalar_mac(dst, a, b) }
+}
+
+/// Compute `a <- a OP b mod n` over the Bn254 scalar field (`F_r`), `OP` selected by
+/// `general_field_op` ([`FR_OP_ADD`]/[`FR_OP_SUB`]/[`FR_OP_MUL`]).
+pub fn fr_op(a: &mut [u32; 8], b: &[u32; 8], general_field_op: u32) {
+    unsafe { syscall_bn254_fr_op(a, b, general_field_op) }
+}
+
+/// Multiply two Bn254 scalar-field (`F_r`) elements in place: `p <- p * q mod n`. A thin wrapper
+/// over [`fr_op`] with [`FR_OP_MUL`], named to match the `syscall_bn254_scalar_mul(p, q)` shape of
+/// [`scalar_mul`] above -- but operating on plain `F_r` elements rather than a G1 point, since that
+/// name is already taken by point-by-scalar multiplication.
+pub fn fr_mul(p: &mut [u32; 8], q: &[u32; 8]) {
+    fr_op(p, q, FR_OP_MUL)
+}
+
+/// Check that the product of pairings over `pairs` (alternating 16-word G1 and 32-word G2 point
+/// encodings) equals the identity in the target group, as used by Groth16/zkSNARK verification.
+#[must_use]
+pub fn pairing_check(pairs: &[u32]) -> bool {
+    unsafe { syscall_bn254_pairing_check(pairs.as_ptr(), (pairs.len() / (16 + 32)) as u32) != 0 }
+}
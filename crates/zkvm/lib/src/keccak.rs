@@ -2,7 +2,7 @@
 //! https://github.com/debris/tiny-keccak
 
 use crate::hasher::{Hasher, Mode};
-use crate::syscall_keccak_permute;
+use crate::{syscall_keccak_permute, syscall_keccak_permute_batch};
 
 pub const WORDS: usize = 25;
 
@@ -183,6 +183,116 @@ pub(crate) fn keccakf(state: &mut [u64; 25]) {
     }
 }
 
+/// Drives `N` independent Keccak sponges in lockstep, sharing one [`syscall_keccak_permute_batch`]
+/// call per round instead of `N` separate [`syscall_keccak_permute`] calls. Padding, the rate and
+/// the absorb/squeeze bookkeeping all stay per-sponge -- only the permutation itself is shared --
+/// so every digest produced this way is identical to running [`KeccakState`] `N` times.
+///
+/// Callers must keep all `N` sponges in lockstep: every [`Self::update`]/[`Self::squeeze`] call
+/// takes exactly one input/output slice per sponge, and all `N` must be the same length.
+pub(crate) struct KeccakBatch<const N: usize> {
+    buffers: [KeccakBuffer; N],
+    offset: usize,
+    rate: usize,
+    delim: u8,
+    mode: Mode,
+}
+
+impl<const N: usize> KeccakBatch<N> {
+    pub(crate) fn new(rate: usize, delim: u8) -> Self {
+        assert!(rate != 0, "rate cannot be equal 0");
+        KeccakBatch {
+            buffers: core::array::from_fn(|_| KeccakBuffer::default()),
+            offset: 0,
+            rate,
+            delim,
+            mode: Mode::Absorbing,
+        }
+    }
+
+    fn keccak(&mut self) {
+        let mut states: [[u64; 25]; N] = core::array::from_fn(|i| *self.buffers[i].words());
+        unsafe {
+            syscall_keccak_permute_batch(states.as_mut_ptr(), N as u32);
+        }
+        for (buffer, state) in self.buffers.iter_mut().zip(states.iter()) {
+            *buffer.words() = *state;
+        }
+    }
+
+    pub(crate) fn update(&mut self, inputs: &[&[u8]; N]) {
+        if let Mode::Squeezing = self.mode {
+            self.mode = Mode::Absorbing;
+            self.fill_block();
+        }
+
+        let len = inputs[0].len();
+        assert!(inputs.iter().all(|input| input.len() == len), "batched sponges must stay in lockstep");
+
+        let mut ip = 0;
+        let mut l = len;
+        let mut rate = self.rate - self.offset;
+        let mut offset = self.offset;
+        while l >= rate {
+            for (buffer, input) in self.buffers.iter_mut().zip(inputs.iter()) {
+                buffer.xorin(&input[ip..], offset, rate);
+            }
+            self.keccak();
+            ip += rate;
+            l -= rate;
+            rate = self.rate;
+            offset = 0;
+        }
+
+        for (buffer, input) in self.buffers.iter_mut().zip(inputs.iter()) {
+            buffer.xorin(&input[ip..], offset, l);
+        }
+        self.offset = offset + l;
+    }
+
+    fn pad(&mut self) {
+        for buffer in &mut self.buffers {
+            buffer.pad(self.offset, self.delim, self.rate);
+        }
+    }
+
+    pub(crate) fn squeeze(&mut self, outputs: &mut [&mut [u8]; N]) {
+        if let Mode::Absorbing = self.mode {
+            self.mode = Mode::Squeezing;
+            self.pad();
+            self.fill_block();
+        }
+
+        let len = outputs[0].len();
+        assert!(outputs.iter().all(|output| output.len() == len), "batched sponges must stay in lockstep");
+
+        let mut op = 0;
+        let mut l = len;
+        let mut rate = self.rate - self.offset;
+        let mut offset = self.offset;
+        while l >= rate {
+            for (buffer, output) in self.buffers.iter_mut().zip(outputs.iter_mut()) {
+                buffer.setout(&mut output[op..], offset, rate);
+            }
+            self.keccak();
+            op += rate;
+            l -= rate;
+            rate = self.rate;
+            offset = 0;
+        }
+
+        for (buffer, output) in self.buffers.iter_mut().zip(outputs.iter_mut()) {
+            buffer.setout(&mut output[op..], offset, l);
+        }
+        self.offset = offset + l;
+    }
+
+    fn fill_block(&mut self) {
+        self.keccak();
+        self.offset = 0;
+    }
+}
+
 #[derive(Clone)]
 pub struct Keccak {
     state: KeccakState,
@@ -235,3 +345,91 @@ impl Hasher for Keccak {
         self.state.finalize(output);
     }
 }
+
+/// FIPS-202 SHA3-224/256/384/512: the NIST-standardized sibling of [`Keccak`], differing only in
+/// its padding (domain suffix `0x06` instead of `0x01`).
+#[derive(Clone)]
+pub struct Sha3 {
+    state: KeccakState,
+}
+
+impl Sha3 {
+    const DELIM: u8 = 0x06;
+
+    /// Creates a new [`Sha3`] hasher with a security level of 224 bits.
+    pub fn v224() -> Sha3 {
+        Sha3::new(224)
+    }
+
+    /// Creates a new [`Sha3`] hasher with a security level of 256 bits.
+    pub fn v256() -> Sha3 {
+        Sha3::new(256)
+    }
+
+    /// Creates a new [`Sha3`] hasher with a security level of 384 bits.
+    pub fn v384() -> Sha3 {
+        Sha3::new(384)
+    }
+
+    /// Creates a new [`Sha3`] hasher with a security level of 512 bits.
+    pub fn v512() -> Sha3 {
+        Sha3::new(512)
+    }
+
+    fn new(bits: usize) -> Sha3 {
+        Sha3 { state: KeccakState::new(bits_to_rate(bits), Self::DELIM) }
+    }
+}
+
+impl Hasher for Sha3 {
+    /// Absorb additional input. Can be called multiple times.
+    fn update(&mut self, input: &[u8]) {
+        self.state.update(input);
+    }
+
+    /// Pad and squeeze the state to the output.
+    fn finalize(self, output: &mut [u8]) {
+        self.state.finalize(output);
+    }
+}
+
+/// A FIPS-202 SHAKE128/256 extendable-output function (XOF). Unlike [`Sha3`], the digest length
+/// isn't fixed by the security level: [`Shake::squeeze`] can be called repeatedly, each time
+/// appending the next `output.len()` bytes of keystream, because the underlying
+/// [`KeccakState::squeeze`] already runs another permutation and keeps going once the current
+/// block is exhausted rather than being limited to a single block's worth of output.
+#[derive(Clone)]
+pub struct Shake {
+    state: KeccakState,
+}
+
+impl Shake {
+    const DELIM: u8 = 0x1f;
+
+    /// Creates a new SHAKE128 instance (rate 168 bytes, 128-bit security).
+    pub fn v128() -> Shake {
+        Shake::new(128)
+    }
+
+    /// Creates a new SHAKE256 instance (rate 136 bytes, 256-bit security).
+    pub fn v256() -> Shake {
+        Shake::new(256)
+    }
+
+    fn new(bits: usize) -> Shake {
+        Shake { state: KeccakState::new(bits_to_rate(bits), Self::DELIM) }
+    }
+
+    /// Absorb additional input. Can be called multiple times, but not after [`Self::squeeze`] has
+    /// started producing output.
+    pub fn update(&mut self, input: &[u8]) {
+        self.state.update(input);
+    }
+
+    /// Squeezes the next `output.len()` bytes of keystream out of the sponge. May be called
+    /// repeatedly to draw an arbitrarily long XOF output incrementally, picking up exactly where
+    /// the previous call left off.
+    pub fn squeeze(&mut self, output: &mut [u8]) {
+        self.state.squeeze(output);
+    }
+}
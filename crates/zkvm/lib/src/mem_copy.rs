@@ -0,0 +1,20 @@
+//! Fixed-block bulk memory copy, for guest code moving whole buffers (shuffling data before
+//! hashing, ABI-encoding EVM calldata, etc.) that would otherwise cost one load/store pair per
+//! word. Modeled on the single-syscall shape of `syscall_keccak_sponge`
+//! (`zkm2_zkvm::syscalls::syscall_keccak_sponge`), just for plain memory movement instead of a
+//! specific algorithm.
+
+extern "C" {
+    fn syscall_memcpy_32(src: *const u32, dst: *mut u32);
+    fn syscall_memcpy_64(src: *const u32, dst: *mut u32);
+}
+
+/// Copy a fixed 32-word (128-byte) block from `src` to `dst` in one precompile call.
+pub fn copy_32(src: &[u32; 32], dst: &mut [u32; 32]) {
+    unsafe { syscall_memcpy_32(src.as_ptr(), dst.as_mut_ptr()) }
+}
+
+/// Copy a fixed 64-word (256-byte) block from `src` to `dst` in one precompile call.
+pub fn copy_64(src: &[u32; 64], dst: &mut [u32; 64]) {
+    unsafe { syscall_memcpy_64(src.as_ptr(), dst.as_mut_ptr()) }
+}
@@ -0,0 +1,29 @@
+//! Merkle-tree membership/merge precompile wrappers, atop `syscall_mtree_verify_path` and
+//! `syscall_mtree_merge` (see `zkm2_core_executor::syscalls::mtree`).
+
+extern "C" {
+    fn syscall_mtree_verify_path(
+        leaf: *const [u32; 4],
+        index: u32,
+        depth: u32,
+        claimed_root: *const [u32; 4],
+    ) -> u32;
+    fn syscall_mtree_merge(left: *mut [u32; 4], right: *const [u32; 4]);
+}
+
+/// Verifies that `leaf` at `index` is included in the tree rooted at `claimed_root`, given
+/// `depth` sibling digests already queued onto the hint stream (leaf-to-root order) via
+/// `zkm2_zkvm::io::hint_slice`-style advice. Returns `false` both when the path genuinely doesn't
+/// verify and when `index`/`depth` are malformed (e.g. `index` doesn't fit in `depth` bits, or
+/// `depth` exceeds the chip's fixed maximum) -- see
+/// `zkm2_core_executor::events::MTREE_MAX_DEPTH`.
+#[must_use]
+pub fn verify_path(leaf: &[u32; 4], index: u32, depth: u32, claimed_root: &[u32; 4]) -> bool {
+    unsafe { syscall_mtree_verify_path(leaf, index, depth, claimed_root) != 0 }
+}
+
+/// Merges two subtree roots into their parent root with the same compression function
+/// [`verify_path`] uses per level.
+pub fn merge(left: &mut [u32; 4], right: &[u32; 4]) {
+    unsafe { syscall_mtree_merge(left, right) }
+}
@@ -0,0 +1,39 @@
+//! secp256k1 ECDSA public-key recovery and signature verification, accelerated by
+//! `syscall_secp256k1_recover` instead of a pure-Rust elliptic-curve fallback. This is the
+//! building block behind in-guest Ethereum-style signature checks (`ecrecover`).
+
+extern "C" {
+    fn syscall_secp256k1_recover(hash: *const [u32; 8], sig: *mut [u32; 17]) -> u32;
+}
+
+/// Recovers the 64-byte uncompressed public key `(x, y)` for a signature `(r, s, recovery_id)`
+/// over `hash`, or `None` if the inputs don't describe a valid signature (out-of-range `r`/`s`,
+/// `r`'s x-coordinate off the curve, or a recovered point at infinity).
+pub fn recover(hash: &[u32; 8], r: &[u32; 8], s: &[u32; 8], recovery_id: u8) -> Option<[u32; 16]> {
+    let mut sig = [0u32; 17];
+    sig[0..8].copy_from_slice(r);
+    sig[8..16].copy_from_slice(s);
+    sig[16] = recovery_id as u32;
+
+    let status = unsafe { syscall_secp256k1_recover(hash, &mut sig) };
+    if status != 0 {
+        return None;
+    }
+
+    let mut pubkey = [0u32; 16];
+    pubkey.copy_from_slice(&sig[0..16]);
+    Some(pubkey)
+}
+
+/// Verifies that signature `(r, s, recovery_id)` over `hash` recovers exactly `expected_pubkey`
+/// (the 64-byte uncompressed `(x, y)` public key).
+#[must_use]
+pub fn verify(
+    hash: &[u32; 8],
+    r: &[u32; 8],
+    s: &[u32; 8],
+    recovery_id: u8,
+    expected_pubkey: &[u32; 16],
+) -> bool {
+    matches!(recover(hash, r, s, recovery_id), Some(pubkey) if pubkey == *expected_pubkey)
+}
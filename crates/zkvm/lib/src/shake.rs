@@ -0,0 +1,24 @@
+//! SHAKE128/256 extendable-output functions. The one-shot helpers below are built on the shared
+//! [`crate::sponge`] driver, which squeezes as many rate blocks as `out_len` needs in a single
+//! `syscall_keccak_sponge` call; for streamed output where the total length isn't known upfront,
+//! use [`crate::keccak::Shake`] instead, which squeezes incrementally off the
+//! [`crate::keccak::KeccakState`] permutation loop. Both share domain suffix `0x1f`; only the
+//! rate differs, matching their respective security strengths. See [`crate::cshake`] for the
+//! customizable variant.
+use crate::sponge::sponge;
+
+/// SHAKE128: rate 168 bytes, domain suffix `0x1f`. For streamed output, use
+/// [`crate::keccak::Shake::v128`].
+pub fn shake128(data: &[u8], out_len: usize) -> Vec<u8> {
+    let mut result = vec![0u8; out_len];
+    sponge(data, 168, 0x1f, &mut result);
+    result
+}
+
+/// SHAKE256: rate 136 bytes, domain suffix `0x1f`. For streamed output, use
+/// [`crate::keccak::Shake::v256`].
+pub fn shake256(data: &[u8], out_len: usize) -> Vec<u8> {
+    let mut result = vec![0u8; out_len];
+    sponge(data, 136, 0x1f, &mut result);
+    result
+}
@@ -0,0 +1,77 @@
+//! Shared Keccak/SHA-3 sponge driver underlying [`crate::sha3`], [`crate::keccak256`], and
+//! [`crate::shake`].
+//!
+//! SHA3-224/256/384/512, Keccak-256, and SHAKE128/256 all run the same sponge construction over
+//! the same `syscall_keccak_sponge` precompile; they differ only in the rate (how many bytes of
+//! the 1600-bit state are absorbed/squeezed per permutation) and the domain-separation suffix
+//! appended after the message before the `10*1` padding. [`sponge`] factors that shared
+//! pad/pack/squeeze logic out so each algorithm's module only needs to supply its `(rate, suffix)`.
+//!
+//! The packing below is correct for any rate, but `KeccakSpongeChip`'s trace currently only
+//! proves one fixed absorb/squeeze block width (see
+//! `zkm2_core_executor::syscalls::keccak_sponge::KECCAK_SPONGE_RATE_U32S`), which happens to match
+//! the block size this packing produces for Keccak-256's 136-byte rate; the other algorithms'
+//! differing rates pack to a different block width and aren't yet provable by that one chip.
+
+use crate::syscall_keccak_sponge;
+
+/// Multi-rate padding: `suffix` is appended as its own byte right after the message, the block is
+/// then zero-filled up to `rate`, and finally the top bit of the last rate byte is set. When the
+/// message already fills the block to one byte short (`data.len() % rate == rate - 1`), both
+/// markers land on the same trailing byte and collapse into `suffix | 0x80`.
+fn pad(data: &[u8], rate: usize, suffix: u8) -> Vec<u8> {
+    let len = data.len();
+    let final_block_len = len % rate;
+    let padded_len = len - final_block_len + rate;
+
+    let mut padded = Vec::with_capacity(padded_len);
+    padded.extend_from_slice(data);
+    padded.resize(padded_len, 0);
+
+    if final_block_len == rate - 1 {
+        padded[padded_len - 1] = suffix | 0b1000_0000;
+    } else {
+        padded[len] = suffix;
+        padded[padded_len - 1] = 0b1000_0000;
+    }
+    padded
+}
+
+/// Runs the sponge over `data` with the given `rate` (bytes absorbed/squeezed per permutation)
+/// and domain-separation `suffix`, writing `output.len()` squeezed bytes into `output`.
+/// `output.len()` isn't limited to one rate block: `syscall_keccak_sponge` re-permutes and
+/// squeezes another block as many times as the requested length needs.
+pub(crate) fn sponge(data: &[u8], rate: usize, suffix: u8, output: &mut [u8]) {
+    let padded = pad(data, rate, suffix);
+
+    // Re-pack big-endian bytes into little-endian u32 words, with two padding words inserted
+    // after every rate-sized block to align the sponge state the precompile expects.
+    let words_per_block = rate / 4;
+    let mut u32_array = Vec::with_capacity(padded.len() / 4 + (padded.len() / rate) * 2);
+    let mut count = 0;
+    for chunk in padded.chunks_exact(4) {
+        u32_array.push(u32::from_be_bytes([chunk[3], chunk[2], chunk[1], chunk[0]]));
+        count += 1;
+        if count == words_per_block {
+            u32_array.extend_from_slice(&[0, 0]);
+            count = 0;
+        }
+    }
+
+    let input_len_words = u32_array.len() as u32;
+    let out_len_words = output.len().div_ceil(4);
+    let mut result = vec![0u32; out_len_words];
+
+    // `syscall_keccak_sponge` only takes `input` directly; `result`/`input_len_words`/
+    // `out_len_words` are packed into a descriptor instead (see the syscall's own doc comment).
+    let descriptor: [u32; 3] =
+        [result.as_mut_ptr() as u32, &input_len_words as *const u32 as u32, out_len_words as u32];
+    unsafe {
+        syscall_keccak_sponge(u32_array.as_ptr(), descriptor.as_ptr());
+    }
+
+    let result_bytes: &[u8] = unsafe {
+        core::slice::from_raw_parts(result.as_ptr().cast::<u8>(), out_len_words * 4)
+    };
+    output.copy_from_slice(&result_bytes[..output.len()]);
+}